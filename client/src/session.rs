@@ -1675,6 +1675,105 @@ impl Session {
         }
     }
 
+    /// Reads the `DataTypeDefinition` attribute of data types from the server and adds them to
+    /// the registry so that values of those types can be decoded into a [`DynamicStructure`].
+    /// The data types used by the fields of each definition are read as well until the registry
+    /// knows every type it needs. A data type without a definition, e.g. a subtype of `String`,
+    /// is added to the registry as a subtype of its base type.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - The registry to add definitions to.
+    /// * `data_type_ids` - The data types to read, e.g. the `DataType` attribute of a variable.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The definitions were read. Types that the server could not describe are left
+    ///              in `registry.unknown_data_types()`.
+    /// * `Err(StatusCode)` - Request failed, status code is the reason for failure
+    ///
+    /// [`DynamicStructure`]: ./struct.DynamicStructure.html
+    ///
+    pub fn read_data_type_definitions(
+        &mut self,
+        registry: &mut DataTypeRegistry,
+        data_type_ids: &[NodeId],
+    ) -> Result<(), StatusCode> {
+        let decoding_limits = {
+            let secure_channel = trace_read_lock_unwrap!(self.secure_channel);
+            secure_channel.decoding_limits()
+        };
+        let mut attempted = HashSet::new();
+        let mut to_read = data_type_ids
+            .iter()
+            .filter(|data_type_id| !registry.is_known(data_type_id))
+            .cloned()
+            .collect::<Vec<NodeId>>();
+        while !to_read.is_empty() {
+            attempted.extend(to_read.iter().cloned());
+            let nodes_to_read = to_read
+                .iter()
+                .map(|data_type_id| ReadValueId {
+                    node_id: data_type_id.clone(),
+                    attribute_id: AttributeId::DataTypeDefinition as u32,
+                    index_range: UAString::null(),
+                    data_encoding: QualifiedName::null(),
+                })
+                .collect::<Vec<ReadValueId>>();
+            let results = self.read(&nodes_to_read)?;
+            let mut without_definition = Vec::new();
+            for (data_type_id, result) in to_read.iter().zip(results.iter()) {
+                if let Some(Variant::ExtensionObject(ref value)) = result.value {
+                    let definition =
+                        DataTypeDefinition::from_extension_object(value, &decoding_limits)?;
+                    registry.add_definition(data_type_id, definition);
+                } else {
+                    without_definition.push(data_type_id.clone());
+                }
+            }
+
+            // Types without a definition are encoded as their base type, which may be unknown too
+            let mut base_types = Vec::new();
+            if !without_definition.is_empty() {
+                let nodes_to_browse = without_definition
+                    .iter()
+                    .map(|data_type_id| BrowseDescription {
+                        node_id: data_type_id.clone(),
+                        browse_direction: BrowseDirection::Inverse,
+                        reference_type_id: ReferenceTypeId::HasSubtype.into(),
+                        include_subtypes: false,
+                        node_class_mask: NodeClassMask::DATA_TYPE.bits(),
+                        result_mask: BrowseDescriptionResultMask::empty().bits(),
+                    })
+                    .collect::<Vec<BrowseDescription>>();
+                let results = self.browse(&nodes_to_browse)?.unwrap_or_default();
+                for (data_type_id, result) in without_definition.iter().zip(results.iter()) {
+                    if let Some(base_type) = result.references.as_ref().and_then(|r| r.first()) {
+                        let base_type_id = base_type.node_id.node_id.clone();
+                        registry.add_subtype(data_type_id, &base_type_id);
+                        base_types.push(base_type_id);
+                    } else {
+                        session_warn!(
+                            self,
+                            "Data type {} has no definition or base type",
+                            data_type_id
+                        );
+                    }
+                }
+            }
+
+            to_read = registry
+                .unknown_data_types()
+                .into_iter()
+                .chain(base_types.into_iter().filter(|id| !registry.is_known(id)))
+                .filter(|data_type_id| !attempted.contains(data_type_id))
+                .collect::<HashSet<NodeId>>()
+                .into_iter()
+                .collect();
+        }
+        Ok(())
+    }
+
     /// Reads historical values or events of one or more nodes. The caller is expected to encode a history read
     /// operation into an extension object which must be one of the following:
     ///
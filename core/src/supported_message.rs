@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated by tools/schema/gen_supported_message.js
+// This file was autogenerated by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
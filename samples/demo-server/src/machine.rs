@@ -26,14 +26,6 @@ pub fn add_machinery(server: &mut Server, ns: u16, raise_event: bool) {
             .add_folder("Devices", "Devices", &NodeId::objects_folder_id())
             .unwrap();
 
-        // Create the machine events folder
-        let _ = address_space.add_folder_with_id(
-            &machine_events_folder_id(ns),
-            "Events",
-            "Events",
-            &devices_folder_id,
-        );
-
         // Create an object representing a machine that cycles from 0 to 100. Each time it cycles it will create an event
         let machine1_id = add_machine(
             &mut address_space,
@@ -76,10 +68,6 @@ fn machine_type_id(ns: u16) -> NodeId {
     NodeId::new(ns, "MachineTypeId")
 }
 
-fn machine_events_folder_id(ns: u16) -> NodeId {
-    NodeId::new(ns, "MachineEvents")
-}
-
 fn add_machinery_model(address_space: &mut AddressSpace, ns: u16) {
     // Create a machine counter type derived from BaseObjectType
     let machine_type_id = machine_type_id(ns);
@@ -168,14 +156,13 @@ lazy_static! {
 }
 
 impl MachineCycledEventType {
-    fn new<R, S, T, U, V>(
+    fn new<R, S, T, U>(
         machine_name: &str,
         ns: u16,
         node_id: R,
         browse_name: S,
         display_name: T,
-        parent_node: U,
-        source_node: V,
+        source_node: U,
         time: DateTime,
     ) -> Self
     where
@@ -183,7 +170,6 @@ impl MachineCycledEventType {
         S: Into<QualifiedName>,
         T: Into<LocalizedText>,
         U: Into<NodeId>,
-        V: Into<NodeId>,
     {
         let event_type_id = MachineCycledEventType::event_type_id(ns);
         let source_node: NodeId = source_node.into();
@@ -193,7 +179,7 @@ impl MachineCycledEventType {
                 event_type_id,
                 browse_name,
                 display_name,
-                parent_node,
+                NodeId::null(),
                 time,
             )
             .source_node(source_node.clone())
@@ -239,12 +225,11 @@ fn raise_machine_cycled_event(
         &event_node_id,
         event_name.clone(),
         event_name,
-        machine_events_folder_id(ns),
         source_machine_id,
         now,
     );
 
-    // create an event object in a folder with the
+    // Raise the event onto the event bus
     let _ = event.raise(address_space);
 }
 
@@ -4,30 +4,50 @@
 
 //! Implementation of `AddressSpace`.
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::mem;
+use std::path::Path;
 use std::sync::{Arc, Mutex, RwLock};
 
 use chrono::Utc;
+use futures::future;
 
 use opcua_types::{
     node_ids::VariableId::*,
-    service_types::{BrowseDirection, CallMethodRequest, CallMethodResult, NodeClass},
+    service_types::{
+        BrowseDirection, CallMethodRequest, CallMethodResult, ModelChangeStructureDataType,
+        ModelChangeStructureVerbMask, NodeClass, SemanticChangeStructureDataType,
+    },
     status_code::StatusCode,
     *,
 };
 
 use crate::{
     address_space::{
-        node::{HasNodeId, NodeType},
+        method::Method,
+        node::{HasNodeId, NodeBase, NodeType},
         object::{Object, ObjectBuilder},
         references::{Reference, ReferenceDirection, References},
-        variable::Variable,
-        AttrFnGetter,
+        snapshot::{AddressSpaceSnapshot, NodeSnapshot, ReferenceSnapshot},
+        value_changes::ValueChanges,
+        variable::{Variable, VariableBuilder},
+        AccessLevel, AttrFnGetter,
     },
-    callbacks, constants,
+    callbacks::{self, AsyncResult},
     diagnostics::ServerDiagnostics,
+    events::{
+        event::Event,
+        event_bus::EventBus,
+        model_change_event::{
+            BaseModelChangeEventType, GeneralModelChangeEventType, SemanticChangeEventType,
+        },
+    },
     historical::HistoryServerCapabilities,
+    node_manager::NodeManager,
     session::Session,
     state::ServerState,
+    util,
 };
 
 /// Finds a node in the address space and coerces it into a reference of the expected node type.
@@ -124,8 +144,20 @@ macro_rules! server_diagnostics_summary {
 
 pub(crate) type MethodCallback = Box<dyn callbacks::Method + Send + Sync>;
 
+pub(crate) type AsyncMethodCallback = Box<dyn callbacks::AsyncMethod + Send + Sync>;
+
 const OPCUA_INTERNAL_NAMESPACE_IDX: u16 = 1;
 
+/// Properties whose value changes the meaning of the node they belong to, even when they do not
+/// have the `SEMANTIC_CHANGE` access level.
+const SEMANTIC_PROPERTIES: [&str; 5] = [
+    "EURange",
+    "EngineeringUnits",
+    "EnumStrings",
+    "EnumValues",
+    "InstrumentRange",
+];
+
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
 struct MethodKey {
     object_id: NodeId,
@@ -174,6 +206,20 @@ pub struct AddressSpace {
     internal_namespace: u16,
     /// The list of all registered namespaces.
     namespaces: Vec<String>,
+    /// Node managers that serve whole namespaces in place of this address space
+    node_managers: HashMap<u16, Arc<Mutex<dyn NodeManager + Send>>>,
+    /// Records model and semantic changes so they can be raised as events
+    model_change_events: bool,
+    /// Model changes that have not been raised yet, one per affected node
+    model_changes: Vec<ModelChangeStructureDataType>,
+    /// Semantic changes that have not been raised yet, one per affected node
+    semantic_changes: Vec<SemanticChangeStructureDataType>,
+    /// Set when an attribute other than Value has changed and it has not been raised yet
+    attribute_changes: bool,
+    /// Monitored items to tell when the value of a variable changes
+    value_changes: Arc<Mutex<ValueChanges>>,
+    /// Events that have been raised and are waiting to be received by monitored items
+    events: EventBus,
 }
 
 impl Default for AddressSpace {
@@ -190,6 +236,13 @@ impl Default for AddressSpace {
             // OPC UA namespace for its standard nodes. The second is the internal namespace used
             // by this implementation.
             namespaces: vec!["http://opcfoundation.org/UA/".to_string()],
+            node_managers: HashMap::new(),
+            model_change_events: false,
+            model_changes: Vec::new(),
+            semantic_changes: Vec::new(),
+            attribute_changes: false,
+            value_changes: Arc::new(Mutex::new(ValueChanges::default())),
+            events: EventBus::default(),
         }
     }
 }
@@ -237,6 +290,43 @@ impl AddressSpace {
         }
     }
 
+    /// Returns the index each namespace would have if they were registered in order, without
+    /// registering them. Namespaces that are already registered keep their index. An error is
+    /// returned if any of them could not be registered.
+    pub(crate) fn namespace_indexes_to_register(
+        &self,
+        namespaces: &[String],
+    ) -> Result<Vec<u16>, ()> {
+        use std::u16;
+        let mut new_namespaces: Vec<&str> = Vec::new();
+        namespaces
+            .iter()
+            .map(|namespace| {
+                if namespace.is_empty() {
+                    Err(())
+                } else if let Some(i) = self.namespace_index(namespace) {
+                    Ok(i)
+                } else {
+                    let i = if let Some(i) = new_namespaces
+                        .iter()
+                        .position(|ns| *ns == namespace.as_str())
+                    {
+                        i
+                    } else {
+                        new_namespaces.push(namespace);
+                        new_namespaces.len() - 1
+                    };
+                    let ns = self.namespaces.len() + i;
+                    if ns >= u16::MAX as usize {
+                        Err(())
+                    } else {
+                        Ok(ns as u16)
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Finds the namespace index of a given namespace
     pub fn namespace_index(&self, namespace: &str) -> Option<u16> {
         self.namespaces
@@ -248,6 +338,67 @@ impl AddressSpace {
             .map(|i| i as u16)
     }
 
+    /// Finds the namespace uri of a given namespace index
+    pub fn namespace_uri(&self, namespace: u16) -> Option<&str> {
+        self.namespaces
+            .get(namespace as usize)
+            .map(|ns| ns.as_str())
+    }
+
+    /// Registers a namespace and delegates every node in it to the supplied [`NodeManager`]. The
+    /// return code is the index of the namespace. Unlike `register_namespace()`, the default
+    /// namespace is left unchanged since nodes in the namespace are not held by the address space.
+    /// It is an error to register a namespace that already has a manager.
+    ///
+    /// [`NodeManager`]: ../../node_manager/trait.NodeManager.html
+    pub fn register_node_manager<T>(&mut self, namespace: &str, node_manager: T) -> Result<u16, ()>
+    where
+        T: NodeManager + Send + 'static,
+    {
+        let default_namespace = self.default_namespace;
+        let ns = self.register_namespace(namespace)?;
+        self.default_namespace = default_namespace;
+        if self.node_managers.contains_key(&ns) {
+            error!("Namespace {} already has a node manager", namespace);
+            Err(())
+        } else {
+            self.node_managers
+                .insert(ns, Arc::new(Mutex::new(node_manager)));
+            Ok(ns)
+        }
+    }
+
+    /// Returns the listeners that are told when the value of a variable changes
+    pub(crate) fn value_changes(&self) -> &Arc<Mutex<ValueChanges>> {
+        &self.value_changes
+    }
+
+    /// Returns the events that have been raised
+    pub fn events(&self) -> &EventBus {
+        &self.events
+    }
+
+    /// Returns the events that have been raised for modification, e.g. to raise an event or to
+    /// change the number of events held
+    pub fn events_mut(&mut self) -> &mut EventBus {
+        &mut self.events
+    }
+
+    /// Lets a variable tell the monitored items listening to it when its value changes
+    fn set_value_changes(&self, node_type: &mut NodeType) {
+        if let NodeType::Variable(ref mut variable) = *node_type {
+            variable.set_value_changes(self.value_changes.clone());
+        }
+    }
+
+    /// Returns the [`NodeManager`] for the namespace of the node id, or `None` if the node
+    /// belongs to the address space.
+    ///
+    /// [`NodeManager`]: ../../node_manager/trait.NodeManager.html
+    pub fn node_manager(&self, node_id: &NodeId) -> Option<Arc<Mutex<dyn NodeManager + Send>>> {
+        self.node_managers.get(&node_id.namespace).cloned()
+    }
+
     fn set_servers(&mut self, server_state: Arc<RwLock<ServerState>>, now: &DateTime) {
         let server_state = trace_read_lock_unwrap!(server_state);
         if let Some(ref mut v) = self.find_variable_mut(Server_ServerArray) {
@@ -292,129 +443,7 @@ impl AddressSpace {
             // ServerCapabilities
             {
                 let server_state = trace_read_lock_unwrap!(server_state);
-                let server_config = trace_read_lock_unwrap!(server_state.config);
-                self.set_variable_value(
-                    Server_ServerCapabilities_MaxArrayLength,
-                    server_config.limits.max_array_length as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_MaxStringLength,
-                    server_config.limits.max_string_length as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_MaxByteStringLength,
-                    server_config.limits.max_byte_string_length as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_MaxBrowseContinuationPoints,
-                    constants::MAX_BROWSE_CONTINUATION_POINTS as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_MaxHistoryContinuationPoints,
-                    constants::MAX_HISTORY_CONTINUATION_POINTS as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_MaxQueryContinuationPoints,
-                    constants::MAX_QUERY_CONTINUATION_POINTS as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_MinSupportedSampleRate,
-                    constants::MIN_SAMPLING_INTERVAL as f64,
-                    &now,
-                    &now,
-                );
-                let locale_ids: Vec<Variant> = server_config
-                    .locale_ids
-                    .iter()
-                    .map(|v| UAString::from(v).into())
-                    .collect();
-                self.set_variable_value(
-                    Server_ServerCapabilities_LocaleIdArray,
-                    locale_ids,
-                    &now,
-                    &now,
-                );
-
-                let ol = &server_state.operational_limits;
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerRead,
-                    ol.max_nodes_per_read as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerWrite,
-                    ol.max_nodes_per_write as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerMethodCall,
-                    ol.max_nodes_per_method_call as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerBrowse,
-                    ol.max_nodes_per_browse as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerRegisterNodes,
-                    ol.max_nodes_per_register_nodes as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(Server_ServerCapabilities_OperationLimits_MaxNodesPerTranslateBrowsePathsToNodeIds, ol.max_nodes_per_translate_browse_paths_to_node_ids as u32, &now, &now);
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerNodeManagement,
-                    ol.max_nodes_per_node_management as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxMonitoredItemsPerCall,
-                    ol.max_monitored_items_per_call as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryReadData,
-                    ol.max_nodes_per_history_read_data as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryReadEvents,
-                    ol.max_nodes_per_history_read_events as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryUpdateData,
-                    ol.max_nodes_per_history_update_data as u32,
-                    &now,
-                    &now,
-                );
-                self.set_variable_value(
-                    Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryUpdateEvents,
-                    ol.max_nodes_per_history_update_events as u32,
-                    &now,
-                    &now,
-                );
+                self.update_server_capabilities(&server_state);
             }
 
             // Server_ServerCapabilities_ServerProfileArray
@@ -603,16 +632,12 @@ impl AddressSpace {
 
             // State OPC UA Part 5 12.6, Valid states are
             //     State (Server_ServerStatus_State)
-            self.set_variable_getter(
-                Server_ServerStatus_State,
-                move |_, timestamps_to_return, _, _, _, _| {
-                    // let server_state =  trace_read_lock_unwrap!(server_state);
-                    let now = DateTime::now();
-                    let mut value = DataValue::from(0i32);
-                    value.set_timestamps(timestamps_to_return, now.clone(), now);
-                    Ok(Some(value))
-                },
-            );
+            //     SecondsTillShutdown (Server_ServerStatus_SecondsTillShutdown)
+            //     ShutdownReason (Server_ServerStatus_ShutdownReason)
+            {
+                let server_state = trace_read_lock_unwrap!(server_state);
+                self.update_server_status(&server_state);
+            }
 
             // ServerStatus_BuildInfo
             {
@@ -634,9 +659,170 @@ impl AddressSpace {
                 MethodId::Server_GetMonitoredItems,
                 Box::new(method_impls::ServerGetMonitoredItemsMethod),
             );
+            self.register_method_handler(
+                MethodId::Server_SetSubscriptionDurable,
+                Box::new(method_impls::ServerSetSubscriptionDurableMethod),
+            );
         }
     }
 
+    /// Sets the server capabilities variables from the server state and its configuration.
+    pub fn update_server_capabilities(&mut self, server_state: &ServerState) {
+        let now = DateTime::now();
+        let server_config = trace_read_lock_unwrap!(server_state.config);
+        self.set_variable_value(
+            Server_ServerCapabilities_MaxArrayLength,
+            server_config.limits.max_array_length as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_MaxStringLength,
+            server_config.limits.max_string_length as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_MaxByteStringLength,
+            server_config.limits.max_byte_string_length as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_MaxBrowseContinuationPoints,
+            server_config.limits.max_browse_continuation_points as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_MaxHistoryContinuationPoints,
+            server_config.limits.max_history_continuation_points as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_MaxQueryContinuationPoints,
+            server_config.limits.max_query_continuation_points as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_MinSupportedSampleRate,
+            server_state.min_sampling_interval_ms,
+            &now,
+            &now,
+        );
+        let locale_ids: Vec<Variant> = server_config
+            .locale_ids
+            .iter()
+            .map(|v| UAString::from(v).into())
+            .collect();
+        self.set_variable_value(
+            Server_ServerCapabilities_LocaleIdArray,
+            locale_ids,
+            &now,
+            &now,
+        );
+
+        let ol = &server_state.operational_limits;
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerRead,
+            ol.max_nodes_per_read as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerWrite,
+            ol.max_nodes_per_write as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerMethodCall,
+            ol.max_nodes_per_method_call as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerBrowse,
+            ol.max_nodes_per_browse as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerRegisterNodes,
+            ol.max_nodes_per_register_nodes as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerTranslateBrowsePathsToNodeIds,
+            ol.max_nodes_per_translate_browse_paths_to_node_ids as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerNodeManagement,
+            ol.max_nodes_per_node_management as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxMonitoredItemsPerCall,
+            ol.max_monitored_items_per_call as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryReadData,
+            ol.max_nodes_per_history_read_data as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryReadEvents,
+            ol.max_nodes_per_history_read_events as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryUpdateData,
+            ol.max_nodes_per_history_update_data as u32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerCapabilities_OperationLimits_MaxNodesPerHistoryUpdateEvents,
+            ol.max_nodes_per_history_update_events as u32,
+            &now,
+            &now,
+        );
+    }
+
+    /// Sets the server status variables for the server's state and any pending shutdown from
+    /// the supplied server state.
+    pub fn update_server_status(&mut self, server_state: &ServerState) {
+        let now = DateTime::now();
+        self.set_variable_value(
+            Server_ServerStatus_State,
+            server_state.state() as i32,
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_SecondsTillShutdown,
+            server_state.seconds_till_shutdown(),
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_ShutdownReason,
+            server_state.shutdown_reason().clone(),
+            &now,
+            &now,
+        );
+    }
+
     /// Sets the history server capabilities based on the supplied flags
     pub fn set_history_server_capabilities(&mut self, capabilities: &HistoryServerCapabilities) {
         let now = DateTime::now();
@@ -778,10 +964,11 @@ impl AddressSpace {
         T: Into<NodeType>,
         S: Into<NodeId> + Clone,
     {
-        let node_type = node.into();
+        let mut node_type = node.into();
         let node_id = node_type.node_id();
 
         self.assert_namespace(&node_id);
+        self.set_value_changes(&mut node_type);
 
         if self.node_exists(&node_id) {
             error!("This node {} already exists", node_id);
@@ -792,6 +979,24 @@ impl AddressSpace {
             if let Some(references) = references {
                 self.references.insert(&node_id, references);
             }
+            self.record_node_change(&node_id, ModelChangeStructureVerbMask::NodeAdded);
+            if let Some(references) = references {
+                references.iter().for_each(|r| {
+                    let target_node = r.0;
+                    match r.2 {
+                        ReferenceDirection::Forward => self.record_reference_change(
+                            &node_id,
+                            target_node,
+                            ModelChangeStructureVerbMask::ReferenceAdded,
+                        ),
+                        ReferenceDirection::Inverse => self.record_reference_change(
+                            target_node,
+                            &node_id,
+                            ModelChangeStructureVerbMask::ReferenceAdded,
+                        ),
+                    }
+                });
+            }
             self.update_last_modified();
             true
         }
@@ -817,6 +1022,9 @@ impl AddressSpace {
         T: Into<NodeId> + Clone,
     {
         self.references.insert_references(references);
+        references.iter().for_each(|r| {
+            self.record_reference_change(r.0, r.1, ModelChangeStructureVerbMask::ReferenceAdded)
+        });
         self.update_last_modified();
     }
 
@@ -831,6 +1039,11 @@ impl AddressSpace {
     {
         self.references
             .insert_reference(node_id, target_node_id, &reference_type_id);
+        self.record_reference_change(
+            node_id,
+            target_node_id,
+            ModelChangeStructureVerbMask::ReferenceAdded,
+        );
         self.update_last_modified();
     }
 
@@ -849,6 +1062,17 @@ impl AddressSpace {
         self.node_map.contains_key(node_id)
     }
 
+    /// Tests if the node exists either in the address space or in the namespace of a
+    /// node manager.
+    pub fn node_exists_or_managed(&self, node_id: &NodeId) -> bool {
+        if let Some(node_manager) = self.node_manager(node_id) {
+            let mut node_manager = trace_lock_unwrap!(node_manager);
+            node_manager.node_exists(node_id)
+        } else {
+            self.node_exists(node_id)
+        }
+    }
+
     /// Adds a folder with a specified id
     pub fn add_folder_with_id<R, S>(
         &mut self,
@@ -921,6 +1145,23 @@ impl AddressSpace {
                 let _ = self.delete(&node_id, delete_target_references);
             });
         }
+        // Changes are recorded while the node and its references still exist
+        if self.node_exists(node_id) {
+            self.record_node_change(node_id, ModelChangeStructureVerbMask::NodeDeleted);
+        }
+        if delete_target_references {
+            if let Some(references) =
+                self.find_inverse_references(node_id, None::<(ReferenceTypeId, bool)>)
+            {
+                references.iter().for_each(|r| {
+                    self.record_reference_change(
+                        &r.target_node,
+                        node_id,
+                        ModelChangeStructureVerbMask::ReferenceDeleted,
+                    )
+                });
+            }
+        }
         // Remove the node
         let removed_node = self.node_map.remove(&node_id);
         // Remove references
@@ -929,7 +1170,11 @@ impl AddressSpace {
         } else {
             false
         };
-        removed_node.is_some() || removed_target_references
+        let deleted = removed_node.is_some() || removed_target_references;
+        if deleted {
+            self.update_last_modified();
+        }
+        deleted
     }
 
     /// Finds the matching reference and deletes it
@@ -942,8 +1187,159 @@ impl AddressSpace {
     where
         T: Into<NodeId>,
     {
-        self.references
-            .delete_reference(node_id, target_node_id, reference_type_id)
+        let deleted = self
+            .references
+            .delete_reference(node_id, target_node_id, reference_type_id);
+        if deleted {
+            self.record_reference_change(
+                node_id,
+                target_node_id,
+                ModelChangeStructureVerbMask::ReferenceDeleted,
+            );
+            self.update_last_modified();
+        }
+        deleted
+    }
+
+    /// Takes a snapshot of every node in the supplied namespaces with its attributes, current
+    /// value and references. References between a node in the namespaces and a node outside of
+    /// them are part of the snapshot too, so that restored nodes are reattached to the rest of
+    /// the address space, e.g. to the folder that organizes them.
+    pub fn snapshot(&self, namespaces: &[u16]) -> AddressSpaceSnapshot {
+        let in_namespaces = |node_id: &NodeId| namespaces.contains(&node_id.namespace);
+        let nodes = self
+            .node_map
+            .iter()
+            .filter(|(node_id, _)| in_namespaces(node_id))
+            .map(|(_, node)| NodeSnapshot::from_node(node))
+            .collect();
+        let references = self
+            .references
+            .iter()
+            .filter(|(source_node, reference)| {
+                in_namespaces(source_node) || in_namespaces(&reference.target_node)
+            })
+            .map(|(source_node, reference)| ReferenceSnapshot {
+                source_node: source_node.clone(),
+                reference_type: reference.reference_type.clone(),
+                target_node: reference.target_node.clone(),
+            })
+            .collect();
+        AddressSpaceSnapshot {
+            namespaces: self
+                .namespaces
+                .iter()
+                .map(|ns| UAString::from(ns))
+                .collect(),
+            nodes,
+            references,
+        }
+    }
+
+    /// Restores the nodes and references held by a snapshot and returns the number of nodes
+    /// restored. Namespaces in the snapshot that are not registered yet are registered and the
+    /// snapshot is mapped onto the namespace indexes of this address space.
+    ///
+    /// A node in the snapshot replaces any node with the same id that is already in the address
+    /// space, along with any value getter or setter that node had. Nothing is restored if any node
+    /// in the snapshot cannot be recreated. Nodes and references that the address space did not
+    /// have are recorded as model changes.
+    pub fn restore_snapshot(
+        &mut self,
+        mut snapshot: AddressSpaceSnapshot,
+    ) -> Result<usize, StatusCode> {
+        let default_namespace = self.default_namespace;
+        let namespace_map = snapshot
+            .namespaces
+            .iter()
+            .map(|namespace| {
+                self.register_namespace(namespace.as_ref()).map_err(|_| {
+                    error!("Snapshot namespace {} cannot be registered", namespace);
+                    StatusCode::BadDecodingError
+                })
+            })
+            .collect::<Result<Vec<u16>, StatusCode>>();
+        self.default_namespace = default_namespace;
+        snapshot.remap_namespaces(&namespace_map?);
+
+        let nodes = snapshot
+            .nodes
+            .into_iter()
+            .map(|node| node.into_node_type())
+            .collect::<Result<Vec<NodeType>, StatusCode>>()?;
+        let node_count = nodes.len();
+        let mut added_nodes = Vec::with_capacity(node_count);
+        nodes.into_iter().for_each(|mut node| {
+            let node_id = node.node_id();
+            self.set_value_changes(&mut node);
+            // Nodes created with a generated id must not clash with ones generated from now on
+            if let Identifier::Numeric(numeric_id) = node_id.identifier {
+                if node_id.namespace != 0 {
+                    NodeId::reserve_numeric(numeric_id);
+                }
+            }
+            if self.node_map.insert(node_id.clone(), node).is_some() {
+                debug!("Node {} has been replaced by the snapshot", node_id);
+                let mut value_changes = trace_lock_unwrap!(self.value_changes);
+                value_changes.value_changed(&node_id);
+            } else {
+                added_nodes.push(node_id);
+            }
+        });
+        let added_references = snapshot
+            .references
+            .into_iter()
+            .filter(|r| {
+                r.source_node != r.target_node
+                    && !self.has_reference(&r.source_node, &r.target_node, r.reference_type.clone())
+            })
+            .collect::<Vec<_>>();
+        added_references.iter().for_each(|r| {
+            self.references
+                .insert_reference(&r.source_node, &r.target_node, &r.reference_type)
+        });
+        // Changes are recorded once every reference is in place so the type of each node is known
+        added_nodes.iter().for_each(|node_id| {
+            self.record_node_change(node_id, ModelChangeStructureVerbMask::NodeAdded)
+        });
+        added_references.iter().for_each(|r| {
+            self.record_reference_change(
+                &r.source_node,
+                &r.target_node,
+                ModelChangeStructureVerbMask::ReferenceAdded,
+            )
+        });
+        self.update_last_modified();
+        Ok(node_count)
+    }
+
+    /// Saves a snapshot of the supplied namespaces to a file and returns the number of nodes
+    /// saved. The snapshot is written to a temporary file next to the path which then replaces
+    /// the file, so a failed save never leaves a partially written snapshot behind.
+    pub fn save_snapshot_file(&self, path: &Path, namespaces: &[u16]) -> Result<usize, StatusCode> {
+        let snapshot = self.snapshot(namespaces);
+        util::save_encoded_file(&snapshot, path, "Address space snapshot")?;
+        Ok(snapshot.node_count())
+    }
+
+    /// Loads a snapshot file saved by `save_snapshot_file()` and restores it into the address
+    /// space. The decoding limits apply to the values in the snapshot, not the number of nodes.
+    pub fn load_snapshot_file(
+        &mut self,
+        path: &Path,
+        decoding_limits: &DecodingLimits,
+    ) -> Result<usize, StatusCode> {
+        let file = File::open(path).map_err(|err| {
+            error!(
+                "Address space snapshot {} cannot be opened, error = {}",
+                path.display(),
+                err
+            );
+            StatusCode::BadUnexpectedError
+        })?;
+        let mut stream = BufReader::new(file);
+        let snapshot = AddressSpaceSnapshot::decode(&mut stream, decoding_limits)?;
+        self.restore_snapshot(snapshot)
     }
 
     /// Find node by something that can be turned into a node id and return a reference to it.
@@ -962,6 +1358,11 @@ impl AddressSpace {
         self.find_node_mut(&node_id.into())
     }
 
+    /// Returns an iterator over every node in the address space
+    pub(crate) fn nodes(&self) -> impl Iterator<Item = &NodeType> {
+        self.node_map.values()
+    }
+
     /// Finds a node by its node id and returns a reference to it.
     pub fn find_node(&self, node_id: &NodeId) -> Option<&NodeType> {
         self.node_map.get(node_id)
@@ -1030,7 +1431,7 @@ impl AddressSpace {
     where
         V: Into<Variant>,
     {
-        if let Some(ref mut variable) = self.find_variable_mut_by_ref(node_id) {
+        let found = if let Some(ref mut variable) = self.find_variable_mut_by_ref(node_id) {
             let _ = variable.set_value_direct(
                 value,
                 StatusCode::Good,
@@ -1040,7 +1441,11 @@ impl AddressSpace {
             true
         } else {
             false
+        };
+        if found {
+            self.record_semantic_change(node_id);
         }
+        found
     }
 
     /// Gets a variable value with the supplied NodeId. The function will return Err if the
@@ -1078,6 +1483,22 @@ impl AddressSpace {
         }
     }
 
+    /// Registers an asynchronous method callback on the specified method id
+    pub fn register_async_method_handler<N>(&mut self, method_id: N, handler: AsyncMethodCallback)
+    where
+        N: Into<NodeId>,
+    {
+        let method_id = method_id.into();
+        if let Some(method) = self.find_mut(&method_id) {
+            match method {
+                NodeType::Method(method) => method.set_async_callback(handler),
+                _ => panic!("{} is not a method node", method_id),
+            }
+        } else {
+            panic!("{} method id does not exist", method_id);
+        }
+    }
+
     /// Test if the type definition is defined and valid for a class of the specified type.
     /// i.e. if we have a Variable or Object class that the type is a VariableType or ObjectType
     /// respectively.
@@ -1153,14 +1574,38 @@ impl AddressSpace {
     ///
     /// Calls require a registered handler to handle the method. If there is no handler, or if
     /// the request refers to a non existent object / method, the function will return an error.
+    /// A method that only has an asynchronous handler returns `BadNotSupported` since its result
+    /// must be obtained with `async_call_method`.
     pub fn call_method(
         &mut self,
         _server_state: &ServerState,
         session: &mut Session,
         request: &CallMethodRequest,
     ) -> Result<CallMethodResult, StatusCode> {
+        self.find_method_to_call(request)?.call(session, request)
+    }
+
+    /// Calls a method node with the supplied request, returning a future for the result. A method
+    /// with an asynchronous handler is not waited for. Errors are the same as for `call_method`.
+    pub fn async_call_method(
+        &mut self,
+        _server_state: &ServerState,
+        session: &mut Session,
+        request: &CallMethodRequest,
+    ) -> AsyncResult<CallMethodResult> {
+        match self.find_method_to_call(request) {
+            Ok(method) => method.async_call(session, request),
+            Err(status_code) => Box::new(future::err(status_code)),
+        }
+    }
+
+    /// Finds the method node that the request calls, checking that the object and method exist
+    /// and that the method belongs to the object.
+    fn find_method_to_call(
+        &mut self,
+        request: &CallMethodRequest,
+    ) -> Result<&mut Method, StatusCode> {
         let (object_id, method_id) = (&request.object_id, &request.method_id);
-        // Handle the call
         if !is_object!(self, object_id) {
             error!(
                 "Method call to {:?} on {:?} but the node id is not recognized!",
@@ -1179,14 +1624,12 @@ impl AddressSpace {
                 method_id, object_id
             );
             Err(StatusCode::BadMethodInvalid)
-        } else if let Some(method) = self.find_mut(method_id) {
+        } else {
             // TODO check security - session / user may not have permission to call methods
-            match method {
-                NodeType::Method(method) => method.call(session, request),
+            match self.find_mut(method_id) {
+                Some(NodeType::Method(method)) => Ok(&mut **method),
                 _ => Err(StatusCode::BadMethodInvalid),
             }
-        } else {
-            Err(StatusCode::BadMethodInvalid)
         }
     }
 
@@ -1208,6 +1651,65 @@ impl AddressSpace {
             }
         }
     }
+    /// Returns the base type of a type, i.e. the source of its inverse `HasSubtype` reference
+    fn base_type(&self, type_id: &NodeId) -> Option<NodeId> {
+        self.find_inverse_references(type_id, Some((ReferenceTypeId::HasSubtype, false)))
+            .and_then(|references| references.first().map(|r| r.target_node.clone()))
+    }
+
+    /// Returns the id of the `Default Binary` encoding of a data type, found through its
+    /// `HasEncoding` references
+    pub(crate) fn default_binary_encoding(&self, data_type_id: &NodeId) -> Option<NodeId> {
+        self.find_references(data_type_id, Some((ReferenceTypeId::HasEncoding, false)))?
+            .iter()
+            .find(|r| {
+                self.find_node(&r.target_node)
+                    .map(|n| n.as_node().browse_name() == QualifiedName::new(0, "Default Binary"))
+                    .unwrap_or(false)
+            })
+            .map(|r| r.target_node.clone())
+    }
+
+    /// Creates a registry of the data types of the address space which have a
+    /// `DataTypeDefinition`, so that values of those types can be encoded and decoded as
+    /// `DynamicStructure`s. Data types without a definition that derive from a type other than
+    /// `Structure` are added as subtypes, e.g. a custom string type.
+    pub fn data_type_registry(&self) -> DataTypeRegistry {
+        let mut registry = DataTypeRegistry::new();
+        let structure_id: NodeId = DataTypeId::Structure.into();
+        for node in self.nodes() {
+            if let NodeType::DataType(data_type) = node {
+                let data_type_id = node.node_id();
+                if let Some(definition) = data_type.data_type_definition() {
+                    let mut definition = definition.clone();
+                    if let DataTypeDefinition::Structure(ref mut definition) = definition {
+                        if definition.default_encoding_id.is_null() {
+                            if let Some(encoding_id) = self.default_binary_encoding(&data_type_id) {
+                                definition.default_encoding_id = encoding_id;
+                            }
+                        }
+                    }
+                    registry.add_definition(&data_type_id, definition);
+                } else if let Some(base_type_id) = self.base_type(&data_type_id) {
+                    // Structures without a definition cannot be encoded field by field
+                    let mut type_id = Some(base_type_id.clone());
+                    let mut is_structure = false;
+                    while let Some(id) = type_id {
+                        if id == structure_id {
+                            is_structure = true;
+                            break;
+                        }
+                        type_id = self.base_type(&id);
+                    }
+                    if !is_structure {
+                        registry.add_subtype(&data_type_id, &base_type_id);
+                    }
+                }
+            }
+        }
+        registry
+    }
+
     /// Finds objects by a specified type.
     fn find_nodes_by_type<T>(
         &self,
@@ -1366,6 +1868,266 @@ impl AddressSpace {
         self.last_modified = Utc::now();
     }
 
+    /// Turns the recording of model and semantic changes on or off. While it is on, each node or
+    /// reference that is added or deleted, and each property value that changes the meaning of its
+    /// node, is held until `raise_model_change_events()` raises them as events. The server turns
+    /// this on when it starts running so the nodes it starts with are not reported.
+    pub fn set_model_change_events(&mut self, model_change_events: bool) {
+        self.model_change_events = model_change_events;
+        if !model_change_events {
+            self.model_changes.clear();
+            self.semantic_changes.clear();
+            self.attribute_changes = false;
+        }
+    }
+
+    /// Tests if model and semantic changes are being recorded
+    pub fn model_change_events(&self) -> bool {
+        self.model_change_events
+    }
+
+    /// Raises the model and semantic changes recorded since the last call. Model changes are
+    /// batched into `GeneralModelChangeEventType` events, one from each affected node that has a
+    /// `NodeVersion` property and one from the Server object for the rest. Semantic changes are
+    /// batched into a `SemanticChangeEventType` event from the Server object. Changes to
+    /// attributes other than Value are raised as one `BaseModelChangeEventType` event from the
+    /// Server object.
+    pub fn raise_model_change_events(&mut self) {
+        let model_changes = mem::replace(&mut self.model_changes, Vec::new());
+        let semantic_changes = mem::replace(&mut self.semantic_changes, Vec::new());
+        let attribute_changes = mem::replace(&mut self.attribute_changes, false);
+        let now = DateTime::now();
+
+        if attribute_changes {
+            let event_id = NodeId::next_numeric(self.internal_namespace);
+            let mut event = BaseModelChangeEventType::new(event_id, now.clone());
+            if event.raise(self).is_err() {
+                error!("Cannot raise a model change event");
+            }
+        }
+
+        let mut model_changes_by_source: Vec<(NodeId, Vec<ModelChangeStructureDataType>)> =
+            Vec::new();
+        model_changes.into_iter().for_each(|change| {
+            let source_node = if self.node_version_id(&change.affected).is_some() {
+                change.affected.clone()
+            } else {
+                ObjectId::Server.into()
+            };
+            if let Some((_, changes)) = model_changes_by_source
+                .iter_mut()
+                .find(|(node_id, _)| *node_id == source_node)
+            {
+                changes.push(change);
+            } else {
+                model_changes_by_source.push((source_node, vec![change]));
+            }
+        });
+        model_changes_by_source
+            .into_iter()
+            .for_each(|(source_node, changes)| {
+                let source_name = self
+                    .find_node(&source_node)
+                    .map(|node| node.as_node().browse_name().name)
+                    .unwrap_or_else(UAString::null);
+                let event_id = NodeId::next_numeric(self.internal_namespace);
+                let mut event = GeneralModelChangeEventType::new(event_id, now.clone(), changes)
+                    .source_node(source_node)
+                    .source_name(source_name);
+                if event.raise(self).is_err() {
+                    error!("Cannot raise a general model change event");
+                }
+            });
+
+        if !semantic_changes.is_empty() {
+            let event_id = NodeId::next_numeric(self.internal_namespace);
+            let mut event = SemanticChangeEventType::new(event_id, now, semantic_changes);
+            if event.raise(self).is_err() {
+                error!("Cannot raise a semantic change event");
+            }
+        }
+    }
+
+    /// Adds a `NodeVersion` property to the node. The version is changed each time a reference
+    /// is added to or deleted from the node, so a client can tell when what it browsed has
+    /// changed. Model change events about the node are raised from the node itself. Returns false
+    /// if the node does not exist or already has a `NodeVersion`.
+    pub fn add_node_version(&mut self, node_id: &NodeId) -> bool {
+        if !self.node_exists(node_id) || self.node_version_id(node_id).is_some() {
+            false
+        } else {
+            let property_id = NodeId::next_numeric(self.internal_namespace);
+            VariableBuilder::new(&property_id, "NodeVersion", "NodeVersion")
+                .property_of(node_id.clone())
+                .has_type_definition(VariableTypeId::PropertyType)
+                .data_type(DataTypeId::String)
+                .value(UAString::from("0"))
+                .insert(self)
+        }
+    }
+
+    /// Finds the `NodeVersion` property of the node
+    fn node_version_id(&self, node_id: &NodeId) -> Option<NodeId> {
+        let node_version = QualifiedName::new(0, "NodeVersion");
+        self.find_references(node_id, Some((ReferenceTypeId::HasProperty, false)))?
+            .into_iter()
+            .map(|r| r.target_node)
+            .find(|property_id| {
+                self.find_node(property_id)
+                    .map_or(false, |node| node.as_node().browse_name() == node_version)
+            })
+    }
+
+    /// Changes the `NodeVersion` of the node if it has one
+    fn increment_node_version(&mut self, node_id: &NodeId) {
+        if let Some(property_id) = self.node_version_id(node_id) {
+            if let Some(variable) = self.find_variable_mut_by_ref(&property_id) {
+                let version = match variable
+                    .value(
+                        TimestampsToReturn::Neither,
+                        NumericRange::None,
+                        &QualifiedName::null(),
+                        0.0,
+                    )
+                    .value
+                {
+                    Some(Variant::String(version)) => version.as_ref().parse::<u64>().unwrap_or(0),
+                    _ => 0,
+                };
+                let _ = variable.set_value(
+                    NumericRange::None,
+                    UAString::from((version + 1).to_string()),
+                );
+            }
+        }
+    }
+
+    /// Records that a node was added or deleted
+    fn record_node_change(&mut self, node_id: &NodeId, verb: ModelChangeStructureVerbMask) {
+        if self.model_change_events && !self.is_event(node_id) {
+            self.add_model_change(node_id, verb);
+        }
+    }
+
+    /// Records that a reference was added or deleted. The change affects the source node and the
+    /// `NodeVersion` of both nodes.
+    fn record_reference_change(
+        &mut self,
+        source_node: &NodeId,
+        target_node: &NodeId,
+        verb: ModelChangeStructureVerbMask,
+    ) {
+        self.increment_node_version(source_node);
+        self.increment_node_version(target_node);
+        if self.model_change_events && !self.is_event(source_node) && !self.is_event(target_node) {
+            self.add_model_change(source_node, verb);
+        }
+    }
+
+    /// Adds the change, combining its verb with any earlier change to the same node
+    fn add_model_change(&mut self, affected: &NodeId, verb: ModelChangeStructureVerbMask) {
+        let verb = verb as u8;
+        if let Some(change) = self
+            .model_changes
+            .iter_mut()
+            .find(|change| change.affected == *affected)
+        {
+            change.verb |= verb;
+        } else {
+            let affected_type = self
+                .references
+                .get_type_id(affected)
+                .unwrap_or_else(NodeId::null);
+            self.model_changes.push(ModelChangeStructureDataType {
+                affected: affected.clone(),
+                affected_type,
+                verb,
+            });
+        }
+    }
+
+    /// Records that an attribute other than Value was written, e.g. a DisplayName
+    pub(crate) fn record_attribute_change(&mut self) {
+        if self.model_change_events {
+            self.attribute_changes = true;
+        }
+    }
+
+    /// Records a semantic change to the node that owns the property, if the property has the
+    /// `SEMANTIC_CHANGE` access level or is one of the properties, e.g. EURange, whose value is
+    /// understood to change the meaning of its node.
+    pub(crate) fn record_semantic_change(&mut self, property_id: &NodeId) {
+        if !self.model_change_events {
+            return;
+        }
+        let is_semantic = if let Some(variable) = self.find_variable_by_ref(property_id) {
+            variable
+                .access_level()
+                .contains(AccessLevel::SEMANTIC_CHANGE)
+                || SEMANTIC_PROPERTIES.contains(&variable.browse_name().name.as_ref())
+        } else {
+            false
+        };
+        if !is_semantic {
+            return;
+        }
+        if let Some(references) =
+            self.find_inverse_references(property_id, Some((ReferenceTypeId::HasProperty, false)))
+        {
+            references.into_iter().for_each(|r| {
+                let affected = r.target_node;
+                if !self
+                    .semantic_changes
+                    .iter()
+                    .any(|change| change.affected == affected)
+                {
+                    let affected_type = self
+                        .references
+                        .get_type_id(&affected)
+                        .unwrap_or_else(NodeId::null);
+                    self.semantic_changes.push(SemanticChangeStructureDataType {
+                        affected,
+                        affected_type,
+                    });
+                }
+            });
+        }
+    }
+
+    /// Tests if the node is an event or a property of an event. Raising and purging events adds
+    /// and deletes nodes but these are not changes to the model.
+    fn is_event(&self, node_id: &NodeId) -> bool {
+        let is_event_object = |node_id: &NodeId| {
+            self.references
+                .get_type_id(node_id)
+                .map_or(false, |type_id| self.is_event_type(&type_id))
+        };
+        is_event_object(node_id)
+            || self
+                .find_inverse_references(node_id, Some((ReferenceTypeId::HasProperty, false)))
+                .map_or(false, |references| {
+                    references.iter().any(|r| is_event_object(&r.target_node))
+                })
+    }
+
+    /// Tests if the type is BaseEventType or one of its subtypes by walking up the type hierarchy
+    fn is_event_type(&self, type_id: &NodeId) -> bool {
+        let base_event_type_id: NodeId = ObjectTypeId::BaseEventType.into();
+        let mut type_id = type_id.clone();
+        loop {
+            if type_id == base_event_type_id {
+                return true;
+            }
+            match self
+                .find_inverse_references(&type_id, Some((ReferenceTypeId::HasSubtype, false)))
+                .and_then(|references| references.into_iter().next())
+            {
+                Some(reference) => type_id = reference.target_node,
+                None => return false,
+            }
+        }
+    }
+
     /// Sets the getter for a variable node
     fn set_variable_getter<N, F>(&mut self, variable_id: N, getter: F)
     where
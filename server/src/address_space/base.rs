@@ -17,8 +17,12 @@ pub(crate) struct Base {
     browse_name: QualifiedName,
     /// The human readable display name
     display_name: LocalizedText,
+    /// Translations of the display name into other locales
+    display_name_translations: Vec<LocalizedText>,
     /// The description of the node (optional)
     description: Option<LocalizedText>,
+    /// Translations of the description into other locales
+    description_translations: Vec<LocalizedText>,
     /// Write mask bits (optional)
     write_mask: Option<u32>,
     /// User write mask bits (optional)
@@ -46,6 +50,22 @@ impl NodeBase for Base {
         self.display_name = display_name.into();
     }
 
+    fn add_display_name_translation(&mut self, display_name: LocalizedText) {
+        add_translation(&mut self.display_name_translations, display_name);
+    }
+
+    fn display_name_for_locales(&self, locale_ids: &[UAString]) -> LocalizedText {
+        best_translation(
+            &self.display_name,
+            &self.display_name_translations,
+            locale_ids,
+        )
+    }
+
+    fn display_name_translations(&self) -> Vec<LocalizedText> {
+        self.display_name_translations.clone()
+    }
+
     fn description(&self) -> Option<LocalizedText> {
         self.description.clone()
     }
@@ -54,6 +74,24 @@ impl NodeBase for Base {
         self.description = Some(description.into())
     }
 
+    fn add_description_translation(&mut self, description: LocalizedText) {
+        add_translation(&mut self.description_translations, description);
+    }
+
+    fn description_for_locales(&self, locale_ids: &[UAString]) -> Option<LocalizedText> {
+        // Translations without a default description still make a description
+        self.description
+            .as_ref()
+            .or_else(|| self.description_translations.first())
+            .map(|description| {
+                best_translation(description, &self.description_translations, locale_ids)
+            })
+    }
+
+    fn description_translations(&self) -> Vec<LocalizedText> {
+        self.description_translations.clone()
+    }
+
     fn write_mask(&self) -> Option<WriteMask> {
         self.write_mask
             .map(|write_mask| WriteMask::from_bits_truncate(write_mask))
@@ -140,7 +178,9 @@ impl Node for Base {
             }
             AttributeId::DisplayName => {
                 if let Variant::LocalizedText(v) = value {
-                    self.display_name = *v;
+                    if !replace_translation(&mut self.display_name_translations, &v) {
+                        self.display_name = *v;
+                    }
                     Ok(())
                 } else {
                     Err(StatusCode::BadTypeMismatch)
@@ -148,7 +188,9 @@ impl Node for Base {
             }
             AttributeId::Description => {
                 if let Variant::LocalizedText(v) = value {
-                    self.description = Some(*v);
+                    if !replace_translation(&mut self.description_translations, &v) {
+                        self.description = Some(*v);
+                    }
                     Ok(())
                 } else {
                     Err(StatusCode::BadTypeMismatch)
@@ -191,7 +233,9 @@ impl Base {
             node_class,
             browse_name: browse_name.into(),
             display_name: display_name.into(),
+            display_name_translations: Vec::new(),
             description: None,
+            description_translations: Vec::new(),
             write_mask: None,
             user_write_mask: None,
         }
@@ -213,3 +257,77 @@ impl Base {
         self.browse_name = browse_name.into();
     }
 }
+
+/// Adds the text to the translations, replacing any existing translation for the same locale
+pub(crate) fn add_translation(translations: &mut Vec<LocalizedText>, text: LocalizedText) {
+    if !replace_translation(translations, &text) {
+        translations.push(text);
+    }
+}
+
+/// Returns the translation of a `LocalizedText` value which best matches the locale ids, in order
+/// of preference. Other values are returned unchanged.
+pub(crate) fn localize_value(
+    value: Variant,
+    translations: &[LocalizedText],
+    locale_ids: &[UAString],
+) -> Variant {
+    match value {
+        Variant::LocalizedText(ref text) if !translations.is_empty() => {
+            best_translation(text, translations, locale_ids).into()
+        }
+        value => value,
+    }
+}
+
+/// Replaces the translation with the same locale as the text and returns true, or returns false
+/// if there is no translation for that locale.
+fn replace_translation(translations: &mut [LocalizedText], text: &LocalizedText) -> bool {
+    if text.locale.is_null() {
+        return false;
+    }
+    if let Some(translation) = translations
+        .iter_mut()
+        .find(|t| t.locale.as_ref().eq_ignore_ascii_case(text.locale.as_ref()))
+    {
+        *translation = text.clone();
+        true
+    } else {
+        false
+    }
+}
+
+/// Returns the text that best matches the locale ids, which are in order of preference. Each locale
+/// id is tried in turn against the default text and its translations, first for an exact match,
+/// e.g. "de-CH" with "de-CH", and then for the same language, e.g. "de-CH" with "de" or "de-DE".
+/// The default text is returned if nothing matches.
+pub(crate) fn best_translation(
+    default: &LocalizedText,
+    translations: &[LocalizedText],
+    locale_ids: &[UAString],
+) -> LocalizedText {
+    if translations.is_empty() {
+        return default.clone();
+    }
+    let candidates = || std::iter::once(default).chain(translations.iter());
+    locale_ids
+        .iter()
+        .map(|locale_id| locale_id.as_ref())
+        .filter(|locale_id| !locale_id.is_empty())
+        .find_map(|locale_id| {
+            candidates()
+                .find(|t| t.locale.as_ref().eq_ignore_ascii_case(locale_id))
+                .or_else(|| {
+                    let language = language_of(locale_id);
+                    candidates()
+                        .find(|t| language_of(t.locale.as_ref()).eq_ignore_ascii_case(language))
+                })
+        })
+        .unwrap_or(default)
+        .clone()
+}
+
+/// Returns the language part of a locale id, e.g. "de" from "de-CH"
+fn language_of(locale_id: &str) -> &str {
+    locale_id.split('-').next().unwrap_or(locale_id)
+}
@@ -10,11 +10,30 @@ use crate::address_space::{base::Base, node::Node, node::NodeBase};
 
 node_builder_impl!(DataTypeBuilder, DataType);
 
+node_builder_impl_subtype!(DataTypeBuilder);
+
+impl DataTypeBuilder {
+    pub fn is_abstract(mut self, is_abstract: bool) -> Self {
+        self.node.set_is_abstract(is_abstract);
+        self
+    }
+
+    pub fn data_type_definition<V>(mut self, data_type_definition: V) -> Self
+    where
+        V: Into<DataTypeDefinition>,
+    {
+        self.node
+            .set_data_type_definition(Some(data_type_definition.into()));
+        self
+    }
+}
+
 /// A `DataType` is a type of node within the `AddressSpace`.
 #[derive(Debug)]
 pub struct DataType {
     base: Base,
     is_abstract: bool,
+    data_type_definition: Option<DataTypeDefinition>,
 }
 
 impl Default for DataType {
@@ -22,6 +41,7 @@ impl Default for DataType {
         Self {
             base: Base::new(NodeClass::DataType, &NodeId::null(), "", ""),
             is_abstract: false,
+            data_type_definition: None,
         }
     }
 }
@@ -39,6 +59,9 @@ impl Node for DataType {
     ) -> Option<DataValue> {
         match attribute_id {
             AttributeId::IsAbstract => Some(self.is_abstract().into()),
+            AttributeId::DataTypeDefinition => {
+                self.data_type_definition().map(|v| Variant::from(v).into())
+            }
             _ => self.base.get_attribute_max_age(
                 timestamps_to_return,
                 attribute_id,
@@ -63,6 +86,17 @@ impl Node for DataType {
                     Err(StatusCode::BadTypeMismatch)
                 }
             }
+            AttributeId::DataTypeDefinition => {
+                if let Variant::ExtensionObject(v) = value {
+                    let data_type_definition =
+                        DataTypeDefinition::from_extension_object(&v, &DecodingLimits::default())
+                            .map_err(|_| StatusCode::BadTypeMismatch)?;
+                    self.set_data_type_definition(Some(data_type_definition));
+                    Ok(())
+                } else {
+                    Err(StatusCode::BadTypeMismatch)
+                }
+            }
             _ => self.base.set_attribute(attribute_id, value),
         }
     }
@@ -82,6 +116,7 @@ impl DataType {
         DataType {
             base: Base::new(NodeClass::DataType, node_id, browse_name, display_name),
             is_abstract,
+            data_type_definition: None,
         }
     }
 
@@ -128,4 +163,13 @@ impl DataType {
     pub fn set_is_abstract(&mut self, is_abstract: bool) {
         self.is_abstract = is_abstract;
     }
+
+    /// Returns the definition of a structure or enumeration data type, if it has one
+    pub fn data_type_definition(&self) -> Option<&DataTypeDefinition> {
+        self.data_type_definition.as_ref()
+    }
+
+    pub fn set_data_type_definition(&mut self, data_type_definition: Option<DataTypeDefinition>) {
+        self.data_type_definition = data_type_definition;
+    }
 }
@@ -1,7 +1,7 @@
 // OPCUA for Rust
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
-// This file was autogenerated from Opc.Ua.NodeSet2.Part14.xml by tools/schema/gen_address_space.js
+// This file was autogenerated from Opc.Ua.NodeSet2.Part14.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![rustfmt::skip]
 
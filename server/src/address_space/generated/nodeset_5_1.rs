@@ -1,7 +1,7 @@
 // OPCUA for Rust
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
-// This file was autogenerated from Opc.Ua.NodeSet2.Part5.xml by tools/schema/gen_address_space.js
+// This file was autogenerated from Opc.Ua.NodeSet2.Part5.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![rustfmt::skip]
 
@@ -567,7 +567,7 @@ fn add_object_35(address_space: &mut AddressSpace) {
     // Object
     let name = "Server";
     let node_id = NodeId::new(0, 2253);
-    let node = Object::new(&node_id, name, name, EventNotifier::empty());
+    let node = Object::new(&node_id, name, name, EventNotifier::from_bits_truncate(1));
     let _ = address_space.insert(node, Some(&[
         (&NodeId::new(0, 2254), &ReferenceTypeId::HasProperty, ReferenceDirection::Forward),
         (&NodeId::new(0, 2255), &ReferenceTypeId::HasProperty, ReferenceDirection::Forward),
@@ -4,15 +4,18 @@
 
 //! Contains the implementation of `Method` and `MethodBuilder`.
 
+use futures::future;
+
 use opcua_types::service_types::{Argument, MethodAttributes};
 
 use crate::{
     address_space::{
-        address_space::MethodCallback,
+        address_space::{AsyncMethodCallback, MethodCallback},
         base::Base,
         node::{Node, NodeBase},
         variable::VariableBuilder,
     },
+    callbacks::AsyncResult,
     session::Session,
 };
 
@@ -40,6 +43,11 @@ impl MethodBuilder {
         self
     }
 
+    pub fn async_callback(mut self, callback: AsyncMethodCallback) -> Self {
+        self.node.set_async_callback(callback);
+        self
+    }
+
     fn args_to_variant(arguments: &[Argument]) -> Vec<Variant> {
         arguments
             .iter()
@@ -81,6 +89,8 @@ pub struct Method {
     user_executable: bool,
     #[derivative(Debug = "ignore")]
     callback: Option<MethodCallback>,
+    #[derivative(Debug = "ignore")]
+    async_callback: Option<AsyncMethodCallback>,
 }
 
 impl Default for Method {
@@ -90,6 +100,7 @@ impl Default for Method {
             executable: false,
             user_executable: false,
             callback: None,
+            async_callback: None,
         }
     }
 }
@@ -162,6 +173,7 @@ impl Method {
             executable,
             user_executable,
             callback: None,
+            async_callback: None,
         }
     }
 
@@ -228,8 +240,14 @@ impl Method {
         self.callback = Some(callback);
     }
 
+    /// Sets a callback which handles calls to the method asynchronously. The Call service waits
+    /// for the result without blocking other requests.
+    pub fn set_async_callback(&mut self, callback: AsyncMethodCallback) {
+        self.async_callback = Some(callback);
+    }
+
     pub fn has_callback(&self) -> bool {
-        self.callback.is_some()
+        self.callback.is_some() || self.async_callback.is_some()
     }
 
     pub fn call(
@@ -240,6 +258,14 @@ impl Method {
         if let Some(ref mut callback) = self.callback {
             // Call the handler
             callback.call(session, request)
+        } else if self.async_callback.is_some() {
+            // Waiting for the result here would block the caller so the method must be called
+            // with `async_call()` instead
+            error!(
+                "Method call to {} only has an asynchronous handler",
+                self.node_id()
+            );
+            Err(StatusCode::BadNotSupported)
         } else {
             error!(
                 "Method call to {} has no handler, treating as invalid",
@@ -248,4 +274,18 @@ impl Method {
             Err(StatusCode::BadMethodInvalid)
         }
     }
+
+    /// Calls the method, returning a future for the result. A method with a synchronous callback
+    /// is called immediately and its result is returned as a completed future.
+    pub(crate) fn async_call(
+        &mut self,
+        session: &mut Session,
+        request: &CallMethodRequest,
+    ) -> AsyncResult<CallMethodResult> {
+        if let Some(ref mut callback) = self.async_callback {
+            callback.call(session, request)
+        } else {
+            Box::new(future::result(self.call(session, request)))
+        }
+    }
 }
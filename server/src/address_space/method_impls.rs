@@ -134,3 +134,59 @@ impl Method for ServerGetMonitoredItemsMethod {
         }
     }
 }
+
+/// This is the handler for the Server.SetSubscriptionDurable method call.
+pub struct ServerSetSubscriptionDurableMethod;
+
+impl Method for ServerSetSubscriptionDurableMethod {
+    fn call(
+        &mut self,
+        session: &mut Session,
+        request: &CallMethodRequest,
+    ) -> Result<CallMethodResult, StatusCode> {
+        debug!("Method handler for SetSubscriptionDurable");
+
+        // OPC UA part 5 - SetSubscriptionDurable([in] UInt32 subscriptionId, [in] UInt32 lifetimeInHours, [out] UInt32 revisedLifetimeInHours);
+        //
+        // subscriptionId - Identifier of the subscription
+        // lifetimeInHours - The requested lifetime in hours for the durable subscription
+        // revisedLifetimeInHours - The revised lifetime in hours the server applied to the durable subscription
+        //
+        // Return codes
+        //
+        // BadSubscriptionIdInvalid
+        // BadInvalidState - the subscription already contains monitored items
+        // BadUserAccessDenied
+        // BadNotSupported - the server does not support durable subscriptions
+
+        ensure_input_argument_count(request, 2)?;
+
+        let subscription_id = get_input_argument!(request, 0, UInt32)?;
+        let lifetime_in_hours = get_input_argument!(request, 1, UInt32)?;
+
+        let max_lifetime_in_hours = session.max_subscription_lifetime_in_hours();
+        if max_lifetime_in_hours == 0 {
+            return Err(StatusCode::BadNotSupported);
+        }
+
+        if let Some(subscription) = session.subscriptions_mut().get_mut(*subscription_id) {
+            let revised_lifetime_in_hours = if *lifetime_in_hours == 0 {
+                1
+            } else if *lifetime_in_hours > max_lifetime_in_hours {
+                max_lifetime_in_hours
+            } else {
+                *lifetime_in_hours
+            };
+            subscription.set_durable(revised_lifetime_in_hours)?;
+            Ok(CallMethodResult {
+                status_code: StatusCode::Good,
+                input_argument_results: Some(vec![StatusCode::Good, StatusCode::Good]),
+                input_argument_diagnostic_infos: None,
+                output_arguments: Some(vec![Variant::UInt32(revised_lifetime_in_hours)]),
+            })
+        } else {
+            // Subscription id does not exist
+            Err(StatusCode::BadSubscriptionIdInvalid)
+        }
+    }
+}
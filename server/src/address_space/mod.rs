@@ -193,6 +193,26 @@ macro_rules! node_builder_impl {
                 self
             }
 
+            /// Adds a translation of the display name in another locale. The translation that best
+            /// matches a session's locale ids is returned to its client.
+            pub fn display_name_translation<V>(mut self, display_name: V) -> Self
+            where
+                V: Into<LocalizedText>,
+            {
+                self.node.add_display_name_translation(display_name.into());
+                self
+            }
+
+            /// Adds a translation of the description in another locale. The translation that best
+            /// matches a session's locale ids is returned to its client.
+            pub fn description_translation<V>(mut self, description: V) -> Self
+            where
+                V: Into<LocalizedText>,
+            {
+                self.node.add_description_translation(description.into());
+                self
+            }
+
             /// Adds a reference to the node
             pub fn reference<T>(
                 mut self,
@@ -411,6 +431,18 @@ macro_rules! node_base_impl {
                 self.base.set_display_name(display_name);
             }
 
+            fn add_display_name_translation(&mut self, display_name: LocalizedText) {
+                self.base.add_display_name_translation(display_name);
+            }
+
+            fn display_name_for_locales(&self, locale_ids: &[UAString]) -> LocalizedText {
+                self.base.display_name_for_locales(locale_ids)
+            }
+
+            fn display_name_translations(&self) -> Vec<LocalizedText> {
+                self.base.display_name_translations()
+            }
+
             fn description(&self) -> Option<LocalizedText> {
                 self.base.description()
             }
@@ -419,6 +451,18 @@ macro_rules! node_base_impl {
                 self.base.set_description(description);
             }
 
+            fn add_description_translation(&mut self, description: LocalizedText) {
+                self.base.add_description_translation(description);
+            }
+
+            fn description_for_locales(&self, locale_ids: &[UAString]) -> Option<LocalizedText> {
+                self.base.description_for_locales(locale_ids)
+            }
+
+            fn description_translations(&self) -> Vec<LocalizedText> {
+                self.base.description_translations()
+            }
+
             fn write_mask(&self) -> Option<WriteMask> {
                 self.base.write_mask()
             }
@@ -443,11 +487,14 @@ pub mod base;
 pub mod data_type;
 pub mod method;
 pub mod node;
+pub mod nodeset2;
 pub mod object;
 pub mod object_type;
 pub mod reference_type;
 pub mod references;
 pub mod relative_path;
+pub mod snapshot;
+pub(crate) mod value_changes;
 pub mod variable;
 pub mod variable_type;
 pub mod view;
@@ -463,10 +510,9 @@ bitflags! {
         const CURRENT_WRITE = 2;
         const HISTORY_READ = 4;
         const HISTORY_WRITE = 8;
-        // These can be uncommented if they become used
-        // const SEMANTIC_CHANGE = 16;
-        // const STATUS_WRITE = 32;
-        // const TIMESTAMP_WRITE = 64;
+        const SEMANTIC_CHANGE = 16;
+        const STATUS_WRITE = 32;
+        const TIMESTAMP_WRITE = 64;
     }
 }
 
@@ -476,9 +522,8 @@ bitflags! {
         const CURRENT_WRITE = 2;
         const HISTORY_READ = 4;
         const HISTORY_WRITE = 8;
-        // These can be uncommented if they become used
-        // const STATUS_WRITE = 32;
-        // const TIMESTAMP_WRITE = 64;
+        const STATUS_WRITE = 32;
+        const TIMESTAMP_WRITE = 64;
     }
 }
 
@@ -495,10 +540,12 @@ pub mod types {
     pub use super::data_type::{DataType, DataTypeBuilder};
     pub use super::method::{Method, MethodBuilder};
     pub use super::node::{NodeBase, NodeType};
+    pub use super::nodeset2::{NodeSetImportReport, UnresolvedReference};
     pub use super::object::{Object, ObjectBuilder};
     pub use super::object_type::{ObjectType, ObjectTypeBuilder};
     pub use super::reference_type::{ReferenceType, ReferenceTypeBuilder};
     pub use super::references::ReferenceDirection;
+    pub use super::snapshot::AddressSpaceSnapshot;
     pub use super::variable::{Variable, VariableBuilder};
     pub use super::variable_type::{VariableType, VariableTypeBuilder};
     pub use super::view::{View, ViewBuilder};
@@ -4,7 +4,7 @@
 
 use opcua_types::{
     service_types::NodeClass, status_code::StatusCode, AttributeId, DataValue, LocalizedText,
-    NodeId, NumericRange, QualifiedName, TimestampsToReturn, Variant, WriteMask,
+    NodeId, NumericRange, QualifiedName, TimestampsToReturn, UAString, Variant, WriteMask,
 };
 
 use crate::address_space::types::{
@@ -35,6 +35,23 @@ impl HasNodeId for NodeType {
 }
 
 impl NodeType {
+    /// Creates a node of the node class with default attributes, or `None` for
+    /// `NodeClass::Unspecified`. The caller is expected to set the attributes afterwards.
+    pub(crate) fn from_node_class(node_class: NodeClass) -> Option<NodeType> {
+        let node_type = match node_class {
+            NodeClass::Object => NodeType::Object(Box::new(Object::default())),
+            NodeClass::ObjectType => NodeType::ObjectType(Box::new(ObjectType::default())),
+            NodeClass::ReferenceType => NodeType::ReferenceType(Box::new(ReferenceType::default())),
+            NodeClass::Variable => NodeType::Variable(Box::new(Variable::default())),
+            NodeClass::VariableType => NodeType::VariableType(Box::new(VariableType::default())),
+            NodeClass::View => NodeType::View(Box::new(View::default())),
+            NodeClass::DataType => NodeType::DataType(Box::new(DataType::default())),
+            NodeClass::Method => NodeType::Method(Box::new(Method::default())),
+            NodeClass::Unspecified => return None,
+        };
+        Some(node_type)
+    }
+
     pub fn as_node(&self) -> &dyn Node {
         match self {
             NodeType::Object(value) => value.as_ref(),
@@ -94,10 +111,28 @@ pub trait NodeBase {
     /// Sets the node's display name
     fn set_display_name(&mut self, display_name: LocalizedText);
 
+    /// Adds a translation of the display name, replacing any existing one with the same locale
+    fn add_display_name_translation(&mut self, display_name: LocalizedText);
+
+    /// Returns the display name which best matches the locale ids, in order of preference
+    fn display_name_for_locales(&self, locale_ids: &[UAString]) -> LocalizedText;
+
+    /// Returns the translations of the display name
+    fn display_name_translations(&self) -> Vec<LocalizedText>;
+
     fn description(&self) -> Option<LocalizedText>;
 
     fn set_description(&mut self, description: LocalizedText);
 
+    /// Adds a translation of the description, replacing any existing one with the same locale
+    fn add_description_translation(&mut self, description: LocalizedText);
+
+    /// Returns the description which best matches the locale ids, in order of preference
+    fn description_for_locales(&self, locale_ids: &[UAString]) -> Option<LocalizedText>;
+
+    /// Returns the translations of the description
+    fn description_translations(&self) -> Vec<LocalizedText>;
+
     fn write_mask(&self) -> Option<WriteMask>;
 
     fn set_write_mask(&mut self, write_mask: WriteMask);
@@ -0,0 +1,1578 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the implementation of `AddressSpace::import_nodeset2()` which imports the nodes of a
+//! NodeSet2 XML document, e.g. a companion specification, into the address space at runtime and
+//! `AddressSpace::export_nodeset2()` which does the reverse for namespaces of the address space.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    convert::TryFrom,
+    fmt::{self, Write as FmtWrite},
+    io::{Read, Write},
+    str::FromStr,
+};
+
+use roxmltree::{Document, Node as XmlNode};
+
+use opcua_types::{
+    service_types::{
+        Argument, EUInformation, EnumDefinition, EnumField, EnumValueType, Range,
+        StructureDefinition, StructureField, StructureType,
+    },
+    status_code::StatusCode,
+    *,
+};
+
+use crate::address_space::{
+    address_space::AddressSpace,
+    node::{HasNodeId, Node, NodeBase, NodeType},
+};
+
+/// The element of a node's `<Extensions>` that holds the translations of its value, and the
+/// namespace the element is written in
+const VALUE_TRANSLATIONS_EXTENSION: &str = "ValueTranslations";
+const VALUE_TRANSLATIONS_NAMESPACE: &str = "urn:opcua-rs:nodeset2";
+
+/// A reference of an imported node where the other node or the reference type is in neither
+/// the address space nor the node set. The reference is still added to the address space.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnresolvedReference {
+    pub source_node: NodeId,
+    pub reference_type: NodeId,
+    pub target_node: NodeId,
+}
+
+/// Describes the outcome of importing a node set with `AddressSpace::import_nodeset2()`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NodeSetImportReport {
+    /// The namespace index of each uri in the `NamespaceUris` of the node set, in order
+    pub namespaces: Vec<u16>,
+    /// Number of nodes added to the address space
+    pub nodes_added: usize,
+    /// Nodes that were not added because the address space already has a node with the same id
+    pub nodes_skipped: Vec<NodeId>,
+    /// References to nodes or of reference types that do not exist
+    pub unresolved_references: Vec<UnresolvedReference>,
+}
+
+/// A reference as it is declared in the node set, relative to the node declaring it
+struct NodeSetReference {
+    reference_type: NodeId,
+    target_node: NodeId,
+    is_forward: bool,
+}
+
+/// Holds the namespace mapping and aliases of the node set being imported
+struct NodeSetContext {
+    /// Maps a namespace index in the node set to the index in the address space
+    namespace_map: Vec<u16>,
+    /// Aliases for node ids, already mapped to the address space
+    aliases: HashMap<String, NodeId>,
+}
+
+impl NodeSetContext {
+    fn namespace(&self, namespace: u16) -> Result<u16, StatusCode> {
+        self.namespace_map
+            .get(namespace as usize)
+            .copied()
+            .ok_or_else(|| {
+                error!(
+                    "Node set refers to undeclared namespace index {}",
+                    namespace
+                );
+                StatusCode::BadNodeIdInvalid
+            })
+    }
+
+    /// Parses a node id or alias, mapping it to the namespace indexes of the address space
+    fn node_id(&self, value: &str) -> Result<NodeId, StatusCode> {
+        let value = value.trim();
+        if let Some(node_id) = self.aliases.get(value) {
+            Ok(node_id.clone())
+        } else {
+            let mut node_id = NodeId::from_str(value).map_err(|err| {
+                error!("Node set contains an invalid node id {}", value);
+                err
+            })?;
+            node_id.namespace = self.namespace(node_id.namespace)?;
+            Ok(node_id)
+        }
+    }
+
+    /// Parses a browse name of the form `<namespace index>:<name>`, or `<name>` for namespace 0
+    fn browse_name(&self, value: &str) -> Result<QualifiedName, StatusCode> {
+        let mut parts = value.splitn(2, ':');
+        let prefix = parts.next().unwrap_or("");
+        if let (Ok(namespace), Some(name)) = (prefix.parse::<u16>(), parts.next()) {
+            Ok(QualifiedName::new(self.namespace(namespace)?, name))
+        } else {
+            Ok(QualifiedName::new(0, value))
+        }
+    }
+}
+
+fn child<'a, 'input>(node: XmlNode<'a, 'input>, name: &str) -> Option<XmlNode<'a, 'input>> {
+    node.children()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn children<'a, 'input: 'a>(
+    node: XmlNode<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = XmlNode<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn child_text<'a>(node: XmlNode<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name).map(|n| n.text().unwrap_or(""))
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, StatusCode> {
+    value.trim().parse::<T>().map_err(|_| {
+        error!("Node set contains an invalid value {}", value);
+        StatusCode::BadDecodingError
+    })
+}
+
+/// Parses an optional attribute of the element, returning the default if it is absent
+fn attribute<T: FromStr>(node: XmlNode, name: &str, default: T) -> Result<T, StatusCode> {
+    node.attribute(name).map_or(Ok(default), parse)
+}
+
+fn localized_text(node: XmlNode) -> LocalizedText {
+    LocalizedText::new(
+        node.attribute("Locale").unwrap_or(""),
+        node.text().unwrap_or(""),
+    )
+}
+
+/// Parses the translations of a `LocalizedText` value. NodeSet2 has nowhere for them so they are
+/// written as `<LocalizedText>` elements inside a `<ValueTranslations>` extension of the node.
+fn value_translations(element: XmlNode) -> Vec<LocalizedText> {
+    child(element, "Extensions")
+        .into_iter()
+        .flat_map(|extensions| children(extensions, "Extension"))
+        .filter_map(|extension| child(extension, VALUE_TRANSLATIONS_EXTENSION))
+        .flat_map(|translations| children(translations, "LocalizedText"))
+        .map(|n| {
+            LocalizedText::new(
+                child_text(n, "Locale").unwrap_or(""),
+                child_text(n, "Text").unwrap_or(""),
+            )
+        })
+        .collect()
+}
+
+/// Parses a value encoded as a child of `<Value>` according to the XML encoding of Part 6
+fn value(context: &NodeSetContext, node: XmlNode) -> Result<Variant, StatusCode> {
+    let text = || node.text().unwrap_or("");
+    let name = node.tag_name().name();
+    let value = match name {
+        "Boolean" => Variant::Boolean(parse::<bool>(text())?),
+        "SByte" => Variant::SByte(parse(text())?),
+        "Byte" => Variant::Byte(parse(text())?),
+        "Int16" => Variant::Int16(parse(text())?),
+        "UInt16" => Variant::UInt16(parse(text())?),
+        "Int32" => Variant::Int32(parse(text())?),
+        "UInt32" => Variant::UInt32(parse(text())?),
+        "Int64" => Variant::Int64(parse(text())?),
+        "UInt64" => Variant::UInt64(parse(text())?),
+        "Float" => Variant::Float(parse(text())?),
+        "Double" => Variant::Double(parse(text())?),
+        "String" => Variant::String(UAString::from(text())),
+        "XmlElement" => Variant::XmlElement(UAString::from(text())),
+        "DateTime" => Variant::from(
+            DateTime::from_str(text().trim()).map_err(|_| StatusCode::BadDecodingError)?,
+        ),
+        "Guid" => {
+            let guid = child_text(node, "String").unwrap_or_else(text);
+            Variant::from(Guid::from_str(guid.trim()).map_err(|_| StatusCode::BadDecodingError)?)
+        }
+        "ByteString" => {
+            let data = text().split_whitespace().collect::<String>();
+            Variant::ByteString(ByteString::from_base64(&data).ok_or(StatusCode::BadDecodingError)?)
+        }
+        "StatusCode" => {
+            let code = parse::<u32>(child_text(node, "Code").unwrap_or("0"))?;
+            Variant::StatusCode(StatusCode::from_bits_truncate(code))
+        }
+        "LocalizedText" => Variant::from(LocalizedText::new(
+            child_text(node, "Locale").unwrap_or(""),
+            child_text(node, "Text").unwrap_or(""),
+        )),
+        "QualifiedName" => {
+            let namespace = parse::<u16>(child_text(node, "NamespaceIndex").unwrap_or("0"))?;
+            Variant::from(QualifiedName::new(
+                context.namespace(namespace)?,
+                child_text(node, "Name").unwrap_or(""),
+            ))
+        }
+        "NodeId" => Variant::from(context.node_id(child_text(node, "Identifier").unwrap_or(""))?),
+        "ExpandedNodeId" => Variant::from(ExpandedNodeId::from(
+            context.node_id(child_text(node, "Identifier").unwrap_or(""))?,
+        )),
+        "ExtensionObject" => Variant::from(extension_object(context, node)?),
+        name if name.starts_with("ListOf") => {
+            let values = node
+                .children()
+                .filter(|n| n.is_element())
+                .map(|n| value(context, n))
+                .collect::<Result<Vec<Variant>, StatusCode>>()?;
+            Variant::from(values)
+        }
+        name => {
+            error!("Node set value of type {} is not supported", name);
+            return Err(StatusCode::BadDataTypeIdUnknown);
+        }
+    };
+    Ok(value)
+}
+
+/// Parses an extension object. Only the structures that commonly appear as the values of
+/// properties in node sets are supported.
+fn extension_object(
+    context: &NodeSetContext,
+    node: XmlNode,
+) -> Result<ExtensionObject, StatusCode> {
+    let body = child(node, "Body")
+        .and_then(|body| body.children().find(|n| n.is_element()))
+        .ok_or(StatusCode::BadDecodingError)?;
+    let text = |name: &str| child_text(body, name).unwrap_or("");
+    let localized_text = |name: &str| {
+        child(body, name).map_or(LocalizedText::null(), |n| {
+            LocalizedText::new(
+                child_text(n, "Locale").unwrap_or(""),
+                child_text(n, "Text").unwrap_or(""),
+            )
+        })
+    };
+    let extension_object = match body.tag_name().name() {
+        "Argument" => {
+            let data_type = child(body, "DataType")
+                .and_then(|n| child_text(n, "Identifier"))
+                .map_or(Ok(NodeId::null()), |v| context.node_id(v))?;
+            let array_dimensions = child(body, "ArrayDimensions")
+                .map(|n| {
+                    n.children()
+                        .filter(|n| n.is_element())
+                        .map(|n| parse::<u32>(n.text().unwrap_or("")))
+                        .collect::<Result<Vec<u32>, StatusCode>>()
+                })
+                .transpose()?
+                .filter(|array_dimensions| !array_dimensions.is_empty());
+            ExtensionObject::from_encodable(
+                ObjectId::Argument_Encoding_DefaultBinary,
+                &Argument {
+                    name: UAString::from(text("Name")),
+                    data_type,
+                    value_rank: attribute_text(text("ValueRank"), -1)?,
+                    array_dimensions,
+                    description: localized_text("Description"),
+                },
+            )
+        }
+        "EnumValueType" => ExtensionObject::from_encodable(
+            ObjectId::EnumValueType_Encoding_DefaultBinary,
+            &EnumValueType {
+                value: attribute_text(text("Value"), 0)?,
+                display_name: localized_text("DisplayName"),
+                description: localized_text("Description"),
+            },
+        ),
+        "Range" => ExtensionObject::from_encodable(
+            ObjectId::Range_Encoding_DefaultBinary,
+            &Range {
+                low: attribute_text(text("Low"), 0f64)?,
+                high: attribute_text(text("High"), 0f64)?,
+            },
+        ),
+        "EUInformation" => ExtensionObject::from_encodable(
+            ObjectId::EUInformation_Encoding_DefaultBinary,
+            &EUInformation {
+                namespace_uri: UAString::from(text("NamespaceUri")),
+                unit_id: attribute_text(text("UnitId"), -1)?,
+                display_name: localized_text("DisplayName"),
+                description: localized_text("Description"),
+            },
+        ),
+        name => {
+            error!("Node set extension object {} is not supported", name);
+            return Err(StatusCode::BadDataTypeIdUnknown);
+        }
+    };
+    Ok(extension_object)
+}
+
+/// Parses the text of an optional element, returning the default if it is empty
+fn attribute_text<T: FromStr>(value: &str, default: T) -> Result<T, StatusCode> {
+    if value.trim().is_empty() {
+        Ok(default)
+    } else {
+        parse(value)
+    }
+}
+
+/// Creates the node described by one of the `UAObject`, `UAVariable` etc. elements of the node
+/// set together with the references it declares.
+fn node(
+    context: &NodeSetContext,
+    element: XmlNode,
+) -> Result<Option<(NodeType, Vec<NodeSetReference>)>, StatusCode> {
+    let node_class = match element.tag_name().name() {
+        "UAObject" => NodeClass::Object,
+        "UAVariable" => NodeClass::Variable,
+        "UAMethod" => NodeClass::Method,
+        "UAObjectType" => NodeClass::ObjectType,
+        "UAVariableType" => NodeClass::VariableType,
+        "UADataType" => NodeClass::DataType,
+        "UAReferenceType" => NodeClass::ReferenceType,
+        "UAView" => NodeClass::View,
+        _ => return Ok(None),
+    };
+
+    let node_id = context.node_id(element.attribute("NodeId").unwrap_or(""))?;
+    let browse_name = context.browse_name(element.attribute("BrowseName").unwrap_or(""))?;
+    // The first display name and description are the defaults and the others are translations
+    let mut display_names = children(element, "DisplayName").map(localized_text);
+    let display_name = display_names
+        .next()
+        .unwrap_or_else(|| LocalizedText::new("", browse_name.name.as_ref()));
+    let display_name_translations = display_names.collect::<Vec<LocalizedText>>();
+    let mut descriptions = children(element, "Description").map(localized_text);
+    let description = descriptions.next();
+    let description_translations = descriptions.collect::<Vec<LocalizedText>>();
+
+    // Attributes are set through the node as if they had been written
+    let mut attributes: Vec<(AttributeId, Variant)> = vec![
+        (AttributeId::NodeId, node_id.clone().into()),
+        (AttributeId::BrowseName, browse_name.into()),
+        (AttributeId::DisplayName, display_name.into()),
+    ];
+    if let Some(description) = description {
+        attributes.push((AttributeId::Description, description.into()));
+    }
+    if let Some(write_mask) = element.attribute("WriteMask") {
+        attributes.push((AttributeId::WriteMask, parse::<u32>(write_mask)?.into()));
+    }
+    if let Some(user_write_mask) = element.attribute("UserWriteMask") {
+        attributes.push((
+            AttributeId::UserWriteMask,
+            parse::<u32>(user_write_mask)?.into(),
+        ));
+    }
+
+    let is_abstract = || -> Result<(AttributeId, Variant), StatusCode> {
+        Ok((
+            AttributeId::IsAbstract,
+            attribute(element, "IsAbstract", false)?.into(),
+        ))
+    };
+    let data_type = || -> Result<Vec<(AttributeId, Variant)>, StatusCode> {
+        let mut attributes = vec![
+            (
+                AttributeId::DataType,
+                context
+                    .node_id(element.attribute("DataType").unwrap_or("i=24"))?
+                    .into(),
+            ),
+            (
+                AttributeId::ValueRank,
+                attribute(element, "ValueRank", -1i32)?.into(),
+            ),
+        ];
+        if let Some(array_dimensions) = element.attribute("ArrayDimensions") {
+            let array_dimensions = array_dimensions
+                .split(',')
+                .filter(|v| !v.trim().is_empty())
+                .map(parse::<u32>)
+                .collect::<Result<Vec<u32>, StatusCode>>()?;
+            if !array_dimensions.is_empty() {
+                attributes.push((AttributeId::ArrayDimensions, array_dimensions.into()));
+            }
+        }
+        if let Some(value_element) =
+            child(element, "Value").and_then(|n| n.children().find(|n| n.is_element()))
+        {
+            // A value that cannot be understood leaves the node without a value rather than
+            // failing the whole import
+            match value(context, value_element) {
+                Ok(value) => attributes.push((AttributeId::Value, value)),
+                Err(_) => warn!("Node {} has a value that cannot be imported", node_id),
+            }
+        }
+        Ok(attributes)
+    };
+
+    match node_class {
+        NodeClass::Object => {
+            attributes.push((
+                AttributeId::EventNotifier,
+                attribute(element, "EventNotifier", 0u8)?.into(),
+            ));
+        }
+        NodeClass::Variable => {
+            attributes.append(&mut data_type()?);
+            attributes.push((
+                AttributeId::AccessLevel,
+                attribute(element, "AccessLevel", 1u8)?.into(),
+            ));
+            attributes.push((
+                AttributeId::UserAccessLevel,
+                attribute(element, "UserAccessLevel", 1u8)?.into(),
+            ));
+            attributes.push((
+                AttributeId::Historizing,
+                attribute(element, "Historizing", false)?.into(),
+            ));
+            if let Some(minimum_sampling_interval) = element.attribute("MinimumSamplingInterval") {
+                attributes.push((
+                    AttributeId::MinimumSamplingInterval,
+                    parse::<f64>(minimum_sampling_interval)?.into(),
+                ));
+            }
+        }
+        NodeClass::Method => {
+            attributes.push((
+                AttributeId::Executable,
+                attribute(element, "Executable", true)?.into(),
+            ));
+            attributes.push((
+                AttributeId::UserExecutable,
+                attribute(element, "UserExecutable", true)?.into(),
+            ));
+        }
+        NodeClass::ObjectType | NodeClass::DataType => {
+            attributes.push(is_abstract()?);
+        }
+        NodeClass::VariableType => {
+            attributes.push(is_abstract()?);
+            attributes.append(&mut data_type()?);
+        }
+        NodeClass::ReferenceType => {
+            attributes.push(is_abstract()?);
+            attributes.push((
+                AttributeId::Symmetric,
+                attribute(element, "Symmetric", false)?.into(),
+            ));
+            if let Some(inverse_name) = child(element, "InverseName") {
+                attributes.push((
+                    AttributeId::InverseName,
+                    localized_text(inverse_name).into(),
+                ));
+            }
+        }
+        NodeClass::View => {
+            attributes.push((
+                AttributeId::ContainsNoLoops,
+                attribute(element, "ContainsNoLoops", false)?.into(),
+            ));
+            attributes.push((
+                AttributeId::EventNotifier,
+                attribute(element, "EventNotifier", 0u8)?.into(),
+            ));
+        }
+        NodeClass::Unspecified => {}
+    }
+
+    let mut node_type = NodeType::from_node_class(node_class).unwrap();
+    for (attribute_id, value) in attributes {
+        node_type
+            .as_mut_node()
+            .set_attribute(attribute_id, value)
+            .map_err(|err| {
+                error!(
+                    "Node {} attribute {:?} cannot be set, error = {}",
+                    node_id, attribute_id, err
+                );
+                err
+            })?;
+    }
+    // Translations follow the attributes since setting a value discards its translations
+    {
+        let node = node_type.as_mut_node();
+        display_name_translations
+            .into_iter()
+            .for_each(|text| node.add_display_name_translation(text));
+        description_translations
+            .into_iter()
+            .for_each(|text| node.add_description_translation(text));
+    }
+    if let NodeType::Variable(variable) = &mut node_type {
+        value_translations(element)
+            .into_iter()
+            .for_each(|text| variable.add_value_translation(text));
+    }
+
+    let references = if let Some(references) = child(element, "References") {
+        references
+            .children()
+            .filter(|n| n.is_element() && n.tag_name().name() == "Reference")
+            .map(|n| {
+                Ok(NodeSetReference {
+                    reference_type: context.node_id(n.attribute("ReferenceType").unwrap_or(""))?,
+                    target_node: context.node_id(n.text().unwrap_or(""))?,
+                    is_forward: attribute(n, "IsForward", true)?,
+                })
+            })
+            .collect::<Result<Vec<NodeSetReference>, StatusCode>>()?
+    } else {
+        Vec::new()
+    };
+
+    if let (NodeType::DataType(data_type), Some(definition)) =
+        (&mut node_type, child(element, "Definition"))
+    {
+        let base_data_type = references
+            .iter()
+            .find(|r| !r.is_forward && r.reference_type == ReferenceTypeId::HasSubtype.into())
+            .map(|r| r.target_node.clone())
+            .unwrap_or_else(NodeId::null);
+        data_type.set_data_type_definition(Some(data_type_definition(
+            context,
+            definition,
+            base_data_type,
+        )?));
+    }
+
+    Ok(Some((node_type, references)))
+}
+
+/// Parses the `<Definition>` of a data type. The definition is of an enumeration or option set if
+/// its fields have values and of a structure otherwise. The default encoding of a structure is
+/// found from the references of the data type once the node set has been imported.
+fn data_type_definition(
+    context: &NodeSetContext,
+    definition: XmlNode,
+    base_data_type: NodeId,
+) -> Result<DataTypeDefinition, StatusCode> {
+    let fields = definition
+        .children()
+        .filter(|n| n.is_element() && n.tag_name().name() == "Field")
+        .collect::<Vec<XmlNode>>();
+    let is_enum = base_data_type == DataTypeId::Enumeration.into()
+        || fields.iter().any(|f| f.attribute("Value").is_some());
+    let name = |field: &XmlNode| UAString::from(field.attribute("Name").unwrap_or(""));
+    let description = |field: &XmlNode| {
+        child(*field, "Description").map_or_else(LocalizedText::null, localized_text)
+    };
+    if is_enum {
+        let fields = fields
+            .iter()
+            .map(|field| {
+                Ok(EnumField {
+                    value: attribute(*field, "Value", 0i64)?,
+                    display_name: child(*field, "DisplayName").map_or_else(
+                        || LocalizedText::new("", field.attribute("Name").unwrap_or("")),
+                        localized_text,
+                    ),
+                    description: description(field),
+                    name: name(field),
+                })
+            })
+            .collect::<Result<Vec<EnumField>, StatusCode>>()?;
+        Ok(EnumDefinition {
+            fields: Some(fields),
+        }
+        .into())
+    } else {
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let array_dimensions = field
+                    .attribute("ArrayDimensions")
+                    .map(|v| {
+                        v.split(',')
+                            .filter(|v| !v.trim().is_empty())
+                            .map(parse::<u32>)
+                            .collect::<Result<Vec<u32>, StatusCode>>()
+                    })
+                    .transpose()?;
+                Ok(StructureField {
+                    name: name(field),
+                    description: description(field),
+                    data_type: context.node_id(field.attribute("DataType").unwrap_or("i=24"))?,
+                    value_rank: attribute(*field, "ValueRank", -1i32)?,
+                    array_dimensions,
+                    max_string_length: attribute(*field, "MaxStringLength", 0u32)?,
+                    is_optional: attribute(*field, "IsOptional", false)?,
+                })
+            })
+            .collect::<Result<Vec<StructureField>, StatusCode>>()?;
+        let structure_type = if attribute(definition, "IsUnion", false)? {
+            StructureType::Union
+        } else if fields.iter().any(|f| f.is_optional) {
+            StructureType::StructureWithOptionalFields
+        } else {
+            StructureType::Structure
+        };
+        let base_data_type = if base_data_type.is_null() {
+            DataTypeId::Structure.into()
+        } else {
+            base_data_type
+        };
+        Ok(StructureDefinition {
+            default_encoding_id: NodeId::null(),
+            base_data_type,
+            structure_type,
+            fields: Some(fields),
+        }
+        .into())
+    }
+}
+
+impl AddressSpace {
+    /// Imports the nodes of a NodeSet2 XML document, such as a companion specification or a
+    /// model exported from a modelling tool.
+    ///
+    /// The namespaces in the `NamespaceUris` of the node set are registered if they are not
+    /// already, and every node id, browse name and value is mapped onto the namespace indexes
+    /// of this address space. Aliases are resolved, every node class is supported and values are
+    /// decoded into `Variant`s. Nodes that already exist are skipped.
+    ///
+    /// Nothing is added if the document is malformed. Otherwise the report describes what was
+    /// imported, including references to nodes that are in neither the address space nor the
+    /// node set, e.g. because a node set it depends upon has not been imported yet.
+    pub fn import_nodeset2<R>(&mut self, mut reader: R) -> Result<NodeSetImportReport, StatusCode>
+    where
+        R: Read,
+    {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml).map_err(|err| {
+            error!("Node set cannot be read, error = {}", err);
+            StatusCode::BadDecodingError
+        })?;
+        let document = Document::parse(&xml).map_err(|err| {
+            error!("Node set is not valid XML, error = {}", err);
+            StatusCode::BadDecodingError
+        })?;
+        let root = document.root_element();
+        if root.tag_name().name() != "UANodeSet" {
+            error!("Document is not a node set");
+            return Err(StatusCode::BadDecodingError);
+        }
+
+        // Namespace index 0 is always the OPC UA namespace, the node set's own namespaces follow
+        let uris = child(root, "NamespaceUris")
+            .map(|n| {
+                n.children()
+                    .filter(|n| n.is_element() && n.tag_name().name() == "Uri")
+                    .map(|n| n.text().unwrap_or("").trim().to_string())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+        // The namespaces are only registered once the node set has been parsed
+        let namespaces = self.namespace_indexes_to_register(&uris).map_err(|_| {
+            error!("Node set namespaces {:?} cannot be registered", uris);
+            StatusCode::BadDecodingError
+        })?;
+
+        let mut context = NodeSetContext {
+            namespace_map: std::iter::once(0)
+                .chain(namespaces.iter().copied())
+                .collect(),
+            aliases: HashMap::new(),
+        };
+        if let Some(aliases) = child(root, "Aliases") {
+            for alias in aliases
+                .children()
+                .filter(|n| n.is_element() && n.tag_name().name() == "Alias")
+            {
+                let node_id = context.node_id(alias.text().unwrap_or(""))?;
+                context
+                    .aliases
+                    .insert(alias.attribute("Alias").unwrap_or("").to_string(), node_id);
+            }
+        }
+
+        // Every node is parsed before any is added so a malformed node set adds nothing
+        let nodes = root
+            .children()
+            .filter(|n| n.is_element())
+            .filter_map(|n| node(&context, n).transpose())
+            .collect::<Result<Vec<(NodeType, Vec<NodeSetReference>)>, StatusCode>>()?;
+
+        let default_namespace = self.default_namespace();
+        uris.iter().for_each(|uri| {
+            let _ = self.register_namespace(uri);
+        });
+        self.set_default_namespace(default_namespace);
+
+        let mut report = NodeSetImportReport {
+            namespaces,
+            ..Default::default()
+        };
+        let mut references = Vec::new();
+        let mut data_types = Vec::new();
+        for (node_type, node_references) in nodes {
+            let node_id = node_type.node_id();
+            if self.node_exists(&node_id) {
+                debug!("Node {} already exists and is not imported", node_id);
+                report.nodes_skipped.push(node_id);
+                continue;
+            }
+            if let NodeType::DataType(_) = node_type {
+                data_types.push(node_id.clone());
+            }
+            let _ = self.insert::<_, NodeId>(node_type, None);
+            report.nodes_added += 1;
+            references.extend(node_references.into_iter().map(|r| {
+                // Inverse references are held as forward references from the other node
+                if r.is_forward {
+                    (node_id.clone(), r.reference_type, r.target_node)
+                } else {
+                    (r.target_node, r.reference_type, node_id.clone())
+                }
+            }));
+        }
+
+        for (source_node, reference_type, target_node) in references {
+            if source_node == target_node {
+                warn!(
+                    "Node {} has a reference to itself which is ignored",
+                    source_node
+                );
+                continue;
+            }
+            if !self.node_exists(&source_node)
+                || !self.node_exists(&target_node)
+                || !self.node_exists(&reference_type)
+            {
+                report.unresolved_references.push(UnresolvedReference {
+                    source_node: source_node.clone(),
+                    reference_type: reference_type.clone(),
+                    target_node: target_node.clone(),
+                });
+            }
+            self.insert_reference(&source_node, &target_node, reference_type);
+        }
+
+        // Structure definitions in a node set do not say which encoding is the default one
+        for data_type_id in data_types {
+            let encoding_id = self.default_binary_encoding(&data_type_id);
+            if let (Some(NodeType::DataType(data_type)), Some(encoding_id)) =
+                (self.find_node_mut(&data_type_id), encoding_id)
+            {
+                if let Some(DataTypeDefinition::Structure(definition)) =
+                    data_type.data_type_definition()
+                {
+                    if definition.default_encoding_id.is_null() {
+                        let mut definition = definition.clone();
+                        definition.default_encoding_id = encoding_id;
+                        data_type.set_data_type_definition(Some(definition.into()));
+                    }
+                }
+            }
+        }
+
+        info!(
+            "Imported {} nodes from node set, {} skipped, {} unresolved references",
+            report.nodes_added,
+            report.nodes_skipped.len(),
+            report.unresolved_references.len()
+        );
+        Ok(report)
+    }
+}
+
+/// The namespace of the OPC UA information model, which is always namespace index 0
+const OPCUA_NAMESPACE: &str = "http://opcfoundation.org/UA/";
+const NODESET_XMLNS: &str = "http://opcfoundation.org/UA/2011/03/UANodeSet.xsd";
+const TYPES_XMLNS: &str = "http://opcfoundation.org/UA/2008/02/Types.xsd";
+
+/// Ids of the default XML encodings of the structures that can be exported as values. The ids are
+/// not amongst the generated object ids since the stack does not implement the XML encoding.
+const ARGUMENT_ENCODING_DEFAULT_XML: u32 = 297;
+const RANGE_ENCODING_DEFAULT_XML: u32 = 885;
+const EU_INFORMATION_ENCODING_DEFAULT_XML: u32 = 888;
+const ENUM_VALUE_TYPE_ENCODING_DEFAULT_XML: u32 = 7616;
+
+/// A node to export along with its value and the references to write with it
+struct ExportNode<'a> {
+    node_type: &'a NodeType,
+    value: Option<Variant>,
+    /// Reference type, the other node and whether the reference is forward from this node
+    references: Vec<(NodeId, NodeId, bool)>,
+}
+
+/// Holds the namespace mapping and aliases of the node set being exported
+struct ExportContext {
+    /// Maps a namespace index in the address space to the index in the node set
+    namespace_map: HashMap<u16, u16>,
+    /// Aliases by the node id they stand for
+    aliases: HashMap<NodeId, String>,
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    value.chars().for_each(|c| match c {
+        '&' => escaped.push_str("&amp;"),
+        '<' => escaped.push_str("&lt;"),
+        '>' => escaped.push_str("&gt;"),
+        '"' => escaped.push_str("&quot;"),
+        '\'' => escaped.push_str("&apos;"),
+        c => escaped.push(c),
+    });
+    escaped
+}
+
+/// Makes an element in the namespace of the built-in types, which is what values are written in
+fn element(name: &str, content: &str) -> String {
+    format!("<uax:{}>{}</uax:{}>", name, content, name)
+}
+
+fn float(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0f64 { "INF" } else { "-INF" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn localized_text_xml(value: &LocalizedText) -> String {
+    format!(
+        "{}{}",
+        element("Locale", &escape(value.locale.as_ref())),
+        element("Text", &escape(value.text.as_ref()))
+    )
+}
+
+/// Returns the name of the element a scalar value is written as
+fn value_type_name(value: &Variant) -> Option<&'static str> {
+    let name = match value {
+        Variant::Boolean(_) => "Boolean",
+        Variant::SByte(_) => "SByte",
+        Variant::Byte(_) => "Byte",
+        Variant::Int16(_) => "Int16",
+        Variant::UInt16(_) => "UInt16",
+        Variant::Int32(_) => "Int32",
+        Variant::UInt32(_) => "UInt32",
+        Variant::Int64(_) => "Int64",
+        Variant::UInt64(_) => "UInt64",
+        Variant::Float(_) => "Float",
+        Variant::Double(_) => "Double",
+        Variant::String(_) => "String",
+        Variant::DateTime(_) => "DateTime",
+        Variant::Guid(_) => "Guid",
+        Variant::StatusCode(_) => "StatusCode",
+        Variant::ByteString(_) => "ByteString",
+        Variant::XmlElement(_) => "XmlElement",
+        Variant::QualifiedName(_) => "QualifiedName",
+        Variant::LocalizedText(_) => "LocalizedText",
+        Variant::NodeId(_) => "NodeId",
+        Variant::ExpandedNodeId(_) => "ExpandedNodeId",
+        Variant::ExtensionObject(_) => "ExtensionObject",
+        Variant::Empty | Variant::Array(_) => return None,
+    };
+    Some(name)
+}
+
+/// Reads an attribute of a node, returning `None` if it does not have the attribute
+fn node_attribute(node: &dyn Node, attribute_id: AttributeId) -> Option<Variant> {
+    node.get_attribute(
+        TimestampsToReturn::Neither,
+        attribute_id,
+        NumericRange::None,
+        &QualifiedName::null(),
+    )
+    .and_then(|value| value.value)
+}
+
+/// Adds the namespace indexes used by a value to the set
+fn value_namespaces(value: &Variant, namespaces: &mut BTreeSet<u16>) {
+    match value {
+        Variant::NodeId(node_id) => {
+            namespaces.insert(node_id.namespace);
+        }
+        Variant::ExpandedNodeId(node_id) => {
+            namespaces.insert(node_id.node_id.namespace);
+        }
+        Variant::QualifiedName(browse_name) => {
+            namespaces.insert(browse_name.namespace_index);
+        }
+        Variant::ExtensionObject(extension_object) => {
+            if let Ok(ObjectId::Argument_Encoding_DefaultBinary) = extension_object.object_id() {
+                if let Ok(argument) =
+                    extension_object.decode_inner::<Argument>(&DecodingLimits::default())
+                {
+                    namespaces.insert(argument.data_type.namespace);
+                }
+            }
+        }
+        Variant::Array(array) => array
+            .values
+            .iter()
+            .for_each(|value| value_namespaces(value, namespaces)),
+        _ => {}
+    }
+}
+
+impl ExportContext {
+    fn namespace(&self, namespace: u16) -> u16 {
+        self.namespace_map
+            .get(&namespace)
+            .copied()
+            .unwrap_or(namespace)
+    }
+
+    fn node_id(&self, node_id: &NodeId) -> String {
+        NodeId {
+            namespace: self.namespace(node_id.namespace),
+            identifier: node_id.identifier.clone(),
+        }
+        .to_string()
+    }
+
+    /// Returns the alias of the node id, or the node id if it does not have one
+    fn node_id_or_alias(&self, node_id: &NodeId) -> String {
+        self.aliases
+            .get(node_id)
+            .cloned()
+            .unwrap_or_else(|| self.node_id(node_id))
+    }
+
+    fn browse_name(&self, browse_name: &QualifiedName) -> String {
+        let namespace = self.namespace(browse_name.namespace_index);
+        if namespace == 0 {
+            browse_name.name.as_ref().to_string()
+        } else {
+            format!("{}:{}", namespace, browse_name.name.as_ref())
+        }
+    }
+
+    /// Writes a value according to the XML encoding of Part 6, returning `None` for values that
+    /// cannot be written
+    fn value_xml(&self, value: &Variant) -> Option<String> {
+        let xml = match value {
+            Variant::Boolean(v) => element("Boolean", &v.to_string()),
+            Variant::SByte(v) => element("SByte", &v.to_string()),
+            Variant::Byte(v) => element("Byte", &v.to_string()),
+            Variant::Int16(v) => element("Int16", &v.to_string()),
+            Variant::UInt16(v) => element("UInt16", &v.to_string()),
+            Variant::Int32(v) => element("Int32", &v.to_string()),
+            Variant::UInt32(v) => element("UInt32", &v.to_string()),
+            Variant::Int64(v) => element("Int64", &v.to_string()),
+            Variant::UInt64(v) => element("UInt64", &v.to_string()),
+            Variant::Float(v) => element("Float", &float(f64::from(*v))),
+            Variant::Double(v) => element("Double", &float(*v)),
+            Variant::String(v) => element("String", &escape(v.as_ref())),
+            Variant::XmlElement(v) => element("XmlElement", &escape(v.as_ref())),
+            Variant::DateTime(v) => element("DateTime", &v.to_string()),
+            Variant::Guid(v) => element("Guid", &element("String", &v.to_string())),
+            Variant::ByteString(v) => element("ByteString", &v.as_base64()),
+            Variant::StatusCode(v) => {
+                element("StatusCode", &element("Code", &v.bits().to_string()))
+            }
+            Variant::QualifiedName(v) => element(
+                "QualifiedName",
+                &format!(
+                    "{}{}",
+                    element(
+                        "NamespaceIndex",
+                        &self.namespace(v.namespace_index).to_string()
+                    ),
+                    element("Name", &escape(v.name.as_ref()))
+                ),
+            ),
+            Variant::LocalizedText(v) => element("LocalizedText", &localized_text_xml(v)),
+            Variant::NodeId(v) => {
+                element("NodeId", &element("Identifier", &escape(&self.node_id(v))))
+            }
+            Variant::ExpandedNodeId(v) => element(
+                "ExpandedNodeId",
+                &element("Identifier", &escape(&self.node_id(&v.node_id))),
+            ),
+            Variant::ExtensionObject(v) => {
+                element("ExtensionObject", &self.extension_object_xml(v)?)
+            }
+            Variant::Array(array) => {
+                // Arrays must be of one dimension and have an element to know their type
+                if array.dimensions.len() > 1 {
+                    return None;
+                }
+                let name = array.values.first().and_then(value_type_name)?;
+                let values = array
+                    .values
+                    .iter()
+                    .map(|value| self.value_xml(value))
+                    .collect::<Option<Vec<String>>>()?;
+                element(&format!("ListOf{}", name), &values.concat())
+            }
+            Variant::Empty => return None,
+        };
+        Some(xml)
+    }
+
+    /// Writes the structures that commonly appear as the values of properties. These are the same
+    /// structures that can be imported.
+    fn extension_object_xml(&self, value: &ExtensionObject) -> Option<String> {
+        let decoding_limits = DecodingLimits::default();
+        let (encoding_id, body) = match value.object_id().ok()? {
+            ObjectId::Argument_Encoding_DefaultBinary => {
+                let v = value.decode_inner::<Argument>(&decoding_limits).ok()?;
+                let array_dimensions = v
+                    .array_dimensions
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|d| element("UInt32", &d.to_string()))
+                    .collect::<String>();
+                let body = [
+                    element("Name", &escape(v.name.as_ref())),
+                    element(
+                        "DataType",
+                        &element("Identifier", &escape(&self.node_id(&v.data_type))),
+                    ),
+                    element("ValueRank", &v.value_rank.to_string()),
+                    element("ArrayDimensions", &array_dimensions),
+                    element("Description", &localized_text_xml(&v.description)),
+                ];
+                (
+                    ARGUMENT_ENCODING_DEFAULT_XML,
+                    element("Argument", &body.concat()),
+                )
+            }
+            ObjectId::EnumValueType_Encoding_DefaultBinary => {
+                let v = value.decode_inner::<EnumValueType>(&decoding_limits).ok()?;
+                let body = [
+                    element("Value", &v.value.to_string()),
+                    element("DisplayName", &localized_text_xml(&v.display_name)),
+                    element("Description", &localized_text_xml(&v.description)),
+                ];
+                (
+                    ENUM_VALUE_TYPE_ENCODING_DEFAULT_XML,
+                    element("EnumValueType", &body.concat()),
+                )
+            }
+            ObjectId::Range_Encoding_DefaultBinary => {
+                let v = value.decode_inner::<Range>(&decoding_limits).ok()?;
+                let body = [
+                    element("Low", &float(v.low)),
+                    element("High", &float(v.high)),
+                ];
+                (RANGE_ENCODING_DEFAULT_XML, element("Range", &body.concat()))
+            }
+            ObjectId::EUInformation_Encoding_DefaultBinary => {
+                let v = value.decode_inner::<EUInformation>(&decoding_limits).ok()?;
+                let body = [
+                    element("NamespaceUri", &escape(v.namespace_uri.as_ref())),
+                    element("UnitId", &v.unit_id.to_string()),
+                    element("DisplayName", &localized_text_xml(&v.display_name)),
+                    element("Description", &localized_text_xml(&v.description)),
+                ];
+                (
+                    EU_INFORMATION_ENCODING_DEFAULT_XML,
+                    element("EUInformation", &body.concat()),
+                )
+            }
+            _ => return None,
+        };
+        Some(format!(
+            "{}{}",
+            element(
+                "TypeId",
+                &element("Identifier", &format!("i={}", encoding_id))
+            ),
+            element("Body", &body)
+        ))
+    }
+
+    fn write_node(&self, out: &mut String, node: &ExportNode) -> fmt::Result {
+        let node_type = node.node_type;
+        let n = node_type.as_node();
+        let byte = |attribute_id| match node_attribute(n, attribute_id) {
+            Some(Variant::Byte(v)) => v,
+            _ => 0,
+        };
+        let boolean = |attribute_id| match node_attribute(n, attribute_id) {
+            Some(Variant::Boolean(v)) => v,
+            _ => false,
+        };
+
+        // Attributes are only written when they differ from the defaults of the schema
+        let mut attributes = vec![
+            ("NodeId", self.node_id(&n.node_id())),
+            ("BrowseName", self.browse_name(&n.browse_name())),
+        ];
+        if let Some(write_mask) = n.write_mask().filter(|v| !v.is_empty()) {
+            attributes.push(("WriteMask", write_mask.bits().to_string()));
+        }
+        let data_type_attributes = |attributes: &mut Vec<(&str, String)>| {
+            if let Some(Variant::NodeId(data_type)) = node_attribute(n, AttributeId::DataType) {
+                if *data_type != NodeId::from(DataTypeId::BaseDataType) {
+                    attributes.push(("DataType", self.node_id_or_alias(&data_type)));
+                }
+            }
+            if let Some(Variant::Int32(value_rank)) = node_attribute(n, AttributeId::ValueRank) {
+                if value_rank != -1 {
+                    attributes.push(("ValueRank", value_rank.to_string()));
+                }
+            }
+            if let Some(array_dimensions) = node_attribute(n, AttributeId::ArrayDimensions)
+                .and_then(|v| <Vec<u32>>::try_from(&v).ok())
+                .filter(|v| !v.is_empty())
+            {
+                let array_dimensions = array_dimensions
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",");
+                attributes.push(("ArrayDimensions", array_dimensions));
+            }
+        };
+        let tag = match node_type {
+            NodeType::Object(_) => {
+                if byte(AttributeId::EventNotifier) != 0 {
+                    attributes.push((
+                        "EventNotifier",
+                        byte(AttributeId::EventNotifier).to_string(),
+                    ));
+                }
+                "UAObject"
+            }
+            NodeType::Variable(_) => {
+                data_type_attributes(&mut attributes);
+                if byte(AttributeId::AccessLevel) != 1 {
+                    attributes.push(("AccessLevel", byte(AttributeId::AccessLevel).to_string()));
+                }
+                if let Some(Variant::Double(v)) =
+                    node_attribute(n, AttributeId::MinimumSamplingInterval)
+                {
+                    if v != 0f64 {
+                        attributes.push(("MinimumSamplingInterval", float(v)));
+                    }
+                }
+                if boolean(AttributeId::Historizing) {
+                    attributes.push(("Historizing", "true".to_string()));
+                }
+                "UAVariable"
+            }
+            NodeType::Method(_) => {
+                if !boolean(AttributeId::Executable) {
+                    attributes.push(("Executable", "false".to_string()));
+                }
+                "UAMethod"
+            }
+            NodeType::ObjectType(_) => "UAObjectType",
+            NodeType::VariableType(_) => {
+                data_type_attributes(&mut attributes);
+                "UAVariableType"
+            }
+            NodeType::DataType(_) => "UADataType",
+            NodeType::ReferenceType(_) => {
+                if boolean(AttributeId::Symmetric) {
+                    attributes.push(("Symmetric", "true".to_string()));
+                }
+                "UAReferenceType"
+            }
+            NodeType::View(_) => {
+                if boolean(AttributeId::ContainsNoLoops) {
+                    attributes.push(("ContainsNoLoops", "true".to_string()));
+                }
+                if byte(AttributeId::EventNotifier) != 0 {
+                    attributes.push((
+                        "EventNotifier",
+                        byte(AttributeId::EventNotifier).to_string(),
+                    ));
+                }
+                "UAView"
+            }
+        };
+        if boolean(AttributeId::IsAbstract) {
+            attributes.push(("IsAbstract", "true".to_string()));
+        }
+
+        write!(out, "  <{}", tag)?;
+        for (name, value) in attributes {
+            write!(out, " {}=\"{}\"", name, escape(&value))?;
+        }
+        writeln!(out, ">")?;
+
+        let localized_text = |out: &mut String, name: &str, value: &LocalizedText| {
+            if value.locale.is_null() || value.locale.as_ref().is_empty() {
+                writeln!(
+                    out,
+                    "    <{}>{}</{}>",
+                    name,
+                    escape(value.text.as_ref()),
+                    name
+                )
+            } else {
+                writeln!(
+                    out,
+                    "    <{} Locale=\"{}\">{}</{}>",
+                    name,
+                    escape(value.locale.as_ref()),
+                    escape(value.text.as_ref()),
+                    name
+                )
+            }
+        };
+        // Translations follow the default display name and description as further elements
+        localized_text(out, "DisplayName", &n.display_name())?;
+        for translation in n.display_name_translations() {
+            localized_text(out, "DisplayName", &translation)?;
+        }
+        if let Some(description) = n.description().filter(|v| !v.text.is_null()) {
+            localized_text(out, "Description", &description)?;
+        }
+        for translation in n.description_translations() {
+            localized_text(out, "Description", &translation)?;
+        }
+
+        if !node.references.is_empty() {
+            writeln!(out, "    <References>")?;
+            for (reference_type, other_node, is_forward) in &node.references {
+                write!(
+                    out,
+                    "      <Reference ReferenceType=\"{}\"",
+                    escape(&self.node_id_or_alias(reference_type))
+                )?;
+                if !is_forward {
+                    write!(out, " IsForward=\"false\"")?;
+                }
+                writeln!(out, ">{}</Reference>", escape(&self.node_id(other_node)))?;
+            }
+            writeln!(out, "    </References>")?;
+        }
+
+        if let NodeType::Variable(variable) = node_type {
+            if !variable.value_translations().is_empty() {
+                writeln!(out, "    <Extensions>")?;
+                writeln!(out, "      <Extension>")?;
+                write!(
+                    out,
+                    "        <{} xmlns=\"{}\">",
+                    VALUE_TRANSLATIONS_EXTENSION, VALUE_TRANSLATIONS_NAMESPACE
+                )?;
+                for translation in variable.value_translations() {
+                    write!(
+                        out,
+                        "{}",
+                        element("LocalizedText", &localized_text_xml(translation))
+                    )?;
+                }
+                writeln!(out, "</{}>", VALUE_TRANSLATIONS_EXTENSION)?;
+                writeln!(out, "      </Extension>")?;
+                writeln!(out, "    </Extensions>")?;
+            }
+        }
+
+        if let NodeType::DataType(data_type) = node_type {
+            if let Some(definition) = data_type.data_type_definition() {
+                self.write_definition(out, &n.browse_name(), definition)?;
+            }
+        }
+
+        if let Some(ref value) = node.value {
+            // A value that cannot be written leaves the node without a value in the node set
+            if let Some(value) = self.value_xml(value) {
+                writeln!(out, "    <Value>{}</Value>", value)?;
+            } else {
+                warn!("Node {} has a value that cannot be exported", n.node_id());
+            }
+        }
+
+        if let Some(Variant::LocalizedText(inverse_name)) =
+            node_attribute(n, AttributeId::InverseName)
+        {
+            localized_text(out, "InverseName", &inverse_name)?;
+        }
+
+        writeln!(out, "  </{}>", tag)
+    }
+
+    /// Writes the `<Definition>` of a data type, leaving out attributes that have their defaults
+    fn write_definition(
+        &self,
+        out: &mut String,
+        browse_name: &QualifiedName,
+        definition: &DataTypeDefinition,
+    ) -> fmt::Result {
+        let description = |out: &mut String, description: &LocalizedText| {
+            if description.text.is_null() {
+                Ok(())
+            } else {
+                writeln!(
+                    out,
+                    "        <Description>{}</Description>",
+                    escape(description.text.as_ref())
+                )
+            }
+        };
+        write!(
+            out,
+            "    <Definition Name=\"{}\"",
+            escape(&self.browse_name(browse_name))
+        )?;
+        match definition {
+            DataTypeDefinition::Structure(definition) => {
+                if definition.structure_type == StructureType::Union {
+                    write!(out, " IsUnion=\"true\"")?;
+                }
+                writeln!(out, ">")?;
+                for field in definition.fields.as_deref().unwrap_or(&[]) {
+                    write!(out, "      <Field Name=\"{}\"", escape(field.name.as_ref()))?;
+                    if field.data_type != DataTypeId::BaseDataType.into() {
+                        write!(
+                            out,
+                            " DataType=\"{}\"",
+                            escape(&self.node_id_or_alias(&field.data_type))
+                        )?;
+                    }
+                    if field.value_rank != -1 {
+                        write!(out, " ValueRank=\"{}\"", field.value_rank)?;
+                    }
+                    if let Some(array_dimensions) =
+                        field.array_dimensions.as_ref().filter(|v| !v.is_empty())
+                    {
+                        let array_dimensions = array_dimensions
+                            .iter()
+                            .map(|v| v.to_string())
+                            .collect::<Vec<String>>()
+                            .join(",");
+                        write!(out, " ArrayDimensions=\"{}\"", array_dimensions)?;
+                    }
+                    if field.max_string_length != 0 {
+                        write!(out, " MaxStringLength=\"{}\"", field.max_string_length)?;
+                    }
+                    if field.is_optional {
+                        write!(out, " IsOptional=\"true\"")?;
+                    }
+                    if field.description.text.is_null() {
+                        writeln!(out, " />")?;
+                    } else {
+                        writeln!(out, ">")?;
+                        description(out, &field.description)?;
+                        writeln!(out, "      </Field>")?;
+                    }
+                }
+            }
+            DataTypeDefinition::Enum(definition) => {
+                writeln!(out, ">")?;
+                for field in definition.fields.as_deref().unwrap_or(&[]) {
+                    writeln!(
+                        out,
+                        "      <Field Name=\"{}\" Value=\"{}\">",
+                        escape(field.name.as_ref()),
+                        field.value
+                    )?;
+                    if !field.display_name.text.is_null() && field.display_name.text != field.name {
+                        writeln!(
+                            out,
+                            "        <DisplayName>{}</DisplayName>",
+                            escape(field.display_name.text.as_ref())
+                        )?;
+                    }
+                    description(out, &field.description)?;
+                    writeln!(out, "      </Field>")?;
+                }
+            }
+        }
+        writeln!(out, "    </Definition>")
+    }
+}
+
+impl AddressSpace {
+    /// Exports the nodes of the namespaces, together with their references and values, as a
+    /// NodeSet2 XML document which other OPC UA tools can import. The return code is the number
+    /// of nodes exported.
+    ///
+    /// The exported namespaces are written first in the `NamespaceUris` of the document, followed
+    /// by any other namespace that nodes refer to. References from nodes outside of the exported
+    /// namespaces, e.g. from the `Objects` folder, are written as inverse references. Aliases are
+    /// made for the reference types and data types of the OPC UA namespace.
+    pub fn export_nodeset2<W>(&self, mut writer: W, namespaces: &[u16]) -> Result<usize, StatusCode>
+    where
+        W: Write,
+    {
+        let mut exported = Vec::with_capacity(namespaces.len());
+        for namespace in namespaces {
+            if *namespace == 0 || self.namespace_uri(*namespace).is_none() {
+                error!("Namespace {} cannot be exported", namespace);
+                return Err(StatusCode::BadInvalidArgument);
+            }
+            if !exported.contains(namespace) {
+                exported.push(*namespace);
+            }
+        }
+        let in_namespaces = |node_id: &NodeId| exported.contains(&node_id.namespace);
+
+        let mut nodes = self
+            .nodes()
+            .filter(|node_type| in_namespaces(&node_type.node_id()))
+            .map(|node_type| ExportNode {
+                node_type,
+                value: node_attribute(node_type.as_node(), AttributeId::Value),
+                references: Vec::new(),
+            })
+            .collect::<Vec<ExportNode>>();
+        // Nodes and references are sorted so that the same address space is always written the
+        // same way
+        nodes.sort_by_key(|node| {
+            let node_id = node.node_type.node_id();
+            match node_id.identifier {
+                Identifier::Numeric(v) => (node_id.namespace, 0, v, String::new()),
+                ref identifier => (node_id.namespace, 1, 0, identifier.to_string()),
+            }
+        });
+        let node_idx = nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (node.node_type.node_id(), idx))
+            .collect::<HashMap<NodeId, usize>>();
+        for (source_node, reference) in self.references().iter() {
+            if let Some(idx) = node_idx.get(source_node) {
+                nodes[*idx].references.push((
+                    reference.reference_type.clone(),
+                    reference.target_node.clone(),
+                    true,
+                ));
+            } else if let Some(idx) = node_idx.get(&reference.target_node) {
+                nodes[*idx].references.push((
+                    reference.reference_type.clone(),
+                    source_node.clone(),
+                    false,
+                ));
+            }
+        }
+        nodes.iter_mut().for_each(|node| {
+            node.references
+                .sort_by_key(|(reference_type, other_node, is_forward)| {
+                    (
+                        !*is_forward,
+                        reference_type.to_string(),
+                        other_node.to_string(),
+                    )
+                })
+        });
+
+        // Find the other namespaces the nodes refer to and the types that need aliases
+        let mut namespaces_used = BTreeSet::new();
+        let mut types_used = HashSet::new();
+        for node in &nodes {
+            let n = node.node_type.as_node();
+            namespaces_used.insert(n.browse_name().namespace_index);
+            if let Some(Variant::NodeId(data_type)) = node_attribute(n, AttributeId::DataType) {
+                namespaces_used.insert(data_type.namespace);
+                types_used.insert(*data_type);
+            }
+            for (reference_type, other_node, _) in &node.references {
+                namespaces_used.insert(reference_type.namespace);
+                namespaces_used.insert(other_node.namespace);
+                types_used.insert(reference_type.clone());
+            }
+            if let Some(ref value) = node.value {
+                value_namespaces(value, &mut namespaces_used);
+            }
+            if let NodeType::DataType(data_type) = node.node_type {
+                if let Some(DataTypeDefinition::Structure(definition)) =
+                    data_type.data_type_definition()
+                {
+                    for field in definition.fields.as_deref().unwrap_or(&[]) {
+                        namespaces_used.insert(field.data_type.namespace);
+                        types_used.insert(field.data_type.clone());
+                    }
+                }
+            }
+        }
+        let uris = exported
+            .iter()
+            .copied()
+            .chain(
+                namespaces_used
+                    .into_iter()
+                    .filter(|ns| *ns != 0 && !exported.contains(ns)),
+            )
+            .collect::<Vec<u16>>();
+
+        let mut aliases = BTreeMap::new();
+        for node_id in types_used
+            .into_iter()
+            .filter(|node_id| node_id.namespace == 0)
+        {
+            if let Some(node) = self.find_node(&node_id) {
+                let alias = node.as_node().browse_name().name.as_ref().to_string();
+                if !alias.is_empty() && !aliases.contains_key(&alias) {
+                    aliases.insert(alias, node_id);
+                }
+            }
+        }
+
+        let context = ExportContext {
+            namespace_map: uris
+                .iter()
+                .enumerate()
+                .map(|(idx, ns)| (*ns, idx as u16 + 1))
+                .collect(),
+            aliases: aliases
+                .iter()
+                .map(|(alias, node_id)| (node_id.clone(), alias.clone()))
+                .collect(),
+        };
+
+        let write_document = |out: &mut String| -> fmt::Result {
+            writeln!(out, "<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+            writeln!(
+                out,
+                "<UANodeSet xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xmlns:uax=\"{}\" xmlns=\"{}\">",
+                TYPES_XMLNS, NODESET_XMLNS
+            )?;
+            if !uris.is_empty() {
+                writeln!(out, "  <NamespaceUris>")?;
+                for ns in &uris {
+                    let uri = self.namespace_uri(*ns).unwrap_or("");
+                    writeln!(out, "    <Uri>{}</Uri>", escape(uri))?;
+                }
+                writeln!(out, "  </NamespaceUris>")?;
+
+                // Each exported namespace is a model that requires the OPC UA model and the
+                // models of any other namespace that its nodes refer to
+                writeln!(out, "  <Models>")?;
+                for ns in &exported {
+                    let uri = self.namespace_uri(*ns).unwrap_or("");
+                    writeln!(out, "    <Model ModelUri=\"{}\">", escape(uri))?;
+                    writeln!(
+                        out,
+                        "      <RequiredModel ModelUri=\"{}\" />",
+                        OPCUA_NAMESPACE
+                    )?;
+                    for ns in uris.iter().skip(exported.len()) {
+                        let uri = self.namespace_uri(*ns).unwrap_or("");
+                        writeln!(out, "      <RequiredModel ModelUri=\"{}\" />", escape(uri))?;
+                    }
+                    writeln!(out, "    </Model>")?;
+                }
+                writeln!(out, "  </Models>")?;
+            }
+            if !aliases.is_empty() {
+                writeln!(out, "  <Aliases>")?;
+                for (alias, node_id) in &aliases {
+                    writeln!(
+                        out,
+                        "    <Alias Alias=\"{}\">{}</Alias>",
+                        escape(alias),
+                        node_id
+                    )?;
+                }
+                writeln!(out, "  </Aliases>")?;
+            }
+            for node in &nodes {
+                context.write_node(out, node)?;
+            }
+            writeln!(out, "</UANodeSet>")
+        };
+
+        let mut xml = String::new();
+        write_document(&mut xml).map_err(|_| StatusCode::BadEncodingError)?;
+        writer.write_all(xml.as_bytes()).map_err(|err| {
+            error!("Node set cannot be written, error = {}", err);
+            StatusCode::BadEncodingError
+        })?;
+        info!("Exported {} nodes to node set", nodes.len());
+        Ok(nodes.len())
+    }
+}
@@ -43,6 +43,44 @@ impl ObjectBuilder {
             ReferenceDirection::Forward,
         )
     }
+
+    /// Makes the object an event source of the notifier, so events from the object reach
+    /// monitored items on the notifier
+    pub fn event_source_of<T>(self, notifier_id: T) -> Self
+    where
+        T: Into<NodeId>,
+    {
+        self.reference(
+            notifier_id,
+            ReferenceTypeId::HasEventSource,
+            ReferenceDirection::Inverse,
+        )
+    }
+
+    /// Adds a notifier beneath the object in the notifier hierarchy, so events from the notifier
+    /// and its sources reach monitored items on the object
+    pub fn has_notifier<T>(self, notifier_id: T) -> Self
+    where
+        T: Into<NodeId>,
+    {
+        self.reference(
+            notifier_id,
+            ReferenceTypeId::HasNotifier,
+            ReferenceDirection::Forward,
+        )
+    }
+
+    /// Makes the object a notifier beneath the parent notifier in the notifier hierarchy
+    pub fn notifier_of<T>(self, parent_notifier_id: T) -> Self
+    where
+        T: Into<NodeId>,
+    {
+        self.reference(
+            parent_notifier_id,
+            ReferenceTypeId::HasNotifier,
+            ReferenceDirection::Inverse,
+        )
+    }
 }
 
 /// An `Object` is a type of node within the `AddressSpace`.
@@ -284,6 +284,17 @@ impl References {
         deleted_references || deleted_lookups
     }
 
+    /// Iterates every reference in the map, pairing each with the node it is from.
+    pub fn iter(&self) -> impl Iterator<Item = (&NodeId, &Reference)> {
+        self.references_map
+            .iter()
+            .flat_map(|(source_node, references)| {
+                references
+                    .iter()
+                    .map(move |reference| (source_node, reference))
+            })
+    }
+
     /// Test if a reference relationship exists between one node and another node
     pub fn has_reference<T>(
         &self,
@@ -0,0 +1,385 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the implementation of `AddressSpaceSnapshot`, the binary form nodes of an address
+//! space take when they are saved to disk.
+
+use std::io::{Read, Write};
+
+use opcua_types::{status_code::StatusCode, *};
+
+use crate::address_space::node::{Node, NodeBase, NodeType};
+
+/// Identifies a snapshot file and the version of its layout
+const SNAPSHOT_MAGIC: &str = "urn:opcua-rs:address-space-snapshot";
+const SNAPSHOT_VERSION: u32 = 2;
+
+/// Every attribute that a node might have. Attributes a node does not have are skipped.
+const SNAPSHOT_ATTRIBUTES: [AttributeId; 23] = [
+    AttributeId::NodeId,
+    AttributeId::NodeClass,
+    AttributeId::BrowseName,
+    AttributeId::DisplayName,
+    AttributeId::Description,
+    AttributeId::WriteMask,
+    AttributeId::UserWriteMask,
+    AttributeId::IsAbstract,
+    AttributeId::Symmetric,
+    AttributeId::InverseName,
+    AttributeId::ContainsNoLoops,
+    AttributeId::EventNotifier,
+    AttributeId::Value,
+    AttributeId::DataType,
+    AttributeId::ValueRank,
+    AttributeId::ArrayDimensions,
+    AttributeId::AccessLevel,
+    AttributeId::UserAccessLevel,
+    AttributeId::MinimumSamplingInterval,
+    AttributeId::Historizing,
+    AttributeId::Executable,
+    AttributeId::UserExecutable,
+    AttributeId::DataTypeDefinition,
+];
+
+/// The attributes of a single node
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NodeSnapshot {
+    node_class: NodeClass,
+    attributes: Vec<(AttributeId, DataValue)>,
+    /// Translations of the `DisplayName`, `Description` and a `LocalizedText` `Value` into other
+    /// locales
+    translations: Vec<(AttributeId, LocalizedText)>,
+}
+
+impl BinaryEncoder<NodeSnapshot> for NodeSnapshot {
+    fn byte_len(&self) -> usize {
+        let mut size = self.node_class.byte_len() + 4;
+        size += self
+            .attributes
+            .iter()
+            .map(|(_, v)| 4 + v.byte_len())
+            .sum::<usize>();
+        size += 4 + self
+            .translations
+            .iter()
+            .map(|(_, v)| 4 + v.byte_len())
+            .sum::<usize>();
+        size
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.node_class.encode(stream)?;
+        size += write_u32(stream, self.attributes.len() as u32)?;
+        for (attribute_id, value) in &self.attributes {
+            size += write_u32(stream, *attribute_id as u32)?;
+            size += value.encode(stream)?;
+        }
+        size += write_u32(stream, self.translations.len() as u32)?;
+        for (attribute_id, text) in &self.translations {
+            size += write_u32(stream, *attribute_id as u32)?;
+            size += text.encode(stream)?;
+        }
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_class = NodeClass::decode(stream, decoding_limits)?;
+        let count = read_u32(stream)? as usize;
+        if count > SNAPSHOT_ATTRIBUTES.len() {
+            error!("Snapshot node has too many attributes {}", count);
+            return Err(StatusCode::BadDecodingError);
+        }
+        let mut attributes = Vec::with_capacity(count);
+        for _ in 0..count {
+            let attribute_id = AttributeId::from_u32(read_u32(stream)?)
+                .map_err(|_| StatusCode::BadDecodingError)?;
+            let value = DataValue::decode(stream, decoding_limits)?;
+            attributes.push((attribute_id, value));
+        }
+        let count = read_u32(stream)?;
+        let mut translations = Vec::new();
+        for _ in 0..count {
+            let attribute_id = match AttributeId::from_u32(read_u32(stream)?) {
+                Ok(attribute_id @ AttributeId::DisplayName)
+                | Ok(attribute_id @ AttributeId::Description)
+                | Ok(attribute_id @ AttributeId::Value) => attribute_id,
+                _ => {
+                    error!("Snapshot node has a translation of an invalid attribute");
+                    return Err(StatusCode::BadDecodingError);
+                }
+            };
+            let text = LocalizedText::decode(stream, decoding_limits)?;
+            translations.push((attribute_id, text));
+        }
+        Ok(NodeSnapshot {
+            node_class,
+            attributes,
+            translations,
+        })
+    }
+}
+
+impl NodeSnapshot {
+    pub fn from_node(node_type: &NodeType) -> NodeSnapshot {
+        let node = node_type.as_node();
+        let attributes = SNAPSHOT_ATTRIBUTES
+            .iter()
+            .filter_map(|attribute_id| {
+                node.get_attribute(
+                    TimestampsToReturn::Both,
+                    *attribute_id,
+                    NumericRange::None,
+                    &QualifiedName::null(),
+                )
+                .map(|value| (*attribute_id, value))
+            })
+            .collect();
+        let translation = |attribute_id| move |text: LocalizedText| (attribute_id, text);
+        let mut translations = node
+            .display_name_translations()
+            .into_iter()
+            .map(translation(AttributeId::DisplayName))
+            .chain(
+                node.description_translations()
+                    .into_iter()
+                    .map(translation(AttributeId::Description)),
+            )
+            .collect::<Vec<(AttributeId, LocalizedText)>>();
+        if let NodeType::Variable(variable) = node_type {
+            translations.extend(
+                variable
+                    .value_translations()
+                    .iter()
+                    .cloned()
+                    .map(translation(AttributeId::Value)),
+            );
+        }
+        NodeSnapshot {
+            node_class: node.node_class(),
+            attributes,
+            translations,
+        }
+    }
+
+    /// Creates the node from its attributes. Nodes that cannot be recreated, e.g. because an
+    /// attribute has the wrong type, are an error.
+    pub fn into_node_type(self) -> Result<NodeType, StatusCode> {
+        let mut node_type =
+            NodeType::from_node_class(self.node_class).ok_or(StatusCode::BadNodeClassInvalid)?;
+        for (attribute_id, value) in self.attributes {
+            match (attribute_id, &mut node_type) {
+                // The value of a variable keeps its status and timestamps
+                (AttributeId::Value, NodeType::Variable(variable)) => {
+                    let status = value.status();
+                    let now = DateTime::now();
+                    variable.set_value_direct(
+                        value.value.unwrap_or(Variant::Empty),
+                        status,
+                        value.server_timestamp.as_ref().unwrap_or(&now),
+                        value.source_timestamp.as_ref().unwrap_or(&now),
+                    )?;
+                }
+                (attribute_id, node_type) => {
+                    if let Some(value) = value.value {
+                        node_type.as_mut_node().set_attribute(attribute_id, value)?;
+                    }
+                }
+            }
+        }
+        // Translations follow the attributes since setting a value discards its translations
+        for (attribute_id, text) in self.translations {
+            match (attribute_id, &mut node_type) {
+                (AttributeId::DisplayName, node_type) => {
+                    node_type.as_mut_node().add_display_name_translation(text)
+                }
+                (AttributeId::Description, node_type) => {
+                    node_type.as_mut_node().add_description_translation(text)
+                }
+                (AttributeId::Value, NodeType::Variable(variable)) => {
+                    variable.add_value_translation(text)
+                }
+                _ => return Err(StatusCode::BadAttributeIdInvalid),
+            }
+        }
+        Ok(node_type)
+    }
+
+    fn remap_namespaces(&mut self, namespace_map: &[u16]) {
+        self.attributes.iter_mut().for_each(|(_, value)| {
+            if let Some(ref mut value) = value.value {
+                remap_variant(value, namespace_map);
+            }
+        });
+    }
+}
+
+/// A reference from one node to another
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ReferenceSnapshot {
+    pub source_node: NodeId,
+    pub reference_type: NodeId,
+    pub target_node: NodeId,
+}
+
+impl BinaryEncoder<ReferenceSnapshot> for ReferenceSnapshot {
+    fn byte_len(&self) -> usize {
+        self.source_node.byte_len() + self.reference_type.byte_len() + self.target_node.byte_len()
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.source_node.encode(stream)?;
+        size += self.reference_type.encode(stream)?;
+        size += self.target_node.encode(stream)?;
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let source_node = NodeId::decode(stream, decoding_limits)?;
+        let reference_type = NodeId::decode(stream, decoding_limits)?;
+        let target_node = NodeId::decode(stream, decoding_limits)?;
+        Ok(ReferenceSnapshot {
+            source_node,
+            reference_type,
+            target_node,
+        })
+    }
+}
+
+/// An `AddressSpaceSnapshot` holds the nodes of one or more namespaces of an address space
+/// together with their references. It is created by `AddressSpace::snapshot()` and restored
+/// with `AddressSpace::restore_snapshot()`.
+///
+/// Namespace indexes are not stable between runs of a server, so the snapshot also holds the
+/// namespace array it was taken with. Node ids, browse names and values are mapped onto the
+/// namespace indexes of the address space they are restored into.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressSpaceSnapshot {
+    pub(crate) namespaces: Vec<UAString>,
+    pub(crate) nodes: Vec<NodeSnapshot>,
+    pub(crate) references: Vec<ReferenceSnapshot>,
+}
+
+impl BinaryEncoder<AddressSpaceSnapshot> for AddressSpaceSnapshot {
+    fn byte_len(&self) -> usize {
+        let mut size = UAString::from(SNAPSHOT_MAGIC).byte_len() + 4;
+        size += 4 + self.namespaces.iter().map(|v| v.byte_len()).sum::<usize>();
+        size += 4 + self.nodes.iter().map(|v| v.byte_len()).sum::<usize>();
+        size += 4 + self.references.iter().map(|v| v.byte_len()).sum::<usize>();
+        size
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += UAString::from(SNAPSHOT_MAGIC).encode(stream)?;
+        size += write_u32(stream, SNAPSHOT_VERSION)?;
+        // Lengths are written without the array limits of the decoder since a snapshot can
+        // easily hold more nodes than a message may
+        size += write_u32(stream, self.namespaces.len() as u32)?;
+        for namespace in &self.namespaces {
+            size += namespace.encode(stream)?;
+        }
+        size += write_u32(stream, self.nodes.len() as u32)?;
+        for node in &self.nodes {
+            size += node.encode(stream)?;
+        }
+        size += write_u32(stream, self.references.len() as u32)?;
+        for reference in &self.references {
+            size += reference.encode(stream)?;
+        }
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let magic = UAString::decode(stream, decoding_limits)?;
+        if magic.as_ref() != SNAPSHOT_MAGIC {
+            error!("Stream does not contain an address space snapshot");
+            return Err(StatusCode::BadDecodingError);
+        }
+        let version = read_u32(stream)?;
+        if version != SNAPSHOT_VERSION {
+            error!(
+                "Address space snapshot version {} is not supported",
+                version
+            );
+            return Err(StatusCode::BadDecodingError);
+        }
+        let count = read_u32(stream)?;
+        let mut namespaces = Vec::new();
+        for _ in 0..count {
+            namespaces.push(UAString::decode(stream, decoding_limits)?);
+        }
+        let count = read_u32(stream)?;
+        let mut nodes = Vec::new();
+        for _ in 0..count {
+            nodes.push(NodeSnapshot::decode(stream, decoding_limits)?);
+        }
+        let count = read_u32(stream)?;
+        let mut references = Vec::new();
+        for _ in 0..count {
+            references.push(ReferenceSnapshot::decode(stream, decoding_limits)?);
+        }
+        Ok(AddressSpaceSnapshot {
+            namespaces,
+            nodes,
+            references,
+        })
+    }
+}
+
+impl AddressSpaceSnapshot {
+    /// Returns the number of nodes in the snapshot
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of references in the snapshot
+    pub fn reference_count(&self) -> usize {
+        self.references.len()
+    }
+
+    /// Changes every namespace index in the snapshot to the index at that position of the map.
+    /// Indexes beyond the end of the map are left alone.
+    pub(crate) fn remap_namespaces(&mut self, namespace_map: &[u16]) {
+        self.nodes
+            .iter_mut()
+            .for_each(|node| node.remap_namespaces(namespace_map));
+        self.references.iter_mut().for_each(|reference| {
+            remap_node_id(&mut reference.source_node, namespace_map);
+            remap_node_id(&mut reference.reference_type, namespace_map);
+            remap_node_id(&mut reference.target_node, namespace_map);
+        });
+    }
+}
+
+fn remap_namespace(namespace: &mut u16, namespace_map: &[u16]) {
+    if let Some(new_namespace) = namespace_map.get(*namespace as usize) {
+        *namespace = *new_namespace;
+    }
+}
+
+fn remap_node_id(node_id: &mut NodeId, namespace_map: &[u16]) {
+    remap_namespace(&mut node_id.namespace, namespace_map);
+}
+
+fn remap_variant(value: &mut Variant, namespace_map: &[u16]) {
+    match *value {
+        Variant::NodeId(ref mut node_id) => remap_node_id(node_id, namespace_map),
+        Variant::ExpandedNodeId(ref mut node_id) => {
+            // An expanded node id with a namespace uri is already independent of the index
+            if node_id.namespace_uri.is_null() {
+                remap_node_id(&mut node_id.node_id, namespace_map)
+            }
+        }
+        Variant::QualifiedName(ref mut browse_name) => {
+            remap_namespace(&mut browse_name.namespace_index, namespace_map)
+        }
+        Variant::Array(ref mut array) => array
+            .values
+            .iter_mut()
+            .for_each(|value| remap_variant(value, namespace_map)),
+        _ => {}
+    }
+}
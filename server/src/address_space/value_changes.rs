@@ -0,0 +1,88 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Notifies monitored items when the value of a variable changes so they do not have to keep
+//! sampling variables whose values are only ever set through the address space.
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex, Weak};
+
+use opcua_types::NodeId;
+
+/// The ids of monitored items that have been told their value changed but have not sampled it
+/// yet. A subscription shares one set between all of its monitored items.
+pub(crate) type ChangedItems = Arc<Mutex<BTreeSet<u32>>>;
+
+/// A monitored item that wants to be told when the value of a variable changes.
+#[derive(Debug)]
+pub(crate) struct ValueChangeListener {
+    monitored_item_id: u32,
+    changed_items: ChangedItems,
+}
+
+impl ValueChangeListener {
+    pub fn new(monitored_item_id: u32, changed_items: ChangedItems) -> ValueChangeListener {
+        ValueChangeListener {
+            monitored_item_id,
+            changed_items,
+        }
+    }
+
+    fn notify(&self) {
+        let mut changed_items = trace_lock_unwrap!(self.changed_items);
+        changed_items.insert(self.monitored_item_id);
+    }
+}
+
+/// The listeners waiting on value changes, by the node id of the variable. The address space
+/// shares this with each of its variables. Listeners are held weakly, so a listener goes away
+/// when its monitored item is deleted and is pruned the next time any listener is added.
+#[derive(Debug, Default)]
+pub(crate) struct ValueChanges {
+    listeners: HashMap<NodeId, Vec<Weak<ValueChangeListener>>>,
+}
+
+impl ValueChanges {
+    /// Adds a listener for changes to the value of the node
+    pub fn add_listener(&mut self, node_id: &NodeId, listener: &Arc<ValueChangeListener>) {
+        // Drop any listeners whose monitored items have gone, including those of variables
+        // whose values never change
+        self.listeners.retain(|_, listeners| {
+            listeners.retain(|l| l.strong_count() > 0);
+            !listeners.is_empty()
+        });
+        self.listeners
+            .entry(node_id.clone())
+            .or_insert_with(Vec::new)
+            .push(Arc::downgrade(listener));
+    }
+
+    /// For testing purposes, the number of listeners of every node, live or not
+    #[cfg(test)]
+    pub fn listener_count(&self) -> usize {
+        self.listeners
+            .values()
+            .map(|listeners| listeners.len())
+            .sum()
+    }
+
+    /// Tells every listener of the node that its value has changed
+    pub fn value_changed(&mut self, node_id: &NodeId) {
+        let no_listeners = if let Some(listeners) = self.listeners.get_mut(node_id) {
+            listeners.retain(|l| {
+                if let Some(listener) = l.upgrade() {
+                    listener.notify();
+                    true
+                } else {
+                    false
+                }
+            });
+            listeners.is_empty()
+        } else {
+            false
+        };
+        if no_listeners {
+            self.listeners.remove(node_id);
+        }
+    }
+}
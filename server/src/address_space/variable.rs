@@ -11,11 +11,12 @@ use opcua_types::service_types::VariableAttributes;
 
 use crate::{
     address_space::{
-        base::Base,
+        base::{self, Base},
         node::{Node, NodeBase},
+        value_changes::ValueChanges,
         AccessLevel, UserAccessLevel,
     },
-    callbacks::{AttributeGetter, AttributeSetter},
+    callbacks::{AsyncAttributeGetter, AsyncResult, AttributeGetter, AttributeSetter},
 };
 
 // This is a builder object for constructing variable nodes programmatically.
@@ -34,6 +35,17 @@ impl VariableBuilder {
         self
     }
 
+    /// Adds a translation of a `LocalizedText` value in another locale. The translation that best
+    /// matches a session's locale ids is returned to its client. Translations are discarded when
+    /// the value changes, so they must follow the value.
+    pub fn value_translation<V>(mut self, value: V) -> Self
+    where
+        V: Into<LocalizedText>,
+    {
+        self.node.add_value_translation(value.into());
+        self
+    }
+
     /// Sets the data type of the variable.
     pub fn data_type<T>(mut self, data_type: T) -> Self
     where
@@ -100,6 +112,14 @@ impl VariableBuilder {
         self
     }
 
+    /// Marks the variable as a property whose value changes the meaning of the node it belongs
+    /// to, so that writing it raises a semantic change event
+    pub fn semantic_change(mut self) -> Self {
+        self.node
+            .set_access_level(self.node.access_level() | AccessLevel::SEMANTIC_CHANGE);
+        self
+    }
+
     /// Sets the minimum sampling interval for the variable.
     pub fn minimum_sampling_interval(mut self, minimum_sampling_interval: f64) -> Self {
         self.node
@@ -115,6 +135,17 @@ impl VariableBuilder {
         self
     }
 
+    /// Sets an asynchronous value getter function for the variable. The Read service waits for
+    /// the value without blocking other requests. Synchronous reads of the value, e.g. through
+    /// `AddressSpace::get_variable_value()`, get `BadWouldBlock`.
+    pub fn async_value_getter(
+        mut self,
+        getter: Arc<Mutex<dyn AsyncAttributeGetter + Send>>,
+    ) -> Self {
+        self.node.set_async_value_getter(getter);
+        self
+    }
+
     /// Sets a value setter function for the variable. Whenever the value of a variable is set via
     /// a service, this trait will be called to set the value. It is up to the implementation
     /// to decide what to do if that happens.
@@ -159,6 +190,7 @@ pub struct Variable {
     historizing: bool,
     value_rank: i32,
     value: DataValue,
+    value_translations: Vec<LocalizedText>,
     access_level: u8,
     user_access_level: u8,
     array_dimensions: Option<Vec<u32>>,
@@ -167,6 +199,10 @@ pub struct Variable {
     value_setter: Option<Arc<Mutex<dyn AttributeSetter + Send>>>,
     #[derivative(Debug = "ignore")]
     value_getter: Option<Arc<Mutex<dyn AttributeGetter + Send>>>,
+    #[derivative(Debug = "ignore")]
+    async_value_getter: Option<Arc<Mutex<dyn AsyncAttributeGetter + Send>>>,
+    #[derivative(Debug = "ignore")]
+    value_changes: Option<Arc<Mutex<ValueChanges>>>,
 }
 
 impl Default for Variable {
@@ -177,12 +213,15 @@ impl Default for Variable {
             historizing: false,
             value_rank: -1,
             value: Variant::Empty.into(),
+            value_translations: Vec::new(),
             access_level: UserAccessLevel::CURRENT_READ.bits(),
             user_access_level: AccessLevel::CURRENT_READ.bits(),
             array_dimensions: None,
             minimum_sampling_interval: None,
             value_getter: None,
+            async_value_getter: None,
             value_setter: None,
+            value_changes: None,
         }
     }
 }
@@ -455,6 +494,24 @@ impl Variable {
         !self.data_type.is_null() && self.base.is_valid()
     }
 
+    /// Adds a translation of a `LocalizedText` value, replacing any existing one with the same locale.
+    /// The translations belong to the current value and are discarded when a new value is set.
+    pub fn add_value_translation(&mut self, value: LocalizedText) {
+        base::add_translation(&mut self.value_translations, value);
+        self.value_changed();
+    }
+
+    /// Returns the translations of a `LocalizedText` value
+    pub fn value_translations(&self) -> &[LocalizedText] {
+        &self.value_translations
+    }
+
+    /// Returns the translation of a `LocalizedText` value which best matches the locale ids, in
+    /// order of preference. Other values are returned unchanged.
+    pub fn localize_value(&self, value: Variant, locale_ids: &[UAString]) -> Variant {
+        base::localize_value(value, &self.value_translations, locale_ids)
+    }
+
     pub fn value(
         &self,
         timestamps_to_return: TimestampsToReturn,
@@ -477,6 +534,13 @@ impl Variable {
                 )
                 .unwrap()
                 .unwrap()
+        } else if self.async_value_getter.is_some() {
+            // The value can only be obtained asynchronously and waiting for it here would block
+            // the caller, so the caller must use `async_value()` instead
+            DataValue {
+                status: Some(StatusCode::BadWouldBlock),
+                ..DataValue::null()
+            }
         } else {
             let data_value = &self.value;
             let mut result = DataValue {
@@ -513,16 +577,24 @@ impl Variable {
     where
         V: Into<Variant>,
     {
-        let mut value = value.into();
+        self.set_data_value(index_range, DataValue::value_only(value))
+    }
 
+    /// Sets the variable's value from a `DataValue`, keeping the status and timestamps that it
+    /// holds. A missing status is `Good` and missing timestamps are updated to now.
+    pub fn set_data_value(
+        &mut self,
+        index_range: NumericRange,
+        mut data_value: DataValue,
+    ) -> Result<(), StatusCode> {
         // A special case is required here for when the variable is a single dimension
         // byte array and the value is a ByteString.
         match self.value_rank {
             -3 | -2 | 1 => {
                 if self.data_type == DataTypeId::Byte.into() {
-                    if let Variant::ByteString(_) = value {
+                    if let Some(Variant::ByteString(_)) = data_value.value {
                         // Convert the value from a byte string to a byte array
-                        value = value.to_byte_array();
+                        data_value.value = data_value.value.map(|v| v.to_byte_array());
                     }
                 }
             }
@@ -531,20 +603,35 @@ impl Variable {
 
         // The value is set to the value getter
         if let Some(ref value_setter) = self.value_setter {
-            let mut value_setter = value_setter.lock().unwrap();
-            value_setter.set(
-                &self.node_id(),
-                AttributeId::Value,
-                index_range,
-                value.into(),
-            )
+            let result = {
+                let mut value_setter = value_setter.lock().unwrap();
+                value_setter.set(&self.node_id(), AttributeId::Value, index_range, data_value)
+            };
+            if result.is_ok() {
+                self.value_translations.clear();
+                self.value_changed();
+            }
+            result
         } else {
             let now = DateTime::now();
+            let value = data_value.value.unwrap_or(Variant::Empty);
+            let status_code = data_value.status.unwrap_or(StatusCode::Good);
+            let server_timestamp = data_value.server_timestamp.unwrap_or_else(|| now.clone());
+            let source_timestamp = data_value.source_timestamp.unwrap_or(now);
             if index_range.has_range() {
-                self.set_value_range(value, index_range, StatusCode::Good, &now, &now)
+                self.set_value_range(
+                    value,
+                    index_range,
+                    status_code,
+                    &server_timestamp,
+                    &source_timestamp,
+                )?;
             } else {
-                self.set_value_direct(value, StatusCode::Good, &now, &now)
+                self.set_value_direct(value, status_code, &server_timestamp, &source_timestamp)?;
             }
+            self.value.server_picoseconds = data_value.server_picoseconds;
+            self.value.source_picoseconds = data_value.source_picoseconds;
+            Ok(())
         }
     }
 
@@ -564,6 +651,8 @@ impl Variable {
                 self.value.status = Some(status_code);
                 self.value.server_timestamp = Some(server_timestamp.clone());
                 self.value.source_timestamp = Some(source_timestamp.clone());
+                self.value_translations.clear();
+                self.value_changed();
                 Ok(())
             }
             None => Err(StatusCode::BadIndexRangeInvalid),
@@ -585,12 +674,72 @@ impl Variable {
         self.value.status = Some(status_code);
         self.value.server_timestamp = Some(server_timestamp.clone());
         self.value.source_timestamp = Some(source_timestamp.clone());
+        // Translations of the old value no longer apply
+        self.value_translations.clear();
+        self.value_changed();
         Ok(())
     }
 
     /// Sets a getter function that will be called to get the value of this variable.
     pub fn set_value_getter(&mut self, value_getter: Arc<Mutex<dyn AttributeGetter + Send>>) {
         self.value_getter = Some(value_getter);
+        // Monitored items waiting on value changes must sample the getter instead
+        self.value_changed();
+    }
+
+    /// Sets an asynchronous getter function that will be called to get the value of this variable.
+    pub fn set_async_value_getter(
+        &mut self,
+        async_value_getter: Arc<Mutex<dyn AsyncAttributeGetter + Send>>,
+    ) {
+        self.async_value_getter = Some(async_value_getter);
+        self.value_changed();
+    }
+
+    /// Tests if the value of this variable comes from a getter function
+    pub(crate) fn has_value_getter(&self) -> bool {
+        self.value_getter.is_some() || self.async_value_getter.is_some()
+    }
+
+    /// Tests if the value of this variable can only be obtained asynchronously
+    pub(crate) fn has_async_value_getter_only(&self) -> bool {
+        self.value_getter.is_none() && self.async_value_getter.is_some()
+    }
+
+    /// Returns a future for the value of this variable from its asynchronous getter, or `None` if
+    /// it does not have one.
+    pub(crate) fn async_value(
+        &self,
+        timestamps_to_return: TimestampsToReturn,
+        index_range: NumericRange,
+        data_encoding: &QualifiedName,
+        max_age: f64,
+    ) -> Option<AsyncResult<Option<DataValue>>> {
+        self.async_value_getter.as_ref().map(|async_value_getter| {
+            let mut async_value_getter = async_value_getter.lock().unwrap();
+            async_value_getter.get(
+                &self.node_id(),
+                timestamps_to_return,
+                AttributeId::Value,
+                index_range,
+                data_encoding,
+                max_age,
+            )
+        })
+    }
+
+    /// Sets the listeners to tell when the value of this variable changes. The address space
+    /// calls this when the variable is inserted.
+    pub(crate) fn set_value_changes(&mut self, value_changes: Arc<Mutex<ValueChanges>>) {
+        self.value_changes = Some(value_changes);
+    }
+
+    /// Tells monitored items listening to this variable that its value has changed
+    fn value_changed(&self) {
+        if let Some(ref value_changes) = self.value_changes {
+            let mut value_changes = trace_lock_unwrap!(value_changes);
+            value_changes.value_changed(&self.node_id());
+        }
     }
 
     /// Sets a setter function that will be called to set the value of this variable.
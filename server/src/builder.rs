@@ -7,7 +7,10 @@ use std::path::PathBuf;
 use opcua_core::config::Config;
 
 use crate::{
-    config::{ServerConfig, ServerEndpoint, ServerUserToken, ANONYMOUS_USER_TOKEN_ID},
+    config::{
+        AddressSpaceSnapshotConfig, DurableSubscriptionsConfig, OperationalLimits, ServerConfig,
+        ServerEndpoint, ServerUserToken, ANONYMOUS_USER_TOKEN_ID,
+    },
     constants,
     server::Server,
 };
@@ -338,6 +341,39 @@ impl ServerBuilder {
         self
     }
 
+    /// Set the minimum sampling interval in seconds. This is exposed to clients through
+    /// `ServerCapabilities/MinSupportedSampleRate`.
+    pub fn min_sampling_interval(mut self, min_sampling_interval: f64) -> Self {
+        self.config.limits.min_sampling_interval = min_sampling_interval;
+        self
+    }
+
+    /// Set the minimum publishing interval in seconds.
+    pub fn min_publishing_interval(mut self, min_publishing_interval: f64) -> Self {
+        self.config.limits.min_publishing_interval = min_publishing_interval;
+        self
+    }
+
+    /// Set the maximum number of browse continuation points per session
+    pub fn max_browse_continuation_points(mut self, max_browse_continuation_points: usize) -> Self {
+        self.config.limits.max_browse_continuation_points = max_browse_continuation_points;
+        self
+    }
+
+    /// Set the operational limits, i.e. the maximum number of nodes / operations a client may
+    /// pass in a single service call.
+    pub fn operational_limits(mut self, operational_limits: OperationalLimits) -> Self {
+        self.config.limits.operational = operational_limits;
+        self
+    }
+
+    /// Sets the locale ids supported by the server. These are exposed to clients through
+    /// `ServerCapabilities/LocaleIdArray`.
+    pub fn locale_ids(mut self, locale_ids: Vec<String>) -> Self {
+        self.config.locale_ids = locale_ids;
+        self
+    }
+
     /// Sets the server to automatically trust client certs. This subverts the
     /// authentication during handshake, so only do this if you understand the risks.
     pub fn trust_client_certs(mut self) -> Self {
@@ -345,6 +381,47 @@ impl ServerBuilder {
         self
     }
 
+    /// Saves the nodes of the namespaces to a snapshot file when the server stops and every
+    /// `auto_save_interval` seconds while it runs (0 to disable). The snapshot is restored when
+    /// the server is created.
+    pub fn address_space_snapshot<T>(
+        mut self,
+        path: T,
+        namespaces: &[&str],
+        auto_save_interval: u32,
+    ) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        self.config.address_space_snapshot = Some(AddressSpaceSnapshotConfig {
+            path: path.into(),
+            namespaces: namespaces.iter().map(|ns| ns.to_string()).collect(),
+            auto_save_interval,
+        });
+        self
+    }
+
+    /// Lets clients make subscriptions durable with a lifetime of up to `max_lifetime_in_hours`.
+    /// Durable subscriptions are saved to a file when the server stops and every
+    /// `auto_save_interval` seconds while it runs (0 to disable). The file is restored when the
+    /// server is created.
+    pub fn durable_subscriptions<T>(
+        mut self,
+        path: T,
+        max_lifetime_in_hours: u32,
+        auto_save_interval: u32,
+    ) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        self.config.durable_subscriptions = Some(DurableSubscriptionsConfig {
+            path: path.into(),
+            max_lifetime_in_hours,
+            auto_save_interval,
+        });
+        self
+    }
+
     /// Set that clients can modify the address space, i.e. they can add or remove nodes through
     /// the node management service. By default, they cannot.
     pub fn clients_can_modify_address_space(mut self) -> Self {
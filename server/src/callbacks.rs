@@ -6,6 +6,8 @@
 
 use std::sync::{Arc, RwLock};
 
+use futures::Future;
+
 use opcua_types::{
     service_types::{CallMethodRequest, CallMethodResult, TimestampsToReturn},
     status_code::StatusCode,
@@ -41,6 +43,28 @@ pub trait AttributeGetter {
     ) -> Result<Option<DataValue>, StatusCode>;
 }
 
+/// The result of an asynchronous callback. The future is driven to completion by the server after
+/// it has released any locks it held while making the call, so the implementation may perform I/O
+/// without blocking other requests.
+pub type AsyncResult<T> = Box<dyn Future<Item = T, Error = StatusCode> + Send>;
+
+/// An asynchronous version of `AttributeGetter`. The Read service waits for the returned future
+/// without blocking other requests on the session. Monitored items start the getter when they
+/// sample the variable and report the value it supplies when they are next sampled. Nothing ever
+/// blocks on the future, so callers that need a value immediately get `BadWouldBlock`.
+pub trait AsyncAttributeGetter {
+    /// Returns a future that resolves to a data value of the specified attribute or none.
+    fn get(
+        &mut self,
+        node_id: &NodeId,
+        timestamps_to_return: TimestampsToReturn,
+        attribute_id: AttributeId,
+        index_range: NumericRange,
+        data_encoding: &QualifiedName,
+        max_age: f64,
+    ) -> AsyncResult<Option<DataValue>>;
+}
+
 // An attribute setter. Sets the value on the specified attribute
 pub trait AttributeSetter {
     /// Sets the attribute on the specified node
@@ -97,3 +121,14 @@ pub trait Method {
         request: &CallMethodRequest,
     ) -> Result<CallMethodResult, StatusCode>;
 }
+
+/// An asynchronous version of `Method`. The session is only available while the call is made so
+/// the implementation should copy whatever it needs from it into the returned future.
+pub trait AsyncMethod {
+    /// Returns a future that resolves to the result of the call.
+    fn call(
+        &mut self,
+        session: &mut Session,
+        request: &CallMethodRequest,
+    ) -> AsyncResult<CallMethodResult>;
+}
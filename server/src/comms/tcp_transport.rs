@@ -90,6 +90,21 @@ impl MessageSender {
             .sender
             .unbounded_send(Message::Message(request_id, message));
     }
+
+    /// Creates a sender whose messages are received by the returned stream instead of being
+    /// written to a connection
+    #[cfg(test)]
+    pub(crate) fn new_test() -> (
+        MessageSender,
+        impl Stream<Item = (u32, SupportedMessage), Error = ()>,
+    ) {
+        let (sender, receiver) = unbounded();
+        let messages = receiver.filter_map(|message| match message {
+            Message::Message(request_id, message) => Some((request_id, message)),
+            Message::Quit => None,
+        });
+        (MessageSender { sender }, messages)
+    }
 }
 
 struct ReadState {
@@ -123,6 +123,112 @@ impl ServerUserToken {
     }
 }
 
+/// Operational limits are the maximum number of operations / nodes a client may pass to a
+/// service in a single call. They are exposed to clients through the
+/// `Server/ServerCapabilities/OperationLimits` nodes.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct OperationalLimits {
+    /// Maximum number of browse paths in a TranslateBrowsePathsToNodeIds call
+    pub max_nodes_per_translate_browse_paths_to_node_ids: usize,
+    /// Maximum number of nodes in a Read call
+    pub max_nodes_per_read: usize,
+    /// Maximum number of nodes in a Write call
+    pub max_nodes_per_write: usize,
+    /// Maximum number of methods in a Call call
+    pub max_nodes_per_method_call: usize,
+    /// Maximum number of nodes in a Browse call
+    pub max_nodes_per_browse: usize,
+    /// Maximum number of nodes in a RegisterNodes / UnregisterNodes call
+    pub max_nodes_per_register_nodes: usize,
+    /// Maximum number of nodes / references in a node management call
+    pub max_nodes_per_node_management: usize,
+    /// Maximum number of monitored items in a Create/Modify/Delete monitored items call
+    pub max_monitored_items_per_call: usize,
+    /// Maximum number of nodes in a HistoryRead call for data
+    pub max_nodes_per_history_read_data: usize,
+    /// Maximum number of nodes in a HistoryRead call for events
+    pub max_nodes_per_history_read_events: usize,
+    /// Maximum number of nodes in a HistoryUpdate call for data
+    pub max_nodes_per_history_update_data: usize,
+    /// Maximum number of nodes in a HistoryUpdate call for events
+    pub max_nodes_per_history_update_events: usize,
+}
+
+impl Default for OperationalLimits {
+    fn default() -> Self {
+        Self {
+            max_nodes_per_translate_browse_paths_to_node_ids:
+                constants::MAX_NODES_PER_TRANSLATE_BROWSE_PATHS_TO_NODE_IDS,
+            max_nodes_per_read: constants::MAX_NODES_PER_READ,
+            max_nodes_per_write: constants::MAX_NODES_PER_WRITE,
+            max_nodes_per_method_call: constants::MAX_NODES_PER_METHOD_CALL,
+            max_nodes_per_browse: constants::MAX_NODES_PER_BROWSE,
+            max_nodes_per_register_nodes: constants::MAX_NODES_PER_REGISTER_NODES,
+            max_nodes_per_node_management: constants::MAX_NODES_PER_NODE_MANAGEMENT,
+            max_monitored_items_per_call: constants::MAX_MONITORED_ITEMS_PER_CALL,
+            max_nodes_per_history_read_data: constants::MAX_NODES_PER_HISTORY_READ_DATA,
+            max_nodes_per_history_read_events: constants::MAX_NODES_PER_HISTORY_READ_EVENTS,
+            max_nodes_per_history_update_data: constants::MAX_NODES_PER_HISTORY_UPDATE_DATA,
+            max_nodes_per_history_update_events: constants::MAX_NODES_PER_HISTORY_UPDATE_EVENTS,
+        }
+    }
+}
+
+impl OperationalLimits {
+    /// Test if the limits are valid. A limit of 0 would make the corresponding service unusable.
+    pub fn is_valid(&self) -> bool {
+        let limits = [
+            (
+                "max_nodes_per_translate_browse_paths_to_node_ids",
+                self.max_nodes_per_translate_browse_paths_to_node_ids,
+            ),
+            ("max_nodes_per_read", self.max_nodes_per_read),
+            ("max_nodes_per_write", self.max_nodes_per_write),
+            ("max_nodes_per_method_call", self.max_nodes_per_method_call),
+            ("max_nodes_per_browse", self.max_nodes_per_browse),
+            (
+                "max_nodes_per_register_nodes",
+                self.max_nodes_per_register_nodes,
+            ),
+            (
+                "max_nodes_per_node_management",
+                self.max_nodes_per_node_management,
+            ),
+            (
+                "max_monitored_items_per_call",
+                self.max_monitored_items_per_call,
+            ),
+            (
+                "max_nodes_per_history_read_data",
+                self.max_nodes_per_history_read_data,
+            ),
+            (
+                "max_nodes_per_history_read_events",
+                self.max_nodes_per_history_read_events,
+            ),
+            (
+                "max_nodes_per_history_update_data",
+                self.max_nodes_per_history_update_data,
+            ),
+            (
+                "max_nodes_per_history_update_events",
+                self.max_nodes_per_history_update_events,
+            ),
+        ];
+        let mut valid = true;
+        for (name, value) in limits.iter() {
+            if *value == 0 {
+                error!(
+                    "Server configuration is invalid. Operational limit {} must be greater than 0",
+                    name
+                );
+                valid = false;
+            }
+        }
+        valid
+    }
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct ServerLimits {
     /// Indicates if clients are able to modify the address space through the node management service
@@ -143,6 +249,18 @@ pub struct ServerLimits {
     pub min_sampling_interval: f64,
     /// Specifies the minimum publishing interval for this server in seconds.
     pub min_publishing_interval: f64,
+    /// Maximum number of browse continuation points per session
+    #[serde(default = "ServerLimits::default_max_browse_continuation_points")]
+    pub max_browse_continuation_points: usize,
+    /// Maximum number of history continuation points per session
+    #[serde(default = "ServerLimits::default_max_history_continuation_points")]
+    pub max_history_continuation_points: usize,
+    /// Maximum number of query continuation points per session
+    #[serde(default = "ServerLimits::default_max_query_continuation_points")]
+    pub max_query_continuation_points: usize,
+    /// Operational limits, i.e. the maximum number of nodes / operations per service call
+    #[serde(default)]
+    pub operational: OperationalLimits,
 }
 
 impl Default for ServerLimits {
@@ -156,10 +274,65 @@ impl Default for ServerLimits {
             clients_can_modify_address_space: false,
             min_sampling_interval: constants::MIN_SAMPLING_INTERVAL,
             min_publishing_interval: constants::MIN_PUBLISHING_INTERVAL,
+            max_browse_continuation_points: constants::MAX_BROWSE_CONTINUATION_POINTS,
+            max_history_continuation_points: constants::MAX_HISTORY_CONTINUATION_POINTS,
+            max_query_continuation_points: constants::MAX_QUERY_CONTINUATION_POINTS,
+            operational: OperationalLimits::default(),
         }
     }
 }
 
+impl ServerLimits {
+    fn default_max_browse_continuation_points() -> usize {
+        constants::MAX_BROWSE_CONTINUATION_POINTS
+    }
+
+    fn default_max_history_continuation_points() -> usize {
+        constants::MAX_HISTORY_CONTINUATION_POINTS
+    }
+
+    fn default_max_query_continuation_points() -> usize {
+        constants::MAX_QUERY_CONTINUATION_POINTS
+    }
+}
+
+/// Saves nodes of the address space to a snapshot file so they survive a restart of the server,
+/// e.g. nodes that clients add through the node management service set.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct AddressSpaceSnapshotConfig {
+    /// Path to the snapshot file. If the file exists, it is restored when the server is created.
+    pub path: PathBuf,
+    /// Uris of the namespaces whose nodes are saved
+    pub namespaces: Vec<String>,
+    /// Interval in seconds between automatic saves while the server is running, 0 to only save
+    /// when the server stops
+    #[serde(default)]
+    pub auto_save_interval: u32,
+}
+
+/// Saves durable subscriptions, i.e. subscriptions that a client has made durable with the
+/// SetSubscriptionDurable method, to a file so a client can transfer them after the server restarts.
+/// Durable subscriptions are not supported without this configuration.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct DurableSubscriptionsConfig {
+    /// Path to the durable subscriptions file. If the file exists, it is restored when the server
+    /// is created.
+    pub path: PathBuf,
+    /// Maximum lifetime in hours that a client may give a durable subscription
+    #[serde(default = "DurableSubscriptionsConfig::default_max_lifetime_in_hours")]
+    pub max_lifetime_in_hours: u32,
+    /// Interval in seconds between automatic saves while the server is running, 0 to only save
+    /// when the server stops
+    #[serde(default)]
+    pub auto_save_interval: u32,
+}
+
+impl DurableSubscriptionsConfig {
+    fn default_max_lifetime_in_hours() -> u32 {
+        constants::DEFAULT_MAX_SUBSCRIPTION_LIFETIME_IN_HOURS
+    }
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct ServerEndpoint {
     /// Endpoint path
@@ -517,6 +690,29 @@ pub struct ServerConfig {
     pub default_endpoint: Option<String>,
     /// Endpoints supported by the server
     pub endpoints: BTreeMap<String, ServerEndpoint>,
+    /// Address space snapshot, if nodes should be persisted between runs of the server
+    #[serde(default)]
+    pub address_space_snapshot: Option<AddressSpaceSnapshotConfig>,
+    /// Durable subscriptions, if clients may make subscriptions that outlive their sessions
+    #[serde(default)]
+    pub durable_subscriptions: Option<DurableSubscriptionsConfig>,
+}
+
+/// Describes the outcome of reloading the configuration of a running server. Each entry is the
+/// name of a setting in the configuration file that differs between the old and new configuration.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ConfigReloadReport {
+    /// Settings which were applied to the running server
+    pub applied: Vec<String>,
+    /// Settings which have not been applied and only take effect when the server is restarted
+    pub restart_required: Vec<String>,
+}
+
+impl ConfigReloadReport {
+    /// Test if the new configuration was the same as the old one
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.restart_required.is_empty()
+    }
 }
 
 impl Config for ServerConfig {
@@ -562,10 +758,47 @@ impl Config for ServerConfig {
             error!("Server configuration is invalid. Max byte string length is invalid");
             valid = false;
         }
+        if self.limits.min_sampling_interval <= 0.0 {
+            error!("Server configuration is invalid. Min sampling interval is invalid");
+            valid = false;
+        }
+        if self.limits.min_publishing_interval <= 0.0 {
+            error!("Server configuration is invalid. Min publishing interval is invalid");
+            valid = false;
+        }
+        if self.limits.max_browse_continuation_points == 0 {
+            error!("Server configuration is invalid. Max browse continuation points is invalid");
+            valid = false;
+        }
+        if !self.limits.operational.is_valid() {
+            valid = false;
+        }
+        if self.locale_ids.is_empty() {
+            warn!("No locale ids were set");
+        }
         if self.discovery_urls.is_empty() {
             error!("Server configuration is invalid. Discovery urls not set");
             valid = false;
         }
+        if let Some(ref address_space_snapshot) = self.address_space_snapshot {
+            if address_space_snapshot.path.as_os_str().is_empty() {
+                error!("Server configuration is invalid. Address space snapshot path not set");
+                valid = false;
+            }
+            if address_space_snapshot.namespaces.is_empty() {
+                warn!("No namespaces were set for the address space snapshot");
+            }
+        }
+        if let Some(ref durable_subscriptions) = self.durable_subscriptions {
+            if durable_subscriptions.path.as_os_str().is_empty() {
+                error!("Server configuration is invalid. Durable subscriptions path not set");
+                valid = false;
+            }
+            if durable_subscriptions.max_lifetime_in_hours == 0 {
+                error!("Server configuration is invalid. Durable subscriptions max lifetime in hours is invalid");
+                valid = false;
+            }
+        }
         valid
     }
 
@@ -621,6 +854,8 @@ impl Default for ServerConfig {
             discovery_urls: Vec::new(),
             default_endpoint: None,
             endpoints: BTreeMap::new(),
+            address_space_snapshot: None,
+            durable_subscriptions: None,
         }
     }
 }
@@ -671,6 +906,8 @@ impl ServerConfig {
             discovery_urls,
             default_endpoint: None,
             endpoints,
+            address_space_snapshot: None,
+            durable_subscriptions: None,
         }
     }
 
@@ -693,6 +930,141 @@ impl ServerConfig {
             .for_each(|(_, token)| token.read_thumbprint());
     }
 
+    /// Compares this configuration to a new one and reports which settings differ, split into
+    /// those which can be applied to a running server and those which require a restart. The
+    /// new configuration should have had its x509 thumbprints read to compare user tokens.
+    pub fn diff(&self, new_config: &ServerConfig) -> ConfigReloadReport {
+        let mut report = ConfigReloadReport::default();
+        {
+            let mut changed = |setting: &str, is_changed: bool, restart_required: bool| {
+                if is_changed {
+                    if restart_required {
+                        report.restart_required.push(setting.to_string());
+                    } else {
+                        report.applied.push(setting.to_string());
+                    }
+                }
+            };
+
+            // The identity of the server, its listener and discovery registration are only
+            // set up when the server starts
+            changed(
+                "application_name",
+                self.application_name != new_config.application_name,
+                true,
+            );
+            changed(
+                "application_uri",
+                self.application_uri != new_config.application_uri,
+                true,
+            );
+            changed(
+                "product_uri",
+                self.product_uri != new_config.product_uri,
+                true,
+            );
+            changed(
+                "tcp_config.host",
+                self.tcp_config.host != new_config.tcp_config.host,
+                true,
+            );
+            changed(
+                "tcp_config.port",
+                self.tcp_config.port != new_config.tcp_config.port,
+                true,
+            );
+            changed(
+                "discovery_server_url",
+                self.discovery_server_url != new_config.discovery_server_url,
+                true,
+            );
+            changed(
+                "address_space_snapshot",
+                self.address_space_snapshot != new_config.address_space_snapshot,
+                true,
+            );
+            changed(
+                "durable_subscriptions",
+                self.durable_subscriptions != new_config.durable_subscriptions,
+                true,
+            );
+
+            // Everything else is applied live
+            changed(
+                "tcp_config.hello_timeout",
+                self.tcp_config.hello_timeout != new_config.tcp_config.hello_timeout,
+                false,
+            );
+            changed(
+                "create_sample_keypair",
+                self.create_sample_keypair != new_config.create_sample_keypair,
+                false,
+            );
+            changed(
+                "certificate_path",
+                self.certificate_path != new_config.certificate_path,
+                false,
+            );
+            changed(
+                "private_key_path",
+                self.private_key_path != new_config.private_key_path,
+                false,
+            );
+            changed(
+                "trust_client_certs",
+                self.trust_client_certs != new_config.trust_client_certs,
+                false,
+            );
+            changed("pki_dir", self.pki_dir != new_config.pki_dir, false);
+            changed("limits", self.limits != new_config.limits, false);
+            changed(
+                "locale_ids",
+                self.locale_ids != new_config.locale_ids,
+                false,
+            );
+            changed(
+                "user_tokens",
+                self.user_tokens != new_config.user_tokens,
+                false,
+            );
+            changed(
+                "discovery_urls",
+                self.discovery_urls != new_config.discovery_urls,
+                false,
+            );
+            changed(
+                "default_endpoint",
+                self.default_endpoint != new_config.default_endpoint,
+                false,
+            );
+            changed("endpoints", self.endpoints != new_config.endpoints, false);
+        }
+        report
+    }
+
+    /// Tests if the new configuration uses a different certificate store or server keypair
+    pub(crate) fn certificate_store_changed(&self, new_config: &ServerConfig) -> bool {
+        self.create_sample_keypair != new_config.create_sample_keypair
+            || self.certificate_path != new_config.certificate_path
+            || self.private_key_path != new_config.private_key_path
+            || self.trust_client_certs != new_config.trust_client_certs
+            || self.pki_dir != new_config.pki_dir
+    }
+
+    /// Copies the settings that cannot change on a running server from this configuration into
+    /// the new configuration, so the server's configuration stays consistent with what it is
+    /// actually doing until it is restarted.
+    pub(crate) fn retain_restart_settings(&self, new_config: &mut ServerConfig) {
+        new_config.application_name = self.application_name.clone();
+        new_config.application_uri = self.application_uri.clone();
+        new_config.product_uri = self.product_uri.clone();
+        new_config.tcp_config.host = self.tcp_config.host.clone();
+        new_config.tcp_config.port = self.tcp_config.port;
+        new_config.discovery_server_url = self.discovery_server_url.clone();
+        new_config.address_space_snapshot = self.address_space_snapshot.clone();
+        new_config.durable_subscriptions = self.durable_subscriptions.clone();
+    }
+
     /// Returns a opc.tcp://server:port url that paths can be appended onto
     pub fn base_endpoint_url(&self) -> String {
         format!(
@@ -49,6 +49,24 @@ impl Event for AuditCancelEventType {
 audit_session_event_impl!(AuditCancelEventType, base);
 
 impl AuditCancelEventType {
+    pub fn new<R>(node_id: R, time: DateTime) -> Self
+    where
+        R: Into<NodeId>,
+    {
+        let event_type_id = ObjectTypeId::AuditCancelEventType;
+        Self {
+            base: AuditSessionEventType::new(
+                node_id,
+                event_type_id,
+                "AuditCancelEventType",
+                "AuditCancelEventType",
+                time,
+            ),
+            request_handle: 0,
+        }
+        .source_name("Session/Cancel")
+    }
+
     pub fn request_handle(mut self, request_handle: u32) -> Self {
         self.request_handle = request_handle;
         self
@@ -2,34 +2,34 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-//! Contains functions for generating events and adding them to the address space of the server.
+//! Contains functions for generating events and raising them onto the event bus of the server.
+use std::collections::HashSet;
+
+#[cfg(test)]
+use opcua_types::ObjectTypeId;
 use opcua_types::{
-    service_types::TimeZoneDataType, AttributeId, ByteString, DataTypeId, DateTime, DateTimeUtc,
-    ExtensionObject, Guid, LocalizedText, NodeId, NumericRange, ObjectId, ObjectTypeId,
-    QualifiedName, TimestampsToReturn, UAString, VariableTypeId, Variant,
+    service_types::TimeZoneDataType, ByteString, DateTime, DateTimeUtc, ExtensionObject, Guid,
+    LocalizedText, NodeId, ObjectId, QualifiedName, ReferenceTypeId, UAString, Variant,
 };
 
-use crate::address_space::{
-    object::ObjectBuilder, relative_path::*, variable::VariableBuilder, AddressSpace,
-};
+use crate::{address_space::AddressSpace, events::event_bus::RaisedEvent};
 
-/// Events can implement this to populate themselves into the address space
+/// Events can implement this to raise themselves onto the event bus of the address space
 pub trait Event {
     type Err;
 
     /// Tests if the event is valid
     fn is_valid(&self) -> bool;
 
-    /// Raises the event, i.e. adds the object into the address space. The event must be valid to be inserted.
+    /// Raises the event, i.e. adds its fields to the event bus of the address space where monitored
+    /// items receive it. The event must be valid to be raised.
     fn raise(&mut self, address_space: &mut AddressSpace) -> Result<NodeId, Self::Err>;
 }
 
 /// This corresponds to BaseEventType definition in OPC UA Part 5
 pub struct BaseEventType {
-    /// Node id
+    /// Node id that identifies the event
     node_id: NodeId,
-    /// Parent node
-    parent_node: NodeId,
     /// Browse name
     browse_name: QualifiedName,
     /// Display name
@@ -56,6 +56,8 @@ pub struct BaseEventType {
     /// Message provides a human readable localizable text description
     /// of the event.
     message: LocalizedText,
+    /// Translations of the message into other locales
+    message_translations: Vec<LocalizedText>,
     /// Severity is an indication of the urgency of the event. Values from 1 to 1000, with 1 as the lowest
     /// severity and 1000 being the highest. A value of 1000 would indicate an event of catastrophic nature.
     ///
@@ -83,96 +85,79 @@ impl Event for BaseEventType {
     }
 
     fn raise(&mut self, address_space: &mut AddressSpace) -> Result<NodeId, Self::Err> {
-        if self.is_valid() {
-            // create an event object in a folder with the
-            let ns = self.node_id.namespace;
+        if !self.is_valid() {
+            error!("Event is invalid and will not be raised");
+            Err(())
+        } else {
             let node_id = self.node_id.clone();
-
-            let object_builder = ObjectBuilder::new(
-                &self.node_id,
+            let event = RaisedEvent::new(
+                &node_id,
                 self.browse_name.clone(),
                 self.display_name.clone(),
-            )
-            .organized_by(self.parent_node.clone())
-            .has_type_definition(self.event_type.clone());
-
-            let object_builder = if !self.source_node.is_null() {
-                object_builder.has_event_source(self.source_node.clone())
-            } else {
-                object_builder
-            };
-            object_builder.insert(address_space);
+                &self.event_type,
+                &self.source_node,
+                self.time.clone(),
+            );
+            if !address_space.events_mut().push(event) {
+                return Err(());
+            } else if address_space.events().capacity() == 0 {
+                // The bus discarded the event so there are no fields to set
+                return Ok(node_id);
+            }
 
             // Mandatory properties
-            self.add_property(
+            self.add_field(
                 &node_id,
-                NodeId::next_numeric(ns),
                 "EventId",
                 "EventId",
-                DataTypeId::ByteString,
                 self.event_id.clone(),
                 address_space,
             );
-            self.add_property(
+            self.add_field(
                 &node_id,
-                NodeId::next_numeric(ns),
                 "EventType",
                 "EventType",
-                DataTypeId::NodeId,
                 self.event_type.clone(),
                 address_space,
             );
-            self.add_property(
+            self.add_field(
                 &node_id,
-                NodeId::next_numeric(ns),
                 "SourceNode",
                 "SourceNode",
-                DataTypeId::NodeId,
                 self.source_node.clone(),
                 address_space,
             );
-            self.add_property(
+            self.add_field(
                 &node_id,
-                NodeId::next_numeric(ns),
                 "SourceName",
                 "SourceName",
-                DataTypeId::String,
                 self.source_name.clone(),
                 address_space,
             );
-            self.add_property(
-                &node_id,
-                NodeId::next_numeric(ns),
-                "Time",
-                "Time",
-                DataTypeId::UtcTime,
-                self.time.clone(),
-                address_space,
-            );
-            self.add_property(
+            self.add_field(&node_id, "Time", "Time", self.time.clone(), address_space);
+            self.add_field(
                 &node_id,
-                NodeId::next_numeric(ns),
                 "ReceiveTime",
                 "ReceiveTime",
-                DataTypeId::UtcTime,
                 self.receive_time.clone(),
                 address_space,
             );
-            self.add_property(
+            self.add_field(
                 &node_id,
-                NodeId::next_numeric(ns),
                 "Message",
                 "Message",
-                DataTypeId::LocalizedText,
                 self.message.clone(),
                 address_space,
             );
-            self.add_property(
+            if let Some(event) = address_space.events_mut().find_mut(&node_id) {
+                self.message_translations
+                    .iter()
+                    .for_each(|t| event.add_field_translation(vec!["Message".into()], t.clone()));
+            }
+            self.add_field(
                 &node_id,
-                NodeId::next_numeric(ns),
                 "Severity",
                 "Severity",
-                DataTypeId::UInt16,
                 self.severity,
                 address_space,
             );
@@ -184,21 +169,16 @@ impl Event for BaseEventType {
                     ObjectId::TimeZoneDataType_Encoding_DefaultBinary,
                     local_time,
                 );
-                self.add_property(
+                self.add_field(
                     &node_id,
-                    NodeId::next_numeric(ns),
                     "LocalTime",
                     "LocalTime",
-                    DataTypeId::TimeZoneDataType,
                     local_time,
                     address_space,
                 );
             }
 
             Ok(node_id)
-        } else {
-            error!("Event is invalid and will not be inserted");
-            Err(())
         }
     }
 }
@@ -209,7 +189,7 @@ impl BaseEventType {
         event_type_id: E,
         browse_name: S,
         display_name: T,
-        parent_node: U,
+        _parent_node: U,
     ) -> Self
     where
         R: Into<NodeId>,
@@ -224,17 +204,19 @@ impl BaseEventType {
             event_type_id,
             browse_name,
             display_name,
-            parent_node,
+            NodeId::null(),
             now,
         )
     }
 
+    /// Creates an event of the type. The parent node is not used because events are held by the
+    /// event bus rather than organized beneath a node of the address space.
     pub fn new<R, E, S, T, U>(
         node_id: R,
         event_type_id: E,
         browse_name: S,
         display_name: T,
-        parent_node: U,
+        _parent_node: U,
         time: DateTime,
     ) -> Self
     where
@@ -248,7 +230,6 @@ impl BaseEventType {
             node_id: node_id.into(),
             browse_name: browse_name.into(),
             display_name: display_name.into(),
-            parent_node: parent_node.into(),
             event_id: Guid::new().into(),
             event_type: event_type_id.into(),
             source_node: NodeId::null(),
@@ -257,19 +238,22 @@ impl BaseEventType {
             receive_time: time,
             local_time: None,
             message: LocalizedText::null(),
+            message_translations: Vec::new(),
             severity: 1,
             properties: Vec::with_capacity(20),
         }
     }
 
-    /// Add a property to the event object
+    /// Add a property to the event. The property is held as a field of the event whose browse
+    /// path is the browse name. The property id and data type are not used because the property
+    /// is not inserted into the address space as a node.
     pub fn add_property<T, R, S, U, V>(
         &mut self,
         event_id: &NodeId,
-        property_id: T,
+        _property_id: T,
         browse_name: R,
         display_name: S,
-        data_type: U,
+        _data_type: U,
         value: V,
         address_space: &mut AddressSpace,
     ) where
@@ -279,43 +263,33 @@ impl BaseEventType {
         U: Into<NodeId>,
         V: Into<Variant>,
     {
-        let display_name = display_name.into();
-        let value = value.into();
-        self.properties.push((display_name.clone(), value.clone()));
-
-        Self::do_add_property(
-            event_id,
-            property_id,
-            browse_name,
-            display_name,
-            data_type,
-            value,
-            address_space,
-        )
+        self.add_field(event_id, browse_name, display_name, value, address_space)
     }
 
-    /// Helper function inserts a property for the event
-    fn do_add_property<T, R, S, U, V>(
+    /// Helper function sets a field of the raised event
+    fn add_field<R, S, V>(
+        &mut self,
         event_id: &NodeId,
-        property_id: T,
         browse_name: R,
         display_name: S,
-        data_type: U,
         value: V,
         address_space: &mut AddressSpace,
     ) where
-        T: Into<NodeId>,
         R: Into<QualifiedName>,
         S: Into<LocalizedText>,
-        U: Into<NodeId>,
         V: Into<Variant>,
     {
-        VariableBuilder::new(&property_id.into(), browse_name, display_name)
-            .property_of(event_id.clone())
-            .has_type_definition(VariableTypeId::PropertyType)
-            .data_type(data_type)
-            .value(value)
-            .insert(address_space);
+        let value = value.into();
+        self.properties.push((display_name.into(), value.clone()));
+        if let Some(event) = address_space.events_mut().find_mut(event_id) {
+            event.set_field(vec![browse_name.into()], value);
+        } else if address_space.events().capacity() > 0 {
+            // An event bus without capacity discards every event so there is nothing to set
+            error!(
+                "Event {} has not been raised so the field cannot be set",
+                event_id
+            );
+        }
     }
 
     pub fn message<T>(mut self, message: T) -> Self
@@ -326,6 +300,15 @@ impl BaseEventType {
         self
     }
 
+    /// Adds a translation of the message in another locale
+    pub fn message_translation<T>(mut self, message: T) -> Self
+    where
+        T: Into<LocalizedText>,
+    {
+        self.message_translations.push(message.into());
+        self
+    }
+
     pub fn source_node<T>(mut self, source_node: T) -> Self
     where
         T: Into<NodeId>,
@@ -402,6 +385,14 @@ macro_rules! base_event_impl {
                 self
             }
 
+            pub fn message_translation<T>(mut self, message: T) -> $event
+            where
+                T: Into<LocalizedText>,
+            {
+                self.$base = self.$base.message_translation(message);
+                self
+            }
+
             pub fn source_node<T>(mut self, source_node: T) -> $event
             where
                 T: Into<NodeId>,
@@ -436,58 +427,25 @@ macro_rules! base_event_impl {
     };
 }
 
-fn event_source_node(event_id: &NodeId, address_space: &AddressSpace) -> Option<NodeId> {
-    if let Ok(event_time_node) =
-        find_node_from_browse_path(address_space, event_id, &["SourceNode".into()])
-    {
-        if let Some(value) = event_time_node.as_node().get_attribute(
-            TimestampsToReturn::Neither,
-            AttributeId::Value,
-            NumericRange::None,
-            &QualifiedName::null(),
-        ) {
-            if let Some(value) = value.value {
-                match value {
-                    Variant::NodeId(node_id) => Some(*node_id),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        }
-    } else {
+/// Collects the node ids of the events that match the predicate, or `None` if none match
+fn matching_events<'a, I, P>(events: I, predicate: P) -> Option<Vec<NodeId>>
+where
+    I: Iterator<Item = &'a RaisedEvent>,
+    P: Fn(&RaisedEvent) -> bool,
+{
+    let event_ids = events
+        .filter(|event| predicate(event))
+        .map(|event| event.node_id().clone())
+        .collect::<Vec<NodeId>>();
+    if event_ids.is_empty() {
         None
-    }
-}
-
-fn event_time(event_id: &NodeId, address_space: &AddressSpace) -> Option<DateTime> {
-    if let Ok(event_time_node) =
-        find_node_from_browse_path(address_space, event_id, &["Time".into()])
-    {
-        if let Some(value) = event_time_node.as_node().get_attribute(
-            TimestampsToReturn::Neither,
-            AttributeId::Value,
-            NumericRange::None,
-            &QualifiedName::null(),
-        ) {
-            if let Some(value) = value.value {
-                match value {
-                    Variant::DateTime(date_time) => Some(*date_time),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        }
     } else {
-        None
+        Some(event_ids)
     }
 }
 
+/// Finds the events of the event type, or its subtypes, that originated from the source object
+/// and whose time satisfies the time predicate
 pub fn filter_events<T, R, F>(
     source_object_id: T,
     event_type_id: R,
@@ -499,38 +457,17 @@ where
     R: Into<NodeId>,
     F: Fn(&DateTimeUtc) -> bool,
 {
-    let event_type_id = event_type_id.into();
     let source_object_id = source_object_id.into();
-    // Find events of type event_type_id
-    if let Some(events) = address_space.find_objects_by_type(event_type_id, true) {
-        let event_ids = events
-            .iter()
-            .filter(move |event_id| {
-                let mut filter = false;
-                // Browse the relative path for the "Time" variable
-                if let Some(event_time) = event_time(event_id, address_space) {
-                    // Filter on those happened since the time
-                    if time_predicate(&event_time.as_chrono()) {
-                        if let Some(source_node) = event_source_node(event_id, address_space) {
-                            // Whose source node is source_object_id
-                            filter = source_node == source_object_id
-                        }
-                    }
-                }
-                filter
-            })
-            .cloned()
-            .collect::<Vec<NodeId>>();
-        if event_ids.is_empty() {
-            None
-        } else {
-            Some(event_ids)
-        }
-    } else {
-        None
-    }
+    let event_type_id = event_type_id.into();
+    matching_events(address_space.events().iter(), |event| {
+        event.source_node() == Some(&source_object_id)
+            && address_space.is_subtype(event.event_type_id(), &event_type_id)
+            && time_predicate(&event.time().as_chrono())
+    })
 }
 
+/// Removes the events of the event type from the source object that happened before the time,
+/// returning the number removed
 pub fn purge_events<T, R>(
     source_object_id: T,
     event_type_id: R,
@@ -547,34 +484,64 @@ where
         address_space,
         move |event_time| event_time < happened_before,
     ) {
-        // Delete these events from the address space
-        info!("Deleting some events from the address space");
-        let len = events.len();
-        events.into_iter().for_each(|node_id| {
-            debug!("Deleting event {}", node_id);
-            address_space.delete(&node_id, true);
-        });
-        len
+        // Remove these events from the event bus
+        info!("Purging some events from the event bus");
+        let events = events.into_iter().collect::<HashSet<NodeId>>();
+        address_space
+            .events_mut()
+            .remove_where(|event| events.contains(event.node_id()))
     } else {
         0
     }
 }
 
-/// Searches for events of the specified event type which reference the source object
+/// Finds the event sources of a notifier, i.e. the notifier itself and every node beneath it that
+/// is reached by following `HasEventSource` references, or subtypes such as `HasNotifier`.
+pub fn event_sources_of<T>(notifier_id: T, address_space: &AddressSpace) -> HashSet<NodeId>
+where
+    T: Into<NodeId>,
+{
+    let notifier_id = notifier_id.into();
+    let mut event_sources = HashSet::new();
+    event_sources.insert(notifier_id.clone());
+    let mut to_visit = vec![notifier_id];
+    while let Some(node_id) = to_visit.pop() {
+        if let Some(references) =
+            address_space.find_references(&node_id, Some((ReferenceTypeId::HasEventSource, true)))
+        {
+            references.into_iter().for_each(|r| {
+                if event_sources.insert(r.target_node.clone()) {
+                    to_visit.push(r.target_node);
+                }
+            });
+        }
+    }
+    event_sources
+}
+
+/// Searches for the events raised after the sequence number which a monitored item on the
+/// notifier object receives. These are the events from the notifier and from the event sources
+/// beneath it. The `Server` object is the root notifier of the server so it receives every event.
 pub fn events_for_object<T>(
     source_object_id: T,
     address_space: &AddressSpace,
-    happened_since: &DateTimeUtc,
+    last_sequence: u64,
 ) -> Option<Vec<NodeId>>
 where
     T: Into<NodeId>,
 {
-    filter_events(
-        source_object_id,
-        ObjectTypeId::BaseEventType,
-        address_space,
-        move |event_time| event_time >= happened_since,
-    )
+    let source_object_id = source_object_id.into();
+    let events = address_space.events().events_since(last_sequence);
+    if source_object_id == NodeId::from(ObjectId::Server) {
+        matching_events(events, |_| true)
+    } else {
+        let event_sources = event_sources_of(source_object_id, address_space);
+        matching_events(events, |event| {
+            event
+                .source_node()
+                .map_or(false, |s| event_sources.contains(s))
+        })
+    }
 }
 
 #[test]
@@ -594,10 +561,13 @@ fn test_event_source_node() {
     )
     .source_node(ObjectId::Server_ServerCapabilities);
     assert!(event.raise(&mut address_space).is_ok());
-    // Check that the helper fn returns the expected source node
+    // Check that the event holds the expected source node
+    let event = address_space.events().find(&event_id).unwrap();
+    let source_node = NodeId::from(ObjectId::Server_ServerCapabilities);
+    assert_eq!(event.source_node().unwrap(), &source_node);
     assert_eq!(
-        event_source_node(&event_id, &address_space).unwrap(),
-        ObjectId::Server_ServerCapabilities.into()
+        event.field(&["SourceNode".into()]).unwrap(),
+        &Variant::from(source_node)
     );
 }
 
@@ -619,10 +589,12 @@ fn test_event_time() {
     .source_node(ObjectId::Server_ServerCapabilities);
     let expected_time = event.time.clone();
     assert!(event.raise(&mut address_space).is_ok());
-    // Check that the helper fn returns the expected source node
+    // Check that the event holds the expected time
+    let event = address_space.events().find(&event_id).unwrap();
+    assert_eq!(event.time(), &expected_time);
     assert_eq!(
-        event_time(&event_id, &address_space).unwrap(),
-        expected_time
+        event.field(&["Time".into()]).unwrap(),
+        &Variant::from(expected_time)
     );
 }
 
@@ -632,7 +604,7 @@ fn test_events_for_object() {
     let ns = address_space.register_namespace("urn:test").unwrap();
 
     // Raise an event
-    let happened_since = chrono::Utc::now();
+    let last_sequence = address_space.events().last_sequence();
     let event_id = NodeId::next_numeric(ns);
     let event_type_id = ObjectTypeId::BaseEventType;
     let mut event = BaseEventType::new(
@@ -650,44 +622,44 @@ fn test_events_for_object() {
     let mut events = events_for_object(
         ObjectId::Server_ServerCapabilities,
         &address_space,
-        &happened_since,
+        last_sequence,
     )
     .unwrap();
     assert_eq!(events.len(), 1);
     assert_eq!(events.pop().unwrap(), event_id);
+
+    // The event is not found again after it has been received
+    assert!(events_for_object(
+        ObjectId::Server_ServerCapabilities,
+        &address_space,
+        address_space.events().last_sequence(),
+    )
+    .is_none());
+
+    // The event is not a node in the address space
+    assert!(address_space.find_node(&event_id).is_none());
 }
 
 #[test]
 fn test_purge_events() {
     use opcua_console_logging;
-    use opcua_types::Identifier;
 
     opcua_console_logging::init();
 
     let mut address_space = AddressSpace::new();
 
-    // Nodes will be created in this namespace
+    // Events will be identified by nodes in this namespace
     let ns = address_space.register_namespace("urn:mynamespace").unwrap();
 
     // This test is going to raise a bunch of events and then purge some of them. The purged
     // events should be the ones expected to be purged and there should be no trace of them
-    // in the address space after they are removed.
-
-    // Raising events will create bunch of numeric node ids for their properties. This
-    // call will find out the node id that the first node is most likely to have (note that if
-    // tests are run concurrently that use next_numeric() then they are not going to belong to this
-    // test but that does not matter.
-    let first_node_id = match NodeId::next_numeric(ns).identifier {
-        Identifier::Numeric(i) => i + 1,
-        _ => panic!(),
-    };
+    // on the event bus after they are removed.
 
     let source_node = ObjectId::Server_ServerCapabilities;
 
     // Raise a bunch of events
     let start_time = DateTime::now().as_chrono();
     let mut time = start_time.clone();
-    let mut last_purged_node_id = 0;
 
     let event_type_id = ObjectTypeId::BaseEventType;
 
@@ -704,21 +676,11 @@ fn test_purge_events() {
         )
         .source_node(source_node);
         assert!(event.raise(&mut address_space).is_ok());
-
-        // The first 5 events will be purged, so note the last node id here because none of the
-        // ids between start and end should survive when tested.
-        if i == 4 {
-            last_purged_node_id = match NodeId::next_numeric(ns).identifier {
-                Identifier::Numeric(i) => i,
-                _ => panic!(),
-            };
-        }
-
         time = time + chrono::Duration::minutes(5);
     });
 
     // Expect all events
-    let events = events_for_object(source_node, &address_space, &start_time).unwrap();
+    let events = events_for_object(source_node, &address_space, 0).unwrap();
     assert_eq!(events.len(), 10);
 
     // Purge all events up to halfway
@@ -734,33 +696,82 @@ fn test_purge_events() {
     );
 
     // Should have only 5 events left
-    let events = events_for_object(source_node, &address_space, &start_time).unwrap();
+    let events = events_for_object(source_node, &address_space, 0).unwrap();
     assert_eq!(events.len(), 5);
+    assert_eq!(address_space.events().len(), 5);
 
-    // There should be NO reference left to any of the events we purged in the address space
-    let references = address_space.references();
+    // The purged events should be gone and the rest should remain
     (0..5).for_each(|i| {
         let event_id = NodeId::new(ns, format!("Event{}", i));
-        assert!(!references.reference_to_node_exists(&event_id));
+        assert!(address_space.events().find(&event_id).is_none());
     });
     (5..10).for_each(|i| {
         let event_id = NodeId::new(ns, format!("Event{}", i));
-        assert!(references.reference_to_node_exists(&event_id));
+        assert!(address_space.events().find(&event_id).is_some());
     });
 
     // The node that generated the events should not be purged
-    // This was a bug during development
     let source_node: NodeId = source_node.into();
-    debug!("Expecting to still find source node {}", source_node);
     assert!(address_space.find_node(&source_node).is_some());
+}
 
-    // All of properties that were created for purged nodes fall between first and last node id.
-    // None of the properties should exist now either - just scan over the range of numbers these
-    // nodes reside in.
-    (first_node_id..last_purged_node_id).for_each(|i| {
-        // Event properties were numerically assigned from the NS
-        let node_id = NodeId::new(ns, i);
-        assert!(address_space.find_node(&node_id).is_none());
-        assert!(!references.reference_to_node_exists(&node_id));
-    });
+#[test]
+fn test_event_bus_is_bounded() {
+    let mut address_space = AddressSpace::new();
+    let ns = address_space.register_namespace("urn:test").unwrap();
+    address_space.events_mut().set_capacity(3);
+
+    let event_ids = (0..5)
+        .map(|i| {
+            let event_id = NodeId::new(ns, format!("Event{}", i));
+            let mut event = BaseEventType::new_now(
+                &event_id,
+                ObjectTypeId::BaseEventType,
+                "Event",
+                "",
+                NodeId::null(),
+            )
+            .source_node(ObjectId::Server);
+            assert!(event.raise(&mut address_space).is_ok());
+            event_id
+        })
+        .collect::<Vec<NodeId>>();
+
+    // The oldest events were discarded to make room for the newest
+    let events = address_space.events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events.last_sequence(), 5);
+    assert!(events.find(&event_ids[1]).is_none());
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| event.node_id().clone())
+            .collect::<Vec<NodeId>>(),
+        event_ids[2..].to_vec()
+    );
+
+    // An event cannot be raised twice
+    let mut event = BaseEventType::new_now(
+        &event_ids[4],
+        ObjectTypeId::BaseEventType,
+        "Event",
+        "",
+        NodeId::null(),
+    );
+    assert!(event.raise(&mut address_space).is_err());
+
+    // An event bus without capacity discards the event but raising it still succeeds
+    address_space.events_mut().set_capacity(0);
+    let event_id = NodeId::new(ns, "Event5");
+    let mut event = BaseEventType::new_now(
+        &event_id,
+        ObjectTypeId::BaseEventType,
+        "Event",
+        "",
+        NodeId::null(),
+    )
+    .source_node(ObjectId::Server);
+    assert_eq!(event.raise(&mut address_space), Ok(event_id.clone()));
+    assert!(address_space.events().is_empty());
+    assert!(address_space.events().find(&event_id).is_none());
 }
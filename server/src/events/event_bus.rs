@@ -0,0 +1,279 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the event bus which holds raised events until monitored items have received them.
+//! Events are held as the values of their fields rather than as nodes in the address space.
+use std::collections::{BTreeMap, HashMap};
+
+use opcua_types::{DateTime, LocalizedText, NodeId, QualifiedName, UAString, Variant};
+
+use crate::{address_space::base, constants};
+
+/// An event that has been raised. Each field of the event is identified by its browse path from
+/// the event type, e.g. `Message` or `EnabledState/Id`.
+#[derive(Debug, Clone)]
+pub struct RaisedEvent {
+    /// The position of the event in the order that events were raised, assigned by the bus
+    sequence: u64,
+    /// Node id that identifies the event
+    node_id: NodeId,
+    /// Browse name of the event
+    browse_name: QualifiedName,
+    /// Display name of the event
+    display_name: LocalizedText,
+    /// The type of the event
+    event_type_id: NodeId,
+    /// The node that the event originated from, or null
+    source_node: NodeId,
+    /// The time the event occurred
+    time: DateTime,
+    /// The values of the fields by browse path
+    fields: HashMap<Vec<QualifiedName>, Variant>,
+    /// Translations of `LocalizedText` fields into other locales
+    translations: HashMap<Vec<QualifiedName>, Vec<LocalizedText>>,
+}
+
+impl RaisedEvent {
+    pub fn new<R, S, T, E, U>(
+        node_id: R,
+        browse_name: S,
+        display_name: T,
+        event_type_id: E,
+        source_node: U,
+        time: DateTime,
+    ) -> RaisedEvent
+    where
+        R: Into<NodeId>,
+        S: Into<QualifiedName>,
+        T: Into<LocalizedText>,
+        E: Into<NodeId>,
+        U: Into<NodeId>,
+    {
+        RaisedEvent {
+            sequence: 0,
+            node_id: node_id.into(),
+            browse_name: browse_name.into(),
+            display_name: display_name.into(),
+            event_type_id: event_type_id.into(),
+            source_node: source_node.into(),
+            time,
+            fields: HashMap::new(),
+            translations: HashMap::new(),
+        }
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    pub fn browse_name(&self) -> &QualifiedName {
+        &self.browse_name
+    }
+
+    pub fn display_name(&self) -> &LocalizedText {
+        &self.display_name
+    }
+
+    pub fn event_type_id(&self) -> &NodeId {
+        &self.event_type_id
+    }
+
+    /// Returns the node the event originated from, or `None` if it has no source node
+    pub fn source_node(&self) -> Option<&NodeId> {
+        if self.source_node.is_null() {
+            None
+        } else {
+            Some(&self.source_node)
+        }
+    }
+
+    pub fn time(&self) -> &DateTime {
+        &self.time
+    }
+
+    /// Sets the value of the field at the browse path, replacing any existing value
+    pub fn set_field<V>(&mut self, browse_path: Vec<QualifiedName>, value: V)
+    where
+        V: Into<Variant>,
+    {
+        self.fields.insert(browse_path, value.into());
+    }
+
+    /// Adds a translation of a `LocalizedText` field in another locale
+    pub fn add_field_translation(&mut self, browse_path: Vec<QualifiedName>, text: LocalizedText) {
+        let translations = self
+            .translations
+            .entry(browse_path)
+            .or_insert_with(Vec::new);
+        base::add_translation(translations, text);
+    }
+
+    /// Returns the value of the field at the browse path
+    pub fn field(&self, browse_path: &[QualifiedName]) -> Option<&Variant> {
+        self.fields.get(browse_path)
+    }
+
+    /// Returns the value of the field at the browse path. A `LocalizedText` value is chosen from
+    /// the translations that best match the locale ids, which are in order of preference.
+    pub fn localized_field(
+        &self,
+        browse_path: &[QualifiedName],
+        locale_ids: &[UAString],
+    ) -> Option<Variant> {
+        self.field(browse_path).map(|value| match value {
+            Variant::LocalizedText(ref text) => {
+                if let Some(translations) = self.translations.get(browse_path) {
+                    base::best_translation(text, translations, locale_ids).into()
+                } else {
+                    value.clone()
+                }
+            }
+            value => value.clone(),
+        })
+    }
+}
+
+/// The event bus holds the events that have been raised, oldest first, until monitored items have
+/// received them. The bus is bounded so when it is full the oldest event is discarded to make
+/// room for a new one.
+pub struct EventBus {
+    /// The maximum number of events held
+    capacity: usize,
+    /// The sequence number given to the last event raised
+    last_sequence: u64,
+    /// Events by sequence number
+    events: BTreeMap<u64, RaisedEvent>,
+    /// The sequence number of each event by its node id
+    index: HashMap<NodeId, u64>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(constants::MAX_EVENT_QUEUE_SIZE)
+    }
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> EventBus {
+        EventBus {
+            capacity,
+            last_sequence: 0,
+            events: BTreeMap::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of events held, discarding the oldest events if there are more
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.events.len() > self.capacity {
+            self.discard_oldest();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the sequence number of the last event raised, or 0 if no event has been raised.
+    /// Events raised later have higher sequence numbers.
+    pub fn last_sequence(&self) -> u64 {
+        self.last_sequence
+    }
+
+    /// Raises the event onto the bus, returning false if an event with the same node id is
+    /// already held.
+    pub fn push(&mut self, mut event: RaisedEvent) -> bool {
+        if self.index.contains_key(&event.node_id) {
+            error!("Event {} has already been raised", event.node_id);
+            false
+        } else if self.capacity == 0 {
+            debug!(
+                "Event bus has no capacity so event {} is discarded",
+                event.node_id
+            );
+            true
+        } else {
+            if self.events.len() >= self.capacity {
+                self.discard_oldest();
+            }
+            self.last_sequence += 1;
+            event.sequence = self.last_sequence;
+            self.index.insert(event.node_id.clone(), event.sequence);
+            self.events.insert(event.sequence, event);
+            true
+        }
+    }
+
+    fn discard_oldest(&mut self) {
+        if let Some(sequence) = self.events.keys().next().cloned() {
+            if let Some(event) = self.events.remove(&sequence) {
+                debug!(
+                    "Event bus is full so oldest event {} is discarded",
+                    event.node_id
+                );
+                self.index.remove(&event.node_id);
+            }
+        }
+    }
+
+    /// Finds the event with the node id
+    pub fn find(&self, node_id: &NodeId) -> Option<&RaisedEvent> {
+        self.index
+            .get(node_id)
+            .and_then(|sequence| self.events.get(sequence))
+    }
+
+    /// Finds the event with the node id for modification, e.g. to set the fields of an event type
+    /// after its base type has raised it.
+    pub fn find_mut(&mut self, node_id: &NodeId) -> Option<&mut RaisedEvent> {
+        if let Some(sequence) = self.index.get(node_id) {
+            self.events.get_mut(sequence)
+        } else {
+            None
+        }
+    }
+
+    /// Iterates the events from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &RaisedEvent> {
+        self.events.values()
+    }
+
+    /// Iterates the events raised after the one with the sequence number, from oldest to newest
+    pub fn events_since(&self, sequence: u64) -> impl Iterator<Item = &RaisedEvent> {
+        self.events.range((sequence + 1)..).map(|(_, event)| event)
+    }
+
+    /// Removes the events that match the predicate, returning the number removed
+    pub fn remove_where<F>(&mut self, predicate: F) -> usize
+    where
+        F: Fn(&RaisedEvent) -> bool,
+    {
+        let sequences = self
+            .events
+            .values()
+            .filter(|event| predicate(event))
+            .map(|event| event.sequence)
+            .collect::<Vec<u64>>();
+        sequences.iter().for_each(|sequence| {
+            if let Some(event) = self.events.remove(sequence) {
+                debug!("Removing event {}", event.node_id);
+                self.index.remove(&event.node_id);
+            }
+        });
+        sequences.len()
+    }
+}
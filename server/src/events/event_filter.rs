@@ -7,11 +7,11 @@ use std::convert::TryFrom;
 use opcua_types::{
     operand::Operand,
     service_types::{
-        ContentFilter, ContentFilterElementResult, ContentFilterResult, EventFieldList,
-        EventFilter, EventFilterResult, FilterOperator, SimpleAttributeOperand,
+        ContentFilter, ContentFilterElement, ContentFilterElementResult, ContentFilterResult,
+        EventFieldList, EventFilter, EventFilterResult, FilterOperator, SimpleAttributeOperand,
     },
     status_code::StatusCode,
-    AttributeId, DateTimeUtc, NodeId, Variant,
+    AttributeId, NodeId, Variant, VariantTypeId,
 };
 
 use crate::{
@@ -43,15 +43,17 @@ pub fn validate(
     })
 }
 
-/// Evaluate the event filter and see if it triggers.
+/// Evaluate the event filter against the events raised after the sequence number and see if it
+/// triggers.
 pub fn evaluate(
     object_id: &NodeId,
     event_filter: &EventFilter,
     address_space: &AddressSpace,
-    happened_since: &DateTimeUtc,
+    last_sequence: u64,
     client_handle: u32,
+    locale_ids: &[UAString],
 ) -> Option<Vec<EventFieldList>> {
-    if let Some(events) = events_for_object(object_id, address_space, happened_since) {
+    if let Some(events) = events_for_object(object_id, address_space, last_sequence) {
         let event_fields = events
             .iter()
             .filter(|event_id| {
@@ -70,7 +72,12 @@ pub fn evaluate(
                         select_clauses
                             .iter()
                             .map(|v| {
-                                operator::value_of_simple_attribute(event_id, v, address_space)
+                                operator::value_of_simple_attribute(
+                                    event_id,
+                                    v,
+                                    address_space,
+                                    locale_ids,
+                                )
                             })
                             .collect(),
                     )
@@ -177,6 +184,72 @@ fn validate_select_clause(
     }
 }
 
+/// Validates the operands of OfType, InView and RelatedTo which must identify nodes of a
+/// particular node class. Operands of other operators are not checked here.
+fn validate_node_operand(
+    filter_operator: FilterOperator,
+    index: usize,
+    operand: &Operand,
+    elements: &[ContentFilterElement],
+    address_space: &AddressSpace,
+) -> StatusCode {
+    let literal = if let Operand::LiteralOperand(ref o) = operand {
+        Some(&o.value)
+    } else {
+        None
+    };
+    // Finds the node that a literal operand identifies
+    let node = || {
+        literal
+            .cloned()
+            .and_then(operator::node_id_of)
+            .and_then(|node_id| address_space.find_node(&node_id))
+    };
+    let valid = match (filter_operator, index) {
+        (FilterOperator::OfType, 0) => match node() {
+            Some(NodeType::ObjectType(_)) => true,
+            _ => false,
+        },
+        (FilterOperator::InView, 0) => match node() {
+            Some(NodeType::View(_)) => true,
+            _ => false,
+        },
+        (FilterOperator::RelatedTo, 0) | (FilterOperator::RelatedTo, 1) => {
+            if let Operand::ElementOperand(ref o) = operand {
+                // The element must be another RelatedTo
+                elements[o.index as usize].filter_operator == FilterOperator::RelatedTo
+            } else {
+                match node() {
+                    Some(NodeType::ObjectType(_)) | Some(NodeType::VariableType(_)) => true,
+                    _ => false,
+                }
+            }
+        }
+        (FilterOperator::RelatedTo, 2) => match node() {
+            Some(NodeType::ReferenceType(_)) => true,
+            _ => false,
+        },
+        (FilterOperator::RelatedTo, 3) => match literal {
+            Some(v) => v.clone().convert(VariantTypeId::UInt32).type_id() == VariantTypeId::UInt32,
+            None => false,
+        },
+        (FilterOperator::RelatedTo, 4) | (FilterOperator::RelatedTo, 5) => match literal {
+            Some(Variant::Boolean(_)) => true,
+            _ => false,
+        },
+        _ => true,
+    };
+    if valid {
+        StatusCode::Good
+    } else {
+        error!(
+            "Operand {} of {:?} does not identify a node of the expected class",
+            index, filter_operator
+        );
+        StatusCode::BadFilterOperandInvalid
+    }
+}
+
 fn validate_where_clause(
     where_clause: &ContentFilter,
     address_space: &AddressSpace,
@@ -219,15 +292,18 @@ fn validate_where_clause(
                     FilterOperator::Cast => filter_operands.len() < 2,
                     FilterOperator::BitwiseAnd => filter_operands.len() < 2,
                     FilterOperator::BitwiseOr => filter_operands.len() < 2,
-                    _ => true,
+                    FilterOperator::OfType => filter_operands.len() < 1,
+                    FilterOperator::InView => filter_operands.len() < 1,
+                    FilterOperator::RelatedTo => filter_operands.len() < 3, // 3..6
                 };
 
                 // Check if the operands look okay
-                let operand_status_codes = filter_operands.iter().map(|e| {
+                let filter_operator = e.filter_operator;
+                let operand_status_codes = filter_operands.iter().enumerate().map(|(index, e)| {
                     // Look to see if any operand cannot be parsed
                     match <Operand>::try_from(e) {
                         Ok(operand) => {
-                            match operand {
+                            let status_code = match operand {
                                 Operand::AttributeOperand(_) => {
                                     // AttributeOperand may not be used in an EventFilter where clause
                                     error!("AttributeOperand is not permitted in EventFilter where clause");
@@ -269,6 +345,11 @@ fn validate_where_clause(
                                     }
                                 }
                                 _ => StatusCode::Good
+                            };
+                            if status_code.is_good() {
+                                validate_node_operand(filter_operator, index, &operand, elements, address_space)
+                            } else {
+                                status_code
                             }
                         }
                         Err(err) => {
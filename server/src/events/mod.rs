@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
+pub mod event_bus;
 pub mod event_filter;
 pub(crate) mod operator;
 #[macro_use]
 pub mod event;
 #[macro_use]
 pub mod audit;
+pub mod model_change_event;
@@ -0,0 +1,197 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the events that the server raises when the address space is changed by a client.
+use opcua_types::{
+    service_types::{
+        ModelChangeStructureDataType, SemanticChangeStructureDataType, TimeZoneDataType,
+    },
+    DataTypeId, DateTime, ExtensionObject, LocalizedText, NodeId, ObjectId, ObjectTypeId,
+    QualifiedName, UAString, Variant,
+};
+
+use crate::{
+    address_space::AddressSpace,
+    events::event::{BaseEventType, Event},
+};
+
+/// This corresponds to BaseModelChangeEventType in OPC UA Part 5. The source of the event is
+/// the Server object so it is received by clients subscribing to events on the server.
+pub struct BaseModelChangeEventType {
+    base: BaseEventType,
+}
+
+impl Event for BaseModelChangeEventType {
+    type Err = ();
+
+    fn is_valid(&self) -> bool {
+        self.base.is_valid()
+    }
+
+    fn raise(&mut self, address_space: &mut AddressSpace) -> Result<NodeId, Self::Err> {
+        self.base.raise(address_space)
+    }
+}
+
+base_event_impl!(BaseModelChangeEventType, base);
+
+impl BaseModelChangeEventType {
+    pub fn new<R>(node_id: R, time: DateTime) -> Self
+    where
+        R: Into<NodeId>,
+    {
+        Self::new_event(
+            node_id,
+            ObjectTypeId::BaseModelChangeEventType,
+            "BaseModelChangeEvent",
+            time,
+        )
+    }
+
+    pub(crate) fn new_event<R, E, S>(
+        node_id: R,
+        event_type_id: E,
+        browse_name: S,
+        time: DateTime,
+    ) -> Self
+    where
+        R: Into<NodeId>,
+        E: Into<NodeId>,
+        S: Into<QualifiedName>,
+    {
+        let browse_name = browse_name.into();
+        let display_name = browse_name.name.to_string();
+        Self {
+            base: BaseEventType::new(
+                node_id,
+                event_type_id,
+                browse_name,
+                display_name,
+                NodeId::null(),
+                time,
+            )
+            .source_node(ObjectId::Server)
+            .source_name("Server"),
+        }
+    }
+}
+
+/// This corresponds to GeneralModelChangeEventType in OPC UA Part 5. The changes hold each node
+/// that was added or deleted, or whose references were added or deleted, with the verbs of
+/// `ModelChangeStructureVerbMask` combined into one entry per node.
+pub struct GeneralModelChangeEventType {
+    base: BaseModelChangeEventType,
+    changes: Vec<ModelChangeStructureDataType>,
+}
+
+impl Event for GeneralModelChangeEventType {
+    type Err = ();
+
+    fn is_valid(&self) -> bool {
+        !self.changes.is_empty() && self.base.is_valid()
+    }
+
+    fn raise(&mut self, address_space: &mut AddressSpace) -> Result<NodeId, Self::Err> {
+        let node_id = self.base.raise(address_space)?;
+        let ns = node_id.namespace;
+        let changes = self
+            .changes
+            .iter()
+            .map(|change| {
+                Variant::from(ExtensionObject::from_encodable(
+                    ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary,
+                    change,
+                ))
+            })
+            .collect::<Vec<Variant>>();
+        self.add_property(
+            &node_id,
+            NodeId::next_numeric(ns),
+            "Changes",
+            "Changes",
+            DataTypeId::ModelChangeStructureDataType,
+            changes,
+            address_space,
+        );
+        Ok(node_id)
+    }
+}
+
+base_event_impl!(GeneralModelChangeEventType, base);
+
+impl GeneralModelChangeEventType {
+    pub fn new<R>(node_id: R, time: DateTime, changes: Vec<ModelChangeStructureDataType>) -> Self
+    where
+        R: Into<NodeId>,
+    {
+        Self {
+            base: BaseModelChangeEventType::new_event(
+                node_id,
+                ObjectTypeId::GeneralModelChangeEventType,
+                "GeneralModelChangeEvent",
+                time,
+            ),
+            changes,
+        }
+    }
+}
+
+/// This corresponds to SemanticChangeEventType in OPC UA Part 5. The changes hold each node whose
+/// semantics changed because the value of one of its properties, e.g. EURange, was changed.
+pub struct SemanticChangeEventType {
+    base: BaseModelChangeEventType,
+    changes: Vec<SemanticChangeStructureDataType>,
+}
+
+impl Event for SemanticChangeEventType {
+    type Err = ();
+
+    fn is_valid(&self) -> bool {
+        !self.changes.is_empty() && self.base.is_valid()
+    }
+
+    fn raise(&mut self, address_space: &mut AddressSpace) -> Result<NodeId, Self::Err> {
+        let node_id = self.base.raise(address_space)?;
+        let ns = node_id.namespace;
+        let changes = self
+            .changes
+            .iter()
+            .map(|change| {
+                Variant::from(ExtensionObject::from_encodable(
+                    ObjectId::SemanticChangeStructureDataType_Encoding_DefaultBinary,
+                    change,
+                ))
+            })
+            .collect::<Vec<Variant>>();
+        self.add_property(
+            &node_id,
+            NodeId::next_numeric(ns),
+            "Changes",
+            "Changes",
+            DataTypeId::SemanticChangeStructureDataType,
+            changes,
+            address_space,
+        );
+        Ok(node_id)
+    }
+}
+
+base_event_impl!(SemanticChangeEventType, base);
+
+impl SemanticChangeEventType {
+    pub fn new<R>(node_id: R, time: DateTime, changes: Vec<SemanticChangeStructureDataType>) -> Self
+    where
+        R: Into<NodeId>,
+    {
+        Self {
+            base: BaseModelChangeEventType::new_event(
+                node_id,
+                ObjectTypeId::SemanticChangeEventType,
+                "SemanticChangeEvent",
+                time,
+            ),
+            changes,
+        }
+    }
+}
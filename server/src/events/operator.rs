@@ -12,14 +12,17 @@ use opcua_types::{
     operand::Operand,
     service_types::{ContentFilterElement, FilterOperator, SimpleAttributeOperand},
     status_code::StatusCode,
-    AttributeId, ExtensionObject, NodeId, NumericRange, QualifiedName, TimestampsToReturn, Variant,
-    VariantTypeId,
+    AttributeId, ExtensionObject, NodeId, NumericRange, QualifiedName, ReferenceTypeId,
+    TimestampsToReturn, UAString, Variant, VariantTypeId,
 };
 
-use crate::address_space::{
-    node::{NodeBase, NodeType},
-    relative_path::find_node_from_browse_path,
-    AddressSpace,
+use crate::{
+    address_space::{
+        node::{NodeBase, NodeType},
+        relative_path::find_node_from_browse_path,
+        AddressSpace,
+    },
+    events::event_bus::RaisedEvent,
 };
 
 /// Turns a list of operands inside extension objects to their analogous Operand objects
@@ -161,7 +164,27 @@ pub(crate) fn evaluate(
                     elements,
                     address_space,
                 ),
-                _ => Err(StatusCode::BadFilterOperatorUnsupported),
+                FilterOperator::OfType => of_type(
+                    object_id,
+                    &operands[..],
+                    used_elements,
+                    elements,
+                    address_space,
+                ),
+                FilterOperator::InView => in_view(
+                    object_id,
+                    &operands[..],
+                    used_elements,
+                    elements,
+                    address_space,
+                ),
+                FilterOperator::RelatedTo => related_to(
+                    object_id,
+                    &operands[..],
+                    used_elements,
+                    elements,
+                    address_space,
+                ),
             }
         } else {
             // All operators need at least one operand
@@ -188,13 +211,46 @@ fn value_as(
     Ok(v.convert(as_type))
 }
 
+/// Fetches the value of a simple attribute operand from a field of a raised event. An empty
+/// browse path with the NodeId attribute selects the event itself.
+fn value_of_event_field(
+    event: &RaisedEvent,
+    o: &SimpleAttributeOperand,
+    locale_ids: &[UAString],
+) -> Variant {
+    if let Some(ref browse_path) = o.browse_path {
+        if o.attribute_id == AttributeId::Value as u32 {
+            event
+                .localized_field(browse_path, locale_ids)
+                .unwrap_or(Variant::Empty)
+        } else if o.attribute_id == AttributeId::NodeId as u32 && browse_path.is_empty() {
+            event.node_id().clone().into()
+        } else {
+            error!(
+                "value_of, unsupported attribute id {} on event field",
+                o.attribute_id
+            );
+            Variant::Empty
+        }
+    } else {
+        error!("value_of, invalid browse path supplied to operand");
+        Variant::Empty
+    }
+}
+
+/// Fetches the value of a simple attribute operand from a raised event, or from a node of the
+/// address space. A `LocalizedText` value is chosen from the translations that best match the
+/// locale ids, which are in order of preference.
 pub(crate) fn value_of_simple_attribute(
     object_id: &NodeId,
     o: &SimpleAttributeOperand,
     address_space: &AddressSpace,
+    locale_ids: &[UAString],
 ) -> Variant {
-    // Get the Object / Variable by browse path
-    if let Some(ref browse_path) = o.browse_path {
+    if let Some(event) = address_space.events().find(object_id) {
+        value_of_event_field(event, o, locale_ids)
+    } else if let Some(ref browse_path) = o.browse_path {
+        // Get the Object / Variable by browse path
         // TODO o.data_type is ignored but be used to restrict the browse
         // path to subtypes of HierarchicalReferences
 
@@ -223,7 +279,7 @@ pub(crate) fn value_of_simple_attribute(
                             )
                             .value
                         {
-                            value.clone()
+                            node.localize_value(value.clone(), locale_ids)
                         } else {
                             Variant::Empty
                         }
@@ -278,7 +334,7 @@ pub(crate) fn value_of(
         }
         Operand::LiteralOperand(ref o) => Ok(o.value.clone()),
         Operand::SimpleAttributeOperand(ref o) => {
-            Ok(value_of_simple_attribute(object_id, o, address_space))
+            Ok(value_of_simple_attribute(object_id, o, address_space, &[]))
         }
         Operand::AttributeOperand(_) => {
             panic!();
@@ -1010,3 +1066,332 @@ pub(crate) fn bitwise_or(
         address_space,
     )
 }
+
+/// Gets the node id from a value that holds a `NodeId` or `ExpandedNodeId`
+pub(crate) fn node_id_of(v: Variant) -> Option<NodeId> {
+    match v {
+        Variant::NodeId(node_id) => Some(*node_id),
+        Variant::ExpandedNodeId(node_id) => Some(node_id.node_id),
+        _ => None,
+    }
+}
+
+/// Fetches the value of an operand that must resolve to a node id
+fn node_id_operand(
+    object_id: &NodeId,
+    operand: &Operand,
+    used_elements: &mut HashSet<u32>,
+    elements: &[ContentFilterElement],
+    address_space: &AddressSpace,
+) -> Result<NodeId, StatusCode> {
+    let v = value_of(object_id, operand, used_elements, elements, address_space)?;
+    node_id_of(v).ok_or_else(|| {
+        error!("Operand does not resolve to a node id");
+        StatusCode::BadFilterOperandInvalid
+    })
+}
+
+/// Gets the type of a raised event, or the type definition of a node
+fn type_of(node_id: &NodeId, address_space: &AddressSpace) -> Option<NodeId> {
+    if let Some(event) = address_space.events().find(node_id) {
+        Some(event.event_type_id().clone())
+    } else {
+        address_space.references().get_type_id(node_id)
+    }
+}
+
+/// Tests if the type of an event, or the type definition of a node, is the type, or optionally a
+/// subtype of it
+fn is_of_type(
+    node_id: &NodeId,
+    type_id: &NodeId,
+    include_subtypes: bool,
+    address_space: &AddressSpace,
+) -> bool {
+    if let Some(type_definition_id) = type_of(node_id, address_space) {
+        if include_subtypes {
+            address_space.is_subtype(&type_definition_id, type_id)
+        } else {
+            type_definition_id == *type_id
+        }
+    } else {
+        false
+    }
+}
+
+// TRUE if the target node is of the type identified by operand[0] or of a subtype of it.
+pub(crate) fn of_type(
+    object_id: &NodeId,
+    operands: &[Operand],
+    used_elements: &mut HashSet<u32>,
+    elements: &[ContentFilterElement],
+    address_space: &AddressSpace,
+) -> Result<Variant, StatusCode> {
+    // [0] Any operand that resolves to the NodeId of an ObjectType
+    let type_id = node_id_operand(
+        object_id,
+        &operands[0],
+        used_elements,
+        elements,
+        address_space,
+    )?;
+    Ok(is_of_type(object_id, &type_id, true, address_space).into())
+}
+
+/// Tests if a node can be reached from the view by following hierarchical references
+fn is_node_in_view(view_id: &NodeId, node_id: &NodeId, address_space: &AddressSpace) -> bool {
+    let mut visited = HashSet::new();
+    visited.insert(view_id.clone());
+    let mut to_visit = vec![view_id.clone()];
+    while let Some(parent_id) = to_visit.pop() {
+        if let Some(children) = address_space.find_hierarchical_references(&parent_id) {
+            for child_id in children {
+                if child_id == *node_id {
+                    return true;
+                } else if visited.insert(child_id.clone()) {
+                    to_visit.push(child_id);
+                }
+            }
+        }
+    }
+    false
+}
+
+// TRUE if the target node is contained in the view identified by operand[0]. An event is also in
+// the view when its SourceNode is.
+pub(crate) fn in_view(
+    object_id: &NodeId,
+    operands: &[Operand],
+    used_elements: &mut HashSet<u32>,
+    elements: &[ContentFilterElement],
+    address_space: &AddressSpace,
+) -> Result<Variant, StatusCode> {
+    // [0] Any operand that resolves to the NodeId of a View
+    let view_id = node_id_operand(
+        object_id,
+        &operands[0],
+        used_elements,
+        elements,
+        address_space,
+    )?;
+    if let Some(NodeType::View(_)) = address_space.find_node(&view_id) {
+        let in_view = is_node_in_view(&view_id, object_id, address_space)
+            || address_space
+                .events()
+                .find(object_id)
+                .and_then(|event| event.source_node())
+                .map(|source_node| is_node_in_view(&view_id, source_node, address_space))
+                .unwrap_or(false);
+        Ok(in_view.into())
+    } else {
+        error!("InView operand {:?} is not a view", view_id);
+        Ok(false.into())
+    }
+}
+
+/// Tests if a node matches a type operand of RelatedTo. The operand is either a type, in which
+/// case the node must be of that type, or an element operand that is evaluated against the node.
+fn matches_related_to_type(
+    node_id: &NodeId,
+    operand: &Operand,
+    include_subtypes: bool,
+    used_elements: &mut HashSet<u32>,
+    elements: &[ContentFilterElement],
+    address_space: &AddressSpace,
+) -> Result<bool, StatusCode> {
+    if let Operand::ElementOperand(ref o) = operand {
+        // The element is evaluated with the node as its target
+        if used_elements.contains(&o.index) {
+            error!(
+                "Operator contains elements that have already been used cyclical and is invalid"
+            );
+            Err(StatusCode::BadFilterOperandInvalid)
+        } else {
+            used_elements.insert(o.index);
+            let result = evaluate(
+                node_id,
+                &elements[o.index as usize],
+                used_elements,
+                elements,
+                address_space,
+            );
+            used_elements.remove(&o.index);
+            Ok(result? == Variant::Boolean(true))
+        }
+    } else {
+        let type_id = node_id_operand(node_id, operand, used_elements, elements, address_space)?;
+        Ok(is_of_type(
+            node_id,
+            &type_id,
+            include_subtypes,
+            address_space,
+        ))
+    }
+}
+
+/// Fetches an optional boolean operand, returning the default if it is absent or null
+fn optional_boolean_operand(
+    object_id: &NodeId,
+    operands: &[Operand],
+    index: usize,
+    default: bool,
+    used_elements: &mut HashSet<u32>,
+    elements: &[ContentFilterElement],
+    address_space: &AddressSpace,
+) -> Result<bool, StatusCode> {
+    if let Some(operand) = operands.get(index) {
+        match value_as(
+            object_id,
+            VariantTypeId::Boolean,
+            operand,
+            used_elements,
+            elements,
+            address_space,
+        )? {
+            Variant::Boolean(v) => Ok(v),
+            _ => Ok(default),
+        }
+    } else {
+        Ok(default)
+    }
+}
+
+/// Finds the nodes that a node references with references of the type, or optionally of its
+/// subtypes. A raised event is not a node of the address space but it references its source node
+/// with `HasEventSource`.
+fn referenced_nodes(
+    node_id: &NodeId,
+    reference_type_id: &NodeId,
+    include_subtypes: bool,
+    address_space: &AddressSpace,
+) -> Vec<NodeId> {
+    if let Some(event) = address_space.events().find(node_id) {
+        let has_event_source = NodeId::from(ReferenceTypeId::HasEventSource);
+        let follows_event_source = if include_subtypes {
+            address_space.is_subtype(&has_event_source, reference_type_id)
+        } else {
+            has_event_source == *reference_type_id
+        };
+        match event.source_node() {
+            Some(source_node) if follows_event_source => vec![source_node.clone()],
+            _ => Vec::new(),
+        }
+    } else if let Some(references) =
+        address_space.find_references(node_id, Some((reference_type_id.clone(), include_subtypes)))
+    {
+        references.into_iter().map(|r| r.target_node).collect()
+    } else {
+        Vec::new()
+    }
+}
+
+// TRUE if the target node is of the type in operand[0] and is related to a node of the type in
+// operand[1] by references of the type in operand[2] within the number of hops in operand[3].
+pub(crate) fn related_to(
+    object_id: &NodeId,
+    operands: &[Operand],
+    used_elements: &mut HashSet<u32>,
+    elements: &[ContentFilterElement],
+    address_space: &AddressSpace,
+) -> Result<Variant, StatusCode> {
+    // [0] The NodeId of the source type, or an ElementOperand of a RelatedTo that the source node
+    //     must satisfy
+    // [1] The NodeId of the target type, or an ElementOperand of a RelatedTo that the target node
+    //     must satisfy
+    // [2] The NodeId of a ReferenceType
+    // [3] Optional number of hops, 1 if absent. 0 means the number of hops is not limited.
+    // [4] Optional, TRUE if subtypes of the source and target types match. Default TRUE
+    // [5] Optional, TRUE if subtypes of the reference type are followed. Default TRUE
+    if operands.len() < 3 {
+        return Err(StatusCode::BadFilterOperandCountMismatch);
+    }
+    let reference_type_id = node_id_operand(
+        object_id,
+        &operands[2],
+        used_elements,
+        elements,
+        address_space,
+    )?;
+    let hops = if let Some(operand) = operands.get(3) {
+        match value_as(
+            object_id,
+            VariantTypeId::UInt32,
+            operand,
+            used_elements,
+            elements,
+            address_space,
+        )? {
+            Variant::UInt32(hops) => hops,
+            _ => {
+                error!("RelatedTo hops operand is not a number");
+                return Err(StatusCode::BadFilterOperandInvalid);
+            }
+        }
+    } else {
+        1
+    };
+    let include_type_subtypes = optional_boolean_operand(
+        object_id,
+        operands,
+        4,
+        true,
+        used_elements,
+        elements,
+        address_space,
+    )?;
+    let include_reference_subtypes = optional_boolean_operand(
+        object_id,
+        operands,
+        5,
+        true,
+        used_elements,
+        elements,
+        address_space,
+    )?;
+
+    if !matches_related_to_type(
+        object_id,
+        &operands[0],
+        include_type_subtypes,
+        used_elements,
+        elements,
+        address_space,
+    )? {
+        return Ok(false.into());
+    }
+
+    // Follow the references a hop at a time looking for a node of the target type
+    let mut visited = HashSet::new();
+    visited.insert(object_id.clone());
+    let mut nodes = vec![object_id.clone()];
+    let mut hop = 0;
+    while !nodes.is_empty() && (hops == 0 || hop < hops) {
+        hop += 1;
+        let mut next_nodes = Vec::new();
+        for node_id in &nodes {
+            let target_nodes = referenced_nodes(
+                node_id,
+                &reference_type_id,
+                include_reference_subtypes,
+                address_space,
+            );
+            for target_node in target_nodes {
+                if visited.insert(target_node.clone()) {
+                    if matches_related_to_type(
+                        &target_node,
+                        &operands[1],
+                        include_type_subtypes,
+                        used_elements,
+                        elements,
+                        address_space,
+                    )? {
+                        return Ok(true.into());
+                    }
+                    next_nodes.push(target_node);
+                }
+            }
+        }
+        nodes = next_nodes;
+    }
+    Ok(false.into())
+}
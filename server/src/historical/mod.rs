@@ -7,10 +7,12 @@ use std::{
     sync::{Arc, RwLock},
 };
 
+use futures::future;
+
 use opcua_types::status_code::StatusCode;
 use opcua_types::*;
 
-use crate::address_space::AddressSpace;
+use crate::{address_space::AddressSpace, callbacks::AsyncResult};
 
 /// Values that should be set in the address space via `AddressSpace::set_history_server_capabilities()`
 /// to denote to clients what history capabilities the server has.
@@ -80,9 +82,8 @@ pub enum HistoryRawData {
 /// to process historical data operations. The implementor of this trait may provide their
 /// own implementation as many functions as they desire leaving the remainder as stubs.
 ///
-/// IMPORTANT NOTE: This trait is currently synchronous and may change in the future to some other
-/// form. In the meantime it means if you are doing lengthy reads then use continuation points
-/// to spawn a thread for that activity. Updates and deletes should be spawned on separate threads
+/// IMPORTANT NOTE: This trait is synchronous. Lengthy reads should be implemented with
+/// `AsyncHistoricalDataProvider` instead. Updates and deletes should be spawned on separate threads
 /// if they are lengthy operations.
 pub trait HistoricalDataProvider {
     /// Note: Function returns an `HistoryRawData` enum containing *either* a `HistoryData` for a read raw action
@@ -159,3 +160,45 @@ pub trait HistoricalDataProvider {
         Err(StatusCode::BadHistoryOperationUnsupported)
     }
 }
+
+/// The `AsyncHistoricalDataProvider` trait is an asynchronous version of the read functions of
+/// `HistoricalDataProvider`. Each function returns a future for the results so a lengthy read
+/// does not block other requests. When a server registers both providers, this one is used for
+/// reads and `HistoricalDataProvider` for updates and deletes.
+pub trait AsyncHistoricalDataProvider {
+    fn read_raw_modified_details(
+        &self,
+        _address_space: Arc<RwLock<AddressSpace>>,
+        _request: ReadRawModifiedDetails,
+        _timestamps_to_return: TimestampsToReturn,
+        _release_continuation_points: bool,
+        _nodes_to_read: &[HistoryReadValueId],
+    ) -> AsyncResult<Vec<HistoryReadResult>> {
+        info!("Unimplemented read_raw_modified_details");
+        Box::new(future::err(StatusCode::BadHistoryOperationUnsupported))
+    }
+
+    fn read_processed_details(
+        &self,
+        _address_space: Arc<RwLock<AddressSpace>>,
+        _request: ReadProcessedDetails,
+        _timestamps_to_return: TimestampsToReturn,
+        _release_continuation_points: bool,
+        _nodes_to_read: &[HistoryReadValueId],
+    ) -> AsyncResult<Vec<HistoryReadResult>> {
+        info!("Unimplemented read_processed_details");
+        Box::new(future::err(StatusCode::BadHistoryOperationUnsupported))
+    }
+
+    fn read_at_time_details(
+        &self,
+        _address_space: Arc<RwLock<AddressSpace>>,
+        _request: ReadAtTimeDetails,
+        _timestamps_to_return: TimestampsToReturn,
+        _release_continuation_points: bool,
+        _nodes_to_read: &[HistoryReadValueId],
+    ) -> AsyncResult<Vec<HistoryReadResult>> {
+        info!("Unimplemented read_at_time_details");
+        Box::new(future::err(StatusCode::BadHistoryOperationUnsupported))
+    }
+}
@@ -14,6 +14,8 @@ use futures::{Async, Poll};
 use actix_web::{actix, fs, http, server, App, HttpRequest, HttpResponse, Responder};
 use serde_json;
 
+use opcua_types::LocalizedText;
+
 use crate::{metrics::ServerMetrics, server::Connections, state::ServerState};
 
 /// This is our metrics service, the thing called to handle requests coming from hyper
@@ -39,6 +41,33 @@ fn abort(req: &HttpRequest<HttpState>) -> impl Responder {
     }
 }
 
+fn shutdown(req: &HttpRequest<HttpState>) -> impl Responder {
+    if cfg!(debug_assertions) {
+        // Shutdown takes optional query parameters, e.g. /server/shutdown?delay=10&reason=Maintenance
+        let (delay_secs, reason) = {
+            let query = req.query();
+            let delay_secs = query
+                .get("delay")
+                .and_then(|delay| delay.parse::<u32>().ok())
+                .unwrap_or(0);
+            let reason = query
+                .get("reason")
+                .map(|reason| LocalizedText::new("", reason))
+                .unwrap_or_else(LocalizedText::null);
+            (delay_secs, reason)
+        };
+        let state = req.state();
+        let mut server_state = state.server_state.write().unwrap();
+        server_state.shutdown(delay_secs, reason);
+        HttpResponse::Ok().content_type("text/plain").body("OK")
+    } else {
+        // Shutdown is only enabled in debug mode
+        HttpResponse::Ok()
+            .content_type("text/plain")
+            .body("NOT IMPLEMENTED")
+    }
+}
+
 fn metrics(req: &HttpRequest<HttpState>) -> impl Responder {
     use std::ops::Deref;
 
@@ -123,6 +152,9 @@ pub fn run_http_server(
                 r.method(http::Method::GET).f(metrics)
             })
             .resource("/server/abort", |r| r.method(http::Method::GET).f(abort))
+            .resource("/server/shutdown", |r| {
+                r.method(http::Method::GET).f(shutdown)
+            })
             .handler(
                 "/",
                 fs::StaticFiles::new(base_path.clone())
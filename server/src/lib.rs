@@ -82,6 +82,7 @@ pub mod diagnostics;
 pub mod events;
 pub mod historical;
 pub mod metrics;
+pub mod node_manager;
 pub mod server;
 pub mod session;
 pub mod state;
@@ -99,6 +100,7 @@ pub mod prelude {
         config::*,
         events::event::*,
         historical::*,
+        node_manager::*,
         server::*,
         subscriptions::*,
         util::*,
@@ -135,15 +137,31 @@ pub mod constants {
     pub const MIN_SAMPLING_INTERVAL: f64 = (SUBSCRIPTION_TIMER_RATE_MS as f64) / 1000.0;
     /// Maximum data change queue allowed by clients on monitored items
     pub const MAX_DATA_CHANGE_QUEUE_SIZE: usize = 10;
+    /// Maximum queue allowed by clients on monitored items of a durable subscription. These queues
+    /// hold the values that change while the client is away, so they are much larger.
+    pub const MAX_DURABLE_QUEUE_SIZE: usize = 10000;
+    /// Maximum number of raised events held for monitored items to receive. The oldest event is
+    /// discarded when an event is raised and the queue is full.
+    pub const MAX_EVENT_QUEUE_SIZE: usize = 1000;
+    /// Default maximum lifetime in hours that a client may give a durable subscription
+    pub const DEFAULT_MAX_SUBSCRIPTION_LIFETIME_IN_HOURS: u32 = 24;
     /// The default size of preallocated vecs of monitored items per subscription
     pub const DEFAULT_MONITORED_ITEM_CAPACITY: usize = 100;
     /// Interval to check for HELLO timeout in millis. This can be fairly coarse because it's not
     /// something that requires huge accuracy.
     pub const HELLO_TIMEOUT_POLL_MS: u64 = 500;
+    /// Interval to check a watched configuration file for changes in millis
+    pub const CONFIG_FILE_WATCH_POLL_MS: u64 = 5000;
+    /// Interval to raise events for changes that were made to the address space in millis
+    pub const MODEL_CHANGE_EVENT_POLL_MS: u64 = 1000;
     /// Maximum time in MS that a session can be inactive before a timeout
     pub const MAX_SESSION_TIMEOUT: f64 = 60000f64;
     /// Maximum size in bytes that a request message is allowed to be
     pub const MAX_REQUEST_MESSAGE_SIZE: u32 = 32768;
+    /// Bytes of a publish response set aside for everything except its notifications, i.e. the
+    /// response header, acknowledgement results and available sequence numbers, when notifications
+    /// are split to fit the session's max response message size
+    pub const PUBLISH_RESPONSE_OVERHEAD: usize = 4096;
     /// Default keep alive count
     pub const DEFAULT_KEEP_ALIVE_COUNT: u32 = 10;
     /// Maximum keep alive count
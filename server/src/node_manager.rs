@@ -0,0 +1,86 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Provides the [`NodeManager`] trait, which allows a whole namespace to be served by an
+//! implementation instead of by nodes held in the [`AddressSpace`].
+//!
+//! [`NodeManager`]: ./trait.NodeManager.html
+//! [`AddressSpace`]: ../address_space/address_space/struct.AddressSpace.html
+
+use std::result::Result;
+
+use opcua_types::{
+    service_types::{
+        BrowseDescription, BrowsePath, CallMethodRequest, CallMethodResult, ReadValueId,
+        ReferenceDescription, TimestampsToReturn, WriteValue,
+    },
+    status_code::StatusCode,
+    DataValue, NodeId,
+};
+
+use crate::session::Session;
+
+/// A `NodeManager` is registered with the [`AddressSpace`] for a namespace through
+/// `AddressSpace::register_node_manager()`. Thereafter the services route every operation on a
+/// node in that namespace to the manager rather than looking for the node in the address space.
+/// Namespaces without a manager continue to be served from the address space itself.
+///
+/// Nodes do not have to exist before they are asked for, so a manager can mirror something large
+/// such as a database of tags by creating nodes on demand.
+///
+/// The manager is called while the address space is locked, so it must not try to lock the
+/// address space itself. Calls should also return promptly because they block the service.
+///
+/// [`AddressSpace`]: ../address_space/address_space/struct.AddressSpace.html
+pub trait NodeManager {
+    /// Tests if the node exists in the namespace
+    fn node_exists(&mut self, node_id: &NodeId) -> bool;
+
+    /// Returns the references of the node to browse, honouring its browse direction and
+    /// reference type filter. The service applies the node class and result masks to the
+    /// references and breaks them up into continuation points, so the manager need not.
+    fn browse(
+        &mut self,
+        session: &Session,
+        node_to_browse: &BrowseDescription,
+    ) -> Result<Vec<ReferenceDescription>, StatusCode>;
+
+    /// Reads an attribute of a node. Errors, such as an unknown node or an attribute the node
+    /// does not have, are returned as the status of the data value.
+    fn read(
+        &mut self,
+        session: &Session,
+        node_to_read: &ReadValueId,
+        max_age: f64,
+        timestamps_to_return: TimestampsToReturn,
+    ) -> DataValue;
+
+    /// Writes an attribute of a node. The default implementation makes the namespace read-only.
+    fn write(&mut self, _session: &Session, _node_to_write: &WriteValue) -> StatusCode {
+        StatusCode::BadNotWritable
+    }
+
+    /// Resolves a browse path that starts on a node in the namespace to the node ids it targets.
+    /// The default implementation finds no match.
+    fn translate_browse_path(
+        &mut self,
+        _browse_path: &BrowsePath,
+    ) -> Result<Vec<NodeId>, StatusCode> {
+        Err(StatusCode::BadNoMatch)
+    }
+
+    /// Calls a method on an object in the namespace. The default implementation has no methods.
+    fn call(
+        &mut self,
+        _session: &mut Session,
+        _request: &CallMethodRequest,
+    ) -> Result<CallMethodResult, StatusCode> {
+        Err(StatusCode::BadMethodInvalid)
+    }
+
+    /// Samples the current value of a monitored item. This is called by the subscription timer
+    /// each time the item's sampling interval elapses, so it should be cheap, e.g. by returning
+    /// a cached value. Returning `None` means there is no value and so no change to report.
+    fn sample(&mut self, item_to_monitor: &ReadValueId) -> Option<DataValue>;
+}
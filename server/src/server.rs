@@ -5,10 +5,12 @@
 //! Provides the [`Server`] type and functionality related to it.
 
 use std::{
+    fs,
     marker::Sync,
     net::SocketAddr,
-    sync::{Arc, RwLock},
-    time::{Duration, Instant},
+    path::PathBuf,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant, SystemTime},
 };
 
 use futures::{
@@ -30,14 +32,15 @@ use crate::{
     address_space::types::AddressSpace,
     comms::tcp_transport::*,
     comms::transport::Transport,
-    config::ServerConfig,
+    config::{ConfigReloadReport, ServerConfig},
     constants,
     diagnostics::ServerDiagnostics,
     events::audit::AuditLog,
     metrics::ServerMetrics,
     services::message_handler::MessageHandler,
     session::Session,
-    state::{OperationalLimits, ServerState},
+    state::ServerState,
+    subscriptions::durable_subscriptions::{DurableSubscriptions, DurableSubscriptionsSnapshot},
     util::PollingAction,
 };
 
@@ -117,8 +120,10 @@ impl Server {
         let diagnostics = Arc::new(RwLock::new(ServerDiagnostics::default()));
         let min_publishing_interval_ms = config.limits.min_publishing_interval * 1000.0;
         let min_sampling_interval_ms = config.limits.min_sampling_interval * 1000.0;
-
-        // TODO max string, byte string and array lengths
+        let operational_limits = config.limits.operational.clone();
+        let address_space_snapshot = config.address_space_snapshot.clone();
+        let durable_subscriptions_config = config.durable_subscriptions.clone();
+        let decoding_limits = config.decoding_limits();
 
         // Security, pki auto create cert
         let application_description = if config.create_sample_keypair {
@@ -154,6 +159,35 @@ impl Server {
 
         let audit_log = Arc::new(RwLock::new(AuditLog::new(address_space.clone())));
 
+        // Restore the durable subscriptions saved by a previous run of the server
+        let mut durable_subscriptions = DurableSubscriptions::new();
+        if let Some(ref durable_subscriptions_config) = durable_subscriptions_config {
+            let path = &durable_subscriptions_config.path;
+            if path.exists() {
+                match DurableSubscriptionsSnapshot::load_file(path, &decoding_limits) {
+                    Ok(snapshot) => {
+                        let restored = durable_subscriptions.restore(
+                            diagnostics.clone(),
+                            &chrono::Utc::now(),
+                            snapshot,
+                        );
+                        info!(
+                            "Restored {} durable subscriptions from {}",
+                            restored,
+                            path.display()
+                        );
+                    }
+                    Err(err) => error!(
+                        "Durable subscriptions {} cannot be restored, error = {}",
+                        path.display(),
+                        err
+                    ),
+                }
+            }
+        }
+        // New subscription ids follow on from restored ones so they cannot clash
+        let last_subscription_id = durable_subscriptions.max_subscription_id().unwrap_or(0);
+
         let server_state = ServerState {
             application_uri,
             product_uri,
@@ -168,7 +202,7 @@ impl Server {
             config,
             server_certificate,
             server_pkey,
-            last_subscription_id: 0,
+            last_subscription_id,
             max_subscriptions,
             max_monitored_items_per_sub,
             min_publishing_interval_ms,
@@ -178,18 +212,42 @@ impl Server {
             max_lifetime_count: constants::MAX_KEEP_ALIVE_COUNT * 3,
             diagnostics,
             abort: false,
+            shutdown_deadline: None,
+            shutdown_reason: LocalizedText::null(),
+            shutdown_notified: false,
             audit_log,
             register_nodes_callback: None,
             unregister_nodes_callback: None,
             historical_data_provider: None,
+            async_historical_data_provider: None,
             historical_event_provider: None,
-            operational_limits: OperationalLimits::default(),
+            operational_limits,
+            durable_subscriptions,
         };
         let server_state = Arc::new(RwLock::new(server_state));
 
         {
             let mut address_space = trace_write_lock_unwrap!(address_space);
             address_space.set_server_state(server_state.clone());
+
+            // Restore the nodes saved by a previous run of the server
+            if let Some(ref address_space_snapshot) = address_space_snapshot {
+                let path = &address_space_snapshot.path;
+                if path.exists() {
+                    match address_space.load_snapshot_file(path, &decoding_limits) {
+                        Ok(node_count) => info!(
+                            "Restored {} nodes from address space snapshot {}",
+                            node_count,
+                            path.display()
+                        ),
+                        Err(err) => error!(
+                            "Address space snapshot {} cannot be restored, error = {}",
+                            path.display(),
+                            err
+                        ),
+                    }
+                }
+            }
         }
 
         // Server metrics
@@ -277,6 +335,10 @@ impl Server {
                         server_state.start_time = DateTime::now();
                         server_state.set_state(ServerStateType::Running);
                     }
+                    server.update_server_status();
+
+                    // Changes to the address space are reported from now on
+                    server.start_model_change_event_timer();
 
                     // Start a timer that registers the server with a discovery server
                     if let Some(ref discovery_server_url) = discovery_server_url {
@@ -287,6 +349,12 @@ impl Server {
 
                     // Start any pending polling action timers
                     server.start_pending_polling_actions();
+
+                    // Start a timer that saves the address space snapshot
+                    server.start_address_space_snapshot_timer();
+
+                    // Start timers that tick and save durable subscriptions
+                    server.start_durable_subscriptions_timers();
                 }
 
                 // Start a server abort task loop
@@ -325,6 +393,17 @@ impl Server {
             })
         });
         info!("Server has stopped");
+
+        // Save the address space snapshot one last time
+        let server = trace_read_lock_unwrap!(server);
+        if server.has_address_space_snapshot() {
+            let _ = server.save_address_space_snapshot();
+        }
+
+        // Save durable subscriptions so clients can transfer them when the server runs again
+        if server.has_durable_subscriptions() {
+            let _ = server.save_durable_subscriptions();
+        }
     }
 
     /// Returns the current [`ServerState`] for the server.
@@ -364,26 +443,489 @@ impl Server {
     /// all sessions have disconnected.
     pub fn abort(&mut self) {
         info!("Server has been instructed to abort");
-        let mut server_state = trace_write_lock_unwrap!(self.server_state);
-        server_state.abort();
+        {
+            let mut server_state = trace_write_lock_unwrap!(self.server_state);
+            server_state.abort();
+        }
+        self.update_server_status();
+    }
+
+    /// Begins a graceful shutdown of the server. The server state becomes `Shutdown` and
+    /// `ServerStatus.SecondsTillShutdown` counts down from `delay_secs` with the `reason` exposed
+    /// through `ServerStatus.ShutdownReason`. When the countdown reaches zero, every subscription
+    /// is sent a final status change notification, then sessions and secure channels are closed
+    /// and the server stops listening.
+    pub fn shutdown(&mut self, delay_secs: u32, reason: LocalizedText) {
+        {
+            let mut server_state = trace_write_lock_unwrap!(self.server_state);
+            server_state.shutdown(delay_secs, reason);
+        }
+        self.update_server_status();
+    }
+
+    /// Updates the server status variables in the address space from the server state.
+    fn update_server_status(&self) {
+        let server_state = trace_read_lock_unwrap!(self.server_state);
+        let mut address_space = trace_write_lock_unwrap!(self.address_space);
+        address_space.update_server_status(&server_state);
+    }
+
+    /// Drives a graceful shutdown if one has been requested. The countdown is published to the
+    /// address space until it expires. The first poll after that closes subscriptions so their
+    /// final notifications can go out, and the next finishes every connection and aborts.
+    fn poll_shutdown(&self) {
+        let (seconds_till_shutdown, shutdown_notified) = {
+            let server_state = trace_read_lock_unwrap!(self.server_state);
+            if !server_state.is_shutting_down() || server_state.is_abort() {
+                return;
+            }
+            (
+                server_state.seconds_till_shutdown(),
+                server_state.shutdown_notified,
+            )
+        };
+
+        self.update_server_status();
+        if seconds_till_shutdown > 0 {
+            return;
+        }
+
+        let connections = {
+            let connections = trace_read_lock_unwrap!(self.connections);
+            connections.clone()
+        };
+        if !shutdown_notified {
+            info!("Server is shutting down, closing subscriptions");
+            let now = chrono::Utc::now();
+            connections.iter().for_each(|connection| {
+                let session = {
+                    let connection = trace_read_lock_unwrap!(connection);
+                    connection.session()
+                };
+                // Durable subscriptions are not closed so they can be saved for the next run
+                Self::keep_durable_subscriptions(&self.server_state, &session);
+                let mut session = trace_write_lock_unwrap!(session);
+                session.subscriptions_mut().shutdown(&now);
+            });
+            let mut server_state = trace_write_lock_unwrap!(self.server_state);
+            server_state.shutdown_notified = true;
+        } else {
+            info!("Server is shutting down, closing sessions");
+            connections.iter().for_each(|connection| {
+                let mut connection = trace_write_lock_unwrap!(connection);
+                connection.finish(StatusCode::BadShutdown);
+            });
+            let mut server_state = trace_write_lock_unwrap!(self.server_state);
+            server_state.abort();
+        }
+    }
+
+    /// Saves the namespaces of the address space that are set in the [`AddressSpaceSnapshotConfig`]
+    /// of the server's configuration to the snapshot file, returning the number of nodes saved.
+    /// The server saves automatically when it stops and on the configured interval, so this is
+    /// for saving at other times, e.g. after a batch of changes. The result is
+    /// `BadConfigurationError` if the configuration has no snapshot.
+    ///
+    /// [`AddressSpaceSnapshotConfig`]: ../config/struct.AddressSpaceSnapshotConfig.html
+    pub fn save_address_space_snapshot(&self) -> Result<usize, StatusCode> {
+        Self::save_snapshot(&self.server_state, &self.address_space)
+    }
+
+    fn has_address_space_snapshot(&self) -> bool {
+        let server_state = trace_read_lock_unwrap!(self.server_state);
+        let config = trace_read_lock_unwrap!(server_state.config);
+        config.address_space_snapshot.is_some()
+    }
+
+    fn save_snapshot(
+        server_state: &Arc<RwLock<ServerState>>,
+        address_space: &Arc<RwLock<AddressSpace>>,
+    ) -> Result<usize, StatusCode> {
+        let address_space_snapshot = {
+            let server_state = trace_read_lock_unwrap!(server_state);
+            let config = trace_read_lock_unwrap!(server_state.config);
+            config.address_space_snapshot.clone()
+        };
+        if let Some(address_space_snapshot) = address_space_snapshot {
+            let address_space = trace_read_lock_unwrap!(address_space);
+            // Namespaces which have not been registered have no nodes to save
+            let namespaces = address_space_snapshot
+                .namespaces
+                .iter()
+                .filter_map(|namespace| address_space.namespace_index(namespace))
+                .collect::<Vec<u16>>();
+            let result =
+                address_space.save_snapshot_file(&address_space_snapshot.path, &namespaces);
+            if let Ok(node_count) = result {
+                debug!(
+                    "Saved {} nodes to address space snapshot {}",
+                    node_count,
+                    address_space_snapshot.path.display()
+                );
+            }
+            result
+        } else {
+            error!("Server configuration does not have an address space snapshot");
+            Err(StatusCode::BadConfigurationError)
+        }
+    }
+
+    /// Saves durable subscriptions to the file set in the [`DurableSubscriptionsConfig`] of the
+    /// server's configuration, returning the number of subscriptions saved. This includes the
+    /// durable subscriptions of open sessions. The server saves automatically when it stops and on
+    /// the configured interval. The result is `BadConfigurationError` if the configuration does
+    /// not support durable subscriptions.
+    ///
+    /// [`DurableSubscriptionsConfig`]: ../config/struct.DurableSubscriptionsConfig.html
+    pub fn save_durable_subscriptions(&self) -> Result<usize, StatusCode> {
+        Self::save_durable(&self.server_state, &self.connections)
+    }
+
+    fn has_durable_subscriptions(&self) -> bool {
+        let server_state = trace_read_lock_unwrap!(self.server_state);
+        let config = trace_read_lock_unwrap!(server_state.config);
+        config.durable_subscriptions.is_some()
+    }
+
+    fn save_durable(
+        server_state: &Arc<RwLock<ServerState>>,
+        connections: &Arc<RwLock<Connections>>,
+    ) -> Result<usize, StatusCode> {
+        let path = {
+            let server_state = trace_read_lock_unwrap!(server_state);
+            let config = trace_read_lock_unwrap!(server_state.config);
+            config
+                .durable_subscriptions
+                .as_ref()
+                .map(|durable_subscriptions| durable_subscriptions.path.clone())
+        };
+        if let Some(path) = path {
+            let sessions = {
+                let connections = trace_read_lock_unwrap!(connections);
+                connections
+                    .iter()
+                    .map(|connection| {
+                        let connection = trace_read_lock_unwrap!(connection);
+                        connection.session()
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let mut subscriptions = sessions
+                .iter()
+                .flat_map(|session| {
+                    let session = trace_read_lock_unwrap!(session);
+                    session
+                        .subscriptions()
+                        .durable_subscription_snapshots(&session.client_user_id())
+                })
+                .collect::<Vec<_>>();
+            {
+                let server_state = trace_read_lock_unwrap!(server_state);
+                subscriptions.extend(server_state.durable_subscriptions.snapshot());
+            }
+            let result = DurableSubscriptionsSnapshot { subscriptions }.save_file(&path);
+            if let Ok(subscription_count) = result {
+                debug!(
+                    "Saved {} durable subscriptions to {}",
+                    subscription_count,
+                    path.display()
+                );
+            }
+            result
+        } else {
+            error!("Server configuration does not support durable subscriptions");
+            Err(StatusCode::BadConfigurationError)
+        }
+    }
+
+    /// Moves the durable subscriptions of a session into the server state so they outlive the
+    /// session and a client can transfer them to a new one
+    fn keep_durable_subscriptions(
+        server_state: &Arc<RwLock<ServerState>>,
+        session: &Arc<RwLock<Session>>,
+    ) {
+        let durable_subscriptions = {
+            let mut session = trace_write_lock_unwrap!(session);
+            let client_user_id = session.client_user_id();
+            session
+                .subscriptions_mut()
+                .take_durable_subscriptions(&client_user_id)
+        };
+        if !durable_subscriptions.is_empty() {
+            let mut server_state = trace_write_lock_unwrap!(server_state);
+            durable_subscriptions
+                .into_iter()
+                .for_each(|durable_subscription| {
+                    server_state
+                        .durable_subscriptions
+                        .insert(durable_subscription)
+                });
+        }
+    }
+
+    /// Starts a timer that ticks the durable subscriptions which have no session, so their
+    /// monitored items keep queueing values, and a timer that saves durable subscriptions on the
+    /// interval in the configuration
+    fn start_durable_subscriptions_timers(&self) {
+        let auto_save_interval = {
+            let server_state = trace_read_lock_unwrap!(self.server_state);
+            let config = trace_read_lock_unwrap!(server_state.config);
+            if let Some(ref durable_subscriptions) = config.durable_subscriptions {
+                durable_subscriptions.auto_save_interval
+            } else {
+                return;
+            }
+        };
+        {
+            let server_state = self.server_state.clone();
+            let address_space = self.address_space.clone();
+            let _ = PollingAction::spawn(
+                self.server_state.clone(),
+                constants::SUBSCRIPTION_TIMER_RATE_MS,
+                move || {
+                    let mut server_state = trace_write_lock_unwrap!(server_state);
+                    if !server_state.durable_subscriptions.is_empty() {
+                        let address_space = trace_read_lock_unwrap!(address_space);
+                        let now = chrono::Utc::now();
+                        server_state
+                            .durable_subscriptions
+                            .tick(&now, &address_space);
+                    }
+                },
+            );
+        }
+        if auto_save_interval > 0 {
+            let server_state = self.server_state.clone();
+            let connections = self.connections.clone();
+            let _ = PollingAction::spawn(
+                self.server_state.clone(),
+                auto_save_interval as u64 * 1000,
+                move || {
+                    let _ = Self::save_durable(&server_state, &connections);
+                },
+            );
+        }
+    }
+
+    /// Turns on model change events in the address space and starts a timer that raises events
+    /// for changes which were not made through a service, e.g. by the server's own polling actions
+    fn start_model_change_event_timer(&self) {
+        {
+            let mut address_space = trace_write_lock_unwrap!(self.address_space);
+            address_space.set_model_change_events(true);
+        }
+        let address_space = self.address_space.clone();
+        let _ = PollingAction::spawn(
+            self.server_state.clone(),
+            constants::MODEL_CHANGE_EVENT_POLL_MS,
+            move || {
+                let mut address_space = trace_write_lock_unwrap!(address_space);
+                address_space.raise_model_change_events();
+            },
+        );
+    }
+
+    /// Starts a timer that saves the address space snapshot on the interval in the configuration
+    fn start_address_space_snapshot_timer(&self) {
+        let auto_save_interval = {
+            let server_state = trace_read_lock_unwrap!(self.server_state);
+            let config = trace_read_lock_unwrap!(server_state.config);
+            config
+                .address_space_snapshot
+                .as_ref()
+                .map_or(0, |address_space_snapshot| {
+                    address_space_snapshot.auto_save_interval
+                })
+        };
+        if auto_save_interval > 0 {
+            let server_state = self.server_state.clone();
+            let address_space = self.address_space.clone();
+            let _ = PollingAction::spawn(
+                self.server_state.clone(),
+                auto_save_interval as u64 * 1000,
+                move || {
+                    let _ = Self::save_snapshot(&server_state, &address_space);
+                },
+            );
+        }
+    }
+
+    /// Reloads the configuration of the running server. The new configuration is refused with
+    /// `BadConfigurationError` if it fails [`ServerConfig::is_valid`]. Otherwise endpoints,
+    /// user tokens, limits and the certificate store are updated in place. Settings such as
+    /// the host and port are only read when the server starts, so they are left unchanged and
+    /// listed in the report as requiring a restart.
+    ///
+    /// Existing sessions keep the limits they were created with. New limits apply to sessions
+    /// created after the reload.
+    ///
+    /// [`ServerConfig::is_valid`]: ../config/struct.ServerConfig.html#method.is_valid
+    pub fn reload_config(
+        &mut self,
+        config: ServerConfig,
+    ) -> Result<ConfigReloadReport, StatusCode> {
+        Self::apply_config(
+            &self.server_state,
+            &self.certificate_store,
+            &self.address_space,
+            config,
+        )
+    }
+
+    /// Watches the configuration file at the supplied path and calls [`reload_config`] with its
+    /// contents whenever its modification time changes. The file is polled once the server is
+    /// running.
+    ///
+    /// [`reload_config`]: #method.reload_config
+    pub fn watch_config_file(&mut self, path: PathBuf) {
+        let server_state = self.server_state.clone();
+        let certificate_store = self.certificate_store.clone();
+        let address_space = self.address_space.clone();
+        let modified = |path: &PathBuf| -> Option<SystemTime> {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+        let last_modified = Mutex::new(modified(&path));
+        self.add_polling_action(constants::CONFIG_FILE_WATCH_POLL_MS, move || {
+            let now_modified = modified(&path);
+            let mut last_modified = last_modified.lock().unwrap();
+            if now_modified.is_some() && now_modified != *last_modified {
+                *last_modified = now_modified;
+                info!(
+                    "Configuration file {} has changed, reloading",
+                    path.display()
+                );
+                if let Ok(config) = ServerConfig::load(&path) {
+                    let _ = Self::apply_config(
+                        &server_state,
+                        &certificate_store,
+                        &address_space,
+                        config,
+                    );
+                } else {
+                    error!(
+                        "Configuration file {} cannot be loaded, ignoring change",
+                        path.display()
+                    );
+                }
+            }
+        });
+    }
+
+    /// Applies a new configuration to the server state, certificate store and address space.
+    fn apply_config(
+        server_state: &Arc<RwLock<ServerState>>,
+        certificate_store: &Arc<RwLock<CertificateStore>>,
+        address_space: &Arc<RwLock<AddressSpace>>,
+        mut config: ServerConfig,
+    ) -> Result<ConfigReloadReport, StatusCode> {
+        if !config.is_valid() {
+            error!(
+                "Server configuration cannot be reloaded because the new configuration is invalid"
+            );
+            return Err(StatusCode::BadConfigurationError);
+        }
+        config.read_x509_thumbprints();
+
+        let report = {
+            let mut server_state = trace_write_lock_unwrap!(server_state);
+            let server_config = server_state.config.clone();
+            let mut server_config = trace_write_lock_unwrap!(server_config);
+
+            let report = server_config.diff(&config);
+            if report.is_empty() {
+                info!("Server configuration has not changed");
+                return Ok(report);
+            }
+            let certificate_store_changed = server_config.certificate_store_changed(&config);
+            server_config.retain_restart_settings(&mut config);
+            *server_config = config;
+
+            // Limits
+            server_state.max_subscriptions = server_config.limits.max_subscriptions as usize;
+            server_state.max_monitored_items_per_sub =
+                server_config.limits.max_monitored_items_per_sub as usize;
+            server_state.min_publishing_interval_ms =
+                server_config.limits.min_publishing_interval * 1000.0;
+            server_state.min_sampling_interval_ms =
+                server_config.limits.min_sampling_interval * 1000.0;
+            server_state.operational_limits = server_config.limits.operational.clone();
+
+            // Certificate store
+            if certificate_store_changed {
+                let application_description = if server_config.create_sample_keypair {
+                    Some(server_config.application_description())
+                } else {
+                    None
+                };
+                let (mut new_certificate_store, server_certificate, server_pkey) =
+                    CertificateStore::new_with_keypair(
+                        &server_config.pki_dir,
+                        server_config.certificate_path.as_deref(),
+                        server_config.private_key_path.as_deref(),
+                        application_description,
+                    );
+                if server_certificate.is_none() || server_pkey.is_none() {
+                    error!("Server is missing its application instance certificate and/or its private key. Encrypted endpoints will not function correctly.")
+                }
+                new_certificate_store.trust_unknown_certs = server_config.trust_client_certs;
+                let mut certificate_store = trace_write_lock_unwrap!(certificate_store);
+                *certificate_store = new_certificate_store;
+                server_state.server_certificate = server_certificate;
+                server_state.server_pkey = server_pkey;
+            }
+            report
+        };
+
+        {
+            let server_state = trace_read_lock_unwrap!(server_state);
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            address_space.update_server_capabilities(&server_state);
+        }
+
+        info!(
+            "Server configuration reloaded, applied {:?}",
+            report.applied
+        );
+        if !report.restart_required.is_empty() {
+            warn!(
+                "Server configuration changes to {:?} require a restart to take effect",
+                report.restart_required
+            );
+        }
+        Ok(report)
     }
 
     /// Strip out dead connections, i.e those which have disconnected. Returns `true` if there are
     /// still open connections after this function completes.
     fn remove_dead_connections(&self) -> bool {
         // Go through all connections, removing those that have terminated
-        let mut connections = trace_write_lock_unwrap!(self.connections);
-        connections.retain(|connection| {
-            // Try to obtain the lock on the transport and the session and check if session is terminated
-            // if it is, then we'll use its termination status to sweep it out.
-            let mut lock = connection.try_read();
-            if let Ok(ref mut connection) = lock {
-                !connection.is_session_terminated()
-            } else {
-                true
-            }
+        let mut dead_sessions = Vec::new();
+        let has_open_connections = {
+            let mut connections = trace_write_lock_unwrap!(self.connections);
+            connections.retain(|connection| {
+                // Try to obtain the lock on the transport and the session and check if session is terminated
+                // if it is, then we'll use its termination status to sweep it out.
+                let mut lock = connection.try_read();
+                if let Ok(ref mut connection) = lock {
+                    if connection.is_session_terminated() {
+                        dead_sessions.push(connection.session());
+                        false
+                    } else {
+                        true
+                    }
+                } else {
+                    true
+                }
+            });
+            !connections.is_empty()
+        };
+        // Durable subscriptions outlive their sessions
+        dead_sessions.iter().for_each(|session| {
+            Self::keep_durable_subscriptions(&self.server_state, session);
         });
-        !connections.is_empty()
+        has_open_connections
     }
 
     /// Log information about the endpoints on this server
@@ -421,9 +963,9 @@ impl Server {
         }
     }
 
-    /// This timer will poll the server to see if it has aborted. It also cleans up dead connections.
-    /// If it determines to abort it will signal the tx_abort so that the main listener loop can
-    /// be broken at its convenience.
+    /// This timer will poll the server to see if it has aborted. It also cleans up dead connections
+    /// and drives any graceful shutdown. If it determines to abort it will signal the tx_abort so
+    /// that the main listener loop can be broken at its convenience.
     fn start_abort_poll(server: Arc<RwLock<Server>>, tx_abort: UnboundedSender<()>) {
         let task = Interval::new(Instant::now(), Duration::from_millis(1000))
             .take_while(move |_| {
@@ -431,6 +973,7 @@ impl Server {
                 let abort = {
                     // Check if there are any open sessions
                     let server = trace_read_lock_unwrap!(server);
+                    server.poll_shutdown();
                     let has_open_connections = server.remove_dead_connections();
                     let server_state = trace_read_lock_unwrap!(server.server_state);
                     // Predicate breaks take_while on abort & no open connections
@@ -7,6 +7,8 @@ use std::{
     sync::{Arc, RwLock},
 };
 
+use futures::{future, Future};
+
 use opcua_core::supported_message::SupportedMessage;
 use opcua_types::status_code::StatusCode;
 use opcua_types::*;
@@ -14,15 +16,20 @@ use opcua_types::*;
 use crate::address_space::types::NodeBase;
 use crate::{
     address_space::{
+        base,
         node::{HasNodeId, NodeType},
         variable::Variable,
         AddressSpace, UserAccessLevel,
     },
-    services::Service,
+    callbacks::AsyncResult,
+    services::{Service, ServiceFuture},
     session::Session,
     state::ServerState,
 };
 
+/// The value of one node being read, which completes when it has been obtained
+type DataValueFuture = Box<dyn Future<Item = DataValue, Error = ()> + Send>;
+
 enum ReadDetails {
     ReadEventDetails(ReadEventDetails),
     ReadRawModifiedDetails(ReadRawModifiedDetails),
@@ -59,25 +66,34 @@ impl AttributeService {
     /// elements or to read ranges of elements of the composite. Servers may make historical
     /// values available to Clients using this Service, although the historical values themselves
     /// are not visible in the AddressSpace.
-    pub fn read(
+    ///
+    /// The response completes when the values of any variables with asynchronous value getters
+    /// have been obtained.
+    pub fn async_read(
         &self,
         server_state: Arc<RwLock<ServerState>>,
         session: Arc<RwLock<Session>>,
         address_space: Arc<RwLock<AddressSpace>>,
         request: &ReadRequest,
-    ) -> SupportedMessage {
+    ) -> ServiceFuture {
         if is_empty_option_vec!(request.nodes_to_read) {
-            self.service_fault(&request.request_header, StatusCode::BadNothingToDo)
+            Box::new(future::ok(self.service_fault(
+                &request.request_header,
+                StatusCode::BadNothingToDo,
+            )))
         } else if request.max_age < 0f64 {
             // Negative values are invalid for max_age
             warn!("ReadRequest max age is invalid");
-            self.service_fault(&request.request_header, StatusCode::BadMaxAgeInvalid)
+            Box::new(future::ok(self.service_fault(
+                &request.request_header,
+                StatusCode::BadMaxAgeInvalid,
+            )))
         } else if request.timestamps_to_return == TimestampsToReturn::Invalid {
             warn!("ReadRequest invalid timestamps to return");
-            self.service_fault(
+            Box::new(future::ok(self.service_fault(
                 &request.request_header,
                 StatusCode::BadTimestampsToReturnInvalid,
-            )
+            )))
         } else {
             let server_state = trace_read_lock_unwrap!(server_state);
             let nodes_to_read = request.nodes_to_read.as_ref().unwrap();
@@ -88,63 +104,101 @@ impl AttributeService {
                 let timestamps_to_return = request.timestamps_to_return;
                 let results = nodes_to_read
                     .iter()
-                    .map(|node_to_read| {
-                        Self::read_node_value(
-                            &session,
-                            &address_space,
-                            node_to_read,
-                            request.max_age,
-                            timestamps_to_return,
-                        )
+                    .map(|node_to_read| -> DataValueFuture {
+                        if let Some(node_manager) =
+                            address_space.node_manager(&node_to_read.node_id)
+                        {
+                            let mut node_manager = trace_lock_unwrap!(node_manager);
+                            let value = node_manager.read(
+                                &session,
+                                node_to_read,
+                                request.max_age,
+                                timestamps_to_return,
+                            );
+                            Box::new(future::ok(value))
+                        } else {
+                            Self::async_read_node_value(
+                                &session,
+                                &address_space,
+                                node_to_read,
+                                request.max_age,
+                                timestamps_to_return,
+                            )
+                        }
                     })
-                    .collect();
+                    .collect::<Vec<DataValueFuture>>();
 
-                let diagnostic_infos = None;
-                let response = ReadResponse {
-                    response_header: ResponseHeader::new_good(&request.request_header),
-                    results: Some(results),
-                    diagnostic_infos,
-                };
-                response.into()
+                let request_header = request.request_header.clone();
+                Box::new(
+                    future::join_all(results).map(move |results| -> SupportedMessage {
+                        let diagnostic_infos = None;
+                        let response = ReadResponse {
+                            response_header: ResponseHeader::new_good(&request_header),
+                            results: Some(results),
+                            diagnostic_infos,
+                        };
+                        response.into()
+                    }),
+                )
             } else {
                 warn!("ReadRequest too many nodes to read {}", nodes_to_read.len());
-                self.service_fault(&request.request_header, StatusCode::BadTooManyOperations)
+                Box::new(future::ok(self.service_fault(
+                    &request.request_header,
+                    StatusCode::BadTooManyOperations,
+                )))
             }
         }
     }
 
-    /// Used to read historical values
-    pub fn history_read(
+    /// Used to read historical values. The response completes when the historical data provider
+    /// has read the values, which may be asynchronously.
+    pub fn async_history_read(
         &self,
         server_state: Arc<RwLock<ServerState>>,
         _session: Arc<RwLock<Session>>,
         address_space: Arc<RwLock<AddressSpace>>,
         request: &HistoryReadRequest,
-    ) -> SupportedMessage {
+    ) -> ServiceFuture {
         if is_empty_option_vec!(request.nodes_to_read) {
-            self.service_fault(&request.request_header, StatusCode::BadNothingToDo)
+            Box::new(future::ok(self.service_fault(
+                &request.request_header,
+                StatusCode::BadNothingToDo,
+            )))
         } else {
             let decoding_limits = {
                 let server_state = trace_read_lock_unwrap!(server_state);
                 server_state.decoding_limits()
             };
-            match Self::do_history_read_details(
+            let results = Self::do_history_read_details(
                 &decoding_limits,
                 server_state,
                 address_space,
                 request,
-            ) {
-                Ok(results) => {
-                    let diagnostic_infos = None;
-                    let response = HistoryReadResponse {
-                        response_header: ResponseHeader::new_good(&request.request_header),
-                        results: Some(results),
-                        diagnostic_infos,
+            );
+            let request_header = request.request_header.clone();
+            Box::new(future::result(results).flatten().then(
+                move |results| -> Result<SupportedMessage, ()> {
+                    let response = match results {
+                        Ok(results) => {
+                            let diagnostic_infos = None;
+                            HistoryReadResponse {
+                                response_header: ResponseHeader::new_good(&request_header),
+                                results: Some(results),
+                                diagnostic_infos,
+                            }
+                            .into()
+                        }
+                        Err(status_code) => {
+                            warn!(
+                                "HistoryReadRequest, request handle {} failed with status code {}",
+                                request_header.request_handle, status_code
+                            );
+                            ServiceFault::new(&request_header, status_code).into()
+                        }
                     };
-                    response.into()
-                }
-                Err(status_code) => self.service_fault(&request.request_header, status_code),
-            }
+                    Ok(response)
+                },
+            ))
         }
     }
 
@@ -173,9 +227,17 @@ impl AttributeService {
                 let results = nodes_to_write
                     .iter()
                     .map(|node_to_write| {
-                        Self::write_node_value(&session, &mut address_space, node_to_write)
+                        if let Some(node_manager) =
+                            address_space.node_manager(&node_to_write.node_id)
+                        {
+                            let mut node_manager = trace_lock_unwrap!(node_manager);
+                            node_manager.write(&session, node_to_write)
+                        } else {
+                            Self::write_node_value(&session, &mut address_space, node_to_write)
+                        }
                     })
                     .collect();
+                address_space.raise_model_change_events();
 
                 let diagnostic_infos = None;
                 WriteResponse {
@@ -428,7 +490,7 @@ impl AttributeService {
         server_state: Arc<RwLock<ServerState>>,
         address_space: Arc<RwLock<AddressSpace>>,
         request: &HistoryReadRequest,
-    ) -> Result<Vec<HistoryReadResult>, StatusCode> {
+    ) -> Result<AsyncResult<Vec<HistoryReadResult>>, StatusCode> {
         // TODO enforce operation limits
 
         // Validate the action being performed
@@ -438,61 +500,108 @@ impl AttributeService {
         let read_details =
             Self::decode_history_read_details(&request.history_read_details, &decoding_limits)?;
 
+        // Data is read by the asynchronous provider if there is one, otherwise the results
+        // of the synchronous provider are returned as a completed future
         let server_state = trace_read_lock_unwrap!(server_state);
-        let results = match read_details {
-            ReadDetails::ReadEventDetails(details) => {
-                let historical_event_provider = server_state
-                    .historical_event_provider
-                    .as_ref()
-                    .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
-                historical_event_provider.read_event_details(
-                    address_space,
-                    details,
-                    timestamps_to_return,
-                    release_continuation_points,
-                    &nodes_to_read,
-                )?
-            }
-            ReadDetails::ReadRawModifiedDetails(details) => {
-                let historical_data_provider = server_state
-                    .historical_data_provider
-                    .as_ref()
-                    .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
-                historical_data_provider.read_raw_modified_details(
-                    address_space,
-                    details,
-                    timestamps_to_return,
-                    release_continuation_points,
-                    &nodes_to_read,
-                )?
-            }
-            ReadDetails::ReadProcessedDetails(details) => {
-                let historical_data_provider = server_state
-                    .historical_data_provider
-                    .as_ref()
-                    .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
-                historical_data_provider.read_processed_details(
-                    address_space,
-                    details,
-                    timestamps_to_return,
-                    release_continuation_points,
-                    &nodes_to_read,
-                )?
-            }
-            ReadDetails::ReadAtTimeDetails(details) => {
-                let historical_data_provider = server_state
-                    .historical_data_provider
-                    .as_ref()
-                    .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
-                historical_data_provider.read_at_time_details(
-                    address_space,
-                    details,
-                    timestamps_to_return,
-                    release_continuation_points,
-                    &nodes_to_read,
-                )?
-            }
-        };
+        let results: AsyncResult<Vec<HistoryReadResult>> =
+            match read_details {
+                ReadDetails::ReadEventDetails(details) => {
+                    let historical_event_provider = server_state
+                        .historical_event_provider
+                        .as_ref()
+                        .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
+                    Box::new(future::result(
+                        historical_event_provider.read_event_details(
+                            address_space,
+                            details,
+                            timestamps_to_return,
+                            release_continuation_points,
+                            &nodes_to_read,
+                        ),
+                    ))
+                }
+                ReadDetails::ReadRawModifiedDetails(details) => {
+                    if let Some(ref async_historical_data_provider) =
+                        server_state.async_historical_data_provider
+                    {
+                        async_historical_data_provider.read_raw_modified_details(
+                            address_space,
+                            details,
+                            timestamps_to_return,
+                            release_continuation_points,
+                            &nodes_to_read,
+                        )
+                    } else {
+                        let historical_data_provider = server_state
+                            .historical_data_provider
+                            .as_ref()
+                            .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
+                        Box::new(future::result(
+                            historical_data_provider.read_raw_modified_details(
+                                address_space,
+                                details,
+                                timestamps_to_return,
+                                release_continuation_points,
+                                &nodes_to_read,
+                            ),
+                        ))
+                    }
+                }
+                ReadDetails::ReadProcessedDetails(details) => {
+                    if let Some(ref async_historical_data_provider) =
+                        server_state.async_historical_data_provider
+                    {
+                        async_historical_data_provider.read_processed_details(
+                            address_space,
+                            details,
+                            timestamps_to_return,
+                            release_continuation_points,
+                            &nodes_to_read,
+                        )
+                    } else {
+                        let historical_data_provider = server_state
+                            .historical_data_provider
+                            .as_ref()
+                            .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
+                        Box::new(future::result(
+                            historical_data_provider.read_processed_details(
+                                address_space,
+                                details,
+                                timestamps_to_return,
+                                release_continuation_points,
+                                &nodes_to_read,
+                            ),
+                        ))
+                    }
+                }
+                ReadDetails::ReadAtTimeDetails(details) => {
+                    if let Some(ref async_historical_data_provider) =
+                        server_state.async_historical_data_provider
+                    {
+                        async_historical_data_provider.read_at_time_details(
+                            address_space,
+                            details,
+                            timestamps_to_return,
+                            release_continuation_points,
+                            &nodes_to_read,
+                        )
+                    } else {
+                        let historical_data_provider = server_state
+                            .historical_data_provider
+                            .as_ref()
+                            .ok_or(StatusCode::BadHistoryOperationUnsupported)?;
+                        Box::new(future::result(
+                            historical_data_provider.read_at_time_details(
+                                address_space,
+                                details,
+                                timestamps_to_return,
+                                release_continuation_points,
+                                &nodes_to_read,
+                            ),
+                        ))
+                    }
+                }
+            };
         Ok(results)
     }
 
@@ -504,6 +613,122 @@ impl AttributeService {
         }
     }
 
+    /// Reads the value of a node. A variable with an asynchronous value getter completes when
+    /// the getter supplies the value, everything else is read immediately.
+    fn async_read_node_value(
+        session: &Session,
+        address_space: &AddressSpace,
+        node_to_read: &ReadValueId,
+        max_age: f64,
+        timestamps_to_return: TimestampsToReturn,
+    ) -> DataValueFuture {
+        if let Some((value, value_translations)) = Self::async_value_of(
+            session,
+            address_space,
+            node_to_read,
+            max_age,
+            timestamps_to_return,
+        ) {
+            let locale_ids = session.preferred_locale_ids();
+            Box::new(value.then(move |result| -> Result<DataValue, ()> {
+                let result_value = match result {
+                    Ok(Some(value)) => {
+                        let mut result_value = DataValue::null();
+                        Self::copy_timestamps(&mut result_value, &value, timestamps_to_return);
+                        // Localized text is chosen by the session's preferred locales
+                        result_value.value = value.value.map(|value| {
+                            base::localize_value(value, &value_translations, &locale_ids)
+                        });
+                        result_value.status = value.status;
+                        result_value
+                    }
+                    Ok(None) => DataValue {
+                        status: Some(StatusCode::BadAttributeIdInvalid),
+                        ..DataValue::null()
+                    },
+                    Err(status_code) => DataValue {
+                        status: Some(status_code),
+                        ..DataValue::null()
+                    },
+                };
+                Ok(result_value)
+            }))
+        } else {
+            Box::new(future::ok(Self::read_node_value(
+                session,
+                address_space,
+                node_to_read,
+                max_age,
+                timestamps_to_return,
+            )))
+        }
+    }
+
+    /// Returns a future for the value of a variable that has an asynchronous value getter, if the
+    /// request is for its value and is permitted, together with the translations of the value.
+    /// Anything else is left to `read_node_value`.
+    fn async_value_of(
+        session: &Session,
+        address_space: &AddressSpace,
+        node_to_read: &ReadValueId,
+        max_age: f64,
+        timestamps_to_return: TimestampsToReturn,
+    ) -> Option<(AsyncResult<Option<DataValue>>, Vec<LocalizedText>)> {
+        if node_to_read.attribute_id != AttributeId::Value as u32
+            || !Self::is_supported_data_encoding(&node_to_read.data_encoding)
+        {
+            return None;
+        }
+        let node = address_space.find_node(&node_to_read.node_id)?;
+        let index_range = node_to_read
+            .index_range
+            .as_ref()
+            .parse::<NumericRange>()
+            .ok()?;
+        match node {
+            NodeType::Variable(variable)
+                if Self::is_readable(session, node, AttributeId::Value) =>
+            {
+                variable
+                    .async_value(
+                        timestamps_to_return,
+                        index_range,
+                        &node_to_read.data_encoding,
+                        max_age,
+                    )
+                    .map(|value| (value, variable.value_translations().to_vec()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Copies the timestamps that the client asked for from a value into the result
+    fn copy_timestamps(
+        result_value: &mut DataValue,
+        value: &DataValue,
+        timestamps_to_return: TimestampsToReturn,
+    ) {
+        match timestamps_to_return {
+            TimestampsToReturn::Source => {
+                result_value.source_timestamp = value.source_timestamp.clone();
+                result_value.source_picoseconds = value.source_picoseconds;
+            }
+            TimestampsToReturn::Server => {
+                result_value.server_timestamp = value.server_timestamp.clone();
+                result_value.server_picoseconds = value.server_picoseconds;
+            }
+            TimestampsToReturn::Both => {
+                result_value.source_timestamp = value.source_timestamp.clone();
+                result_value.source_picoseconds = value.source_picoseconds;
+                result_value.server_timestamp = value.server_timestamp.clone();
+                result_value.server_picoseconds = value.server_picoseconds;
+            }
+            TimestampsToReturn::Neither | TimestampsToReturn::Invalid => {
+                // Nothing needs to change
+            }
+        }
+    }
+
     fn read_node_value(
         session: &Session,
         address_space: &AddressSpace,
@@ -563,7 +788,7 @@ impl AttributeService {
                     // If caller was reading the user access level, this needs to be modified to
                     // take account of the effective level based on who is logged in.
                     let value = if attribute_id == AttributeId::UserAccessLevel {
-                        if let Some(value) = attribute.value {
+                        if let Some(value) = attribute.value.clone() {
                             if let Variant::Byte(value) = value {
                                 // The bits from the node are further modified by the session
                                 let user_access_level = UserAccessLevel::from_bits_truncate(value);
@@ -579,6 +804,20 @@ impl AttributeService {
                         } else {
                             None
                         }
+                    } else if attribute_id == AttributeId::DisplayName {
+                        // Localized text is chosen by the session's preferred locales
+                        let locale_ids = session.preferred_locale_ids();
+                        Some(node.as_node().display_name_for_locales(&locale_ids).into())
+                    } else if attribute_id == AttributeId::Description {
+                        let locale_ids = session.preferred_locale_ids();
+                        node.as_node()
+                            .description_for_locales(&locale_ids)
+                            .map(|description| description.into())
+                    } else if let (NodeType::Variable(variable), Some(value)) =
+                        (node, attribute.value.as_ref())
+                    {
+                        let locale_ids = session.preferred_locale_ids();
+                        Some(variable.localize_value(value.clone(), &locale_ids))
                     } else {
                         attribute.value.clone()
                     };
@@ -596,25 +835,11 @@ impl AttributeService {
                     // Timestamps to return only applies to variable value
                     if let NodeType::Variable(_) = node {
                         if attribute_id == AttributeId::Value {
-                            match timestamps_to_return {
-                                TimestampsToReturn::Source => {
-                                    result_value.source_timestamp = attribute.source_timestamp;
-                                    result_value.source_picoseconds = attribute.source_picoseconds;
-                                }
-                                TimestampsToReturn::Server => {
-                                    result_value.server_timestamp = attribute.server_timestamp;
-                                    result_value.server_picoseconds = attribute.server_picoseconds;
-                                }
-                                TimestampsToReturn::Both => {
-                                    result_value.source_timestamp = attribute.source_timestamp;
-                                    result_value.source_picoseconds = attribute.source_picoseconds;
-                                    result_value.server_timestamp = attribute.server_timestamp;
-                                    result_value.server_picoseconds = attribute.server_picoseconds;
-                                }
-                                TimestampsToReturn::Neither | TimestampsToReturn::Invalid => {
-                                    // Nothing needs to change
-                                }
-                            }
+                            Self::copy_timestamps(
+                                &mut result_value,
+                                &attribute,
+                                timestamps_to_return,
+                            );
                         }
                     }
                 } else {
@@ -661,7 +886,6 @@ impl AttributeService {
     }
 
     fn is_writable(session: &Session, node: &NodeType, attribute_id: AttributeId) -> bool {
-        // TODO session for current user
         // For a variable, the access level controls access to the variable
         if let NodeType::Variable(_) = node {
             if attribute_id == AttributeId::Value {
@@ -670,53 +894,91 @@ impl AttributeService {
             }
         }
 
+        // Other attributes are controlled by the write mask, further restricted by the user
+        // write mask. A node without a user write mask leaves it to the write mask.
         if let Some(write_mask) = node.as_node().write_mask() {
-            match attribute_id {
-                AttributeId::Value => {
-                    if let NodeType::VariableType(_) = node {
-                        write_mask.contains(WriteMask::VALUE_FOR_VARIABLE_TYPE)
-                    } else {
-                        false
-                    }
-                }
-                AttributeId::NodeId => write_mask.contains(WriteMask::NODE_ID),
-                AttributeId::NodeClass => write_mask.contains(WriteMask::NODE_CLASS),
-                AttributeId::BrowseName => write_mask.contains(WriteMask::BROWSE_NAME),
-                AttributeId::DisplayName => write_mask.contains(WriteMask::DISPLAY_NAME),
-                AttributeId::Description => write_mask.contains(WriteMask::DESCRIPTION),
-                AttributeId::WriteMask => write_mask.contains(WriteMask::WRITE_MASK),
-                AttributeId::UserWriteMask => write_mask.contains(WriteMask::USER_WRITE_MASK),
-                AttributeId::IsAbstract => write_mask.contains(WriteMask::IS_ABSTRACT),
-                AttributeId::Symmetric => write_mask.contains(WriteMask::SYMMETRIC),
-                AttributeId::InverseName => write_mask.contains(WriteMask::INVERSE_NAME),
-                AttributeId::ContainsNoLoops => write_mask.contains(WriteMask::CONTAINS_NO_LOOPS),
-                AttributeId::EventNotifier => write_mask.contains(WriteMask::EVENT_NOTIFIER),
-                AttributeId::DataType => write_mask.contains(WriteMask::DATA_TYPE),
-                AttributeId::ValueRank => write_mask.contains(WriteMask::VALUE_RANK),
-                AttributeId::ArrayDimensions => write_mask.contains(WriteMask::ARRAY_DIMENSIONS),
-                AttributeId::AccessLevel => write_mask.contains(WriteMask::ACCESS_LEVEL),
-                AttributeId::UserAccessLevel => write_mask.contains(WriteMask::USER_ACCESS_LEVEL),
-                AttributeId::MinimumSamplingInterval => {
-                    write_mask.contains(WriteMask::MINIMUM_SAMPLING_INTERVAL)
-                }
-                AttributeId::Historizing => write_mask.contains(WriteMask::HISTORIZING),
-                AttributeId::Executable => write_mask.contains(WriteMask::EXECUTABLE),
-                AttributeId::UserExecutable => write_mask.contains(WriteMask::USER_EXECUTABLE),
-                AttributeId::DataTypeDefinition => {
-                    write_mask.contains(WriteMask::DATA_TYPE_DEFINITION)
-                }
-                AttributeId::RolePermissions => write_mask.contains(WriteMask::ROLE_PERMISSIONS),
-                AttributeId::AccessRestrictions => {
-                    write_mask.contains(WriteMask::ACCESS_RESTRICTIONS)
-                }
-                AttributeId::AccessLevelEx => write_mask.contains(WriteMask::ACCESS_LEVEL_EX),
-                AttributeId::UserRolePermissions => false, // Reserved
+            let user_write_mask = node.as_node().user_write_mask().unwrap_or(write_mask);
+            match Self::write_mask_bit(node, attribute_id) {
+                Some(bit) => write_mask.contains(bit) && user_write_mask.contains(bit),
+                None => false,
             }
         } else {
             false
         }
     }
 
+    /// Tests if the status and timestamps supplied with a value can be written. Only the Value of
+    /// a Variable accepts them, when the user access level has `STATUS_WRITE` and
+    /// `TIMESTAMP_WRITE` respectively.
+    fn is_status_and_timestamps_writable(
+        session: &Session,
+        node: &NodeType,
+        attribute_id: AttributeId,
+        data_value: &DataValue,
+    ) -> bool {
+        let has_status = data_value.status.is_some();
+        let has_timestamps = data_value.source_timestamp.is_some()
+            || data_value.source_picoseconds.is_some()
+            || data_value.server_timestamp.is_some()
+            || data_value.server_picoseconds.is_some();
+        if !has_status && !has_timestamps {
+            true
+        } else if let NodeType::Variable(_) = node {
+            if attribute_id == AttributeId::Value {
+                let user_access_level = Self::user_access_level(session, node, attribute_id);
+                (!has_status || user_access_level.contains(UserAccessLevel::STATUS_WRITE))
+                    && (!has_timestamps
+                        || user_access_level.contains(UserAccessLevel::TIMESTAMP_WRITE))
+            } else {
+                false
+            }
+        } else {
+            false
+        }
+    }
+
+    /// Returns the write mask bit that allows the attribute to be written, or `None` if the
+    /// attribute can never be written through the write mask.
+    fn write_mask_bit(node: &NodeType, attribute_id: AttributeId) -> Option<WriteMask> {
+        let bit = match attribute_id {
+            AttributeId::Value => {
+                if let NodeType::VariableType(_) = node {
+                    WriteMask::VALUE_FOR_VARIABLE_TYPE
+                } else {
+                    return None;
+                }
+            }
+            // The address space is keyed by node id and the node class decides the kind of node,
+            // so neither can be changed in place.
+            AttributeId::NodeId | AttributeId::NodeClass => return None,
+            AttributeId::BrowseName => WriteMask::BROWSE_NAME,
+            AttributeId::DisplayName => WriteMask::DISPLAY_NAME,
+            AttributeId::Description => WriteMask::DESCRIPTION,
+            AttributeId::WriteMask => WriteMask::WRITE_MASK,
+            AttributeId::UserWriteMask => WriteMask::USER_WRITE_MASK,
+            AttributeId::IsAbstract => WriteMask::IS_ABSTRACT,
+            AttributeId::Symmetric => WriteMask::SYMMETRIC,
+            AttributeId::InverseName => WriteMask::INVERSE_NAME,
+            AttributeId::ContainsNoLoops => WriteMask::CONTAINS_NO_LOOPS,
+            AttributeId::EventNotifier => WriteMask::EVENT_NOTIFIER,
+            AttributeId::DataType => WriteMask::DATA_TYPE,
+            AttributeId::ValueRank => WriteMask::VALUE_RANK,
+            AttributeId::ArrayDimensions => WriteMask::ARRAY_DIMENSIONS,
+            AttributeId::AccessLevel => WriteMask::ACCESS_LEVEL,
+            AttributeId::UserAccessLevel => WriteMask::USER_ACCESS_LEVEL,
+            AttributeId::MinimumSamplingInterval => WriteMask::MINIMUM_SAMPLING_INTERVAL,
+            AttributeId::Historizing => WriteMask::HISTORIZING,
+            AttributeId::Executable => WriteMask::EXECUTABLE,
+            AttributeId::UserExecutable => WriteMask::USER_EXECUTABLE,
+            AttributeId::DataTypeDefinition => WriteMask::DATA_TYPE_DEFINITION,
+            AttributeId::RolePermissions => WriteMask::ROLE_PERMISSIONS,
+            AttributeId::AccessRestrictions => WriteMask::ACCESS_RESTRICTIONS,
+            AttributeId::AccessLevelEx => WriteMask::ACCESS_LEVEL_EX,
+            AttributeId::UserRolePermissions => return None, // Reserved
+        };
+        Some(bit)
+    }
+
     /*
     fn is_history_readable(session: &Session, node: &NodeType) -> bool {
         Self::user_access_level(session, node, AttributeId::Value).contains(UserAccessLevel::HISTORY_READ)
@@ -727,6 +989,42 @@ impl AttributeService {
     }
     */
 
+    /// Determine if the value has the data type of an attribute other than Value. Part 3 5.9
+    /// lists the data type of each attribute.
+    fn validate_attribute_to_write(attribute_id: AttributeId, value: &Variant) -> bool {
+        let expected_type = match attribute_id {
+            AttributeId::NodeId | AttributeId::DataType => VariantTypeId::NodeId,
+            AttributeId::NodeClass | AttributeId::ValueRank => VariantTypeId::Int32,
+            AttributeId::BrowseName => VariantTypeId::QualifiedName,
+            AttributeId::DisplayName | AttributeId::Description | AttributeId::InverseName => {
+                VariantTypeId::LocalizedText
+            }
+            AttributeId::WriteMask | AttributeId::UserWriteMask | AttributeId::AccessLevelEx => {
+                VariantTypeId::UInt32
+            }
+            AttributeId::IsAbstract
+            | AttributeId::Symmetric
+            | AttributeId::ContainsNoLoops
+            | AttributeId::Historizing
+            | AttributeId::Executable
+            | AttributeId::UserExecutable => VariantTypeId::Boolean,
+            AttributeId::EventNotifier
+            | AttributeId::AccessLevel
+            | AttributeId::UserAccessLevel => VariantTypeId::Byte,
+            AttributeId::MinimumSamplingInterval => VariantTypeId::Double,
+            AttributeId::AccessRestrictions => VariantTypeId::UInt16,
+            AttributeId::DataTypeDefinition => VariantTypeId::ExtensionObject,
+            AttributeId::ArrayDimensions => {
+                return value.is_array_of_type(VariantTypeId::UInt32);
+            }
+            AttributeId::RolePermissions | AttributeId::UserRolePermissions => {
+                return value.is_array_of_type(VariantTypeId::ExtensionObject);
+            }
+            AttributeId::Value => return true,
+        };
+        value.type_id() == expected_type
+    }
+
     /// Determine if the value is writable to a Variable node's data type
     fn validate_value_to_write(
         address_space: &AddressSpace,
@@ -764,8 +1062,19 @@ impl AttributeService {
                 true
             }
         } else if let Some(value_data_type) = value.array_data_type() {
-            // TODO check that value is array of same dimensions
-            address_space.is_subtype(&value_data_type, &node_data_type)
+            // An array must have as many dimensions as the value rank when the rank is fixed
+            let dimensions_valid = match value {
+                Variant::Array(array) if value_rank > 0 => {
+                    let dimensions = if array.has_dimensions() {
+                        array.dimensions.len()
+                    } else {
+                        1
+                    };
+                    dimensions == value_rank as usize
+                }
+                _ => true,
+            };
+            dimensions_valid && address_space.is_subtype(&value_data_type, &node_data_type)
         } else {
             // Value should have a data type
             false
@@ -792,20 +1101,22 @@ impl AttributeService {
                     // Index ranges are not supported on anything other than a value attribute
                     error!("Server does not support indexes for attributes other than Value");
                     StatusCode::BadWriteNotSupported
-                //                 else if node_to_write.value.server_timestamp.is_some() || node_to_write.value.server_picoseconds.is_some() ||
-                //                    node_to_write.value.source_timestamp.is_some() || node_to_write.value.source_picoseconds.is_some() {
-                //                    error!("Server does not support timestamps in write");
-                //                    StatusCode::BadWriteNotSupported
+                } else if !Self::is_status_and_timestamps_writable(
+                    session,
+                    &node,
+                    attribute_id,
+                    &node_to_write.value,
+                ) {
+                    error!("Server does not support writing the status or timestamps of node {} attribute {:?}", node_to_write.node_id, attribute_id);
+                    StatusCode::BadWriteNotSupported
                 } else if index_range.is_err() {
                     error!("Index range is invalid");
                     StatusCode::BadIndexRangeInvalid
                 } else if let Some(ref value) = node_to_write.value.value {
                     let index_range = index_range.unwrap();
 
-                    // This is a band-aid for Variable::Value which should check if the data type
-                    // matches the written value. Note, that ALL attributes should check for subtypes
-                    // but they don't. There should be a general purpose fn attribute_type(attribute_id) helper
-                    // on the node impl that returns a datatype for the attribute regardless of node.
+                    // A Value must match the data type of the variable, other attributes have
+                    // a fixed data type.
                     let data_type_valid = if attribute_id == AttributeId::Value {
                         match node {
                             NodeType::Variable(ref variable) => {
@@ -814,7 +1125,7 @@ impl AttributeService {
                             _ => true, // Other types don't have this attr but they will reject later during set
                         }
                     } else {
-                        true
+                        Self::validate_attribute_to_write(attribute_id, value)
                     };
                     if !data_type_valid {
                         error!("Data type of value is invalid for writing to attribute");
@@ -824,7 +1135,7 @@ impl AttributeService {
                         let result = if attribute_id == AttributeId::Value {
                             match node {
                                 NodeType::Variable(ref mut variable) => variable
-                                    .set_value(index_range, value.clone())
+                                    .set_data_value(index_range, node_to_write.value.clone())
                                     .map_err(|err| {
                                         error!(
                                             "Value could not be set to node {} Value, error = {}",
@@ -845,6 +1156,11 @@ impl AttributeService {
                         if result.is_err() {
                             result.unwrap_err()
                         } else {
+                            if attribute_id != AttributeId::Value {
+                                address_space.record_attribute_change();
+                            } else {
+                                address_space.record_semantic_change(&node_to_write.node_id);
+                            }
                             StatusCode::Good
                         }
                     }
@@ -8,7 +8,7 @@ use opcua_types::{status_code::StatusCode, *};
 
 use crate::{
     address_space::address_space::AddressSpace,
-    events::audit::{certificate_events::*, session_events::*},
+    events::audit::{cancel_event::AuditCancelEventType, certificate_events::*, session_events::*},
     session::Session,
     state::ServerState,
 };
@@ -121,6 +121,27 @@ pub fn log_close_session(
     let _ = server_state.raise_and_log(event);
 }
 
+pub fn log_cancel(
+    server_state: &ServerState,
+    session: &Session,
+    address_space: Arc<RwLock<AddressSpace>>,
+    status: bool,
+    request: &CancelRequest,
+) {
+    let node_id = next_node_id(address_space);
+    let now = DateTime::now();
+
+    let session_id = session.session_id().clone();
+    let event = AuditCancelEventType::new(node_id, now)
+        .status(status)
+        .client_user_id(session.client_user_id())
+        .client_audit_entry_id(request.request_header.audit_entry_id.clone())
+        .session_id(session_id)
+        .request_handle(request.request_handle);
+
+    let _ = server_state.raise_and_log(event);
+}
+
 pub fn log_certificate_error(
     server_state: &ServerState,
     address_space: Arc<RwLock<AddressSpace>>,
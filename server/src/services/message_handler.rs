@@ -5,6 +5,7 @@
 use std::sync::{Arc, RwLock};
 
 use chrono::Utc;
+use futures::{future, future::Either, Future, IntoFuture};
 
 use opcua_core::supported_message::SupportedMessage;
 use opcua_crypto::{CertificateStore, SecurityPolicy};
@@ -35,13 +36,13 @@ pub struct MessageHandler {
     /// Session state
     session: Arc<RwLock<Session>>,
     /// Attribute service
-    attribute_service: AttributeService,
+    attribute_service: Arc<AttributeService>,
     /// Discovery service
     discovery_service: DiscoveryService,
     /// Node Management service
     node_management_service: NodeManagementService,
     /// Method service
-    method_service: MethodService,
+    method_service: Arc<MethodService>,
     /// MonitoredItem service
     monitored_item_service: MonitoredItemService,
     /// Query service
@@ -51,7 +52,7 @@ pub struct MessageHandler {
     /// Subscription service
     subscription_service: SubscriptionService,
     /// View service
-    view_service: ViewService,
+    view_service: Arc<ViewService>,
 }
 
 impl MessageHandler {
@@ -66,14 +67,14 @@ impl MessageHandler {
             server_state,
             session,
             address_space,
-            attribute_service: AttributeService::new(),
+            attribute_service: Arc::new(AttributeService::new()),
             discovery_service: DiscoveryService::new(),
-            method_service: MethodService::new(),
+            method_service: Arc::new(MethodService::new()),
             monitored_item_service: MonitoredItemService::new(),
             node_management_service: NodeManagementService::new(),
             query_service: QueryService::new(),
             session_service: SessionService::new(),
-            view_service: ViewService::new(),
+            view_service: Arc::new(ViewService::new()),
             subscription_service: SubscriptionService::new(),
         }
     }
@@ -149,7 +150,10 @@ impl MessageHandler {
                     message,
                     session.clone(),
                     "",
-                    move || self.session_service.cancel(server_state, session, request),
+                    move || {
+                        self.session_service
+                            .cancel(server_state, session, address_space, request)
+                    },
                 )
             }
 
@@ -220,14 +224,15 @@ impl MessageHandler {
 
             // View Service Set, OPC UA Part 4, Section 5.8
             SupportedMessage::BrowseRequest(request) => {
-                Self::validate_active_session_service_request(
+                let view_service = self.view_service.clone();
+                let request = request.clone();
+                Self::validate_active_session_async_service_request(
                     message,
+                    request_id,
                     session.clone(),
                     BROWSE_COUNT,
-                    move || {
-                        self.view_service
-                            .browse(server_state, session, address_space, request)
-                    },
+                    sender,
+                    move || Ok(view_service.browse(server_state, session, address_space, &request)),
                 )
             }
             SupportedMessage::BrowseNextRequest(request) => {
@@ -309,39 +314,49 @@ impl MessageHandler {
 
             // Attribute Service Set, OPC UA Part 4, Section 5.10
             SupportedMessage::ReadRequest(request) => {
-                Self::validate_active_session_service_request(
+                let attribute_service = self.attribute_service.clone();
+                let request = request.clone();
+                Self::validate_active_session_async_service_request(
                     message,
+                    request_id,
                     session.clone(),
                     READ_COUNT,
+                    sender,
                     move || {
-                        self.attribute_service
-                            .read(server_state, session, address_space, request)
+                        attribute_service.async_read(server_state, session, address_space, &request)
                     },
                 )
             }
             SupportedMessage::HistoryReadRequest(request) => {
-                Self::validate_active_session_service_request(
+                let attribute_service = self.attribute_service.clone();
+                let request = request.clone();
+                Self::validate_active_session_async_service_request(
                     message,
+                    request_id,
                     session.clone(),
                     HISTORY_READ_COUNT,
+                    sender,
                     move || {
-                        self.attribute_service.history_read(
+                        attribute_service.async_history_read(
                             server_state,
                             session,
                             address_space,
-                            request,
+                            &request,
                         )
                     },
                 )
             }
             SupportedMessage::WriteRequest(request) => {
-                Self::validate_active_session_service_request(
+                let attribute_service = self.attribute_service.clone();
+                let request = request.clone();
+                Self::validate_active_session_async_service_request(
                     message,
+                    request_id,
                     session.clone(),
                     WRITE_COUNT,
+                    sender,
                     move || {
-                        self.attribute_service
-                            .write(server_state, session, address_space, request)
+                        Ok(attribute_service.write(server_state, session, address_space, &request))
                     },
                 )
             }
@@ -363,13 +378,16 @@ impl MessageHandler {
 
             // Method Service Set, OPC UA Part 4, Section 5.11
             SupportedMessage::CallRequest(request) => {
-                Self::validate_active_session_service_request(
+                let method_service = self.method_service.clone();
+                let request = request.clone();
+                Self::validate_active_session_async_service_request(
                     message,
+                    request_id,
                     session.clone(),
                     CALL_COUNT,
+                    sender,
                     move || {
-                        self.method_service
-                            .call(server_state, session, address_space, request)
+                        method_service.async_call(server_state, session, address_space, &request)
                     },
                 )
             }
@@ -492,8 +510,11 @@ impl MessageHandler {
                     session.clone(),
                     TRANSFER_SUBSCRIPTIONS_COUNT,
                     move || {
-                        self.subscription_service
-                            .transfer_subscriptions(session, request)
+                        self.subscription_service.transfer_subscriptions(
+                            server_state,
+                            session,
+                            request,
+                        )
                     },
                 )
             }
@@ -675,6 +696,82 @@ impl MessageHandler {
         Some(response)
     }
 
+    /// Validate the security of the call and also for an active session, then process the request
+    /// on the runtime's worker pool. The response is sent when the action completes, so it may go
+    /// out of order with the responses to requests received after it. Only services whose
+    /// requests do not depend on the order they are processed in should use this.
+    fn validate_active_session_async_service_request<F, R>(
+        request: &SupportedMessage,
+        request_id: u32,
+        session: Arc<RwLock<Session>>,
+        diagnostic_key: &'static str,
+        sender: &MessageSender,
+        action: F,
+    ) -> Option<SupportedMessage>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: IntoFuture<Item = SupportedMessage, Error = ()>,
+        R::Future: Send + 'static,
+    {
+        let now = Utc::now();
+        let request_header = request.request_header();
+        let response = if let Err(response) =
+            Self::is_authentication_token_valid(session.clone(), request_header)
+        {
+            response
+        } else if let Err(response) = Self::is_session_activated(session.clone(), request_header) {
+            response
+        } else if let Err(response) =
+            Self::is_session_timed_out(session.clone(), request_header, now)
+        {
+            response
+        } else {
+            Self::update_last_service_request_timestamp(session.clone(), now);
+            // The request can be cancelled until it is answered
+            let cancelled = {
+                let mut session = trace_write_lock_unwrap!(session);
+                session.add_outstanding_request(request_id, request_header.request_handle)
+            };
+            let request_header = request_header.clone();
+            let sender = sender.clone();
+            // A dropped sender means the request was not cancelled so it is left to complete
+            let task = cancelled
+                .or_else(|_| future::empty::<(), ()>())
+                .select2(future::lazy(action))
+                .then(move |result| {
+                    let response = match result {
+                        Ok(Either::B((response, _))) => response,
+                        Ok(Either::A(_)) => {
+                            // Dropping the action stops whatever it was waiting on
+                            debug!(
+                                "Request {} with request handle {} was cancelled",
+                                request_id, request_header.request_handle
+                            );
+                            ServiceFault::new(
+                                &request_header,
+                                StatusCode::BadRequestCancelledByClient,
+                            )
+                            .into()
+                        }
+                        Err(_) => {
+                            ServiceFault::new(&request_header, StatusCode::BadInternalError).into()
+                        }
+                    };
+                    {
+                        let mut session = trace_write_lock_unwrap!(session);
+                        session.remove_outstanding_request(request_id);
+                    }
+                    Self::diag_service_response(session, true, &response, diagnostic_key);
+                    sender.send_message(request_id, response);
+                    Ok(())
+                });
+            tokio::spawn(task);
+            return None;
+        };
+        Self::diag_service_response(session, false, &response, diagnostic_key);
+        Some(response)
+    }
+
     /// Increment count of request in session diagnostics
     fn diag_authorized_request(session_diagnostics: &mut SessionDiagnostics, authorized: bool) {
         if authorized {
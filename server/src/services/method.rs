@@ -4,10 +4,18 @@
 
 use std::sync::{Arc, RwLock};
 
+use futures::{future, Future};
+
 use opcua_core::supported_message::SupportedMessage;
 use opcua_types::{status_code::StatusCode, *};
 
-use crate::{address_space::AddressSpace, services::Service, session::Session, state::ServerState};
+use crate::{
+    address_space::AddressSpace,
+    callbacks::AsyncResult,
+    services::{Service, ServiceFuture},
+    session::Session,
+    state::ServerState,
+};
 
 /// The method service. Allows a client to call a method on the server.
 pub(crate) struct MethodService;
@@ -23,20 +31,22 @@ impl MethodService {
         MethodService {}
     }
 
-    pub fn call(
+    /// Calls the methods in the request. The response completes when every method has returned
+    /// its result, which may be asynchronously.
+    pub fn async_call(
         &self,
         server_state: Arc<RwLock<ServerState>>,
         session: Arc<RwLock<Session>>,
         address_space: Arc<RwLock<AddressSpace>>,
         request: &CallRequest,
-    ) -> SupportedMessage {
+    ) -> ServiceFuture {
         if let Some(ref calls) = request.methods_to_call {
             let server_state = trace_read_lock_unwrap!(server_state);
             if calls.len() <= server_state.operational_limits.max_nodes_per_method_call {
                 let mut session = trace_write_lock_unwrap!(session);
                 let mut address_space = trace_write_lock_unwrap!(address_space);
 
-                let results: Vec<CallMethodResult> = calls
+                let results = calls
                     .iter()
                     .map(|request| {
                         trace!(
@@ -49,39 +59,63 @@ impl MethodService {
                         // state of the system (acknowledge, batch sequencing or other system changes) must
                         // generate an AuditUpdateMethodEventType or a subtype of it.
 
-                        // Call the method via whatever is registered in the address space
-                        match address_space.call_method(&server_state, &mut session, request) {
-                            Ok(response) => response,
-                            Err(status_code) => {
-                                // Call didn't work for some reason
-                                error!(
-                                    "Call to {:?} on {:?} failed with status code {}",
-                                    request.method_id, request.object_id, status_code
-                                );
-                                CallMethodResult {
-                                    status_code,
-                                    input_argument_results: None,
-                                    input_argument_diagnostic_infos: None,
-                                    output_arguments: None,
+                        // Call the method via the node manager of the object's namespace, or
+                        // whatever is registered in the address space
+                        let result: AsyncResult<CallMethodResult> = if let Some(node_manager) =
+                            address_space.node_manager(&request.object_id)
+                        {
+                            let mut node_manager = trace_lock_unwrap!(node_manager);
+                            Box::new(future::result(node_manager.call(&mut session, request)))
+                        } else {
+                            address_space.async_call_method(&server_state, &mut session, request)
+                        };
+                        let (method_id, object_id) =
+                            (request.method_id.clone(), request.object_id.clone());
+                        result.then(move |result| -> Result<CallMethodResult, ()> {
+                            Ok(match result {
+                                Ok(response) => response,
+                                Err(status_code) => {
+                                    // Call didn't work for some reason
+                                    error!(
+                                        "Call to {:?} on {:?} failed with status code {}",
+                                        method_id, object_id, status_code
+                                    );
+                                    CallMethodResult {
+                                        status_code,
+                                        input_argument_results: None,
+                                        input_argument_diagnostic_infos: None,
+                                        output_arguments: None,
+                                    }
                                 }
-                            }
-                        }
+                            })
+                        })
                     })
-                    .collect();
+                    .collect::<Vec<_>>();
                 // Produce response
-                let response = CallResponse {
-                    response_header: ResponseHeader::new_good(&request.request_header),
-                    results: Some(results),
-                    diagnostic_infos: None,
-                };
-                response.into()
+                let request_header = request.request_header.clone();
+                Box::new(
+                    future::join_all(results).map(move |results| -> SupportedMessage {
+                        let response = CallResponse {
+                            response_header: ResponseHeader::new_good(&request_header),
+                            results: Some(results),
+                            diagnostic_infos: None,
+                        };
+                        response.into()
+                    }),
+                )
             } else {
                 error!("Call request, too many calls {}", calls.len());
-                self.service_fault(&request.request_header, StatusCode::BadTooManyOperations)
+                Box::new(future::ok(self.service_fault(
+                    &request.request_header,
+                    StatusCode::BadTooManyOperations,
+                )))
             }
         } else {
             warn!("Call has nothing to do");
-            self.service_fault(&request.request_header, StatusCode::BadNothingToDo)
+            Box::new(future::ok(self.service_fault(
+                &request.request_header,
+                StatusCode::BadNothingToDo,
+            )))
         }
     }
 }
@@ -2,11 +2,17 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
+use futures::Future;
+
 use opcua_core::supported_message::SupportedMessage;
 use opcua_types::{status_code::StatusCode, RequestHeader, ServiceFault};
 
 pub mod message_handler;
 
+/// The response to a request that a service processes asynchronously. The message handler
+/// sends it when the future completes, so it may go out of order with other responses.
+pub(crate) type ServiceFuture = Box<dyn Future<Item = SupportedMessage, Error = ()> + Send>;
+
 /// The implementation of a service, or a set of services will implement this trait
 trait Service {
     fn name(&self) -> String;
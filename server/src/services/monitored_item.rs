@@ -40,7 +40,15 @@ impl MonitoredItemService {
 
             let items_to_create = request.items_to_create.as_ref().unwrap();
             // Find subscription and add items to it
-            if let Some(subscription) = session.subscriptions_mut().get_mut(request.subscription_id)
+            if items_to_create.len() > server_state.operational_limits.max_monitored_items_per_call
+            {
+                warn!(
+                    "CreateMonitoredItemsRequest too many items to create {}",
+                    items_to_create.len()
+                );
+                self.service_fault(&request.request_header, StatusCode::BadTooManyOperations)
+            } else if let Some(subscription) =
+                session.subscriptions_mut().get_mut(request.subscription_id)
             {
                 let now = chrono::Utc::now();
                 let results = Some(subscription.create_monitored_items(
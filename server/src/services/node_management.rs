@@ -66,6 +66,7 @@ impl NodeManagementService {
                             }
                         })
                         .collect();
+                    address_space.raise_model_change_events();
                     let response = AddNodesResponse {
                         response_header: ResponseHeader::new_good(&request.request_header),
                         results: Some(results),
@@ -106,6 +107,7 @@ impl NodeManagementService {
                         .iter()
                         .map(|r| Self::add_reference(&session, &mut address_space, r))
                         .collect();
+                    address_space.raise_model_change_events();
                     AddReferencesResponse {
                         response_header: ResponseHeader::new_good(&request.request_header),
                         results: Some(results),
@@ -148,6 +150,7 @@ impl NodeManagementService {
                             Self::delete_node(&session, &mut address_space, node_to_delete)
                         })
                         .collect();
+                    address_space.raise_model_change_events();
                     let response = DeleteNodesResponse {
                         response_header: ResponseHeader::new_good(&request.request_header),
                         results: Some(results),
@@ -188,6 +191,7 @@ impl NodeManagementService {
                         .iter()
                         .map(|r| Self::delete_reference(&session, &mut address_space, r))
                         .collect();
+                    address_space.raise_model_change_events();
                     DeleteReferencesResponse {
                         response_header: ResponseHeader::new_good(&request.request_header),
                         results: Some(results),
@@ -272,8 +272,24 @@ impl SessionService {
         address_space: Arc<RwLock<AddressSpace>>,
         request: &CloseSessionRequest,
     ) -> SupportedMessage {
-        let server_state = trace_write_lock_unwrap!(server_state);
+        let mut server_state = trace_write_lock_unwrap!(server_state);
         let mut session = trace_write_lock_unwrap!(session);
+        if request.delete_subscriptions {
+            session.subscriptions_mut().delete_all();
+        } else {
+            // Durable subscriptions outlive the session, so they are kept for their user before
+            // the session forgets who that is
+            let client_user_id = session.client_user_id();
+            session
+                .subscriptions_mut()
+                .take_durable_subscriptions(&client_user_id)
+                .into_iter()
+                .for_each(|durable_subscription| {
+                    server_state
+                        .durable_subscriptions
+                        .insert(durable_subscription)
+                });
+        }
         session.set_authentication_token(NodeId::null());
         session.set_user_identity(IdentityToken::None);
         session.set_activated(false);
@@ -286,16 +302,26 @@ impl SessionService {
         .into()
     }
 
+    /// Cancels the outstanding requests on the session with the request handle. A cancelled
+    /// request is answered with `BadRequestCancelledByClient`.
     pub fn cancel(
         &self,
-        _server_state: Arc<RwLock<ServerState>>,
-        _session: Arc<RwLock<Session>>,
+        server_state: Arc<RwLock<ServerState>>,
+        session: Arc<RwLock<Session>>,
+        address_space: Arc<RwLock<AddressSpace>>,
         request: &CancelRequest,
     ) -> SupportedMessage {
-        // This service call currently does nothing
+        let server_state = trace_read_lock_unwrap!(server_state);
+        let mut session = trace_write_lock_unwrap!(session);
+        let cancel_count = session.cancel_requests(request.request_handle);
+        debug!(
+            "Cancel request for request handle {} cancelled {} requests",
+            request.request_handle, cancel_count
+        );
+        audit::log_cancel(&server_state, &session, address_space, true, request);
         CancelResponse {
             response_header: ResponseHeader::new_good(&request.request_header),
-            cancel_count: 0,
+            cancel_count,
         }
         .into()
     }
@@ -57,7 +57,7 @@ impl SubscriptionService {
 
             // Create a new subscription
             let publishing_enabled = request.publishing_enabled;
-            let subscription = Subscription::new(
+            let mut subscription = Subscription::new(
                 server_state.diagnostics.clone(),
                 subscription_id,
                 publishing_enabled,
@@ -66,6 +66,7 @@ impl SubscriptionService {
                 revised_max_keep_alive_count,
                 request.priority,
             );
+            subscription.set_max_notifications_per_publish(request.max_notifications_per_publish);
             subscriptions.insert(subscription_id, subscription);
 
             // Create the response
@@ -112,10 +113,12 @@ impl SubscriptionService {
             subscription.set_publishing_interval(revised_publishing_interval);
             subscription.set_max_keep_alive_count(revised_max_keep_alive_count);
             subscription.set_max_lifetime_count(revised_lifetime_count);
+            // A durable subscription keeps the lifetime it was given in hours
+            let revised_lifetime_count = subscription.max_lifetime_count();
             subscription.set_priority(request.priority);
+            subscription.set_max_notifications_per_publish(request.max_notifications_per_publish);
             subscription.reset_lifetime_counter();
             subscription.reset_keep_alive_counter();
-            // ...max_notifications_per_publish??
 
             ModifySubscriptionResponse {
                 response_header: ResponseHeader::new_good(&request.request_header),
@@ -163,24 +166,49 @@ impl SubscriptionService {
         }
     }
 
-    /// Handles a TransferSubscriptionsRequest
+    /// Handles a TransferSubscriptionsRequest. Only durable subscriptions which are waiting for a
+    /// session, i.e. whose session has closed or which were restored at startup, can be
+    /// transferred. A subscription still held by another live session is reported as invalid.
     pub fn transfer_subscriptions(
         &self,
-        _session: Arc<RwLock<Session>>,
+        server_state: Arc<RwLock<ServerState>>,
+        session: Arc<RwLock<Session>>,
         request: &TransferSubscriptionsRequest,
     ) -> SupportedMessage {
         if is_empty_option_vec!(request.subscription_ids) {
             self.service_fault(&request.request_header, StatusCode::BadNothingToDo)
         } else {
+            let mut server_state = trace_write_lock_unwrap!(server_state);
+            let mut session = trace_write_lock_unwrap!(session);
+
+            let max_subscriptions = server_state.max_subscriptions;
+            let client_user_id = session.client_user_id();
             let subscription_ids = request.subscription_ids.as_ref().unwrap();
             let results = {
-                // TODO this is a stub. The real thing should look up subscriptions belonging to
-                //  other sessions and transfer them across to this one.
+                let subscriptions = session.subscriptions_mut();
                 let results = subscription_ids
                     .iter()
-                    .map(|_subscription_id| TransferResult {
-                        status_code: StatusCode::BadSubscriptionIdInvalid,
-                        available_sequence_numbers: None,
+                    .map(|subscription_id| {
+                        if max_subscriptions > 0 && subscriptions.len() >= max_subscriptions {
+                            return TransferResult {
+                                status_code: StatusCode::BadTooManySubscriptions,
+                                available_sequence_numbers: None,
+                            };
+                        }
+                        match server_state
+                            .durable_subscriptions
+                            .take(*subscription_id, &client_user_id)
+                        {
+                            Ok(durable_subscription) => TransferResult {
+                                status_code: StatusCode::Good,
+                                available_sequence_numbers: subscriptions
+                                    .transfer(durable_subscription, request.send_initial_values),
+                            },
+                            Err(status_code) => TransferResult {
+                                status_code,
+                                available_sequence_numbers: None,
+                            },
+                        }
                     })
                     .collect::<Vec<TransferResult>>();
                 Some(results)
@@ -10,7 +10,7 @@ use opcua_crypto::random;
 use opcua_types::{node_ids::ReferenceTypeId, status_code::StatusCode, *};
 
 use crate::{
-    address_space::{relative_path, AddressSpace},
+    address_space::{references::Reference, relative_path, AddressSpace},
     continuation_point::BrowseContinuationPoint,
     services::Service,
     session::Session,
@@ -164,12 +164,20 @@ impl ViewService {
                                 targets: None,
                             }
                         } else {
-                            // Starting from the node_id, find paths
-                            match relative_path::find_nodes_relative_path(
-                                &address_space,
-                                &node_id,
-                                &browse_path.relative_path,
-                            ) {
+                            // Starting from the node_id, find paths. A path starting in the
+                            // namespace of a node manager is resolved by the manager.
+                            let result =
+                                if let Some(node_manager) = address_space.node_manager(&node_id) {
+                                    let mut node_manager = trace_lock_unwrap!(node_manager);
+                                    node_manager.translate_browse_path(browse_path)
+                                } else {
+                                    relative_path::find_nodes_relative_path(
+                                        &address_space,
+                                        &node_id,
+                                        &browse_path.relative_path,
+                                    )
+                                };
+                            match result {
                                 Err(err) => {
                                     trace!(
                                         "Browse path result for find nodes returned in error {}",
@@ -336,7 +344,31 @@ impl ViewService {
         max_references_per_node: usize,
     ) -> Result<BrowseResult, StatusCode> {
         // Node must exist or there will be no references
-        if node_to_browse.node_id.is_null() || !address_space.node_exists(&node_to_browse.node_id) {
+        if node_to_browse.node_id.is_null() {
+            return Err(StatusCode::BadNodeIdUnknown);
+        }
+
+        // Nodes in the namespace of a node manager are browsed by the manager
+        if let Some(node_manager) = address_space.node_manager(&node_to_browse.node_id) {
+            let references = {
+                let mut node_manager = trace_lock_unwrap!(node_manager);
+                if !node_manager.node_exists(&node_to_browse.node_id) {
+                    return Err(StatusCode::BadNodeIdUnknown);
+                }
+                node_manager.browse(session, node_to_browse)?
+            };
+            let reference_descriptions =
+                Self::apply_browse_masks(node_to_browse, references, max_references_per_node);
+            return Ok(Self::reference_description_to_browse_result(
+                session,
+                address_space,
+                &reference_descriptions,
+                0,
+                max_references_per_node,
+            ));
+        }
+
+        if !address_space.node_exists(&node_to_browse.node_id) {
             return Err(StatusCode::BadNodeIdUnknown);
         }
 
@@ -368,6 +400,8 @@ impl ViewService {
         // Construct descriptions for each reference
         let mut reference_descriptions: Vec<ReferenceDescription> =
             Vec::with_capacity(max_references_per_node);
+        // Display names are chosen by the session's preferred locales
+        let locale_ids = session.preferred_locale_ids();
         for (idx, reference) in references.iter().enumerate() {
             if idx < starting_index {
                 continue;
@@ -378,6 +412,20 @@ impl ViewService {
             }
             let target_node = address_space.find_node(&target_node_id);
             if target_node.is_none() {
+                // The target may be in the namespace of a node manager, in which case the
+                // manager is asked to describe it
+                if let Some(reference_description) = Self::managed_reference_description(
+                    session,
+                    address_space,
+                    reference,
+                    idx < inverse_ref_idx,
+                ) {
+                    reference_descriptions.extend(Self::apply_browse_masks(
+                        node_to_browse,
+                        vec![reference_description],
+                        1,
+                    ));
+                }
                 continue;
             }
 
@@ -420,7 +468,7 @@ impl ViewService {
                 };
             let display_name =
                 if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_DISPLAY_NAME) {
-                    target_node.display_name().clone()
+                    target_node.display_name_for_locales(&locale_ids)
                 } else {
                     LocalizedText::null()
                 };
@@ -468,6 +516,104 @@ impl ViewService {
         ))
     }
 
+    /// Describes the target of a reference from the address space into the namespace of a
+    /// node manager by reading the target's attributes from the manager.
+    fn managed_reference_description(
+        session: &Session,
+        address_space: &AddressSpace,
+        reference: &Reference,
+        is_forward: bool,
+    ) -> Option<ReferenceDescription> {
+        let node_manager = address_space.node_manager(&reference.target_node)?;
+        let mut node_manager = trace_lock_unwrap!(node_manager);
+        let mut read_attribute = |attribute_id: AttributeId| -> Option<Variant> {
+            let node_to_read = ReadValueId {
+                node_id: reference.target_node.clone(),
+                attribute_id: attribute_id as u32,
+                index_range: UAString::null(),
+                data_encoding: QualifiedName::null(),
+            };
+            node_manager
+                .read(session, &node_to_read, 0f64, TimestampsToReturn::Neither)
+                .value
+        };
+        let node_class = match read_attribute(AttributeId::NodeClass) {
+            Some(Variant::Int32(node_class)) => match node_class {
+                1 => NodeClass::Object,
+                2 => NodeClass::Variable,
+                4 => NodeClass::Method,
+                8 => NodeClass::ObjectType,
+                16 => NodeClass::VariableType,
+                32 => NodeClass::ReferenceType,
+                64 => NodeClass::DataType,
+                128 => NodeClass::View,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let browse_name = match read_attribute(AttributeId::BrowseName) {
+            Some(Variant::QualifiedName(browse_name)) => *browse_name,
+            _ => QualifiedName::null(),
+        };
+        let display_name = match read_attribute(AttributeId::DisplayName) {
+            Some(Variant::LocalizedText(display_name)) => *display_name,
+            _ => LocalizedText::null(),
+        };
+        Some(ReferenceDescription {
+            node_id: ExpandedNodeId::new(reference.target_node.clone()),
+            reference_type_id: reference.reference_type.clone(),
+            is_forward,
+            node_class,
+            browse_name,
+            display_name,
+            type_definition: ExpandedNodeId::null(),
+        })
+    }
+
+    /// Filters the references returned by a node manager by the node class mask of the browse
+    /// description and blanks out the fields that are not in its result mask.
+    fn apply_browse_masks(
+        node_to_browse: &BrowseDescription,
+        references: Vec<ReferenceDescription>,
+        max_references_per_node: usize,
+    ) -> Vec<ReferenceDescription> {
+        let result_mask =
+            BrowseDescriptionResultMask::from_bits_truncate(node_to_browse.result_mask);
+        let node_class_mask = NodeClassMask::from_bits_truncate(node_to_browse.node_class_mask);
+        let mut reference_descriptions = Vec::with_capacity(max_references_per_node);
+        for mut reference in references {
+            if reference.node_id.is_null() {
+                continue;
+            }
+            if reference.node_class != NodeClass::Unspecified && !node_class_mask.is_empty() {
+                let node_class = NodeClassMask::from_bits_truncate(reference.node_class as u32);
+                if !node_class_mask.contains(node_class) {
+                    continue;
+                }
+            }
+            if !result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_REFERENCE_TYPE) {
+                reference.reference_type_id = NodeId::null();
+            }
+            if !result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_IS_FORWARD) {
+                reference.is_forward = true;
+            }
+            if !result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_NODE_CLASS) {
+                reference.node_class = NodeClass::Unspecified;
+            }
+            if !result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_BROWSE_NAME) {
+                reference.browse_name = QualifiedName::null();
+            }
+            if !result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_DISPLAY_NAME) {
+                reference.display_name = LocalizedText::null();
+            }
+            if !result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_TYPE_DEFINITION) {
+                reference.type_definition = ExpandedNodeId::null();
+            }
+            reference_descriptions.push(reference);
+        }
+        reference_descriptions
+    }
+
     fn browse_from_continuation_point(
         session: &mut Session,
         address_space: &AddressSpace,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 use std::{
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     sync::{
         atomic::{AtomicI32, Ordering},
         Arc, RwLock,
@@ -10,6 +10,7 @@ use std::{
 };
 
 use chrono::{self, Utc};
+use futures::sync::oneshot;
 
 use opcua_core::comms::secure_channel::{Role, SecureChannel};
 use opcua_crypto::X509;
@@ -75,6 +76,8 @@ pub struct Session {
     user_identity: IdentityToken,
     /// Session's preferred locale ids
     locale_ids: Option<Vec<UAString>>,
+    /// Server's locale ids from configuration, used as a fallback after the session's own
+    server_locale_ids: Vec<UAString>,
     /// Negotiated max request message size
     max_request_message_size: u32,
     /// Negotiated max response message size
@@ -102,6 +105,19 @@ pub struct Session {
     can_modify_address_space: bool,
     /// Timestamp of the last service request to have happened (only counts service requests while there is a session)
     last_service_request_timestamp: DateTimeUtc,
+    /// Maximum lifetime in hours of a durable subscription, or 0 if subscriptions cannot be made
+    /// durable
+    max_subscription_lifetime_in_hours: u32,
+    /// Requests being processed in the background that the client may cancel, by request id
+    outstanding_requests: HashMap<u32, OutstandingRequest>,
+}
+
+/// A request being processed in the background
+struct OutstandingRequest {
+    /// The client's handle for the request, which a Cancel request refers to
+    request_handle: u32,
+    /// Signals the request to stop
+    cancel: oneshot::Sender<()>,
 }
 
 impl Drop for Session {
@@ -132,6 +148,7 @@ impl Session {
             session_timeout: 0f64,
             user_identity: IdentityToken::None,
             locale_ids: None,
+            server_locale_ids: Vec::new(),
             max_request_message_size: 0,
             max_response_message_size: 0,
             endpoint_url: UAString::null(),
@@ -141,6 +158,9 @@ impl Session {
             diagnostics: Arc::new(RwLock::new(ServerDiagnostics::default())),
             session_diagnostics: Arc::new(RwLock::new(SessionDiagnostics::default())),
             last_service_request_timestamp: Utc::now(),
+            max_subscription_lifetime_in_hours:
+                super::constants::DEFAULT_MAX_SUBSCRIPTION_LIFETIME_IN_HOURS,
+            outstanding_requests: HashMap::new(),
         };
         {
             let mut diagnostics = trace_write_lock_unwrap!(session.diagnostics);
@@ -151,17 +171,33 @@ impl Session {
 
     /// Create a `Session` from a `Server`
     pub fn new(server: &Server) -> Session {
-        let max_browse_continuation_points = super::constants::MAX_BROWSE_CONTINUATION_POINTS;
-
         let server_state = server.server_state();
         let server_state = trace_read_lock_unwrap!(server_state);
         let max_subscriptions = server_state.max_subscriptions;
         let diagnostics = server_state.diagnostics.clone();
-        let (decoding_limits, can_modify_address_space) = {
+        let (
+            decoding_limits,
+            can_modify_address_space,
+            max_browse_continuation_points,
+            server_locale_ids,
+            max_subscription_lifetime_in_hours,
+        ) = {
             let config = trace_read_lock_unwrap!(server_state.config);
             (
                 config.decoding_limits(),
                 config.limits.clients_can_modify_address_space,
+                config.limits.max_browse_continuation_points,
+                config
+                    .locale_ids
+                    .iter()
+                    .map(UAString::from)
+                    .collect::<Vec<_>>(),
+                config
+                    .durable_subscriptions
+                    .as_ref()
+                    .map_or(0, |durable_subscriptions| {
+                        durable_subscriptions.max_lifetime_in_hours
+                    }),
             )
         };
 
@@ -185,6 +221,7 @@ impl Session {
             session_timeout: 0f64,
             user_identity: IdentityToken::None,
             locale_ids: None,
+            server_locale_ids,
             max_request_message_size: 0,
             max_response_message_size: 0,
             endpoint_url: UAString::null(),
@@ -194,6 +231,8 @@ impl Session {
             diagnostics,
             session_diagnostics: Arc::new(RwLock::new(SessionDiagnostics::default())),
             last_service_request_timestamp: Utc::now(),
+            max_subscription_lifetime_in_hours,
+            outstanding_requests: HashMap::new(),
         };
         {
             let mut diagnostics = trace_write_lock_unwrap!(session.diagnostics);
@@ -253,6 +292,9 @@ impl Session {
 
     pub fn set_max_response_message_size(&mut self, max_response_message_size: u32) {
         self.max_response_message_size = max_response_message_size;
+        // Publish responses must fit so subscriptions split their notifications to this size
+        self.subscriptions
+            .set_max_response_message_size(max_response_message_size);
     }
 
     pub fn endpoint_url(&self) -> &UAString {
@@ -288,6 +330,29 @@ impl Session {
 
     pub fn set_locale_ids(&mut self, locale_ids: Option<Vec<UAString>>) {
         self.locale_ids = locale_ids;
+        // Event notifications are localized when the subscription ticks so they must be told
+        let preferred_locale_ids = self.preferred_locale_ids();
+        self.subscriptions.set_locale_ids(preferred_locale_ids);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_server_locale_ids(&mut self, server_locale_ids: Vec<UAString>) {
+        self.server_locale_ids = server_locale_ids;
+        let preferred_locale_ids = self.preferred_locale_ids();
+        self.subscriptions.set_locale_ids(preferred_locale_ids);
+    }
+
+    /// Returns the locale ids that localized text should be chosen by, in order of preference.
+    /// These are the locales the client asked for in ActivateSession followed by the server's
+    /// own locales.
+    pub fn preferred_locale_ids(&self) -> Vec<UAString> {
+        let mut locale_ids = self.locale_ids.clone().unwrap_or_default();
+        self.server_locale_ids.iter().for_each(|locale_id| {
+            if !locale_ids.contains(locale_id) {
+                locale_ids.push(locale_id.clone());
+            }
+        });
+        locale_ids
     }
 
     pub fn client_certificate(&self) -> &Option<X509> {
@@ -360,6 +425,47 @@ impl Session {
         self.subscriptions.expire_stale_publish_requests(now);
     }
 
+    /// Adds a request that is being processed in the background. The returned receiver completes
+    /// when the client cancels the request.
+    pub(crate) fn add_outstanding_request(
+        &mut self,
+        request_id: u32,
+        request_handle: u32,
+    ) -> oneshot::Receiver<()> {
+        let (cancel, cancelled) = oneshot::channel();
+        self.outstanding_requests.insert(
+            request_id,
+            OutstandingRequest {
+                request_handle,
+                cancel,
+            },
+        );
+        cancelled
+    }
+
+    /// Removes a request that has been answered
+    pub(crate) fn remove_outstanding_request(&mut self, request_id: u32) {
+        self.outstanding_requests.remove(&request_id);
+    }
+
+    /// Cancels the outstanding requests with the request handle, including queued publish
+    /// requests. Returns the number of requests cancelled.
+    pub(crate) fn cancel_requests(&mut self, request_handle: u32) -> u32 {
+        let request_ids = self
+            .outstanding_requests
+            .iter()
+            .filter(|(_, request)| request.request_handle == request_handle)
+            .map(|(request_id, _)| *request_id)
+            .collect::<Vec<u32>>();
+        let cancel_count = request_ids
+            .iter()
+            .filter_map(|request_id| self.outstanding_requests.remove(request_id))
+            // A request that has just finished can no longer be cancelled
+            .filter_map(|request| request.cancel.send(()).ok())
+            .count() as u32;
+        cancel_count + self.subscriptions.cancel_publish_requests(request_handle)
+    }
+
     pub(crate) fn add_browse_continuation_point(
         &mut self,
         continuation_point: BrowseContinuationPoint,
@@ -419,6 +525,18 @@ impl Session {
         self.can_modify_address_space
     }
 
+    pub(crate) fn max_subscription_lifetime_in_hours(&self) -> u32 {
+        self.max_subscription_lifetime_in_hours
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_max_subscription_lifetime_in_hours(
+        &mut self,
+        max_subscription_lifetime_in_hours: u32,
+    ) {
+        self.max_subscription_lifetime_in_hours = max_subscription_lifetime_in_hours;
+    }
+
     #[cfg(test)]
     pub(crate) fn set_can_modify_address_space(&mut self, can_modify_address_space: bool) {
         self.can_modify_address_space = can_modify_address_space;
@@ -20,55 +20,20 @@ use opcua_types::{
 
 use crate::{
     callbacks::{RegisterNodes, UnregisterNodes},
-    config::{ServerConfig, ServerEndpoint},
-    constants,
+    config::{OperationalLimits, ServerConfig, ServerEndpoint},
     diagnostics::ServerDiagnostics,
     events::{
         audit::{AuditEvent, AuditLog},
         event::Event,
     },
-    historical::{HistoricalDataProvider, HistoricalEventProvider},
+    historical::{AsyncHistoricalDataProvider, HistoricalDataProvider, HistoricalEventProvider},
     identity_token::{
         IdentityToken, POLICY_ID_ANONYMOUS, POLICY_ID_USER_PASS_NONE, POLICY_ID_USER_PASS_RSA_15,
         POLICY_ID_USER_PASS_RSA_OAEP, POLICY_ID_X509,
     },
+    subscriptions::durable_subscriptions::DurableSubscriptions,
 };
 
-pub(crate) struct OperationalLimits {
-    pub max_nodes_per_translate_browse_paths_to_node_ids: usize,
-    pub max_nodes_per_read: usize,
-    pub max_nodes_per_write: usize,
-    pub max_nodes_per_method_call: usize,
-    pub max_nodes_per_browse: usize,
-    pub max_nodes_per_register_nodes: usize,
-    pub max_nodes_per_node_management: usize,
-    pub max_monitored_items_per_call: usize,
-    pub max_nodes_per_history_read_data: usize,
-    pub max_nodes_per_history_read_events: usize,
-    pub max_nodes_per_history_update_data: usize,
-    pub max_nodes_per_history_update_events: usize,
-}
-
-impl Default for OperationalLimits {
-    fn default() -> Self {
-        Self {
-            max_nodes_per_translate_browse_paths_to_node_ids:
-                constants::MAX_NODES_PER_TRANSLATE_BROWSE_PATHS_TO_NODE_IDS,
-            max_nodes_per_read: constants::MAX_NODES_PER_READ,
-            max_nodes_per_write: constants::MAX_NODES_PER_WRITE,
-            max_nodes_per_method_call: constants::MAX_NODES_PER_METHOD_CALL,
-            max_nodes_per_browse: constants::MAX_NODES_PER_BROWSE,
-            max_nodes_per_register_nodes: constants::MAX_NODES_PER_REGISTER_NODES,
-            max_nodes_per_node_management: constants::MAX_NODES_PER_NODE_MANAGEMENT,
-            max_monitored_items_per_call: constants::MAX_MONITORED_ITEMS_PER_CALL,
-            max_nodes_per_history_read_data: constants::MAX_NODES_PER_HISTORY_READ_DATA,
-            max_nodes_per_history_read_events: constants::MAX_NODES_PER_HISTORY_READ_EVENTS,
-            max_nodes_per_history_update_data: constants::MAX_NODES_PER_HISTORY_UPDATE_DATA,
-            max_nodes_per_history_update_events: constants::MAX_NODES_PER_HISTORY_UPDATE_EVENTS,
-        }
-    }
-}
-
 /// Server state is any state associated with the server as a whole that individual sessions might
 /// be interested in. That includes configuration info etc.
 pub struct ServerState {
@@ -113,6 +78,12 @@ pub struct ServerState {
     pub state: ServerStateType,
     /// Sets the abort flag that terminates the associated server
     pub abort: bool,
+    /// The time at which a requested graceful shutdown will happen
+    pub(crate) shutdown_deadline: Option<DateTimeUtc>,
+    /// The reason given for the shutdown, exposed through ServerStatus.ShutdownReason
+    pub(crate) shutdown_reason: LocalizedText,
+    /// Set once subscriptions have been sent their final status change notification
+    pub(crate) shutdown_notified: bool,
     /// Audit log
     pub(crate) audit_log: Arc<RwLock<AuditLog>>,
     /// Diagnostic information
@@ -123,8 +94,13 @@ pub struct ServerState {
     pub(crate) unregister_nodes_callback: Option<Box<dyn UnregisterNodes + Send + Sync>>,
     /// Callback for historical data
     pub(crate) historical_data_provider: Option<Box<dyn HistoricalDataProvider + Send + Sync>>,
+    /// Asynchronous callback for reading historical data
+    pub(crate) async_historical_data_provider:
+        Option<Box<dyn AsyncHistoricalDataProvider + Send + Sync>>,
     /// Callback for historical events
     pub(crate) historical_event_provider: Option<Box<dyn HistoricalEventProvider + Send + Sync>>,
+    /// Durable subscriptions which have outlived their sessions, waiting to be transferred
+    pub(crate) durable_subscriptions: DurableSubscriptions,
 }
 
 impl ServerState {
@@ -390,6 +366,46 @@ impl ServerState {
         self.state == ServerStateType::Running
     }
 
+    /// Begins a graceful shutdown of the server. The server state becomes `Shutdown` immediately
+    /// and the server counts down `delay_secs` seconds before notifying subscriptions, closing
+    /// sessions and stopping. Calling this again revises the delay and reason.
+    pub fn shutdown(&mut self, delay_secs: u32, reason: LocalizedText) {
+        info!(
+            "Server will shutdown in {} seconds, reason \"{}\"",
+            delay_secs, reason
+        );
+        self.state = ServerStateType::Shutdown;
+        self.shutdown_deadline =
+            Some(chrono::Utc::now() + chrono::Duration::seconds(delay_secs as i64));
+        self.shutdown_reason = reason;
+    }
+
+    /// Tests if a graceful shutdown has been requested
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutdown_deadline.is_some()
+    }
+
+    /// Returns the number of seconds until the server shuts down, or 0 if no shutdown is pending
+    pub fn seconds_till_shutdown(&self) -> u32 {
+        if let Some(ref shutdown_deadline) = self.shutdown_deadline {
+            let remaining = shutdown_deadline.signed_duration_since(chrono::Utc::now());
+            // Round up so the countdown only reaches 0 when the deadline has passed
+            let remaining_ms = remaining.num_milliseconds();
+            if remaining_ms > 0 {
+                ((remaining_ms + 999) / 1000) as u32
+            } else {
+                0
+            }
+        } else {
+            0
+        }
+    }
+
+    /// Returns the reason for the shutdown, which is null if no shutdown is pending
+    pub fn shutdown_reason(&self) -> &LocalizedText {
+        &self.shutdown_reason
+    }
+
     pub fn server_certificate_as_byte_string(&self) -> ByteString {
         if let Some(ref server_certificate) = self.server_certificate {
             server_certificate.as_byte_string()
@@ -663,6 +679,13 @@ impl ServerState {
         self.historical_data_provider = Some(historical_data_provider);
     }
 
+    pub fn set_async_historical_data_provider(
+        &mut self,
+        async_historical_data_provider: Box<dyn AsyncHistoricalDataProvider + Send + Sync>,
+    ) {
+        self.async_historical_data_provider = Some(async_historical_data_provider);
+    }
+
     pub fn set_historical_event_provider(
         &mut self,
         historical_event_provider: Box<dyn HistoricalEventProvider + Send + Sync>,
@@ -0,0 +1,515 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the implementation of `DurableSubscriptions`, which holds durable subscriptions while
+//! no session owns them, and the binary form durable subscriptions take when they are saved to disk.
+//!
+//! A durable subscription is one that a client has made durable with the SetSubscriptionDurable
+//! method. When its session goes away, the subscription carries on sampling into the bounded queues
+//! of its monitored items until the client transfers it to a new session with
+//! TransferSubscriptions or its lifetime expires.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use opcua_types::{
+    service_types::{
+        EventFieldList, MonitoredItemNotification, MonitoringMode, NotificationMessage,
+        ReadValueId, TimestampsToReturn,
+    },
+    status_code::StatusCode,
+    *,
+};
+
+use crate::{
+    address_space::AddressSpace,
+    diagnostics::ServerDiagnostics,
+    subscriptions::{
+        monitored_item::Notification,
+        subscription::{Subscription, TickReason},
+    },
+    util,
+};
+
+/// Identifies a durable subscriptions file and the version of its layout
+const DURABLE_SUBSCRIPTIONS_MAGIC: &str = "urn:opcua-rs:durable-subscriptions";
+const DURABLE_SUBSCRIPTIONS_VERSION: u32 = 1;
+
+fn vec_byte_len<T: BinaryEncoder<T>>(values: &[T]) -> usize {
+    4 + values.iter().map(|v| v.byte_len()).sum::<usize>()
+}
+
+// Lengths are written without the array limits of the decoder since a queue can easily hold more
+// notifications than a message may
+fn encode_vec<S: Write, T: BinaryEncoder<T>>(
+    stream: &mut S,
+    values: &[T],
+) -> EncodingResult<usize> {
+    let mut size = write_u32(stream, values.len() as u32)?;
+    for value in values {
+        size += value.encode(stream)?;
+    }
+    Ok(size)
+}
+
+fn decode_vec<S: Read, T: BinaryEncoder<T>>(
+    stream: &mut S,
+    decoding_limits: &DecodingLimits,
+) -> EncodingResult<Vec<T>> {
+    let count = read_u32(stream)?;
+    let mut values = Vec::new();
+    for _ in 0..count {
+        values.push(T::decode(stream, decoding_limits)?);
+    }
+    Ok(values)
+}
+
+impl BinaryEncoder<Notification> for Notification {
+    fn byte_len(&self) -> usize {
+        1 + match self {
+            Notification::MonitoredItemNotification(v) => v.byte_len(),
+            Notification::Event(v) => v.byte_len(),
+        }
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        match self {
+            Notification::MonitoredItemNotification(v) => {
+                Ok(write_u8(stream, 0u8)? + v.encode(stream)?)
+            }
+            Notification::Event(v) => Ok(write_u8(stream, 1u8)? + v.encode(stream)?),
+        }
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        match read_u8(stream)? {
+            0 => Ok(MonitoredItemNotification::decode(stream, decoding_limits)?.into()),
+            1 => Ok(EventFieldList::decode(stream, decoding_limits)?.into()),
+            kind => {
+                error!("Notification kind {} is not supported", kind);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+/// A monitored item of a durable subscription together with the notifications in its queue
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MonitoredItemSnapshot {
+    pub monitored_item_id: u32,
+    pub item_to_monitor: ReadValueId,
+    pub monitoring_mode: MonitoringMode,
+    pub client_handle: u32,
+    pub sampling_interval: f64,
+    pub filter: ExtensionObject,
+    pub discard_oldest: bool,
+    pub queue_size: u32,
+    pub timestamps_to_return: TimestampsToReturn,
+    pub triggered_items: Vec<u32>,
+    pub last_data_value: Option<DataValue>,
+    pub notification_queue: Vec<Notification>,
+    pub queue_overflow: bool,
+}
+
+impl BinaryEncoder<MonitoredItemSnapshot> for MonitoredItemSnapshot {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.monitored_item_id.byte_len();
+        size += self.item_to_monitor.byte_len();
+        size += self.monitoring_mode.byte_len();
+        size += self.client_handle.byte_len();
+        size += self.sampling_interval.byte_len();
+        size += self.filter.byte_len();
+        size += self.discard_oldest.byte_len();
+        size += self.queue_size.byte_len();
+        size += self.timestamps_to_return.byte_len();
+        size += vec_byte_len(&self.triggered_items);
+        size += 1 + self.last_data_value.as_ref().map_or(0, |v| v.byte_len());
+        size += vec_byte_len(&self.notification_queue);
+        size += self.queue_overflow.byte_len();
+        size
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.monitored_item_id.encode(stream)?;
+        size += self.item_to_monitor.encode(stream)?;
+        size += self.monitoring_mode.encode(stream)?;
+        size += self.client_handle.encode(stream)?;
+        size += self.sampling_interval.encode(stream)?;
+        size += self.filter.encode(stream)?;
+        size += self.discard_oldest.encode(stream)?;
+        size += self.queue_size.encode(stream)?;
+        size += self.timestamps_to_return.encode(stream)?;
+        size += encode_vec(stream, &self.triggered_items)?;
+        size += self.last_data_value.is_some().encode(stream)?;
+        if let Some(ref last_data_value) = self.last_data_value {
+            size += last_data_value.encode(stream)?;
+        }
+        size += encode_vec(stream, &self.notification_queue)?;
+        size += self.queue_overflow.encode(stream)?;
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let monitored_item_id = u32::decode(stream, decoding_limits)?;
+        let item_to_monitor = ReadValueId::decode(stream, decoding_limits)?;
+        let monitoring_mode = MonitoringMode::decode(stream, decoding_limits)?;
+        let client_handle = u32::decode(stream, decoding_limits)?;
+        let sampling_interval = f64::decode(stream, decoding_limits)?;
+        let filter = ExtensionObject::decode(stream, decoding_limits)?;
+        let discard_oldest = bool::decode(stream, decoding_limits)?;
+        let queue_size = u32::decode(stream, decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode(stream, decoding_limits)?;
+        let triggered_items = decode_vec(stream, decoding_limits)?;
+        let last_data_value = if bool::decode(stream, decoding_limits)? {
+            Some(DataValue::decode(stream, decoding_limits)?)
+        } else {
+            None
+        };
+        let notification_queue = decode_vec(stream, decoding_limits)?;
+        let queue_overflow = bool::decode(stream, decoding_limits)?;
+        Ok(MonitoredItemSnapshot {
+            monitored_item_id,
+            item_to_monitor,
+            monitoring_mode,
+            client_handle,
+            sampling_interval,
+            filter,
+            discard_oldest,
+            queue_size,
+            timestamps_to_return,
+            triggered_items,
+            last_data_value,
+            notification_queue,
+            queue_overflow,
+        })
+    }
+}
+
+/// The settings and state of a durable subscription, its monitored items and the notification
+/// messages waiting for a publish request
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SubscriptionSnapshot {
+    pub subscription_id: u32,
+    pub publishing_interval: f64,
+    pub max_lifetime_count: u32,
+    pub lifetime_counter: u32,
+    pub max_keep_alive_count: u32,
+    pub priority: u8,
+    pub max_notifications_per_publish: u32,
+    pub publishing_enabled: bool,
+    pub lifetime_in_hours: u32,
+    pub last_sequence_number: u32,
+    pub next_monitored_item_id: u32,
+    pub notifications: Vec<NotificationMessage>,
+    pub monitored_items: Vec<MonitoredItemSnapshot>,
+}
+
+impl BinaryEncoder<SubscriptionSnapshot> for SubscriptionSnapshot {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.subscription_id.byte_len();
+        size += self.publishing_interval.byte_len();
+        size += self.max_lifetime_count.byte_len();
+        size += self.lifetime_counter.byte_len();
+        size += self.max_keep_alive_count.byte_len();
+        size += self.priority.byte_len();
+        size += self.max_notifications_per_publish.byte_len();
+        size += self.publishing_enabled.byte_len();
+        size += self.lifetime_in_hours.byte_len();
+        size += self.last_sequence_number.byte_len();
+        size += self.next_monitored_item_id.byte_len();
+        size += vec_byte_len(&self.notifications);
+        size += vec_byte_len(&self.monitored_items);
+        size
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.subscription_id.encode(stream)?;
+        size += self.publishing_interval.encode(stream)?;
+        size += self.max_lifetime_count.encode(stream)?;
+        size += self.lifetime_counter.encode(stream)?;
+        size += self.max_keep_alive_count.encode(stream)?;
+        size += self.priority.encode(stream)?;
+        size += self.max_notifications_per_publish.encode(stream)?;
+        size += self.publishing_enabled.encode(stream)?;
+        size += self.lifetime_in_hours.encode(stream)?;
+        size += self.last_sequence_number.encode(stream)?;
+        size += self.next_monitored_item_id.encode(stream)?;
+        size += encode_vec(stream, &self.notifications)?;
+        size += encode_vec(stream, &self.monitored_items)?;
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(SubscriptionSnapshot {
+            subscription_id: u32::decode(stream, decoding_limits)?,
+            publishing_interval: f64::decode(stream, decoding_limits)?,
+            max_lifetime_count: u32::decode(stream, decoding_limits)?,
+            lifetime_counter: u32::decode(stream, decoding_limits)?,
+            max_keep_alive_count: u32::decode(stream, decoding_limits)?,
+            priority: u8::decode(stream, decoding_limits)?,
+            max_notifications_per_publish: u32::decode(stream, decoding_limits)?,
+            publishing_enabled: bool::decode(stream, decoding_limits)?,
+            lifetime_in_hours: u32::decode(stream, decoding_limits)?,
+            last_sequence_number: u32::decode(stream, decoding_limits)?,
+            next_monitored_item_id: u32::decode(stream, decoding_limits)?,
+            notifications: decode_vec(stream, decoding_limits)?,
+            monitored_items: decode_vec(stream, decoding_limits)?,
+        })
+    }
+}
+
+/// A durable subscription with its owner and the notifications which were sent to the client
+/// but have not been acknowledged
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DurableSubscriptionSnapshot {
+    pub client_user_id: UAString,
+    pub subscription: SubscriptionSnapshot,
+    pub retransmission_queue: Vec<NotificationMessage>,
+}
+
+impl BinaryEncoder<DurableSubscriptionSnapshot> for DurableSubscriptionSnapshot {
+    fn byte_len(&self) -> usize {
+        self.client_user_id.byte_len()
+            + self.subscription.byte_len()
+            + vec_byte_len(&self.retransmission_queue)
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.client_user_id.encode(stream)?;
+        size += self.subscription.encode(stream)?;
+        size += encode_vec(stream, &self.retransmission_queue)?;
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(DurableSubscriptionSnapshot {
+            client_user_id: UAString::decode(stream, decoding_limits)?,
+            subscription: SubscriptionSnapshot::decode(stream, decoding_limits)?,
+            retransmission_queue: decode_vec(stream, decoding_limits)?,
+        })
+    }
+}
+
+/// The contents of a durable subscriptions file
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DurableSubscriptionsSnapshot {
+    pub subscriptions: Vec<DurableSubscriptionSnapshot>,
+}
+
+impl BinaryEncoder<DurableSubscriptionsSnapshot> for DurableSubscriptionsSnapshot {
+    fn byte_len(&self) -> usize {
+        UAString::from(DURABLE_SUBSCRIPTIONS_MAGIC).byte_len()
+            + 4
+            + vec_byte_len(&self.subscriptions)
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += UAString::from(DURABLE_SUBSCRIPTIONS_MAGIC).encode(stream)?;
+        size += write_u32(stream, DURABLE_SUBSCRIPTIONS_VERSION)?;
+        size += encode_vec(stream, &self.subscriptions)?;
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let magic = UAString::decode(stream, decoding_limits)?;
+        if magic.as_ref() != DURABLE_SUBSCRIPTIONS_MAGIC {
+            error!("Stream does not contain durable subscriptions");
+            return Err(StatusCode::BadDecodingError);
+        }
+        let version = read_u32(stream)?;
+        if version != DURABLE_SUBSCRIPTIONS_VERSION {
+            error!("Durable subscriptions version {} is not supported", version);
+            return Err(StatusCode::BadDecodingError);
+        }
+        Ok(DurableSubscriptionsSnapshot {
+            subscriptions: decode_vec(stream, decoding_limits)?,
+        })
+    }
+}
+
+impl DurableSubscriptionsSnapshot {
+    /// Saves the durable subscriptions to a file. The file is written to a temporary file first so
+    /// a failed save leaves the previous file intact.
+    pub fn save_file(&self, path: &Path) -> Result<usize, StatusCode> {
+        util::save_encoded_file(self, path, "Durable subscriptions")?;
+        Ok(self.subscriptions.len())
+    }
+
+    /// Loads durable subscriptions from a file saved by `save_file()`
+    pub fn load_file(
+        path: &Path,
+        decoding_limits: &DecodingLimits,
+    ) -> Result<DurableSubscriptionsSnapshot, StatusCode> {
+        let file = File::open(path).map_err(|err| {
+            error!(
+                "Durable subscriptions {} cannot be opened, error = {}",
+                path.display(),
+                err
+            );
+            StatusCode::BadUnexpectedError
+        })?;
+        let mut stream = BufReader::new(file);
+        DurableSubscriptionsSnapshot::decode(&mut stream, decoding_limits)
+    }
+}
+
+/// A durable subscription that has been taken from its session
+#[derive(Debug)]
+pub(crate) struct DurableSubscription {
+    /// The user that owns the subscription. Only a session of the same user may transfer it.
+    pub client_user_id: UAString,
+    pub subscription: Subscription,
+    /// Notifications which were sent to the client but have not been acknowledged, by sequence
+    /// number
+    pub retransmission_queue: BTreeMap<u32, NotificationMessage>,
+}
+
+impl DurableSubscription {
+    pub fn snapshot(&self) -> DurableSubscriptionSnapshot {
+        DurableSubscriptionSnapshot {
+            client_user_id: self.client_user_id.clone(),
+            subscription: self.subscription.snapshot(),
+            retransmission_queue: self.retransmission_queue.values().cloned().collect(),
+        }
+    }
+
+    pub fn from_snapshot(
+        diagnostics: Arc<RwLock<ServerDiagnostics>>,
+        now: &DateTimeUtc,
+        snapshot: DurableSubscriptionSnapshot,
+    ) -> Result<DurableSubscription, StatusCode> {
+        let subscription = Subscription::from_snapshot(diagnostics, now, snapshot.subscription)?;
+        let retransmission_queue = snapshot
+            .retransmission_queue
+            .into_iter()
+            .map(|notification| (notification.sequence_number, notification))
+            .collect();
+        Ok(DurableSubscription {
+            client_user_id: snapshot.client_user_id,
+            subscription,
+            retransmission_queue,
+        })
+    }
+}
+
+/// Holds the durable subscriptions that belong to no session, either because their session has
+/// closed or because they were restored when the server started. They are kept ticking so their
+/// monitored items go on queueing values until a client transfers them or they expire.
+#[derive(Debug, Default)]
+pub(crate) struct DurableSubscriptions {
+    subscriptions: BTreeMap<u32, DurableSubscription>,
+}
+
+impl DurableSubscriptions {
+    pub fn new() -> DurableSubscriptions {
+        DurableSubscriptions::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.subscriptions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.subscriptions.is_empty()
+    }
+
+    /// Returns the largest subscription id, so new subscriptions can be given ids that follow it
+    pub fn max_subscription_id(&self) -> Option<u32> {
+        self.subscriptions.keys().next_back().cloned()
+    }
+
+    pub fn insert(&mut self, durable_subscription: DurableSubscription) {
+        let subscription_id = durable_subscription.subscription.subscription_id();
+        debug!(
+            "Durable subscription {} is waiting to be transferred",
+            subscription_id
+        );
+        self.subscriptions
+            .insert(subscription_id, durable_subscription);
+    }
+
+    /// Takes a subscription to transfer it to a session of the user who owns it
+    pub fn take(
+        &mut self,
+        subscription_id: u32,
+        client_user_id: &UAString,
+    ) -> Result<DurableSubscription, StatusCode> {
+        let is_owner = self
+            .subscriptions
+            .get(&subscription_id)
+            .map(|durable_subscription| durable_subscription.client_user_id == *client_user_id)
+            .ok_or(StatusCode::BadSubscriptionIdInvalid)?;
+        if is_owner {
+            Ok(self.subscriptions.remove(&subscription_id).unwrap())
+        } else {
+            Err(StatusCode::BadUserAccessDenied)
+        }
+    }
+
+    /// Ticks the subscriptions so their monitored items sample values. Subscriptions whose lifetime
+    /// has expired are removed.
+    pub fn tick(&mut self, now: &DateTimeUtc, address_space: &AddressSpace) {
+        let expired = self
+            .subscriptions
+            .iter_mut()
+            .filter_map(|(subscription_id, durable_subscription)| {
+                let subscription = &mut durable_subscription.subscription;
+                subscription.tick(now, address_space, TickReason::TickTimerFired, false);
+                if subscription.is_closed() {
+                    Some(*subscription_id)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<u32>>();
+        expired.into_iter().for_each(|subscription_id| {
+            info!(
+                "Durable subscription {} has expired without being transferred",
+                subscription_id
+            );
+            self.subscriptions.remove(&subscription_id);
+        });
+    }
+
+    pub fn snapshot(&self) -> Vec<DurableSubscriptionSnapshot> {
+        self.subscriptions
+            .values()
+            .map(|durable_subscription| durable_subscription.snapshot())
+            .collect()
+    }
+
+    /// Restores the subscriptions of a durable subscriptions file, returning the number restored.
+    /// Subscriptions which cannot be restored are skipped.
+    pub fn restore(
+        &mut self,
+        diagnostics: Arc<RwLock<ServerDiagnostics>>,
+        now: &DateTimeUtc,
+        snapshot: DurableSubscriptionsSnapshot,
+    ) -> usize {
+        let mut restored = 0;
+        for subscription in snapshot.subscriptions {
+            let subscription_id = subscription.subscription.subscription_id;
+            match DurableSubscription::from_snapshot(diagnostics.clone(), now, subscription) {
+                Ok(durable_subscription) => {
+                    self.insert(durable_subscription);
+                    restored += 1;
+                }
+                Err(err) => error!(
+                    "Durable subscription {} cannot be restored, error = {}",
+                    subscription_id, err
+                ),
+            }
+        }
+        restored
+    }
+}
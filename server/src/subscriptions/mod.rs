@@ -34,6 +34,7 @@ fn duration_from_ms(d: f64) -> time::Duration {
     time::Duration::microseconds((d * 1000f64) as i64)
 }
 
+pub(crate) mod durable_subscriptions;
 pub mod monitored_item;
 pub mod subscription;
 pub mod subscriptions;
@@ -4,21 +4,32 @@
 
 use std::collections::{BTreeSet, VecDeque};
 use std::result::Result;
+use std::sync::{Arc, Mutex};
+
+use futures::Future;
+use tokio::executor::{DefaultExecutor, Executor};
 
 use opcua_types::{
     node_ids::ObjectId,
     service_types::{
         DataChangeFilter, EventFieldList, EventFilter, MonitoredItemCreateRequest,
-        MonitoredItemModifyRequest, MonitoredItemNotification, ReadValueId, TimestampsToReturn,
+        MonitoredItemModifyRequest, MonitoredItemNotification, MonitoringParameters, ReadValueId,
+        TimestampsToReturn,
     },
     status_code::StatusCode,
     *,
 };
 
 use crate::{
-    address_space::{node::Node, AddressSpace, EventNotifier},
+    address_space::{
+        node::{Node, NodeType},
+        value_changes::{ChangedItems, ValueChangeListener},
+        variable::Variable,
+        AddressSpace, EventNotifier,
+    },
     constants,
     events::event_filter,
+    subscriptions::durable_subscriptions::MonitoredItemSnapshot,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -27,6 +38,16 @@ pub enum Notification {
     Event(EventFieldList),
 }
 
+impl Notification {
+    /// Returns the encoded size of the notification
+    pub fn byte_len(&self) -> usize {
+        match self {
+            Notification::MonitoredItemNotification(v) => v.byte_len(),
+            Notification::Event(v) => v.byte_len(),
+        }
+    }
+}
+
 impl From<MonitoredItemNotification> for Notification {
     fn from(v: MonitoredItemNotification) -> Self {
         Notification::MonitoredItemNotification(v)
@@ -83,12 +104,30 @@ impl FilterType {
             Err(StatusCode::BadFilterNotAllowed)
         }
     }
+
+    /// Returns the filter in the form that a client supplies it, the reverse of `from_filter()`
+    pub fn to_filter(&self) -> ExtensionObject {
+        match self {
+            FilterType::None => ExtensionObject::null(),
+            FilterType::DataChangeFilter(filter) => ExtensionObject::from_encodable(
+                ObjectId::DataChangeFilter_Encoding_DefaultBinary,
+                filter,
+            ),
+            FilterType::EventFilter(filter) => ExtensionObject::from_encodable(
+                ObjectId::EventFilter_Encoding_DefaultBinary,
+                filter,
+            ),
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub(crate) struct MonitoredItem {
     monitored_item_id: u32,
     item_to_monitor: ReadValueId,
+    /// The index range of the item to monitor, parsed when the item is created
+    #[serde(skip)]
+    index_range: NumericRange,
     monitoring_mode: MonitoringMode,
     // Triggered items are other monitored items in the same subscription which are reported if this
     // monitored item changes.
@@ -98,6 +137,9 @@ pub(crate) struct MonitoredItem {
     filter: FilterType,
     discard_oldest: bool,
     queue_size: usize,
+    /// The largest queue size a client may ask for. Monitored items of durable subscriptions may
+    /// have much larger queues than others.
+    max_queue_size: usize,
     /// The notification queue is arranged from oldest to newest, i.e. pop front gets the oldest
     /// message, pop back gets the most recent.
     notification_queue: VecDeque<Notification>,
@@ -105,8 +147,27 @@ pub(crate) struct MonitoredItem {
     timestamps_to_return: TimestampsToReturn,
     last_sample_time: DateTimeUtc,
     last_data_value: Option<DataValue>,
+    /// Sequence number of the last event on the event bus that the item has checked
+    last_event_sequence: u64,
+    /// Locale ids used to choose the text of localized event fields, in order of preference
+    locale_ids: Vec<UAString>,
+    /// Ids of the monitored items whose value changed since they were last sampled. The set is
+    /// shared by all the monitored items of a subscription.
+    #[serde(skip)]
+    changed_items: ChangedItems,
+    /// Listens for changes to the value of a variable held by the address space. An item with a
+    /// listener is only sampled when the value changes, anything else is sampled at its interval.
+    #[serde(skip)]
+    value_change_listener: Option<Arc<ValueChangeListener>>,
+    /// Receives the value of a variable whose value is obtained asynchronously. The value is
+    /// checked when the item is next sampled.
+    #[serde(skip)]
+    pending_sample: Option<PendingSample>,
 }
 
+/// The value of an asynchronous sample, which is `None` until the sample completes
+type PendingSample = Arc<Mutex<Option<DataValue>>>;
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum TickResult {
     /// The value changed and it should be reported
@@ -123,15 +184,41 @@ impl MonitoredItem {
         monitored_item_id: u32,
         timestamps_to_return: TimestampsToReturn,
         request: &MonitoredItemCreateRequest,
+    ) -> Result<MonitoredItem, StatusCode> {
+        Self::with_max_queue_size(
+            now,
+            monitored_item_id,
+            timestamps_to_return,
+            request,
+            constants::MAX_DATA_CHANGE_QUEUE_SIZE,
+        )
+    }
+
+    /// Creates a monitored item whose queue may be as large as the max queue size
+    pub fn with_max_queue_size(
+        now: &DateTimeUtc,
+        monitored_item_id: u32,
+        timestamps_to_return: TimestampsToReturn,
+        request: &MonitoredItemCreateRequest,
+        max_queue_size: usize,
     ) -> Result<MonitoredItem, StatusCode> {
         let filter = FilterType::from_filter(&request.requested_parameters.filter)?;
+        let index_range = request
+            .item_to_monitor
+            .index_range
+            .as_ref()
+            .parse::<NumericRange>()
+            .map_err(|_| StatusCode::BadIndexRangeInvalid)?;
         let sampling_interval =
             Self::sanitize_sampling_interval(request.requested_parameters.sampling_interval);
-        let queue_size =
-            Self::sanitize_queue_size(request.requested_parameters.queue_size as usize);
+        let queue_size = Self::sanitize_queue_size(
+            request.requested_parameters.queue_size as usize,
+            max_queue_size,
+        );
         Ok(MonitoredItem {
             monitored_item_id,
             item_to_monitor: request.item_to_monitor.clone(),
+            index_range,
             monitoring_mode: request.monitoring_mode,
             triggered_items: BTreeSet::new(),
             client_handle: request.requested_parameters.client_handle,
@@ -141,12 +228,84 @@ impl MonitoredItem {
             timestamps_to_return,
             last_sample_time: now.clone(),
             last_data_value: None,
+            last_event_sequence: 0,
+            locale_ids: Vec::new(),
+            changed_items: Arc::new(Mutex::new(BTreeSet::new())),
+            value_change_listener: None,
+            pending_sample: None,
             queue_size,
+            max_queue_size,
             notification_queue: VecDeque::with_capacity(queue_size),
             queue_overflow: false,
         })
     }
 
+    /// Returns the monitored item and its queued notifications in the form they are saved to disk
+    pub(crate) fn snapshot(&self) -> MonitoredItemSnapshot {
+        MonitoredItemSnapshot {
+            monitored_item_id: self.monitored_item_id,
+            item_to_monitor: self.item_to_monitor.clone(),
+            monitoring_mode: self.monitoring_mode,
+            client_handle: self.client_handle,
+            sampling_interval: self.sampling_interval,
+            filter: self.filter.to_filter(),
+            discard_oldest: self.discard_oldest,
+            queue_size: self.queue_size as u32,
+            timestamps_to_return: self.timestamps_to_return,
+            triggered_items: self.triggered_items.iter().cloned().collect(),
+            last_data_value: self.last_data_value.clone(),
+            notification_queue: self.notification_queue.iter().cloned().collect(),
+            queue_overflow: self.queue_overflow,
+        }
+    }
+
+    /// Recreates a monitored item that was saved to disk, together with its queued notifications
+    pub(crate) fn from_snapshot(
+        now: &DateTimeUtc,
+        snapshot: MonitoredItemSnapshot,
+        max_queue_size: usize,
+    ) -> Result<MonitoredItem, StatusCode> {
+        let request = MonitoredItemCreateRequest {
+            item_to_monitor: snapshot.item_to_monitor,
+            monitoring_mode: snapshot.monitoring_mode,
+            requested_parameters: MonitoringParameters {
+                client_handle: snapshot.client_handle,
+                sampling_interval: snapshot.sampling_interval,
+                filter: snapshot.filter,
+                queue_size: snapshot.queue_size,
+                discard_oldest: snapshot.discard_oldest,
+            },
+        };
+        let mut monitored_item = Self::with_max_queue_size(
+            now,
+            snapshot.monitored_item_id,
+            snapshot.timestamps_to_return,
+            &request,
+            max_queue_size,
+        )?;
+        monitored_item.triggered_items = snapshot.triggered_items.into_iter().collect();
+        monitored_item.last_data_value = snapshot.last_data_value;
+        monitored_item
+            .notification_queue
+            .extend(snapshot.notification_queue);
+        monitored_item
+            .notification_queue
+            .truncate(monitored_item.queue_size);
+        monitored_item.queue_overflow = snapshot.queue_overflow;
+        Ok(monitored_item)
+    }
+
+    /// Skips the events that have already been raised so the item only receives events raised
+    /// after it was created
+    pub fn skip_raised_events(&mut self, address_space: &AddressSpace) {
+        self.last_event_sequence = address_space.events().last_sequence();
+    }
+
+    /// Sets the locale ids used to localize the fields of event notifications
+    pub fn set_locale_ids(&mut self, locale_ids: &[UAString]) {
+        self.locale_ids = locale_ids.to_vec();
+    }
+
     /// Modifies the existing item with the values of the modify request. On success, the result
     /// holds the filter result.
     pub fn modify(
@@ -159,10 +318,14 @@ impl MonitoredItem {
         self.filter = FilterType::from_filter(&request.requested_parameters.filter)?;
         self.sampling_interval =
             Self::sanitize_sampling_interval(request.requested_parameters.sampling_interval);
-        self.queue_size =
-            Self::sanitize_queue_size(request.requested_parameters.queue_size as usize);
+        self.queue_size = Self::sanitize_queue_size(
+            request.requested_parameters.queue_size as usize,
+            self.max_queue_size,
+        );
         self.client_handle = request.requested_parameters.client_handle;
         self.discard_oldest = request.requested_parameters.discard_oldest;
+        // Sample the item again in case the new sampling interval makes it due sooner
+        self.mark_changed();
 
         // Shrink / grow the notification queue to the new threshold
         if self.notification_queue.len() > self.queue_size {
@@ -216,7 +379,8 @@ impl MonitoredItem {
     ///
     /// If the monitored item has a negative interval and subscription interval has elapsed,
     /// the value is tested immediately. Otherwise, the monitored items sampling interval is enforced
-    /// the subscriptions and controls the rate.
+    /// the subscriptions and controls the rate. An item listening for value changes is only tested
+    /// when it has been told the value changed.
     ///
     /// Function returns a `TickResult` denoting if the value changed or not, and whether it should
     /// be reported.
@@ -230,7 +394,7 @@ impl MonitoredItem {
         if self.monitoring_mode == MonitoringMode::Disabled {
             TickResult::NoChange
         } else {
-            let check_value = if resend_data {
+            let sampling_due = if resend_data {
                 // Always check for resend_data flag
                 true
             } else if self.sampling_interval < 0f64 {
@@ -249,14 +413,15 @@ impl MonitoredItem {
             };
 
             // Test the value (or don't)
+            let check_value = sampling_due && (resend_data || self.needs_sample());
             let value_changed = check_value && {
                 // Indicate a change if reporting is enabled
                 let first_tick = !self.is_event_filter() && self.last_data_value.is_none();
                 let value_changed = self.check_value(address_space, now, resend_data);
-                first_tick || value_changed || !self.notification_queue.is_empty()
+                first_tick || value_changed
             };
 
-            if value_changed {
+            if value_changed || (sampling_due && !self.notification_queue.is_empty()) {
                 if self.monitoring_mode == MonitoringMode::Reporting {
                     TickResult::ReportValueChanged
                 } else {
@@ -286,13 +451,10 @@ impl MonitoredItem {
         }
     }
 
-    /// Check for
-    fn check_for_events(
-        &mut self,
-        address_space: &AddressSpace,
-        happened_since: &DateTimeUtc,
-        node: &dyn Node,
-    ) -> bool {
+    /// Check for events raised since the item last checked
+    fn check_for_events(&mut self, address_space: &AddressSpace, node: &dyn Node) -> bool {
+        let last_event_sequence = self.last_event_sequence;
+        self.last_event_sequence = address_space.events().last_sequence();
         match self.filter {
             FilterType::EventFilter(ref filter) => {
                 // Node has to allow subscribe to events
@@ -302,8 +464,9 @@ impl MonitoredItem {
                         &object_id,
                         filter,
                         address_space,
-                        &happened_since,
+                        last_event_sequence,
                         self.client_handle,
+                        &self.locale_ids,
                     ) {
                         events
                             .into_iter()
@@ -320,19 +483,7 @@ impl MonitoredItem {
         }
     }
 
-    fn check_for_data_change(
-        &mut self,
-        _address_space: &AddressSpace,
-        resend_data: bool,
-        attribute_id: AttributeId,
-        node: &dyn Node,
-    ) -> bool {
-        let data_value = node.get_attribute(
-            TimestampsToReturn::Neither,
-            attribute_id,
-            NumericRange::None,
-            &QualifiedName::null(),
-        );
+    fn check_for_data_change(&mut self, resend_data: bool, data_value: Option<DataValue>) -> bool {
         if let Some(mut data_value) = data_value {
             // Test for data change
             let data_change = if resend_data {
@@ -431,46 +582,196 @@ impl MonitoredItem {
         if self.monitoring_mode == MonitoringMode::Disabled {
             panic!("Should not check value while monitoring mode is disabled");
         }
-        let changed = if let Some(node) = address_space.find_node(&self.item_to_monitor.node_id) {
-            match AttributeId::from_u32(self.item_to_monitor.attribute_id) {
-                Ok(attribute_id) => {
-                    let node = node.as_node();
-                    match self.filter {
-                        FilterType::EventFilter(_) => {
-                            // EventFilter is only relevant on the EventNotifier attribute
-                            if attribute_id == AttributeId::EventNotifier {
-                                let happened_since = self.last_sample_time.clone();
-                                self.check_for_events(address_space, &happened_since, node)
-                            } else {
-                                false
+        // The value is sampled now so any pending change is taken care of
+        trace_lock_unwrap!(self.changed_items).remove(&self.monitored_item_id);
+        if self.monitors_held_value(address_space) {
+            if self.value_change_listener.is_none() {
+                let listener = Arc::new(ValueChangeListener::new(
+                    self.monitored_item_id,
+                    self.changed_items.clone(),
+                ));
+                let mut value_changes = trace_lock_unwrap!(address_space.value_changes());
+                value_changes.add_listener(&self.item_to_monitor.node_id, &listener);
+                self.value_change_listener = Some(listener);
+            }
+        } else {
+            // Stop listening, e.g. if the variable now has a getter
+            self.value_change_listener = None;
+        }
+        let changed =
+            if let Some(node_manager) = address_space.node_manager(&self.item_to_monitor.node_id) {
+                // Nodes in the namespace of a node manager are sampled by the manager. Events are
+                // not supported on such nodes.
+                if self.is_event_filter() {
+                    false
+                } else {
+                    let data_value = {
+                        let mut node_manager = trace_lock_unwrap!(node_manager);
+                        node_manager.sample(&self.item_to_monitor)
+                    };
+                    self.check_for_data_change(resend_data, data_value)
+                }
+            } else if let Some(node) = address_space.find_node(&self.item_to_monitor.node_id) {
+                match AttributeId::from_u32(self.item_to_monitor.attribute_id) {
+                    Ok(attribute_id) => {
+                        let node_type = node;
+                        let node = node.as_node();
+                        match self.filter {
+                            FilterType::EventFilter(_) => {
+                                // EventFilter is only relevant on the EventNotifier attribute
+                                if attribute_id == AttributeId::EventNotifier {
+                                    self.check_for_events(address_space, node)
+                                } else {
+                                    false
+                                }
+                            }
+                            _ => {
+                                let index_range = self.index_range.clone();
+                                let data_value = match node_type {
+                                    NodeType::Variable(variable)
+                                        if attribute_id == AttributeId::Value
+                                            && variable.has_async_value_getter_only() =>
+                                    {
+                                        self.sample_async_value(variable, index_range)
+                                    }
+                                    _ => node.get_attribute(
+                                        TimestampsToReturn::Neither,
+                                        attribute_id,
+                                        index_range,
+                                        &QualifiedName::null(),
+                                    ),
+                                };
+                                self.check_for_data_change(resend_data, data_value)
                             }
                         }
-                        _ => self.check_for_data_change(
-                            address_space,
-                            resend_data,
-                            attribute_id,
-                            node,
-                        ),
+                    }
+                    Err(_) => {
+                        trace!(
+                            "Item has no attribute_id {} so it hasn't changed, node {:?}",
+                            self.item_to_monitor.attribute_id,
+                            self.item_to_monitor.node_id
+                        );
+                        false
                     }
                 }
-                Err(_) => {
-                    trace!(
-                        "Item has no attribute_id {} so it hasn't changed, node {:?}",
-                        self.item_to_monitor.attribute_id,
-                        self.item_to_monitor.node_id
-                    );
-                    false
-                }
+            } else {
+                trace!(
+                    "Cannot find item to monitor, node {:?}",
+                    self.item_to_monitor.node_id
+                );
+                false
+            };
+        self.last_sample_time = *now;
+        changed
+    }
+
+    /// Samples a variable whose value is obtained asynchronously without waiting for it. Each sample
+    /// starts the getter and returns the value of the previous sample if it has completed, so
+    /// values are reported one sample late. Nothing is started while a sample is outstanding.
+    fn sample_async_value(
+        &mut self,
+        variable: &Variable,
+        index_range: NumericRange,
+    ) -> Option<DataValue> {
+        let data_value = if let Some(ref pending_sample) = self.pending_sample {
+            let data_value = trace_lock_unwrap!(pending_sample).take();
+            if data_value.is_none() {
+                trace!(
+                    "Asynchronous sample of node {:?} has not completed",
+                    self.item_to_monitor.node_id
+                );
+                return None;
             }
+            data_value
         } else {
-            trace!(
-                "Cannot find item to monitor, node {:?}",
-                self.item_to_monitor.node_id
-            );
-            false
+            None
         };
-        self.last_sample_time = *now;
-        changed
+        self.pending_sample = None;
+        if let Some(value) = variable.async_value(
+            TimestampsToReturn::Neither,
+            index_range,
+            &QualifiedName::null(),
+            0f64,
+        ) {
+            let pending_sample = Arc::new(Mutex::new(None));
+            let sample = pending_sample.clone();
+            let task = value.then(move |result| -> Result<(), ()> {
+                let data_value = match result {
+                    Ok(Some(data_value)) => data_value,
+                    Ok(None) => DataValue::null(),
+                    Err(status_code) => DataValue {
+                        status: Some(status_code),
+                        ..DataValue::null()
+                    },
+                };
+                *trace_lock_unwrap!(sample) = Some(data_value);
+                Ok(())
+            });
+            if let Err(err) = DefaultExecutor::current().spawn(Box::new(task)) {
+                error!(
+                    "Asynchronous sample of node {:?} cannot be started, error = {:?}",
+                    self.item_to_monitor.node_id, err
+                );
+            } else {
+                self.pending_sample = Some(pending_sample);
+            }
+        }
+        data_value
+    }
+
+    /// Tests if the item monitors the value of a variable held by the address space. Such a
+    /// variable tells its listeners when its value is set so it does not need to be sampled.
+    fn monitors_held_value(&self, address_space: &AddressSpace) -> bool {
+        let node_id = &self.item_to_monitor.node_id;
+        !self.is_event_filter()
+            && self.item_to_monitor.attribute_id == AttributeId::Value as u32
+            && address_space.node_manager(node_id).is_none()
+            && match address_space.find_node(node_id) {
+                Some(&NodeType::Variable(ref variable)) => !variable.has_value_getter(),
+                _ => false,
+            }
+    }
+
+    /// Tests if the item should be sampled when it is next due. An item that listens for value
+    /// changes only needs sampling when it has been told its value changed.
+    pub fn needs_sample(&self) -> bool {
+        self.value_change_listener.is_none()
+            || trace_lock_unwrap!(self.changed_items).contains(&self.monitored_item_id)
+    }
+
+    /// Shares the set of changed items with the other items of a subscription. The item is marked
+    /// as changed so it is sampled on the next tick.
+    pub fn set_changed_items(&mut self, changed_items: ChangedItems) {
+        self.changed_items = changed_items;
+        // The listener notifies the old set so it must be registered again
+        self.value_change_listener = None;
+        self.mark_changed();
+    }
+
+    /// Marks the item as changed so it is sampled when it is next due
+    pub fn mark_changed(&self) {
+        trace_lock_unwrap!(self.changed_items).insert(self.monitored_item_id);
+    }
+
+    /// Returns the time the item should next be sampled, or `None` if the item is disabled or is
+    /// only sampled when it is told its value changed.
+    pub fn next_sample_time(&self, publishing_interval: Duration) -> Option<DateTimeUtc> {
+        if self.monitoring_mode == MonitoringMode::Disabled || self.value_change_listener.is_some()
+        {
+            None
+        } else {
+            let sampling_interval = if self.sampling_interval < 0f64 {
+                publishing_interval
+            } else {
+                self.sampling_interval
+            };
+            Some(self.last_sample_time + super::duration_from_ms(sampling_interval))
+        }
+    }
+
+    /// Tests if the item has notifications waiting to be collected
+    pub fn has_notifications(&self) -> bool {
+        !self.notification_queue.is_empty()
     }
 
     /// Enqueues a notification message for the monitored item
@@ -548,7 +849,7 @@ impl MonitoredItem {
     }
 
     /// Takes the requested queue size and ensures it is within the range supported by the server
-    fn sanitize_queue_size(requested_queue_size: usize) -> usize {
+    fn sanitize_queue_size(requested_queue_size: usize, max_queue_size: usize) -> usize {
         if requested_queue_size == 0 {
             // For data monitored items 0 -> 1
             1
@@ -556,8 +857,8 @@ impl MonitoredItem {
         } else if requested_queue_size == 1 {
             1
         // Future - for event monitored items, the minimum queue size the server requires for event notifications
-        } else if requested_queue_size > constants::MAX_DATA_CHANGE_QUEUE_SIZE {
-            constants::MAX_DATA_CHANGE_QUEUE_SIZE
+        } else if requested_queue_size > max_queue_size {
+            max_queue_size
         // Future - for event monitored items MaxUInt32 returns the maximum queue size the server support
         // for event notifications
         } else {
@@ -583,6 +884,7 @@ impl MonitoredItem {
 
     pub fn set_monitoring_mode(&mut self, monitoring_mode: MonitoringMode) {
         self.monitoring_mode = monitoring_mode;
+        self.mark_changed();
     }
 
     pub fn monitoring_mode(&self) -> MonitoringMode {
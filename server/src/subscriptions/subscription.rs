@@ -3,14 +3,16 @@
 // Copyright (C) 2017-2020 Adam Lock
 
 use std::collections::{BTreeSet, HashMap, VecDeque};
-use std::sync::{Arc, RwLock};
+use std::mem;
+use std::sync::{Arc, Mutex, RwLock};
 
 use chrono;
 
 use opcua_types::{
     service_types::{
-        MonitoredItemCreateRequest, MonitoredItemCreateResult, MonitoredItemModifyRequest,
-        MonitoredItemModifyResult, NotificationMessage, TimestampsToReturn,
+        EventFieldList, MonitoredItemCreateRequest, MonitoredItemCreateResult,
+        MonitoredItemModifyRequest, MonitoredItemModifyResult, MonitoredItemNotification,
+        NotificationMessage, TimestampsToReturn,
     },
     status_code::StatusCode,
     *,
@@ -19,10 +21,13 @@ use opcua_types::{
 use opcua_core::handle::Handle;
 
 use crate::{
-    address_space::AddressSpace,
+    address_space::{value_changes::ChangedItems, AddressSpace},
     constants,
     diagnostics::ServerDiagnostics,
-    subscriptions::monitored_item::{MonitoredItem, Notification, TickResult},
+    subscriptions::{
+        durable_subscriptions::SubscriptionSnapshot,
+        monitored_item::{MonitoredItem, Notification, TickResult},
+    },
 };
 
 /// The state of the subscription
@@ -120,6 +125,12 @@ pub struct Subscription {
     ///  needs to send notifications the highest priority subscription should
     /// be sent first.
     priority: u8,
+    /// The maximum number of notifications in a notification message requested by the client, or
+    /// 0 for no limit
+    max_notifications_per_publish: usize,
+    /// The maximum encoded size of the notifications in a notification message so that a publish
+    /// response fits the session's max response message size, or 0 for no limit
+    max_notification_message_size: usize,
     /// Map of monitored items
     monitored_items: HashMap<u32, MonitoredItem>,
     /// State of the subscription
@@ -153,6 +164,21 @@ pub struct Subscription {
     // Currently outstanding notifications to send
     #[serde(skip)]
     notifications: VecDeque<NotificationMessage>,
+    /// Locale ids of the session, in order of preference, used to localize event notifications
+    locale_ids: Vec<UAString>,
+    /// The lifetime in hours of a durable subscription, or None if the subscription is not durable.
+    /// A durable subscription holds notifications in the queues of its monitored items while there
+    /// are no publish requests to send them, and outlives its session.
+    lifetime_in_hours: Option<u32>,
+    /// Ids of monitored items whose value changed since they were last sampled
+    #[serde(skip)]
+    changed_items: ChangedItems,
+    /// Times that monitored items sampled at their sampling interval are next due, soonest first
+    #[serde(skip)]
+    sampling_schedule: BTreeSet<(DateTimeUtc, u32)>,
+    /// Ids of monitored items holding notifications that have not been collected yet
+    #[serde(skip)]
+    items_with_notifications: BTreeSet<u32>,
     /// Server diagnostics to track creation / destruction / modification of the subscription
     #[serde(skip)]
     diagnostics: Arc<RwLock<ServerDiagnostics>>,
@@ -184,6 +210,8 @@ impl Subscription {
             subscription_id,
             publishing_interval,
             priority,
+            max_notifications_per_publish: 0,
+            max_notification_message_size: 0,
             monitored_items: HashMap::with_capacity(constants::DEFAULT_MONITORED_ITEM_CAPACITY),
             max_lifetime_counter: lifetime_counter,
             max_keep_alive_counter: keep_alive_counter,
@@ -200,6 +228,11 @@ impl Subscription {
             next_monitored_item_id: 1,
             last_time_publishing_interval_elapsed: chrono::Utc::now(),
             notifications: VecDeque::with_capacity(100),
+            locale_ids: Vec::new(),
+            lifetime_in_hours: None,
+            changed_items: Arc::new(Mutex::new(BTreeSet::new())),
+            sampling_schedule: BTreeSet::new(),
+            items_with_notifications: BTreeSet::new(),
             diagnostics,
             diagnostics_on_drop: true,
         };
@@ -210,6 +243,140 @@ impl Subscription {
         subscription
     }
 
+    /// Sets the locale ids used to localize event notifications on this and any monitored items
+    pub(crate) fn set_locale_ids(&mut self, locale_ids: &[UAString]) {
+        self.locale_ids = locale_ids.to_vec();
+        self.monitored_items
+            .values_mut()
+            .for_each(|monitored_item| monitored_item.set_locale_ids(locale_ids));
+    }
+
+    /// Returns the subscription in the form it is saved to disk when it is durable
+    pub(crate) fn snapshot(&self) -> SubscriptionSnapshot {
+        let mut monitored_items = self
+            .monitored_items
+            .values()
+            .map(|monitored_item| monitored_item.snapshot())
+            .collect::<Vec<_>>();
+        monitored_items.sort_by_key(|monitored_item| monitored_item.monitored_item_id);
+        SubscriptionSnapshot {
+            subscription_id: self.subscription_id,
+            publishing_interval: self.publishing_interval,
+            max_lifetime_count: self.max_lifetime_counter,
+            lifetime_counter: self.lifetime_counter,
+            max_keep_alive_count: self.max_keep_alive_counter,
+            priority: self.priority,
+            max_notifications_per_publish: self.max_notifications_per_publish as u32,
+            publishing_enabled: self.publishing_enabled,
+            lifetime_in_hours: self.lifetime_in_hours.unwrap_or(0),
+            last_sequence_number: self.last_sequence_number,
+            next_monitored_item_id: self.next_monitored_item_id,
+            notifications: self.notifications.iter().cloned().collect(),
+            monitored_items,
+        }
+    }
+
+    /// Recreates a durable subscription that was saved to disk. The subscription is late, since no
+    /// client has sent it a publish request yet.
+    pub(crate) fn from_snapshot(
+        diagnostics: Arc<RwLock<ServerDiagnostics>>,
+        now: &DateTimeUtc,
+        snapshot: SubscriptionSnapshot,
+    ) -> Result<Subscription, StatusCode> {
+        if snapshot.publishing_interval <= 0f64 || snapshot.lifetime_counter == 0 {
+            return Err(StatusCode::BadDecodingError);
+        }
+        let mut subscription = Subscription::new(
+            diagnostics,
+            snapshot.subscription_id,
+            snapshot.publishing_enabled,
+            snapshot.publishing_interval,
+            snapshot.max_lifetime_count,
+            snapshot.max_keep_alive_count,
+            snapshot.priority,
+        );
+        subscription.state = SubscriptionState::Late;
+        subscription.first_message_sent = true;
+        subscription.lifetime_counter = snapshot.lifetime_counter;
+        subscription.max_notifications_per_publish =
+            snapshot.max_notifications_per_publish as usize;
+        if snapshot.lifetime_in_hours > 0 {
+            subscription.lifetime_in_hours = Some(snapshot.lifetime_in_hours);
+        }
+        subscription.last_sequence_number = snapshot.last_sequence_number;
+        subscription
+            .sequence_number
+            .set_next(if snapshot.last_sequence_number == std::u32::MAX {
+                1
+            } else {
+                snapshot.last_sequence_number + 1
+            });
+        subscription.next_monitored_item_id = snapshot.next_monitored_item_id;
+        subscription.notifications = snapshot.notifications.into_iter().collect();
+        let max_queue_size = subscription.max_queue_size();
+        for monitored_item in snapshot.monitored_items {
+            let mut monitored_item =
+                MonitoredItem::from_snapshot(now, monitored_item, max_queue_size)?;
+            monitored_item.set_changed_items(subscription.changed_items.clone());
+            let monitored_item_id = monitored_item.monitored_item_id();
+            if monitored_item.has_notifications() {
+                subscription
+                    .items_with_notifications
+                    .insert(monitored_item_id);
+            }
+            subscription
+                .monitored_items
+                .insert(monitored_item_id, monitored_item);
+        }
+        Ok(subscription)
+    }
+
+    /// Makes the subscription durable with a lifetime in hours. This must happen before any
+    /// monitored items are created so that they get the larger queues of a durable subscription.
+    pub(crate) fn set_durable(&mut self, lifetime_in_hours: u32) -> Result<(), StatusCode> {
+        if !self.monitored_items.is_empty() {
+            Err(StatusCode::BadInvalidState)
+        } else {
+            self.lifetime_in_hours = Some(lifetime_in_hours);
+            self.set_durable_lifetime_count();
+            Ok(())
+        }
+    }
+
+    /// Sets the lifetime count of a durable subscription to however many publishing intervals
+    /// make up its lifetime in hours
+    fn set_durable_lifetime_count(&mut self) {
+        if let Some(lifetime_in_hours) = self.lifetime_in_hours {
+            let lifetime_ms = lifetime_in_hours as f64 * 3_600_000f64;
+            let lifetime_count = (lifetime_ms / self.publishing_interval)
+                .max(self.max_keep_alive_counter as f64 * 3f64)
+                .min(std::u32::MAX as f64);
+            self.max_lifetime_counter = lifetime_count as u32;
+            self.reset_lifetime_counter();
+        }
+    }
+
+    pub(crate) fn is_durable(&self) -> bool {
+        self.lifetime_in_hours.is_some()
+    }
+
+    pub(crate) fn lifetime_in_hours(&self) -> Option<u32> {
+        self.lifetime_in_hours
+    }
+
+    /// The largest queue size that monitored items of the subscription may have
+    fn max_queue_size(&self) -> usize {
+        if self.is_durable() {
+            constants::MAX_DURABLE_QUEUE_SIZE
+        } else {
+            constants::MAX_DATA_CHANGE_QUEUE_SIZE
+        }
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.state == SubscriptionState::Closed
+    }
+
     pub(crate) fn ready_to_remove(&self) -> bool {
         self.state == SubscriptionState::Closed && self.notifications.is_empty()
     }
@@ -239,26 +406,30 @@ impl Subscription {
         max_monitored_items_per_sub: usize,
     ) -> Vec<MonitoredItemCreateResult> {
         self.reset_lifetime_counter();
+        let max_queue_size = self.max_queue_size();
 
         // Add items to the subscription if they're not already in its
         items_to_create
             .iter()
             .map(|item_to_create| {
-                if !address_space.node_exists(&item_to_create.item_to_monitor.node_id) {
+                if !address_space.node_exists_or_managed(&item_to_create.item_to_monitor.node_id) {
                     Self::monitored_item_create_error(StatusCode::BadNodeIdUnknown)
                 } else {
                     // TODO validate the attribute id for the type of node
-                    // TODO validate the index range for the node
 
                     // Create a monitored item, if possible
                     let monitored_item_id = self.next_monitored_item_id;
-                    match MonitoredItem::new(
+                    match MonitoredItem::with_max_queue_size(
                         now,
                         monitored_item_id,
                         timestamps_to_return,
                         item_to_create,
+                        max_queue_size,
                     ) {
-                        Ok(monitored_item) => {
+                        Ok(mut monitored_item) => {
+                            monitored_item.set_locale_ids(&self.locale_ids);
+                            monitored_item.set_changed_items(self.changed_items.clone());
+                            monitored_item.skip_raised_events(address_space);
                             if max_monitored_items_per_sub == 0
                                 || self.monitored_items.len() <= max_monitored_items_per_sub
                             {
@@ -434,25 +605,33 @@ impl Subscription {
             }
         };
 
+        // A durable subscription leaves notifications in the queues of its monitored items while
+        // there is no publish request to send them, rather than making notification messages that
+        // would be discarded.
+        let hold_notifications = self.is_durable() && !publishing_req_queued;
+
         // Do a tick on monitored items. Note that monitored items normally update when the interval
         // elapses but they don't have to. So this is called every tick just to catch items with their
         // own intervals.
 
-        let notification = match self.state {
-            SubscriptionState::Closed | SubscriptionState::Creating => None,
+        let notifications = match self.state {
+            SubscriptionState::Closed | SubscriptionState::Creating => Vec::new(),
             _ => {
                 let resend_data = self.resend_data;
                 self.tick_monitored_items(
                     now,
                     address_space,
                     publishing_interval_elapsed,
+                    hold_notifications,
                     resend_data,
                 )
             }
         };
         self.resend_data = false;
 
-        let notifications_available = !self.notifications.is_empty() || notification.is_some();
+        let notifications_available = !self.notifications.is_empty()
+            || !notifications.is_empty()
+            || (hold_notifications && !self.items_with_notifications.is_empty());
         let more_notifications = self.notifications.len() > 1;
 
         // If items have changed or subscription interval elapsed then we may have notifications
@@ -472,7 +651,7 @@ impl Subscription {
                 "subscription tick - update_state_result = {:?}",
                 update_state_result
             );
-            self.handle_state_result(now, update_state_result, notification);
+            self.handle_state_result(now, update_state_result, notifications);
         }
     }
 
@@ -499,12 +678,12 @@ impl Subscription {
         &mut self,
         now: &DateTimeUtc,
         update_state_result: UpdateStateResult,
-        notification: Option<NotificationMessage>,
+        notifications: Vec<NotificationMessage>,
     ) {
         // Now act on the state's action
         match update_state_result.update_state_action {
             UpdateStateAction::None => {
-                if let Some(ref notification) = notification {
+                if let Some(notification) = notifications.first() {
                     // Reset the next sequence number to the discarded notification
                     let notification_sequence_number = notification.sequence_number;
                     self.sequence_number.set_next(notification_sequence_number);
@@ -513,7 +692,7 @@ impl Subscription {
                 // Send nothing
             }
             UpdateStateAction::ReturnKeepAlive => {
-                if let Some(ref notification) = notification {
+                if let Some(notification) = notifications.first() {
                     // Reset the next sequence number to the discarded notification
                     let notification_sequence_number = notification.sequence_number;
                     self.sequence_number.set_next(notification_sequence_number);
@@ -528,13 +707,13 @@ impl Subscription {
                 self.enqueue_notification(notification);
             }
             UpdateStateAction::ReturnNotifications => {
-                // Add the notification message to the queue
-                if let Some(notification) = notification {
-                    self.enqueue_notification(notification);
-                }
+                // Add the notification messages to the queue
+                notifications
+                    .into_iter()
+                    .for_each(|notification| self.enqueue_notification(notification));
             }
             UpdateStateAction::SubscriptionCreated => {
-                if notification.is_some() {
+                if !notifications.is_empty() {
                     panic!("SubscriptionCreated got a notification");
                 }
                 // Subscription was created successfully
@@ -542,7 +721,7 @@ impl Subscription {
                 //                self.enqueue_notification(notification);
             }
             UpdateStateAction::SubscriptionExpired => {
-                if notification.is_some() {
+                if !notifications.is_empty() {
                     panic!("SubscriptionExpired got a notification");
                 }
                 // Delete the monitored items, issue a status change for the subscription
@@ -558,10 +737,34 @@ impl Subscription {
         }
     }
 
+    /// Closes the subscription because the server is shutting down. The monitored items are
+    /// deleted and a final status change notification is queued to tell the client why.
+    pub(crate) fn shutdown(&mut self, now: &DateTimeUtc) {
+        if self.state != SubscriptionState::Closed {
+            debug!(
+                "Subscription {} is closing because the server is shutting down",
+                self.subscription_id
+            );
+            self.state = SubscriptionState::Closed;
+            self.monitored_items.clear();
+            let notification = NotificationMessage::status_change(
+                self.sequence_number.next(),
+                DateTime::from(now.clone()),
+                StatusCode::BadShutdown,
+            );
+            self.enqueue_notification(notification);
+        }
+    }
+
     pub(crate) fn take_notification(&mut self) -> Option<NotificationMessage> {
         self.notifications.pop_front()
     }
 
+    /// Tests if the subscription has notification messages waiting for a publish request
+    pub(crate) fn has_notifications(&self) -> bool {
+        !self.notifications.is_empty()
+    }
+
     // See OPC UA Part 4 5.13.1.2 State Table
     //
     // This function implements the main guts of updating the subscription's state according to
@@ -829,10 +1032,44 @@ impl Subscription {
         UpdateStateResult::new(HandledState::None0, UpdateStateAction::None)
     }
 
-    /// Iterate through the monitored items belonging to the subscription, calling tick on each in turn.
+    /// Returns the ids of the monitored items that need to be ticked. These are the items that are
+    /// due to be sampled, that were told their value changed, or that hold notifications when they
+    /// are to be collected. Every item is ticked when data must be resent.
+    fn monitored_items_to_tick(
+        &mut self,
+        now: &DateTimeUtc,
+        collect_notifications: bool,
+        resend_data: bool,
+    ) -> BTreeSet<u32> {
+        if resend_data {
+            // Every item is sampled and scheduled again
+            self.sampling_schedule.clear();
+            self.monitored_items.keys().cloned().collect()
+        } else {
+            let mut item_ids = trace_lock_unwrap!(self.changed_items).clone();
+            let due = self
+                .sampling_schedule
+                .iter()
+                .take_while(|(sample_time, _)| sample_time <= now)
+                .cloned()
+                .collect::<Vec<_>>();
+            due.into_iter().for_each(|entry| {
+                self.sampling_schedule.remove(&entry);
+                item_ids.insert(entry.1);
+            });
+            if collect_notifications {
+                item_ids.extend(self.items_with_notifications.iter());
+            }
+            item_ids
+        }
+    }
+
+    /// Iterate through the monitored items belonging to the subscription that need it, calling tick
+    /// on each in turn.
     ///
     /// Items that are in a reporting state, or triggered to report will be have their pending notifications
-    /// collected together when the publish interval elapsed flag is `true`.
+    /// collected together when the publish interval elapsed flag is `true`, unless notifications are
+    /// being held in the queues of the monitored items.
     ///
     /// The function returns a `notifications` and a `more_notifications` boolean to indicate if the notifications
     /// are available.
@@ -841,18 +1078,30 @@ impl Subscription {
         now: &DateTimeUtc,
         address_space: &AddressSpace,
         publishing_interval_elapsed: bool,
+        hold_notifications: bool,
         resend_data: bool,
-    ) -> Option<NotificationMessage> {
+    ) -> Vec<NotificationMessage> {
         let mut triggered_items: BTreeSet<u32> = BTreeSet::new();
-        let mut monitored_item_notifications = Vec::with_capacity(self.monitored_items.len() * 2);
-
-        for (_, monitored_item) in &mut self.monitored_items {
+        let mut monitored_item_notifications = Vec::new();
+
+        let collect_notifications = publishing_interval_elapsed && !hold_notifications;
+        let item_ids = self.monitored_items_to_tick(now, collect_notifications, resend_data);
+        for monitored_item_id in item_ids {
+            let monitored_item =
+                if let Some(monitored_item) = self.monitored_items.get_mut(&monitored_item_id) {
+                    monitored_item
+                } else {
+                    // The item has been deleted
+                    trace_lock_unwrap!(self.changed_items).remove(&monitored_item_id);
+                    self.items_with_notifications.remove(&monitored_item_id);
+                    continue;
+                };
             // If this returns true then the monitored item wants to report its notification
             let monitoring_mode = monitored_item.monitoring_mode();
             match monitored_item.tick(now, address_space, publishing_interval_elapsed, resend_data)
             {
                 TickResult::ReportValueChanged => {
-                    if publishing_interval_elapsed {
+                    if collect_notifications {
                         // If this monitored item has triggered items, then they need to be handled
                         match monitoring_mode {
                             MonitoringMode::Reporting => {
@@ -879,7 +1128,7 @@ impl Subscription {
                 TickResult::ValueChanged => {
                     // The monitored item doesn't have changes to report but its value did change so it
                     // is still necessary to check its triggered items.
-                    if publishing_interval_elapsed {
+                    if collect_notifications {
                         match monitoring_mode {
                             MonitoringMode::Sampling => {
                                 // If the monitoring mode of the triggering item is SAMPLING, then it is not reported when the
@@ -899,6 +1148,19 @@ impl Subscription {
                     // Ignore
                 }
             }
+
+            // Schedule the next sample of an item that is sampled at its interval
+            if let Some(next_sample_time) =
+                monitored_item.next_sample_time(self.publishing_interval)
+            {
+                self.sampling_schedule
+                    .insert((next_sample_time, monitored_item_id));
+            }
+            if monitored_item.has_notifications() {
+                self.items_with_notifications.insert(monitored_item_id);
+            } else {
+                self.items_with_notifications.remove(&monitored_item_id);
+            }
         }
 
         // Are there any triggered items to force a change on?
@@ -933,53 +1195,75 @@ impl Subscription {
             }
         });
 
-        // Produce a data change notification
-        if !monitored_item_notifications.is_empty() {
-            let next_sequence_number = self.sequence_number.next();
-
-            trace!(
-                "Create notification for subscription {}, sequence number {}",
-                self.subscription_id,
-                next_sequence_number
-            );
-
-            // Collect all datachange notifications
-            let data_change_notifications = monitored_item_notifications
-                .iter()
-                .filter(|v| matches!(v, Notification::MonitoredItemNotification(_)))
-                .map(|v| {
-                    if let Notification::MonitoredItemNotification(v) = v {
-                        v.clone()
-                    } else {
-                        panic!()
-                    }
-                })
-                .collect();
-
-            // Collect event notifications
-            let event_notifications = monitored_item_notifications
-                .iter()
-                .filter(|v| matches!(v, Notification::Event(_)))
-                .map(|v| {
-                    if let Notification::Event(v) = v {
-                        v.clone()
-                    } else {
-                        panic!()
-                    }
-                })
-                .collect();
+        // Produce data change notifications
+        self.make_notification_messages(now, monitored_item_notifications)
+    }
 
-            // Make a notification
-            let notification = NotificationMessage::data_change(
-                next_sequence_number,
-                DateTime::from(now.clone()),
-                data_change_notifications,
-                event_notifications,
-            );
-            Some(notification)
-        } else {
-            None
+    /// Makes notification messages from the notifications of the monitored items. The
+    /// notifications are split over as many messages as it takes to keep each within the maximum
+    /// notifications per publish and the maximum message size. Each message gets its own sequence
+    /// number.
+    fn make_notification_messages(
+        &mut self,
+        now: &DateTimeUtc,
+        monitored_item_notifications: Vec<Notification>,
+    ) -> Vec<NotificationMessage> {
+        let mut notification_messages = Vec::new();
+        let mut data_change_notifications = Vec::new();
+        let mut event_notifications = Vec::new();
+        let mut message_size = 0;
+        for notification in monitored_item_notifications {
+            let notification_size = notification.byte_len();
+            // A message always holds at least one notification, even one that is too big
+            let notifications_len = data_change_notifications.len() + event_notifications.len();
+            let message_full = notifications_len > 0
+                && ((self.max_notifications_per_publish > 0
+                    && notifications_len >= self.max_notifications_per_publish)
+                    || (self.max_notification_message_size > 0
+                        && message_size + notification_size > self.max_notification_message_size));
+            if message_full {
+                let notification_message = self.make_notification_message(
+                    now,
+                    mem::replace(&mut data_change_notifications, Vec::new()),
+                    mem::replace(&mut event_notifications, Vec::new()),
+                );
+                notification_messages.push(notification_message);
+                message_size = 0;
+            }
+            message_size += notification_size;
+            match notification {
+                Notification::MonitoredItemNotification(v) => data_change_notifications.push(v),
+                Notification::Event(v) => event_notifications.push(v),
+            }
         }
+        if !data_change_notifications.is_empty() || !event_notifications.is_empty() {
+            let notification_message =
+                self.make_notification_message(now, data_change_notifications, event_notifications);
+            notification_messages.push(notification_message);
+        }
+        notification_messages
+    }
+
+    fn make_notification_message(
+        &mut self,
+        now: &DateTimeUtc,
+        data_change_notifications: Vec<MonitoredItemNotification>,
+        event_notifications: Vec<EventFieldList>,
+    ) -> NotificationMessage {
+        let next_sequence_number = self.sequence_number.next();
+
+        trace!(
+            "Create notification for subscription {}, sequence number {}",
+            self.subscription_id,
+            next_sequence_number
+        );
+
+        NotificationMessage::data_change(
+            next_sequence_number,
+            DateTime::from(now.clone()),
+            data_change_notifications,
+            event_notifications,
+        )
     }
 
     /// Reset the keep-alive counter to the maximum keep-alive count of the Subscription.
@@ -1049,6 +1333,7 @@ impl Subscription {
     pub(crate) fn set_publishing_interval(&mut self, publishing_interval: Duration) {
         self.publishing_interval = publishing_interval;
         self.reset_lifetime_counter();
+        self.set_durable_lifetime_count();
     }
 
     pub fn max_keep_alive_count(&self) -> u32 {
@@ -1063,8 +1348,14 @@ impl Subscription {
         self.max_lifetime_counter
     }
 
+    /// Sets the lifetime count, unless the subscription is durable in which case the count is
+    /// worked out again from its lifetime in hours
     pub(crate) fn set_max_lifetime_count(&mut self, max_lifetime_count: u32) {
-        self.max_lifetime_counter = max_lifetime_count;
+        if self.is_durable() {
+            self.set_durable_lifetime_count();
+        } else {
+            self.max_lifetime_counter = max_lifetime_count;
+        }
     }
 
     pub fn priority(&self) -> u8 {
@@ -1075,6 +1366,21 @@ impl Subscription {
         self.priority = priority;
     }
 
+    pub fn max_notifications_per_publish(&self) -> usize {
+        self.max_notifications_per_publish
+    }
+
+    pub(crate) fn set_max_notifications_per_publish(&mut self, max_notifications_per_publish: u32) {
+        self.max_notifications_per_publish = max_notifications_per_publish as usize;
+    }
+
+    pub(crate) fn set_max_notification_message_size(
+        &mut self,
+        max_notification_message_size: usize,
+    ) {
+        self.max_notification_message_size = max_notification_message_size;
+    }
+
     pub(crate) fn set_publishing_enabled(&mut self, publishing_enabled: bool) {
         self.publishing_enabled = publishing_enabled;
         self.reset_lifetime_counter();
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
+use std::cmp::Reverse;
 use std::collections::{BTreeMap, VecDeque};
 
 use time;
@@ -14,8 +15,10 @@ use opcua_types::{
 
 use crate::{
     address_space::types::AddressSpace,
+    constants,
     subscriptions::{
-        subscription::{Subscription, TickReason},
+        durable_subscriptions::{DurableSubscription, DurableSubscriptionSnapshot},
+        subscription::{Subscription, SubscriptionState, TickReason},
         PublishRequestEntry, PublishResponseEntry,
     },
 };
@@ -43,6 +46,15 @@ pub(crate) struct Subscriptions {
     // Notifications that have been sent but have yet to be acknowledged (retransmission queue).
     // Key is (subscription_id, sequence_number). Value is notification message.
     retransmission_queue: BTreeMap<(u32, u32), NotificationMessage>,
+    // Preferred locale ids of the session which are given to each subscription
+    locale_ids: Vec<UAString>,
+    // Maximum size of the notifications in a publish response, or 0 for no limit
+    max_notification_message_size: usize,
+    // The number of publish requests that have been handed to subscriptions
+    publish_requests_served: u64,
+    // The value of publish_requests_served when each subscription was last handed a publish request.
+    // Key is the subscription id.
+    last_served: BTreeMap<u32, u64>,
 }
 
 #[derive(Serialize)]
@@ -68,6 +80,10 @@ impl Subscriptions {
             subscriptions: BTreeMap::new(),
             transmission_queue: VecDeque::with_capacity(max_publish_requests),
             retransmission_queue: BTreeMap::new(),
+            locale_ids: Vec::new(),
+            max_notification_message_size: 0,
+            publish_requests_served: 0,
+            last_served: BTreeMap::new(),
         }
     }
 
@@ -145,25 +161,39 @@ impl Subscriptions {
             let _ = self.tick(now, address_space, TickReason::ReceivePublishRequest);
         }
 
-        // Enqueue request or return error
-        if self.publish_request_queue.len() >= max_publish_requests {
+        // Make space for the request by failing the oldest requests
+        while !self.publish_request_queue.is_empty()
+            && self.publish_request_queue.len() >= max_publish_requests
+        {
+            let oldest = self.publish_request_queue.pop_back().unwrap();
             error!(
-                "Too many publish requests {} for capacity {}",
-                self.publish_request_queue.len(),
-                max_publish_requests
+                "Too many publish requests {} for capacity {}, publish request {} is discarded",
+                self.publish_request_queue.len() + 1,
+                max_publish_requests,
+                oldest.request_id
             );
-            Err(StatusCode::BadTooManyPublishRequests)
-        } else {
-            // Add to the front of the queue - older items are popped from the back
-            let results = self.process_subscription_acknowledgements(&request);
-            self.publish_request_queue.push_front(PublishRequestEntry {
-                request_id,
-                request,
-                results,
+            self.publish_response_queue.push_back(PublishResponseEntry {
+                request_id: oldest.request_id,
+                response: ServiceFault {
+                    response_header: ResponseHeader::new_timestamped_service_result(
+                        DateTime::from(now.clone()),
+                        &oldest.request.request_header,
+                        StatusCode::BadTooManyPublishRequests,
+                    ),
+                }
+                .into(),
             });
-            // Tick to trigger publish
-            self.tick(now, address_space, TickReason::ReceivePublishRequest)
         }
+
+        // Add to the front of the queue - older items are popped from the back
+        let results = self.process_subscription_acknowledgements(&request);
+        self.publish_request_queue.push_front(PublishRequestEntry {
+            request_id,
+            request,
+            results,
+        });
+        // Tick to trigger publish
+        self.tick(now, address_space, TickReason::ReceivePublishRequest)
     }
 
     /// Tests if there are no subscriptions/
@@ -186,11 +216,39 @@ impl Subscriptions {
         self.subscriptions.contains_key(&subscription_id)
     }
 
-    pub fn insert(&mut self, subscription_id: u32, subscription: Subscription) {
+    pub fn insert(&mut self, subscription_id: u32, mut subscription: Subscription) {
+        subscription.set_locale_ids(&self.locale_ids);
+        subscription.set_max_notification_message_size(self.max_notification_message_size);
         self.subscriptions.insert(subscription_id, subscription);
     }
 
+    /// Sets the session's preferred locale ids on every subscription and any that are inserted later
+    pub fn set_locale_ids(&mut self, locale_ids: Vec<UAString>) {
+        self.subscriptions
+            .values_mut()
+            .for_each(|subscription| subscription.set_locale_ids(&locale_ids));
+        self.locale_ids = locale_ids;
+    }
+
+    /// Sets the session's max response message size so that subscriptions split their notifications
+    /// over as many publish responses as it takes for each to fit. 0 means no limit.
+    pub fn set_max_response_message_size(&mut self, max_response_message_size: u32) {
+        self.max_notification_message_size = if max_response_message_size == 0 {
+            0
+        } else {
+            // Always leave room for at least one notification
+            (max_response_message_size as usize)
+                .saturating_sub(constants::PUBLISH_RESPONSE_OVERHEAD)
+                .max(1)
+        };
+        let max_notification_message_size = self.max_notification_message_size;
+        self.subscriptions.values_mut().for_each(|subscription| {
+            subscription.set_max_notification_message_size(max_notification_message_size)
+        });
+    }
+
     pub fn remove(&mut self, subscription_id: u32) -> Option<Subscription> {
+        self.last_served.remove(&subscription_id);
         self.subscriptions.remove(&subscription_id)
     }
 
@@ -198,65 +256,192 @@ impl Subscriptions {
         self.subscriptions.get_mut(&subscription_id)
     }
 
+    /// Deletes every subscription, including durable ones which would otherwise outlive the
+    /// session
+    pub(crate) fn delete_all(&mut self) {
+        self.subscriptions.clear();
+        self.last_served.clear();
+        self.retransmission_queue.clear();
+    }
+
+    /// Removes the durable subscriptions together with their unacknowledged notifications, so
+    /// they can outlive the session. The user is the owner of the session.
+    pub(crate) fn take_durable_subscriptions(
+        &mut self,
+        client_user_id: &UAString,
+    ) -> Vec<DurableSubscription> {
+        let subscription_ids = self
+            .subscriptions
+            .values()
+            .filter(|subscription| subscription.is_durable() && !subscription.is_closed())
+            .map(|subscription| subscription.subscription_id())
+            .collect::<Vec<u32>>();
+        subscription_ids
+            .into_iter()
+            .map(|subscription_id| {
+                let subscription = self.remove(subscription_id).unwrap();
+                DurableSubscription {
+                    client_user_id: client_user_id.clone(),
+                    subscription,
+                    retransmission_queue: self.take_retransmission_queue(subscription_id),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the durable subscriptions of the session in the form they are saved to disk
+    pub(crate) fn durable_subscription_snapshots(
+        &self,
+        client_user_id: &UAString,
+    ) -> Vec<DurableSubscriptionSnapshot> {
+        self.subscriptions
+            .values()
+            .filter(|subscription| subscription.is_durable() && !subscription.is_closed())
+            .map(|subscription| {
+                let subscription_id = subscription.subscription_id();
+                DurableSubscriptionSnapshot {
+                    client_user_id: client_user_id.clone(),
+                    subscription: subscription.snapshot(),
+                    retransmission_queue: self
+                        .retransmission_queue
+                        .range((subscription_id, 0)..=(subscription_id, std::u32::MAX))
+                        .map(|(_, notification_message)| notification_message.clone())
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Adds a durable subscription which is being transferred from another session, returning the
+    /// sequence numbers of its notifications that are available for republishing
+    pub(crate) fn transfer(
+        &mut self,
+        durable_subscription: DurableSubscription,
+        send_initial_values: bool,
+    ) -> Option<Vec<u32>> {
+        let DurableSubscription {
+            mut subscription,
+            retransmission_queue,
+            ..
+        } = durable_subscription;
+        let subscription_id = subscription.subscription_id();
+        subscription.reset_lifetime_counter();
+        if send_initial_values {
+            subscription.set_resend_data();
+        }
+        self.insert(subscription_id, subscription);
+        retransmission_queue
+            .into_iter()
+            .for_each(|(sequence_number, notification_message)| {
+                self.retransmission_queue
+                    .insert((subscription_id, sequence_number), notification_message);
+            });
+        self.available_sequence_numbers(subscription_id)
+    }
+
+    fn take_retransmission_queue(
+        &mut self,
+        subscription_id: u32,
+    ) -> BTreeMap<u32, NotificationMessage> {
+        let keys = self
+            .retransmission_queue
+            .range((subscription_id, 0)..=(subscription_id, std::u32::MAX))
+            .map(|(k, _)| *k)
+            .collect::<Vec<_>>();
+        keys.into_iter()
+            .filter_map(|k| {
+                self.retransmission_queue
+                    .remove(&k)
+                    .map(|notification_message| (k.1, notification_message))
+            })
+            .collect()
+    }
+
+    /// Returns the ids of the subscriptions in the order that they are handed publish requests.
+    /// Higher priorities come first. Within a priority, late subscriptions come before the others
+    /// and then the subscription that was served least recently, so that they take turns.
+    fn subscriptions_by_priority(&self) -> Vec<u32> {
+        let mut subscriptions = self
+            .subscriptions
+            .values()
+            .map(|s| {
+                let subscription_id = s.subscription_id();
+                let not_late = s.state() != SubscriptionState::Late;
+                let last_served = self.last_served.get(&subscription_id).cloned().unwrap_or(0);
+                (
+                    Reverse(s.priority()),
+                    not_late,
+                    last_served,
+                    subscription_id,
+                )
+            })
+            .collect::<Vec<_>>();
+        subscriptions.sort();
+        subscriptions.into_iter().map(|s| s.3).collect()
+    }
+
     /// The tick causes the subscription manager to iterate through individual subscriptions calling tick
     /// on each in order of priority. In each case this could generate data change notifications. Data change
     /// notifications will be attached to the next available publish response and queued for sending
     /// to the client.
+    ///
+    /// Queued publish requests are handed out in rounds, one to each subscription with notifications in
+    /// order of priority, so a subscription with many notifications cannot starve the ones after it.
     pub(crate) fn tick(
         &mut self,
         now: &DateTimeUtc,
         address_space: &AddressSpace,
         tick_reason: TickReason,
     ) -> Result<(), StatusCode> {
-        let subscription_ids = {
-            // Sort subscriptions by priority
-            let mut subscription_priority: Vec<(u32, u8)> = self
-                .subscriptions
-                .values()
-                .map(|v| (v.subscription_id(), v.priority()))
-                .collect();
-            subscription_priority.sort_by(|s1, s2| s1.1.cmp(&s2.1));
-            subscription_priority
-                .iter()
-                .map(|s| s.0)
-                .collect::<Vec<u32>>()
-        };
-
-        // Iterate through all subscriptions. If there is a publish request it will be used to
-        // acknowledge notifications and the response to return new notifications.
+        let subscription_ids = self.subscriptions_by_priority();
 
-        // Now tick over the subscriptions
-        for subscription_id in subscription_ids {
-            let publishing_req_queued = !self.publish_request_queue.is_empty();
-            let subscription = self.subscriptions.get_mut(&subscription_id).unwrap();
+        // Now tick over the subscriptions. A subscription is told that a publish request is queued if
+        // one is left over after the subscriptions before it that have notifications waiting.
+        let mut claimed_publish_requests = 0;
+        for subscription_id in &subscription_ids {
+            let publishing_req_queued = self.publish_request_queue.len() > claimed_publish_requests;
+            let subscription = self.subscriptions.get_mut(subscription_id).unwrap();
 
             // Now tick the subscription to see if it has any notifications. If there are
             // notifications then the publish response will be associated with his subscription
             // and ready to go.
             subscription.tick(now, address_space, tick_reason, publishing_req_queued);
+            if subscription.has_notifications() {
+                claimed_publish_requests += 1;
+            }
+        }
 
-            // Process any notifications
-            loop {
-                if !self.publish_request_queue.is_empty() {
-                    if let Some(notification_message) = subscription.take_notification() {
-                        let publish_request = self.publish_request_queue.pop_back().unwrap();
-                        // Consume the publish request and queue the notification onto the transmission queue
-                        self.transmission_queue.push_front((
-                            subscription_id,
-                            publish_request,
-                            notification_message,
-                        ));
-                    } else {
-                        break;
-                    }
-                } else {
+        // Process any notifications, consuming a publish request for each
+        loop {
+            let mut served = false;
+            for subscription_id in &subscription_ids {
+                if self.publish_request_queue.is_empty() {
                     break;
                 }
+                let subscription = self.subscriptions.get_mut(subscription_id).unwrap();
+                if let Some(notification_message) = subscription.take_notification() {
+                    let publish_request = self.publish_request_queue.pop_back().unwrap();
+                    // Consume the publish request and queue the notification onto the transmission queue
+                    self.transmission_queue.push_front((
+                        *subscription_id,
+                        publish_request,
+                        notification_message,
+                    ));
+                    self.publish_requests_served += 1;
+                    self.last_served
+                        .insert(*subscription_id, self.publish_requests_served);
+                    served = true;
+                }
             }
+            if !served || self.publish_request_queue.is_empty() {
+                break;
+            }
+        }
 
-            // Remove the subscription if it is done
-            if subscription.ready_to_remove() {
-                self.subscriptions.remove(&subscription_id);
+        // Remove the subscriptions that are done
+        for subscription_id in &subscription_ids {
+            if self.subscriptions[subscription_id].ready_to_remove() {
+                self.remove(*subscription_id);
             }
         }
 
@@ -297,6 +482,14 @@ impl Subscriptions {
         Ok(())
     }
 
+    /// Closes all the subscriptions because the server is shutting down. Each subscription
+    /// queues a final status change notification which goes out with the next publish response.
+    pub(crate) fn shutdown(&mut self, now: &DateTimeUtc) {
+        self.subscriptions
+            .values_mut()
+            .for_each(|subscription| subscription.shutdown(now));
+    }
+
     /// Iterates through the existing queued publish requests and creates a timeout
     /// publish response any that have expired.
     pub fn expire_stale_publish_requests(&mut self, now: &DateTimeUtc) {
@@ -338,6 +531,36 @@ impl Subscriptions {
             .append(&mut expired_publish_responses);
     }
 
+    /// Removes the queued publish requests with the request handle, answering each of them with
+    /// `BadRequestCancelledByClient`. Returns the number of requests cancelled.
+    pub fn cancel_publish_requests(&mut self, request_handle: u32) -> u32 {
+        let mut cancelled_publish_responses = VecDeque::new();
+        self.publish_request_queue.retain(|ref request| {
+            let request_header = &request.request.request_header;
+            if request_header.request_handle == request_handle {
+                debug!("Publish request {} has been cancelled", request_handle);
+                cancelled_publish_responses.push_back(PublishResponseEntry {
+                    request_id: request.request_id,
+                    response: ServiceFault {
+                        response_header: ResponseHeader::new_timestamped_service_result(
+                            DateTime::now(),
+                            request_header,
+                            StatusCode::BadRequestCancelledByClient,
+                        ),
+                    }
+                    .into(),
+                });
+                false
+            } else {
+                true
+            }
+        });
+        let cancel_count = cancelled_publish_responses.len() as u32;
+        self.publish_response_queue
+            .append(&mut cancelled_publish_responses);
+        cancel_count
+    }
+
     /// Deletes the acknowledged notifications, returning a list of status code for each according
     /// to whether it was found or not.
     ///
@@ -378,12 +601,17 @@ impl Subscriptions {
     }
 
     /// Searches the transmission queue to see if there are more notifications for the specified
-    /// subscription id
+    /// subscription id, or if the subscription holds notifications waiting for a publish request
     fn more_notifications(&self, subscription_id: u32) -> bool {
         // At least one match means more notifications
         self.transmission_queue
             .iter()
             .any(|v| v.0 == subscription_id)
+            || self
+                .subscriptions
+                .get(&subscription_id)
+                .map(|subscription| subscription.has_notifications())
+                .unwrap_or(false)
     }
 
     /// Returns the array of available sequence numbers in the retransmission queue for the specified subscription
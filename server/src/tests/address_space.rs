@@ -1,3 +1,5 @@
+use std::io::Cursor;
+
 use crate::{
     address_space::{
         references::Reference, relative_path::find_node_from_browse_path, EventNotifier,
@@ -928,3 +930,891 @@ fn hierarchical_references() {
     assert!(refs.contains(&ObjectId::Server_ServerCapabilities_AggregateFunctions.into()));
     assert!(refs.contains(&ObjectId::HistoryServerCapabilities.into()));
 }
+
+fn make_snapshot_address_space() -> (AddressSpace, u16) {
+    let mut address_space = AddressSpace::new();
+    let ns = address_space.register_namespace("urn:snapshot").unwrap();
+
+    let folder_id = NodeId::new(ns, "folder");
+    ObjectBuilder::new(&folder_id, QualifiedName::new(ns, "Folder"), "Folder")
+        .is_folder()
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+    VariableBuilder::new(&NodeId::new(ns, "v1"), QualifiedName::new(ns, "v1"), "v1")
+        .data_type(DataTypeId::Int32)
+        .value(100i32)
+        .writable()
+        .component_of(folder_id)
+        .insert(&mut address_space);
+
+    (address_space, ns)
+}
+
+fn verify_snapshot_address_space(address_space: &AddressSpace, ns: u16) {
+    let folder_id = NodeId::new(ns, "folder");
+    let v1_id = NodeId::new(ns, "v1");
+
+    let folder = address_space.find_node(&folder_id).unwrap().as_node();
+    assert_eq!(folder.node_class(), NodeClass::Object);
+    assert_eq!(folder.browse_name(), QualifiedName::new(ns, "Folder"));
+
+    let v1 = address_space.find_variable(v1_id.clone()).unwrap();
+    assert_eq!(v1.browse_name(), QualifiedName::new(ns, "v1"));
+    assert_eq!(v1.data_type(), NodeId::from(DataTypeId::Int32));
+    assert!(v1.access_level().contains(AccessLevel::CURRENT_WRITE));
+    let value = address_space.get_variable_value(v1_id.clone()).unwrap();
+    assert_eq!(value.value, Some(Variant::Int32(100)));
+
+    assert!(address_space.has_reference(
+        &NodeId::objects_folder_id(),
+        &folder_id,
+        ReferenceTypeId::Organizes
+    ));
+    assert!(address_space.has_reference(&folder_id, &v1_id, ReferenceTypeId::HasComponent));
+    assert!(address_space.has_reference(
+        &folder_id,
+        &ObjectTypeId::FolderType.into(),
+        ReferenceTypeId::HasTypeDefinition
+    ));
+}
+
+#[test]
+fn snapshot_restore() {
+    let (address_space, ns) = make_snapshot_address_space();
+
+    // Only the nodes of the namespace are in the snapshot
+    let snapshot = address_space.snapshot(&[ns]);
+    assert_eq!(snapshot.node_count(), 2);
+    assert_eq!(snapshot.reference_count(), 3);
+
+    let mut stream = Cursor::new(snapshot.encode_to_vec());
+    let snapshot = AddressSpaceSnapshot::decode(&mut stream, &DecodingLimits::default()).unwrap();
+
+    // Restore into an address space where the namespace has a different index
+    let mut address_space = AddressSpace::new();
+    let other_ns = address_space.register_namespace("urn:other").unwrap();
+    assert_eq!(address_space.restore_snapshot(snapshot).unwrap(), 2);
+    let restored_ns = address_space.namespace_index("urn:snapshot").unwrap();
+    assert_ne!(ns, restored_ns);
+    assert_eq!(address_space.default_namespace(), other_ns);
+
+    verify_snapshot_address_space(&address_space, restored_ns);
+}
+
+#[test]
+fn snapshot_restore_model_changes() {
+    let (mut address_space, ns) = make_snapshot_address_space();
+    let nodes_id = NodeId::new(ns, "nodes");
+    VariableBuilder::new(&nodes_id, QualifiedName::new(ns, "nodes"), "nodes")
+        .data_type(DataTypeId::NodeId)
+        .value(Variant::from(vec![
+            Variant::from(NodeId::new(ns, "folder")),
+            Variant::from(NodeId::new(ns, "v1")),
+        ]))
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+    let snapshot = address_space.snapshot(&[ns]);
+
+    let mut address_space = AddressSpace::new();
+    address_space.register_namespace("urn:other").unwrap();
+    address_space.set_model_change_events(true);
+    assert_eq!(address_space.restore_snapshot(snapshot).unwrap(), 3);
+    let restored_ns = address_space.namespace_index("urn:snapshot").unwrap();
+    assert_ne!(ns, restored_ns);
+
+    // Node ids held in array values are remapped too
+    let value = address_space
+        .get_variable_value(NodeId::new(restored_ns, "nodes"))
+        .unwrap();
+    assert_eq!(
+        value.value,
+        Some(Variant::from(vec![
+            Variant::from(NodeId::new(restored_ns, "folder")),
+            Variant::from(NodeId::new(restored_ns, "v1")),
+        ]))
+    );
+
+    // The restored nodes and the references to them are model changes
+    address_space.raise_model_change_events();
+    let changes = model_change_event_changes::<ModelChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::GeneralModelChangeEventType,
+        &ObjectId::Server.into(),
+    );
+    assert_eq!(changes.len(), 4);
+    let objects_folder_change = changes
+        .iter()
+        .find(|c| c.affected == NodeId::objects_folder_id())
+        .unwrap();
+    assert_eq!(
+        objects_folder_change.verb,
+        ModelChangeStructureVerbMask::ReferenceAdded as u8
+    );
+    let folder_change = changes
+        .iter()
+        .find(|c| c.affected == NodeId::new(restored_ns, "folder"))
+        .unwrap();
+    assert_eq!(folder_change.affected_type, ObjectTypeId::FolderType.into());
+    assert_eq!(
+        folder_change.verb,
+        ModelChangeStructureVerbMask::NodeAdded as u8
+            | ModelChangeStructureVerbMask::ReferenceAdded as u8
+    );
+    ["v1", "nodes"].iter().for_each(|name| {
+        let change = changes
+            .iter()
+            .find(|c| c.affected == NodeId::new(restored_ns, *name))
+            .unwrap();
+        assert_eq!(change.verb, ModelChangeStructureVerbMask::NodeAdded as u8);
+    });
+
+    // Restoring the same nodes again replaces them, which adds nothing to the model
+    let snapshot = address_space.snapshot(&[restored_ns]);
+    assert_eq!(address_space.restore_snapshot(snapshot).unwrap(), 3);
+    address_space.raise_model_change_events();
+    let changes = model_change_event_changes::<ModelChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::GeneralModelChangeEventType,
+        &ObjectId::Server.into(),
+    );
+    assert_eq!(changes.len(), 4);
+}
+
+#[test]
+fn snapshot_file() {
+    let (address_space, ns) = make_snapshot_address_space();
+
+    let path = make_test_file("address_space_snapshot.bin");
+    assert_eq!(address_space.save_snapshot_file(&path, &[ns]).unwrap(), 2);
+
+    // The snapshot is written to a temporary file which then replaces the snapshot
+    let mut tmp_path = path.clone().into_os_string();
+    tmp_path.push(".tmp");
+    assert!(!PathBuf::from(tmp_path).exists());
+
+    let mut address_space = AddressSpace::new();
+    assert_eq!(
+        address_space
+            .load_snapshot_file(&path, &DecodingLimits::default())
+            .unwrap(),
+        2
+    );
+    let restored_ns = address_space.namespace_index("urn:snapshot").unwrap();
+    verify_snapshot_address_space(&address_space, restored_ns);
+
+    // A file that is not a snapshot is rejected
+    std::fs::write(&path, b"not a snapshot").unwrap();
+    let mut address_space = AddressSpace::new();
+    assert!(address_space
+        .load_snapshot_file(&path, &DecodingLimits::default())
+        .is_err());
+    assert!(address_space.namespace_index("urn:snapshot").is_none());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+const TEST_NODESET: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<UANodeSet xmlns="http://opcfoundation.org/UA/2011/03/UANodeSet.xsd">
+  <NamespaceUris>
+    <Uri>urn:nodeset</Uri>
+  </NamespaceUris>
+  <Aliases>
+    <Alias Alias="Int32">i=6</Alias>
+    <Alias Alias="Organizes">i=35</Alias>
+    <Alias Alias="HasComponent">i=47</Alias>
+    <Alias Alias="HasProperty">i=46</Alias>
+    <Alias Alias="HasTypeDefinition">i=40</Alias>
+  </Aliases>
+  <UAObject NodeId="ns=1;i=1000" BrowseName="1:Pump">
+    <DisplayName>Pump</DisplayName>
+    <Description Locale="en">A pump</Description>
+    <References>
+      <Reference ReferenceType="Organizes" IsForward="false">i=85</Reference>
+      <Reference ReferenceType="HasTypeDefinition">i=58</Reference>
+      <Reference ReferenceType="HasComponent">ns=1;i=1001</Reference>
+      <Reference ReferenceType="HasComponent">ns=1;i=9999</Reference>
+    </References>
+  </UAObject>
+  <UAVariable NodeId="ns=1;i=1001" BrowseName="1:Speed" DataType="Int32" AccessLevel="3">
+    <DisplayName>Speed</DisplayName>
+    <Value>
+      <Int32 xmlns="http://opcfoundation.org/UA/2008/02/Types.xsd">1500</Int32>
+    </Value>
+  </UAVariable>
+  <UAVariable NodeId="ns=1;s=Modes" BrowseName="1:Modes" DataType="i=21" ValueRank="1">
+    <DisplayName>Modes</DisplayName>
+    <References>
+      <Reference ReferenceType="HasProperty" IsForward="false">ns=1;i=1000</Reference>
+    </References>
+    <Value>
+      <ListOfLocalizedText xmlns="http://opcfoundation.org/UA/2008/02/Types.xsd">
+        <LocalizedText><Locale>en</Locale><Text>Off</Text></LocalizedText>
+        <LocalizedText><Locale>en</Locale><Text>On</Text></LocalizedText>
+      </ListOfLocalizedText>
+    </Value>
+  </UAVariable>
+  <UAMethod NodeId="ns=1;i=1002" BrowseName="1:Start">
+    <DisplayName>Start</DisplayName>
+    <References>
+      <Reference ReferenceType="HasComponent" IsForward="false">ns=1;i=1000</Reference>
+    </References>
+  </UAMethod>
+</UANodeSet>
+"#;
+
+#[test]
+fn import_nodeset2() {
+    let mut address_space = AddressSpace::new();
+    // The namespace index in the node set is mapped onto the index in the address space
+    let other_ns = address_space.register_namespace("urn:other").unwrap();
+    let report = address_space
+        .import_nodeset2(TEST_NODESET.as_bytes())
+        .unwrap();
+    let ns = address_space.namespace_index("urn:nodeset").unwrap();
+    assert_ne!(ns, 1);
+    assert_eq!(report.namespaces, vec![ns]);
+    assert_eq!(address_space.default_namespace(), other_ns);
+    assert_eq!(report.nodes_added, 4);
+    assert!(report.nodes_skipped.is_empty());
+
+    let pump_id = NodeId::new(ns, 1000);
+    let speed_id = NodeId::new(ns, 1001);
+    let modes_id = NodeId::new(ns, "Modes");
+    let start_id = NodeId::new(ns, 1002);
+
+    let pump = address_space.find_node(&pump_id).unwrap().as_node();
+    assert_eq!(pump.node_class(), NodeClass::Object);
+    assert_eq!(pump.browse_name(), QualifiedName::new(ns, "Pump"));
+    assert_eq!(pump.description(), Some(LocalizedText::new("en", "A pump")));
+
+    let speed = address_space.find_variable(speed_id.clone()).unwrap();
+    assert_eq!(speed.data_type(), NodeId::from(DataTypeId::Int32));
+    assert!(speed.access_level().contains(AccessLevel::CURRENT_WRITE));
+    let value = address_space.get_variable_value(speed_id.clone()).unwrap();
+    assert_eq!(value.value, Some(Variant::Int32(1500)));
+
+    let modes = address_space.find_variable(modes_id.clone()).unwrap();
+    assert_eq!(modes.value_rank(), 1);
+    let value = address_space.get_variable_value(modes_id.clone()).unwrap();
+    assert_eq!(
+        value.value,
+        Some(Variant::from(vec![
+            Variant::from(LocalizedText::new("en", "Off")),
+            Variant::from(LocalizedText::new("en", "On")),
+        ]))
+    );
+
+    // Methods are executable unless the node set says otherwise
+    match address_space.find_node(&start_id).unwrap() {
+        NodeType::Method(method) => assert!(method.executable()),
+        _ => panic!("Start is not a method"),
+    }
+
+    // Forward and inverse references are both resolved
+    assert!(address_space.has_reference(
+        &NodeId::objects_folder_id(),
+        &pump_id,
+        ReferenceTypeId::Organizes
+    ));
+    assert!(address_space.has_reference(&pump_id, &speed_id, ReferenceTypeId::HasComponent));
+    assert!(address_space.has_reference(&pump_id, &modes_id, ReferenceTypeId::HasProperty));
+    assert!(address_space.has_reference(&pump_id, &start_id, ReferenceTypeId::HasComponent));
+
+    // The reference to a node that is nowhere to be found is reported
+    assert_eq!(
+        report.unresolved_references,
+        vec![UnresolvedReference {
+            source_node: pump_id.clone(),
+            reference_type: ReferenceTypeId::HasComponent.into(),
+            target_node: NodeId::new(ns, 9999),
+        }]
+    );
+
+    // Importing the node set again skips the nodes that already exist
+    let report = address_space
+        .import_nodeset2(TEST_NODESET.as_bytes())
+        .unwrap();
+    assert_eq!(report.nodes_added, 0);
+    assert_eq!(report.nodes_skipped.len(), 4);
+}
+
+#[test]
+fn import_nodeset2_invalid() {
+    // Malformed XML
+    let mut address_space = AddressSpace::new();
+    assert_eq!(
+        address_space.import_nodeset2("<UANodeSet>".as_bytes()),
+        Err(StatusCode::BadDecodingError)
+    );
+
+    // A node with an invalid node id fails the import without adding anything, not even the
+    // namespaces of the node set
+    let nodeset = TEST_NODESET.replace("ns=1;i=1002", "ns=1;x=1002");
+    assert!(address_space.import_nodeset2(nodeset.as_bytes()).is_err());
+    assert!(address_space.namespace_index("urn:nodeset").is_none());
+
+    // The valid node set is imported afterwards
+    let report = address_space
+        .import_nodeset2(TEST_NODESET.as_bytes())
+        .unwrap();
+    let ns = address_space.namespace_index("urn:nodeset").unwrap();
+    assert_eq!(report.namespaces, vec![ns]);
+    assert!(address_space.node_exists(&NodeId::new(ns, 1000)));
+}
+
+#[test]
+fn export_nodeset2() {
+    let mut address_space = AddressSpace::new();
+    let ns = address_space.register_namespace("urn:export").unwrap();
+
+    let pump_type_id = NodeId::new(ns, "PumpType");
+    ObjectTypeBuilder::new(
+        &pump_type_id,
+        QualifiedName::new(ns, "PumpType"),
+        "PumpType",
+    )
+    .is_abstract(true)
+    .subtype_of(ObjectTypeId::BaseObjectType)
+    .insert(&mut address_space);
+    let pump_id = NodeId::new(ns, 1);
+    ObjectBuilder::new(&pump_id, QualifiedName::new(ns, "Pump"), "Pump")
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+    let speed_id = NodeId::new(ns, 2);
+    VariableBuilder::new(&speed_id, QualifiedName::new(ns, "Speed"), "Speed & <rate>")
+        .data_type(DataTypeId::Double)
+        .value(12.5f64)
+        .writable()
+        .component_of(pump_id.clone())
+        .insert(&mut address_space);
+    let start_id = NodeId::new(ns, 3);
+    MethodBuilder::new(&start_id, QualifiedName::new(ns, "Start"), "Start")
+        .component_of(pump_id.clone())
+        .input_args(
+            &mut address_space,
+            &[Argument {
+                name: UAString::from("Rpm"),
+                data_type: DataTypeId::Int32.into(),
+                value_rank: -1,
+                array_dimensions: None,
+                description: LocalizedText::new("", "Revolutions"),
+            }],
+        )
+        .insert(&mut address_space);
+
+    let mut xml = Vec::new();
+    assert_eq!(address_space.export_nodeset2(&mut xml, &[ns]).unwrap(), 5);
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.contains("<Uri>urn:export</Uri>"));
+    assert!(xml.contains("<Model ModelUri=\"urn:export\">"));
+    assert!(xml.contains("<Alias Alias=\"HasComponent\">i=47</Alias>"));
+    assert!(xml.contains("<DisplayName>Speed &amp; &lt;rate&gt;</DisplayName>"));
+    assert!(
+        xml.contains("<Reference ReferenceType=\"Organizes\" IsForward=\"false\">i=85</Reference>")
+    );
+
+    // Import the node set into an address space where the namespace has another index
+    let mut address_space = AddressSpace::new();
+    address_space.register_namespace("urn:other").unwrap();
+    let report = address_space.import_nodeset2(xml.as_bytes()).unwrap();
+    assert_eq!(report.nodes_added, 5);
+    assert!(report.unresolved_references.is_empty());
+    let imported_ns = address_space.namespace_index("urn:export").unwrap();
+    assert_ne!(imported_ns, ns);
+
+    let pump_type_id = NodeId::new(imported_ns, "PumpType");
+    let pump_id = NodeId::new(imported_ns, 1);
+    let speed_id = NodeId::new(imported_ns, 2);
+    let start_id = NodeId::new(imported_ns, 3);
+    match address_space.find_node(&pump_type_id).unwrap() {
+        NodeType::ObjectType(object_type) => assert!(object_type.is_abstract()),
+        _ => panic!("PumpType is not an object type"),
+    }
+    assert!(address_space.has_reference(
+        &ObjectTypeId::BaseObjectType.into(),
+        &pump_type_id,
+        ReferenceTypeId::HasSubtype
+    ));
+    assert!(address_space.has_reference(
+        &NodeId::objects_folder_id(),
+        &pump_id,
+        ReferenceTypeId::Organizes
+    ));
+    assert!(address_space.has_reference(&pump_id, &speed_id, ReferenceTypeId::HasComponent));
+    assert!(address_space.has_reference(&pump_id, &start_id, ReferenceTypeId::HasComponent));
+
+    let speed = address_space.find_variable(speed_id.clone()).unwrap();
+    assert_eq!(
+        speed.display_name(),
+        LocalizedText::new("", "Speed & <rate>")
+    );
+    assert_eq!(speed.data_type(), NodeId::from(DataTypeId::Double));
+    assert!(speed.access_level().contains(AccessLevel::CURRENT_WRITE));
+    let value = address_space.get_variable_value(speed_id).unwrap();
+    assert_eq!(value.value, Some(Variant::Double(12.5)));
+
+    // The method's arguments survive as extension objects
+    let arguments_id = address_space
+        .find_references(&start_id, Some((ReferenceTypeId::HasProperty, false)))
+        .unwrap()[0]
+        .target_node
+        .clone();
+    let arguments = address_space.get_variable_value(arguments_id).unwrap();
+    match arguments.value {
+        Some(Variant::Array(array)) => match array.values[0] {
+            Variant::ExtensionObject(ref argument) => {
+                let argument = argument
+                    .decode_inner::<Argument>(&DecodingLimits::default())
+                    .unwrap();
+                assert_eq!(argument.name.as_ref(), "Rpm");
+                assert_eq!(argument.data_type, NodeId::from(DataTypeId::Int32));
+                assert_eq!(argument.value_rank, -1);
+            }
+            _ => panic!("Argument is not an extension object"),
+        },
+        _ => panic!("InputArguments is not an array"),
+    }
+
+    // Namespace 0 and unknown namespaces cannot be exported
+    assert!(address_space.export_nodeset2(Vec::new(), &[0]).is_err());
+    assert!(address_space.export_nodeset2(Vec::new(), &[99]).is_err());
+}
+
+#[test]
+fn snapshot_and_export_translations() {
+    // Test that translations of the display name, description and value survive a snapshot and
+    // an export to NodeSet2
+    let mut address_space = AddressSpace::new();
+    let ns = address_space
+        .register_namespace("urn:translations")
+        .unwrap();
+    let state_id = NodeId::new(ns, "state");
+    VariableBuilder::new(
+        &state_id,
+        QualifiedName::new(ns, "State"),
+        LocalizedText::new("en", "State"),
+    )
+    .display_name_translation(LocalizedText::new("de", "Zustand"))
+    .description(LocalizedText::new("en", "The state"))
+    .description_translation(LocalizedText::new("de", "Der Zustand"))
+    .data_type(DataTypeId::LocalizedText)
+    .value(LocalizedText::new("en", "Running"))
+    .value_translation(LocalizedText::new("de", "Läuft"))
+    .organized_by(ObjectId::ObjectsFolder)
+    .insert(&mut address_space);
+
+    let verify = |address_space: &AddressSpace, ns: u16| {
+        let locale_ids = vec![UAString::from("de")];
+        let state = address_space
+            .find_variable(NodeId::new(ns, "state"))
+            .unwrap();
+        assert_eq!(state.display_name(), LocalizedText::new("en", "State"));
+        assert_eq!(
+            state.display_name_for_locales(&locale_ids),
+            LocalizedText::new("de", "Zustand")
+        );
+        assert_eq!(
+            state.description_for_locales(&locale_ids),
+            Some(LocalizedText::new("de", "Der Zustand"))
+        );
+        assert_eq!(
+            state.value_translations(),
+            &[LocalizedText::new("de", "Läuft")]
+        );
+    };
+
+    // Snapshot
+    let snapshot = address_space.snapshot(&[ns]);
+    let mut stream = Cursor::new(snapshot.encode_to_vec());
+    let snapshot = AddressSpaceSnapshot::decode(&mut stream, &DecodingLimits::default()).unwrap();
+    let mut restored = AddressSpace::new();
+    assert_eq!(restored.restore_snapshot(snapshot).unwrap(), 1);
+    verify(
+        &restored,
+        restored.namespace_index("urn:translations").unwrap(),
+    );
+
+    // NodeSet2
+    let mut xml = Vec::new();
+    assert_eq!(address_space.export_nodeset2(&mut xml, &[ns]).unwrap(), 1);
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.contains("<DisplayName Locale=\"de\">Zustand</DisplayName>"));
+    let mut imported = AddressSpace::new();
+    let report = imported.import_nodeset2(xml.as_bytes()).unwrap();
+    assert_eq!(report.nodes_added, 1);
+    verify(
+        &imported,
+        imported.namespace_index("urn:translations").unwrap(),
+    );
+}
+
+const DATA_TYPE_NODESET: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<UANodeSet xmlns="http://opcfoundation.org/UA/2011/03/UANodeSet.xsd">
+  <NamespaceUris>
+    <Uri>urn:datatypes</Uri>
+  </NamespaceUris>
+  <Aliases>
+    <Alias Alias="Double">i=11</Alias>
+    <Alias Alias="String">i=12</Alias>
+    <Alias Alias="HasEncoding">i=38</Alias>
+    <Alias Alias="HasSubtype">i=45</Alias>
+    <Alias Alias="HasTypeDefinition">i=40</Alias>
+  </Aliases>
+  <UADataType NodeId="ns=1;i=5000" BrowseName="1:Point">
+    <DisplayName>Point</DisplayName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">i=22</Reference>
+      <Reference ReferenceType="HasEncoding">ns=1;i=5001</Reference>
+    </References>
+    <Definition Name="1:Point">
+      <Field Name="X" DataType="Double" />
+      <Field Name="Y" DataType="Double" />
+      <Field Name="Label" DataType="ns=1;i=7000" IsOptional="true">
+        <Description>Text shown next to the point</Description>
+      </Field>
+    </Definition>
+  </UADataType>
+  <UAObject NodeId="ns=1;i=5001" BrowseName="Default Binary">
+    <DisplayName>Default Binary</DisplayName>
+    <References>
+      <Reference ReferenceType="HasTypeDefinition">i=76</Reference>
+    </References>
+  </UAObject>
+  <UADataType NodeId="ns=1;i=6000" BrowseName="1:Color">
+    <DisplayName>Color</DisplayName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">i=29</Reference>
+    </References>
+    <Definition Name="1:Color">
+      <Field Name="Red" Value="0" />
+      <Field Name="Green" Value="1" />
+    </Definition>
+  </UADataType>
+  <UADataType NodeId="ns=1;i=7000" BrowseName="1:Label">
+    <DisplayName>Label</DisplayName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">String</Reference>
+    </References>
+  </UADataType>
+</UANodeSet>
+"#;
+
+fn verify_data_type_definitions(address_space: &AddressSpace, ns: u16) {
+    let point_id = NodeId::new(ns, 5000);
+    let color_id = NodeId::new(ns, 6000);
+    let label_id = NodeId::new(ns, 7000);
+    match address_space.find_node(&point_id).unwrap() {
+        NodeType::DataType(data_type) => match data_type.data_type_definition() {
+            Some(DataTypeDefinition::Structure(definition)) => {
+                // The default encoding is found from the HasEncoding reference
+                assert_eq!(definition.default_encoding_id, NodeId::new(ns, 5001));
+                assert_eq!(
+                    definition.base_data_type,
+                    NodeId::from(DataTypeId::Structure)
+                );
+                assert_eq!(
+                    definition.structure_type,
+                    StructureType::StructureWithOptionalFields
+                );
+                let fields = definition.fields.as_ref().unwrap();
+                assert_eq!(fields.len(), 3);
+                assert_eq!(fields[0].name.as_ref(), "X");
+                assert_eq!(fields[0].data_type, NodeId::from(DataTypeId::Double));
+                assert_eq!(fields[0].value_rank, -1);
+                assert!(!fields[0].is_optional);
+                assert_eq!(fields[2].data_type, label_id);
+                assert!(fields[2].is_optional);
+                assert_eq!(
+                    fields[2].description.text.as_ref(),
+                    "Text shown next to the point"
+                );
+            }
+            _ => panic!("Point has no structure definition"),
+        },
+        _ => panic!("Point is not a data type"),
+    }
+    match address_space.find_node(&color_id).unwrap() {
+        NodeType::DataType(data_type) => match data_type.data_type_definition() {
+            Some(DataTypeDefinition::Enum(definition)) => {
+                let fields = definition.fields.as_ref().unwrap();
+                assert_eq!(fields.len(), 2);
+                assert_eq!(fields[1].name.as_ref(), "Green");
+                assert_eq!(fields[1].value, 1);
+            }
+            _ => panic!("Color has no enum definition"),
+        },
+        _ => panic!("Color is not a data type"),
+    }
+    match address_space.find_node(&label_id).unwrap() {
+        NodeType::DataType(data_type) => assert!(data_type.data_type_definition().is_none()),
+        _ => panic!("Label is not a data type"),
+    }
+}
+
+#[test]
+fn data_type_definition() {
+    let mut address_space = AddressSpace::new();
+    address_space.register_namespace("urn:other").unwrap();
+    let report = address_space
+        .import_nodeset2(DATA_TYPE_NODESET.as_bytes())
+        .unwrap();
+    assert_eq!(report.nodes_added, 4);
+    let ns = address_space.namespace_index("urn:datatypes").unwrap();
+    verify_data_type_definitions(&address_space, ns);
+
+    // The registry encodes and decodes values of the types, the label as a string
+    let registry = address_space.data_type_registry();
+    assert!(registry.unknown_data_types().is_empty());
+    let point = DynamicStructure::new(NodeId::new(ns, 5000))
+        .with_field("X", Variant::from(1.0))
+        .with_field("Y", Variant::from(2.0))
+        .with_field("Label", Variant::from("origin"));
+    let extension_object = registry.encode(&point).unwrap();
+    assert_eq!(extension_object.node_id, NodeId::new(ns, 5001));
+    assert_eq!(
+        registry
+            .decode(&extension_object, &DecodingLimits::default())
+            .unwrap(),
+        point
+    );
+    assert_eq!(
+        registry
+            .enum_field(&NodeId::new(ns, 6000), 0)
+            .unwrap()
+            .name
+            .as_ref(),
+        "Red"
+    );
+
+    // Definitions are exported and survive being imported again
+    let mut xml = Vec::new();
+    assert_eq!(address_space.export_nodeset2(&mut xml, &[ns]).unwrap(), 4);
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.contains("<Definition Name=\"1:Point\">"));
+    assert!(xml.contains("<Field Name=\"Green\" Value=\"1\">"));
+    let mut address_space = AddressSpace::new();
+    address_space.import_nodeset2(xml.as_bytes()).unwrap();
+    let ns = address_space.namespace_index("urn:datatypes").unwrap();
+    verify_data_type_definitions(&address_space, ns);
+}
+
+/// Decodes the changes held by the events of the type that were raised from the source node
+fn model_change_event_changes<T>(
+    address_space: &AddressSpace,
+    event_type_id: ObjectTypeId,
+    source_node: &NodeId,
+) -> Vec<T>
+where
+    T: BinaryEncoder<T>,
+{
+    let events = filter_events(source_node.clone(), event_type_id, address_space, |_| true)
+        .unwrap_or_default();
+    events
+        .iter()
+        .flat_map(|event_id| {
+            let changes = address_space
+                .events()
+                .find(event_id)
+                .unwrap()
+                .field(&["Changes".into()])
+                .unwrap()
+                .clone();
+            match changes {
+                Variant::Array(array) => array
+                    .values
+                    .iter()
+                    .map(|v| match v {
+                        Variant::ExtensionObject(v) => {
+                            v.decode_inner::<T>(&DecodingLimits::default()).unwrap()
+                        }
+                        _ => panic!(),
+                    })
+                    .collect::<Vec<T>>(),
+                _ => panic!(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn model_change_events() {
+    let mut address_space = AddressSpace::new();
+    let ns = address_space.register_namespace("urn:test").unwrap();
+    let folder_id = NodeId::new(ns, "Folder");
+    let object_id = NodeId::new(ns, "Object");
+    ObjectBuilder::new(&folder_id, "Folder", "Folder")
+        .is_folder()
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+
+    // Nothing is recorded until model change events are turned on
+    address_space.raise_model_change_events();
+    let server_id: NodeId = ObjectId::Server.into();
+    let changes = model_change_event_changes::<ModelChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::GeneralModelChangeEventType,
+        &server_id,
+    );
+    assert!(changes.is_empty());
+
+    address_space.set_model_change_events(true);
+    ObjectBuilder::new(&object_id, "Object", "Object")
+        .organized_by(folder_id.clone())
+        .insert(&mut address_space);
+    address_space.insert_reference(&object_id, &folder_id, ReferenceTypeId::HasNotifier);
+    address_space.raise_model_change_events();
+
+    // One batch of changes, merged per affected node
+    let changes = model_change_event_changes::<ModelChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::GeneralModelChangeEventType,
+        &server_id,
+    );
+    assert_eq!(changes.len(), 2);
+    let folder_change = changes.iter().find(|c| c.affected == folder_id).unwrap();
+    assert_eq!(folder_change.affected_type, ObjectTypeId::FolderType.into());
+    assert_eq!(
+        folder_change.verb,
+        ModelChangeStructureVerbMask::ReferenceAdded as u8
+    );
+    let object_change = changes.iter().find(|c| c.affected == object_id).unwrap();
+    assert_eq!(
+        object_change.verb,
+        ModelChangeStructureVerbMask::NodeAdded as u8
+            | ModelChangeStructureVerbMask::ReferenceAdded as u8
+    );
+
+    // Raising the events themselves is not a model change so there is nothing more to raise
+    address_space.raise_model_change_events();
+    let changes = model_change_event_changes::<ModelChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::GeneralModelChangeEventType,
+        &server_id,
+    );
+    assert_eq!(changes.len(), 2);
+}
+
+#[test]
+fn node_version() {
+    let mut address_space = AddressSpace::new();
+    let ns = address_space.register_namespace("urn:test").unwrap();
+    let folder_id = NodeId::new(ns, "Folder");
+    ObjectBuilder::new(&folder_id, "Folder", "Folder")
+        .is_folder()
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+    assert!(address_space.add_node_version(&folder_id));
+    assert!(!address_space.add_node_version(&folder_id));
+    address_space.set_model_change_events(true);
+
+    let node_version = |address_space: &AddressSpace| {
+        let node_version =
+            find_node_from_browse_path(address_space, &folder_id, &["NodeVersion".into()]).unwrap();
+        match node_version
+            .as_node()
+            .get_attribute(
+                TimestampsToReturn::Neither,
+                AttributeId::Value,
+                NumericRange::None,
+                &QualifiedName::null(),
+            )
+            .unwrap()
+            .value
+        {
+            Some(Variant::String(v)) => v.as_ref().parse::<u64>().unwrap(),
+            _ => panic!(),
+        }
+    };
+
+    // Adding and deleting a child changes the version each time
+    let version = node_version(&address_space);
+    let object_id = NodeId::new(ns, "Object");
+    ObjectBuilder::new(&object_id, "Object", "Object")
+        .organized_by(folder_id.clone())
+        .insert(&mut address_space);
+    assert_eq!(node_version(&address_space), version + 1);
+    assert!(address_space.delete(&object_id, true));
+    assert_eq!(node_version(&address_space), version + 2);
+
+    // The changes to the folder are raised from the folder, the deleted object from the server
+    address_space.raise_model_change_events();
+    let changes = model_change_event_changes::<ModelChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::GeneralModelChangeEventType,
+        &folder_id,
+    );
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].affected, folder_id);
+    assert_eq!(
+        changes[0].verb,
+        ModelChangeStructureVerbMask::ReferenceAdded as u8
+            | ModelChangeStructureVerbMask::ReferenceDeleted as u8
+    );
+    let changes = model_change_event_changes::<ModelChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::GeneralModelChangeEventType,
+        &ObjectId::Server.into(),
+    );
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].affected, object_id);
+}
+
+#[test]
+fn semantic_change_events() {
+    let mut address_space = AddressSpace::new();
+    let ns = address_space.register_namespace("urn:test").unwrap();
+    let analog_id = NodeId::new(ns, "Analog");
+    let eu_range_id = NodeId::new(ns, "EURange");
+    let limit_id = NodeId::new(ns, "Limit");
+    let other_id = NodeId::new(ns, "Other");
+    VariableBuilder::new(&analog_id, "Analog", "Analog")
+        .data_type(DataTypeId::Double)
+        .value(1.0f64)
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+    let eu_range = |low: f64, high: f64| {
+        ExtensionObject::from_encodable(
+            ObjectId::Range_Encoding_DefaultBinary,
+            &Range { low, high },
+        )
+    };
+    VariableBuilder::new(&eu_range_id, "EURange", "EURange")
+        .data_type(DataTypeId::Range)
+        .value(eu_range(0.0, 10.0))
+        .property_of(analog_id.clone())
+        .insert(&mut address_space);
+    VariableBuilder::new(&limit_id, "Limit", "Limit")
+        .data_type(DataTypeId::Double)
+        .value(0.0f64)
+        .semantic_change()
+        .property_of(analog_id.clone())
+        .insert(&mut address_space);
+    VariableBuilder::new(&other_id, "Other", "Other")
+        .data_type(DataTypeId::Double)
+        .value(0.0f64)
+        .property_of(analog_id.clone())
+        .insert(&mut address_space);
+    address_space.set_model_change_events(true);
+
+    let now = DateTime::now();
+    assert!(address_space.set_variable_value(other_id, 1.0f64, &now, &now));
+    address_space.raise_model_change_events();
+    let changes = model_change_event_changes::<SemanticChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::SemanticChangeEventType,
+        &ObjectId::Server.into(),
+    );
+    assert!(changes.is_empty());
+
+    // Both properties change the meaning of the variable, which is reported once
+    assert!(address_space.set_variable_value(eu_range_id, eu_range(0.0, 100.0), &now, &now));
+    assert!(address_space.set_variable_value(limit_id, 50.0f64, &now, &now));
+    address_space.raise_model_change_events();
+    let changes = model_change_event_changes::<SemanticChangeStructureDataType>(
+        &address_space,
+        ObjectTypeId::SemanticChangeEventType,
+        &ObjectId::Server.into(),
+    );
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].affected, analog_id);
+}
@@ -3,14 +3,18 @@ use std::collections::HashSet;
 use opcua_types::{
     node_ids::ReferenceTypeId,
     operand::{ContentFilterBuilder, Operand},
-    service_types::ContentFilterElement,
-    AttributeId, DataTypeId, LocalizedText, NodeId, ObjectId, ObjectTypeId, QualifiedName,
-    UAString, VariableTypeId, Variant,
+    service_types::{ContentFilterElement, EventFilter, SimpleAttributeOperand},
+    status_code::StatusCode,
+    AttributeId, DataTypeId, DateTime, LocalizedText, NodeId, ObjectId, ObjectTypeId,
+    QualifiedName, UAString, VariableTypeId, Variant,
 };
 
 use crate::{
-    address_space::{object_type::ObjectTypeBuilder, variable::VariableBuilder, AddressSpace},
-    events::event::{BaseEventType, Event},
+    address_space::{
+        object::ObjectBuilder, object_type::ObjectTypeBuilder, variable::VariableBuilder,
+        view::ViewBuilder, AddressSpace, EventNotifier,
+    },
+    events::event::{event_sources_of, events_for_object, BaseEventType, Event},
     events::event_filter,
     events::operator,
     tests::*,
@@ -830,10 +834,11 @@ fn test_where_clause() {
     // Do some relative path comparisons against the event to ensure content filters appear to work
     let expected = vec![
         // Valid
-        (NodeId::root_folder_id(), "Objects/Event100/Foo", 100, true),
-        (NodeId::objects_folder_id(), "Event100/Foo", 100, true),
         (event_id(), "Foo", 100, true),
         // Invalid
+        // Events are not nodes of the address space so they cannot be browsed to
+        (NodeId::root_folder_id(), "Objects/Event100/Foo", 100, false),
+        (NodeId::objects_folder_id(), "Event100/Foo", 100, false),
         (NodeId::root_folder_id(), "Objects/Event101/Foo", 100, false),
         (NodeId::root_folder_id(), "Objects/Foo", 100, false),
         (NodeId::root_folder_id(), "Objects/Event100/Foo", 101, false),
@@ -860,3 +865,449 @@ fn test_where_clause() {
             assert_eq!(result.unwrap(), expected.into());
         });
 }
+
+#[test]
+fn test_of_type() {
+    do_operator_test(|address_space, object_id, used_elements, elements| {
+        // The event's own type and its base types match
+        let operands = &[Operand::literal(TestEventType::event_type_id())];
+        let result = operator::of_type(
+            &object_id,
+            &operands[..],
+            used_elements,
+            elements,
+            address_space,
+        )
+        .unwrap();
+        assert_eq!(result, Variant::Boolean(true));
+
+        let operands = &[Operand::literal(NodeId::from(ObjectTypeId::BaseEventType))];
+        let result = operator::of_type(
+            &object_id,
+            &operands[..],
+            used_elements,
+            elements,
+            address_space,
+        )
+        .unwrap();
+        assert_eq!(result, Variant::Boolean(true));
+
+        // Other types do not
+        let operands = &[Operand::literal(NodeId::from(ObjectTypeId::AuditEventType))];
+        let result = operator::of_type(
+            &object_id,
+            &operands[..],
+            used_elements,
+            elements,
+            address_space,
+        )
+        .unwrap();
+        assert_eq!(result, Variant::Boolean(false));
+
+        // The operand must be a node id
+        let operands = &[Operand::literal(10)];
+        assert_eq!(
+            operator::of_type(
+                &object_id,
+                &operands[..],
+                used_elements,
+                elements,
+                address_space
+            )
+            .unwrap_err(),
+            StatusCode::BadFilterOperandInvalid
+        );
+    })
+}
+
+#[test]
+fn test_in_view() {
+    opcua_console_logging::init();
+    let mut used_elements = HashSet::new();
+    let elements = vec![];
+    let mut address_space = address_space();
+    let object_id = event_id();
+
+    // A view containing the source of the event and a view that does not
+    let server_view_id = NodeId::new(2, "ServerView");
+    ViewBuilder::new(&server_view_id, "ServerView", "ServerView")
+        .organized_by(ObjectId::ViewsFolder)
+        .organizes(ObjectId::Server)
+        .insert(&mut address_space);
+    let empty_view_id = NodeId::new(2, "EmptyView");
+    ViewBuilder::new(&empty_view_id, "EmptyView", "EmptyView")
+        .organized_by(ObjectId::ViewsFolder)
+        .insert(&mut address_space);
+
+    let operands = &[Operand::literal(server_view_id)];
+    let result = operator::in_view(
+        &object_id,
+        &operands[..],
+        &mut used_elements,
+        &elements,
+        &address_space,
+    )
+    .unwrap();
+    assert_eq!(result, Variant::Boolean(true));
+
+    // Nodes organized below the view are in it too
+    let operands = &[Operand::literal(NodeId::new(2, "ServerView"))];
+    let result = operator::in_view(
+        &ObjectId::Server_ServerCapabilities.into(),
+        &operands[..],
+        &mut used_elements,
+        &elements,
+        &address_space,
+    )
+    .unwrap();
+    assert_eq!(result, Variant::Boolean(true));
+
+    let operands = &[Operand::literal(empty_view_id)];
+    let result = operator::in_view(
+        &object_id,
+        &operands[..],
+        &mut used_elements,
+        &elements,
+        &address_space,
+    )
+    .unwrap();
+    assert_eq!(result, Variant::Boolean(false));
+}
+
+fn related_to(
+    node_id: &NodeId,
+    operands: &[Operand],
+    used_elements: &mut HashSet<u32>,
+    address_space: &AddressSpace,
+) -> Variant {
+    operator::related_to(node_id, operands, used_elements, &[], address_space).unwrap()
+}
+
+#[test]
+fn test_related_to() {
+    opcua_console_logging::init();
+    let mut used_elements = HashSet::new();
+    let mut address_space = address_space();
+    let object_id = event_id();
+
+    // A folder organizing an object organizing a variable
+    let folder_id = NodeId::new(2, "Folder");
+    let object_id_b = NodeId::new(2, "ObjectB");
+    let variable_id_c = NodeId::new(2, "VariableC");
+    ObjectBuilder::new(&folder_id, "Folder", "Folder")
+        .organized_by(ObjectId::ObjectsFolder)
+        .has_type_definition(ObjectTypeId::FolderType)
+        .insert(&mut address_space);
+    ObjectBuilder::new(&object_id_b, "ObjectB", "ObjectB")
+        .organized_by(folder_id.clone())
+        .has_type_definition(ObjectTypeId::BaseObjectType)
+        .insert(&mut address_space);
+    VariableBuilder::new(&variable_id_c, "VariableC", "VariableC")
+        .organized_by(object_id_b.clone())
+        .data_type(DataTypeId::Int32)
+        .value(1)
+        .has_type_definition(VariableTypeId::BaseDataVariableType)
+        .insert(&mut address_space);
+
+    // The event is related to its source, the server
+    let operands = &[
+        Operand::literal(TestEventType::event_type_id()),
+        Operand::literal(NodeId::from(ObjectTypeId::ServerType)),
+        Operand::literal(NodeId::from(ReferenceTypeId::HasEventSource)),
+        Operand::literal(1u32),
+    ];
+    assert_eq!(
+        related_to(&object_id, operands, &mut used_elements, &address_space),
+        Variant::Boolean(true)
+    );
+
+    // Subtypes of the source type only match when subtypes are included
+    let operands = &[
+        Operand::literal(NodeId::from(ObjectTypeId::BaseEventType)),
+        Operand::literal(NodeId::from(ObjectTypeId::ServerType)),
+        Operand::literal(NodeId::from(ReferenceTypeId::HasEventSource)),
+        Operand::literal(1u32),
+        Operand::literal(false),
+    ];
+    assert_eq!(
+        related_to(&object_id, operands, &mut used_elements, &address_space),
+        Variant::Boolean(false)
+    );
+
+    // The variable is two hops from the folder
+    let hops = |hops: u32| {
+        vec![
+            Operand::literal(NodeId::from(ObjectTypeId::FolderType)),
+            Operand::literal(NodeId::from(VariableTypeId::BaseDataVariableType)),
+            Operand::literal(NodeId::from(ReferenceTypeId::Organizes)),
+            Operand::literal(hops),
+        ]
+    };
+    assert_eq!(
+        related_to(&folder_id, &hops(1), &mut used_elements, &address_space),
+        Variant::Boolean(false)
+    );
+    assert_eq!(
+        related_to(&folder_id, &hops(2), &mut used_elements, &address_space),
+        Variant::Boolean(true)
+    );
+    // No limit on hops
+    assert_eq!(
+        related_to(&folder_id, &hops(0), &mut used_elements, &address_space),
+        Variant::Boolean(true)
+    );
+
+    // HierarchicalReferences includes Organizes unless subtypes of the reference type are excluded
+    let operands = |include_reference_subtypes: bool| {
+        vec![
+            Operand::literal(NodeId::from(ObjectTypeId::FolderType)),
+            Operand::literal(NodeId::from(ObjectTypeId::BaseObjectType)),
+            Operand::literal(NodeId::from(ReferenceTypeId::HierarchicalReferences)),
+            Operand::literal(1u32),
+            Operand::literal(true),
+            Operand::literal(include_reference_subtypes),
+        ]
+    };
+    assert_eq!(
+        related_to(
+            &folder_id,
+            &operands(true),
+            &mut used_elements,
+            &address_space
+        ),
+        Variant::Boolean(true)
+    );
+    assert_eq!(
+        related_to(
+            &folder_id,
+            &operands(false),
+            &mut used_elements,
+            &address_space
+        ),
+        Variant::Boolean(false)
+    );
+
+    // The target can be another RelatedTo which is evaluated against each related node
+    let elements = ContentFilterBuilder::new()
+        .is_related_to(
+            Operand::literal(NodeId::from(ObjectTypeId::FolderType)),
+            Operand::element(1),
+            Operand::literal(NodeId::from(ReferenceTypeId::Organizes)),
+            Operand::literal(1u32),
+        )
+        .is_related_to(
+            Operand::literal(NodeId::from(ObjectTypeId::BaseObjectType)),
+            Operand::literal(NodeId::from(VariableTypeId::BaseDataVariableType)),
+            Operand::literal(NodeId::from(ReferenceTypeId::Organizes)),
+            Operand::literal(1u32),
+        )
+        .build()
+        .elements
+        .unwrap();
+    used_elements.insert(0);
+    let result = operator::evaluate(
+        &folder_id,
+        &elements[0],
+        &mut used_elements,
+        &elements,
+        &address_space,
+    )
+    .unwrap();
+    assert_eq!(result, Variant::Boolean(true));
+}
+
+#[test]
+fn validate_type_operators() {
+    let address_space = address_space();
+
+    let where_clause = ContentFilterBuilder::new()
+        .is_of_type(Operand::literal(TestEventType::event_type_id()))
+        .is_in_view(Operand::literal(NodeId::from(ObjectId::ViewsFolder)))
+        .is_related_to(
+            Operand::literal(TestEventType::event_type_id()),
+            Operand::element(0),
+            Operand::literal(NodeId::from(ReferenceTypeId::HasEventSource)),
+            Operand::literal(1u32),
+        )
+        .is_related_to(
+            Operand::literal(TestEventType::event_type_id()),
+            Operand::literal(NodeId::from(ObjectTypeId::ServerType)),
+            Operand::literal(NodeId::from(ObjectTypeId::ServerType)),
+            Operand::literal("one"),
+        )
+        .build();
+    let event_filter = EventFilter {
+        select_clauses: None,
+        where_clause,
+    };
+    let result = event_filter::validate(&event_filter, &address_space).unwrap();
+    let element_results = result.where_clause_result.element_results.unwrap();
+
+    // OfType is valid
+    assert_eq!(element_results[0].status_code, StatusCode::Good);
+
+    // The objects folder is not a view
+    assert_eq!(
+        element_results[1].status_code,
+        StatusCode::BadFilterOperatorInvalid
+    );
+    assert_eq!(
+        element_results[1].operand_status_codes,
+        Some(vec![StatusCode::BadFilterOperandInvalid])
+    );
+
+    // An element operand of RelatedTo must be another RelatedTo
+    assert_eq!(
+        element_results[2].operand_status_codes,
+        Some(vec![
+            StatusCode::Good,
+            StatusCode::BadFilterOperandInvalid,
+            StatusCode::Good,
+            StatusCode::Good,
+        ])
+    );
+
+    // The reference type and hops must be valid
+    assert_eq!(
+        element_results[3].operand_status_codes,
+        Some(vec![
+            StatusCode::Good,
+            StatusCode::Good,
+            StatusCode::BadFilterOperandInvalid,
+            StatusCode::BadFilterOperandInvalid,
+        ])
+    );
+}
+
+#[test]
+fn test_event_propagation() {
+    let mut address_space = address_space();
+
+    // A plant area containing a machine and a sub area containing another machine
+    let area1_id = NodeId::new(2, "Area1");
+    let area2_id = NodeId::new(2, "Area2");
+    let machine1_id = NodeId::new(2, "Machine1");
+    let machine2_id = NodeId::new(2, "Machine2");
+    ObjectBuilder::new(&area1_id, "Area1", "Area1")
+        .organized_by(ObjectId::ObjectsFolder)
+        .event_notifier(EventNotifier::SUBSCRIBE_TO_EVENTS)
+        .notifier_of(ObjectId::Server)
+        .insert(&mut address_space);
+    ObjectBuilder::new(&area2_id, "Area2", "Area2")
+        .organized_by(area1_id.clone())
+        .event_notifier(EventNotifier::SUBSCRIBE_TO_EVENTS)
+        .notifier_of(area1_id.clone())
+        .insert(&mut address_space);
+    ObjectBuilder::new(&machine1_id, "Machine1", "Machine1")
+        .organized_by(area1_id.clone())
+        .event_source_of(area1_id.clone())
+        .insert(&mut address_space);
+    ObjectBuilder::new(&machine2_id, "Machine2", "Machine2")
+        .organized_by(area2_id.clone())
+        .event_source_of(area2_id.clone())
+        .insert(&mut address_space);
+
+    let event_sources = event_sources_of(area1_id.clone(), &address_space);
+    assert_eq!(event_sources.len(), 4);
+    assert!(event_sources.contains(&machine2_id));
+
+    let event1_id = NodeId::new(2, "Event1");
+    let event2_id = NodeId::new(2, "Event2");
+    create_event(&mut address_space, event1_id.clone(), &machine1_id, 1);
+    create_event(&mut address_space, event2_id.clone(), &machine2_id, 2);
+
+    let events = |notifier_id: &NodeId| {
+        let mut events =
+            events_for_object(notifier_id.clone(), &address_space, 0).unwrap_or_default();
+        events.sort_by_key(|event_id| format!("{}", event_id));
+        events
+    };
+
+    // Events reach the notifiers above their sources
+    assert_eq!(events(&machine2_id), vec![event2_id.clone()]);
+    assert_eq!(events(&area2_id), vec![event2_id.clone()]);
+    assert_eq!(
+        events(&area1_id),
+        vec![event1_id.clone(), event2_id.clone()]
+    );
+    assert_eq!(events(&machine1_id), vec![event1_id.clone()]);
+
+    // The server receives every event, including the one raised by address_space()
+    let server_events = events(&NodeId::from(ObjectId::Server));
+    assert_eq!(server_events.len(), 3);
+    assert!(server_events.contains(&event_id()));
+
+    // The server object can be subscribed to for events
+    let server = address_space.find_node(&ObjectId::Server.into()).unwrap();
+    if let crate::address_space::node::NodeType::Object(ref server) = server {
+        assert!(server
+            .event_notifier()
+            .contains(EventNotifier::SUBSCRIBE_TO_EVENTS));
+    } else {
+        panic!();
+    }
+}
+
+#[test]
+fn test_evaluate_raised_events() {
+    let mut address_space = address_space();
+    let last_sequence = address_space.events().last_sequence();
+
+    // An event with a translated message which happened an hour before it was raised
+    let event_id = NodeId::new(2, "Event2");
+    let time = DateTime::from(chrono::Utc::now() - chrono::Duration::hours(1));
+    let mut event = BaseEventType::new(
+        &event_id,
+        TestEventType::event_type_id(),
+        "Event2",
+        "Event2",
+        NodeId::null(),
+        time,
+    )
+    .source_node(ObjectId::Server)
+    .message(LocalizedText::new("en", "Hello"))
+    .message_translation(LocalizedText::new("de", "Hallo"));
+    assert!(event.raise(&mut address_space).is_ok());
+    assert!(address_space.find_node(&event_id).is_none());
+
+    let event_filter = EventFilter {
+        select_clauses: Some(vec![SimpleAttributeOperand::new(
+            ObjectTypeId::BaseEventType,
+            "Message",
+            AttributeId::Value,
+            UAString::null(),
+        )]),
+        where_clause: ContentFilterBuilder::new()
+            .is_of_type(Operand::literal(TestEventType::event_type_id()))
+            .build(),
+    };
+
+    // Only the event raised after the sequence number is received, despite its time, and its
+    // message is in the preferred locale
+    let server_id = NodeId::from(ObjectId::Server);
+    let events = event_filter::evaluate(
+        &server_id,
+        &event_filter,
+        &address_space,
+        last_sequence,
+        1,
+        &[UAString::from("de")],
+    )
+    .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].event_fields,
+        Some(vec![Variant::from(LocalizedText::new("de", "Hallo"))])
+    );
+
+    // There is nothing more to receive
+    assert!(event_filter::evaluate(
+        &server_id,
+        &event_filter,
+        &address_space,
+        address_space.events().last_sequence(),
+        1,
+        &[],
+    )
+    .is_none());
+}
@@ -15,6 +15,7 @@ use crate::{
     address_space::{address_space::*, variable::*},
     builder::ServerBuilder,
     config::ServerConfig,
+    server::Server,
     session::*,
     subscriptions::*,
 };
@@ -104,6 +105,174 @@ pub fn server_config_invalid() {
     assert_eq!(config.is_valid(), false);
 }
 
+#[test]
+pub fn server_config_invalid_limits() {
+    let mut config = ServerBuilder::new_anonymous("foo").config();
+    config.limits.operational.max_nodes_per_read = 0;
+    assert_eq!(config.is_valid(), false);
+
+    config = ServerBuilder::new_anonymous("foo").config();
+    config.limits.max_browse_continuation_points = 0;
+    assert_eq!(config.is_valid(), false);
+
+    config = ServerBuilder::new_anonymous("foo").config();
+    config.limits.min_sampling_interval = 0.0;
+    assert_eq!(config.is_valid(), false);
+}
+
+#[test]
+pub fn server_capabilities_from_config() {
+    use opcua_types::node_ids::VariableId;
+
+    let mut operational_limits = crate::config::OperationalLimits::default();
+    operational_limits.max_nodes_per_read = 123;
+    operational_limits.max_monitored_items_per_call = 77;
+
+    let server = ServerBuilder::new_sample()
+        .max_array_length(5000)
+        .max_browse_continuation_points(7)
+        .min_sampling_interval(0.25)
+        .locale_ids(vec!["en".to_string(), "de".to_string()])
+        .operational_limits(operational_limits)
+        .server()
+        .unwrap();
+
+    let address_space = server.address_space();
+    let address_space = trace_read_lock_unwrap!(address_space);
+
+    let value = |id: VariableId| address_space.get_variable_value(id).unwrap().value.unwrap();
+
+    assert_eq!(
+        value(VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerRead),
+        Variant::UInt32(123)
+    );
+    assert_eq!(
+        value(VariableId::Server_ServerCapabilities_OperationLimits_MaxMonitoredItemsPerCall),
+        Variant::UInt32(77)
+    );
+    assert_eq!(
+        value(VariableId::Server_ServerCapabilities_MaxArrayLength),
+        Variant::UInt32(5000)
+    );
+    assert_eq!(
+        value(VariableId::Server_ServerCapabilities_MaxBrowseContinuationPoints),
+        Variant::UInt32(7)
+    );
+    assert_eq!(
+        value(VariableId::Server_ServerCapabilities_MinSupportedSampleRate),
+        Variant::Double(250.0)
+    );
+    assert_eq!(
+        value(VariableId::Server_ServerCapabilities_LocaleIdArray),
+        Variant::from(vec![
+            Variant::from(UAString::from("en")),
+            Variant::from(UAString::from("de"))
+        ])
+    );
+}
+
+#[test]
+pub fn server_shutdown_status() {
+    use opcua_types::{node_ids::VariableId, service_types::ServerState as ServerStateType};
+
+    let mut server = ServerBuilder::new_sample().server().unwrap();
+    server.shutdown(10, LocalizedText::new("", "Maintenance"));
+
+    {
+        let server_state = server.server_state();
+        let server_state = trace_read_lock_unwrap!(server_state);
+        assert!(server_state.is_shutting_down());
+        assert!(!server_state.is_abort());
+        assert_eq!(server_state.state(), ServerStateType::Shutdown);
+    }
+
+    let address_space = server.address_space();
+    let address_space = trace_read_lock_unwrap!(address_space);
+
+    let value = |id: VariableId| address_space.get_variable_value(id).unwrap().value.unwrap();
+
+    assert_eq!(
+        value(VariableId::Server_ServerStatus_State),
+        Variant::Int32(ServerStateType::Shutdown as i32)
+    );
+    match value(VariableId::Server_ServerStatus_SecondsTillShutdown) {
+        Variant::UInt32(seconds_till_shutdown) => {
+            assert!(seconds_till_shutdown > 0 && seconds_till_shutdown <= 10)
+        }
+        v => panic!("Unexpected SecondsTillShutdown {:?}", v),
+    }
+    assert_eq!(
+        value(VariableId::Server_ServerStatus_ShutdownReason),
+        Variant::from(LocalizedText::new("", "Maintenance"))
+    );
+}
+
+#[test]
+pub fn server_reload_config() {
+    use crate::config::{ServerEndpoint, ANONYMOUS_USER_TOKEN_ID};
+    use opcua_types::node_ids::VariableId;
+
+    let config = ServerBuilder::new_sample().config();
+    let mut server = Server::new(config.clone());
+
+    // Change a setting that needs a restart and some that can be applied live
+    let mut new_config = config.clone();
+    new_config.tcp_config.port += 1;
+    new_config.limits.operational.max_nodes_per_read = 321;
+    new_config.add_endpoint(
+        "extra",
+        ServerEndpoint::new_none("/extra", &[ANONYMOUS_USER_TOKEN_ID.to_string()]),
+    );
+
+    let report = server.reload_config(new_config).unwrap();
+    assert_eq!(report.restart_required, vec!["tcp_config.port".to_string()]);
+    assert_eq!(
+        report.applied,
+        vec!["limits".to_string(), "endpoints".to_string()]
+    );
+
+    {
+        let server_state = server.server_state();
+        let server_state = trace_read_lock_unwrap!(server_state);
+        assert_eq!(server_state.operational_limits.max_nodes_per_read, 321);
+        let server_config = trace_read_lock_unwrap!(server_state.config);
+        assert!(server_config.endpoints.contains_key("extra"));
+        // The port is left alone until a restart
+        assert_eq!(server_config.tcp_config.port, config.tcp_config.port);
+    }
+    {
+        let address_space = server.address_space();
+        let address_space = trace_read_lock_unwrap!(address_space);
+        assert_eq!(
+            address_space
+                .get_variable_value(
+                    VariableId::Server_ServerCapabilities_OperationLimits_MaxNodesPerRead
+                )
+                .unwrap()
+                .value
+                .unwrap(),
+            Variant::UInt32(321)
+        );
+    }
+
+    // Reloading the same config again changes nothing
+    let mut same_config = config.clone();
+    same_config.limits.operational.max_nodes_per_read = 321;
+    same_config.add_endpoint(
+        "extra",
+        ServerEndpoint::new_none("/extra", &[ANONYMOUS_USER_TOKEN_ID.to_string()]),
+    );
+    assert!(server.reload_config(same_config).unwrap().is_empty());
+
+    // Invalid configs are refused
+    let mut invalid_config = config.clone();
+    invalid_config.endpoints.clear();
+    assert_eq!(
+        server.reload_config(invalid_config).unwrap_err(),
+        StatusCode::BadConfigurationError
+    );
+}
+
 #[test]
 pub fn expired_publish_requests() {
     let now = chrono::Utc::now();
@@ -1,4 +1,7 @@
+use std::sync::Mutex;
+
 use chrono::Duration;
+use futures::{future, sync::oneshot, Future};
 
 use opcua_types::{Variant, WriteMask};
 
@@ -103,7 +106,10 @@ fn read() {
                 nodes_to_read: Some(nodes_to_read),
             };
 
-            let response = ats.read(server_state, session, address_space, &request);
+            let response = ats
+                .async_read(server_state, session, address_space, &request)
+                .wait()
+                .unwrap();
             let response: ReadResponse = supported_message_as!(response, ReadResponse);
 
             // Verify expected values
@@ -165,6 +171,65 @@ fn read() {
     });
 }
 
+#[test]
+fn read_data_type_definition() {
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let definition = StructureDefinition {
+            default_encoding_id: NodeId::new(1, "PointEncoding"),
+            base_data_type: DataTypeId::Structure.into(),
+            structure_type: StructureType::Structure,
+            fields: Some(vec![StructureField {
+                name: UAString::from("X"),
+                description: LocalizedText::null(),
+                data_type: DataTypeId::Double.into(),
+                value_rank: -1,
+                array_dimensions: None,
+                max_string_length: 0,
+                is_optional: false,
+            }]),
+        };
+        let point_id = NodeId::new(1, "Point");
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            DataTypeBuilder::new(&point_id, "Point", "Point")
+                .subtype_of(DataTypeId::Structure)
+                .data_type_definition(definition.clone())
+                .insert(&mut address_space);
+        }
+
+        let nodes_to_read = vec![
+            read_value(&point_id, AttributeId::DataTypeDefinition),
+            // A data type without a definition
+            read_value(&DataTypeId::Double.into(), AttributeId::DataTypeDefinition),
+        ];
+        let request = ReadRequest {
+            request_header: make_request_header(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Neither,
+            nodes_to_read: Some(nodes_to_read),
+        };
+        let response = ats
+            .async_read(server_state, session, address_space, &request)
+            .wait()
+            .unwrap();
+        let response: ReadResponse = supported_message_as!(response, ReadResponse);
+        let results = response.results.unwrap();
+
+        match results[0].value {
+            Some(Variant::ExtensionObject(ref value)) => assert_eq!(
+                DataTypeDefinition::from_extension_object(value, &DecodingLimits::default())
+                    .unwrap(),
+                DataTypeDefinition::Structure(definition)
+            ),
+            _ => panic!("Definition is not an extension object"),
+        }
+        assert_eq!(
+            results[1].status.as_ref().unwrap(),
+            &StatusCode::BadAttributeIdInvalid
+        );
+    });
+}
+
 #[test]
 fn read_invalid_timestamps() {
     // The TimestampsToReturnEnum will be set to Invalid to simulate a decoding error.
@@ -183,7 +248,10 @@ fn read_invalid_timestamps() {
             nodes_to_read: Some(nodes_to_read),
         };
 
-        let response = ats.read(server_state, session, address_space, &request);
+        let response = ats
+            .async_read(server_state, session, address_space, &request)
+            .wait()
+            .unwrap();
         let response = supported_message_as!(response, ServiceFault);
 
         assert_eq!(
@@ -193,6 +261,91 @@ fn read_invalid_timestamps() {
     });
 }
 
+/// Supplies the value sent on a channel, or an error if the sender is dropped
+struct AsyncValueGetter {
+    receiver: Option<oneshot::Receiver<i32>>,
+}
+
+impl AsyncAttributeGetter for AsyncValueGetter {
+    fn get(
+        &mut self,
+        _node_id: &NodeId,
+        _timestamps_to_return: TimestampsToReturn,
+        _attribute_id: AttributeId,
+        _index_range: NumericRange,
+        _data_encoding: &QualifiedName,
+        _max_age: f64,
+    ) -> AsyncResult<Option<DataValue>> {
+        let receiver = self.receiver.take().unwrap();
+        Box::new(
+            receiver
+                .map(|value| Some(DataValue::new_now(value)))
+                .map_err(|_| StatusCode::BadCommunicationError),
+        )
+    }
+}
+
+#[test]
+fn read_async_value_getter() {
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_ids = node_ids(address_space.clone());
+
+        let (sender_0, receiver_0) = oneshot::channel();
+        let (sender_2, receiver_2) = oneshot::channel();
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let v = address_space.find_variable_mut(&node_ids[0]).unwrap();
+            v.set_async_value_getter(Arc::new(Mutex::new(AsyncValueGetter {
+                receiver: Some(receiver_0),
+            })));
+            let v = address_space.find_variable_mut(&node_ids[2]).unwrap();
+            v.set_async_value_getter(Arc::new(Mutex::new(AsyncValueGetter {
+                receiver: Some(receiver_2),
+            })));
+        }
+
+        let nodes_to_read = vec![
+            read_value(&node_ids[0], AttributeId::Value),
+            read_value(&node_ids[1], AttributeId::Value),
+            read_value(&node_ids[2], AttributeId::Value),
+        ];
+        let request = ReadRequest {
+            request_header: make_request_header(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Server,
+            nodes_to_read: Some(nodes_to_read),
+        };
+        let response = ats.async_read(server_state, session, address_space.clone(), &request);
+
+        // The address space is not locked while the values are obtained
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let v = address_space.find_variable_mut(&node_ids[1]).unwrap();
+            let _ = v.set_value(NumericRange::None, 99);
+        }
+        let _ = sender_0.send(100);
+        drop(sender_2);
+
+        let response: ReadResponse = supported_message_as!(response.wait().unwrap(), ReadResponse);
+        let results = response.results.unwrap();
+
+        // 1. a value from the asynchronous getter with only the server timestamp
+        assert_eq!(results[0].value.as_ref().unwrap(), &Variant::Int32(100));
+        assert!(results[0].server_timestamp.is_some());
+        assert!(results[0].source_timestamp.is_none());
+
+        // 2. a value read immediately, before the address space was modified
+        assert_eq!(results[1].value.as_ref().unwrap(), &Variant::Int32(1));
+
+        // 3. the asynchronous getter failed
+        assert_eq!(results[2].value, None);
+        assert_eq!(
+            results[2].status.as_ref().unwrap(),
+            &StatusCode::BadCommunicationError
+        );
+    });
+}
+
 fn write_value(node_id: &NodeId, attribute_id: AttributeId, value: DataValue) -> WriteValue {
     WriteValue {
         node_id: node_id.clone(),
@@ -312,7 +465,7 @@ fn write() {
             node_ids
         };
 
-        let mut data_value_empty = DataValue::new_now(100 as i32);
+        let mut data_value_empty = DataValue::value_only(100 as i32);
         data_value_empty.value = None;
 
         // This is a cross section of variables and other kinds of nodes that we want to write to
@@ -321,43 +474,43 @@ fn write() {
             write_value(
                 &node_ids[0],
                 AttributeId::Value,
-                DataValue::new_now(100 as i32),
+                DataValue::value_only(100 as i32),
             ),
             // 2. a variable with a bad attribute (IsAbstract doesn't exist on a var)
             write_value(
                 &node_ids[1],
                 AttributeId::IsAbstract,
-                DataValue::new_now(true),
+                DataValue::value_only(true),
             ),
             // 3. a variable value which has no write access
             write_value(
                 &node_ids[2],
                 AttributeId::Value,
-                DataValue::new_now(200 as i32),
+                DataValue::value_only(200 as i32),
             ),
             // 4. a node of some kind other than variable
             write_value(
                 &ReferenceTypeId::HasEncoding.into(),
                 AttributeId::IsAbstract,
-                DataValue::new_now(false),
+                DataValue::value_only(false),
             ),
             // 5. a node with some kind other than variable with no write mask
             write_value(
                 &ReferenceTypeId::HasChild.into(),
                 AttributeId::IsAbstract,
-                DataValue::new_now(false),
+                DataValue::value_only(false),
             ),
             // 6. a non existent variable
             write_value(
                 &NodeId::new(2, "vxxx"),
                 AttributeId::Value,
-                DataValue::new_now(100i32),
+                DataValue::value_only(100i32),
             ),
             // 7. wrong type for attribute
             write_value(
                 &node_ids[6],
                 AttributeId::AccessLevel,
-                DataValue::new_now(-1i8),
+                DataValue::value_only(-1i8),
             ),
             // 8. a data value with no value
             write_value(&node_ids[7], AttributeId::Value, data_value_empty),
@@ -399,6 +552,203 @@ fn write() {
     });
 }
 
+#[test]
+fn write_attributes_with_write_mask() {
+    // Test that attributes other than Value are written according to the write mask and the
+    // user write mask, and that the successful writes raise one model change event.
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_id = NodeId::next_numeric(2);
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            address_space.set_model_change_events(true);
+            let _ = VariableBuilder::new(&node_id, var_name(0), "Old name")
+                .data_type(DataTypeId::Int32)
+                .value(0i32)
+                .organized_by(ObjectId::RootFolder)
+                .insert(&mut address_space);
+            let node = address_space.find_node_mut(&node_id).unwrap().as_mut_node();
+            node.set_write_mask(
+                WriteMask::DISPLAY_NAME
+                    | WriteMask::DESCRIPTION
+                    | WriteMask::HISTORIZING
+                    | WriteMask::NODE_ID,
+            );
+            node.set_user_write_mask(
+                WriteMask::DISPLAY_NAME | WriteMask::HISTORIZING | WriteMask::NODE_ID,
+            );
+        }
+
+        let nodes_to_write = vec![
+            // 1. allowed by both masks
+            write_value(
+                &node_id,
+                AttributeId::DisplayName,
+                DataValue::value_only(LocalizedText::new("", "New name")),
+            ),
+            // 2. denied by the user write mask
+            write_value(
+                &node_id,
+                AttributeId::Description,
+                DataValue::value_only(LocalizedText::new("", "Description")),
+            ),
+            // 3. denied by the write mask
+            write_value(
+                &node_id,
+                AttributeId::ValueRank,
+                DataValue::value_only(1i32),
+            ),
+            // 4. wrong type for the attribute
+            write_value(
+                &node_id,
+                AttributeId::Historizing,
+                DataValue::value_only(1i32),
+            ),
+            // 5. node id can never be written
+            write_value(
+                &node_id,
+                AttributeId::NodeId,
+                DataValue::value_only(NodeId::new(2, "xyz")),
+            ),
+            // 6. allowed again in the same request
+            write_value(
+                &node_id,
+                AttributeId::DisplayName,
+                DataValue::value_only(LocalizedText::new("", "Newer name")),
+            ),
+        ];
+        let response = write_request(
+            server_state,
+            session,
+            address_space.clone(),
+            ats,
+            nodes_to_write,
+        );
+        let results = response.results.unwrap();
+        assert_eq!(results[0], StatusCode::Good);
+        assert_eq!(results[1], StatusCode::BadNotWritable);
+        assert_eq!(results[2], StatusCode::BadNotWritable);
+        assert_eq!(results[3], StatusCode::BadTypeMismatch);
+        assert_eq!(results[4], StatusCode::BadNotWritable);
+        assert_eq!(results[5], StatusCode::Good);
+
+        let address_space = trace_read_lock_unwrap!(address_space);
+        let node = address_space.find_node(&node_id).unwrap();
+        assert_eq!(
+            node.as_node().display_name(),
+            LocalizedText::new("", "Newer name")
+        );
+        let event_type_id = NodeId::from(ObjectTypeId::BaseModelChangeEventType);
+        let events = address_space
+            .events()
+            .iter()
+            .filter(|event| *event.event_type_id() == event_type_id)
+            .count();
+        assert_eq!(events, 1);
+    });
+}
+
+#[test]
+fn write_status_and_timestamps() {
+    // Test that the status and timestamps of a value are only written when the access level has
+    // StatusWrite and TimestampWrite.
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_id_1 = NodeId::next_numeric(2);
+        let node_id_2 = NodeId::next_numeric(2);
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let _ = VariableBuilder::new(&node_id_1, var_name(0), "")
+                .data_type(DataTypeId::Int32)
+                .value(0i32)
+                .organized_by(ObjectId::RootFolder)
+                .access_level(
+                    AccessLevel::CURRENT_READ
+                        | AccessLevel::CURRENT_WRITE
+                        | AccessLevel::STATUS_WRITE
+                        | AccessLevel::TIMESTAMP_WRITE,
+                )
+                .user_access_level(
+                    UserAccessLevel::CURRENT_READ
+                        | UserAccessLevel::CURRENT_WRITE
+                        | UserAccessLevel::STATUS_WRITE
+                        | UserAccessLevel::TIMESTAMP_WRITE,
+                )
+                .insert(&mut address_space);
+            let _ = VariableBuilder::new(&node_id_2, var_name(1), "")
+                .data_type(DataTypeId::Int32)
+                .value(0i32)
+                .organized_by(ObjectId::RootFolder)
+                .writable()
+                .insert(&mut address_space);
+            let node = address_space.find_node_mut(&node_id_2).unwrap();
+            node.as_mut_node().set_write_mask(WriteMask::DISPLAY_NAME);
+        }
+
+        let source_timestamp = DateTime::ymd_hms(2020, 1, 2, 3, 4, 5);
+        let data_value = DataValue {
+            value: Some(Variant::Int32(1)),
+            status: Some(StatusCode::UncertainLastUsableValue),
+            source_timestamp: Some(source_timestamp.clone()),
+            source_picoseconds: None,
+            server_timestamp: None,
+            server_picoseconds: None,
+        };
+        let nodes_to_write = vec![
+            // 1. status and timestamps are allowed
+            write_value(&node_id_1, AttributeId::Value, data_value.clone()),
+            // 2. status and timestamps are not allowed
+            write_value(&node_id_2, AttributeId::Value, data_value),
+            // 3. the value on its own is allowed
+            write_value(&node_id_2, AttributeId::Value, DataValue::value_only(2i32)),
+            // 4. timestamps are never allowed on other attributes
+            write_value(
+                &node_id_2,
+                AttributeId::DisplayName,
+                DataValue::new_now(LocalizedText::new("", "Name")),
+            ),
+        ];
+        let response = write_request(
+            server_state,
+            session,
+            address_space.clone(),
+            ats,
+            nodes_to_write,
+        );
+        let results = response.results.unwrap();
+        assert_eq!(results[0], StatusCode::Good);
+        assert_eq!(results[1], StatusCode::BadWriteNotSupported);
+        assert_eq!(results[2], StatusCode::Good);
+        assert_eq!(results[3], StatusCode::BadWriteNotSupported);
+
+        let address_space = trace_read_lock_unwrap!(address_space);
+        if let NodeType::Variable(node) = address_space.find_node(&node_id_1).unwrap() {
+            let value = node.value(
+                TimestampsToReturn::Both,
+                NumericRange::None,
+                &QualifiedName::null(),
+                0.,
+            );
+            assert_eq!(value.value, Some(Variant::Int32(1)));
+            assert_eq!(value.status, Some(StatusCode::UncertainLastUsableValue));
+            assert_eq!(value.source_timestamp, Some(source_timestamp));
+            assert!(value.server_timestamp.is_some());
+        } else {
+            panic!();
+        }
+        if let NodeType::Variable(node) = address_space.find_node(&node_id_2).unwrap() {
+            let value = node.value(
+                TimestampsToReturn::Both,
+                NumericRange::None,
+                &QualifiedName::null(),
+                0.,
+            );
+            assert_eq!(value.value, Some(Variant::Int32(2)));
+            assert_eq!(value.status, Some(StatusCode::Good));
+        } else {
+            panic!();
+        }
+    });
+}
+
 #[test]
 fn write_bytestring_to_byte_array() {
     // This test checks that writing a byte string to a byte array variable works
@@ -420,7 +770,7 @@ fn write_bytestring_to_byte_array() {
         let nodes_to_write = vec![write_value(
             &node_id,
             AttributeId::Value,
-            DataValue::new_now(bytes),
+            DataValue::value_only(bytes),
         )];
 
         // Do a write
@@ -488,13 +838,13 @@ fn write_index_range() {
                 &node_id_1,
                 AttributeId::Value,
                 format!("{}", index),
-                DataValue::new_now(index_bytes),
+                DataValue::value_only(index_bytes),
             ),
             write_value_index_range(
                 &node_id_2,
                 AttributeId::Value,
                 format!("{}:{}", range_min, range_max),
-                DataValue::new_now(range_value),
+                DataValue::value_only(range_value),
             ),
         ];
 
@@ -552,6 +902,285 @@ fn write_index_range() {
     });
 }
 
+#[test]
+fn read_write_matrix_index_range() {
+    // Test that index ranges work on each dimension of a matrix and on string elements
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let matrix_id = NodeId::next_numeric(2);
+        let strings_id = NodeId::next_numeric(2);
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            // A 3x3 matrix of values 0..9
+            let values: Vec<Variant> = (0..9).map(Variant::Int32).collect();
+            let _ = VariableBuilder::new(&matrix_id, var_name(0), "")
+                .data_type(DataTypeId::Int32)
+                .value_rank(2)
+                .array_dimensions(&[3, 3])
+                .value(Variant::from((values, vec![3u32, 3u32])))
+                .organized_by(ObjectId::RootFolder)
+                .writable()
+                .insert(&mut address_space);
+            let _ = VariableBuilder::new(&strings_id, var_name(1), "")
+                .data_type(DataTypeId::String)
+                .value_rank(1)
+                .value(vec![Variant::from("Hello"), Variant::from("World")])
+                .organized_by(ObjectId::RootFolder)
+                .writable()
+                .insert(&mut address_space);
+        }
+
+        let nodes_to_read = vec![
+            read_value_range(&matrix_id, AttributeId::Value, UAString::from("1:2,0")),
+            read_value_range(&strings_id, AttributeId::Value, UAString::from("0:1,1:2")),
+            read_value_range(&matrix_id, AttributeId::Value, UAString::from("3,0")),
+            read_value_range(&matrix_id, AttributeId::Value, UAString::from("1")),
+        ];
+        let request = ReadRequest {
+            request_header: make_request_header(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Both,
+            nodes_to_read: Some(nodes_to_read),
+        };
+        let response = ats
+            .async_read(
+                server_state.clone(),
+                session.clone(),
+                address_space.clone(),
+                &request,
+            )
+            .wait()
+            .unwrap();
+        let response: ReadResponse = supported_message_as!(response, ReadResponse);
+        let results = response.results.unwrap();
+
+        // The first column of the last two rows
+        assert_eq!(
+            results[0].value.as_ref().unwrap(),
+            &Variant::from((vec![Variant::Int32(3), Variant::Int32(6)], vec![2u32, 1u32]))
+        );
+        // Substrings of each element
+        assert_eq!(
+            results[1].value.as_ref().unwrap(),
+            &Variant::from(vec![Variant::from("el"), Variant::from("or")])
+        );
+        // Out of range and too few dimensions
+        assert_eq!(
+            results[2].status.as_ref().unwrap(),
+            &StatusCode::BadIndexRangeNoData
+        );
+        assert_eq!(
+            results[3].status.as_ref().unwrap(),
+            &StatusCode::BadIndexRangeInvalid
+        );
+
+        let nodes_to_write = vec![
+            // Overwrite the middle row
+            write_value_index_range(
+                &matrix_id,
+                AttributeId::Value,
+                "1,0:2",
+                DataValue::value_only(Variant::from((
+                    vec![Variant::Int32(30), Variant::Int32(40), Variant::Int32(50)],
+                    vec![1u32, 3u32],
+                ))),
+            ),
+            // Overwrite the first letter of the second string
+            write_value_index_range(
+                &strings_id,
+                AttributeId::Value,
+                "1,0",
+                DataValue::value_only(Variant::from(vec![Variant::from("w")])),
+            ),
+            // Value is the wrong size for the range
+            write_value_index_range(
+                &matrix_id,
+                AttributeId::Value,
+                "0:1,0",
+                DataValue::value_only(Variant::from((vec![Variant::Int32(1)], vec![1u32, 1u32]))),
+            ),
+            // Value does not have the dimensions of the variable
+            write_value(
+                &matrix_id,
+                AttributeId::Value,
+                DataValue::value_only(Variant::from(vec![Variant::Int32(1)])),
+            ),
+        ];
+        let response = write_request(
+            server_state,
+            session,
+            address_space.clone(),
+            ats,
+            nodes_to_write,
+        );
+        let results = response.results.unwrap();
+        assert_eq!(results[0], StatusCode::Good);
+        assert_eq!(results[1], StatusCode::Good);
+        assert_eq!(results[2], StatusCode::BadIndexRangeInvalid);
+        assert_eq!(results[3], StatusCode::BadTypeMismatch);
+
+        validate_variable_value(address_space.clone(), &matrix_id, |value| {
+            let expected: Vec<Variant> = [0, 1, 2, 30, 40, 50, 6, 7, 8]
+                .iter()
+                .map(|v| Variant::Int32(*v))
+                .collect();
+            assert_eq!(*value, Variant::from((expected, vec![3u32, 3u32])));
+        });
+        validate_variable_value(address_space, &strings_id, |value| {
+            assert_eq!(
+                *value,
+                Variant::from(vec![Variant::from("Hello"), Variant::from("world")])
+            );
+        });
+    });
+}
+
+#[test]
+fn read_localized_text_for_session_locales() {
+    // Test that display names, descriptions and localized text values are chosen by the session's
+    // locale ids, falling back on the server's locale ids and then the default text
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_id = NodeId::next_numeric(2);
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let _ = VariableBuilder::new(&node_id, var_name(0), LocalizedText::new("en", "Pump"))
+                .display_name_translation(LocalizedText::new("de", "Pumpe"))
+                .display_name_translation(LocalizedText::new("fr-FR", "Pompe"))
+                .description(LocalizedText::new("en", "A pump"))
+                .description_translation(LocalizedText::new("de", "Eine Pumpe"))
+                .data_type(DataTypeId::LocalizedText)
+                .value(LocalizedText::new("en", "Running"))
+                .value_translation(LocalizedText::new("de", "Läuft"))
+                .organized_by(ObjectId::RootFolder)
+                .insert(&mut address_space);
+        }
+
+        let read = |locale_ids: Option<Vec<UAString>>| {
+            {
+                let mut session = trace_write_lock_unwrap!(session);
+                session.set_locale_ids(locale_ids);
+            }
+            let request = ReadRequest {
+                request_header: make_request_header(),
+                max_age: 0f64,
+                timestamps_to_return: TimestampsToReturn::Neither,
+                nodes_to_read: Some(vec![
+                    read_value(&node_id, AttributeId::DisplayName),
+                    read_value(&node_id, AttributeId::Description),
+                    read_value(&node_id, AttributeId::Value),
+                ]),
+            };
+            let response = ats
+                .async_read(
+                    server_state.clone(),
+                    session.clone(),
+                    address_space.clone(),
+                    &request,
+                )
+                .wait()
+                .unwrap();
+            let response: ReadResponse = supported_message_as!(response, ReadResponse);
+            response
+                .results
+                .unwrap()
+                .into_iter()
+                .map(|r| match r.value {
+                    Some(Variant::LocalizedText(v)) => *v,
+                    _ => panic!(),
+                })
+                .collect::<Vec<LocalizedText>>()
+        };
+
+        // Exact match
+        let results = read(Some(vec!["de".into()]));
+        assert_eq!(results[0], LocalizedText::new("de", "Pumpe"));
+        assert_eq!(results[1], LocalizedText::new("de", "Eine Pumpe"));
+        assert_eq!(results[2], LocalizedText::new("de", "Läuft"));
+
+        // Match by language and in order of preference
+        let results = read(Some(vec!["es".into(), "fr-CA".into(), "de".into()]));
+        assert_eq!(results[0], LocalizedText::new("fr-FR", "Pompe"));
+        assert_eq!(results[1], LocalizedText::new("de", "Eine Pumpe"));
+
+        // No match so the default text
+        let results = read(Some(vec!["es".into()]));
+        assert_eq!(results[0], LocalizedText::new("en", "Pump"));
+        assert_eq!(results[2], LocalizedText::new("en", "Running"));
+
+        // The server's locale ids are used when the session has none
+        {
+            let mut session = trace_write_lock_unwrap!(session);
+            session.set_server_locale_ids(vec!["de".into()]);
+        }
+        let results = read(None);
+        assert_eq!(results[0], LocalizedText::new("de", "Pumpe"));
+        assert_eq!(results[2], LocalizedText::new("de", "Läuft"));
+
+        // Translations of the old value are discarded when the value changes
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let variable = address_space.find_variable_mut(&node_id).unwrap();
+            let _ = variable.set_value(NumericRange::None, LocalizedText::new("en", "Stopped"));
+        }
+        let results = read(None);
+        assert_eq!(results[2], LocalizedText::new("en", "Stopped"));
+    });
+}
+
+struct LocalizedValueGetter;
+
+impl AsyncAttributeGetter for LocalizedValueGetter {
+    fn get(
+        &mut self,
+        _node_id: &NodeId,
+        _timestamps_to_return: TimestampsToReturn,
+        _attribute_id: AttributeId,
+        _index_range: NumericRange,
+        _data_encoding: &QualifiedName,
+        _max_age: f64,
+    ) -> AsyncResult<Option<DataValue>> {
+        let value = LocalizedText::new("en", "Running");
+        Box::new(future::ok(Some(DataValue::new_now(value))))
+    }
+}
+
+#[test]
+fn read_localized_text_from_async_value_getter() {
+    // Test that a localized text value from an asynchronous getter is chosen by the session's
+    // locale ids, the same as a value read immediately
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_id = NodeId::next_numeric(2);
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let _ = VariableBuilder::new(&node_id, var_name(0), "Status")
+                .data_type(DataTypeId::LocalizedText)
+                .value(LocalizedText::new("en", "Running"))
+                .value_translation(LocalizedText::new("de", "Läuft"))
+                .organized_by(ObjectId::RootFolder)
+                .insert(&mut address_space);
+            let v = address_space.find_variable_mut(&node_id).unwrap();
+            v.set_async_value_getter(Arc::new(Mutex::new(LocalizedValueGetter)));
+        }
+        {
+            let mut session = trace_write_lock_unwrap!(session);
+            session.set_locale_ids(Some(vec!["de".into()]));
+        }
+
+        let request = ReadRequest {
+            request_header: make_request_header(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Neither,
+            nodes_to_read: Some(vec![read_value(&node_id, AttributeId::Value)]),
+        };
+        let response = ats.async_read(server_state, session, address_space, &request);
+        let response: ReadResponse = supported_message_as!(response.wait().unwrap(), ReadResponse);
+        let results = response.results.unwrap();
+        assert_eq!(
+            results[0].value.as_ref().unwrap(),
+            &Variant::from(LocalizedText::new("de", "Läuft"))
+        );
+    });
+}
+
 // #[test] fn write_null_value() { /* Write an empty variant to a value and see that it is allowed */}
 
 struct DataProvider;
@@ -631,7 +1260,9 @@ fn history_read_nothing_to_do_1() {
             nodes_to_read: None,
         };
         let response: ServiceFault = supported_message_as!(
-            ats.history_read(server_state, session, address_space.clone(), &request),
+            ats.async_history_read(server_state, session, address_space.clone(), &request)
+                .wait()
+                .unwrap(),
             ServiceFault
         );
         assert_eq!(
@@ -653,7 +1284,9 @@ fn history_read_nothing_history_operation_invalid() {
             nodes_to_read: Some(nodes_to_read()),
         };
         let response: ServiceFault = supported_message_as!(
-            ats.history_read(server_state, session, address_space, &request),
+            ats.async_history_read(server_state, session, address_space, &request)
+                .wait()
+                .unwrap(),
             ServiceFault
         );
         assert_eq!(
@@ -686,11 +1319,97 @@ fn history_read_nothing_data_provider() {
             nodes_to_read: Some(nodes_to_read()),
         };
         let response: HistoryReadResponse = supported_message_as!(
-            ats.history_read(server_state, session, address_space, &request),
+            ats.async_history_read(server_state, session, address_space, &request)
+                .wait()
+                .unwrap(),
+            HistoryReadResponse
+        );
+        let expected_read_result = DataProvider::historical_read_result();
+        assert_eq!(response.results, Some(expected_read_result));
+    });
+}
+
+struct AsyncDataProvider;
+
+impl AsyncHistoricalDataProvider for AsyncDataProvider {
+    fn read_raw_modified_details(
+        &self,
+        _address_space: Arc<RwLock<AddressSpace>>,
+        _request: ReadRawModifiedDetails,
+        _timestamps_to_return: TimestampsToReturn,
+        _release_continuation_points: bool,
+        _nodes_to_read: &[HistoryReadValueId],
+    ) -> AsyncResult<Vec<HistoryReadResult>> {
+        info!("AsyncDataProvider's read_raw_modified_details");
+        Box::new(future::ok(DataProvider::historical_read_result()))
+    }
+}
+
+#[test]
+fn history_read_async_data_provider() {
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        {
+            let mut server_state = server_state.write().unwrap();
+            server_state.set_async_historical_data_provider(Box::new(AsyncDataProvider));
+        }
+
+        let read_raw_modified_details = read_raw_modified_details();
+        let history_read_details = ExtensionObject::from_encodable(
+            ObjectId::ReadRawModifiedDetails_Encoding_DefaultBinary,
+            &read_raw_modified_details,
+        );
+        let request = HistoryReadRequest {
+            request_header: make_request_header(),
+            history_read_details,
+            timestamps_to_return: TimestampsToReturn::Both,
+            release_continuation_points: true,
+            nodes_to_read: Some(nodes_to_read()),
+        };
+        let response: HistoryReadResponse = supported_message_as!(
+            ats.async_history_read(
+                server_state.clone(),
+                session.clone(),
+                address_space.clone(),
+                &request
+            )
+            .wait()
+            .unwrap(),
             HistoryReadResponse
         );
         let expected_read_result = DataProvider::historical_read_result();
         assert_eq!(response.results, Some(expected_read_result));
+
+        // Processed details are not implemented by the provider
+        let read_processed_details = ReadProcessedDetails {
+            start_time: DateTime::now(),
+            end_time: DateTime::now(),
+            processing_interval: 0f64,
+            aggregate_type: None,
+            aggregate_configuration: AggregateConfiguration {
+                use_server_capabilities_defaults: true,
+                treat_uncertain_as_bad: false,
+                percent_data_bad: 0,
+                percent_data_good: 0,
+                use_sloped_extrapolation: false,
+            },
+        };
+        let request = HistoryReadRequest {
+            history_read_details: ExtensionObject::from_encodable(
+                ObjectId::ReadProcessedDetails_Encoding_DefaultBinary,
+                &read_processed_details,
+            ),
+            ..request
+        };
+        let response: ServiceFault = supported_message_as!(
+            ats.async_history_read(server_state, session, address_space, &request)
+                .wait()
+                .unwrap(),
+            ServiceFault
+        );
+        assert_eq!(
+            response.response_header.service_result,
+            StatusCode::BadHistoryOperationUnsupported
+        );
     });
 }
 
@@ -1,3 +1,5 @@
+use futures::{sync::oneshot, Future};
+
 use opcua_types::{
     node_ids::{MethodId, ObjectId},
     service_types::{CallMethodRequest, CallMethodResult, CallRequest, CallResponse},
@@ -95,15 +97,18 @@ fn call_single(
     address_space: Arc<RwLock<AddressSpace>>,
     request: CallMethodRequest,
 ) -> Result<CallMethodResult, StatusCode> {
-    let response = s.call(
-        server_state,
-        session,
-        address_space,
-        &CallRequest {
-            request_header: RequestHeader::dummy(),
-            methods_to_call: Some(vec![request]),
-        },
-    );
+    let response = s
+        .async_call(
+            server_state,
+            session,
+            address_space,
+            &CallRequest {
+                request_header: RequestHeader::dummy(),
+                methods_to_call: Some(vec![request]),
+            },
+        )
+        .wait()
+        .unwrap();
     let response: CallResponse = supported_message_as!(response, CallResponse);
     Ok(response.results.unwrap().remove(0))
 }
@@ -328,3 +333,165 @@ fn call_resend_data() {
         }
     });
 }
+
+#[test]
+fn call_set_subscription_durable() {
+    do_method_service_test(|server_state, session, address_space, s| {
+        let call = |args: Vec<Variant>| {
+            let request = new_call_method_request(
+                ObjectId::Server,
+                MethodId::Server_SetSubscriptionDurable,
+                Some(args),
+            );
+            call_single(
+                s,
+                server_state.clone(),
+                session.clone(),
+                address_space.clone(),
+                request,
+            )
+            .unwrap()
+        };
+
+        // Call with invalid subscription id
+        let response = call(vec![100u32.into(), 1u32.into()]);
+        assert_eq!(response.status_code, StatusCode::BadSubscriptionIdInvalid);
+
+        let ss = SubscriptionService::new();
+        let mis = MonitoredItemService::new();
+
+        let subscription_id = {
+            let request = create_subscription_request();
+            let response: CreateSubscriptionResponse = supported_message_as!(
+                ss.create_subscription(server_state.clone(), session.clone(), &request),
+                CreateSubscriptionResponse
+            );
+            response.subscription_id
+        };
+
+        // A lifetime beyond the maximum is revised down
+        let response = call(vec![subscription_id.into(), 1000u32.into()]);
+        assert_eq!(response.status_code, StatusCode::Good);
+        assert_eq!(
+            response.output_arguments,
+            Some(vec![Variant::UInt32(
+                crate::constants::DEFAULT_MAX_SUBSCRIPTION_LIFETIME_IN_HOURS
+            )])
+        );
+
+        // A lifetime of 0 is revised up
+        let response = call(vec![subscription_id.into(), 0u32.into()]);
+        assert_eq!(response.status_code, StatusCode::Good);
+        assert_eq!(response.output_arguments, Some(vec![Variant::UInt32(1)]));
+        {
+            let session = trace_read_lock_unwrap!(session);
+            let subscription = session
+                .subscriptions()
+                .subscriptions()
+                .get(&subscription_id)
+                .unwrap();
+            // An hour of 100ms publishing intervals
+            assert_eq!(subscription.max_lifetime_count(), 36000);
+        }
+
+        // The subscription cannot be made durable once it has monitored items
+        let _ = mis.create_monitored_items(
+            server_state.clone(),
+            session.clone(),
+            address_space.clone(),
+            &create_monitored_items_request(
+                subscription_id,
+                1,
+                VariableId::Server_ServerStatus_CurrentTime,
+            ),
+        );
+        let response = call(vec![subscription_id.into(), 1u32.into()]);
+        assert_eq!(response.status_code, StatusCode::BadInvalidState);
+
+        // Servers that do not support durable subscriptions say so
+        {
+            let mut session = trace_write_lock_unwrap!(session);
+            session.set_max_subscription_lifetime_in_hours(0);
+        }
+        let response = call(vec![subscription_id.into(), 1u32.into()]);
+        assert_eq!(response.status_code, StatusCode::BadNotSupported);
+    });
+}
+
+/// Returns the value sent on a channel as the output of the call
+struct AsyncEcho {
+    receiver: Option<oneshot::Receiver<String>>,
+}
+
+impl AsyncMethod for AsyncEcho {
+    fn call(
+        &mut self,
+        _session: &mut Session,
+        _request: &CallMethodRequest,
+    ) -> AsyncResult<CallMethodResult> {
+        let receiver = self.receiver.take().unwrap();
+        Box::new(
+            receiver
+                .map(|value| CallMethodResult {
+                    status_code: StatusCode::Good,
+                    input_argument_results: None,
+                    input_argument_diagnostic_infos: None,
+                    output_arguments: Some(vec![Variant::from(value)]),
+                })
+                .map_err(|_| StatusCode::BadCommunicationError),
+        )
+    }
+}
+
+#[test]
+fn call_async_method() {
+    do_method_service_test(|server_state, session, address_space, s| {
+        let method_id = NodeId::new(1, "AsyncEcho");
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let inserted = MethodBuilder::new(&method_id, "AsyncEcho", "AsyncEcho")
+                .component_of(ObjectId::ObjectsFolder)
+                .async_callback(Box::new(AsyncEcho {
+                    receiver: Some(receiver),
+                }))
+                .insert(&mut address_space);
+            assert!(inserted);
+        }
+
+        let request = CallRequest {
+            request_header: RequestHeader::dummy(),
+            methods_to_call: Some(vec![new_call_method_request(
+                ObjectId::ObjectsFolder,
+                method_id.clone(),
+                None,
+            )]),
+        };
+        // The method cannot be called synchronously since that would wait for the result
+        {
+            let server_state = trace_read_lock_unwrap!(server_state);
+            let mut session = trace_write_lock_unwrap!(session);
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let result = address_space.call_method(
+                &server_state,
+                &mut session,
+                &request.methods_to_call.as_ref().unwrap()[0],
+            );
+            assert_eq!(result.unwrap_err(), StatusCode::BadNotSupported);
+        }
+
+        let response = s.async_call(server_state, session, address_space.clone(), &request);
+
+        // The address space is not locked while the method runs
+        {
+            let address_space = trace_write_lock_unwrap!(address_space);
+            assert!(address_space.find_node(&method_id).is_some());
+        }
+        let _ = sender.send("Hello".to_string());
+
+        let response: CallResponse = supported_message_as!(response.wait().unwrap(), CallResponse);
+        let result = response.results.unwrap().remove(0);
+        assert_eq!(result.status_code, StatusCode::Good);
+        assert_eq!(result.output_arguments, Some(vec![Variant::from("Hello")]));
+    });
+}
@@ -162,6 +162,7 @@ pub mod discovery;
 pub mod method;
 pub mod monitored_item;
 pub mod node_management;
+pub mod node_manager;
 pub mod session;
 pub mod subscription;
 pub mod view;
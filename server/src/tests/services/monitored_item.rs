@@ -1,10 +1,14 @@
 use std::collections::HashSet;
 use std::ops::Add;
+use std::sync::Mutex;
 
 use chrono::{self, Utc};
+use futures::future;
+use tokio::runtime::current_thread;
 
 use super::*;
 use crate::{
+    address_space::value_changes::{ChangedItems, ValueChangeListener},
     prelude::*,
     services::{monitored_item::MonitoredItemService, subscription::SubscriptionService},
     subscriptions::{
@@ -508,6 +512,65 @@ fn monitored_item_data_change_filter() {
     assert_eq!(monitored_item.notification_queue().len(), 2);
 }
 
+#[test]
+fn monitored_item_value_change_listener() {
+    let mut address_space = make_address_space();
+
+    let mut monitored_item = MonitoredItem::new(
+        &chrono::Utc::now(),
+        1,
+        TimestampsToReturn::Both,
+        &make_create_request_data_change_filter(-1f64, 5),
+    )
+    .unwrap();
+
+    let now = Utc::now();
+
+    // The first sample starts listening for changes to the variable's value
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    assert!(!monitored_item.needs_sample());
+    assert!(monitored_item.next_sample_time(1000f64).is_none());
+
+    // Setting the value tells the item to sample it
+    let value_time = DateTime::now();
+    assert!(address_space.set_variable_value(test_var_node_id(), 2u32, &value_time, &value_time));
+    assert!(monitored_item.needs_sample());
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    assert!(!monitored_item.needs_sample());
+
+    // Values from a getter are sampled at the item's interval instead
+    address_space
+        .find_variable_mut(test_var_node_id())
+        .unwrap()
+        .set_value_getter(AttrFnGetter::new_boxed(
+            |_, _, _, _, _, _| -> Result<Option<DataValue>, StatusCode> {
+                Ok(Some(DataValue::new_now(3u32)))
+            },
+        ));
+    assert!(monitored_item.needs_sample());
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    assert!(monitored_item.needs_sample());
+    assert!(monitored_item.next_sample_time(1000f64).is_some());
+    assert_eq!(monitored_item.notification_queue().len(), 3);
+
+    // The item no longer listens, and its dead listener is pruned when another is added even
+    // though the value of its variable never changes again
+    let mut value_changes = trace_lock_unwrap!(address_space.value_changes());
+    assert_eq!(value_changes.listener_count(), 1);
+    let listener = Arc::new(ValueChangeListener::new(2, ChangedItems::default()));
+    value_changes.add_listener(&NodeId::new(1, 2), &listener);
+    assert_eq!(value_changes.listener_count(), 1);
+}
+
 #[test]
 fn monitored_item_event_filter() {
     // create an address space
@@ -989,3 +1052,130 @@ fn monitored_item_queue_discard_oldest() {
         assert_first_notification_is_i32(&mut monitored_item, 10);
     }
 }
+
+/// Supplies a value that increases each time it is asked for
+struct CountingValueGetter {
+    count: u32,
+}
+
+impl AsyncAttributeGetter for CountingValueGetter {
+    fn get(
+        &mut self,
+        _node_id: &NodeId,
+        _timestamps_to_return: TimestampsToReturn,
+        _attribute_id: AttributeId,
+        _index_range: NumericRange,
+        _data_encoding: &QualifiedName,
+        _max_age: f64,
+    ) -> AsyncResult<Option<DataValue>> {
+        self.count += 1;
+        Box::new(future::ok(Some(DataValue::new_now(self.count))))
+    }
+}
+
+#[test]
+fn monitored_item_async_value_getter() {
+    // Test that sampling a variable with an asynchronous getter does not wait for the getter and
+    // that the value it supplies is reported when the item is next sampled
+    let mut address_space = make_address_space();
+    address_space
+        .find_variable_mut(test_var_node_id())
+        .unwrap()
+        .set_async_value_getter(Arc::new(Mutex::new(CountingValueGetter { count: 0 })));
+
+    let mut monitored_item = MonitoredItem::new(
+        &chrono::Utc::now(),
+        1,
+        TimestampsToReturn::Both,
+        &make_create_request_data_change_filter(-1f64, 5),
+    )
+    .unwrap();
+
+    let now = Utc::now();
+    let mut runtime = current_thread::Runtime::new().unwrap();
+    let mut sample = |monitored_item: &mut MonitoredItem| {
+        runtime
+            .block_on(future::lazy(|| -> Result<(), ()> {
+                let _ = monitored_item.tick(&now, &address_space, true, false);
+                Ok(())
+            }))
+            .unwrap();
+        // Let the getter complete
+        runtime.run().unwrap();
+    };
+
+    // The first sample only starts the getter
+    sample(&mut monitored_item);
+    assert_eq!(monitored_item.notification_queue().len(), 0);
+
+    // Each later sample reports the value of the one before
+    sample(&mut monitored_item);
+    sample(&mut monitored_item);
+    let values = monitored_item
+        .all_notifications()
+        .unwrap()
+        .into_iter()
+        .map(|notification| match notification {
+            Notification::MonitoredItemNotification(notification) => notification.value.value,
+            _ => panic!(),
+        })
+        .collect::<Vec<Option<Variant>>>();
+    assert_eq!(
+        values,
+        vec![Some(Variant::UInt32(1)), Some(Variant::UInt32(2))]
+    );
+
+    // A synchronous read of the value does not wait for the getter
+    let value = address_space
+        .get_variable_value(test_var_node_id())
+        .unwrap();
+    assert_eq!(value.status, Some(StatusCode::BadWouldBlock));
+}
+
+#[test]
+fn monitored_item_index_range() {
+    // Test that the index range is checked when the item is created and that each sample of the
+    // value only holds the elements in the range
+    let mut address_space = make_address_space();
+    let node_id = NodeId::new(1, "Array");
+    VariableBuilder::new(&node_id, "Array", "Array")
+        .data_type(DataTypeId::Int32)
+        .value_rank(1)
+        .value(vec![
+            Variant::Int32(1),
+            Variant::Int32(2),
+            Variant::Int32(3),
+        ])
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+
+    let mut request = make_create_request(
+        -1f64,
+        5,
+        node_id,
+        AttributeId::Value,
+        ExtensionObject::null(),
+    );
+    request.item_to_monitor.index_range = UAString::from("abc");
+    assert_eq!(
+        MonitoredItem::new(&chrono::Utc::now(), 1, TimestampsToReturn::Both, &request).unwrap_err(),
+        StatusCode::BadIndexRangeInvalid
+    );
+
+    request.item_to_monitor.index_range = UAString::from("1:2");
+    let mut monitored_item =
+        MonitoredItem::new(&chrono::Utc::now(), 1, TimestampsToReturn::Both, &request).unwrap();
+    assert_eq!(
+        monitored_item.tick(&Utc::now(), &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    let notification = monitored_item.oldest_notification_message().unwrap();
+    if let Notification::MonitoredItemNotification(notification) = notification {
+        assert_eq!(
+            notification.value.value.unwrap(),
+            Variant::from(vec![Variant::Int32(2), Variant::Int32(3)])
+        );
+    } else {
+        panic!();
+    }
+}
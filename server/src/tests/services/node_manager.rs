@@ -0,0 +1,288 @@
+use std::sync::Mutex;
+
+use chrono::Utc;
+use futures::Future;
+
+use crate::{
+    node_manager::NodeManager,
+    services::{attribute::AttributeService, view::ViewService},
+    subscriptions::monitored_item::{MonitoredItem, TickResult},
+};
+
+use super::*;
+
+// A node manager that serves a folder of tags whose value is held outside of the address space
+
+const TAG_COUNT: i32 = 5;
+
+struct TagManager {
+    value: Arc<Mutex<i32>>,
+}
+
+impl TagManager {
+    // The manager is only asked about nodes in its own namespace so it only looks at identifiers
+
+    fn is_folder(node_id: &NodeId) -> bool {
+        node_id.identifier == Identifier::String(UAString::from("tags"))
+    }
+
+    fn tag_idx(node_id: &NodeId) -> Option<i32> {
+        match node_id.identifier {
+            Identifier::String(ref s) => s
+                .as_ref()
+                .trim_start_matches("tag")
+                .parse::<i32>()
+                .ok()
+                .filter(|idx| *idx >= 0 && *idx < TAG_COUNT),
+            _ => None,
+        }
+    }
+}
+
+impl NodeManager for TagManager {
+    fn node_exists(&mut self, node_id: &NodeId) -> bool {
+        Self::is_folder(node_id) || Self::tag_idx(node_id).is_some()
+    }
+
+    fn browse(
+        &mut self,
+        _session: &Session,
+        node_to_browse: &BrowseDescription,
+    ) -> Result<Vec<ReferenceDescription>, StatusCode> {
+        if !Self::is_folder(&node_to_browse.node_id) {
+            return Ok(Vec::new());
+        }
+        let namespace = node_to_browse.node_id.namespace;
+        Ok((0..TAG_COUNT)
+            .map(|idx| {
+                let name = format!("tag{}", idx);
+                ReferenceDescription {
+                    reference_type_id: ReferenceTypeId::Organizes.into(),
+                    is_forward: true,
+                    node_id: NodeId::new(namespace, name.clone()).into(),
+                    browse_name: QualifiedName::new(namespace, name.as_ref()),
+                    display_name: LocalizedText::new("", &name),
+                    node_class: NodeClass::Variable,
+                    type_definition: VariableTypeId::BaseDataVariableType.into(),
+                }
+            })
+            .collect())
+    }
+
+    fn read(
+        &mut self,
+        _session: &Session,
+        node_to_read: &ReadValueId,
+        _max_age: f64,
+        _timestamps_to_return: TimestampsToReturn,
+    ) -> DataValue {
+        if let Some(idx) = Self::tag_idx(&node_to_read.node_id) {
+            if node_to_read.attribute_id == AttributeId::Value as u32 {
+                DataValue::new_now(*self.value.lock().unwrap() + idx)
+            } else {
+                DataValue::from(StatusCode::BadAttributeIdInvalid)
+            }
+        } else {
+            DataValue::from(StatusCode::BadNodeIdUnknown)
+        }
+    }
+
+    fn sample(&mut self, item_to_monitor: &ReadValueId) -> Option<DataValue> {
+        Self::tag_idx(&item_to_monitor.node_id)
+            .map(|idx| DataValue::new_now(*self.value.lock().unwrap() + idx))
+    }
+}
+
+fn register_tag_manager(address_space: Arc<RwLock<AddressSpace>>) -> (u16, Arc<Mutex<i32>>) {
+    let value = Arc::new(Mutex::new(100));
+    let mut address_space = trace_write_lock_unwrap!(address_space);
+    let ns = address_space
+        .register_node_manager(
+            "urn:tags",
+            TagManager {
+                value: value.clone(),
+            },
+        )
+        .unwrap();
+    (ns, value)
+}
+
+#[test]
+fn node_manager_registration() {
+    let st = ServiceTest::new();
+    let default_namespace = trace_read_lock_unwrap!(st.address_space).default_namespace();
+    let (ns, _) = register_tag_manager(st.address_space.clone());
+    let address_space = trace_read_lock_unwrap!(st.address_space);
+    // Registering a manager does not change the default namespace
+    assert_eq!(address_space.default_namespace(), default_namespace);
+    assert!(address_space
+        .node_manager(&NodeId::new(ns, "tag1"))
+        .is_some());
+    assert!(address_space.node_manager(&NodeId::new(0, 1)).is_none());
+    assert!(address_space.node_exists_or_managed(&NodeId::new(ns, "tag1")));
+    assert!(!address_space.node_exists_or_managed(&NodeId::new(ns, "tag99")));
+    drop(address_space);
+
+    // A namespace can only have one manager
+    let mut address_space = trace_write_lock_unwrap!(st.address_space);
+    assert!(address_space
+        .register_node_manager(
+            "urn:tags",
+            TagManager {
+                value: Arc::new(Mutex::new(0)),
+            },
+        )
+        .is_err());
+}
+
+#[test]
+fn node_manager_read() {
+    let st = ServiceTest::new();
+    let (ns, value) = register_tag_manager(st.address_space.clone());
+    *value.lock().unwrap() = 10;
+
+    let read_value = |node_id: NodeId, attribute_id: AttributeId| ReadValueId {
+        node_id,
+        attribute_id: attribute_id as u32,
+        index_range: UAString::null(),
+        data_encoding: QualifiedName::null(),
+    };
+    let request = ReadRequest {
+        request_header: make_request_header(),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Both,
+        nodes_to_read: Some(vec![
+            read_value(NodeId::new(ns, "tag2"), AttributeId::Value),
+            read_value(NodeId::new(ns, "tag2"), AttributeId::IsAbstract),
+            read_value(NodeId::new(ns, "tag99"), AttributeId::Value),
+            // A node in the address space is unaffected by the manager
+            read_value(
+                VariableId::Server_ServerStatus_State.into(),
+                AttributeId::Value,
+            ),
+        ]),
+    };
+
+    let ats = AttributeService::new();
+    let response = ats
+        .async_read(
+            st.server_state.clone(),
+            st.session.clone(),
+            st.address_space.clone(),
+            &request,
+        )
+        .wait()
+        .unwrap();
+    let response: ReadResponse = supported_message_as!(response, ReadResponse);
+    let results = response.results.unwrap();
+    assert_eq!(results[0].value.as_ref().unwrap(), &Variant::Int32(12));
+    assert_eq!(
+        results[1].status.as_ref().unwrap(),
+        &StatusCode::BadAttributeIdInvalid
+    );
+    assert_eq!(
+        results[2].status.as_ref().unwrap(),
+        &StatusCode::BadNodeIdUnknown
+    );
+    assert!(results[3].value.is_some());
+}
+
+#[test]
+fn node_manager_browse() {
+    let st = ServiceTest::new();
+    let (ns, _) = register_tag_manager(st.address_space.clone());
+
+    let browse_description = |node_id: NodeId, node_class_mask: NodeClassMask| BrowseDescription {
+        node_id,
+        browse_direction: BrowseDirection::Forward,
+        reference_type_id: ReferenceTypeId::Organizes.into(),
+        include_subtypes: true,
+        node_class_mask: node_class_mask.bits(),
+        result_mask: BrowseDescriptionResultMask::RESULT_MASK_BROWSE_NAME.bits() as u32,
+    };
+    let request = BrowseRequest {
+        request_header: make_request_header(),
+        view: ViewDescription {
+            view_id: NodeId::null(),
+            timestamp: DateTime::null(),
+            view_version: 0,
+        },
+        requested_max_references_per_node: 1000,
+        nodes_to_browse: Some(vec![
+            browse_description(NodeId::new(ns, "tags"), NodeClassMask::empty()),
+            browse_description(NodeId::new(ns, "tags"), NodeClassMask::OBJECT),
+            browse_description(NodeId::new(ns, "nothing"), NodeClassMask::empty()),
+        ]),
+    };
+
+    let vs = ViewService::new();
+    let response = vs.browse(
+        st.server_state.clone(),
+        st.session.clone(),
+        st.address_space.clone(),
+        &request,
+    );
+    let response: BrowseResponse = supported_message_as!(response, BrowseResponse);
+    let results = response.results.unwrap();
+
+    // All the tags are returned with only the browse name populated
+    let references = results[0].references.as_ref().unwrap();
+    assert_eq!(references.len(), TAG_COUNT as usize);
+    references.iter().enumerate().for_each(|(idx, r)| {
+        assert_eq!(r.node_id.node_id, NodeId::new(ns, format!("tag{}", idx)));
+        assert_eq!(r.browse_name.name.as_ref(), format!("tag{}", idx));
+        assert!(r.display_name.text.is_null());
+    });
+
+    // Tags are variables so the node class mask filters them out
+    assert!(results[1].references.as_ref().unwrap().is_empty());
+
+    // Node the manager does not know
+    assert_eq!(results[2].status_code, StatusCode::BadNodeIdUnknown);
+}
+
+#[test]
+fn node_manager_monitored_item() {
+    let st = ServiceTest::new();
+    let (ns, value) = register_tag_manager(st.address_space.clone());
+
+    let request = MonitoredItemCreateRequest {
+        item_to_monitor: ReadValueId {
+            node_id: NodeId::new(ns, "tag1"),
+            attribute_id: AttributeId::Value as u32,
+            index_range: UAString::null(),
+            data_encoding: QualifiedName::null(),
+        },
+        monitoring_mode: MonitoringMode::Reporting,
+        requested_parameters: MonitoringParameters {
+            client_handle: 999,
+            sampling_interval: -1f64,
+            filter: ExtensionObject::null(),
+            queue_size: 5,
+            discard_oldest: true,
+        },
+    };
+    let mut monitored_item =
+        MonitoredItem::new(&Utc::now(), 1, TimestampsToReturn::Both, &request).unwrap();
+
+    let address_space = trace_read_lock_unwrap!(st.address_space);
+    let now = Utc::now();
+
+    // The value is sampled from the manager
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::NoChange
+    );
+
+    // Change the value the manager serves
+    *value.lock().unwrap() = 200;
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    assert_eq!(monitored_item.notification_queue().len(), 2);
+}
@@ -1,14 +1,26 @@
+use std::sync::Mutex;
+
+use futures::{future, sync::oneshot, Async, Future, Stream};
+use tokio::runtime::current_thread;
+
 use opcua_crypto::{random, user_identity::make_user_name_identity_token, SecurityPolicy};
-use opcua_types::{ActivateSessionRequest, RequestHeader, SignatureData};
+use opcua_types::{
+    ActivateSessionRequest, CancelRequest, CancelResponse, RequestHeader, SignatureData,
+};
 
 use crate::{
     builder::ServerBuilder,
+    callbacks::{AsyncAttributeGetter, AsyncResult},
+    comms::tcp_transport::MessageSender,
     identity_token::{
         POLICY_ID_USER_PASS_NONE, POLICY_ID_USER_PASS_RSA_15, POLICY_ID_USER_PASS_RSA_OAEP,
     },
+    services::{message_handler::MessageHandler, session::SessionService},
     tests::*,
 };
 
+use super::ServiceTest;
+
 fn dummy_activate_session_request() -> ActivateSessionRequest {
     ActivateSessionRequest {
         request_header: RequestHeader::dummy(),
@@ -301,3 +313,161 @@ fn user_name_pass_token() {
     );
     assert_eq!(result.unwrap_err(), StatusCode::BadUserAccessDenied);
 }
+
+#[test]
+fn cancel_outstanding_requests() {
+    let st = ServiceTest::new();
+    let (server_state, session) = st.get_server_state_and_session();
+
+    // Two requests share the request handle being cancelled, the third does not
+    let (mut cancelled_1, mut cancelled_2, mut cancelled_3) = {
+        let mut session = trace_write_lock_unwrap!(session);
+        (
+            session.add_outstanding_request(1, 100),
+            session.add_outstanding_request(2, 100),
+            session.add_outstanding_request(3, 101),
+        )
+    };
+
+    let request = CancelRequest {
+        request_header: RequestHeader::dummy(),
+        request_handle: 100,
+    };
+    let response = SessionService::new().cancel(
+        server_state,
+        session.clone(),
+        st.address_space.clone(),
+        &request,
+    );
+    let response: CancelResponse = supported_message_as!(response, CancelResponse);
+    assert_eq!(response.cancel_count, 2);
+
+    assert_eq!(cancelled_1.poll(), Ok(Async::Ready(())));
+    assert_eq!(cancelled_2.poll(), Ok(Async::Ready(())));
+    assert_eq!(cancelled_3.poll(), Ok(Async::NotReady));
+
+    // Nothing is outstanding for the handle any more
+    {
+        let mut session = trace_write_lock_unwrap!(session);
+        session.remove_outstanding_request(1);
+        session.remove_outstanding_request(2);
+        assert_eq!(session.cancel_requests(100), 0);
+    }
+
+    // The cancel is audited
+    let address_space = trace_read_lock_unwrap!(st.address_space);
+    let event_type_id = NodeId::from(ObjectTypeId::AuditCancelEventType);
+    let events = address_space
+        .events()
+        .iter()
+        .filter(|event| *event.event_type_id() == event_type_id)
+        .count();
+    assert_eq!(events, 1);
+}
+
+/// Supplies no value until the sender of the channel sends one
+struct PendingValueGetter {
+    receiver: Option<oneshot::Receiver<i32>>,
+}
+
+impl AsyncAttributeGetter for PendingValueGetter {
+    fn get(
+        &mut self,
+        _node_id: &NodeId,
+        _timestamps_to_return: TimestampsToReturn,
+        _attribute_id: AttributeId,
+        _index_range: NumericRange,
+        _data_encoding: &QualifiedName,
+        _max_age: f64,
+    ) -> AsyncResult<Option<DataValue>> {
+        let receiver = self.receiver.take().unwrap();
+        Box::new(
+            receiver
+                .map(|value| Some(DataValue::new_now(value)))
+                .map_err(|_| StatusCode::BadCommunicationError),
+        )
+    }
+}
+
+#[test]
+fn cancel_request_in_progress() {
+    // Test that a Read still waiting on an asynchronous getter is answered with a fault when the
+    // client cancels it
+    let st = ServiceTest::new();
+    let node_id = NodeId::next_numeric(2);
+    // The value is never sent but the sender is held so the getter does not fail
+    let (_value_sender, receiver) = oneshot::channel();
+    {
+        let mut address_space = trace_write_lock_unwrap!(st.address_space);
+        let _ = VariableBuilder::new(&node_id, "Pending", "")
+            .data_type(DataTypeId::Int32)
+            .value(0i32)
+            .organized_by(ObjectId::RootFolder)
+            .insert(&mut address_space);
+        let v = address_space.find_variable_mut(&node_id).unwrap();
+        v.set_async_value_getter(Arc::new(Mutex::new(PendingValueGetter {
+            receiver: Some(receiver),
+        })));
+    }
+    {
+        let mut session = trace_write_lock_unwrap!(st.session);
+        session.set_activated(true);
+        session.set_session_timeout(60000f64);
+    }
+
+    let mut message_handler = MessageHandler::new(
+        st.server.certificate_store(),
+        st.server_state.clone(),
+        st.session.clone(),
+        st.address_space.clone(),
+    );
+    let (sender, messages) = MessageSender::new_test();
+
+    let read: SupportedMessage = ReadRequest {
+        request_header: RequestHeader::new(&NodeId::null(), &DateTime::now(), 100),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Neither,
+        nodes_to_read: Some(vec![ReadValueId {
+            node_id: node_id.clone(),
+            attribute_id: AttributeId::Value as u32,
+            index_range: UAString::null(),
+            data_encoding: QualifiedName::null(),
+        }]),
+    }
+    .into();
+    let cancel: SupportedMessage = CancelRequest {
+        request_header: RequestHeader::new(&NodeId::null(), &DateTime::now(), 101),
+        request_handle: 100,
+    }
+    .into();
+
+    let mut runtime = current_thread::Runtime::new().unwrap();
+    runtime
+        .block_on(future::lazy(|| {
+            message_handler.handle_message(1, &read, &sender)?;
+            message_handler.handle_message(2, &cancel, &sender)
+        }))
+        .unwrap();
+    // Let the cancelled read answer
+    runtime.run().unwrap();
+    drop(sender);
+
+    let mut messages = messages.collect().wait().unwrap();
+    assert_eq!(messages.len(), 2);
+    messages.sort_by_key(|(request_id, _)| *request_id);
+    let mut messages = messages.into_iter().map(|(_, message)| message);
+
+    let response = supported_message_as!(messages.next().unwrap(), ServiceFault);
+    assert_eq!(response.response_header.request_handle, 100);
+    assert_eq!(
+        response.response_header.service_result,
+        StatusCode::BadRequestCancelledByClient
+    );
+
+    let response = supported_message_as!(messages.next().unwrap(), CancelResponse);
+    assert_eq!(response.cancel_count, 1);
+
+    // Nothing is outstanding once the read is answered
+    let mut session = trace_write_lock_unwrap!(st.session);
+    assert_eq!(session.cancel_requests(100), 0);
+}
@@ -4,7 +4,10 @@ use chrono::Utc;
 
 use crate::{
     prelude::*,
-    services::{monitored_item::MonitoredItemService, subscription::SubscriptionService},
+    services::{
+        monitored_item::MonitoredItemService, session::SessionService,
+        subscription::SubscriptionService,
+    },
     state::ServerState,
     subscriptions::subscription::*,
 };
@@ -376,6 +379,120 @@ fn multiple_publish_response_subscription() {
     })
 }
 
+/// Creates a subscription monitoring three variables, configured so that a notification message
+/// can only hold one notification, and publishes until every notification has been returned. Each
+/// response except the last should report more notifications and each part must be republishable.
+fn publish_split_notifications<F>(configure: F)
+where
+    F: FnOnce(&mut Session, u32),
+{
+    do_subscription_service_test(|server_state, session, address_space, ss, mis| {
+        let subscription_id = create_subscription(server_state.clone(), session.clone(), &ss);
+
+        let request = create_monitored_items_request(
+            subscription_id,
+            vec![
+                VariableId::Server_ServerStatus_StartTime,
+                VariableId::Server_ServerStatus_CurrentTime,
+                VariableId::Server_ServerStatus_State,
+            ],
+        );
+        let _ = mis.create_monitored_items(
+            server_state,
+            session.clone(),
+            address_space.clone(),
+            &request,
+        );
+
+        {
+            let mut session = trace_write_lock_unwrap!(session);
+            configure(&mut session, subscription_id);
+            session
+                .subscriptions_mut()
+                .get_mut(subscription_id)
+                .unwrap()
+                .set_state(SubscriptionState::Normal);
+        }
+
+        let now = Utc::now();
+        let mut client_handles = Vec::new();
+        for i in 0..3u32 {
+            let request_id = 1001 + i;
+            let request = publish_request(None);
+            let _ = ss.async_publish(
+                &now,
+                session.clone(),
+                address_space.clone(),
+                request_id,
+                &request,
+            );
+
+            let mut session = trace_write_lock_unwrap!(session);
+            if i == 0 {
+                // The notifications are made when the publishing interval elapses
+                let now = now.add(chrono::Duration::seconds(2));
+                let address_space = trace_read_lock_unwrap!(address_space);
+                let _ =
+                    session.tick_subscriptions(&now, &address_space, TickReason::TickTimerFired);
+            }
+
+            let response = session
+                .subscriptions_mut()
+                .publish_response_queue()
+                .pop_back()
+                .unwrap()
+                .response;
+            let response: PublishResponse = supported_message_as!(response, PublishResponse);
+            assert_eq!(response.more_notifications, i < 2);
+            assert_eq!(response.notification_message.sequence_number, i + 1);
+
+            let (notifications, events) = response
+                .notification_message
+                .notifications(&DecodingLimits::default())
+                .unwrap();
+            assert_eq!(notifications.len(), 1);
+            assert!(events.is_empty());
+            let monitored_items = notifications[0].monitored_items.as_ref().unwrap();
+            assert_eq!(monitored_items.len(), 1);
+            client_handles.push(monitored_items[0].client_handle);
+        }
+        client_handles.sort();
+        assert_eq!(client_handles, vec![0, 1, 2]);
+
+        // Every part went into the retransmission queue
+        (1..=3).for_each(|sequence_number| {
+            let request = republish_request(subscription_id, sequence_number);
+            let response: RepublishResponse =
+                supported_message_as!(ss.republish(session.clone(), &request), RepublishResponse);
+            assert_eq!(
+                response.notification_message.sequence_number,
+                sequence_number
+            );
+        });
+    })
+}
+
+#[test]
+fn publish_split_by_max_notifications_per_publish() {
+    publish_split_notifications(|session, subscription_id| {
+        session
+            .subscriptions_mut()
+            .get_mut(subscription_id)
+            .unwrap()
+            .set_max_notifications_per_publish(1);
+    });
+}
+
+#[test]
+fn publish_split_by_max_response_message_size() {
+    publish_split_notifications(|session, _| {
+        // Leaves room for a single notification in each response
+        session.set_max_response_message_size(
+            (crate::constants::PUBLISH_RESPONSE_OVERHEAD + 1) as u32,
+        );
+    });
+}
+
 #[test]
 fn acknowledge_unknown_sequence_nr() {
     do_subscription_service_test(|server_state, session, address_space, ss, mis| {
@@ -456,3 +573,115 @@ fn republish() {
         );
     })
 }
+
+#[test]
+fn publish_status_change_on_shutdown() {
+    do_subscription_service_test(|server_state, session, address_space, ss, _| {
+        // Create subscription
+        let subscription_id = create_subscription(server_state.clone(), session.clone(), &ss);
+
+        let now = Utc::now();
+
+        // Put the subscription into normal state
+        {
+            let mut session = trace_write_lock_unwrap!(session);
+            session
+                .subscriptions_mut()
+                .get_mut(subscription_id)
+                .unwrap()
+                .set_state(SubscriptionState::Normal);
+        }
+
+        // Queue a publish request, then shutdown the subscriptions and tick
+        let request_id = 1001;
+        let request = publish_request(None);
+        let _ = ss.async_publish(
+            &now,
+            session.clone(),
+            address_space.clone(),
+            request_id,
+            &request,
+        );
+
+        let mut session = trace_write_lock_unwrap!(session);
+        let address_space = trace_read_lock_unwrap!(address_space);
+        session.subscriptions_mut().shutdown(&now);
+        let _ = session.tick_subscriptions(&now, &address_space, TickReason::TickTimerFired);
+
+        // The subscription is gone once its final notification is sent
+        assert!(session.subscriptions().is_empty());
+        assert_eq!(
+            session.subscriptions_mut().publish_response_queue().len(),
+            1
+        );
+
+        let response = session
+            .subscriptions_mut()
+            .publish_response_queue()
+            .pop_back()
+            .unwrap()
+            .response;
+        let response: PublishResponse = supported_message_as!(response, PublishResponse);
+        assert_eq!(response.subscription_id, subscription_id);
+
+        // Expect a status change notification of BadShutdown
+        let notification_data = response.notification_message.notification_data.unwrap();
+        assert_eq!(notification_data.len(), 1);
+        assert_eq!(
+            notification_data[0].object_id().unwrap(),
+            ObjectId::StatusChangeNotification_Encoding_DefaultBinary
+        );
+        let decoding_limits = DecodingLimits::default();
+        let status_change = notification_data[0]
+            .decode_inner::<StatusChangeNotification>(&decoding_limits)
+            .unwrap();
+        assert_eq!(status_change.status, StatusCode::BadShutdown);
+    })
+}
+
+#[test]
+fn cancel_publish_request() {
+    do_subscription_service_test(|server_state, session, address_space, ss, _| {
+        let _ = create_subscription(server_state.clone(), session.clone(), &ss);
+
+        // The publish request is queued until there is something to publish
+        let request_id = 1001;
+        let request = publish_request(None);
+        let response = ss.async_publish(
+            &Utc::now(),
+            session.clone(),
+            address_space.clone(),
+            request_id,
+            &request,
+        );
+        assert!(response.is_none());
+
+        let cancel_request = CancelRequest {
+            request_header: RequestHeader::dummy(),
+            request_handle: request.request_header.request_handle,
+        };
+        let response = SessionService::new().cancel(
+            server_state,
+            session.clone(),
+            address_space,
+            &cancel_request,
+        );
+        let response: CancelResponse = supported_message_as!(response, CancelResponse);
+        assert_eq!(response.cancel_count, 1);
+
+        // The publish request is answered with a service fault
+        let mut session = trace_write_lock_unwrap!(session);
+        let mut publish_responses = session
+            .subscriptions_mut()
+            .take_publish_responses()
+            .unwrap();
+        assert_eq!(publish_responses.len(), 1);
+        let publish_response = publish_responses.pop_front().unwrap();
+        assert_eq!(publish_response.request_id, request_id);
+        let response: ServiceFault = supported_message_as!(publish_response.response, ServiceFault);
+        assert_eq!(
+            response.response_header.service_result,
+            StatusCode::BadRequestCancelledByClient
+        );
+    })
+}
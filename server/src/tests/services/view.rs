@@ -159,6 +159,44 @@ fn browse() {
     });
 }
 
+#[test]
+fn browse_localized_display_name() {
+    // Test that the display name of each reference is chosen by the session's locale ids
+    do_view_service_test(|server_state, session, address_space, vs| {
+        let parent_id = NodeId::new(1, "Parent");
+        let child_id = NodeId::new(1, "Child");
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            ObjectBuilder::new(&parent_id, "Parent", "Parent")
+                .organized_by(ObjectId::ObjectsFolder)
+                .insert(&mut address_space);
+            ObjectBuilder::new(&child_id, "Child", LocalizedText::new("en", "Child"))
+                .display_name_translation(LocalizedText::new("de", "Kind"))
+                .organized_by(parent_id.clone())
+                .insert(&mut address_space);
+        }
+        {
+            let mut session = trace_write_lock_unwrap!(session);
+            session.set_locale_ids(Some(vec!["de-AT".into()]));
+        }
+
+        let response = do_browse(
+            &vs,
+            server_state,
+            session.clone(),
+            address_space.clone(),
+            &[parent_id],
+            1000,
+            BrowseDirection::Forward,
+        );
+        let results = response.results.unwrap();
+        let references = results[0].references.as_ref().unwrap();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].node_id.node_id, child_id);
+        assert_eq!(references[0].display_name, LocalizedText::new("de", "Kind"));
+    });
+}
+
 // Test the response of supplying an unsupported view to the browse request
 #[test]
 fn browse_non_null_view() {
@@ -1,2 +1,364 @@
 // Tests related to the Subscriptions struct go here, in particular relating to publish request
 // and response handling.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::ops::Add;
+use std::sync::{Arc, RwLock};
+
+use chrono::Utc;
+
+use crate::{
+    diagnostics::ServerDiagnostics,
+    prelude::*,
+    subscriptions::{
+        durable_subscriptions::{
+            DurableSubscription, DurableSubscriptions, DurableSubscriptionsSnapshot,
+        },
+        subscription::{Subscription, SubscriptionState, TickReason},
+        subscriptions::Subscriptions,
+    },
+};
+
+/// Makes subscriptions in the normal state from pairs of subscription id and priority
+fn make_subscriptions(priorities: &[(u32, u8)]) -> Subscriptions {
+    let mut subscriptions = Subscriptions::new(100, 30000);
+    priorities.iter().for_each(|&(subscription_id, priority)| {
+        let mut subscription = Subscription::new(
+            Arc::new(RwLock::new(ServerDiagnostics::default())),
+            subscription_id,
+            true,
+            1000f64,
+            300,
+            100,
+            priority,
+        );
+        subscription.set_state(SubscriptionState::Normal);
+        subscriptions.insert(subscription_id, subscription);
+    });
+    subscriptions
+}
+
+fn publish_request() -> PublishRequest {
+    PublishRequest {
+        request_header: RequestHeader::dummy(),
+        subscription_acknowledgements: None,
+    }
+}
+
+/// Takes the publish responses, returning the request id of each with the subscription id of
+/// the response or the service result of a fault
+fn publish_responses(subscriptions: &mut Subscriptions) -> Vec<(u32, Result<u32, StatusCode>)> {
+    subscriptions
+        .take_publish_responses()
+        .map(|responses| {
+            responses
+                .into_iter()
+                .map(|r| {
+                    let result = match r.response {
+                        SupportedMessage::PublishResponse(response) => Ok(response.subscription_id),
+                        SupportedMessage::ServiceFault(fault) => {
+                            Err(fault.response_header.service_result)
+                        }
+                        _ => panic!(),
+                    };
+                    (r.request_id, result)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn publish_request_to_highest_priority() {
+    let address_space = AddressSpace::default();
+    let mut subscriptions = make_subscriptions(&[(1, 0), (2, 200)]);
+    let now = Utc::now();
+
+    // Nothing is ready so the request is queued
+    subscriptions
+        .enqueue_publish_request(&now, 1, publish_request(), &address_space)
+        .unwrap();
+    assert!(publish_responses(&mut subscriptions).is_empty());
+
+    // Both subscriptions want to send a keep alive when the publishing interval elapses. The
+    // request goes to the higher priority subscription and the other one becomes late.
+    let now = now.add(chrono::Duration::seconds(2));
+    subscriptions
+        .tick(&now, &address_space, TickReason::TickTimerFired)
+        .unwrap();
+    assert_eq!(publish_responses(&mut subscriptions), vec![(1, Ok(2))]);
+    assert_eq!(
+        subscriptions.subscriptions()[&1].state(),
+        SubscriptionState::Late
+    );
+
+    // The next request goes to the late subscription
+    subscriptions
+        .enqueue_publish_request(&now, 2, publish_request(), &address_space)
+        .unwrap();
+    assert_eq!(publish_responses(&mut subscriptions), vec![(2, Ok(1))]);
+}
+
+#[test]
+fn publish_request_to_least_recently_served() {
+    let address_space = AddressSpace::default();
+    let mut subscriptions = make_subscriptions(&[(1, 0), (2, 0)]);
+    let now = Utc::now();
+
+    // With equal priorities the first subscription is served and the second becomes late
+    subscriptions
+        .enqueue_publish_request(&now, 1, publish_request(), &address_space)
+        .unwrap();
+    let now = now.add(chrono::Duration::seconds(2));
+    subscriptions
+        .tick(&now, &address_space, TickReason::TickTimerFired)
+        .unwrap();
+    assert_eq!(publish_responses(&mut subscriptions), vec![(1, Ok(1))]);
+
+    // When both are late, the second goes first because it has not been served
+    subscriptions
+        .get_mut(1)
+        .unwrap()
+        .set_state(SubscriptionState::Late);
+    subscriptions
+        .enqueue_publish_request(&now, 2, publish_request(), &address_space)
+        .unwrap();
+    assert_eq!(publish_responses(&mut subscriptions), vec![(2, Ok(2))]);
+}
+
+#[test]
+fn too_many_publish_requests() {
+    let address_space = AddressSpace::default();
+    let mut subscriptions = make_subscriptions(&[(1, 0)]);
+    let now = Utc::now();
+
+    // Two requests can be queued for one subscription
+    (1..=2).for_each(|request_id| {
+        subscriptions
+            .enqueue_publish_request(&now, request_id, publish_request(), &address_space)
+            .unwrap();
+    });
+    assert!(publish_responses(&mut subscriptions).is_empty());
+
+    // The oldest request is failed to make room for a third
+    subscriptions
+        .enqueue_publish_request(&now, 3, publish_request(), &address_space)
+        .unwrap();
+    assert_eq!(
+        publish_responses(&mut subscriptions),
+        vec![(1, Err(StatusCode::BadTooManyPublishRequests))]
+    );
+    assert_eq!(subscriptions.publish_request_queue().len(), 2);
+}
+
+fn durable_var_node_id() -> NodeId {
+    NodeId::new(1, 1)
+}
+
+fn make_durable_address_space() -> AddressSpace {
+    let mut address_space = AddressSpace::new();
+    VariableBuilder::new(&durable_var_node_id(), "test1", "test1")
+        .data_type(DataTypeId::UInt32)
+        .value(0u32)
+        .organized_by(ObjectId::ObjectsFolder)
+        .insert(&mut address_space);
+    address_space
+}
+
+/// Makes a durable subscription in the normal state with one monitored item on the test variable
+fn make_durable_subscription(
+    subscription_id: u32,
+    now: &DateTimeUtc,
+    address_space: &AddressSpace,
+) -> Subscription {
+    let mut subscription = Subscription::new(
+        Arc::new(RwLock::new(ServerDiagnostics::default())),
+        subscription_id,
+        true,
+        1000f64,
+        300,
+        100,
+        0,
+    );
+    subscription.set_durable(1).unwrap();
+    let results = subscription.create_monitored_items(
+        address_space,
+        now,
+        TimestampsToReturn::Both,
+        &[MonitoredItemCreateRequest {
+            item_to_monitor: ReadValueId {
+                node_id: durable_var_node_id(),
+                attribute_id: AttributeId::Value as u32,
+                index_range: UAString::null(),
+                data_encoding: QualifiedName::null(),
+            },
+            monitoring_mode: MonitoringMode::Reporting,
+            requested_parameters: MonitoringParameters {
+                client_handle: 999,
+                sampling_interval: 0f64,
+                filter: ExtensionObject::null(),
+                queue_size: 20000,
+                discard_oldest: true,
+            },
+        }],
+        100,
+    );
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    subscription.set_state(SubscriptionState::Normal);
+    subscription
+}
+
+#[test]
+fn set_durable_after_monitored_items() {
+    let address_space = make_durable_address_space();
+    let now = Utc::now();
+    let mut subscription = make_durable_subscription(1, &now, &address_space);
+    assert!(subscription.is_durable());
+    assert_eq!(subscription.lifetime_in_hours(), Some(1));
+    // An hour of one second publishing intervals
+    assert_eq!(subscription.max_lifetime_count(), 3600);
+
+    // Monitored items of a durable subscription may have larger queues
+    let snapshot = subscription.snapshot();
+    assert_eq!(
+        snapshot.monitored_items[0].queue_size,
+        crate::constants::MAX_DURABLE_QUEUE_SIZE as u32
+    );
+
+    // Once there are monitored items the lifetime cannot be changed
+    assert_eq!(
+        subscription.set_durable(2).unwrap_err(),
+        StatusCode::BadInvalidState
+    );
+    assert_eq!(subscription.lifetime_in_hours(), Some(1));
+}
+
+#[test]
+fn durable_subscription_holds_notifications() {
+    let mut address_space = make_durable_address_space();
+    let mut now = Utc::now();
+    let mut subscription = make_durable_subscription(1, &now, &address_space);
+
+    // Without a publish request the values stay queued in the monitored item
+    (1..=3u32).for_each(|value| {
+        let timestamp = DateTime::from(now.clone());
+        assert!(address_space.set_variable_value(
+            durable_var_node_id(),
+            value,
+            &timestamp,
+            &timestamp
+        ));
+        now = now.add(chrono::Duration::seconds(2));
+        subscription.tick(&now, &address_space, TickReason::TickTimerFired, false);
+        assert!(subscription.take_notification().is_none());
+    });
+    assert_eq!(
+        subscription.snapshot().monitored_items[0]
+            .notification_queue
+            .len(),
+        3
+    );
+
+    // The values are sent once there is a publish request for them
+    now = now.add(chrono::Duration::seconds(2));
+    subscription.tick(&now, &address_space, TickReason::TickTimerFired, true);
+    assert!(subscription.take_notification().is_some());
+    assert!(subscription.snapshot().monitored_items[0]
+        .notification_queue
+        .is_empty());
+}
+
+#[test]
+fn durable_subscriptions_snapshot() {
+    let mut address_space = make_durable_address_space();
+    let now = Utc::now();
+    let mut subscription = make_durable_subscription(1, &now, &address_space);
+    let timestamp = DateTime::from(now.clone());
+    address_space.set_variable_value(durable_var_node_id(), 5u32, &timestamp, &timestamp);
+    let now = now.add(chrono::Duration::seconds(2));
+    subscription.tick(&now, &address_space, TickReason::TickTimerFired, false);
+
+    let mut retransmission_queue = BTreeMap::new();
+    retransmission_queue.insert(7, NotificationMessage::keep_alive(7, timestamp.clone()));
+    let durable_subscription = DurableSubscription {
+        client_user_id: UAString::from("user1"),
+        subscription,
+        retransmission_queue,
+    };
+    let snapshot = DurableSubscriptionsSnapshot {
+        subscriptions: vec![durable_subscription.snapshot()],
+    };
+
+    // The snapshot survives being encoded and decoded
+    let mut stream = Cursor::new(snapshot.encode_to_vec());
+    let decoded =
+        DurableSubscriptionsSnapshot::decode(&mut stream, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, snapshot);
+
+    // Restoring the snapshot gives back the same subscription and queued values
+    let mut durable_subscriptions = DurableSubscriptions::new();
+    assert_eq!(
+        durable_subscriptions.restore(
+            Arc::new(RwLock::new(ServerDiagnostics::default())),
+            &now,
+            decoded
+        ),
+        1
+    );
+    assert_eq!(durable_subscriptions.max_subscription_id(), Some(1));
+    let restored = durable_subscriptions.snapshot();
+    assert_eq!(restored, snapshot.subscriptions);
+    assert_eq!(
+        restored[0].subscription.monitored_items[0]
+            .notification_queue
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn transfer_durable_subscription() {
+    let address_space = make_durable_address_space();
+    let now = Utc::now();
+    let client_user_id = UAString::from("user1");
+
+    // Only durable subscriptions are kept when the session goes away
+    let mut subscriptions = make_subscriptions(&[(1, 0)]);
+    subscriptions.insert(2, make_durable_subscription(2, &now, &address_space));
+    let mut taken = subscriptions.take_durable_subscriptions(&client_user_id);
+    assert_eq!(taken.len(), 1);
+    assert!(subscriptions.contains(1));
+    assert!(!subscriptions.contains(2));
+
+    let mut durable_subscription = taken.remove(0);
+    assert_eq!(durable_subscription.subscription.subscription_id(), 2);
+    durable_subscription.retransmission_queue.insert(
+        4,
+        NotificationMessage::keep_alive(4, DateTime::from(now.clone())),
+    );
+    let mut durable_subscriptions = DurableSubscriptions::new();
+    durable_subscriptions.insert(durable_subscription);
+
+    // Only the owner may take the subscription
+    assert_eq!(
+        durable_subscriptions.take(3, &client_user_id).unwrap_err(),
+        StatusCode::BadSubscriptionIdInvalid
+    );
+    assert_eq!(
+        durable_subscriptions
+            .take(2, &UAString::from("user2"))
+            .unwrap_err(),
+        StatusCode::BadUserAccessDenied
+    );
+    let durable_subscription = durable_subscriptions.take(2, &client_user_id).unwrap();
+    assert!(durable_subscriptions.is_empty());
+
+    // The new session gets the subscription and the notifications it can republish
+    let mut subscriptions = Subscriptions::new(100, 30000);
+    assert_eq!(
+        subscriptions.transfer(durable_subscription, true),
+        Some(vec![4])
+    );
+    assert!(subscriptions.contains(2));
+}
@@ -4,6 +4,9 @@
 
 //! Provides utility routines for things that might be used in a number of places elsewhere.
 
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
@@ -14,7 +17,9 @@ use futures::Stream;
 use tokio;
 use tokio_timer::Interval;
 
-use opcua_types::service_types::ServerState as ServerStateType;
+use opcua_types::{
+    service_types::ServerState as ServerStateType, status_code::StatusCode, BinaryEncoder,
+};
 
 use crate::state::ServerState;
 
@@ -64,3 +69,36 @@ impl PollingAction {
         PollingAction {}
     }
 }
+
+/// Encodes the value to a file. The value is written to a temporary file next to the path which
+/// then replaces the file, so a failed save never leaves a partially written file behind. The
+/// description names what is being saved in any error that is logged.
+pub(crate) fn save_encoded_file<T>(
+    value: &T,
+    path: &Path,
+    description: &str,
+) -> Result<(), StatusCode>
+where
+    T: BinaryEncoder<T>,
+{
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    let io_error = |err: io::Error| {
+        error!(
+            "{} {} cannot be saved, error = {}",
+            description,
+            path.display(),
+            err
+        );
+        StatusCode::BadUnexpectedError
+    };
+    let file = File::create(&tmp_path).map_err(io_error)?;
+    let mut stream = BufWriter::new(file);
+    value.encode(&mut stream)?;
+    let file = stream.into_inner().map_err(|err| io_error(err.into()))?;
+    file.sync_all().map_err(io_error)?;
+    fs::rename(&tmp_path, path).map_err(io_error)?;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Generates the Rust code of the OPC UA for Rust crates from the schemas published by the OPC
+//! Foundation, i.e. `Opc.Ua.Types.bsd`, `NodeIds.csv`, `Opc.Ua.StatusCodes.csv` and NodeSet2 XML
+//! files.
+//!
+//! The same generators are used for the code of `opcua-types`, `opcua-core` and `opcua-server`
+//! and for third-party node sets such as companion specifications. The [`nodeset`] generator can
+//! be called from the `build.rs` of a server to turn a node set into a module during the build:
+//!
+//! ```no_run
+//! use std::{env, path::Path};
+//!
+//! use opcua_codegen::nodeset::{self, NodeSetConfig};
+//!
+//! let out_dir = env::var("OUT_DIR").unwrap();
+//! let config = NodeSetConfig {
+//!     namespace_index: Some(2),
+//!     inner_attributes: false,
+//!     ..Default::default()
+//! };
+//! nodeset::generate_file(
+//!     Path::new("Opc.Ua.EUROMAP83.NodeSet2.xml"),
+//!     "euromap83",
+//!     Path::new(&out_dir),
+//!     &config,
+//! )
+//! .unwrap();
+//! ```
+//!
+//! The server then pulls the module in with
+//! `mod euromap83 { include!(concat!(env!("OUT_DIR"), "/euromap83.rs")); }`.
+//!
+//! [`nodeset`]: ./nodeset/index.html
+
+use std::{fmt, fs, io, path::Path};
+
+pub mod node_ids;
+pub mod nodeset;
+pub mod status_codes;
+pub mod supported_message;
+pub mod types;
+
+mod util;
+
+/// The copyright header of every file generated for the OPC UA for Rust crates
+const COPYRIGHT: &str = "// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+";
+
+/// The errors that can happen while generating code
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written
+    Io(io::Error),
+    /// A file is not well formed XML
+    Xml(roxmltree::Error),
+    /// A file does not match the schema that the generator expects
+    Schema(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error, {}", err),
+            Error::Xml(err) => write!(f, "XML error, {}", err),
+            Error::Schema(err) => write!(f, "Schema error, {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<roxmltree::Error> for Error {
+    fn from(err: roxmltree::Error) -> Self {
+        Error::Xml(err)
+    }
+}
+
+/// A file produced by a generator, named relative to the directory it is written into
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFile {
+    pub file_name: String,
+    pub contents: String,
+}
+
+impl GeneratedFile {
+    pub fn new<T>(file_name: T, contents: String) -> GeneratedFile
+    where
+        T: Into<String>,
+    {
+        GeneratedFile {
+            file_name: file_name.into(),
+            contents,
+        }
+    }
+
+    /// Writes the file into the directory
+    pub fn write_to(&self, dir: &Path) -> Result<(), Error> {
+        fs::write(dir.join(&self.file_name), &self.contents)?;
+        Ok(())
+    }
+}
+
+/// Writes every file into the directory
+pub fn write_files(files: &[GeneratedFile], dir: &Path) -> Result<(), Error> {
+    files.iter().try_for_each(|file| file.write_to(dir))
+}
@@ -0,0 +1,135 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use opcua_codegen::{
+    node_ids,
+    nodeset::{self, NodeSetConfig},
+    status_codes, supported_message, types, write_files, Error,
+};
+
+const USAGE: &str = r#"Usage: opcua-codegen <command> [options]
+
+Commands:
+  all                 Generates all of the code of the OPC UA for Rust crates
+  types               Generates the service types of opcua-types from Opc.Ua.Types.bsd
+  node-ids            Generates node_ids.rs of opcua-types from NodeIds.csv
+  status-codes        Generates status_codes.rs of opcua-types from Opc.Ua.StatusCodes.csv
+  supported-message   Generates supported_message.rs of opcua-core
+  address-space       Generates the address space of opcua-server from the OPC UA node sets
+  nodeset             Generates a Rust module from a NodeSet2 XML file
+
+Options:
+  --schemas <path>    The directory of the OPC UA schemas, defaults to tools/schema/schemas/1.0.4
+  --root <path>       The root of the OPC UA for Rust source tree, defaults to this repository
+
+Options of nodeset:
+  --nodeset <path>    The NodeSet2 XML file to generate from
+  --module <name>     The module to generate, e.g. foo writes a file called foo.rs
+  --outputdir <path>  The directory to write the module into, defaults to the current directory
+  --ns <index>        Rewrites any non-zero namespace index to this value
+"#;
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    if args.contains(["-h", "--help"]) {
+        print!("{}", USAGE);
+        return;
+    }
+    if let Err(err) = run(&mut args) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run(args: &mut pico_args::Arguments) -> Result<(), String> {
+    let command = args.subcommand().map_err(|err| err.to_string())?;
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root: PathBuf = args
+        .opt_value_from_str("--root")
+        .map_err(|err| err.to_string())?
+        .unwrap_or_else(|| manifest_dir.join("../.."));
+    let schema_dir: PathBuf = args
+        .opt_value_from_str("--schemas")
+        .map_err(|err| err.to_string())?
+        .unwrap_or_else(|| manifest_dir.join("../schema/schemas/1.0.4"));
+
+    let result = match command.as_deref() {
+        Some("all") => gen_types(&root, &schema_dir)
+            .and_then(|_| gen_node_ids(&root, &schema_dir))
+            .and_then(|_| gen_status_codes(&root, &schema_dir))
+            .and_then(|_| gen_supported_message(&root))
+            .and_then(|_| gen_address_space(&root, &schema_dir)),
+        Some("types") => gen_types(&root, &schema_dir),
+        Some("node-ids") => gen_node_ids(&root, &schema_dir),
+        Some("status-codes") => gen_status_codes(&root, &schema_dir),
+        Some("supported-message") => gen_supported_message(&root),
+        Some("address-space") => gen_address_space(&root, &schema_dir),
+        Some("nodeset") => {
+            let nodeset: PathBuf = args
+                .value_from_str("--nodeset")
+                .map_err(|err| err.to_string())?;
+            let module: String = args
+                .value_from_str("--module")
+                .map_err(|err| err.to_string())?;
+            let output_dir: PathBuf = args
+                .opt_value_from_str("--outputdir")
+                .map_err(|err| err.to_string())?
+                .unwrap_or_else(|| PathBuf::from("."));
+            let namespace_index: Option<u16> = args
+                .opt_value_from_str("--ns")
+                .map_err(|err| err.to_string())?;
+            gen_nodeset(&nodeset, &module, &output_dir, namespace_index)
+        }
+        _ => return Err(USAGE.to_string()),
+    };
+    result.map_err(|err| err.to_string())
+}
+
+fn gen_types(root: &Path, schema_dir: &Path) -> Result<(), Error> {
+    let bsd = fs::read_to_string(schema_dir.join("Opc.Ua.Types.bsd"))?;
+    write_files(
+        &types::generate(&bsd)?,
+        &root.join("types/src/service_types"),
+    )
+}
+
+fn gen_node_ids(root: &Path, schema_dir: &Path) -> Result<(), Error> {
+    let csv = fs::read_to_string(schema_dir.join("NodeIds.csv"))?;
+    node_ids::generate(&csv)?.write_to(&root.join("types/src"))
+}
+
+fn gen_status_codes(root: &Path, schema_dir: &Path) -> Result<(), Error> {
+    let csv = fs::read_to_string(schema_dir.join("Opc.Ua.StatusCodes.csv"))?;
+    status_codes::generate(&csv)?.write_to(&root.join("types/src"))
+}
+
+fn gen_supported_message(root: &Path) -> Result<(), Error> {
+    supported_message::generate(supported_message::MESSAGE_TYPES).write_to(&root.join("core/src"))
+}
+
+fn gen_address_space(root: &Path, schema_dir: &Path) -> Result<(), Error> {
+    let output_dir = root.join("server/src/address_space/generated");
+    write_files(&nodeset::generate_address_space(schema_dir)?, &output_dir)
+}
+
+fn gen_nodeset(
+    nodeset: &Path,
+    module: &str,
+    output_dir: &Path,
+    namespace_index: Option<u16>,
+) -> Result<(), Error> {
+    let config = NodeSetConfig {
+        namespace_index,
+        ..Default::default()
+    };
+    let modules = nodeset::generate_file(nodeset, module, output_dir, &config)?;
+    println!("Generated modules {}", modules.join(", "));
+    Ok(())
+}
@@ -0,0 +1,121 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Generates `node_ids.rs` of `opcua-types` from `NodeIds.csv`. Every type of node in the file,
+//! e.g. `Object` or `DataType`, becomes an enum such as `ObjectId` or `DataTypeId`.
+
+use std::fmt::Write;
+
+use crate::{util, Error, GeneratedFile, COPYRIGHT};
+
+/// The XML encodings and schemas are skipped since the stack does not implement the XML encoding
+fn interested_in_node(name: &str) -> bool {
+    !name.ends_with("_DefaultXml") && !name.starts_with("OpcUa_XmlSchema_")
+}
+
+/// Generates `node_ids.rs` from the contents of `NodeIds.csv`
+pub fn generate(csv: &str) -> Result<GeneratedFile, Error> {
+    // Node ids are grouped by their type in the order each type first appears
+    let mut node_ids: Vec<(String, Vec<(String, String)>)> = Vec::new();
+    for fields in util::parse_csv(csv) {
+        if fields.len() != 3 {
+            return Err(Error::Schema(format!(
+                "Node id line has {} fields",
+                fields.len()
+            )));
+        }
+        let (name, id, node_type) = (fields[0].clone(), fields[1].clone(), &fields[2]);
+        if let Some((_, nodes)) = node_ids.iter_mut().find(|(t, _)| t == node_type) {
+            nodes.push((name, id));
+        } else {
+            node_ids.push((node_type.clone(), vec![(name, id)]));
+        }
+    }
+
+    let mut contents = format!(
+        r#"{}
+// This file was autogenerated from NodeIds.csv by tools/codegen
+// DO NOT EDIT THIS FILE
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use std::convert::TryFrom;
+
+use crate::{{
+    node_id::{{NodeId, ExpandedNodeId}},
+    string::UAString,
+}};
+"#,
+        COPYRIGHT
+    );
+
+    for (node_type, nodes) in &node_ids {
+        let nodes = nodes
+            .iter()
+            .filter(|(name, _)| interested_in_node(name))
+            .collect::<Vec<_>>();
+        let _ = write!(
+            contents,
+            r#"
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum {}Id {{
+"#,
+            node_type
+        );
+        for (name, id) in &nodes {
+            let _ = writeln!(contents, "    {} = {},", name, id);
+        }
+        contents.push_str("}\n");
+
+        let _ = write!(
+            contents,
+            r#"
+impl<'a> From<&'a {0}Id> for NodeId {{
+    fn from(r: &'a {0}Id) -> Self {{
+        NodeId::new(0, *r as u32)
+    }}
+}}
+
+impl Into<NodeId> for {0}Id {{
+    fn into(self) -> NodeId {{
+        NodeId::new(0, self as u32)
+    }}
+}}
+
+impl Into<ExpandedNodeId> for {0}Id {{
+    fn into(self) -> ExpandedNodeId {{
+        ExpandedNodeId {{
+            node_id: NodeId::new(0, self as u32),
+            namespace_uri: UAString::null(),
+            server_index: 0,
+        }}
+    }}
+}}
+
+impl TryFrom<u32> for {0}Id {{
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {{
+        match value {{
+"#,
+            node_type
+        );
+        for (name, id) in &nodes {
+            let _ = writeln!(
+                contents,
+                "            {} => Ok({}Id::{}),",
+                id, node_type, name
+            );
+        }
+        contents.push_str(
+            r#"            _ => Err(())
+        }
+    }
+}
+"#,
+        );
+    }
+    Ok(GeneratedFile::new("node_ids.rs", contents))
+}
@@ -0,0 +1,584 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Generates Rust modules from NodeSet2 XML files. Each module has a `populate_address_space`
+//! function which inserts the nodes of the node set into an `AddressSpace`.
+//!
+//! The address space of `opcua-server` is generated from the node sets of the OPC UA
+//! specification but the same generator can turn a third-party node set into a module, either with
+//! the `opcua-codegen nodeset` command or from a `build.rs`.
+
+use std::{collections::HashMap, fmt::Write, fs, path::Path};
+
+use roxmltree::{Document, Node};
+
+use crate::{util, util::NodeIdSnippet, Error, GeneratedFile, COPYRIGHT};
+
+/// Settings that control the code generated from a node set
+#[derive(Debug, Clone)]
+pub struct NodeSetConfig {
+    /// Named in the header comment as the generator of the files
+    pub autogenerated_by: String,
+    /// The maximum number of nodes in each generated module, or 0 for no limit. Nodes over the
+    /// limit are split into modules numbered `{module}_1`, `{module}_2` etc.
+    pub max_nodes_per_file: usize,
+    /// Rewrites any non-zero namespace index in the node set to this value
+    pub namespace_index: Option<u16>,
+    /// The path of the server crate in the generated code, e.g. `opcua_server` or `crate`
+    pub server_crate: String,
+    /// Writes the copyright header of OPC UA for Rust at the top of each file
+    pub copyright: bool,
+    /// Writes `#![rustfmt::skip]` at the top of each file. Inner attributes are not allowed in a
+    /// file pulled in with `include!` so this should be off for code generated from a `build.rs`.
+    pub inner_attributes: bool,
+}
+
+impl Default for NodeSetConfig {
+    fn default() -> Self {
+        NodeSetConfig {
+            autogenerated_by: "tools/codegen".to_string(),
+            max_nodes_per_file: 0,
+            namespace_index: None,
+            server_crate: "opcua_server".to_string(),
+            copyright: false,
+            inner_attributes: true,
+        }
+    }
+}
+
+impl NodeSetConfig {
+    /// The configuration used for the address space of `opcua-server` itself
+    pub fn address_space() -> NodeSetConfig {
+        NodeSetConfig {
+            max_nodes_per_file: 100,
+            server_crate: "crate".to_string(),
+            copyright: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// The node sets of the OPC UA specification that make up the address space of `opcua-server`,
+/// and the modules they are generated into
+pub const ADDRESS_SPACE_NODE_SETS: &[(&str, &str)] = &[
+    ("Opc.Ua.NodeSet2.Part3.xml", "nodeset_3"),
+    ("Opc.Ua.NodeSet2.Part4.xml", "nodeset_4"),
+    ("Opc.Ua.NodeSet2.Part5.xml", "nodeset_5"),
+    ("Opc.Ua.NodeSet2.Part8.xml", "nodeset_8"),
+    ("Opc.Ua.NodeSet2.Part9.xml", "nodeset_9"),
+    ("Opc.Ua.NodeSet2.Part10.xml", "nodeset_10"),
+    ("Opc.Ua.NodeSet2.Part11.xml", "nodeset_11"),
+    ("Opc.Ua.NodeSet2.Part12.xml", "nodeset_12"),
+    ("Opc.Ua.NodeSet2.Part13.xml", "nodeset_13"),
+    ("Opc.Ua.NodeSet2.Part14.xml", "nodeset_14"),
+    ("Opc.Ua.NodeSet2.Part999.xml", "nodeset_999"),
+];
+
+/// The classes of node in the order they are generated
+const NODE_CLASSES: &[(&str, &str)] = &[
+    ("UAObject", "Object"),
+    ("UAObjectType", "ObjectType"),
+    ("UADataType", "DataType"),
+    ("UAReferenceType", "ReferenceType"),
+    ("UAVariable", "Variable"),
+    ("UAVariableType", "VariableType"),
+    ("UAMethod", "Method"),
+];
+
+const INDENT: &str = "    ";
+
+/// A node set module, named by the module it should be declared as
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeSetModule {
+    pub module: String,
+    pub file: GeneratedFile,
+}
+
+struct Generator<'a> {
+    config: &'a NodeSetConfig,
+    aliases: HashMap<&'a str, &'a str>,
+}
+
+/// Generates the modules of a node set from the contents of its XML. The `xml_name` is the name of
+/// the file written in the header comment and `module` is the name of the module to generate.
+/// There is more than one module if the node set has more nodes than the configuration allows
+/// per file.
+pub fn generate(
+    xml_name: &str,
+    xml: &str,
+    module: &str,
+    config: &NodeSetConfig,
+) -> Result<Vec<NodeSetModule>, Error> {
+    let document = Document::parse(xml)?;
+    let root = document.root_element();
+
+    let mut aliases = HashMap::new();
+    for alias in util::child(root, "Aliases")
+        .into_iter()
+        .flat_map(|aliases| util::children(aliases, "Alias"))
+    {
+        if let Some(name) = alias.attribute("Alias") {
+            aliases.insert(name, util::text(alias));
+        }
+    }
+
+    let mut nodes = Vec::new();
+    for (element_name, node_class) in NODE_CLASSES {
+        nodes.extend(util::children(root, element_name).map(|node| (*node_class, node)));
+    }
+
+    let generator = Generator { config, aliases };
+    let max_nodes_per_file = config.max_nodes_per_file;
+    if max_nodes_per_file == 0 || nodes.len() <= max_nodes_per_file {
+        Ok(vec![generator.generate_module(
+            xml_name,
+            module.to_string(),
+            &nodes,
+        )?])
+    } else {
+        nodes
+            .chunks(max_nodes_per_file)
+            .enumerate()
+            .map(|(i, nodes)| {
+                generator.generate_module(xml_name, format!("{}_{}", module, i + 1), nodes)
+            })
+            .collect()
+    }
+}
+
+/// Generates the modules of a node set file into the output directory and returns the names of
+/// the modules
+pub fn generate_file(
+    nodeset_path: &Path,
+    module: &str,
+    out_dir: &Path,
+    config: &NodeSetConfig,
+) -> Result<Vec<String>, Error> {
+    let xml = fs::read_to_string(nodeset_path)?;
+    let xml_name = nodeset_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let modules = generate(&xml_name, &xml, module, config)?;
+    modules
+        .into_iter()
+        .map(|module| {
+            module.file.write_to(out_dir)?;
+            Ok(module.module)
+        })
+        .collect()
+}
+
+/// Generates the `mod.rs` of the address space which declares the node set modules and populates
+/// the address space from each of them
+pub fn generate_address_space_mod(modules: &[String], config: &NodeSetConfig) -> GeneratedFile {
+    let mut contents = String::new();
+    if config.copyright {
+        contents.push_str(COPYRIGHT);
+        contents.push('\n');
+    }
+    let _ = write!(
+        contents,
+        r#"// This file was autogenerated by {}
+// DO NOT EDIT THIS FILE
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use {}::address_space::types::AddressSpace;
+
+"#,
+        config.autogenerated_by, config.server_crate
+    );
+    for module in modules {
+        let _ = writeln!(contents, "mod {};", module);
+    }
+    contents.push_str(
+        r#"
+/// Populates the address space with all defined node sets
+pub fn populate_address_space(address_space: &mut AddressSpace) {
+"#,
+    );
+    for module in modules {
+        let _ = writeln!(
+            contents,
+            "    {}::populate_address_space(address_space);",
+            module
+        );
+    }
+    contents.push_str("}\n");
+    GeneratedFile::new("mod.rs", contents)
+}
+
+/// Generates the address space of `opcua-server` from the node sets in the schema directory and
+/// returns the files, including `mod.rs`
+pub fn generate_address_space(schema_dir: &Path) -> Result<Vec<GeneratedFile>, Error> {
+    let config = NodeSetConfig::address_space();
+    let mut modules = Vec::new();
+    let mut files = Vec::new();
+    for (xml_name, module) in ADDRESS_SPACE_NODE_SETS {
+        let xml = fs::read_to_string(schema_dir.join(xml_name))?;
+        for node_set_module in generate(xml_name, &xml, module, &config)? {
+            modules.push(node_set_module.module);
+            files.push(node_set_module.file);
+        }
+    }
+    files.push(generate_address_space_mod(&modules, &config));
+    Ok(files)
+}
+
+fn is_true(node: Node, attribute: &str) -> bool {
+    node.attribute(attribute) == Some("true")
+}
+
+struct Argument {
+    name: String,
+    data_type: String,
+    value_rank: String,
+    array_dimensions: &'static str,
+}
+
+impl<'a> Generator<'a> {
+    fn generate_module(
+        &self,
+        xml_name: &str,
+        module: String,
+        nodes: &[(&str, Node)],
+    ) -> Result<NodeSetModule, Error> {
+        let mut contents = String::new();
+        if self.config.copyright {
+            contents.push_str(COPYRIGHT);
+        }
+        let _ = writeln!(
+            contents,
+            "// This file was autogenerated from {} by {}\n// DO NOT EDIT THIS FILE",
+            xml_name, self.config.autogenerated_by
+        );
+        if self.config.inner_attributes {
+            contents.push_str("#![rustfmt::skip]\n");
+        }
+        let _ = write!(
+            contents,
+            r#"
+#[allow(unused_imports)]
+use std::{{convert::TryFrom, str::FromStr}};
+
+#[allow(unused_imports)]
+use {}::{{
+    address_space::{{EventNotifier, types::*}},
+    prelude::{{DataTypeId, ExtensionObject, LocalizedText, NodeId, ReferenceTypeId, service_types::Argument, UAString, Variant}}
+}};
+
+"#,
+            self.config.server_crate
+        );
+
+        let fn_names = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, (node_class, _))| format!("add_{}_{}", node_class.to_lowercase(), i + 1))
+            .collect::<Vec<String>>();
+
+        contents.push_str("#[allow(unused_variables)]\n");
+        contents.push_str("pub fn populate_address_space(address_space: &mut AddressSpace) {\n");
+        for fn_name in &fn_names {
+            let _ = writeln!(contents, "    {}(address_space);", fn_name);
+        }
+        contents.push_str("}\n\n");
+
+        for (fn_name, (node_class, node)) in fn_names.iter().zip(nodes) {
+            self.insert_node(&mut contents, fn_name, node_class, *node)?;
+        }
+
+        Ok(NodeSetModule {
+            file: GeneratedFile::new(format!("{}.rs", module), contents),
+            module,
+        })
+    }
+
+    /// Turns a snippet like `i=2015` or `ns=2;s=Foo` into the code that constructs the node id
+    fn node_id_ctor(&self, snippet: &str) -> Result<String, Error> {
+        let node_id = NodeIdSnippet::parse(snippet)
+            .ok_or_else(|| Error::Schema(format!("Invalid node id {}", snippet)))?;
+        let namespace = match self.config.namespace_index {
+            Some(namespace_index) if node_id.namespace > 0 => namespace_index,
+            _ => node_id.namespace,
+        };
+        Ok(match node_id.id_type {
+            'i' => format!("NodeId::new({}, {})", namespace, node_id.value),
+            's' => format!("NodeId::new({}, \"{}\")", namespace, node_id.value),
+            // Guid and opaque ids are easier to parse at runtime
+            id_type => format!(
+                "NodeId::from_str(\"ns={};{}={}\").unwrap()",
+                namespace, id_type, node_id.value
+            ),
+        })
+    }
+
+    fn data_type_node_id(&self, data_type: &str) -> Result<String, Error> {
+        let aliased_data_type = self.aliases.get(data_type).cloned().unwrap_or(data_type);
+        if NodeIdSnippet::parse(aliased_data_type).is_some() {
+            self.node_id_ctor(aliased_data_type)
+        } else {
+            Ok(format!("DataTypeId::{}.into()", data_type))
+        }
+    }
+
+    /// Finds the arguments of a method in the value of its `InputArguments` or `OutputArguments`
+    fn arguments(&self, value: Node) -> Vec<Argument> {
+        util::child(value, "ListOfExtensionObject")
+            .into_iter()
+            .flat_map(|list| util::children(list, "ExtensionObject"))
+            .filter_map(|extension_object| {
+                let argument = util::child(util::child(extension_object, "Body")?, "Argument")?;
+                let data_type = util::child(argument, "DataType")
+                    .and_then(|data_type| util::child(data_type, "Identifier"))
+                    .map(util::text)
+                    .unwrap_or("");
+                let value_rank = util::child(argument, "ValueRank")
+                    .map(util::text)
+                    .unwrap_or("");
+                // Array dimensions are not read, a value rank of 1 is a variable length array
+                let array_dimensions = if value_rank == "1" {
+                    "Some(vec![0])"
+                } else {
+                    "None"
+                };
+                Some(Argument {
+                    name: util::child(argument, "Name")
+                        .map(util::text)
+                        .unwrap_or("")
+                        .to_string(),
+                    data_type: data_type.to_string(),
+                    value_rank: value_rank.to_string(),
+                    array_dimensions,
+                })
+            })
+            .collect()
+    }
+
+    fn insert_node(
+        &self,
+        contents: &mut String,
+        fn_name: &str,
+        node_class: &str,
+        node: Node,
+    ) -> Result<(), Error> {
+        let _ = writeln!(
+            contents,
+            "fn {}(address_space: &mut AddressSpace) {{",
+            fn_name
+        );
+        let _ = writeln!(contents, "{}// {}", INDENT, node_class);
+
+        let browse_name = node.attribute("BrowseName").unwrap_or("");
+        let display_name = util::child(node, "DisplayName")
+            .map(util::text)
+            .unwrap_or("");
+        let (browse_name_var, display_name_var) = if browse_name == display_name {
+            // The same variable serves for both names when they are the same
+            let _ = writeln!(
+                contents,
+                "{}let name = {};",
+                INDENT,
+                util::quote(browse_name)
+            );
+            ("name", "name")
+        } else {
+            let _ = writeln!(
+                contents,
+                "{}let browse_name = {};",
+                INDENT,
+                util::quote(browse_name)
+            );
+            let _ = writeln!(
+                contents,
+                "{}let display_name = {};",
+                INDENT,
+                util::quote(display_name)
+            );
+            ("browse_name", "display_name")
+        };
+
+        let is_abstract = is_true(node, "IsAbstract");
+        let node_ctor = match node_class {
+            "Object" => {
+                let event_notifier = match node
+                    .attribute("EventNotifier")
+                    .and_then(|v| v.parse::<u8>().ok())
+                {
+                    Some(event_notifier) if event_notifier != 0 => {
+                        format!("EventNotifier::from_bits_truncate({})", event_notifier)
+                    }
+                    _ => "EventNotifier::empty()".to_string(),
+                };
+                format!(
+                    "Object::new(&node_id, {}, {}, {})",
+                    browse_name_var, display_name_var, event_notifier
+                )
+            }
+            "ObjectType" | "DataType" => format!(
+                "{}::new(&node_id, {}, {}, {})",
+                node_class, browse_name_var, display_name_var, is_abstract
+            ),
+            "ReferenceType" => {
+                let inverse_name = util::child(node, "InverseName")
+                    .map(|inverse_name| {
+                        format!(
+                            "Some(LocalizedText::new(\"\", {}))",
+                            util::quote(util::text(inverse_name))
+                        )
+                    })
+                    .unwrap_or_else(|| "None".to_string());
+                format!(
+                    "ReferenceType::new(&node_id, {}, {}, {}, {}, {})",
+                    browse_name_var,
+                    display_name_var,
+                    inverse_name,
+                    is_true(node, "Symmetric"),
+                    is_abstract
+                )
+            }
+            "Variable" => {
+                let data_type = match node.attribute("DataType") {
+                    Some(data_type) => self.data_type_node_id(data_type)?,
+                    None => "DataTypeId::Boolean".to_string(),
+                };
+                let arguments = util::child(node, "Value")
+                    .map(|value| self.arguments(value))
+                    .unwrap_or_default();
+                if arguments.is_empty() {
+                    let _ = writeln!(contents, "{}let value = Variant::Empty;", INDENT);
+                } else {
+                    let _ = writeln!(contents, "{}let value = vec![", INDENT);
+                    for argument in &arguments {
+                        // The binary encoding of Argument rather than the i=297 in the node set
+                        let _ = writeln!(
+                            contents,
+                            "{0}    Variant::from(ExtensionObject::from_encodable(\n\
+                             {0}        {1}, &Argument {{\n\
+                             {0}            name: UAString::from({2}),\n\
+                             {0}            data_type: {3},\n\
+                             {0}            value_rank: {4},\n\
+                             {0}            array_dimensions: {5},\n\
+                             {0}            description: LocalizedText::new(\"\", \"\"),\n\
+                             {0}        }})),",
+                            INDENT,
+                            self.node_id_ctor("i=298")?,
+                            util::quote(&argument.name),
+                            self.node_id_ctor(&argument.data_type)?,
+                            argument.value_rank,
+                            argument.array_dimensions
+                        );
+                    }
+                    let _ = writeln!(contents, "{}];", INDENT);
+                }
+                let value_rank = node
+                    .attribute("ValueRank")
+                    .map(|v| format!("Some({})", v))
+                    .unwrap_or_else(|| "None".to_string());
+                let array_dimensions = node
+                    .attribute("ArrayDimensions")
+                    .map(|v| format!("Some({})", v))
+                    .unwrap_or_else(|| "None".to_string());
+                format!(
+                    "Variable::new_data_value(&node_id, {}, {}, {}, {}, {}, value)",
+                    browse_name_var, display_name_var, data_type, value_rank, array_dimensions
+                )
+            }
+            "VariableType" => {
+                let data_type = match node.attribute("DataType") {
+                    Some(data_type) => self.data_type_node_id(data_type)?,
+                    None => "NodeId::null()".to_string(),
+                };
+                format!(
+                    "VariableType::new(&node_id, {}, {}, {}, {}, {})",
+                    browse_name_var,
+                    display_name_var,
+                    data_type,
+                    is_abstract,
+                    node.attribute("ValueRank").unwrap_or("-1")
+                )
+            }
+            "Method" => format!(
+                "Method::new(&node_id, {}, {}, true, true)",
+                browse_name_var, display_name_var
+            ),
+            node_class => {
+                return Err(Error::Schema(format!(
+                    "Unsupported node class {}",
+                    node_class
+                )))
+            }
+        };
+
+        let node_id = node
+            .attribute("NodeId")
+            .ok_or_else(|| Error::Schema(format!("{} has no NodeId", browse_name)))?;
+        let _ = writeln!(
+            contents,
+            "{}let node_id = {};",
+            INDENT,
+            self.node_id_ctor(node_id)?
+        );
+
+        let description = util::child(node, "Description")
+            .map(util::text)
+            .unwrap_or("");
+        if description.is_empty() {
+            let _ = writeln!(contents, "{}let node = {};", INDENT, node_ctor);
+        } else {
+            let _ = writeln!(contents, "{}let mut node = {};", INDENT, node_ctor);
+            let _ = writeln!(
+                contents,
+                "{}node.set_description(LocalizedText::from({}));",
+                INDENT,
+                util::quote(description)
+            );
+        }
+
+        let references = self.references(node)?;
+        if references.is_empty() {
+            let _ = writeln!(
+                contents,
+                "{}let _ = address_space.insert::<{}, ReferenceTypeId>(node, None);",
+                INDENT, node_class
+            );
+        } else {
+            let _ = writeln!(
+                contents,
+                "{}let _ = address_space.insert(node, Some(&[",
+                INDENT
+            );
+            for reference in references {
+                let _ = writeln!(contents, "{}    {},", INDENT, reference);
+            }
+            let _ = writeln!(contents, "{}]));", INDENT);
+        }
+        contents.push_str("}\n\n");
+        Ok(())
+    }
+
+    /// Returns the references of a node as tuples of the other node, reference type and direction
+    fn references(&self, node: Node) -> Result<Vec<String>, Error> {
+        let mut references = Vec::new();
+        for reference in util::child(node, "References")
+            .into_iter()
+            .flat_map(|references| util::children(references, "Reference"))
+        {
+            let is_forward = reference.attribute("IsForward").unwrap_or("true") == "true";
+            let node_other = self.node_id_ctor(util::text(reference))?;
+            let reference_type = reference.attribute("ReferenceType").unwrap_or("");
+            // Reference types which are node ids rather than names are not supported yet
+            if NodeIdSnippet::parse(reference_type).is_none() {
+                references.push(format!(
+                    "(&{}, &ReferenceTypeId::{}, ReferenceDirection::{})",
+                    node_other,
+                    reference_type,
+                    if is_forward { "Forward" } else { "Inverse" }
+                ));
+            }
+        }
+        Ok(references)
+    }
+}
@@ -0,0 +1,188 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Generates `status_codes.rs` of `opcua-types` from `Opc.Ua.StatusCodes.csv`.
+
+use std::fmt::Write;
+
+use crate::{util, Error, GeneratedFile, COPYRIGHT};
+
+struct StatusCode {
+    name: String,
+    code: u32,
+    hex_code: String,
+    description: String,
+}
+
+/// Writes a hex code of the form `0xNNNNNNNN` as `0xNNNN_NNNN`
+fn hex_code(value: &str) -> String {
+    if value.len() == 10 {
+        format!("{}_{}", &value[..6], &value[6..])
+    } else {
+        value.to_string()
+    }
+}
+
+/// Generates `status_codes.rs` from the contents of `Opc.Ua.StatusCodes.csv`
+pub fn generate(csv: &str) -> Result<GeneratedFile, Error> {
+    let mut status_codes = vec![StatusCode {
+        name: "Good".to_string(),
+        code: 0,
+        hex_code: "0".to_string(),
+        description: "Good".to_string(),
+    }];
+    for fields in util::parse_csv(csv) {
+        if fields.len() != 3 {
+            return Err(Error::Schema(format!(
+                "Status code line has {} fields",
+                fields.len()
+            )));
+        }
+        let code = u32::from_str_radix(fields[1].trim_start_matches("0x"), 16)
+            .map_err(|_| Error::Schema(format!("Status code {} is invalid", fields[1])))?;
+        status_codes.push(StatusCode {
+            name: fields[0].clone(),
+            code,
+            hex_code: fields[1].clone(),
+            description: fields[2].clone(),
+        });
+    }
+    status_codes.sort_by_key(|status_code| status_code.code);
+
+    let mut contents = String::new();
+    let _ = write!(
+        contents,
+        r#"{}
+// This file was autogenerated from Opc.Ua.StatusCodes.csv by tools/codegen
+// DO NOT EDIT THIS FILE
+#![allow(non_upper_case_globals)]
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use std::str::FromStr;
+
+// This file contains the machine-generated values of StatusCode. Refer to status_code.rs for the hand implemented
+// components.
+
+bitflags! {{
+    pub struct StatusCode: u32 {{
+        // The UPPERCASE values are bitflags. The PascalCase values are OPC UA Status codes.
+
+        // Mask for the status code section
+        const STATUS_MASK = 0xffff_0000;
+        // Mask for the bits section
+        const BIT_MASK = 0x0000_ffff;
+
+        // Flag for an error / uncertain code
+        const IS_ERROR                = 0x8000_0000;
+        const IS_UNCERTAIN            = 0x4000_0000;
+
+        // Historian bits 0:4
+        const HISTORICAL_RAW          = 0x0000_0000;
+        const HISTORICAL_CALCULATED   = 0x0000_0001;
+        const HISTORICAL_INTERPOLATED = 0x0000_0002;
+        const HISTORICAL_RESERVED     = 0x0000_0003;
+        const HISTORICAL_PARTIAL      = 0x0000_0004;
+        const HISTORICAL_EXTRA_DATA   = 0x0000_0008;
+        const HISTORICAL_MULTI_VALUE  = 0x0000_0010;
+        // Overflow bit 7
+        const OVERFLOW                = 0x0000_0080;
+        // Limit bits 8:9
+        const LIMIT_LOW               = 0x0000_0100;
+        const LIMIT_HIGH              = 0x0000_0200;
+        const LIMIT_CONSTANT          = 0x0000_0300;
+        // Info type bits 10:11
+        const LIMIT_DATA_VALUE        = 0x0000_2000;
+        // Semantics changed bit 14
+        const SEMANTICS_CHANGED       = 0x0000_4000;
+        // Semantics changed bit 15
+        const STRUCTURE_CHANGED       = 0x0000_8000;
+
+        // Actual status codes follow here
+"#,
+        COPYRIGHT
+    );
+    for status_code in &status_codes {
+        let _ = writeln!(
+            contents,
+            "        const {} = {};",
+            status_code.name,
+            hex_code(&status_code.hex_code)
+        );
+    }
+    contents.push_str(
+        r#"    }
+}
+
+impl StatusCode {
+
+    /// Returns the descriptive name for the status code, e.g. to put a meaningful code in a log file
+    pub fn name(self) -> &'static str {
+        match self.status() {
+"#,
+    );
+    for status_code in &status_codes {
+        let _ = writeln!(
+            contents,
+            "            StatusCode::{} => {},",
+            status_code.name,
+            util::quote(&status_code.name)
+        );
+    }
+    contents.push_str(
+        r#"            _ => "Unrecognized status code",
+        }
+    }
+
+    /// Returns the descriptive text for the status code
+    pub fn description(self) -> &'static str {
+        match self.status() {
+"#,
+    );
+    for status_code in &status_codes {
+        let _ = writeln!(
+            contents,
+            "            StatusCode::{} => {},",
+            status_code.name,
+            util::quote(&status_code.description)
+        );
+    }
+    contents.push_str(
+        r#"            _ => "Unrecognized status code",
+        }
+    }
+
+    /// Takes an OPC UA status code as a UInt32 and returns the matching StatusCode, assuming there is one
+    /// Note that this is lossy since any bits associated with the status code will be ignored.
+    pub fn from_u32(code: u32) -> Option<StatusCode> {
+        StatusCode::from_bits(code)
+    }
+}
+
+
+impl FromStr for StatusCode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+"#,
+    );
+    for status_code in &status_codes {
+        let _ = writeln!(
+            contents,
+            "            {} => Ok(StatusCode::{}),",
+            util::quote(&status_code.name),
+            status_code.name
+        );
+    }
+    contents.push_str(
+        r#"            _ => Err(())
+        }
+    }
+}
+
+"#,
+    );
+    Ok(GeneratedFile::new("status_codes.rs", contents))
+}
@@ -0,0 +1,315 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Generates `supported_message.rs` of `opcua-core`, which holds the `SupportedMessage` enum that
+//! can hold any service request or response.
+
+use std::fmt::Write;
+
+use crate::{GeneratedFile, COPYRIGHT};
+
+/// The messages which are processed by `SupportedMessage`, i.e. which implement
+/// `Into<SupportedMessage>`
+pub const MESSAGE_TYPES: &[&str] = &[
+    // A service fault, returned when the service failed
+    "ServiceFault",
+    // Secure channel service
+    "OpenSecureChannelRequest",
+    "OpenSecureChannelResponse",
+    "CloseSecureChannelRequest",
+    "CloseSecureChannelResponse",
+    // Discovery service
+    "GetEndpointsRequest",
+    "GetEndpointsResponse",
+    "FindServersRequest",
+    "FindServersResponse",
+    "RegisterServerRequest",
+    "RegisterServerResponse",
+    "RegisterServer2Request",
+    "RegisterServer2Response",
+    // Session service
+    "CreateSessionRequest",
+    "CreateSessionResponse",
+    "CloseSessionRequest",
+    "CloseSessionResponse",
+    "CancelRequest",
+    "CancelResponse",
+    "ActivateSessionRequest",
+    "ActivateSessionResponse",
+    // Node management service
+    "AddNodesRequest",
+    "AddNodesResponse",
+    "AddReferencesRequest",
+    "AddReferencesResponse",
+    "DeleteNodesRequest",
+    "DeleteNodesResponse",
+    "DeleteReferencesRequest",
+    "DeleteReferencesResponse",
+    // MonitoredItem service
+    "CreateMonitoredItemsRequest",
+    "CreateMonitoredItemsResponse",
+    "ModifyMonitoredItemsRequest",
+    "ModifyMonitoredItemsResponse",
+    "DeleteMonitoredItemsRequest",
+    "DeleteMonitoredItemsResponse",
+    "SetMonitoringModeRequest",
+    "SetMonitoringModeResponse",
+    "SetTriggeringRequest",
+    "SetTriggeringResponse",
+    // Subscription service
+    "CreateSubscriptionRequest",
+    "CreateSubscriptionResponse",
+    "ModifySubscriptionRequest",
+    "ModifySubscriptionResponse",
+    "DeleteSubscriptionsRequest",
+    "DeleteSubscriptionsResponse",
+    "TransferSubscriptionsRequest",
+    "TransferSubscriptionsResponse",
+    "SetPublishingModeRequest",
+    "SetPublishingModeResponse",
+    // Query service
+    "QueryFirstRequest",
+    "QueryFirstResponse",
+    "QueryNextRequest",
+    "QueryNextResponse",
+    // View service
+    "BrowseRequest",
+    "BrowseResponse",
+    "BrowseNextRequest",
+    "BrowseNextResponse",
+    "PublishRequest",
+    "PublishResponse",
+    "RepublishRequest",
+    "RepublishResponse",
+    "TranslateBrowsePathsToNodeIdsRequest",
+    "TranslateBrowsePathsToNodeIdsResponse",
+    "RegisterNodesRequest",
+    "RegisterNodesResponse",
+    "UnregisterNodesRequest",
+    "UnregisterNodesResponse",
+    // Attribute service
+    "ReadRequest",
+    "ReadResponse",
+    "HistoryReadRequest",
+    "HistoryReadResponse",
+    "WriteRequest",
+    "WriteResponse",
+    "HistoryUpdateRequest",
+    "HistoryUpdateResponse",
+    // Method service
+    "CallRequest",
+    "CallResponse",
+];
+
+fn is_request(message_type: &str) -> bool {
+    message_type.ends_with("Request")
+}
+
+fn is_response(message_type: &str) -> bool {
+    message_type.ends_with("Response") || message_type == "ServiceFault"
+}
+
+/// Generates `supported_message.rs` for the message types
+pub fn generate(message_types: &[&str]) -> GeneratedFile {
+    let mut contents = format!(
+        "{}\n// This file was autogenerated by tools/codegen\n",
+        COPYRIGHT
+    );
+    contents.push_str(
+        r#"// DO NOT EDIT THIS FILE
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use std::io::{Read, Write};
+
+use opcua_types::{
+    encoding::*,
+    node_id::NodeId,
+    node_ids::ObjectId,
+    request_header::RequestHeader,
+    response_header::ResponseHeader,
+    service_types::*,
+};
+
+pub use crate::comms::tcp_types::AcknowledgeMessage;
+
+/// This macro helps avoid tedious repetition as new messages are added
+/// The first form just handles the trailing comma after the last entry to save some pointless
+/// editing when new messages are added to the list.
+macro_rules! supported_messages_enum {
+    [ $( $x:ident, ) * ] => (supported_messages_enum![ $( $x ),* ];);
+    [ $( $x:ident ), * ] => {
+        #[derive(Debug, PartialEq, Clone)]
+        pub enum SupportedMessage {
+            /// An invalid request / response of some form
+            Invalid(ObjectId),
+            /// Acknowledge message
+            AcknowledgeMessage(Box<AcknowledgeMessage>),
+            /// Other messages
+            $( $x(Box<$x>), )*
+        }
+
+        impl BinaryEncoder <SupportedMessage> for SupportedMessage {
+            fn byte_len(&self) -> usize {
+                match self {
+                    SupportedMessage::Invalid(object_id) => {
+                        panic!("Unsupported message byte_len {:?}", object_id);
+                    },
+                    SupportedMessage::AcknowledgeMessage(value) => value.byte_len(),
+                    $( SupportedMessage::$x(value) => value.byte_len(), )*
+                }
+            }
+
+            fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+                match self {
+                    SupportedMessage::Invalid(object_id) => {
+                        panic!("Unsupported message encode {:?}", object_id);
+                    },
+                    SupportedMessage::AcknowledgeMessage(value) => value.encode(stream),
+                    $( SupportedMessage::$x(value) => value.encode(stream), )*
+                }
+            }
+
+            fn decode<S: Read>(_: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
+                // THIS WILL NOT DO ANYTHING
+                panic!("Cannot decode a stream to a supported message type");
+            }
+        }
+
+        impl Into<SupportedMessage> for AcknowledgeMessage{
+            fn into(self) -> SupportedMessage { SupportedMessage::AcknowledgeMessage(Box::new(self)) }
+        }
+
+        $(
+        impl Into<SupportedMessage> for $x {
+            fn into(self) -> SupportedMessage { SupportedMessage::$x(Box::new(self)) }
+        }
+        )*
+
+        impl SupportedMessage {
+            pub fn node_id(&self) -> NodeId {
+                match self {
+                    SupportedMessage::Invalid(object_id) => {
+                        panic!("Unsupported message invalid, node_id {:?}", object_id);
+                    },
+                    SupportedMessage::AcknowledgeMessage(value) => {
+                        panic!("Unsupported message node_id {:?}", value);
+                    },
+                    $( SupportedMessage::$x(value) => value.object_id().into(), )*
+                }
+            }
+        }
+    }
+}
+
+impl SupportedMessage {
+    pub fn request_handle(&self) -> u32 {
+        if self.is_request() {
+            self.request_header().request_handle
+        } else if self.is_response() {
+            self.response_header().request_handle
+        } else {
+            0
+        }
+    }
+
+    pub fn is_request(&self) -> bool {
+        match self {
+"#,
+    );
+    for message_type in message_types.iter().filter(|m| is_request(m)) {
+        let _ = writeln!(
+            contents,
+            "            SupportedMessage::{}(_) => true,",
+            message_type
+        );
+    }
+    contents.push_str(
+        r#"            _ => false,
+        }
+    }
+
+    pub fn request_header(&self) -> &RequestHeader {
+        match self {
+"#,
+    );
+    for message_type in message_types.iter().filter(|m| is_request(m)) {
+        let _ = writeln!(
+            contents,
+            "            SupportedMessage::{}(r) => &r.request_header,",
+            message_type
+        );
+    }
+    contents.push_str(
+        r#"            _ => panic!()
+        }
+    }
+
+    pub fn is_response(&self) -> bool {
+        match self {
+"#,
+    );
+    for message_type in message_types.iter().filter(|m| is_response(m)) {
+        let _ = writeln!(
+            contents,
+            "            SupportedMessage::{}(_) => true,",
+            message_type
+        );
+    }
+    contents.push_str(
+        r#"            _ => false,
+        }
+    }
+
+    pub fn response_header(&self) -> &ResponseHeader {
+        match self {
+"#,
+    );
+    for message_type in message_types.iter().filter(|m| is_response(m)) {
+        let _ = writeln!(
+            contents,
+            "            SupportedMessage::{}(r) => &r.response_header,",
+            message_type
+        );
+    }
+    contents.push_str(
+        r#"            _ => panic!()
+        }
+    }
+
+    pub fn decode_by_object_id<S: Read>(stream: &mut S, object_id: ObjectId, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        trace!("decoding object_id {:?}", object_id);
+        let decoded_message = match object_id {
+"#,
+    );
+    for message_type in message_types {
+        let _ = write!(
+            contents,
+            r#"            ObjectId::{0}_Encoding_DefaultBinary => {{
+                {0}::decode(stream, decoding_limits)?.into()
+            }}
+"#,
+            message_type
+        );
+    }
+    contents.push_str(
+        r#"            _ => {
+                debug!("decoding unsupported for object id {:?}", object_id);
+                SupportedMessage::Invalid(object_id)
+            }
+        };
+        Ok(decoded_message)
+    }
+}
+
+// These are all the messages handled into and out of streams by the OPCUA server / client code
+supported_messages_enum![
+"#,
+    );
+    for message_type in message_types {
+        let _ = writeln!(contents, "    {},", message_type);
+    }
+    contents.push_str("];\n");
+    GeneratedFile::new("supported_message.rs", contents)
+}
@@ -0,0 +1,738 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Generates the service types of `opcua-types` from `Opc.Ua.Types.bsd`. Each structured type
+//! becomes a struct in its own file with implementations of `BinaryEncoder`, `JsonEncoder` and
+//! `JsonDecoder`, field names are converted to snake case and enumerated types are written to
+//! `enums.rs`. `json_bodies.rs` maps the types which can be held by an extension object to their
+//! data type and encoding ids so their bodies can be written as JSON.
+
+use std::fmt::Write;
+
+use roxmltree::{Document, Node};
+
+use crate::{util, Error, GeneratedFile, COPYRIGHT};
+
+/// Types which are handwritten so are not generated
+const IGNORED_TYPES: &[&str] = &[
+    "ExtensionObject",
+    "DataValue",
+    "LocalizedText",
+    "QualifiedName",
+    "DiagnosticInfo",
+    "Variant",
+    "ExpandedNodeId",
+    "NodeId",
+    "ByteStringNodeId",
+    "GuidNodeId",
+    "StringNodeId",
+    "NumericNodeId",
+    "FourByteNodeId",
+    "TwoByteNodeId",
+    "XmlElement",
+    "Union",
+    "RequestHeader",
+    "ResponseHeader",
+    "Node",
+    "InstanceNode",
+    "TypeNode",
+    "ObjectNode",
+    "ObjectTypeNode",
+    "VariableNode",
+    "VariableTypeNode",
+    "ReferenceTypeNode",
+    "MethodNode",
+    "ViewNode",
+    "DataTypeNode",
+    "ReferenceNode",
+];
+
+/// The modules of the handwritten types which generated types refer to
+const BASIC_TYPES_IMPORT_MAP: &[(&str, &[&str])] = &[
+    ("string", &["UAString", "XmlElement"]),
+    ("byte_string", &["ByteString"]),
+    ("variant", &["Variant"]),
+    ("guid", &["Guid"]),
+    ("localized_text", &["LocalizedText"]),
+    ("qualified_name", &["QualifiedName"]),
+    ("diagnostic_info", &["DiagnosticInfo"]),
+    ("extension_object", &["ExtensionObject"]),
+    ("data_types", &["Duration", "UtcTime"]),
+    ("request_header", &["RequestHeader"]),
+    ("response_header", &["ResponseHeader"]),
+    (
+        "service_types::enums",
+        &[
+            "MessageSecurityMode",
+            "MonitoringMode",
+            "TimestampsToReturn",
+            "FilterOperator",
+            "BrowseDirection",
+            "NodeClass",
+            "SecurityTokenRequestType",
+            "ApplicationType",
+            "UserTokenType",
+            "DataChangeTrigger",
+            "HistoryUpdateType",
+            "PerformUpdateType",
+            "ServerState",
+            "AxisScaleEnumeration",
+            "BrokerTransportQualityOfService",
+            "JsonDataSetMessageContentMask",
+            "JsonNetworkMessageContentMask",
+            "DataSetFieldContentMask",
+            "DataSetFieldFlags",
+            "UadpDataSetMessageContentMask",
+            "UadpNetworkMessageContentMask",
+            "OverrideValueHandling",
+            "DataSetOrderingType",
+            "PermissionType",
+            "StructureType",
+            "IdentityCriteriaType",
+        ],
+    ),
+    ("node_id", &["NodeId", "ExpandedNodeId"]),
+    ("data_value", &["DataValue"]),
+    ("date_time", &["DateTime"]),
+    ("status_codes", &["StatusCode"]),
+];
+
+/// Types which also derive `Serialize`
+const SERDE_SUPPORTED_TYPES: &[&str] = &[
+    "ReadValueId",
+    "DataChangeFilter",
+    "EventFilter",
+    "SimpleAttributeOperand",
+    "ContentFilter",
+    "ContentFilterElement",
+    "MonitoredItemNotification",
+    "ServerDiagnosticsSummaryDataType",
+    "EventFieldList",
+    "DataChangeTrigger",
+    "FilterOperator",
+    "TimestampsToReturn",
+    "MonitoringMode",
+];
+
+/// Maps the OPC UA built-in types onto their Rust types
+fn massage_type_name(name: &str) -> &str {
+    match name {
+        "String" => "UAString",
+        "Boolean" => "bool",
+        "SByte" => "i8",
+        "Byte" => "u8",
+        "Int16" => "i16",
+        "UInt16" => "u16",
+        "Int32" => "i32",
+        "UInt32" => "u32",
+        "Int64" => "i64",
+        "UInt64" => "u64",
+        "Float" => "f32",
+        "Double" => "f64",
+        name => name,
+    }
+}
+
+fn basic_type_module(type_name: &str) -> Option<&'static str> {
+    BASIC_TYPES_IMPORT_MAP
+        .iter()
+        .find(|(_, types)| types.contains(&type_name))
+        .map(|(module, _)| *module)
+}
+
+const HEADER: &str = "
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
+// DO NOT EDIT THIS FILE
+";
+
+struct Field {
+    name: String,
+    /// The name of the field in the schema, which is its name in JSON
+    json_name: String,
+    /// The type of the field which is `Option<Vec<T>>` for an array
+    field_type: String,
+    /// The type of the field, or of the elements of an array
+    contained_type: String,
+    is_array: bool,
+}
+
+struct StructuredType {
+    name: String,
+    fields: Vec<Field>,
+    /// Fields which hold the length of an array and are not written out
+    hidden_fields: Vec<String>,
+    documentation: Option<String>,
+    base_type: Option<String>,
+}
+
+impl StructuredType {
+    fn visible_fields(&self) -> impl Iterator<Item = &Field> {
+        self.fields
+            .iter()
+            .filter(move |field| !self.hidden_fields.contains(&field.name))
+    }
+}
+
+struct EnumeratedType {
+    name: String,
+    documentation: Option<String>,
+    values: Vec<(String, String)>,
+}
+
+/// Returns the documentation of a type, joining the text of each documentation element
+fn documentation(node: Node) -> Option<String> {
+    let documentation = util::children(node, "Documentation")
+        .map(util::text)
+        .collect::<Vec<&str>>();
+    if documentation.is_empty() {
+        None
+    } else {
+        Some(documentation.join(","))
+    }
+}
+
+fn attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str, Error> {
+    node.attribute(name).ok_or_else(|| {
+        Error::Schema(format!(
+            "{} element is missing the {} attribute",
+            node.tag_name().name(),
+            name
+        ))
+    })
+}
+
+fn parse(bsd: &str) -> Result<(Vec<StructuredType>, Vec<EnumeratedType>), Error> {
+    let document = Document::parse(bsd)?;
+    let root = document.root_element();
+
+    let mut structured_types = Vec::new();
+    for element in util::children(root, "StructuredType") {
+        let name = attribute(element, "Name")?;
+        if IGNORED_TYPES.contains(&name) {
+            continue;
+        }
+        let mut fields = Vec::new();
+        let mut hidden_fields = Vec::new();
+        for field in util::children(element, "Field") {
+            let json_name = attribute(field, "Name")?.to_string();
+            let field_name = util::snake_case(&json_name);
+            // Strip the namespace prefix off the type
+            let type_name = attribute(field, "TypeName")?;
+            let type_name = massage_type_name(
+                type_name
+                    .find(':')
+                    .map(|i| &type_name[i + 1..])
+                    .unwrap_or(""),
+            );
+            if let Some(length_field) = field.attribute("LengthField") {
+                fields.push(Field {
+                    name: field_name,
+                    json_name,
+                    field_type: format!("Option<Vec<{}>>", type_name),
+                    contained_type: type_name.to_string(),
+                    is_array: true,
+                });
+                hidden_fields.push(util::snake_case(length_field));
+            } else {
+                fields.push(Field {
+                    name: field_name,
+                    json_name,
+                    field_type: type_name.to_string(),
+                    contained_type: type_name.to_string(),
+                    is_array: false,
+                });
+            }
+        }
+        structured_types.push(StructuredType {
+            name: name.to_string(),
+            fields,
+            hidden_fields,
+            documentation: documentation(element),
+            base_type: element.attribute("BaseType").map(|v| v.to_string()),
+        });
+    }
+
+    let mut enums = Vec::new();
+    for element in util::children(root, "EnumeratedType") {
+        let values = util::children(element, "EnumeratedValue")
+            .map(|value| {
+                Ok((
+                    attribute(value, "Name")?.to_string(),
+                    attribute(value, "Value")?.to_string(),
+                ))
+            })
+            .collect::<Result<Vec<(String, String)>, Error>>()?;
+        enums.push(EnumeratedType {
+            name: attribute(element, "Name")?.to_string(),
+            documentation: documentation(element),
+            values,
+        });
+    }
+
+    Ok((structured_types, enums))
+}
+
+/// Generates the files of the service types from the contents of `Opc.Ua.Types.bsd`. The files are
+/// `mod.rs`, `enums.rs`, `json_bodies.rs` and one file per structured type.
+pub fn generate(bsd: &str) -> Result<Vec<GeneratedFile>, Error> {
+    let (structured_types, enums) = parse(bsd)?;
+    let mut files = vec![generate_types_mod(&structured_types)];
+    files.extend(
+        structured_types.iter().map(|structured_type| {
+            generate_structured_type_file(&structured_types, structured_type)
+        }),
+    );
+    files.push(generate_enum_types(&enums));
+    files.push(generate_json_bodies(&structured_types));
+    Ok(files)
+}
+
+/// Tests if a structured type can be held by an extension object, in which case it has binary
+/// and JSON encoding ids
+fn is_extension_object(structured_type: &StructuredType) -> bool {
+    structured_type.base_type.as_deref() == Some("ua:ExtensionObject")
+}
+
+fn generate_types_mod(structured_types: &[StructuredType]) -> GeneratedFile {
+    let mut contents = format!(
+        r#"{}{}
+// The mods below are handwritten
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+mod enums;
+mod impls;
+
+pub use self::enums::*;
+pub use self::impls::*;
+
+// All of the remaining are generated by script
+
+pub(crate) mod json_bodies;
+
+"#,
+        COPYRIGHT, HEADER
+    );
+    for structured_type in structured_types {
+        let _ = writeln!(contents, "mod {};", util::snake_case(&structured_type.name));
+    }
+    contents.push('\n');
+    for structured_type in structured_types {
+        let _ = writeln!(
+            contents,
+            "pub use self::{}::*;",
+            util::snake_case(&structured_type.name)
+        );
+    }
+    GeneratedFile::new("mod.rs", contents)
+}
+
+fn generate_enum_types(enums: &[EnumeratedType]) -> GeneratedFile {
+    let mut contents = format!(
+        r#"{}{}#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use std::io::{{Read, Write}};
+
+use crate::encoding::*;
+use crate::json::*;
+use crate::status_codes::StatusCode;
+
+// All enums assumed to be i32 length in bits when encoded. In JSON they are their number, or
+// their name and number in the non-reversible form.
+"#,
+        COPYRIGHT, HEADER
+    );
+
+    for enum_type in enums {
+        contents.push('\n');
+        if let Some(ref documentation) = enum_type.documentation {
+            let _ = write!(contents, "/// {}", documentation);
+        }
+        let mut derivations = "Debug, Copy, Clone, PartialEq".to_string();
+        if SERDE_SUPPORTED_TYPES.contains(&enum_type.name.as_str()) {
+            derivations.push_str(", Serialize");
+        }
+        let _ = write!(
+            contents,
+            "\n#[derive({})]\npub enum {} {{",
+            derivations, enum_type.name
+        );
+        for (name, value) in &enum_type.values {
+            let _ = write!(contents, "\n    {} = {},", name, value);
+        }
+        let _ = write!(
+            contents,
+            r#"
+}}
+
+impl BinaryEncoder<{0}> for {0} {{
+    fn byte_len(&self) -> usize {{
+        4
+    }}
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {{
+        write_i32(stream, *self as i32)
+    }}
+
+    fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {{
+        let value = read_i32(stream)?;
+        match value {{"#,
+            enum_type.name
+        );
+        for (name, value) in &enum_type.values {
+            let _ = write!(contents, "\n            {} => Ok(Self::{}),", value, name);
+        }
+        let _ = write!(
+            contents,
+            r#"
+            v => {{
+                error!("Invalid value {{}} for enum {}", v);
+                Err(StatusCode::BadUnexpectedError)
+            }}
+        }}
+    }}
+}}
+
+impl JsonEncoder for {0} {{
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {{
+        if context.is_reversible() {{
+            JsonValue::from(*self as i32)
+        }} else {{
+            JsonValue::from(format!("{{:?}}_{{}}", self, *self as i32))
+        }}
+    }}
+}}
+
+impl JsonDecoder for {0} {{
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {{
+        match read_json_enum(value)? {{"#,
+            enum_type.name
+        );
+        for (name, value) in &enum_type.values {
+            let _ = write!(contents, "\n            {} => Ok(Self::{}),", value, name);
+        }
+        let _ = write!(
+            contents,
+            r#"
+            v => {{
+                error!("Invalid value {{}} for enum {}", v);
+                Err(StatusCode::BadDecodingError)
+            }}
+        }}
+    }}
+}}
+"#,
+            enum_type.name
+        );
+    }
+    GeneratedFile::new("enums.rs", contents)
+}
+
+fn generate_type_imports(
+    structured_types: &[StructuredType],
+    structured_type: &StructuredType,
+    has_message_info: bool,
+) -> String {
+    let mut imports = r#"#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    basic_types::*,
+"#
+    .to_string();
+    if has_message_info {
+        imports.push_str(
+            r#"    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+"#,
+        );
+    }
+
+    // Referenced types are either handwritten or other generated types. Each is imported once in
+    // the order it is first used, with handwritten types grouped by their module.
+    let mut basic_types_to_import: Vec<(&str, Vec<&str>)> = Vec::new();
+    let mut service_types_used: Vec<&str> = Vec::new();
+    for field in structured_type.visible_fields() {
+        let contained_type = field.contained_type.as_str();
+        if let Some(service_type) = structured_types.iter().find(|t| t.name == contained_type) {
+            if !service_types_used.contains(&service_type.name.as_str()) {
+                service_types_used.push(&service_type.name);
+            }
+        } else if let Some(module) = basic_type_module(contained_type) {
+            let type_name = massage_type_name(contained_type);
+            if let Some((_, types)) = basic_types_to_import.iter_mut().find(|(m, _)| *m == module) {
+                if !types.contains(&type_name) {
+                    types.push(type_name);
+                }
+            } else {
+                basic_types_to_import.push((module, vec![type_name]));
+            }
+        }
+    }
+    for (module, types) in basic_types_to_import {
+        for type_name in types {
+            let _ = writeln!(imports, "    {}::{},", module, type_name);
+        }
+    }
+    for service_type in service_types_used {
+        let _ = writeln!(imports, "    service_types::{},", service_type);
+    }
+    imports.push_str("};\n");
+    imports
+}
+
+fn generate_structured_type_file(
+    structured_types: &[StructuredType],
+    structured_type: &StructuredType,
+) -> GeneratedFile {
+    let name = &structured_type.name;
+    let has_message_info = is_extension_object(structured_type);
+
+    let mut contents = format!(
+        r#"{}{}#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use std::io::{{Read, Write}};
+
+"#,
+        COPYRIGHT, HEADER
+    );
+    contents.push_str(&generate_type_imports(
+        structured_types,
+        structured_type,
+        has_message_info,
+    ));
+    contents.push('\n');
+
+    if let Some(ref documentation) = structured_type.documentation {
+        let _ = writeln!(contents, "/// {}", documentation);
+    }
+    let mut derivations = "Debug, Clone, PartialEq".to_string();
+    if SERDE_SUPPORTED_TYPES.contains(&name.as_str()) {
+        derivations.push_str(", Serialize");
+    }
+    let _ = write!(
+        contents,
+        "#[derive({})]\npub struct {} {{\n",
+        derivations, name
+    );
+    for field in structured_type.visible_fields() {
+        let _ = writeln!(contents, "    pub {}: {},", field.name, field.field_type);
+    }
+    contents.push_str("}\n\n");
+
+    if has_message_info {
+        let _ = write!(
+            contents,
+            r#"impl MessageInfo for {0} {{
+    fn object_id(&self) -> ObjectId {{
+        ObjectId::{0}_Encoding_DefaultBinary
+    }}
+}}
+
+"#,
+            name
+        );
+    }
+
+    // Note that the generated code is based on every field, hidden or not, being absent
+    let has_fields = !structured_type.fields.is_empty();
+    let _ = write!(
+        contents,
+        "impl BinaryEncoder<{0}> for {0} {{\n    fn byte_len(&self) -> usize {{\n",
+        name
+    );
+    if has_fields {
+        contents.push_str("        let mut size = 0;\n");
+        for field in structured_type.visible_fields() {
+            if field.is_array {
+                let _ = writeln!(
+                    contents,
+                    "        size += byte_len_array(&self.{});",
+                    field.name
+                );
+            } else {
+                let _ = writeln!(contents, "        size += self.{}.byte_len();", field.name);
+            }
+        }
+        contents.push_str("        size\n");
+    } else {
+        contents.push_str("        0\n");
+    }
+
+    contents.push_str(
+        r#"    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+"#,
+    );
+    if has_fields {
+        contents.push_str("        let mut size = 0;\n");
+        for field in structured_type.visible_fields() {
+            if field.is_array {
+                let _ = writeln!(
+                    contents,
+                    "        size += write_array(stream, &self.{})?;",
+                    field.name
+                );
+            } else {
+                let _ = writeln!(
+                    contents,
+                    "        size += self.{}.encode(stream)?;",
+                    field.name
+                );
+            }
+        }
+        contents.push_str("        Ok(size)\n");
+    } else {
+        contents.push_str("        Ok(0)\n");
+    }
+
+    contents.push_str(
+        r#"    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+"#,
+    );
+    for field in structured_type.visible_fields() {
+        if field.is_array {
+            let _ = writeln!(
+                contents,
+                "        let {}: {} = read_array(stream, decoding_limits)?;",
+                field.name, field.field_type
+            );
+        } else {
+            let _ = writeln!(
+                contents,
+                "        let {} = {}::decode(stream, decoding_limits)?;",
+                field.name, field.field_type
+            );
+        }
+    }
+    let _ = writeln!(contents, "        Ok({} {{", name);
+    for field in structured_type.visible_fields() {
+        let _ = writeln!(contents, "            {},", field.name);
+    }
+    contents.push_str("        })\n    }\n}\n");
+
+    contents.push_str(&generate_json_impls(structured_type));
+
+    GeneratedFile::new(format!("{}.rs", util::snake_case(name)), contents)
+}
+
+/// Generates the `JsonEncoder` and `JsonDecoder` implementations of a structured type, which is a
+/// JSON object with a member for each field
+fn generate_json_impls(structured_type: &StructuredType) -> String {
+    let name = &structured_type.name;
+    let mut contents = format!(
+        r#"
+impl JsonEncoder for {} {{
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {{
+"#,
+        name
+    );
+    if structured_type.visible_fields().next().is_some() {
+        contents.push_str("        let mut object = JsonMap::new();\n");
+        for field in structured_type.visible_fields() {
+            let _ = writeln!(
+                contents,
+                "        write_json_field(&mut object, \"{}\", &self.{}, context);",
+                field.json_name, field.name
+            );
+        }
+        contents.push_str("        JsonValue::Object(object)\n");
+    } else {
+        contents.push_str("        JsonValue::Object(JsonMap::new())\n");
+    }
+    let _ = write!(
+        contents,
+        r#"    }}
+}}
+
+impl JsonDecoder for {} {{
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {{
+        let object = json_object(value)?;
+        Ok({} {{
+"#,
+        name, name
+    );
+    for field in structured_type.visible_fields() {
+        let _ = writeln!(
+            contents,
+            "            {}: read_json_field(object, \"{}\", decoding_limits)?,",
+            field.name, field.json_name
+        );
+    }
+    contents.push_str("        })\n    }\n}\n");
+    contents
+}
+
+/// Generates `json_bodies.rs` which encodes the body of an extension object holding one of the
+/// service types to JSON and back, looking the type up by its binary encoding id or data type id
+fn generate_json_bodies(structured_types: &[StructuredType]) -> GeneratedFile {
+    let types = structured_types
+        .iter()
+        .filter(|structured_type| is_extension_object(structured_type))
+        .map(|structured_type| structured_type.name.as_str())
+        .collect::<Vec<&str>>();
+
+    let mut contents = format!(
+        r#"{}{}#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use std::convert::TryFrom;
+
+use crate::{{
+    encoding::*,
+    json::*,
+    extension_object::ExtensionObject,
+    node_id::{{Identifier, NodeId}},
+    node_ids::{{DataTypeId, ObjectId}},
+    service_types::*,
+}};
+
+/// Encodes the body of an extension object to JSON if the object holds one of the service types,
+/// returning the id of its data type and its JSON
+pub(crate) fn encode_json_body(extension_object: &ExtensionObject, context: &JsonContext) -> Option<(DataTypeId, JsonValue)> {{
+    match extension_object.object_id().ok()? {{
+"#,
+        COPYRIGHT, HEADER
+    );
+    for name in &types {
+        let _ = writeln!(
+            contents,
+            "        ObjectId::{0}_Encoding_DefaultBinary => json_body::<{0}>(extension_object, DataTypeId::{0}, context),",
+            name
+        );
+    }
+    contents.push_str(
+        r#"        _ => None,
+    }
+}
+
+/// Decodes the JSON body of an extension object if its data type is one of the service types,
+/// returning the object with the binary encoding of the body
+pub(crate) fn decode_json_body(data_type_id: &NodeId, body: &JsonValue, decoding_limits: &DecodingLimits) -> Option<EncodingResult<ExtensionObject>> {
+    let data_type_id = match data_type_id.identifier {
+        Identifier::Numeric(id) if data_type_id.namespace == 0 => DataTypeId::try_from(id).ok()?,
+        _ => return None,
+    };
+    match data_type_id {
+"#,
+    );
+    for name in &types {
+        let _ = writeln!(
+            contents,
+            "        DataTypeId::{0} => Some(binary_body::<{0}>(ObjectId::{0}_Encoding_DefaultBinary, body, decoding_limits)),",
+            name
+        );
+    }
+    contents.push_str("        _ => None,\n    }\n}\n");
+    GeneratedFile::new("json_bodies.rs", contents)
+}
@@ -0,0 +1,215 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+use roxmltree::Node;
+
+/// Converts a type or field name to snake case, e.g. `X509IdentityToken` becomes
+/// `x_509_identity_token`. Words are split where the case changes, where an acronym is followed
+/// by a capitalized word and between letters and digits.
+pub fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<char>>();
+    let mut words: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            words.push(chars[start..i].iter().collect());
+        } else if c.is_ascii_uppercase() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_uppercase() {
+                i += 1;
+            }
+            if i < chars.len() && chars[i].is_ascii_lowercase() {
+                // The last capital starts a word, any before it are an acronym
+                if i - start > 1 {
+                    words.push(chars[start..i - 1].iter().collect());
+                }
+                let word_start = i - 1;
+                while i < chars.len() && chars[i].is_ascii_lowercase() {
+                    i += 1;
+                }
+                words.push(chars[word_start..i].iter().collect());
+            } else {
+                words.push(chars[start..i].iter().collect());
+            }
+        } else if c.is_ascii_lowercase() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_lowercase() {
+                i += 1;
+            }
+            words.push(chars[start..i].iter().collect());
+        } else {
+            i += 1;
+        }
+    }
+    words
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<String>>()
+        .join("_")
+}
+
+/// A node id as it appears in a node set, e.g. `ns=1;i=1000`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeIdSnippet {
+    pub namespace: u16,
+    pub id_type: char,
+    pub value: String,
+}
+
+fn is_value_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-'
+}
+
+impl NodeIdSnippet {
+    /// Finds the first node id in the text. Only numeric identifiers and identifiers made of
+    /// letters, digits and hyphens are recognized.
+    pub fn parse(text: &str) -> Option<NodeIdSnippet> {
+        text.char_indices().find_map(|(start, _)| {
+            let rest = &text[start..];
+            // Try with a namespace prefix, then without
+            let with_namespace = if let Some(rest) = rest.strip_prefix("ns=") {
+                let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+                if digits > 0 && rest[digits..].starts_with(';') {
+                    rest[..digits].parse::<u16>().ok().and_then(|namespace| {
+                        Self::parse_identifier(&rest[digits + 1..], namespace)
+                    })
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+            with_namespace.or_else(|| Self::parse_identifier(rest, 0))
+        })
+    }
+
+    fn parse_identifier(text: &str, namespace: u16) -> Option<NodeIdSnippet> {
+        let mut chars = text.chars();
+        let id_type = chars.next()?;
+        if !"ibsg".contains(id_type) || chars.next()? != '=' {
+            return None;
+        }
+        let value = chars.take_while(|c| is_value_char(*c)).collect::<String>();
+        if value.is_empty() {
+            None
+        } else {
+            Some(NodeIdSnippet {
+                namespace,
+                id_type,
+                value,
+            })
+        }
+    }
+}
+
+/// Finds the child elements with the local name
+pub fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Finds the first child element with the local name
+pub fn child<'a, 'input: 'a>(node: Node<'a, 'input>, name: &'a str) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+/// Returns the text of an element, or an empty string if it has none
+pub fn text<'a>(node: Node<'a, '_>) -> &'a str {
+    node.text().unwrap_or("")
+}
+
+/// Splits the lines of a CSV file into their fields. Fields may be quoted, in which case they
+/// can contain commas and quotes are escaped by doubling them.
+pub fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+    csv.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = Vec::new();
+            let mut field = String::new();
+            let mut in_quotes = false;
+            let mut chars = line.chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if in_quotes && chars.peek() == Some(&'"') => {
+                        field.push('"');
+                        chars.next();
+                    }
+                    '"' => in_quotes = !in_quotes,
+                    ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+                    c => field.push(c),
+                }
+            }
+            fields.push(field);
+            fields
+        })
+        .collect()
+}
+
+/// Quotes a string as a Rust string literal
+pub fn quote(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snake_case_names() {
+        assert_eq!(snake_case("Boolean"), "boolean");
+        assert_eq!(snake_case("ArrayDimensions"), "array_dimensions");
+        assert_eq!(
+            snake_case("RegisterServer2Request"),
+            "register_server_2_request"
+        );
+        assert_eq!(snake_case("X509IdentityToken"), "x_509_identity_token");
+        assert_eq!(
+            snake_case("ThreeDCartesianCoordinates"),
+            "three_d_cartesian_coordinates"
+        );
+        assert_eq!(snake_case("EUInformation"), "eu_information");
+        assert_eq!(snake_case("Int32"), "int_32");
+    }
+
+    #[test]
+    fn node_id_snippets() {
+        assert_eq!(
+            NodeIdSnippet::parse("i=2015"),
+            Some(NodeIdSnippet {
+                namespace: 0,
+                id_type: 'i',
+                value: "2015".to_string()
+            })
+        );
+        assert_eq!(
+            NodeIdSnippet::parse("ns=2;s=Foo"),
+            Some(NodeIdSnippet {
+                namespace: 2,
+                id_type: 's',
+                value: "Foo".to_string()
+            })
+        );
+        assert_eq!(NodeIdSnippet::parse("HasComponent"), None);
+        assert_eq!(NodeIdSnippet::parse("Int32"), None);
+    }
+
+    #[test]
+    fn csv_lines() {
+        assert_eq!(
+            parse_csv("Good,0x00000000,\"The operation succeeded, \"\"really\"\".\"\n\nBad,1,x\n"),
+            vec![
+                vec!["Good", "0x00000000", "The operation succeeded, \"really\"."],
+                vec!["Bad", "1", "x"],
+            ]
+        );
+    }
+}
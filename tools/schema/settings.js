@@ -1,7 +0,0 @@
-exports.schema_dir = `${__dirname}/schemas/1.0.4`;
-exports.rs_types_dir = `${__dirname}/../../types/src/service_types`;
-exports.rs_status_codes_dir = `${__dirname}/../../types/src/`;
-exports.rs_node_ids_dir = `${__dirname}/../../types/src/`;
-exports.rs_supported_message_dir = `${__dirname}/../../core/src/`;
-exports.rs_address_space_dir = `${__dirname}/../../server/src/address_space/generated`;
-
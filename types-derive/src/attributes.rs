@@ -0,0 +1,142 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Parses the `#[opcua(...)]` attributes of a type, its fields and its variants.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Error, Lit, LitStr, Meta, NestedMeta, Result};
+
+/// Attributes of the type being derived
+#[derive(Default)]
+pub(crate) struct TypeAttributes {
+    /// `data_type_id = "..."`, the id of the DataType node of the type
+    pub data_type_id: Option<LitStr>,
+    /// `encoding_id = "..."`, the id of the default binary encoding of the type
+    pub encoding_id: Option<LitStr>,
+}
+
+/// Attributes of a field of a structure or a variant of a union
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    /// `optional`, the field is only encoded if it is `Some`
+    pub optional: bool,
+    /// `name = "..."`, the name of the field in the structure definition
+    pub name: Option<LitStr>,
+    /// `data_type = "..."`, the data type of the field in the structure definition
+    pub data_type: Option<LitStr>,
+}
+
+/// Calls the function with each name / value pair and flag of the `#[opcua(...)]` attributes
+fn parse_attributes<F>(attrs: &[Attribute], mut f: F) -> Result<()>
+where
+    F: FnMut(&Meta) -> Result<()>,
+{
+    for attr in attrs.iter().filter(|a| a.path.is_ident("opcua")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(meta) => f(meta)?,
+                        NestedMeta::Lit(lit) => {
+                            return Err(Error::new_spanned(lit, "expected an opcua attribute"))
+                        }
+                    }
+                }
+            }
+            meta => return Err(Error::new_spanned(meta, "expected #[opcua(...)]")),
+        }
+    }
+    Ok(())
+}
+
+fn string_value(meta: &Meta) -> Result<LitStr> {
+    if let Meta::NameValue(name_value) = meta {
+        if let Lit::Str(ref value) = name_value.lit {
+            return Ok(value.clone());
+        }
+    }
+    Err(Error::new_spanned(meta, "expected a string value"))
+}
+
+impl TypeAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<TypeAttributes> {
+        let mut result = TypeAttributes::default();
+        parse_attributes(attrs, |meta| {
+            if meta.path().is_ident("data_type_id") {
+                let value = string_value(meta)?;
+                node_id(&value)?;
+                result.data_type_id = Some(value);
+            } else if meta.path().is_ident("encoding_id") {
+                let value = string_value(meta)?;
+                node_id(&value)?;
+                result.encoding_id = Some(value);
+            } else {
+                return Err(Error::new_spanned(meta, "unknown opcua attribute"));
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+
+    /// Tests if the type describes itself through `DataTypeMetadata`
+    pub fn has_metadata(&self) -> bool {
+        self.data_type_id.is_some() || self.encoding_id.is_some()
+    }
+}
+
+impl FieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> Result<FieldAttributes> {
+        let mut result = FieldAttributes::default();
+        parse_attributes(attrs, |meta| {
+            if meta.path().is_ident("optional") {
+                if let Meta::Path(_) = meta {
+                    result.optional = true;
+                } else {
+                    return Err(Error::new_spanned(meta, "optional does not take a value"));
+                }
+            } else if meta.path().is_ident("name") {
+                result.name = Some(string_value(meta)?);
+            } else if meta.path().is_ident("data_type") {
+                let value = string_value(meta)?;
+                node_id(&value)?;
+                result.data_type = Some(value);
+            } else {
+                return Err(Error::new_spanned(meta, "unknown opcua attribute"));
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+/// Turns a node id of the form `i=123`, `s=Name`, `ns=2;i=123` or `ns=2;s=Name` into an
+/// expression which creates the `NodeId`. The id is checked when the type is compiled.
+pub(crate) fn node_id(value: &LitStr) -> Result<TokenStream> {
+    let text = value.value();
+    let invalid = || {
+        Error::new_spanned(
+            value,
+            "expected a node id such as \"ns=2;i=1000\" or \"ns=2;s=Name\"",
+        )
+    };
+    let (namespace, identifier) = if let Some(text) = text.strip_prefix("ns=") {
+        let mut parts = text.splitn(2, ';');
+        let namespace = parts
+            .next()
+            .and_then(|v| v.parse::<u16>().ok())
+            .ok_or_else(invalid)?;
+        (namespace, parts.next().ok_or_else(invalid)?)
+    } else {
+        (0, text.as_str())
+    };
+    if let Some(id) = identifier.strip_prefix("i=") {
+        let id = id.parse::<u32>().map_err(|_| invalid())?;
+        Ok(quote! { ::opcua_types::NodeId::new(#namespace, #id) })
+    } else if let Some(id) = identifier.strip_prefix("s=") {
+        Ok(quote! { ::opcua_types::NodeId::new(#namespace, #id) })
+    } else {
+        Err(invalid())
+    }
+}
@@ -0,0 +1,242 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Derives `BinaryEncoder` for an enum, which is encoded as an enumeration if its variants hold
+//! no values and as a union if they do.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, DeriveInput, Error, Fields, Result};
+
+use crate::attributes::{FieldAttributes, TypeAttributes};
+
+pub(crate) fn derive(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attributes: &TypeAttributes,
+) -> Result<TokenStream> {
+    if data
+        .variants
+        .iter()
+        .all(|v| matches!(v.fields, Fields::Unit))
+    {
+        derive_enumeration(input, data, attributes)
+    } else {
+        derive_union(input, data, attributes)
+    }
+}
+
+/// An enumeration is encoded as the `Int32` value of the variant
+fn derive_enumeration(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attributes: &TypeAttributes,
+) -> Result<TokenStream> {
+    let name = &input.ident;
+    let variants = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let variant_names = variants.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let metadata = crate::data_type_metadata(
+        input,
+        attributes,
+        quote! {
+            ::opcua_types::service_types::EnumDefinition {
+                fields: Some(vec![#(
+                    ::opcua_types::service_types::EnumField {
+                        value: #name::#variants as i64,
+                        display_name: ::opcua_types::LocalizedText::new("", #variant_names),
+                        description: ::opcua_types::LocalizedText::null(),
+                        name: ::opcua_types::UAString::from(#variant_names),
+                    }
+                ),*]),
+            }
+            .into()
+        },
+    )?;
+
+    Ok(quote! {
+        impl ::opcua_types::BinaryEncoder<#name> for #name {
+            fn byte_len(&self) -> usize {
+                4
+            }
+
+            fn encode<S: ::std::io::Write>(
+                &self,
+                stream: &mut S,
+            ) -> ::opcua_types::EncodingResult<usize> {
+                let value = match self {
+                    #(#name::#variants => #name::#variants as i32),*
+                };
+                ::opcua_types::encoding::write_i32(stream, value)
+            }
+
+            fn decode<S: ::std::io::Read>(
+                stream: &mut S,
+                _: &::opcua_types::DecodingLimits,
+            ) -> ::opcua_types::EncodingResult<Self> {
+                let value = ::opcua_types::encoding::read_i32(stream)?;
+                #(
+                    if value == #name::#variants as i32 {
+                        return Ok(#name::#variants);
+                    }
+                )*
+                Err(::opcua_types::status_code::StatusCode::BadDecodingError)
+            }
+        }
+
+        #metadata
+    })
+}
+
+/// A union is encoded as the 1-based index of the variant followed by its value, or 0 for the
+/// unit variant which stands for no value
+fn derive_union(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attributes: &TypeAttributes,
+) -> Result<TokenStream> {
+    let name = &input.ident;
+    let mut null_variant = None;
+    let mut byte_len = Vec::new();
+    let mut encode = Vec::new();
+    let mut decode = Vec::new();
+    let mut definition_fields = Vec::new();
+    let mut switch_field = 0u32;
+
+    for variant in data.variants.iter() {
+        let ident = &variant.ident;
+        match variant.fields {
+            Fields::Unit => {
+                if null_variant.is_some() {
+                    return Err(Error::new_spanned(
+                        ident,
+                        "a union can only have one variant without a value",
+                    ));
+                }
+                null_variant = Some(ident);
+            }
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                let field_attributes = FieldAttributes::parse(&variant.attrs)?;
+                if field_attributes.optional {
+                    return Err(Error::new_spanned(
+                        ident,
+                        "a variant of a union cannot be optional",
+                    ));
+                }
+                let ty = &fields.unnamed[0].ty;
+                switch_field += 1;
+                let (data_type, value_rank) = if let Some(array_element) = crate::array_element(ty)
+                {
+                    byte_len.push(quote! {
+                            #name::#ident(ref value) => 4 + ::opcua_types::encoding::byte_len_array(value)
+                        });
+                    encode.push(quote! {
+                        #name::#ident(ref value) => {
+                            let size = ::opcua_types::encoding::write_u32(stream, #switch_field)?;
+                            Ok(size + ::opcua_types::encoding::write_array(stream, value)?)
+                        }
+                    });
+                    decode.push(quote! {
+                        #switch_field => Ok(#name::#ident(
+                            ::opcua_types::encoding::read_array(stream, decoding_limits)?,
+                        ))
+                    });
+                    (crate::data_type(array_element, &field_attributes)?, 1i32)
+                } else {
+                    byte_len.push(quote! {
+                            #name::#ident(ref value) => 4 + <#ty as ::opcua_types::BinaryEncoder<#ty>>::byte_len(value)
+                        });
+                    encode.push(quote! {
+                            #name::#ident(ref value) => {
+                                let size = ::opcua_types::encoding::write_u32(stream, #switch_field)?;
+                                Ok(size + <#ty as ::opcua_types::BinaryEncoder<#ty>>::encode(value, stream)?)
+                            }
+                        });
+                    decode.push(quote! {
+                            #switch_field => Ok(#name::#ident(
+                                <#ty as ::opcua_types::BinaryEncoder<#ty>>::decode(stream, decoding_limits)?,
+                            ))
+                        });
+                    (crate::data_type(ty, &field_attributes)?, -1i32)
+                };
+                let field_name = field_attributes
+                    .name
+                    .as_ref()
+                    .map(|name| name.value())
+                    .unwrap_or_else(|| ident.to_string());
+                definition_fields.push(quote! {
+                    ::opcua_types::service_types::StructureField {
+                        name: ::opcua_types::UAString::from(#field_name),
+                        description: ::opcua_types::LocalizedText::null(),
+                        data_type: #data_type,
+                        value_rank: #value_rank,
+                        array_dimensions: None,
+                        max_string_length: 0,
+                        is_optional: false,
+                    }
+                });
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "a variant of a union must hold exactly one unnamed value",
+                ))
+            }
+        }
+    }
+
+    if let Some(null_variant) = null_variant {
+        byte_len.push(quote! { #name::#null_variant => 4 });
+        encode.push(quote! {
+            #name::#null_variant => ::opcua_types::encoding::write_u32(stream, 0u32)
+        });
+        decode.push(quote! { 0 => Ok(#name::#null_variant) });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let metadata = crate::data_type_metadata(
+        input,
+        attributes,
+        quote! {
+            ::opcua_types::service_types::StructureDefinition {
+                default_encoding_id: <Self as ::opcua_types::DataTypeMetadata>::encoding_id(),
+                base_data_type: ::opcua_types::DataTypeId::Union.into(),
+                structure_type: ::opcua_types::service_types::StructureType::Union,
+                fields: Some(vec![#(#definition_fields),*]),
+            }
+            .into()
+        },
+    )?;
+
+    Ok(quote! {
+        impl #impl_generics ::opcua_types::BinaryEncoder<#name #ty_generics> for #name #ty_generics #where_clause {
+            fn byte_len(&self) -> usize {
+                match *self {
+                    #(#byte_len),*
+                }
+            }
+
+            fn encode<S: ::std::io::Write>(
+                &self,
+                stream: &mut S,
+            ) -> ::opcua_types::EncodingResult<usize> {
+                match *self {
+                    #(#encode),*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn decode<S: ::std::io::Read>(
+                stream: &mut S,
+                decoding_limits: &::opcua_types::DecodingLimits,
+            ) -> ::opcua_types::EncodingResult<Self> {
+                match ::opcua_types::encoding::read_u32(stream)? {
+                    #(#decode,)*
+                    _ => Err(::opcua_types::status_code::StatusCode::BadDecodingError),
+                }
+            }
+        }
+
+        #metadata
+    })
+}
@@ -0,0 +1,186 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Derive macros for OPC UA for Rust.
+//!
+//! `#[derive(BinaryEncoder)]` implements `opcua_types::BinaryEncoder` for a structure, a union
+//! or an enumeration declared in Rust, following the same encoding rules as the generated types
+//! in `opcua_types::service_types`:
+//!
+//! * A struct with named fields is a structure whose fields are encoded one after another.
+//!   A field of type `Option<Vec<T>>` is an array. A field marked `#[opcua(optional)]` must be
+//!   an `Option` and makes the type a structure with optional fields, preceded by an encoding
+//!   mask of the fields that are present.
+//! * An enum whose variants each hold one value is a union, preceded by the 1-based index of
+//!   the variant. A single unit variant, e.g. `Null`, stands for a union with no value.
+//! * An enum whose variants hold no values is an enumeration, encoded as an `Int32`.
+//!
+//! A type with `#[opcua(data_type_id = "...")]` and / or `#[opcua(encoding_id = "...")]` also
+//! implements `opcua_types::DataTypeMetadata` so values can be wrapped in an `ExtensionObject`
+//! and the type's `StructureDefinition` or `EnumDefinition` can be given to a DataType node.
+//! The data type of each field in the definition is worked out from the Rust type for the
+//! built-in types, from the `DataTypeMetadata` of a nested type or from
+//! `#[opcua(data_type = "...")]` on the field. Fields are named in the definition by turning
+//! their Rust names into PascalCase unless they have `#[opcua(name = "...")]`.
+//!
+//! ```ignore
+//! use opcua_types::*;
+//! use opcua_types_derive::BinaryEncoder;
+//!
+//! #[derive(BinaryEncoder)]
+//! #[opcua(data_type_id = "ns=2;i=1000", encoding_id = "ns=2;i=1001")]
+//! pub struct Point {
+//!     pub x: f64,
+//!     pub y: f64,
+//!     #[opcua(optional)]
+//!     pub label: Option<UAString>,
+//! }
+//!
+//! let value = Point { x: 1.0, y: 2.0, label: None }.to_extension_object();
+//! ```
+
+extern crate proc_macro;
+
+mod attributes;
+mod enumeration;
+mod structure;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, GenericArgument, PathArguments, Type};
+
+use crate::attributes::{node_id, FieldAttributes, TypeAttributes};
+
+#[proc_macro_derive(BinaryEncoder, attributes(opcua))]
+pub fn derive_binary_encoder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let result = TypeAttributes::parse(&input.attrs).and_then(|attributes| match input.data {
+        Data::Struct(ref data) => structure::derive(&input, data, &attributes),
+        Data::Enum(ref data) => enumeration::derive(&input, data, &attributes),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "BinaryEncoder cannot be derived for a Rust union, use an enum instead",
+        )),
+    });
+    result.unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+/// Returns the type parameter of a type such as `Option<T>` or `Vec<T>`
+fn type_parameter<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    if let Type::Path(ref type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident == wrapper {
+            if let PathArguments::AngleBracketed(ref arguments) = segment.arguments {
+                if let Some(GenericArgument::Type(ref ty)) = arguments.args.first() {
+                    return Some(ty);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns the type of the elements of an array, i.e. `T` of `Option<Vec<T>>`
+fn array_element(ty: &Type) -> Option<&Type> {
+    type_parameter(ty, "Option").and_then(|ty| type_parameter(ty, "Vec"))
+}
+
+/// The built-in types and the ids of their data types
+const BUILT_IN_TYPES: &[(&str, &str)] = &[
+    ("bool", "Boolean"),
+    ("i8", "SByte"),
+    ("u8", "Byte"),
+    ("i16", "Int16"),
+    ("u16", "UInt16"),
+    ("i32", "Int32"),
+    ("u32", "UInt32"),
+    ("i64", "Int64"),
+    ("u64", "UInt64"),
+    ("f32", "Float"),
+    ("f64", "Double"),
+    ("UAString", "String"),
+    ("DateTime", "DateTime"),
+    ("Guid", "Guid"),
+    ("ByteString", "ByteString"),
+    ("XmlElement", "XmlElement"),
+    ("NodeId", "NodeId"),
+    ("ExpandedNodeId", "ExpandedNodeId"),
+    ("StatusCode", "StatusCode"),
+    ("QualifiedName", "QualifiedName"),
+    ("LocalizedText", "LocalizedText"),
+    ("ExtensionObject", "Structure"),
+    ("DataValue", "DataValue"),
+    ("Variant", "BaseDataType"),
+    ("DiagnosticInfo", "DiagnosticInfo"),
+];
+
+/// Returns an expression for the data type of a field or variant in the type's definition
+fn data_type(ty: &Type, attributes: &FieldAttributes) -> syn::Result<TokenStream2> {
+    if let Some(ref data_type) = attributes.data_type {
+        return node_id(data_type);
+    }
+    if let Type::Path(ref type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            let name = segment.ident.to_string();
+            if let Some((_, data_type)) = BUILT_IN_TYPES.iter().find(|(n, _)| *n == name) {
+                let data_type = syn::Ident::new(data_type, segment.ident.span());
+                return Ok(
+                    quote! { ::opcua_types::NodeId::new(0, ::opcua_types::DataTypeId::#data_type as u32) },
+                );
+            }
+        }
+    }
+    Ok(quote! { <#ty as ::opcua_types::DataTypeMetadata>::data_type_id() })
+}
+
+/// Turns the name of a Rust field into the PascalCase of OPC UA
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Returns the implementation of `DataTypeMetadata` if the type has an id
+fn data_type_metadata(
+    input: &DeriveInput,
+    attributes: &TypeAttributes,
+    definition: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    if !attributes.has_metadata() {
+        return Ok(TokenStream2::new());
+    }
+    let null = quote! { ::opcua_types::NodeId::null() };
+    let data_type_id = attributes
+        .data_type_id
+        .as_ref()
+        .map_or_else(|| Ok(null.clone()), node_id)?;
+    let encoding_id = attributes
+        .encoding_id
+        .as_ref()
+        .map_or_else(|| Ok(null.clone()), node_id)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::opcua_types::DataTypeMetadata for #name #ty_generics #where_clause {
+            fn data_type_id() -> ::opcua_types::NodeId {
+                #data_type_id
+            }
+
+            fn encoding_id() -> ::opcua_types::NodeId {
+                #encoding_id
+            }
+
+            fn data_type_definition() -> ::opcua_types::DataTypeDefinition {
+                #definition
+            }
+        }
+    })
+}
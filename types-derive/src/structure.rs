@@ -0,0 +1,241 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Derives `BinaryEncoder` for a struct, which is encoded as a structure or as a structure with
+//! optional fields.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataStruct, DeriveInput, Error, Fields, Result};
+
+use crate::attributes::{FieldAttributes, TypeAttributes};
+
+/// The most optional fields a structure can have, one for each bit of the encoding mask
+const MAX_OPTIONAL_FIELDS: usize = 32;
+
+pub(crate) fn derive(
+    input: &DeriveInput,
+    data: &DataStruct,
+    attributes: &TypeAttributes,
+) -> Result<TokenStream> {
+    let fields = match data.fields {
+        Fields::Named(ref fields) => fields.named.iter().collect::<Vec<_>>(),
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "BinaryEncoder can only be derived for a struct with named fields",
+            ))
+        }
+    };
+
+    let mut byte_len = Vec::with_capacity(fields.len());
+    let mut encode = Vec::with_capacity(fields.len());
+    let mut decode = Vec::with_capacity(fields.len());
+    let mut encoding_mask = Vec::new();
+    let mut definition_fields = Vec::with_capacity(fields.len());
+    let mut field_names = Vec::with_capacity(fields.len());
+    let mut optional_fields = 0;
+
+    for field in fields {
+        let field_attributes = FieldAttributes::parse(&field.attrs)?;
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        field_names.push(ident);
+
+        // The value type of an optional field is what is in the option when it is present
+        let value_ty = if field_attributes.optional {
+            if optional_fields == MAX_OPTIONAL_FIELDS {
+                return Err(Error::new_spanned(
+                    ident,
+                    "a structure cannot have more than 32 optional fields",
+                ));
+            }
+            crate::type_parameter(ty, "Option")
+                .ok_or_else(|| Error::new_spanned(ty, "an optional field must be an Option"))?
+        } else {
+            ty
+        };
+        // Arrays are held as `Option<Vec<T>>` like the generated types. An optional array is
+        // the same, with `None` meaning the field is absent.
+        let array_element = if field_attributes.optional {
+            crate::type_parameter(value_ty, "Vec")
+        } else {
+            crate::array_element(ty)
+        };
+
+        if field_attributes.optional {
+            let bit = optional_fields as u32;
+            optional_fields += 1;
+            encoding_mask.push(quote! {
+                if self.#ident.is_some() {
+                    encoding_mask |= 1 << #bit;
+                }
+            });
+            if array_element.is_some() {
+                byte_len.push(quote! {
+                    if self.#ident.is_some() {
+                        size += ::opcua_types::encoding::byte_len_array(&self.#ident);
+                    }
+                });
+                encode.push(quote! {
+                    if self.#ident.is_some() {
+                        size += ::opcua_types::encoding::write_array(stream, &self.#ident)?;
+                    }
+                });
+                decode.push(quote! {
+                    let #ident: #ty = if encoding_mask & (1 << #bit) != 0 {
+                        ::opcua_types::encoding::read_array(stream, decoding_limits)?
+                    } else {
+                        None
+                    };
+                });
+            } else {
+                byte_len.push(quote! {
+                    if let Some(ref value) = self.#ident {
+                        size += <#value_ty as ::opcua_types::BinaryEncoder<#value_ty>>::byte_len(value);
+                    }
+                });
+                encode.push(quote! {
+                    if let Some(ref value) = self.#ident {
+                        size += <#value_ty as ::opcua_types::BinaryEncoder<#value_ty>>::encode(value, stream)?;
+                    }
+                });
+                decode.push(quote! {
+                    let #ident = if encoding_mask & (1 << #bit) != 0 {
+                        Some(<#value_ty as ::opcua_types::BinaryEncoder<#value_ty>>::decode(
+                            stream,
+                            decoding_limits,
+                        )?)
+                    } else {
+                        None
+                    };
+                });
+            }
+        } else if array_element.is_some() {
+            byte_len.push(quote! {
+                size += ::opcua_types::encoding::byte_len_array(&self.#ident);
+            });
+            encode.push(quote! {
+                size += ::opcua_types::encoding::write_array(stream, &self.#ident)?;
+            });
+            decode.push(quote! {
+                let #ident: #ty = ::opcua_types::encoding::read_array(stream, decoding_limits)?;
+            });
+        } else {
+            byte_len.push(quote! {
+                size += <#ty as ::opcua_types::BinaryEncoder<#ty>>::byte_len(&self.#ident);
+            });
+            encode.push(quote! {
+                size += <#ty as ::opcua_types::BinaryEncoder<#ty>>::encode(&self.#ident, stream)?;
+            });
+            decode.push(quote! {
+                let #ident = <#ty as ::opcua_types::BinaryEncoder<#ty>>::decode(
+                    stream,
+                    decoding_limits,
+                )?;
+            });
+        }
+
+        let name = field_attributes
+            .name
+            .as_ref()
+            .map(|name| name.value())
+            .unwrap_or_else(|| crate::pascal_case(&ident.to_string()));
+        let (data_type, value_rank) = if let Some(array_element) = array_element {
+            (crate::data_type(array_element, &field_attributes)?, 1i32)
+        } else {
+            (crate::data_type(value_ty, &field_attributes)?, -1i32)
+        };
+        let is_optional = field_attributes.optional;
+        definition_fields.push(quote! {
+            ::opcua_types::service_types::StructureField {
+                name: ::opcua_types::UAString::from(#name),
+                description: ::opcua_types::LocalizedText::null(),
+                data_type: #data_type,
+                value_rank: #value_rank,
+                array_dimensions: None,
+                max_string_length: 0,
+                is_optional: #is_optional,
+            }
+        });
+    }
+
+    // A structure with optional fields starts with a mask of the fields which are present
+    let (mask_byte_len, mask_encode, mask_decode, structure_type) = if optional_fields > 0 {
+        (
+            quote! { size += 4; },
+            quote! {
+                let mut encoding_mask = 0u32;
+                #(#encoding_mask)*
+                size += ::opcua_types::encoding::write_u32(stream, encoding_mask)?;
+            },
+            quote! {
+                let encoding_mask = ::opcua_types::encoding::read_u32(stream)?;
+            },
+            quote! { StructureWithOptionalFields },
+        )
+    } else {
+        (
+            TokenStream::new(),
+            TokenStream::new(),
+            TokenStream::new(),
+            quote! { Structure },
+        )
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let construct = match data.fields {
+        Fields::Unit => quote! { #name },
+        _ => quote! { #name { #(#field_names),* } },
+    };
+    let metadata = crate::data_type_metadata(
+        input,
+        attributes,
+        quote! {
+            ::opcua_types::service_types::StructureDefinition {
+                default_encoding_id: <Self as ::opcua_types::DataTypeMetadata>::encoding_id(),
+                base_data_type: ::opcua_types::DataTypeId::Structure.into(),
+                structure_type: ::opcua_types::service_types::StructureType::#structure_type,
+                fields: Some(vec![#(#definition_fields),*]),
+            }
+            .into()
+        },
+    )?;
+
+    Ok(quote! {
+        impl #impl_generics ::opcua_types::BinaryEncoder<#name #ty_generics> for #name #ty_generics #where_clause {
+            fn byte_len(&self) -> usize {
+                let mut size = 0;
+                #mask_byte_len
+                #(#byte_len)*
+                size
+            }
+
+            #[allow(unused_variables)]
+            fn encode<S: ::std::io::Write>(
+                &self,
+                stream: &mut S,
+            ) -> ::opcua_types::EncodingResult<usize> {
+                let mut size = 0;
+                #mask_encode
+                #(#encode)*
+                Ok(size)
+            }
+
+            #[allow(unused_variables)]
+            fn decode<S: ::std::io::Read>(
+                stream: &mut S,
+                decoding_limits: &::opcua_types::DecodingLimits,
+            ) -> ::opcua_types::EncodingResult<Self> {
+                #mask_decode
+                #(#decode)*
+                Ok(#construct)
+            }
+        }
+
+        #metadata
+    })
+}
@@ -7,9 +7,10 @@
 use std::io::{Read, Write};
 
 use crate::{
-    byte_string::ByteString, date_time::*, encoding::*, guid::Guid, localized_text::LocalizedText,
-    node_id::NodeId, qualified_name::QualifiedName, service_types::TimestampsToReturn,
-    status_codes::StatusCode, string::UAString, variant::Variant,
+    byte_string::ByteString, date_time::*, encoding::*, guid::Guid, json::*,
+    localized_text::LocalizedText, node_id::NodeId, qualified_name::QualifiedName,
+    service_types::TimestampsToReturn, status_codes::StatusCode, string::UAString,
+    variant::Variant,
 };
 
 bitflags! {
@@ -166,6 +167,53 @@ impl BinaryEncoder<DataValue> for DataValue {
     }
 }
 
+impl JsonEncoder for DataValue {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Value", &self.value, context);
+        write_json_field(&mut object, "Status", &self.status, context);
+        write_json_field(
+            &mut object,
+            "SourceTimestamp",
+            &self.source_timestamp,
+            context,
+        );
+        write_json_field(
+            &mut object,
+            "SourcePicoseconds",
+            &self.source_picoseconds,
+            context,
+        );
+        write_json_field(
+            &mut object,
+            "ServerTimestamp",
+            &self.server_timestamp,
+            context,
+        );
+        write_json_field(
+            &mut object,
+            "ServerPicoseconds",
+            &self.server_picoseconds,
+            context,
+        );
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DataValue {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DataValue {
+            value: read_json_field(object, "Value", decoding_limits)?,
+            status: read_json_field(object, "Status", decoding_limits)?,
+            source_timestamp: read_json_field(object, "SourceTimestamp", decoding_limits)?,
+            source_picoseconds: read_json_field(object, "SourcePicoseconds", decoding_limits)?,
+            server_timestamp: read_json_field(object, "ServerTimestamp", decoding_limits)?,
+            server_picoseconds: read_json_field(object, "ServerPicoseconds", decoding_limits)?,
+        })
+    }
+}
+
 // It would be nice if everything from here to the ... below could be condensed into a single
 // trait impl somehow because it's more or less duplicating all the code in Variant.
 
@@ -6,7 +6,7 @@
 
 use std::io::{Read, Write};
 
-use crate::{encoding::*, status_codes::StatusCode, string::UAString};
+use crate::{encoding::*, json::*, status_codes::StatusCode, string::UAString};
 
 bitflags! {
     pub struct DiagnosticInfoMask: u8 {
@@ -171,6 +171,58 @@ impl BinaryEncoder<DiagnosticInfo> for DiagnosticInfo {
     }
 }
 
+impl JsonEncoder for DiagnosticInfo {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SymbolicId", &self.symbolic_id, context);
+        write_json_field(&mut object, "NamespaceUri", &self.namespace_uri, context);
+        write_json_field(&mut object, "Locale", &self.locale, context);
+        write_json_field(&mut object, "LocalizedText", &self.localized_text, context);
+        write_json_field(
+            &mut object,
+            "AdditionalInfo",
+            &self.additional_info,
+            context,
+        );
+        write_json_field(
+            &mut object,
+            "InnerStatusCode",
+            &self.inner_status_code,
+            context,
+        );
+        if let Some(ref inner_diagnostic_info) = self.inner_diagnostic_info {
+            write_json_field(
+                &mut object,
+                "InnerDiagnosticInfo",
+                inner_diagnostic_info.as_ref(),
+                context,
+            );
+        }
+        if object.is_empty() {
+            JsonValue::Null
+        } else {
+            JsonValue::Object(object)
+        }
+    }
+}
+
+impl JsonDecoder for DiagnosticInfo {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        let inner_diagnostic_info: Option<DiagnosticInfo> =
+            read_json_field(object, "InnerDiagnosticInfo", decoding_limits)?;
+        Ok(DiagnosticInfo {
+            symbolic_id: read_json_field(object, "SymbolicId", decoding_limits)?,
+            namespace_uri: read_json_field(object, "NamespaceUri", decoding_limits)?,
+            locale: read_json_field(object, "Locale", decoding_limits)?,
+            localized_text: read_json_field(object, "LocalizedText", decoding_limits)?,
+            additional_info: read_json_field(object, "AdditionalInfo", decoding_limits)?,
+            inner_status_code: read_json_field(object, "InnerStatusCode", decoding_limits)?,
+            inner_diagnostic_info: inner_diagnostic_info.map(Box::new),
+        })
+    }
+}
+
 impl Default for DiagnosticInfo {
     fn default() -> Self {
         DiagnosticInfo::null()
@@ -0,0 +1,738 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the implementation of `DataTypeRegistry` and `DynamicStructure`.
+//!
+//! Structured data types which have no Rust type can still be encoded and decoded if their
+//! `StructureDefinition` is known, e.g. from the `DataTypeDefinition` attribute of their DataType
+//! node. The registry holds the definitions and turns the body of an `ExtensionObject` into a
+//! `DynamicStructure` and back again.
+
+use std::{
+    collections::HashMap,
+    io::{Cursor, Read, Write},
+};
+
+use crate::{
+    byte_string::ByteString,
+    encoding::*,
+    extension_object::{ExtensionObject, ExtensionObjectEncoding},
+    node_id::{Identifier, NodeId},
+    node_ids::{DataTypeId, ObjectId},
+    service_types::{
+        EnumDefinition, EnumField, StructureDefinition, StructureField, StructureType,
+    },
+    status_codes::StatusCode,
+    variant::Variant,
+};
+
+/// The definition of a data type as held by the `DataTypeDefinition` attribute of a DataType node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataTypeDefinition {
+    Structure(StructureDefinition),
+    Enum(EnumDefinition),
+}
+
+impl From<StructureDefinition> for DataTypeDefinition {
+    fn from(v: StructureDefinition) -> Self {
+        DataTypeDefinition::Structure(v)
+    }
+}
+
+impl From<EnumDefinition> for DataTypeDefinition {
+    fn from(v: EnumDefinition) -> Self {
+        DataTypeDefinition::Enum(v)
+    }
+}
+
+impl From<&DataTypeDefinition> for Variant {
+    fn from(v: &DataTypeDefinition) -> Self {
+        Variant::from(v.to_extension_object())
+    }
+}
+
+impl DataTypeDefinition {
+    /// Encodes the definition into an extension object, the form it takes as an attribute value
+    pub fn to_extension_object(&self) -> ExtensionObject {
+        match self {
+            DataTypeDefinition::Structure(v) => ExtensionObject::from_encodable(
+                ObjectId::StructureDefinition_Encoding_DefaultBinary,
+                v,
+            ),
+            DataTypeDefinition::Enum(v) => {
+                ExtensionObject::from_encodable(ObjectId::EnumDefinition_Encoding_DefaultBinary, v)
+            }
+        }
+    }
+
+    /// Decodes the definition from an extension object holding a `StructureDefinition` or an
+    /// `EnumDefinition`.
+    pub fn from_extension_object(
+        extension_object: &ExtensionObject,
+        decoding_limits: &DecodingLimits,
+    ) -> EncodingResult<DataTypeDefinition> {
+        match extension_object.object_id() {
+            Ok(ObjectId::StructureDefinition_Encoding_DefaultBinary) => Ok(
+                DataTypeDefinition::Structure(extension_object.decode_inner(decoding_limits)?),
+            ),
+            Ok(ObjectId::EnumDefinition_Encoding_DefaultBinary) => Ok(DataTypeDefinition::Enum(
+                extension_object.decode_inner(decoding_limits)?,
+            )),
+            _ => {
+                error!(
+                    "Extension object {:?} does not hold a data type definition",
+                    extension_object.node_id
+                );
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+/// Describes a Rust type which represents an OPC UA structure or enumeration, so that values of
+/// the type can be wrapped in an `ExtensionObject` and the server can describe the type with a
+/// DataType node. It is implemented by `#[derive(BinaryEncoder)]` from `opcua-types-derive` for
+/// a type with an `#[opcua(data_type_id = "...")]` or `#[opcua(encoding_id = "...")]` attribute.
+pub trait DataTypeMetadata {
+    /// Returns the id of the DataType node of the type
+    fn data_type_id() -> NodeId;
+
+    /// Returns the id of the default binary encoding of the type, which identifies a value of the
+    /// type in an `ExtensionObject`. An enumeration has no encoding and returns a null id.
+    fn encoding_id() -> NodeId;
+
+    /// Returns the definition of the type for the `DataTypeDefinition` attribute of its node
+    fn data_type_definition() -> DataTypeDefinition;
+
+    /// Wraps the value in an `ExtensionObject` using the default binary encoding of the type
+    fn to_extension_object(&self) -> ExtensionObject
+    where
+        Self: BinaryEncoder<Self> + Sized,
+    {
+        ExtensionObject::from_encodable(Self::encoding_id(), self)
+    }
+}
+
+/// A value of a field of a `DynamicStructure`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynamicValue {
+    /// A value of a built-in type, or of any type for a field of an abstract data type
+    Scalar(Variant),
+    /// The value of an enumeration
+    Enum(i32),
+    /// A nested structure
+    Structure(Box<DynamicStructure>),
+    /// An array of values of the field's data type
+    Array(Vec<DynamicValue>),
+}
+
+impl From<Variant> for DynamicValue {
+    fn from(v: Variant) -> Self {
+        DynamicValue::Scalar(v)
+    }
+}
+
+impl From<DynamicStructure> for DynamicValue {
+    fn from(v: DynamicStructure) -> Self {
+        DynamicValue::Structure(Box::new(v))
+    }
+}
+
+/// A named field of a `DynamicStructure`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicField {
+    pub name: String,
+    pub value: DynamicValue,
+}
+
+/// The value of a structured data type whose layout is described by a `StructureDefinition`
+/// rather than by a Rust type.
+///
+/// Only fields which are present are held, so an optional field that is absent is left out, and
+/// a union holds the one field that is set, or no field at all if it is null.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicStructure {
+    pub data_type_id: NodeId,
+    pub fields: Vec<DynamicField>,
+}
+
+impl DynamicStructure {
+    pub fn new<T>(data_type_id: T) -> DynamicStructure
+    where
+        T: Into<NodeId>,
+    {
+        DynamicStructure {
+            data_type_id: data_type_id.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Returns the value of the named field if it is present
+    pub fn field(&self, name: &str) -> Option<&DynamicValue> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| &field.value)
+    }
+
+    /// Sets the value of the named field, replacing any existing value
+    pub fn set_field<T>(&mut self, name: &str, value: T)
+    where
+        T: Into<DynamicValue>,
+    {
+        let value = value.into();
+        if let Some(field) = self.fields.iter_mut().find(|field| field.name == name) {
+            field.value = value;
+        } else {
+            self.fields.push(DynamicField {
+                name: name.to_string(),
+                value,
+            });
+        }
+    }
+
+    /// Sets the value of the named field, consuming and returning the structure
+    pub fn with_field<T>(mut self, name: &str, value: T) -> Self
+    where
+        T: Into<DynamicValue>,
+    {
+        self.set_field(name, value);
+        self
+    }
+}
+
+/// The well known subtypes of built-in types in namespace 0 that are encoded as their base type
+const WELL_KNOWN_SUBTYPES: &[(DataTypeId, DataTypeId)] = &[
+    (DataTypeId::Number, DataTypeId::BaseDataType),
+    (DataTypeId::Integer, DataTypeId::BaseDataType),
+    (DataTypeId::UInteger, DataTypeId::BaseDataType),
+    (DataTypeId::Image, DataTypeId::ByteString),
+    (DataTypeId::ImageBMP, DataTypeId::ByteString),
+    (DataTypeId::ImageGIF, DataTypeId::ByteString),
+    (DataTypeId::ImageJPG, DataTypeId::ByteString),
+    (DataTypeId::ImagePNG, DataTypeId::ByteString),
+    (DataTypeId::AudioDataType, DataTypeId::ByteString),
+    (
+        DataTypeId::ApplicationInstanceCertificate,
+        DataTypeId::ByteString,
+    ),
+    (DataTypeId::IntegerId, DataTypeId::UInt32),
+    (DataTypeId::Counter, DataTypeId::UInt32),
+    (DataTypeId::Index, DataTypeId::UInt32),
+    (DataTypeId::VersionTime, DataTypeId::UInt32),
+    (DataTypeId::BitFieldMaskDataType, DataTypeId::UInt64),
+    (DataTypeId::Duration, DataTypeId::Double),
+    (DataTypeId::Date, DataTypeId::DateTime),
+    (DataTypeId::UtcTime, DataTypeId::DateTime),
+    (DataTypeId::LocaleId, DataTypeId::String),
+    (DataTypeId::NumericRange, DataTypeId::String),
+    (DataTypeId::Time, DataTypeId::String),
+    (DataTypeId::NormalizedString, DataTypeId::String),
+    (DataTypeId::DecimalString, DataTypeId::String),
+    (DataTypeId::DurationString, DataTypeId::String),
+    (DataTypeId::TimeString, DataTypeId::String),
+    (DataTypeId::DateString, DataTypeId::String),
+];
+
+/// Nested structures deeper than this are rejected, e.g. a structure which contains itself
+const MAX_DEPTH: usize = 64;
+
+/// How a field of a given data type is encoded
+enum FieldEncoding<'a> {
+    /// A built-in type, identified by its encoding mask in a variant
+    BuiltIn(u8),
+    /// An abstract type encoded as a variant
+    Variant,
+    /// An enumeration encoded as an Int32
+    Enum,
+    /// A structure encoded field by field
+    Structure(&'a NodeId, &'a StructureDefinition),
+}
+
+/// A registry of data type definitions which encodes and decodes structures of those types.
+///
+/// Definitions are added with the id of the data type they define. The default encoding id of a
+/// structure definition is used to find the data type of an extension object when it is decoded.
+/// Data types that are subtypes of built-in types, e.g. a `String` with a different name, can be
+/// added with `add_subtype()`. Well known subtypes in namespace 0 such as `Duration` are already
+/// known to the registry.
+#[derive(Debug, Clone)]
+pub struct DataTypeRegistry {
+    /// Definitions by the id of their data type
+    definitions: HashMap<NodeId, DataTypeDefinition>,
+    /// Data type ids by the id of their binary encoding
+    encodings: HashMap<NodeId, NodeId>,
+    /// Base types by the id of their subtype
+    subtypes: HashMap<NodeId, NodeId>,
+}
+
+impl Default for DataTypeRegistry {
+    fn default() -> Self {
+        let subtypes = WELL_KNOWN_SUBTYPES
+            .iter()
+            .map(|(subtype, base_type)| (subtype.into(), base_type.into()))
+            .collect();
+        DataTypeRegistry {
+            definitions: HashMap::new(),
+            encodings: HashMap::new(),
+            subtypes,
+        }
+    }
+}
+
+impl DataTypeRegistry {
+    pub fn new() -> DataTypeRegistry {
+        Self::default()
+    }
+
+    /// Adds the definition of a data type, replacing any previous definition
+    pub fn add_definition<T>(&mut self, data_type_id: &NodeId, definition: T)
+    where
+        T: Into<DataTypeDefinition>,
+    {
+        let definition = definition.into();
+        if let DataTypeDefinition::Structure(ref definition) = definition {
+            if !definition.default_encoding_id.is_null() {
+                self.encodings
+                    .insert(definition.default_encoding_id.clone(), data_type_id.clone());
+            }
+        }
+        self.definitions.insert(data_type_id.clone(), definition);
+    }
+
+    /// Adds the definition of a Rust type which describes itself through `DataTypeMetadata`
+    pub fn add_type<T>(&mut self)
+    where
+        T: DataTypeMetadata,
+    {
+        self.add_definition(&T::data_type_id(), T::data_type_definition());
+    }
+
+    /// Adds a data type which is encoded in the same way as its base type
+    pub fn add_subtype(&mut self, data_type_id: &NodeId, base_type_id: &NodeId) {
+        self.subtypes
+            .insert(data_type_id.clone(), base_type_id.clone());
+    }
+
+    /// Returns the definition of a data type
+    pub fn definition(&self, data_type_id: &NodeId) -> Option<&DataTypeDefinition> {
+        self.definitions.get(data_type_id)
+    }
+
+    /// Returns the data type which is encoded with the encoding id
+    pub fn data_type_of_encoding(&self, encoding_id: &NodeId) -> Option<&NodeId> {
+        self.encodings.get(encoding_id)
+    }
+
+    /// Tests if the registry knows how to encode a data type
+    pub fn is_known(&self, data_type_id: &NodeId) -> bool {
+        self.field_encoding(data_type_id).is_ok()
+    }
+
+    /// Returns the field of an enumeration with the value, if there is one
+    pub fn enum_field(&self, data_type_id: &NodeId, value: i32) -> Option<&EnumField> {
+        if let Some(DataTypeDefinition::Enum(definition)) = self.definitions.get(data_type_id) {
+            definition
+                .fields
+                .as_ref()
+                .and_then(|fields| fields.iter().find(|f| f.value == i64::from(value)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the data types used by the fields of the registered structures which the registry
+    /// does not know how to encode. A client can read the definitions of these types from the
+    /// server until there are none left.
+    pub fn unknown_data_types(&self) -> Vec<NodeId> {
+        let mut unknown = Vec::new();
+        for definition in self.definitions.values() {
+            if let DataTypeDefinition::Structure(definition) = definition {
+                if let Some(ref fields) = definition.fields {
+                    for field in fields {
+                        if !self.is_known(&field.data_type) && !unknown.contains(&field.data_type) {
+                            unknown.push(field.data_type.clone());
+                        }
+                    }
+                }
+            }
+        }
+        unknown
+    }
+
+    /// Decodes the body of an extension object into a dynamic structure. The node id of the
+    /// extension object is either the binary encoding id of a registered structure or the id of
+    /// the data type itself.
+    pub fn decode(
+        &self,
+        extension_object: &ExtensionObject,
+        decoding_limits: &DecodingLimits,
+    ) -> EncodingResult<DynamicStructure> {
+        let data_type_id = self
+            .encodings
+            .get(&extension_object.node_id)
+            .unwrap_or(&extension_object.node_id);
+        let definition = self.structure_definition(data_type_id)?;
+        match extension_object.body {
+            ExtensionObjectEncoding::ByteString(ByteString {
+                value: Some(ref value),
+            }) => {
+                let mut stream = Cursor::new(value);
+                self.decode_structure(&mut stream, data_type_id, definition, decoding_limits, 0)
+            }
+            _ => {
+                error!(
+                    "Extension object {:?} has no binary body to decode",
+                    extension_object.node_id
+                );
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+
+    /// Encodes a dynamic structure into the body of an extension object. The node id of the
+    /// extension object is the default encoding id of the structure's definition.
+    pub fn encode(&self, value: &DynamicStructure) -> EncodingResult<ExtensionObject> {
+        let definition = self.structure_definition(&value.data_type_id)?;
+        let mut stream = Cursor::new(Vec::new());
+        self.encode_structure(&mut stream, value, definition, 0)?;
+        let node_id = if definition.default_encoding_id.is_null() {
+            value.data_type_id.clone()
+        } else {
+            definition.default_encoding_id.clone()
+        };
+        Ok(ExtensionObject {
+            node_id,
+            body: ExtensionObjectEncoding::ByteString(ByteString::from(stream.into_inner())),
+        })
+    }
+
+    fn structure_definition(&self, data_type_id: &NodeId) -> EncodingResult<&StructureDefinition> {
+        if let Some(DataTypeDefinition::Structure(definition)) = self.definitions.get(data_type_id)
+        {
+            Ok(definition)
+        } else {
+            error!("Data type {:?} has no structure definition", data_type_id);
+            Err(StatusCode::BadDataTypeIdUnknown)
+        }
+    }
+
+    /// Finds how a field of the data type is encoded, following subtypes to their base type
+    fn field_encoding<'a>(&'a self, data_type_id: &'a NodeId) -> EncodingResult<FieldEncoding<'a>> {
+        let mut data_type_id = data_type_id;
+        for _ in 0..MAX_DEPTH {
+            match self.definitions.get(data_type_id) {
+                Some(DataTypeDefinition::Structure(definition)) => {
+                    return Ok(FieldEncoding::Structure(data_type_id, definition));
+                }
+                Some(DataTypeDefinition::Enum(_)) => return Ok(FieldEncoding::Enum),
+                None => {}
+            }
+            if data_type_id.namespace == 0 {
+                if let Identifier::Numeric(id) = data_type_id.identifier {
+                    match id {
+                        // Boolean to ExtensionObject, i.e. the abstract Structure type
+                        1..=22 => return Ok(FieldEncoding::BuiltIn(id as u8)),
+                        id if id == DataTypeId::BaseDataType as u32 => {
+                            return Ok(FieldEncoding::Variant)
+                        }
+                        id if id == DataTypeId::Enumeration as u32 => {
+                            return Ok(FieldEncoding::Enum)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if let Some(base_type_id) = self.subtypes.get(data_type_id) {
+                data_type_id = base_type_id;
+            } else {
+                break;
+            }
+        }
+        Err(StatusCode::BadDataTypeIdUnknown)
+    }
+
+    fn decode_structure<S: Read>(
+        &self,
+        stream: &mut S,
+        data_type_id: &NodeId,
+        definition: &StructureDefinition,
+        decoding_limits: &DecodingLimits,
+        depth: usize,
+    ) -> EncodingResult<DynamicStructure> {
+        if depth >= MAX_DEPTH {
+            error!("Structure {:?} is nested too deeply", data_type_id);
+            return Err(StatusCode::BadDecodingError);
+        }
+        let mut value = DynamicStructure::new(data_type_id.clone());
+        let fields = definition.fields.as_deref().unwrap_or(&[]);
+        match definition.structure_type {
+            StructureType::Structure => {
+                for field in fields {
+                    value.set_field(
+                        field.name.as_ref(),
+                        self.decode_field(stream, field, decoding_limits, depth)?,
+                    );
+                }
+            }
+            StructureType::StructureWithOptionalFields => {
+                let encoding_mask = read_u32(stream)?;
+                let mut optional_index = 0;
+                for field in fields {
+                    if field.is_optional {
+                        let is_present = encoding_mask & (1 << optional_index) != 0;
+                        optional_index += 1;
+                        if !is_present {
+                            continue;
+                        }
+                    }
+                    value.set_field(
+                        field.name.as_ref(),
+                        self.decode_field(stream, field, decoding_limits, depth)?,
+                    );
+                }
+            }
+            StructureType::Union => {
+                let switch_field = read_u32(stream)? as usize;
+                if switch_field > fields.len() {
+                    error!(
+                        "Union {:?} has a switch field {} beyond its {} fields",
+                        data_type_id,
+                        switch_field,
+                        fields.len()
+                    );
+                    return Err(StatusCode::BadDecodingError);
+                } else if switch_field > 0 {
+                    let field = &fields[switch_field - 1];
+                    value.set_field(
+                        field.name.as_ref(),
+                        self.decode_field(stream, field, decoding_limits, depth)?,
+                    );
+                }
+            }
+        }
+        Ok(value)
+    }
+
+    fn decode_field<S: Read>(
+        &self,
+        stream: &mut S,
+        field: &StructureField,
+        decoding_limits: &DecodingLimits,
+        depth: usize,
+    ) -> EncodingResult<DynamicValue> {
+        match field.value_rank {
+            -1 => self.decode_value(stream, &field.data_type, decoding_limits, depth),
+            0 | 1 => {
+                let len = read_i32(stream)?;
+                if len == -1 {
+                    Ok(DynamicValue::Array(Vec::new()))
+                } else if len < -1 {
+                    error!("Array length is negative value and invalid");
+                    Err(StatusCode::BadDecodingError)
+                } else if len as usize > decoding_limits.max_array_length {
+                    error!(
+                        "Array length {} exceeds decoding limit {}",
+                        len, decoding_limits.max_array_length
+                    );
+                    Err(StatusCode::BadDecodingError)
+                } else {
+                    let mut values = Vec::with_capacity(len as usize);
+                    for _ in 0..len {
+                        values.push(self.decode_value(
+                            stream,
+                            &field.data_type,
+                            decoding_limits,
+                            depth,
+                        )?);
+                    }
+                    Ok(DynamicValue::Array(values))
+                }
+            }
+            value_rank => {
+                error!(
+                    "Field {} has unsupported value rank {}",
+                    field.name, value_rank
+                );
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+
+    fn decode_value<S: Read>(
+        &self,
+        stream: &mut S,
+        data_type_id: &NodeId,
+        decoding_limits: &DecodingLimits,
+        depth: usize,
+    ) -> EncodingResult<DynamicValue> {
+        let field_encoding = self.field_encoding(data_type_id).map_err(|_| {
+            error!(
+                "Cannot decode a value of unknown data type {:?}",
+                data_type_id
+            );
+            StatusCode::BadDecodingError
+        })?;
+        match field_encoding {
+            FieldEncoding::BuiltIn(encoding_mask) => Ok(DynamicValue::Scalar(
+                Variant::decode_variant_value(stream, encoding_mask, decoding_limits)?,
+            )),
+            FieldEncoding::Variant => Ok(DynamicValue::Scalar(Variant::decode(
+                stream,
+                decoding_limits,
+            )?)),
+            FieldEncoding::Enum => Ok(DynamicValue::Enum(read_i32(stream)?)),
+            FieldEncoding::Structure(data_type_id, definition) => {
+                Ok(DynamicValue::Structure(Box::new(self.decode_structure(
+                    stream,
+                    data_type_id,
+                    definition,
+                    decoding_limits,
+                    depth + 1,
+                )?)))
+            }
+        }
+    }
+
+    fn encode_structure<S: Write>(
+        &self,
+        stream: &mut S,
+        value: &DynamicStructure,
+        definition: &StructureDefinition,
+        depth: usize,
+    ) -> EncodingResult<usize> {
+        if depth >= MAX_DEPTH {
+            error!("Structure {:?} is nested too deeply", value.data_type_id);
+            return Err(StatusCode::BadEncodingError);
+        }
+        let fields = definition.fields.as_deref().unwrap_or(&[]);
+        let mut size = 0;
+        match definition.structure_type {
+            StructureType::Structure => {
+                for field in fields {
+                    if let Some(field_value) = value.field(field.name.as_ref()) {
+                        size += self.encode_field(stream, field, field_value, depth)?;
+                    } else {
+                        error!(
+                            "Structure {:?} is missing field {}",
+                            value.data_type_id, field.name
+                        );
+                        return Err(StatusCode::BadEncodingError);
+                    }
+                }
+            }
+            StructureType::StructureWithOptionalFields => {
+                let mut encoding_mask = 0u32;
+                let mut optional_index = 0;
+                for field in fields {
+                    if field.is_optional {
+                        if value.field(field.name.as_ref()).is_some() {
+                            encoding_mask |= 1 << optional_index;
+                        }
+                        optional_index += 1;
+                    }
+                }
+                size += write_u32(stream, encoding_mask)?;
+                for field in fields {
+                    if let Some(field_value) = value.field(field.name.as_ref()) {
+                        size += self.encode_field(stream, field, field_value, depth)?;
+                    } else if !field.is_optional {
+                        error!(
+                            "Structure {:?} is missing mandatory field {}",
+                            value.data_type_id, field.name
+                        );
+                        return Err(StatusCode::BadEncodingError);
+                    }
+                }
+            }
+            StructureType::Union => {
+                let mut present = fields
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, field)| {
+                        value
+                            .field(field.name.as_ref())
+                            .map(|field_value| (i, field, field_value))
+                    })
+                    .collect::<Vec<_>>();
+                match present.pop() {
+                    None => size += write_u32(stream, 0u32)?,
+                    Some((i, field, field_value)) if present.is_empty() => {
+                        size += write_u32(stream, (i + 1) as u32)?;
+                        size += self.encode_field(stream, field, field_value, depth)?;
+                    }
+                    _ => {
+                        error!("Union {:?} has more than one field set", value.data_type_id);
+                        return Err(StatusCode::BadEncodingError);
+                    }
+                }
+            }
+        }
+        Ok(size)
+    }
+
+    fn encode_field<S: Write>(
+        &self,
+        stream: &mut S,
+        field: &StructureField,
+        value: &DynamicValue,
+        depth: usize,
+    ) -> EncodingResult<usize> {
+        match (field.value_rank, value) {
+            (-1, value) => self.encode_value(stream, &field.data_type, value, depth),
+            (0, DynamicValue::Array(values)) | (1, DynamicValue::Array(values)) => {
+                let mut size = write_i32(stream, values.len() as i32)?;
+                for value in values {
+                    size += self.encode_value(stream, &field.data_type, value, depth)?;
+                }
+                Ok(size)
+            }
+            (value_rank, _) => {
+                error!(
+                    "Field {} with value rank {} cannot be encoded from {:?}",
+                    field.name, value_rank, value
+                );
+                Err(StatusCode::BadEncodingError)
+            }
+        }
+    }
+
+    fn encode_value<S: Write>(
+        &self,
+        stream: &mut S,
+        data_type_id: &NodeId,
+        value: &DynamicValue,
+        depth: usize,
+    ) -> EncodingResult<usize> {
+        let field_encoding = self.field_encoding(data_type_id).map_err(|_| {
+            error!(
+                "Cannot encode a value of unknown data type {:?}",
+                data_type_id
+            );
+            StatusCode::BadEncodingError
+        })?;
+        match (field_encoding, value) {
+            (FieldEncoding::BuiltIn(encoding_mask), DynamicValue::Scalar(value))
+                if value.encoding_mask() == encoding_mask =>
+            {
+                Variant::encode_variant_value(stream, value)
+            }
+            (FieldEncoding::Variant, DynamicValue::Scalar(value)) => value.encode(stream),
+            (FieldEncoding::Enum, DynamicValue::Enum(value)) => write_i32(stream, *value),
+            (
+                FieldEncoding::Structure(structure_id, definition),
+                DynamicValue::Structure(value),
+            ) if value.data_type_id == *structure_id => {
+                self.encode_structure(stream, value, definition, depth + 1)
+            }
+            (_, value) => {
+                error!(
+                    "Value {:?} does not match data type {:?}",
+                    value, data_type_id
+                );
+                Err(StatusCode::BadEncodingError)
+            }
+        }
+    }
+}
@@ -7,8 +7,8 @@
 use std::io::{Cursor, Read, Write};
 
 use crate::{
-    byte_string::ByteString, encoding::*, node_id::NodeId, node_ids::ObjectId,
-    status_codes::StatusCode, string::XmlElement,
+    byte_string::ByteString, encoding::*, json::*, node_id::NodeId, node_ids::ObjectId,
+    service_types::json_bodies, status_codes::StatusCode, string::XmlElement,
 };
 
 /// Enumeration that holds the kinds of encoding that an ExtensionObject data may be encoded with.
@@ -88,6 +88,77 @@ impl BinaryEncoder<ExtensionObject> for ExtensionObject {
     }
 }
 
+/// An extension object holding one of the service types is written with the id of its data type
+/// and the JSON of the type as its body, or as just the JSON of the type in the non-reversible
+/// form. Other extension objects are written with the id of their encoding, the kind of encoding
+/// and the encoded body, which is Base64 for a byte string.
+impl JsonEncoder for ExtensionObject {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        match self.body {
+            ExtensionObjectEncoding::None => {
+                if self.is_null() {
+                    return JsonValue::Null;
+                }
+                write_json_field(&mut object, "TypeId", &self.node_id, context);
+            }
+            ExtensionObjectEncoding::ByteString(ref body) => {
+                if let Some((data_type_id, body)) = json_bodies::encode_json_body(self, context) {
+                    if !context.is_reversible() {
+                        return body;
+                    }
+                    let data_type_id: NodeId = data_type_id.into();
+                    write_json_field(&mut object, "TypeId", &data_type_id, context);
+                    object.insert("Body".to_string(), body);
+                } else {
+                    write_json_field(&mut object, "TypeId", &self.node_id, context);
+                    object.insert("Encoding".to_string(), JsonValue::from(1));
+                    write_json_field(&mut object, "Body", body, context);
+                }
+            }
+            ExtensionObjectEncoding::XmlElement(ref body) => {
+                write_json_field(&mut object, "TypeId", &self.node_id, context);
+                object.insert("Encoding".to_string(), JsonValue::from(2));
+                write_json_field(&mut object, "Body", body, context);
+            }
+        }
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ExtensionObject {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        let node_id: NodeId = read_json_field(object, "TypeId", decoding_limits)?;
+        let body = json_field(object, "Body");
+        let body = match read_json_field::<u8>(object, "Encoding", decoding_limits)? {
+            _ if body.is_null() => ExtensionObjectEncoding::None,
+            0 => {
+                // The body is the JSON of the type, which has to be one of the service types
+                return json_bodies::decode_json_body(&node_id, body, decoding_limits)
+                    .unwrap_or_else(|| {
+                        error!(
+                            "Cannot decode the JSON body of an extension object of type {}",
+                            node_id
+                        );
+                        Err(StatusCode::BadDecodingError)
+                    });
+            }
+            1 => {
+                ExtensionObjectEncoding::ByteString(ByteString::decode_json(body, decoding_limits)?)
+            }
+            2 => {
+                ExtensionObjectEncoding::XmlElement(XmlElement::decode_json(body, decoding_limits)?)
+            }
+            encoding => {
+                error!("Invalid extension object encoding {}", encoding);
+                return Err(StatusCode::BadDecodingError);
+            }
+        };
+        Ok(ExtensionObject { node_id, body })
+    }
+}
+
 impl ExtensionObject {
     /// Creates a null extension object, i.e. one with no value or payload
     pub fn null() -> ExtensionObject {
@@ -0,0 +1,587 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the `JsonEncoder` and `JsonDecoder` traits of the JSON mapping described in OPC UA
+//! Part 6, together with their implementations for the scalar types.
+//!
+//! Values are encoded either in the reversible form, which can be decoded back into the same
+//! value, or in the non-reversible form which is meant for consumers that only read the values,
+//! e.g. to export them. Only the reversible form can be decoded. Fields and values which are
+//! null, and fields holding a Good status code, are left out of the objects which are written
+//! and are treated as null when they are missing from the objects which are read.
+
+use chrono::{Datelike, SecondsFormat};
+
+use crate::{
+    byte_string::ByteString,
+    date_time::DateTime,
+    encoding::*,
+    extension_object::ExtensionObject,
+    guid::Guid,
+    node_ids::{DataTypeId, ObjectId},
+    status_codes::StatusCode,
+    string::UAString,
+};
+
+pub use serde_json::{Map as JsonMap, Value as JsonValue};
+
+/// Null, which is what a missing field is decoded from
+static NULL: JsonValue = JsonValue::Null;
+
+/// The date times at or before the OPC UA epoch are encoded as this
+const MIN_DATE_TIME: &str = "0001-01-01T00:00:00Z";
+/// The date times at or after the OPC UA end of time are encoded as this
+const MAX_DATE_TIME: &str = "9999-12-31T23:59:59Z";
+
+/// Says which form of JSON to encode values to. The non-reversible form writes the namespace
+/// and server URIs in place of their indexes, so it needs the namespace and server arrays of
+/// the server the values come from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonContext {
+    reversible: bool,
+    namespace_uris: Vec<String>,
+    server_uris: Vec<String>,
+}
+
+impl Default for JsonContext {
+    fn default() -> Self {
+        Self::reversible()
+    }
+}
+
+impl JsonContext {
+    /// The reversible form, which can be decoded
+    pub fn reversible() -> JsonContext {
+        JsonContext {
+            reversible: true,
+            namespace_uris: Vec::new(),
+            server_uris: Vec::new(),
+        }
+    }
+
+    /// The non-reversible form, writing namespace and server URIs from the arrays supplied
+    pub fn non_reversible(namespace_uris: Vec<String>, server_uris: Vec<String>) -> JsonContext {
+        JsonContext {
+            reversible: false,
+            namespace_uris,
+            server_uris,
+        }
+    }
+
+    /// Tests if values are encoded in the reversible form
+    pub fn is_reversible(&self) -> bool {
+        self.reversible
+    }
+
+    /// Returns the URI of a namespace index, if it is known
+    pub fn namespace_uri(&self, namespace_index: u16) -> Option<&str> {
+        self.namespace_uris
+            .get(namespace_index as usize)
+            .map(|uri| uri.as_str())
+    }
+
+    /// Returns the URI of a server index, if it is known
+    pub fn server_uri(&self, server_index: u32) -> Option<&str> {
+        self.server_uris
+            .get(server_index as usize)
+            .map(|uri| uri.as_str())
+    }
+
+    /// Returns the value which stands for a namespace index in a NodeId or QualifiedName. The
+    /// reversible form always writes the index. The non-reversible form writes the URI of
+    /// namespaces other than 0 and 1 when it is known.
+    pub(crate) fn namespace_value(&self, namespace_index: u16) -> JsonValue {
+        if !self.reversible && namespace_index > 1 {
+            if let Some(uri) = self.namespace_uri(namespace_index) {
+                return JsonValue::from(uri);
+            }
+        }
+        JsonValue::from(namespace_index)
+    }
+}
+
+/// Implemented by types which can be encoded to the JSON of OPC UA Part 6
+pub trait JsonEncoder {
+    /// Encodes the value to JSON in the form asked for by the context
+    fn encode_json(&self, context: &JsonContext) -> JsonValue;
+
+    /// Tests if the value is left out of the object it is a field of, in the same way as a null
+    /// value is, even though it is written when it stands on its own or is in an array
+    fn is_omitted_field(&self) -> bool {
+        false
+    }
+
+    /// Encodes the value to a string of JSON in the form asked for by the context
+    fn to_json_string(&self, context: &JsonContext) -> String {
+        self.encode_json(context).to_string()
+    }
+}
+
+/// Implemented by types which can be decoded from the reversible JSON of OPC UA Part 6
+pub trait JsonDecoder: Sized {
+    /// Decodes the value from JSON. A null value decodes to the null or default value of the
+    /// type.
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self>;
+
+    /// Decodes the value from a string of JSON
+    fn from_json_str(value: &str, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let value = serde_json::from_str(value).map_err(|err| {
+            error!("Cannot parse JSON, error = {}", err);
+            StatusCode::BadDecodingError
+        })?;
+        Self::decode_json(&value, decoding_limits)
+    }
+}
+
+/// Adds a field to an object unless its value is null or otherwise left out of objects
+pub fn write_json_field<T>(
+    object: &mut JsonMap<String, JsonValue>,
+    name: &str,
+    value: &T,
+    context: &JsonContext,
+) where
+    T: JsonEncoder + ?Sized,
+{
+    if value.is_omitted_field() {
+        return;
+    }
+    let value = value.encode_json(context);
+    if !value.is_null() {
+        object.insert(name.to_string(), value);
+    }
+}
+
+/// Checks that a value is an object, or null which is read as an object with no fields
+pub fn json_object(value: &JsonValue) -> EncodingResult<&JsonValue> {
+    if value.is_object() || value.is_null() {
+        Ok(value)
+    } else {
+        error!("Expected a JSON object but got {}", value);
+        Err(StatusCode::BadDecodingError)
+    }
+}
+
+/// Returns a field of an object, or null if the field is missing
+pub fn json_field<'a>(object: &'a JsonValue, name: &str) -> &'a JsonValue {
+    object.get(name).unwrap_or(&NULL)
+}
+
+/// Decodes a field of an object, or the null value of the field's type if it is missing
+pub fn read_json_field<T>(
+    object: &JsonValue,
+    name: &str,
+    decoding_limits: &DecodingLimits,
+) -> EncodingResult<T>
+where
+    T: JsonDecoder,
+{
+    T::decode_json(json_field(object, name), decoding_limits)
+}
+
+/// Decodes the value of an enumeration, which is its number or, in the non-reversible form, its
+/// name followed by an underscore and its number
+pub fn read_json_enum(value: &JsonValue) -> EncodingResult<i32> {
+    match value {
+        JsonValue::Null => Ok(0),
+        JsonValue::String(value) => value
+            .rsplit('_')
+            .next()
+            .and_then(|v| v.parse::<i32>().ok())
+            .ok_or(StatusCode::BadDecodingError),
+        value => i32::decode_json(value, &DecodingLimits::default()),
+    }
+}
+
+/// Decodes the binary body of an extension object as the service type `T` and encodes it to
+/// JSON. Used by the generated code which looks up the type of an extension object.
+pub(crate) fn json_body<T>(
+    extension_object: &ExtensionObject,
+    data_type_id: DataTypeId,
+    context: &JsonContext,
+) -> Option<(DataTypeId, JsonValue)>
+where
+    T: BinaryEncoder<T> + JsonEncoder,
+{
+    extension_object
+        .decode_inner::<T>(&DecodingLimits::default())
+        .ok()
+        .map(|value| (data_type_id, value.encode_json(context)))
+}
+
+/// Decodes the JSON body of an extension object as the service type `T` and returns an extension
+/// object holding its binary encoding. Used by the generated code which looks up the type of an
+/// extension object.
+pub(crate) fn binary_body<T>(
+    encoding_id: ObjectId,
+    body: &JsonValue,
+    decoding_limits: &DecodingLimits,
+) -> EncodingResult<ExtensionObject>
+where
+    T: BinaryEncoder<T> + JsonDecoder,
+{
+    let value = T::decode_json(body, decoding_limits)?;
+    Ok(ExtensionObject::from_encodable(encoding_id, &value))
+}
+
+/// Returns the string held by a value, or none if it is null
+fn json_str(value: &JsonValue) -> EncodingResult<Option<&str>> {
+    match value {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(value) => Ok(Some(value)),
+        value => {
+            error!("Expected a JSON string but got {}", value);
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+}
+
+impl<T> JsonEncoder for Option<T>
+where
+    T: JsonEncoder,
+{
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        self.as_ref()
+            .map_or(JsonValue::Null, |value| value.encode_json(context))
+    }
+
+    fn is_omitted_field(&self) -> bool {
+        self.as_ref()
+            .map_or(false, |value| value.is_omitted_field())
+    }
+}
+
+impl<T> JsonDecoder for Option<T>
+where
+    T: JsonDecoder,
+{
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if value.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(T::decode_json(value, decoding_limits)?))
+        }
+    }
+}
+
+impl<T> JsonEncoder for Vec<T>
+where
+    T: JsonEncoder,
+{
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        JsonValue::Array(self.iter().map(|v| v.encode_json(context)).collect())
+    }
+}
+
+impl<T> JsonDecoder for Vec<T>
+where
+    T: JsonDecoder,
+{
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        match value {
+            JsonValue::Null => Ok(Vec::new()),
+            JsonValue::Array(values) => {
+                if values.len() > decoding_limits.max_array_length {
+                    error!(
+                        "Array length {} exceeds decoding limit {}",
+                        values.len(),
+                        decoding_limits.max_array_length
+                    );
+                    Err(StatusCode::BadEncodingLimitsExceeded)
+                } else {
+                    values
+                        .iter()
+                        .map(|v| T::decode_json(v, decoding_limits))
+                        .collect()
+                }
+            }
+            value => {
+                error!("Expected a JSON array but got {}", value);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+impl JsonEncoder for bool {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        JsonValue::Bool(*self)
+    }
+}
+
+impl JsonDecoder for bool {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match value {
+            JsonValue::Null => Ok(false),
+            JsonValue::Bool(value) => Ok(*value),
+            value => {
+                error!("Expected a JSON boolean but got {}", value);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+// Integers up to 32-bits are JSON numbers
+macro_rules! json_integer_impl {
+    ( $t: ty ) => {
+        impl JsonEncoder for $t {
+            fn encode_json(&self, _: &JsonContext) -> JsonValue {
+                JsonValue::from(*self)
+            }
+        }
+
+        impl JsonDecoder for $t {
+            fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+                match value {
+                    JsonValue::Null => Ok(0),
+                    JsonValue::Number(number) => number
+                        .as_i64()
+                        .and_then(|v| <$t as std::convert::TryFrom<i64>>::try_from(v).ok())
+                        .ok_or_else(|| {
+                            error!("JSON number {} is not a valid {}", number, stringify!($t));
+                            StatusCode::BadDecodingError
+                        }),
+                    value => {
+                        error!("Expected a JSON number but got {}", value);
+                        Err(StatusCode::BadDecodingError)
+                    }
+                }
+            }
+        }
+    };
+}
+
+json_integer_impl!(i8);
+json_integer_impl!(u8);
+json_integer_impl!(i16);
+json_integer_impl!(u16);
+json_integer_impl!(i32);
+json_integer_impl!(u32);
+
+// 64-bit integers are JSON strings because JavaScript numbers cannot hold all of their values
+macro_rules! json_integer64_impl {
+    ( $t: ty ) => {
+        impl JsonEncoder for $t {
+            fn encode_json(&self, _: &JsonContext) -> JsonValue {
+                JsonValue::String(self.to_string())
+            }
+        }
+
+        impl JsonDecoder for $t {
+            fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+                let result = match value {
+                    JsonValue::Null => Some(0),
+                    JsonValue::String(value) => value.parse::<$t>().ok(),
+                    JsonValue::Number(number) => number.to_string().parse::<$t>().ok(),
+                    _ => None,
+                };
+                result.ok_or_else(|| {
+                    error!("JSON value {} is not a valid {}", value, stringify!($t));
+                    StatusCode::BadDecodingError
+                })
+            }
+        }
+    };
+}
+
+json_integer64_impl!(i64);
+json_integer64_impl!(u64);
+
+// Floating point numbers are JSON numbers, except for infinity and NaN which are strings
+macro_rules! json_float_impl {
+    ( $t: ty ) => {
+        impl JsonEncoder for $t {
+            fn encode_json(&self, _: &JsonContext) -> JsonValue {
+                if self.is_nan() {
+                    JsonValue::from("NaN")
+                } else if self.is_infinite() {
+                    JsonValue::from(if self.is_sign_positive() {
+                        "Infinity"
+                    } else {
+                        "-Infinity"
+                    })
+                } else {
+                    JsonValue::from(*self)
+                }
+            }
+        }
+
+        impl JsonDecoder for $t {
+            fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+                match value {
+                    JsonValue::Null => Ok(0.0),
+                    JsonValue::Number(number) => Ok(number.as_f64().unwrap_or(0.0) as $t),
+                    JsonValue::String(value) => match value.as_str() {
+                        "NaN" => Ok(<$t>::NAN),
+                        "Infinity" => Ok(<$t>::INFINITY),
+                        "-Infinity" => Ok(<$t>::NEG_INFINITY),
+                        _ => {
+                            error!("JSON string {} is not a valid {}", value, stringify!($t));
+                            Err(StatusCode::BadDecodingError)
+                        }
+                    },
+                    value => {
+                        error!("Expected a JSON number but got {}", value);
+                        Err(StatusCode::BadDecodingError)
+                    }
+                }
+            }
+        }
+    };
+}
+
+json_float_impl!(f32);
+json_float_impl!(f64);
+
+impl JsonEncoder for UAString {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        self.value()
+            .as_ref()
+            .map_or(JsonValue::Null, |value| JsonValue::from(value.as_str()))
+    }
+}
+
+impl JsonDecoder for UAString {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        match json_str(value)? {
+            None => Ok(UAString::null()),
+            Some(value) if value.len() > decoding_limits.max_string_length => {
+                error!(
+                    "String length {} exceeds decoding limit {}",
+                    value.len(),
+                    decoding_limits.max_string_length
+                );
+                Err(StatusCode::BadEncodingLimitsExceeded)
+            }
+            Some(value) => Ok(UAString::from(value)),
+        }
+    }
+}
+
+/// A byte string is a Base64 encoded string
+impl JsonEncoder for ByteString {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            JsonValue::from(self.as_base64())
+        }
+    }
+}
+
+impl JsonDecoder for ByteString {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        match json_str(value)? {
+            None => Ok(ByteString::null()),
+            Some(value) => {
+                let value = ByteString::from_base64(value).ok_or_else(|| {
+                    error!("JSON string is not valid Base64");
+                    StatusCode::BadDecodingError
+                })?;
+                if value.value.as_ref().map_or(0, |v| v.len())
+                    > decoding_limits.max_byte_string_length
+                {
+                    error!(
+                        "Byte string length exceeds decoding limit {}",
+                        decoding_limits.max_byte_string_length
+                    );
+                    Err(StatusCode::BadEncodingLimitsExceeded)
+                } else {
+                    Ok(value)
+                }
+            }
+        }
+    }
+}
+
+impl JsonEncoder for Guid {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        JsonValue::from(self.to_string())
+    }
+}
+
+impl JsonDecoder for Guid {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match json_str(value)? {
+            None => Ok(Guid::null()),
+            Some(value) => value.parse::<Guid>().map_err(|_| {
+                error!("JSON string {} is not a valid Guid", value);
+                StatusCode::BadDecodingError
+            }),
+        }
+    }
+}
+
+/// A date time is an ISO 8601 string in UTC. Date times outside of the range of OPC UA are
+/// clamped to its earliest and latest values.
+impl JsonEncoder for DateTime {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        let ticks = self.checked_ticks();
+        if ticks == 0 {
+            JsonValue::from(MIN_DATE_TIME)
+        } else if ticks == i64::MAX {
+            JsonValue::from(MAX_DATE_TIME)
+        } else {
+            JsonValue::from(
+                self.as_chrono()
+                    .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )
+        }
+    }
+}
+
+impl JsonDecoder for DateTime {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match json_str(value)? {
+            None => Ok(DateTime::null()),
+            Some(value) => {
+                let date_time = chrono::DateTime::parse_from_rfc3339(value).map_err(|err| {
+                    error!("Cannot parse date {}, error = {}", value, err);
+                    StatusCode::BadDecodingError
+                })?;
+                if date_time.year() < 1601 {
+                    Ok(DateTime::null())
+                } else {
+                    let date_time = DateTime::from(date_time.with_timezone(&chrono::Utc));
+                    if date_time.checked_ticks() == i64::MAX {
+                        Ok(DateTime::endtimes())
+                    } else {
+                        Ok(date_time)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A status code is its number in the reversible form and an object with its number and its
+/// name in the non-reversible form. A Good status code is left out of objects but is still
+/// written in arrays.
+impl JsonEncoder for StatusCode {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(self.bits())
+        } else {
+            let mut object = JsonMap::new();
+            object.insert("Code".to_string(), JsonValue::from(self.bits()));
+            object.insert("Symbol".to_string(), JsonValue::from(self.name()));
+            JsonValue::Object(object)
+        }
+    }
+
+    fn is_omitted_field(&self) -> bool {
+        *self == StatusCode::Good
+    }
+}
+
+impl JsonDecoder for StatusCode {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let code = if value.is_object() {
+            read_json_field::<u32>(value, "Code", decoding_limits)?
+        } else {
+            u32::decode_json(value, decoding_limits)?
+        };
+        Ok(StatusCode::from_bits_truncate(code))
+    }
+}
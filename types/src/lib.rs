@@ -20,8 +20,6 @@ extern crate lazy_static;
 extern crate bitflags;
 #[macro_use]
 extern crate serde_derive;
-#[cfg(test)]
-extern crate serde_json;
 
 ///Contains constants recognized by OPC UA clients and servers to describe various protocols and
 /// profiles used during communication and encryption.
@@ -200,9 +198,11 @@ pub mod data_types;
 pub mod data_value;
 pub mod date_time;
 pub mod diagnostic_info;
+pub mod dynamic_structure;
 pub mod encoding;
 pub mod extension_object;
 pub mod guid;
+pub mod json;
 pub mod localized_text;
 pub mod node_id;
 pub mod node_ids;
@@ -220,10 +220,15 @@ pub mod variant;
 
 pub use crate::{
     argument::*, array::*, attribute::*, basic_types::*, byte_string::*, data_types::*,
-    data_value::*, date_time::*, diagnostic_info::*, encoding::*, extension_object::*, guid::*,
-    localized_text::*, node_id::*, node_ids::*, numeric_range::*, operand::*, qualified_name::*,
-    request_header::*, response_header::*, service_types::*, string::*, variant::*,
+    data_value::*, date_time::*, diagnostic_info::*, dynamic_structure::*, encoding::*,
+    extension_object::*, guid::*, json::*, localized_text::*, node_id::*, node_ids::*,
+    numeric_range::*, operand::*, qualified_name::*, request_header::*, response_header::*,
+    service_types::*, string::*, variant::*,
 };
 
+// Lets the tests use `opcua-types-derive`, whose code refers to types by `::opcua_types` paths
+#[cfg(test)]
+extern crate self as opcua_types;
+
 #[cfg(test)]
 mod tests;
@@ -8,7 +8,7 @@ use std::{
     io::{Read, Write},
 };
 
-use crate::{encoding::*, string::*};
+use crate::{encoding::*, json::*, string::*};
 
 /// A human readable text with an optional locale identifier.
 #[derive(PartialEq, Default, Debug, Clone, Serialize, Deserialize)]
@@ -103,6 +103,33 @@ impl BinaryEncoder<LocalizedText> for LocalizedText {
     }
 }
 
+/// A localized text is an object holding its locale and text in the reversible form and just its
+/// text in the non-reversible form
+impl JsonEncoder for LocalizedText {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if !context.is_reversible() {
+            return self.text.encode_json(context);
+        }
+        if self.locale.is_null() && self.text.is_null() {
+            return JsonValue::Null;
+        }
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Locale", &self.locale, context);
+        write_json_field(&mut object, "Text", &self.text, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for LocalizedText {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(LocalizedText {
+            locale: read_json_field(object, "Locale", decoding_limits)?,
+            text: read_json_field(object, "Text", decoding_limits)?,
+        })
+    }
+}
+
 impl LocalizedText {
     pub fn new(locale: &str, text: &str) -> LocalizedText {
         LocalizedText {
@@ -18,6 +18,7 @@ use crate::{
     byte_string::ByteString,
     encoding::*,
     guid::Guid,
+    json::*,
     node_ids::{ObjectId, ReferenceTypeId},
     status_codes::StatusCode,
     string::*,
@@ -233,6 +234,35 @@ impl BinaryEncoder<NodeId> for NodeId {
     }
 }
 
+/// A node id is an object holding the type of its identifier, which is left out for numeric
+/// identifiers, the identifier and the namespace, which is left out for namespace 0
+impl JsonEncoder for NodeId {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if self.is_null() {
+            return JsonValue::Null;
+        }
+        let mut object = JsonMap::new();
+        self.write_json_identifier(&mut object, context);
+        if self.namespace != 0 {
+            object.insert(
+                "Namespace".to_string(),
+                context.namespace_value(self.namespace),
+            );
+        }
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for NodeId {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(NodeId {
+            namespace: read_json_field(object, "Namespace", decoding_limits)?,
+            identifier: NodeId::read_json_identifier(object, decoding_limits)?,
+        })
+    }
+}
+
 impl FromStr for NodeId {
     type Err = StatusCode;
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -375,6 +405,25 @@ impl NodeId {
         )
     }
 
+    /// Ensures that `next_numeric()` will not return the supplied identifier or any below it, e.g.
+    /// because nodes created with `next_numeric()` by a previous run of a server have been
+    /// restored.
+    pub fn reserve_numeric(numeric_id: u32) {
+        let next = numeric_id as usize + 1;
+        let mut current = NEXT_NODE_ID_NUMERIC.load(Ordering::SeqCst);
+        while current < next {
+            match NEXT_NODE_ID_NUMERIC.compare_exchange(
+                current,
+                next,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => break,
+                Err(value) => current = value,
+            }
+        }
+    }
+
     /// Extracts an ObjectId from a node id, providing the node id holds an object id
     pub fn as_object_id(&self) -> std::result::Result<ObjectId, ()> {
         match self.identifier {
@@ -427,6 +476,50 @@ impl NodeId {
             _ => false,
         }
     }
+
+    /// Writes the IdType and Id fields of the JSON object of the node id
+    fn write_json_identifier(
+        &self,
+        object: &mut JsonMap<String, JsonValue>,
+        context: &JsonContext,
+    ) {
+        let (id_type, id) = match self.identifier {
+            Identifier::Numeric(ref id) => (0, id.encode_json(context)),
+            Identifier::String(ref id) => (1, id.encode_json(context)),
+            Identifier::Guid(ref id) => (2, id.encode_json(context)),
+            Identifier::ByteString(ref id) => (3, id.encode_json(context)),
+        };
+        if id_type != 0 {
+            object.insert("IdType".to_string(), JsonValue::from(id_type));
+        }
+        if !id.is_null() {
+            object.insert("Id".to_string(), id);
+        }
+    }
+
+    /// Reads the identifier of a node id from the IdType and Id fields of its JSON object
+    fn read_json_identifier(
+        object: &JsonValue,
+        decoding_limits: &DecodingLimits,
+    ) -> EncodingResult<Identifier> {
+        let id = json_field(object, "Id");
+        match read_json_field::<u8>(object, "IdType", decoding_limits)? {
+            0 => Ok(Identifier::Numeric(u32::decode_json(id, decoding_limits)?)),
+            1 => Ok(Identifier::String(UAString::decode_json(
+                id,
+                decoding_limits,
+            )?)),
+            2 => Ok(Identifier::Guid(Guid::decode_json(id, decoding_limits)?)),
+            3 => Ok(Identifier::ByteString(ByteString::decode_json(
+                id,
+                decoding_limits,
+            )?)),
+            id_type => {
+                error!("Unrecognized node id type {}", id_type);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
 }
 
 /// A NodeId that allows the namespace URI to be specified instead of an index.
@@ -564,6 +657,60 @@ impl BinaryEncoder<ExpandedNodeId> for ExpandedNodeId {
     }
 }
 
+/// An expanded node id is written like a node id, with the namespace URI in place of the index
+/// when it has one and with the index or URI of the server when it is not the local server
+impl JsonEncoder for ExpandedNodeId {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if self.is_null() && self.namespace_uri.is_null() && self.server_index == 0 {
+            return JsonValue::Null;
+        }
+        let mut object = JsonMap::new();
+        self.node_id.write_json_identifier(&mut object, context);
+        if !self.namespace_uri.is_null() {
+            write_json_field(&mut object, "Namespace", &self.namespace_uri, context);
+        } else if self.node_id.namespace != 0 {
+            object.insert(
+                "Namespace".to_string(),
+                context.namespace_value(self.node_id.namespace),
+            );
+        }
+        if self.server_index != 0 {
+            let server_uri = if context.is_reversible() {
+                None
+            } else {
+                context.server_uri(self.server_index)
+            };
+            object.insert(
+                "ServerUri".to_string(),
+                server_uri.map_or_else(|| JsonValue::from(self.server_index), JsonValue::from),
+            );
+        }
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ExpandedNodeId {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        let identifier = NodeId::read_json_identifier(object, decoding_limits)?;
+        let (namespace, namespace_uri) = match json_field(object, "Namespace") {
+            JsonValue::String(uri) => (0, UAString::from(uri.as_str())),
+            namespace => (
+                u16::decode_json(namespace, decoding_limits)?,
+                UAString::null(),
+            ),
+        };
+        Ok(ExpandedNodeId {
+            node_id: NodeId {
+                namespace,
+                identifier,
+            },
+            namespace_uri,
+            server_index: read_json_field(object, "ServerUri", decoding_limits)?,
+        })
+    }
+}
+
 impl<'a> Into<ExpandedNodeId> for &'a NodeId {
     fn into(self) -> ExpandedNodeId {
         self.clone().into()
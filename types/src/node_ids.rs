@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from NodeIds.csv by tools/schema/gen_node_ids.js
+// This file was autogenerated from NodeIds.csv by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
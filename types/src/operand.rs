@@ -96,6 +96,12 @@ impl From<()> for LiteralOperand {
     }
 }
 
+impl From<NodeId> for LiteralOperand {
+    fn from(v: NodeId) -> Self {
+        Self::from(Variant::from(v))
+    }
+}
+
 impl From<Variant> for LiteralOperand {
     fn from(v: Variant) -> Self {
         LiteralOperand { value: v }
@@ -404,6 +410,40 @@ impl ContentFilterBuilder {
         self.add_element(FilterOperator::BitwiseOr, vec![o1.into(), o2.into()])
     }
 
+    pub fn is_in_view<T>(self, o1: T) -> Self
+    where
+        T: Into<Operand>,
+    {
+        self.add_element(FilterOperator::InView, vec![o1.into()])
+    }
+
+    pub fn is_of_type<T>(self, o1: T) -> Self
+    where
+        T: Into<Operand>,
+    {
+        self.add_element(FilterOperator::OfType, vec![o1.into()])
+    }
+
+    /// Adds a RelatedTo element. The source and target are types or elements of other RelatedTo
+    /// elements, related by the reference type within the number of hops.
+    pub fn is_related_to<T, S, U, V>(self, source: T, target: S, reference_type: U, hops: V) -> Self
+    where
+        T: Into<Operand>,
+        S: Into<Operand>,
+        U: Into<Operand>,
+        V: Into<Operand>,
+    {
+        self.add_element(
+            FilterOperator::RelatedTo,
+            vec![
+                source.into(),
+                target.into(),
+                reference_type.into(),
+                hops.into(),
+            ],
+        )
+    }
+
     pub fn build(self) -> ContentFilter {
         ContentFilter {
             elements: Some(self.elements),
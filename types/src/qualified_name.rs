@@ -5,7 +5,7 @@
 //! Contains the definition of `QualifiedName`.
 use std::io::{Read, Write};
 
-use crate::{encoding::*, string::*};
+use crate::{encoding::*, json::*, string::*};
 
 /// An identifier for a error or condition that is associated with a value or an operation.
 ///
@@ -71,6 +71,35 @@ impl BinaryEncoder<QualifiedName> for QualifiedName {
     }
 }
 
+/// A qualified name is an object holding its name and the index of its namespace, which is left
+/// out for namespace 0
+impl JsonEncoder for QualifiedName {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if self.is_null() {
+            return JsonValue::Null;
+        }
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Name", &self.name, context);
+        if self.namespace_index != 0 {
+            object.insert(
+                "Uri".to_string(),
+                context.namespace_value(self.namespace_index),
+            );
+        }
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for QualifiedName {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(QualifiedName {
+            namespace_index: read_json_field(object, "Uri", decoding_limits)?,
+            name: read_json_field(object, "Name", decoding_limits)?,
+        })
+    }
+}
+
 impl QualifiedName {
     pub fn new<T>(namespace_index: u16, name: T) -> QualifiedName
     where
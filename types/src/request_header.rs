@@ -9,7 +9,7 @@ use std::{
 
 use crate::{
     data_types::*, date_time::DateTime, diagnostic_info::DiagnosticBits, encoding::*,
-    extension_object::ExtensionObject, node_id::NodeId, string::UAString,
+    extension_object::ExtensionObject, json::*, node_id::NodeId, string::UAString,
 };
 
 /// The `RequestHeader` contains information common to every request from a client to the server.
@@ -132,6 +132,54 @@ impl BinaryEncoder<RequestHeader> for RequestHeader {
     }
 }
 
+impl JsonEncoder for RequestHeader {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(
+            &mut object,
+            "AuthenticationToken",
+            &self.authentication_token,
+            context,
+        );
+        write_json_field(&mut object, "Timestamp", &self.timestamp, context);
+        write_json_field(&mut object, "RequestHandle", &self.request_handle, context);
+        write_json_field(
+            &mut object,
+            "ReturnDiagnostics",
+            &self.return_diagnostics.bits(),
+            context,
+        );
+        write_json_field(&mut object, "AuditEntryId", &self.audit_entry_id, context);
+        write_json_field(&mut object, "TimeoutHint", &self.timeout_hint, context);
+        write_json_field(
+            &mut object,
+            "AdditionalHeader",
+            &self.additional_header,
+            context,
+        );
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for RequestHeader {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(RequestHeader {
+            authentication_token: read_json_field(object, "AuthenticationToken", decoding_limits)?,
+            timestamp: read_json_field(object, "Timestamp", decoding_limits)?,
+            request_handle: read_json_field(object, "RequestHandle", decoding_limits)?,
+            return_diagnostics: DiagnosticBits::from_bits_truncate(read_json_field(
+                object,
+                "ReturnDiagnostics",
+                decoding_limits,
+            )?),
+            audit_entry_id: read_json_field(object, "AuditEntryId", decoding_limits)?,
+            timeout_hint: read_json_field(object, "TimeoutHint", decoding_limits)?,
+            additional_header: read_json_field(object, "AdditionalHeader", decoding_limits)?,
+        })
+    }
+}
+
 impl RequestHeader {
     pub fn new(
         authentication_token: &NodeId,
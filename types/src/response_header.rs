@@ -9,8 +9,8 @@ use std::{
 
 use crate::{
     data_types::*, date_time::DateTime, diagnostic_info::DiagnosticInfo, encoding::*,
-    extension_object::ExtensionObject, request_header::RequestHeader, status_codes::StatusCode,
-    string::UAString,
+    extension_object::ExtensionObject, json::*, request_header::RequestHeader,
+    status_codes::StatusCode, string::UAString,
 };
 
 /// The `ResponseHeader` contains information common to every response from server to client.
@@ -66,6 +66,43 @@ impl BinaryEncoder<ResponseHeader> for ResponseHeader {
     }
 }
 
+impl JsonEncoder for ResponseHeader {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Timestamp", &self.timestamp, context);
+        write_json_field(&mut object, "RequestHandle", &self.request_handle, context);
+        write_json_field(&mut object, "ServiceResult", &self.service_result, context);
+        write_json_field(
+            &mut object,
+            "ServiceDiagnostics",
+            &self.service_diagnostics,
+            context,
+        );
+        write_json_field(&mut object, "StringTable", &self.string_table, context);
+        write_json_field(
+            &mut object,
+            "AdditionalHeader",
+            &self.additional_header,
+            context,
+        );
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ResponseHeader {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ResponseHeader {
+            timestamp: read_json_field(object, "Timestamp", decoding_limits)?,
+            request_handle: read_json_field(object, "RequestHandle", decoding_limits)?,
+            service_result: read_json_field(object, "ServiceResult", decoding_limits)?,
+            service_diagnostics: read_json_field(object, "ServiceDiagnostics", decoding_limits)?,
+            string_table: read_json_field(object, "StringTable", decoding_limits)?,
+            additional_header: read_json_field(object, "AdditionalHeader", decoding_limits)?,
+        })
+    }
+}
+
 impl ResponseHeader {
     pub fn new_good(request_header: &RequestHeader) -> ResponseHeader {
         ResponseHeader::new_service_result(request_header, StatusCode::Good)
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -80,3 +81,32 @@ impl BinaryEncoder<ActivateSessionRequest> for ActivateSessionRequest {
         })
     }
 }
+
+impl JsonEncoder for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "ClientSignature", &self.client_signature, context);
+        write_json_field(&mut object, "ClientSoftwareCertificates", &self.client_software_certificates, context);
+        write_json_field(&mut object, "LocaleIds", &self.locale_ids, context);
+        write_json_field(&mut object, "UserIdentityToken", &self.user_identity_token, context);
+        write_json_field(&mut object, "UserTokenSignature", &self.user_token_signature, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ActivateSessionRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            client_signature: read_json_field(object, "ClientSignature", decoding_limits)?,
+            client_software_certificates: read_json_field(object, "ClientSoftwareCertificates", decoding_limits)?,
+            locale_ids: read_json_field(object, "LocaleIds", decoding_limits)?,
+            user_identity_token: read_json_field(object, "UserIdentityToken", decoding_limits)?,
+            user_token_signature: read_json_field(object, "UserTokenSignature", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -69,3 +70,28 @@ impl BinaryEncoder<ActivateSessionResponse> for ActivateSessionResponse {
         })
     }
 }
+
+impl JsonEncoder for ActivateSessionResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ResponseHeader", &self.response_header, context);
+        write_json_field(&mut object, "ServerNonce", &self.server_nonce, context);
+        write_json_field(&mut object, "Results", &self.results, context);
+        write_json_field(&mut object, "DiagnosticInfos", &self.diagnostic_infos, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ActivateSessionResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ActivateSessionResponse {
+            response_header: read_json_field(object, "ResponseHeader", decoding_limits)?,
+            server_nonce: read_json_field(object, "ServerNonce", decoding_limits)?,
+            results: read_json_field(object, "Results", decoding_limits)?,
+            diagnostic_infos: read_json_field(object, "DiagnosticInfos", decoding_limits)?,
+        })
+    }
+}
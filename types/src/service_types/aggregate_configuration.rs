@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -70,3 +71,30 @@ impl BinaryEncoder<AggregateConfiguration> for AggregateConfiguration {
         })
     }
 }
+
+impl JsonEncoder for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "UseServerCapabilitiesDefaults", &self.use_server_capabilities_defaults, context);
+        write_json_field(&mut object, "TreatUncertainAsBad", &self.treat_uncertain_as_bad, context);
+        write_json_field(&mut object, "PercentDataBad", &self.percent_data_bad, context);
+        write_json_field(&mut object, "PercentDataGood", &self.percent_data_good, context);
+        write_json_field(&mut object, "UseSlopedExtrapolation", &self.use_sloped_extrapolation, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(AggregateConfiguration {
+            use_server_capabilities_defaults: read_json_field(object, "UseServerCapabilitiesDefaults", decoding_limits)?,
+            treat_uncertain_as_bad: read_json_field(object, "TreatUncertainAsBad", decoding_limits)?,
+            percent_data_bad: read_json_field(object, "PercentDataBad", decoding_limits)?,
+            percent_data_good: read_json_field(object, "PercentDataGood", decoding_limits)?,
+            use_sloped_extrapolation: read_json_field(object, "UseSlopedExtrapolation", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     date_time::DateTime,
     node_id::NodeId,
@@ -60,3 +61,28 @@ impl BinaryEncoder<AggregateFilter> for AggregateFilter {
         })
     }
 }
+
+impl JsonEncoder for AggregateFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "StartTime", &self.start_time, context);
+        write_json_field(&mut object, "AggregateType", &self.aggregate_type, context);
+        write_json_field(&mut object, "ProcessingInterval", &self.processing_interval, context);
+        write_json_field(&mut object, "AggregateConfiguration", &self.aggregate_configuration, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for AggregateFilter {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(AggregateFilter {
+            start_time: read_json_field(object, "StartTime", decoding_limits)?,
+            aggregate_type: read_json_field(object, "AggregateType", decoding_limits)?,
+            processing_interval: read_json_field(object, "ProcessingInterval", decoding_limits)?,
+            aggregate_configuration: read_json_field(object, "AggregateConfiguration", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     date_time::DateTime,
     service_types::AggregateConfiguration,
@@ -54,3 +55,26 @@ impl BinaryEncoder<AggregateFilterResult> for AggregateFilterResult {
         })
     }
 }
+
+impl JsonEncoder for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RevisedStartTime", &self.revised_start_time, context);
+        write_json_field(&mut object, "RevisedProcessingInterval", &self.revised_processing_interval, context);
+        write_json_field(&mut object, "RevisedAggregateConfiguration", &self.revised_aggregate_configuration, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(AggregateFilterResult {
+            revised_start_time: read_json_field(object, "RevisedStartTime", decoding_limits)?,
+            revised_processing_interval: read_json_field(object, "RevisedProcessingInterval", decoding_limits)?,
+            revised_aggregate_configuration: read_json_field(object, "RevisedAggregateConfiguration", decoding_limits)?,
+        })
+    }
+}
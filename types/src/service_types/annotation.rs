@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<Annotation> for Annotation {
         })
     }
 }
+
+impl JsonEncoder for Annotation {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Message", &self.message, context);
+        write_json_field(&mut object, "UserName", &self.user_name, context);
+        write_json_field(&mut object, "AnnotationTime", &self.annotation_time, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for Annotation {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(Annotation {
+            message: read_json_field(object, "Message", decoding_limits)?,
+            user_name: read_json_field(object, "UserName", decoding_limits)?,
+            annotation_time: read_json_field(object, "AnnotationTime", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -83,3 +84,34 @@ impl BinaryEncoder<ApplicationDescription> for ApplicationDescription {
         })
     }
 }
+
+impl JsonEncoder for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ApplicationUri", &self.application_uri, context);
+        write_json_field(&mut object, "ProductUri", &self.product_uri, context);
+        write_json_field(&mut object, "ApplicationName", &self.application_name, context);
+        write_json_field(&mut object, "ApplicationType", &self.application_type, context);
+        write_json_field(&mut object, "GatewayServerUri", &self.gateway_server_uri, context);
+        write_json_field(&mut object, "DiscoveryProfileUri", &self.discovery_profile_uri, context);
+        write_json_field(&mut object, "DiscoveryUrls", &self.discovery_urls, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ApplicationDescription {
+            application_uri: read_json_field(object, "ApplicationUri", decoding_limits)?,
+            product_uri: read_json_field(object, "ProductUri", decoding_limits)?,
+            application_name: read_json_field(object, "ApplicationName", decoding_limits)?,
+            application_type: read_json_field(object, "ApplicationType", decoding_limits)?,
+            gateway_server_uri: read_json_field(object, "GatewayServerUri", decoding_limits)?,
+            discovery_profile_uri: read_json_field(object, "DiscoveryProfileUri", decoding_limits)?,
+            discovery_urls: read_json_field(object, "DiscoveryUrls", decoding_limits)?,
+        })
+    }
+}
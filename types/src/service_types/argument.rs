@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -73,3 +74,30 @@ impl BinaryEncoder<Argument> for Argument {
         })
     }
 }
+
+impl JsonEncoder for Argument {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "DataType", &self.data_type, context);
+        write_json_field(&mut object, "ValueRank", &self.value_rank, context);
+        write_json_field(&mut object, "ArrayDimensions", &self.array_dimensions, context);
+        write_json_field(&mut object, "Description", &self.description, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for Argument {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(Argument {
+            name: read_json_field(object, "Name", decoding_limits)?,
+            data_type: read_json_field(object, "DataType", decoding_limits)?,
+            value_rank: read_json_field(object, "ValueRank", decoding_limits)?,
+            array_dimensions: read_json_field(object, "ArrayDimensions", decoding_limits)?,
+            description: read_json_field(object, "Description", decoding_limits)?,
+        })
+    }
+}
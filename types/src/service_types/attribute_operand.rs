@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     node_id::NodeId,
     string::UAString,
@@ -65,3 +66,30 @@ impl BinaryEncoder<AttributeOperand> for AttributeOperand {
         })
     }
 }
+
+impl JsonEncoder for AttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "Alias", &self.alias, context);
+        write_json_field(&mut object, "BrowsePath", &self.browse_path, context);
+        write_json_field(&mut object, "AttributeId", &self.attribute_id, context);
+        write_json_field(&mut object, "IndexRange", &self.index_range, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for AttributeOperand {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(AttributeOperand {
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            alias: read_json_field(object, "Alias", decoding_limits)?,
+            browse_path: read_json_field(object, "BrowsePath", decoding_limits)?,
+            attribute_id: read_json_field(object, "AttributeId", decoding_limits)?,
+            index_range: read_json_field(object, "IndexRange", decoding_limits)?,
+        })
+    }
+}
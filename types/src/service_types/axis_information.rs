@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -74,3 +75,30 @@ impl BinaryEncoder<AxisInformation> for AxisInformation {
         })
     }
 }
+
+impl JsonEncoder for AxisInformation {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "EngineeringUnits", &self.engineering_units, context);
+        write_json_field(&mut object, "EURange", &self.eu_range, context);
+        write_json_field(&mut object, "Title", &self.title, context);
+        write_json_field(&mut object, "AxisScaleType", &self.axis_scale_type, context);
+        write_json_field(&mut object, "AxisSteps", &self.axis_steps, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for AxisInformation {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(AxisInformation {
+            engineering_units: read_json_field(object, "EngineeringUnits", decoding_limits)?,
+            eu_range: read_json_field(object, "EURange", decoding_limits)?,
+            title: read_json_field(object, "Title", decoding_limits)?,
+            axis_scale_type: read_json_field(object, "AxisScaleType", decoding_limits)?,
+            axis_steps: read_json_field(object, "AxisSteps", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     string::UAString,
     service_types::enums::BrokerTransportQualityOfService,
@@ -64,3 +65,30 @@ impl BinaryEncoder<BrokerDataSetReaderTransportDataType> for BrokerDataSetReader
         })
     }
 }
+
+impl JsonEncoder for BrokerDataSetReaderTransportDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "QueueName", &self.queue_name, context);
+        write_json_field(&mut object, "ResourceUri", &self.resource_uri, context);
+        write_json_field(&mut object, "AuthenticationProfileUri", &self.authentication_profile_uri, context);
+        write_json_field(&mut object, "RequestedDeliveryGuarantee", &self.requested_delivery_guarantee, context);
+        write_json_field(&mut object, "MetaDataQueueName", &self.meta_data_queue_name, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for BrokerDataSetReaderTransportDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(BrokerDataSetReaderTransportDataType {
+            queue_name: read_json_field(object, "QueueName", decoding_limits)?,
+            resource_uri: read_json_field(object, "ResourceUri", decoding_limits)?,
+            authentication_profile_uri: read_json_field(object, "AuthenticationProfileUri", decoding_limits)?,
+            requested_delivery_guarantee: read_json_field(object, "RequestedDeliveryGuarantee", decoding_limits)?,
+            meta_data_queue_name: read_json_field(object, "MetaDataQueueName", decoding_limits)?,
+        })
+    }
+}
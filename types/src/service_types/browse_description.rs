@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -77,3 +78,32 @@ impl BinaryEncoder<BrowseDescription> for BrowseDescription {
         })
     }
 }
+
+impl JsonEncoder for BrowseDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "BrowseDirection", &self.browse_direction, context);
+        write_json_field(&mut object, "ReferenceTypeId", &self.reference_type_id, context);
+        write_json_field(&mut object, "IncludeSubtypes", &self.include_subtypes, context);
+        write_json_field(&mut object, "NodeClassMask", &self.node_class_mask, context);
+        write_json_field(&mut object, "ResultMask", &self.result_mask, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for BrowseDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(BrowseDescription {
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            browse_direction: read_json_field(object, "BrowseDirection", decoding_limits)?,
+            reference_type_id: read_json_field(object, "ReferenceTypeId", decoding_limits)?,
+            include_subtypes: read_json_field(object, "IncludeSubtypes", decoding_limits)?,
+            node_class_mask: read_json_field(object, "NodeClassMask", decoding_limits)?,
+            result_mask: read_json_field(object, "ResultMask", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -68,3 +69,28 @@ impl BinaryEncoder<BrowseRequest> for BrowseRequest {
         })
     }
 }
+
+impl JsonEncoder for BrowseRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "View", &self.view, context);
+        write_json_field(&mut object, "RequestedMaxReferencesPerNode", &self.requested_max_references_per_node, context);
+        write_json_field(&mut object, "NodesToBrowse", &self.nodes_to_browse, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for BrowseRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(BrowseRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            view: read_json_field(object, "View", decoding_limits)?,
+            requested_max_references_per_node: read_json_field(object, "RequestedMaxReferencesPerNode", decoding_limits)?,
+            nodes_to_browse: read_json_field(object, "NodesToBrowse", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -77,3 +78,32 @@ impl BinaryEncoder<BuildInfo> for BuildInfo {
         })
     }
 }
+
+impl JsonEncoder for BuildInfo {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ProductUri", &self.product_uri, context);
+        write_json_field(&mut object, "ManufacturerName", &self.manufacturer_name, context);
+        write_json_field(&mut object, "ProductName", &self.product_name, context);
+        write_json_field(&mut object, "SoftwareVersion", &self.software_version, context);
+        write_json_field(&mut object, "BuildNumber", &self.build_number, context);
+        write_json_field(&mut object, "BuildDate", &self.build_date, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for BuildInfo {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(BuildInfo {
+            product_uri: read_json_field(object, "ProductUri", decoding_limits)?,
+            manufacturer_name: read_json_field(object, "ManufacturerName", decoding_limits)?,
+            product_name: read_json_field(object, "ProductName", decoding_limits)?,
+            software_version: read_json_field(object, "SoftwareVersion", decoding_limits)?,
+            build_number: read_json_field(object, "BuildNumber", decoding_limits)?,
+            build_date: read_json_field(object, "BuildDate", decoding_limits)?,
+        })
+    }
+}
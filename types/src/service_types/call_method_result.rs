@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -68,3 +69,28 @@ impl BinaryEncoder<CallMethodResult> for CallMethodResult {
         })
     }
 }
+
+impl JsonEncoder for CallMethodResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "StatusCode", &self.status_code, context);
+        write_json_field(&mut object, "InputArgumentResults", &self.input_argument_results, context);
+        write_json_field(&mut object, "InputArgumentDiagnosticInfos", &self.input_argument_diagnostic_infos, context);
+        write_json_field(&mut object, "OutputArguments", &self.output_arguments, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for CallMethodResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(CallMethodResult {
+            status_code: read_json_field(object, "StatusCode", decoding_limits)?,
+            input_argument_results: read_json_field(object, "InputArgumentResults", decoding_limits)?,
+            input_argument_diagnostic_infos: read_json_field(object, "InputArgumentDiagnosticInfos", decoding_limits)?,
+            output_arguments: read_json_field(object, "OutputArguments", decoding_limits)?,
+        })
+    }
+}
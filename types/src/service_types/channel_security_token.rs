@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -66,3 +67,28 @@ impl BinaryEncoder<ChannelSecurityToken> for ChannelSecurityToken {
         })
     }
 }
+
+impl JsonEncoder for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ChannelId", &self.channel_id, context);
+        write_json_field(&mut object, "TokenId", &self.token_id, context);
+        write_json_field(&mut object, "CreatedAt", &self.created_at, context);
+        write_json_field(&mut object, "RevisedLifetime", &self.revised_lifetime, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ChannelSecurityToken {
+            channel_id: read_json_field(object, "ChannelId", decoding_limits)?,
+            token_id: read_json_field(object, "TokenId", decoding_limits)?,
+            created_at: read_json_field(object, "CreatedAt", decoding_limits)?,
+            revised_lifetime: read_json_field(object, "RevisedLifetime", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -55,3 +56,24 @@ impl BinaryEncoder<ConfigurationVersionDataType> for ConfigurationVersionDataTyp
         })
     }
 }
+
+impl JsonEncoder for ConfigurationVersionDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "MajorVersion", &self.major_version, context);
+        write_json_field(&mut object, "MinorVersion", &self.minor_version, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ConfigurationVersionDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ConfigurationVersionDataType {
+            major_version: read_json_field(object, "MajorVersion", decoding_limits)?,
+            minor_version: read_json_field(object, "MinorVersion", decoding_limits)?,
+        })
+    }
+}
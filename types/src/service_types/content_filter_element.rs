@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -57,3 +58,24 @@ impl BinaryEncoder<ContentFilterElement> for ContentFilterElement {
         })
     }
 }
+
+impl JsonEncoder for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "FilterOperator", &self.filter_operator, context);
+        write_json_field(&mut object, "FilterOperands", &self.filter_operands, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ContentFilterElement {
+            filter_operator: read_json_field(object, "FilterOperator", decoding_limits)?,
+            filter_operands: read_json_field(object, "FilterOperands", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<ContentFilterElementResult> for ContentFilterElementResult {
         })
     }
 }
+
+impl JsonEncoder for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "StatusCode", &self.status_code, context);
+        write_json_field(&mut object, "OperandStatusCodes", &self.operand_status_codes, context);
+        write_json_field(&mut object, "OperandDiagnosticInfos", &self.operand_diagnostic_infos, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ContentFilterElementResult {
+            status_code: read_json_field(object, "StatusCode", decoding_limits)?,
+            operand_status_codes: read_json_field(object, "OperandStatusCodes", decoding_limits)?,
+            operand_diagnostic_infos: read_json_field(object, "OperandDiagnosticInfos", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -94,3 +95,38 @@ impl BinaryEncoder<CreateSessionRequest> for CreateSessionRequest {
         })
     }
 }
+
+impl JsonEncoder for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "ClientDescription", &self.client_description, context);
+        write_json_field(&mut object, "ServerUri", &self.server_uri, context);
+        write_json_field(&mut object, "EndpointUrl", &self.endpoint_url, context);
+        write_json_field(&mut object, "SessionName", &self.session_name, context);
+        write_json_field(&mut object, "ClientNonce", &self.client_nonce, context);
+        write_json_field(&mut object, "ClientCertificate", &self.client_certificate, context);
+        write_json_field(&mut object, "RequestedSessionTimeout", &self.requested_session_timeout, context);
+        write_json_field(&mut object, "MaxResponseMessageSize", &self.max_response_message_size, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(CreateSessionRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            client_description: read_json_field(object, "ClientDescription", decoding_limits)?,
+            server_uri: read_json_field(object, "ServerUri", decoding_limits)?,
+            endpoint_url: read_json_field(object, "EndpointUrl", decoding_limits)?,
+            session_name: read_json_field(object, "SessionName", decoding_limits)?,
+            client_nonce: read_json_field(object, "ClientNonce", decoding_limits)?,
+            client_certificate: read_json_field(object, "ClientCertificate", decoding_limits)?,
+            requested_session_timeout: read_json_field(object, "RequestedSessionTimeout", decoding_limits)?,
+            max_response_message_size: read_json_field(object, "MaxResponseMessageSize", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -101,3 +102,40 @@ impl BinaryEncoder<CreateSessionResponse> for CreateSessionResponse {
         })
     }
 }
+
+impl JsonEncoder for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ResponseHeader", &self.response_header, context);
+        write_json_field(&mut object, "SessionId", &self.session_id, context);
+        write_json_field(&mut object, "AuthenticationToken", &self.authentication_token, context);
+        write_json_field(&mut object, "RevisedSessionTimeout", &self.revised_session_timeout, context);
+        write_json_field(&mut object, "ServerNonce", &self.server_nonce, context);
+        write_json_field(&mut object, "ServerCertificate", &self.server_certificate, context);
+        write_json_field(&mut object, "ServerEndpoints", &self.server_endpoints, context);
+        write_json_field(&mut object, "ServerSoftwareCertificates", &self.server_software_certificates, context);
+        write_json_field(&mut object, "ServerSignature", &self.server_signature, context);
+        write_json_field(&mut object, "MaxRequestMessageSize", &self.max_request_message_size, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(CreateSessionResponse {
+            response_header: read_json_field(object, "ResponseHeader", decoding_limits)?,
+            session_id: read_json_field(object, "SessionId", decoding_limits)?,
+            authentication_token: read_json_field(object, "AuthenticationToken", decoding_limits)?,
+            revised_session_timeout: read_json_field(object, "RevisedSessionTimeout", decoding_limits)?,
+            server_nonce: read_json_field(object, "ServerNonce", decoding_limits)?,
+            server_certificate: read_json_field(object, "ServerCertificate", decoding_limits)?,
+            server_endpoints: read_json_field(object, "ServerEndpoints", decoding_limits)?,
+            server_software_certificates: read_json_field(object, "ServerSoftwareCertificates", decoding_limits)?,
+            server_signature: read_json_field(object, "ServerSignature", decoding_limits)?,
+            max_request_message_size: read_json_field(object, "MaxRequestMessageSize", decoding_limits)?,
+        })
+    }
+}
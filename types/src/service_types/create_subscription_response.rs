@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -71,3 +72,30 @@ impl BinaryEncoder<CreateSubscriptionResponse> for CreateSubscriptionResponse {
         })
     }
 }
+
+impl JsonEncoder for CreateSubscriptionResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ResponseHeader", &self.response_header, context);
+        write_json_field(&mut object, "SubscriptionId", &self.subscription_id, context);
+        write_json_field(&mut object, "RevisedPublishingInterval", &self.revised_publishing_interval, context);
+        write_json_field(&mut object, "RevisedLifetimeCount", &self.revised_lifetime_count, context);
+        write_json_field(&mut object, "RevisedMaxKeepAliveCount", &self.revised_max_keep_alive_count, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for CreateSubscriptionResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(CreateSubscriptionResponse {
+            response_header: read_json_field(object, "ResponseHeader", decoding_limits)?,
+            subscription_id: read_json_field(object, "SubscriptionId", decoding_limits)?,
+            revised_publishing_interval: read_json_field(object, "RevisedPublishingInterval", decoding_limits)?,
+            revised_lifetime_count: read_json_field(object, "RevisedLifetimeCount", decoding_limits)?,
+            revised_max_keep_alive_count: read_json_field(object, "RevisedMaxKeepAliveCount", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::enums::DataChangeTrigger,
 };
@@ -53,3 +54,26 @@ impl BinaryEncoder<DataChangeFilter> for DataChangeFilter {
         })
     }
 }
+
+impl JsonEncoder for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Trigger", &self.trigger, context);
+        write_json_field(&mut object, "DeadbandType", &self.deadband_type, context);
+        write_json_field(&mut object, "DeadbandValue", &self.deadband_value, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DataChangeFilter {
+            trigger: read_json_field(object, "Trigger", decoding_limits)?,
+            deadband_type: read_json_field(object, "DeadbandType", decoding_limits)?,
+            deadband_value: read_json_field(object, "DeadbandValue", decoding_limits)?,
+        })
+    }
+}
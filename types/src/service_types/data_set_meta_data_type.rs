@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     string::UAString,
     localized_text::LocalizedText,
@@ -90,3 +91,38 @@ impl BinaryEncoder<DataSetMetaDataType> for DataSetMetaDataType {
         })
     }
 }
+
+impl JsonEncoder for DataSetMetaDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Namespaces", &self.namespaces, context);
+        write_json_field(&mut object, "StructureDataTypes", &self.structure_data_types, context);
+        write_json_field(&mut object, "EnumDataTypes", &self.enum_data_types, context);
+        write_json_field(&mut object, "SimpleDataTypes", &self.simple_data_types, context);
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "Description", &self.description, context);
+        write_json_field(&mut object, "Fields", &self.fields, context);
+        write_json_field(&mut object, "DataSetClassId", &self.data_set_class_id, context);
+        write_json_field(&mut object, "ConfigurationVersion", &self.configuration_version, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DataSetMetaDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DataSetMetaDataType {
+            namespaces: read_json_field(object, "Namespaces", decoding_limits)?,
+            structure_data_types: read_json_field(object, "StructureDataTypes", decoding_limits)?,
+            enum_data_types: read_json_field(object, "EnumDataTypes", decoding_limits)?,
+            simple_data_types: read_json_field(object, "SimpleDataTypes", decoding_limits)?,
+            name: read_json_field(object, "Name", decoding_limits)?,
+            description: read_json_field(object, "Description", decoding_limits)?,
+            fields: read_json_field(object, "Fields", decoding_limits)?,
+            data_set_class_id: read_json_field(object, "DataSetClassId", decoding_limits)?,
+            configuration_version: read_json_field(object, "ConfigurationVersion", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -94,3 +95,38 @@ impl BinaryEncoder<DataSetWriterDataType> for DataSetWriterDataType {
         })
     }
 }
+
+impl JsonEncoder for DataSetWriterDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "Enabled", &self.enabled, context);
+        write_json_field(&mut object, "DataSetWriterId", &self.data_set_writer_id, context);
+        write_json_field(&mut object, "DataSetFieldContentMask", &self.data_set_field_content_mask, context);
+        write_json_field(&mut object, "KeyFrameCount", &self.key_frame_count, context);
+        write_json_field(&mut object, "DataSetName", &self.data_set_name, context);
+        write_json_field(&mut object, "DataSetWriterProperties", &self.data_set_writer_properties, context);
+        write_json_field(&mut object, "TransportSettings", &self.transport_settings, context);
+        write_json_field(&mut object, "MessageSettings", &self.message_settings, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DataSetWriterDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DataSetWriterDataType {
+            name: read_json_field(object, "Name", decoding_limits)?,
+            enabled: read_json_field(object, "Enabled", decoding_limits)?,
+            data_set_writer_id: read_json_field(object, "DataSetWriterId", decoding_limits)?,
+            data_set_field_content_mask: read_json_field(object, "DataSetFieldContentMask", decoding_limits)?,
+            key_frame_count: read_json_field(object, "KeyFrameCount", decoding_limits)?,
+            data_set_name: read_json_field(object, "DataSetName", decoding_limits)?,
+            data_set_writer_properties: read_json_field(object, "DataSetWriterProperties", decoding_limits)?,
+            transport_settings: read_json_field(object, "TransportSettings", decoding_limits)?,
+            message_settings: read_json_field(object, "MessageSettings", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -57,3 +58,24 @@ impl BinaryEncoder<DataTypeDescription> for DataTypeDescription {
         })
     }
 }
+
+impl JsonEncoder for DataTypeDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "DataTypeId", &self.data_type_id, context);
+        write_json_field(&mut object, "Name", &self.name, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DataTypeDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DataTypeDescription {
+            data_type_id: read_json_field(object, "DataTypeId", decoding_limits)?,
+            name: read_json_field(object, "Name", decoding_limits)?,
+        })
+    }
+}
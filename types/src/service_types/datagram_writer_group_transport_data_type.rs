@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
 };
 
@@ -47,3 +48,24 @@ impl BinaryEncoder<DatagramWriterGroupTransportDataType> for DatagramWriterGroup
         })
     }
 }
+
+impl JsonEncoder for DatagramWriterGroupTransportDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "MessageRepeatCount", &self.message_repeat_count, context);
+        write_json_field(&mut object, "MessageRepeatDelay", &self.message_repeat_delay, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DatagramWriterGroupTransportDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DatagramWriterGroupTransportDataType {
+            message_repeat_count: read_json_field(object, "MessageRepeatCount", decoding_limits)?,
+            message_repeat_delay: read_json_field(object, "MessageRepeatDelay", decoding_limits)?,
+        })
+    }
+}
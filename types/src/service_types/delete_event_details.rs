@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     node_id::NodeId,
     byte_string::ByteString,
@@ -49,3 +50,24 @@ impl BinaryEncoder<DeleteEventDetails> for DeleteEventDetails {
         })
     }
 }
+
+impl JsonEncoder for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "EventIds", &self.event_ids, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DeleteEventDetails {
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            event_ids: read_json_field(object, "EventIds", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +57,24 @@ impl BinaryEncoder<DeleteNodesItem> for DeleteNodesItem {
         })
     }
 }
+
+impl JsonEncoder for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "DeleteTargetReferences", &self.delete_target_references, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(DeleteNodesItem {
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            delete_target_references: read_json_field(object, "DeleteTargetReferences", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -67,3 +68,28 @@ impl BinaryEncoder<EndpointType> for EndpointType {
         })
     }
 }
+
+impl JsonEncoder for EndpointType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "EndpointUrl", &self.endpoint_url, context);
+        write_json_field(&mut object, "SecurityMode", &self.security_mode, context);
+        write_json_field(&mut object, "SecurityPolicyUri", &self.security_policy_uri, context);
+        write_json_field(&mut object, "TransportProfileUri", &self.transport_profile_uri, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for EndpointType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(EndpointType {
+            endpoint_url: read_json_field(object, "EndpointUrl", decoding_limits)?,
+            security_mode: read_json_field(object, "SecurityMode", decoding_limits)?,
+            security_policy_uri: read_json_field(object, "SecurityPolicyUri", decoding_limits)?,
+            transport_profile_uri: read_json_field(object, "TransportProfileUri", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     localized_text::LocalizedText,
     string::UAString,
@@ -59,3 +60,28 @@ impl BinaryEncoder<EnumField> for EnumField {
         })
     }
 }
+
+impl JsonEncoder for EnumField {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Value", &self.value, context);
+        write_json_field(&mut object, "DisplayName", &self.display_name, context);
+        write_json_field(&mut object, "Description", &self.description, context);
+        write_json_field(&mut object, "Name", &self.name, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for EnumField {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(EnumField {
+            value: read_json_field(object, "Value", decoding_limits)?,
+            display_name: read_json_field(object, "DisplayName", decoding_limits)?,
+            description: read_json_field(object, "Description", decoding_limits)?,
+            name: read_json_field(object, "Name", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +62,26 @@ impl BinaryEncoder<EnumValueType> for EnumValueType {
         })
     }
 }
+
+impl JsonEncoder for EnumValueType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Value", &self.value, context);
+        write_json_field(&mut object, "DisplayName", &self.display_name, context);
+        write_json_field(&mut object, "Description", &self.description, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for EnumValueType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(EnumValueType {
+            value: read_json_field(object, "Value", decoding_limits)?,
+            display_name: read_json_field(object, "DisplayName", decoding_limits)?,
+            description: read_json_field(object, "Description", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -10,9 +10,11 @@
 use std::io::{Read, Write};
 
 use crate::encoding::*;
+use crate::json::*;
 use crate::status_codes::StatusCode;
 
-// All enums assumed to be i32 length in bits when encoded.
+// All enums assumed to be i32 length in bits when encoded. In JSON they are their number, or
+// their name and number in the non-reversible form.
 
 /// The possible encodings for a NodeId value.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -51,6 +53,33 @@ impl BinaryEncoder<NodeIdType> for NodeIdType {
     }
 }
 
+impl JsonEncoder for NodeIdType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for NodeIdType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::TwoByte),
+            1 => Ok(Self::FourByte),
+            2 => Ok(Self::Numeric),
+            3 => Ok(Self::String),
+            4 => Ok(Self::Guid),
+            5 => Ok(Self::ByteString),
+            v => {
+                error!("Invalid value {} for enum NodeIdType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum NamingRuleType {
@@ -82,6 +111,30 @@ impl BinaryEncoder<NamingRuleType> for NamingRuleType {
     }
 }
 
+impl JsonEncoder for NamingRuleType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for NamingRuleType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            1 => Ok(Self::Mandatory),
+            2 => Ok(Self::Optional),
+            3 => Ok(Self::Constraint),
+            v => {
+                error!("Invalid value {} for enum NamingRuleType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum OpenFileMode {
@@ -115,6 +168,31 @@ impl BinaryEncoder<OpenFileMode> for OpenFileMode {
     }
 }
 
+impl JsonEncoder for OpenFileMode {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for OpenFileMode {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            1 => Ok(Self::Read),
+            2 => Ok(Self::Write),
+            4 => Ok(Self::EraseExisting),
+            8 => Ok(Self::Append),
+            v => {
+                error!("Invalid value {} for enum OpenFileMode", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum IdentityCriteriaType {
@@ -152,6 +230,33 @@ impl BinaryEncoder<IdentityCriteriaType> for IdentityCriteriaType {
     }
 }
 
+impl JsonEncoder for IdentityCriteriaType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for IdentityCriteriaType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            1 => Ok(Self::UserName),
+            2 => Ok(Self::Thumbprint),
+            3 => Ok(Self::Role),
+            4 => Ok(Self::GroupId),
+            5 => Ok(Self::Anonymous),
+            6 => Ok(Self::AuthenticatedUser),
+            v => {
+                error!("Invalid value {} for enum IdentityCriteriaType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TrustListMasks {
@@ -189,6 +294,33 @@ impl BinaryEncoder<TrustListMasks> for TrustListMasks {
     }
 }
 
+impl JsonEncoder for TrustListMasks {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for TrustListMasks {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::TrustedCertificates),
+            2 => Ok(Self::TrustedCrls),
+            4 => Ok(Self::IssuerCertificates),
+            8 => Ok(Self::IssuerCrls),
+            15 => Ok(Self::All),
+            v => {
+                error!("Invalid value {} for enum TrustListMasks", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum PubSubState {
@@ -222,6 +354,31 @@ impl BinaryEncoder<PubSubState> for PubSubState {
     }
 }
 
+impl JsonEncoder for PubSubState {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for PubSubState {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Disabled),
+            1 => Ok(Self::Paused),
+            2 => Ok(Self::Operational),
+            3 => Ok(Self::Error),
+            v => {
+                error!("Invalid value {} for enum PubSubState", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum DataSetFieldFlags {
@@ -251,6 +408,29 @@ impl BinaryEncoder<DataSetFieldFlags> for DataSetFieldFlags {
     }
 }
 
+impl JsonEncoder for DataSetFieldFlags {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for DataSetFieldFlags {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::PromotedField),
+            v => {
+                error!("Invalid value {} for enum DataSetFieldFlags", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum DataSetFieldContentMask {
@@ -290,6 +470,34 @@ impl BinaryEncoder<DataSetFieldContentMask> for DataSetFieldContentMask {
     }
 }
 
+impl JsonEncoder for DataSetFieldContentMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for DataSetFieldContentMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::StatusCode),
+            2 => Ok(Self::SourceTimestamp),
+            4 => Ok(Self::ServerTimestamp),
+            8 => Ok(Self::SourcePicoSeconds),
+            16 => Ok(Self::ServerPicoSeconds),
+            32 => Ok(Self::RawData),
+            v => {
+                error!("Invalid value {} for enum DataSetFieldContentMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum OverrideValueHandling {
@@ -321,6 +529,30 @@ impl BinaryEncoder<OverrideValueHandling> for OverrideValueHandling {
     }
 }
 
+impl JsonEncoder for OverrideValueHandling {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for OverrideValueHandling {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Disabled),
+            1 => Ok(Self::LastUsableValue),
+            2 => Ok(Self::OverrideValue),
+            v => {
+                error!("Invalid value {} for enum OverrideValueHandling", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum DataSetOrderingType {
@@ -352,6 +584,30 @@ impl BinaryEncoder<DataSetOrderingType> for DataSetOrderingType {
     }
 }
 
+impl JsonEncoder for DataSetOrderingType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for DataSetOrderingType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Undefined),
+            1 => Ok(Self::AscendingWriterId),
+            2 => Ok(Self::AscendingWriterIdSingle),
+            v => {
+                error!("Invalid value {} for enum DataSetOrderingType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum UadpNetworkMessageContentMask {
@@ -401,6 +657,39 @@ impl BinaryEncoder<UadpNetworkMessageContentMask> for UadpNetworkMessageContentM
     }
 }
 
+impl JsonEncoder for UadpNetworkMessageContentMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for UadpNetworkMessageContentMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::PublisherId),
+            2 => Ok(Self::GroupHeader),
+            4 => Ok(Self::WriterGroupId),
+            8 => Ok(Self::GroupVersion),
+            16 => Ok(Self::NetworkMessageNumber),
+            32 => Ok(Self::SequenceNumber),
+            64 => Ok(Self::PayloadHeader),
+            128 => Ok(Self::Timestamp),
+            256 => Ok(Self::PicoSeconds),
+            512 => Ok(Self::DataSetClassId),
+            1024 => Ok(Self::PromotedFields),
+            v => {
+                error!("Invalid value {} for enum UadpNetworkMessageContentMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum UadpDataSetMessageContentMask {
@@ -440,6 +729,34 @@ impl BinaryEncoder<UadpDataSetMessageContentMask> for UadpDataSetMessageContentM
     }
 }
 
+impl JsonEncoder for UadpDataSetMessageContentMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for UadpDataSetMessageContentMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Timestamp),
+            2 => Ok(Self::PicoSeconds),
+            4 => Ok(Self::Status),
+            8 => Ok(Self::MajorVersion),
+            16 => Ok(Self::MinorVersion),
+            32 => Ok(Self::SequenceNumber),
+            v => {
+                error!("Invalid value {} for enum UadpDataSetMessageContentMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum JsonNetworkMessageContentMask {
@@ -479,6 +796,34 @@ impl BinaryEncoder<JsonNetworkMessageContentMask> for JsonNetworkMessageContentM
     }
 }
 
+impl JsonEncoder for JsonNetworkMessageContentMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for JsonNetworkMessageContentMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::NetworkMessageHeader),
+            2 => Ok(Self::DataSetMessageHeader),
+            4 => Ok(Self::SingleDataSetMessage),
+            8 => Ok(Self::PublisherId),
+            16 => Ok(Self::DataSetClassId),
+            32 => Ok(Self::ReplyTo),
+            v => {
+                error!("Invalid value {} for enum JsonNetworkMessageContentMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum JsonDataSetMessageContentMask {
@@ -516,6 +861,33 @@ impl BinaryEncoder<JsonDataSetMessageContentMask> for JsonDataSetMessageContentM
     }
 }
 
+impl JsonEncoder for JsonDataSetMessageContentMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for JsonDataSetMessageContentMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::DataSetWriterId),
+            2 => Ok(Self::MetaDataVersion),
+            4 => Ok(Self::SequenceNumber),
+            8 => Ok(Self::Timestamp),
+            16 => Ok(Self::Status),
+            v => {
+                error!("Invalid value {} for enum JsonDataSetMessageContentMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum BrokerTransportQualityOfService {
@@ -551,6 +923,32 @@ impl BinaryEncoder<BrokerTransportQualityOfService> for BrokerTransportQualityOf
     }
 }
 
+impl JsonEncoder for BrokerTransportQualityOfService {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for BrokerTransportQualityOfService {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::NotSpecified),
+            1 => Ok(Self::BestEffort),
+            2 => Ok(Self::AtLeastOnce),
+            3 => Ok(Self::AtMostOnce),
+            4 => Ok(Self::ExactlyOnce),
+            v => {
+                error!("Invalid value {} for enum BrokerTransportQualityOfService", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum DiagnosticsLevel {
@@ -586,16 +984,42 @@ impl BinaryEncoder<DiagnosticsLevel> for DiagnosticsLevel {
     }
 }
 
-
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum PubSubDiagnosticsCounterClassification {
-    Information = 0,
-    Error = 1,
+impl JsonEncoder for DiagnosticsLevel {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
 }
 
-impl BinaryEncoder<PubSubDiagnosticsCounterClassification> for PubSubDiagnosticsCounterClassification {
-    fn byte_len(&self) -> usize {
-        4
+impl JsonDecoder for DiagnosticsLevel {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Basic),
+            1 => Ok(Self::Advanced),
+            2 => Ok(Self::Info),
+            3 => Ok(Self::Log),
+            4 => Ok(Self::Debug),
+            v => {
+                error!("Invalid value {} for enum DiagnosticsLevel", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PubSubDiagnosticsCounterClassification {
+    Information = 0,
+    Error = 1,
+}
+
+impl BinaryEncoder<PubSubDiagnosticsCounterClassification> for PubSubDiagnosticsCounterClassification {
+    fn byte_len(&self) -> usize {
+        4
     }
 
     fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
@@ -615,6 +1039,29 @@ impl BinaryEncoder<PubSubDiagnosticsCounterClassification> for PubSubDiagnostics
     }
 }
 
+impl JsonEncoder for PubSubDiagnosticsCounterClassification {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for PubSubDiagnosticsCounterClassification {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Information),
+            1 => Ok(Self::Error),
+            v => {
+                error!("Invalid value {} for enum PubSubDiagnosticsCounterClassification", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum IdType {
@@ -648,6 +1095,31 @@ impl BinaryEncoder<IdType> for IdType {
     }
 }
 
+impl JsonEncoder for IdType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for IdType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Numeric),
+            1 => Ok(Self::String),
+            2 => Ok(Self::Guid),
+            3 => Ok(Self::Opaque),
+            v => {
+                error!("Invalid value {} for enum IdType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum NodeClass {
@@ -691,6 +1163,36 @@ impl BinaryEncoder<NodeClass> for NodeClass {
     }
 }
 
+impl JsonEncoder for NodeClass {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for NodeClass {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Unspecified),
+            1 => Ok(Self::Object),
+            2 => Ok(Self::Variable),
+            4 => Ok(Self::Method),
+            8 => Ok(Self::ObjectType),
+            16 => Ok(Self::VariableType),
+            32 => Ok(Self::ReferenceType),
+            64 => Ok(Self::DataType),
+            128 => Ok(Self::View),
+            v => {
+                error!("Invalid value {} for enum NodeClass", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum PermissionType {
@@ -752,6 +1254,45 @@ impl BinaryEncoder<PermissionType> for PermissionType {
     }
 }
 
+impl JsonEncoder for PermissionType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for PermissionType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Browse),
+            2 => Ok(Self::ReadRolePermissions),
+            4 => Ok(Self::WriteAttribute),
+            8 => Ok(Self::WriteRolePermissions),
+            16 => Ok(Self::WriteHistorizing),
+            32 => Ok(Self::Read),
+            64 => Ok(Self::Write),
+            128 => Ok(Self::ReadHistory),
+            256 => Ok(Self::InsertHistory),
+            512 => Ok(Self::ModifyHistory),
+            1024 => Ok(Self::DeleteHistory),
+            2048 => Ok(Self::ReceiveEvents),
+            4096 => Ok(Self::Call),
+            8192 => Ok(Self::AddReference),
+            16384 => Ok(Self::RemoveReference),
+            32768 => Ok(Self::DeleteNode),
+            65536 => Ok(Self::AddNode),
+            v => {
+                error!("Invalid value {} for enum PermissionType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum AccessLevelType {
@@ -793,6 +1334,35 @@ impl BinaryEncoder<AccessLevelType> for AccessLevelType {
     }
 }
 
+impl JsonEncoder for AccessLevelType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for AccessLevelType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::CurrentRead),
+            2 => Ok(Self::CurrentWrite),
+            4 => Ok(Self::HistoryRead),
+            8 => Ok(Self::HistoryWrite),
+            16 => Ok(Self::SemanticChange),
+            32 => Ok(Self::StatusWrite),
+            64 => Ok(Self::TimestampWrite),
+            v => {
+                error!("Invalid value {} for enum AccessLevelType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum AccessLevelExType {
@@ -840,6 +1410,38 @@ impl BinaryEncoder<AccessLevelExType> for AccessLevelExType {
     }
 }
 
+impl JsonEncoder for AccessLevelExType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for AccessLevelExType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::CurrentRead),
+            2 => Ok(Self::CurrentWrite),
+            4 => Ok(Self::HistoryRead),
+            8 => Ok(Self::HistoryWrite),
+            16 => Ok(Self::SemanticChange),
+            32 => Ok(Self::StatusWrite),
+            64 => Ok(Self::TimestampWrite),
+            256 => Ok(Self::NonatomicRead),
+            512 => Ok(Self::NonatomicWrite),
+            1024 => Ok(Self::WriteFullArrayOnly),
+            v => {
+                error!("Invalid value {} for enum AccessLevelExType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum EventNotifierType {
@@ -873,6 +1475,31 @@ impl BinaryEncoder<EventNotifierType> for EventNotifierType {
     }
 }
 
+impl JsonEncoder for EventNotifierType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for EventNotifierType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::SubscribeToEvents),
+            4 => Ok(Self::HistoryRead),
+            8 => Ok(Self::HistoryWrite),
+            v => {
+                error!("Invalid value {} for enum EventNotifierType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum AccessRestrictionType {
@@ -906,6 +1533,31 @@ impl BinaryEncoder<AccessRestrictionType> for AccessRestrictionType {
     }
 }
 
+impl JsonEncoder for AccessRestrictionType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for AccessRestrictionType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::SigningRequired),
+            2 => Ok(Self::EncryptionRequired),
+            4 => Ok(Self::SessionRequired),
+            v => {
+                error!("Invalid value {} for enum AccessRestrictionType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum StructureType {
@@ -937,6 +1589,30 @@ impl BinaryEncoder<StructureType> for StructureType {
     }
 }
 
+impl JsonEncoder for StructureType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for StructureType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Structure),
+            1 => Ok(Self::StructureWithOptionalFields),
+            2 => Ok(Self::Union),
+            v => {
+                error!("Invalid value {} for enum StructureType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ApplicationType {
@@ -970,6 +1646,31 @@ impl BinaryEncoder<ApplicationType> for ApplicationType {
     }
 }
 
+impl JsonEncoder for ApplicationType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for ApplicationType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Server),
+            1 => Ok(Self::Client),
+            2 => Ok(Self::ClientAndServer),
+            3 => Ok(Self::DiscoveryServer),
+            v => {
+                error!("Invalid value {} for enum ApplicationType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum MessageSecurityMode {
@@ -1003,6 +1704,31 @@ impl BinaryEncoder<MessageSecurityMode> for MessageSecurityMode {
     }
 }
 
+impl JsonEncoder for MessageSecurityMode {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for MessageSecurityMode {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Invalid),
+            1 => Ok(Self::None),
+            2 => Ok(Self::Sign),
+            3 => Ok(Self::SignAndEncrypt),
+            v => {
+                error!("Invalid value {} for enum MessageSecurityMode", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum UserTokenType {
@@ -1036,6 +1762,31 @@ impl BinaryEncoder<UserTokenType> for UserTokenType {
     }
 }
 
+impl JsonEncoder for UserTokenType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for UserTokenType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Anonymous),
+            1 => Ok(Self::UserName),
+            2 => Ok(Self::Certificate),
+            3 => Ok(Self::IssuedToken),
+            v => {
+                error!("Invalid value {} for enum UserTokenType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum SecurityTokenRequestType {
@@ -1065,6 +1816,29 @@ impl BinaryEncoder<SecurityTokenRequestType> for SecurityTokenRequestType {
     }
 }
 
+impl JsonEncoder for SecurityTokenRequestType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for SecurityTokenRequestType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Issue),
+            1 => Ok(Self::Renew),
+            v => {
+                error!("Invalid value {} for enum SecurityTokenRequestType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum NodeAttributesMask {
@@ -1160,6 +1934,62 @@ impl BinaryEncoder<NodeAttributesMask> for NodeAttributesMask {
     }
 }
 
+impl JsonEncoder for NodeAttributesMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for NodeAttributesMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::AccessLevel),
+            2 => Ok(Self::ArrayDimensions),
+            4 => Ok(Self::BrowseName),
+            8 => Ok(Self::ContainsNoLoops),
+            16 => Ok(Self::DataType),
+            32 => Ok(Self::Description),
+            64 => Ok(Self::DisplayName),
+            128 => Ok(Self::EventNotifier),
+            256 => Ok(Self::Executable),
+            512 => Ok(Self::Historizing),
+            1024 => Ok(Self::InverseName),
+            2048 => Ok(Self::IsAbstract),
+            4096 => Ok(Self::MinimumSamplingInterval),
+            8192 => Ok(Self::NodeClass),
+            16384 => Ok(Self::NodeId),
+            32768 => Ok(Self::Symmetric),
+            65536 => Ok(Self::UserAccessLevel),
+            131072 => Ok(Self::UserExecutable),
+            262144 => Ok(Self::UserWriteMask),
+            524288 => Ok(Self::ValueRank),
+            1048576 => Ok(Self::WriteMask),
+            2097152 => Ok(Self::Value),
+            4194304 => Ok(Self::DataTypeDefinition),
+            8388608 => Ok(Self::RolePermissions),
+            16777216 => Ok(Self::AccessRestrictions),
+            33554431 => Ok(Self::All),
+            26501220 => Ok(Self::BaseNode),
+            26501348 => Ok(Self::Object),
+            26503268 => Ok(Self::ObjectType),
+            26571383 => Ok(Self::Variable),
+            28600438 => Ok(Self::VariableType),
+            26632548 => Ok(Self::Method),
+            26537060 => Ok(Self::ReferenceType),
+            26501356 => Ok(Self::View),
+            v => {
+                error!("Invalid value {} for enum NodeAttributesMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum AttributeWriteMask {
@@ -1197,13 +2027,61 @@ impl BinaryEncoder<AttributeWriteMask> for AttributeWriteMask {
         4
     }
 
-    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
-        write_i32(stream, *self as i32)
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        write_i32(stream, *self as i32)
+    }
+
+    fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
+        let value = read_i32(stream)?;
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::AccessLevel),
+            2 => Ok(Self::ArrayDimensions),
+            4 => Ok(Self::BrowseName),
+            8 => Ok(Self::ContainsNoLoops),
+            16 => Ok(Self::DataType),
+            32 => Ok(Self::Description),
+            64 => Ok(Self::DisplayName),
+            128 => Ok(Self::EventNotifier),
+            256 => Ok(Self::Executable),
+            512 => Ok(Self::Historizing),
+            1024 => Ok(Self::InverseName),
+            2048 => Ok(Self::IsAbstract),
+            4096 => Ok(Self::MinimumSamplingInterval),
+            8192 => Ok(Self::NodeClass),
+            16384 => Ok(Self::NodeId),
+            32768 => Ok(Self::Symmetric),
+            65536 => Ok(Self::UserAccessLevel),
+            131072 => Ok(Self::UserExecutable),
+            262144 => Ok(Self::UserWriteMask),
+            524288 => Ok(Self::ValueRank),
+            1048576 => Ok(Self::WriteMask),
+            2097152 => Ok(Self::ValueForVariableType),
+            4194304 => Ok(Self::DataTypeDefinition),
+            8388608 => Ok(Self::RolePermissions),
+            16777216 => Ok(Self::AccessRestrictions),
+            33554432 => Ok(Self::AccessLevelEx),
+            v => {
+                error!("Invalid value {} for enum AttributeWriteMask", v);
+                Err(StatusCode::BadUnexpectedError)
+            }
+        }
+    }
+}
+
+impl JsonEncoder for AttributeWriteMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
     }
+}
 
-    fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
-        let value = read_i32(stream)?;
-        match value {
+impl JsonDecoder for AttributeWriteMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
             0 => Ok(Self::None),
             1 => Ok(Self::AccessLevel),
             2 => Ok(Self::ArrayDimensions),
@@ -1233,7 +2111,7 @@ impl BinaryEncoder<AttributeWriteMask> for AttributeWriteMask {
             33554432 => Ok(Self::AccessLevelEx),
             v => {
                 error!("Invalid value {} for enum AttributeWriteMask", v);
-                Err(StatusCode::BadUnexpectedError)
+                Err(StatusCode::BadDecodingError)
             }
         }
     }
@@ -1272,6 +2150,31 @@ impl BinaryEncoder<BrowseDirection> for BrowseDirection {
     }
 }
 
+impl JsonEncoder for BrowseDirection {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for BrowseDirection {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Forward),
+            1 => Ok(Self::Inverse),
+            2 => Ok(Self::Both),
+            3 => Ok(Self::Invalid),
+            v => {
+                error!("Invalid value {} for enum BrowseDirection", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum BrowseResultMask {
@@ -1317,6 +2220,37 @@ impl BinaryEncoder<BrowseResultMask> for BrowseResultMask {
     }
 }
 
+impl JsonEncoder for BrowseResultMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for BrowseResultMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::ReferenceTypeId),
+            2 => Ok(Self::IsForward),
+            4 => Ok(Self::NodeClass),
+            8 => Ok(Self::BrowseName),
+            16 => Ok(Self::DisplayName),
+            32 => Ok(Self::TypeDefinition),
+            63 => Ok(Self::All),
+            3 => Ok(Self::ReferenceTypeInfo),
+            60 => Ok(Self::TargetInfo),
+            v => {
+                error!("Invalid value {} for enum BrowseResultMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
 pub enum FilterOperator {
@@ -1378,6 +2312,45 @@ impl BinaryEncoder<FilterOperator> for FilterOperator {
     }
 }
 
+impl JsonEncoder for FilterOperator {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for FilterOperator {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Equals),
+            1 => Ok(Self::IsNull),
+            2 => Ok(Self::GreaterThan),
+            3 => Ok(Self::LessThan),
+            4 => Ok(Self::GreaterThanOrEqual),
+            5 => Ok(Self::LessThanOrEqual),
+            6 => Ok(Self::Like),
+            7 => Ok(Self::Not),
+            8 => Ok(Self::Between),
+            9 => Ok(Self::InList),
+            10 => Ok(Self::And),
+            11 => Ok(Self::Or),
+            12 => Ok(Self::Cast),
+            13 => Ok(Self::InView),
+            14 => Ok(Self::OfType),
+            15 => Ok(Self::RelatedTo),
+            16 => Ok(Self::BitwiseAnd),
+            17 => Ok(Self::BitwiseOr),
+            v => {
+                error!("Invalid value {} for enum FilterOperator", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
 pub enum TimestampsToReturn {
@@ -1413,6 +2386,32 @@ impl BinaryEncoder<TimestampsToReturn> for TimestampsToReturn {
     }
 }
 
+impl JsonEncoder for TimestampsToReturn {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for TimestampsToReturn {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Source),
+            1 => Ok(Self::Server),
+            2 => Ok(Self::Both),
+            3 => Ok(Self::Neither),
+            4 => Ok(Self::Invalid),
+            v => {
+                error!("Invalid value {} for enum TimestampsToReturn", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum HistoryUpdateType {
@@ -1446,6 +2445,31 @@ impl BinaryEncoder<HistoryUpdateType> for HistoryUpdateType {
     }
 }
 
+impl JsonEncoder for HistoryUpdateType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for HistoryUpdateType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            1 => Ok(Self::Insert),
+            2 => Ok(Self::Replace),
+            3 => Ok(Self::Update),
+            4 => Ok(Self::Delete),
+            v => {
+                error!("Invalid value {} for enum HistoryUpdateType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum PerformUpdateType {
@@ -1479,6 +2503,31 @@ impl BinaryEncoder<PerformUpdateType> for PerformUpdateType {
     }
 }
 
+impl JsonEncoder for PerformUpdateType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for PerformUpdateType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            1 => Ok(Self::Insert),
+            2 => Ok(Self::Replace),
+            3 => Ok(Self::Update),
+            4 => Ok(Self::Remove),
+            v => {
+                error!("Invalid value {} for enum PerformUpdateType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
 pub enum MonitoringMode {
@@ -1510,6 +2559,30 @@ impl BinaryEncoder<MonitoringMode> for MonitoringMode {
     }
 }
 
+impl JsonEncoder for MonitoringMode {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for MonitoringMode {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Disabled),
+            1 => Ok(Self::Sampling),
+            2 => Ok(Self::Reporting),
+            v => {
+                error!("Invalid value {} for enum MonitoringMode", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
 pub enum DataChangeTrigger {
@@ -1541,6 +2614,30 @@ impl BinaryEncoder<DataChangeTrigger> for DataChangeTrigger {
     }
 }
 
+impl JsonEncoder for DataChangeTrigger {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for DataChangeTrigger {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Status),
+            1 => Ok(Self::StatusValue),
+            2 => Ok(Self::StatusValueTimestamp),
+            v => {
+                error!("Invalid value {} for enum DataChangeTrigger", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum DeadbandType {
@@ -1572,6 +2669,30 @@ impl BinaryEncoder<DeadbandType> for DeadbandType {
     }
 }
 
+impl JsonEncoder for DeadbandType {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for DeadbandType {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Absolute),
+            2 => Ok(Self::Percent),
+            v => {
+                error!("Invalid value {} for enum DeadbandType", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum RedundancySupport {
@@ -1609,6 +2730,33 @@ impl BinaryEncoder<RedundancySupport> for RedundancySupport {
     }
 }
 
+impl JsonEncoder for RedundancySupport {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for RedundancySupport {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Cold),
+            2 => Ok(Self::Warm),
+            3 => Ok(Self::Hot),
+            4 => Ok(Self::Transparent),
+            5 => Ok(Self::HotAndMirrored),
+            v => {
+                error!("Invalid value {} for enum RedundancySupport", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ServerState {
@@ -1650,6 +2798,35 @@ impl BinaryEncoder<ServerState> for ServerState {
     }
 }
 
+impl JsonEncoder for ServerState {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for ServerState {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Running),
+            1 => Ok(Self::Failed),
+            2 => Ok(Self::NoConfiguration),
+            3 => Ok(Self::Suspended),
+            4 => Ok(Self::Shutdown),
+            5 => Ok(Self::Test),
+            6 => Ok(Self::CommunicationFault),
+            7 => Ok(Self::Unknown),
+            v => {
+                error!("Invalid value {} for enum ServerState", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ModelChangeStructureVerbMask {
@@ -1685,6 +2862,32 @@ impl BinaryEncoder<ModelChangeStructureVerbMask> for ModelChangeStructureVerbMas
     }
 }
 
+impl JsonEncoder for ModelChangeStructureVerbMask {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for ModelChangeStructureVerbMask {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            1 => Ok(Self::NodeAdded),
+            2 => Ok(Self::NodeDeleted),
+            4 => Ok(Self::ReferenceAdded),
+            8 => Ok(Self::ReferenceDeleted),
+            16 => Ok(Self::DataTypeChanged),
+            v => {
+                error!("Invalid value {} for enum ModelChangeStructureVerbMask", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum AxisScaleEnumeration {
@@ -1716,6 +2919,30 @@ impl BinaryEncoder<AxisScaleEnumeration> for AxisScaleEnumeration {
     }
 }
 
+impl JsonEncoder for AxisScaleEnumeration {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for AxisScaleEnumeration {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::Linear),
+            1 => Ok(Self::Log),
+            2 => Ok(Self::Ln),
+            v => {
+                error!("Invalid value {} for enum AxisScaleEnumeration", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ExceptionDeviationFormat {
@@ -1750,3 +2977,29 @@ impl BinaryEncoder<ExceptionDeviationFormat> for ExceptionDeviationFormat {
         }
     }
 }
+
+impl JsonEncoder for ExceptionDeviationFormat {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.is_reversible() {
+            JsonValue::from(*self as i32)
+        } else {
+            JsonValue::from(format!("{:?}_{}", self, *self as i32))
+        }
+    }
+}
+
+impl JsonDecoder for ExceptionDeviationFormat {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match read_json_enum(value)? {
+            0 => Ok(Self::AbsoluteValue),
+            1 => Ok(Self::PercentOfValue),
+            2 => Ok(Self::PercentOfRange),
+            3 => Ok(Self::PercentOfEURange),
+            4 => Ok(Self::Unknown),
+            v => {
+                error!("Invalid value {} for enum ExceptionDeviationFormat", v);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +57,24 @@ impl BinaryEncoder<EventFieldList> for EventFieldList {
         })
     }
 }
+
+impl JsonEncoder for EventFieldList {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ClientHandle", &self.client_handle, context);
+        write_json_field(&mut object, "EventFields", &self.event_fields, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for EventFieldList {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(EventFieldList {
+            client_handle: read_json_field(object, "ClientHandle", decoding_limits)?,
+            event_fields: read_json_field(object, "EventFields", decoding_limits)?,
+        })
+    }
+}
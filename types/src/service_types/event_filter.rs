@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::SimpleAttributeOperand,
     service_types::ContentFilter,
@@ -49,3 +50,24 @@ impl BinaryEncoder<EventFilter> for EventFilter {
         })
     }
 }
+
+impl JsonEncoder for EventFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SelectClauses", &self.select_clauses, context);
+        write_json_field(&mut object, "WhereClause", &self.where_clause, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for EventFilter {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(EventFilter {
+            select_clauses: read_json_field(object, "SelectClauses", decoding_limits)?,
+            where_clause: read_json_field(object, "WhereClause", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     status_codes::StatusCode,
     diagnostic_info::DiagnosticInfo,
@@ -55,3 +56,26 @@ impl BinaryEncoder<EventFilterResult> for EventFilterResult {
         })
     }
 }
+
+impl JsonEncoder for EventFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SelectClauseResults", &self.select_clause_results, context);
+        write_json_field(&mut object, "SelectClauseDiagnosticInfos", &self.select_clause_diagnostic_infos, context);
+        write_json_field(&mut object, "WhereClauseResult", &self.where_clause_result, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for EventFilterResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(EventFilterResult {
+            select_clause_results: read_json_field(object, "SelectClauseResults", decoding_limits)?,
+            select_clause_diagnostic_infos: read_json_field(object, "SelectClauseDiagnosticInfos", decoding_limits)?,
+            where_clause_result: read_json_field(object, "WhereClauseResult", decoding_limits)?,
+        })
+    }
+}
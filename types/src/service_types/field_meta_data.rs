@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -101,3 +102,40 @@ impl BinaryEncoder<FieldMetaData> for FieldMetaData {
         })
     }
 }
+
+impl JsonEncoder for FieldMetaData {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "Description", &self.description, context);
+        write_json_field(&mut object, "FieldFlags", &self.field_flags, context);
+        write_json_field(&mut object, "BuiltInType", &self.built_in_type, context);
+        write_json_field(&mut object, "DataType", &self.data_type, context);
+        write_json_field(&mut object, "ValueRank", &self.value_rank, context);
+        write_json_field(&mut object, "ArrayDimensions", &self.array_dimensions, context);
+        write_json_field(&mut object, "MaxStringLength", &self.max_string_length, context);
+        write_json_field(&mut object, "DataSetFieldId", &self.data_set_field_id, context);
+        write_json_field(&mut object, "Properties", &self.properties, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for FieldMetaData {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(FieldMetaData {
+            name: read_json_field(object, "Name", decoding_limits)?,
+            description: read_json_field(object, "Description", decoding_limits)?,
+            field_flags: read_json_field(object, "FieldFlags", decoding_limits)?,
+            built_in_type: read_json_field(object, "BuiltInType", decoding_limits)?,
+            data_type: read_json_field(object, "DataType", decoding_limits)?,
+            value_rank: read_json_field(object, "ValueRank", decoding_limits)?,
+            array_dimensions: read_json_field(object, "ArrayDimensions", decoding_limits)?,
+            max_string_length: read_json_field(object, "MaxStringLength", decoding_limits)?,
+            data_set_field_id: read_json_field(object, "DataSetFieldId", decoding_limits)?,
+            properties: read_json_field(object, "Properties", decoding_limits)?,
+        })
+    }
+}
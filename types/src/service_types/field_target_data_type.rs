@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -85,3 +86,34 @@ impl BinaryEncoder<FieldTargetDataType> for FieldTargetDataType {
         })
     }
 }
+
+impl JsonEncoder for FieldTargetDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "DataSetFieldId", &self.data_set_field_id, context);
+        write_json_field(&mut object, "ReceiverIndexRange", &self.receiver_index_range, context);
+        write_json_field(&mut object, "TargetNodeId", &self.target_node_id, context);
+        write_json_field(&mut object, "AttributeId", &self.attribute_id, context);
+        write_json_field(&mut object, "WriteIndexRange", &self.write_index_range, context);
+        write_json_field(&mut object, "OverrideValueHandling", &self.override_value_handling, context);
+        write_json_field(&mut object, "OverrideValue", &self.override_value, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for FieldTargetDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(FieldTargetDataType {
+            data_set_field_id: read_json_field(object, "DataSetFieldId", decoding_limits)?,
+            receiver_index_range: read_json_field(object, "ReceiverIndexRange", decoding_limits)?,
+            target_node_id: read_json_field(object, "TargetNodeId", decoding_limits)?,
+            attribute_id: read_json_field(object, "AttributeId", decoding_limits)?,
+            write_index_range: read_json_field(object, "WriteIndexRange", decoding_limits)?,
+            override_value_handling: read_json_field(object, "OverrideValueHandling", decoding_limits)?,
+            override_value: read_json_field(object, "OverrideValue", decoding_limits)?,
+        })
+    }
+}
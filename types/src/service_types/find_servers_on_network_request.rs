@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -67,3 +68,28 @@ impl BinaryEncoder<FindServersOnNetworkRequest> for FindServersOnNetworkRequest
         })
     }
 }
+
+impl JsonEncoder for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "StartingRecordId", &self.starting_record_id, context);
+        write_json_field(&mut object, "MaxRecordsToReturn", &self.max_records_to_return, context);
+        write_json_field(&mut object, "ServerCapabilityFilter", &self.server_capability_filter, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(FindServersOnNetworkRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            starting_record_id: read_json_field(object, "StartingRecordId", decoding_limits)?,
+            max_records_to_return: read_json_field(object, "MaxRecordsToReturn", decoding_limits)?,
+            server_capability_filter: read_json_field(object, "ServerCapabilityFilter", decoding_limits)?,
+        })
+    }
+}
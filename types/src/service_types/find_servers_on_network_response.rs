@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +64,26 @@ impl BinaryEncoder<FindServersOnNetworkResponse> for FindServersOnNetworkRespons
         })
     }
 }
+
+impl JsonEncoder for FindServersOnNetworkResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ResponseHeader", &self.response_header, context);
+        write_json_field(&mut object, "LastCounterResetTime", &self.last_counter_reset_time, context);
+        write_json_field(&mut object, "Servers", &self.servers, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for FindServersOnNetworkResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(FindServersOnNetworkResponse {
+            response_header: read_json_field(object, "ResponseHeader", decoding_limits)?,
+            last_counter_reset_time: read_json_field(object, "LastCounterResetTime", decoding_limits)?,
+            servers: read_json_field(object, "Servers", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -67,3 +68,28 @@ impl BinaryEncoder<FindServersRequest> for FindServersRequest {
         })
     }
 }
+
+impl JsonEncoder for FindServersRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "EndpointUrl", &self.endpoint_url, context);
+        write_json_field(&mut object, "LocaleIds", &self.locale_ids, context);
+        write_json_field(&mut object, "ServerUris", &self.server_uris, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for FindServersRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(FindServersRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            endpoint_url: read_json_field(object, "EndpointUrl", decoding_limits)?,
+            locale_ids: read_json_field(object, "LocaleIds", decoding_limits)?,
+            server_uris: read_json_field(object, "ServerUris", decoding_limits)?,
+        })
+    }
+}
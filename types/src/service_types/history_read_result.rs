@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +64,26 @@ impl BinaryEncoder<HistoryReadResult> for HistoryReadResult {
         })
     }
 }
+
+impl JsonEncoder for HistoryReadResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "StatusCode", &self.status_code, context);
+        write_json_field(&mut object, "ContinuationPoint", &self.continuation_point, context);
+        write_json_field(&mut object, "HistoryData", &self.history_data, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for HistoryReadResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(HistoryReadResult {
+            status_code: read_json_field(object, "StatusCode", decoding_limits)?,
+            continuation_point: read_json_field(object, "ContinuationPoint", decoding_limits)?,
+            history_data: read_json_field(object, "HistoryData", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -69,3 +70,28 @@ impl BinaryEncoder<HistoryReadValueId> for HistoryReadValueId {
         })
     }
 }
+
+impl JsonEncoder for HistoryReadValueId {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "IndexRange", &self.index_range, context);
+        write_json_field(&mut object, "DataEncoding", &self.data_encoding, context);
+        write_json_field(&mut object, "ContinuationPoint", &self.continuation_point, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for HistoryReadValueId {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(HistoryReadValueId {
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            index_range: read_json_field(object, "IndexRange", decoding_limits)?,
+            data_encoding: read_json_field(object, "DataEncoding", decoding_limits)?,
+            continuation_point: read_json_field(object, "ContinuationPoint", decoding_limits)?,
+        })
+    }
+}
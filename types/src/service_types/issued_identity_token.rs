@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     string::UAString,
     byte_string::ByteString,
@@ -54,3 +55,26 @@ impl BinaryEncoder<IssuedIdentityToken> for IssuedIdentityToken {
         })
     }
 }
+
+impl JsonEncoder for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "PolicyId", &self.policy_id, context);
+        write_json_field(&mut object, "TokenData", &self.token_data, context);
+        write_json_field(&mut object, "EncryptionAlgorithm", &self.encryption_algorithm, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(IssuedIdentityToken {
+            policy_id: read_json_field(object, "PolicyId", decoding_limits)?,
+            token_data: read_json_field(object, "TokenData", decoding_limits)?,
+            encryption_algorithm: read_json_field(object, "EncryptionAlgorithm", decoding_limits)?,
+        })
+    }
+}
@@ -0,0 +1,455 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
+// DO NOT EDIT THIS FILE
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+use std::convert::TryFrom;
+
+use crate::{
+    encoding::*,
+    json::*,
+    extension_object::ExtensionObject,
+    node_id::{Identifier, NodeId},
+    node_ids::{DataTypeId, ObjectId},
+    service_types::*,
+};
+
+/// Encodes the body of an extension object to JSON if the object holds one of the service types,
+/// returning the id of its data type and its JSON
+pub(crate) fn encode_json_body(extension_object: &ExtensionObject, context: &JsonContext) -> Option<(DataTypeId, JsonValue)> {
+    match extension_object.object_id().ok()? {
+        ObjectId::KeyValuePair_Encoding_DefaultBinary => json_body::<KeyValuePair>(extension_object, DataTypeId::KeyValuePair, context),
+        ObjectId::EndpointType_Encoding_DefaultBinary => json_body::<EndpointType>(extension_object, DataTypeId::EndpointType, context),
+        ObjectId::RationalNumber_Encoding_DefaultBinary => json_body::<RationalNumber>(extension_object, DataTypeId::RationalNumber, context),
+        ObjectId::Vector_Encoding_DefaultBinary => json_body::<Vector>(extension_object, DataTypeId::Vector, context),
+        ObjectId::CartesianCoordinates_Encoding_DefaultBinary => json_body::<CartesianCoordinates>(extension_object, DataTypeId::CartesianCoordinates, context),
+        ObjectId::Orientation_Encoding_DefaultBinary => json_body::<Orientation>(extension_object, DataTypeId::Orientation, context),
+        ObjectId::Frame_Encoding_DefaultBinary => json_body::<Frame>(extension_object, DataTypeId::Frame, context),
+        ObjectId::IdentityMappingRuleType_Encoding_DefaultBinary => json_body::<IdentityMappingRuleType>(extension_object, DataTypeId::IdentityMappingRuleType, context),
+        ObjectId::TrustListDataType_Encoding_DefaultBinary => json_body::<TrustListDataType>(extension_object, DataTypeId::TrustListDataType, context),
+        ObjectId::DecimalDataType_Encoding_DefaultBinary => json_body::<DecimalDataType>(extension_object, DataTypeId::DecimalDataType, context),
+        ObjectId::DataTypeSchemaHeader_Encoding_DefaultBinary => json_body::<DataTypeSchemaHeader>(extension_object, DataTypeId::DataTypeSchemaHeader, context),
+        ObjectId::DataTypeDescription_Encoding_DefaultBinary => json_body::<DataTypeDescription>(extension_object, DataTypeId::DataTypeDescription, context),
+        ObjectId::FieldMetaData_Encoding_DefaultBinary => json_body::<FieldMetaData>(extension_object, DataTypeId::FieldMetaData, context),
+        ObjectId::ConfigurationVersionDataType_Encoding_DefaultBinary => json_body::<ConfigurationVersionDataType>(extension_object, DataTypeId::ConfigurationVersionDataType, context),
+        ObjectId::PublishedDataSetDataType_Encoding_DefaultBinary => json_body::<PublishedDataSetDataType>(extension_object, DataTypeId::PublishedDataSetDataType, context),
+        ObjectId::PublishedDataSetSourceDataType_Encoding_DefaultBinary => json_body::<PublishedDataSetSourceDataType>(extension_object, DataTypeId::PublishedDataSetSourceDataType, context),
+        ObjectId::PublishedVariableDataType_Encoding_DefaultBinary => json_body::<PublishedVariableDataType>(extension_object, DataTypeId::PublishedVariableDataType, context),
+        ObjectId::DataSetWriterDataType_Encoding_DefaultBinary => json_body::<DataSetWriterDataType>(extension_object, DataTypeId::DataSetWriterDataType, context),
+        ObjectId::DataSetWriterTransportDataType_Encoding_DefaultBinary => json_body::<DataSetWriterTransportDataType>(extension_object, DataTypeId::DataSetWriterTransportDataType, context),
+        ObjectId::DataSetWriterMessageDataType_Encoding_DefaultBinary => json_body::<DataSetWriterMessageDataType>(extension_object, DataTypeId::DataSetWriterMessageDataType, context),
+        ObjectId::PubSubGroupDataType_Encoding_DefaultBinary => json_body::<PubSubGroupDataType>(extension_object, DataTypeId::PubSubGroupDataType, context),
+        ObjectId::WriterGroupTransportDataType_Encoding_DefaultBinary => json_body::<WriterGroupTransportDataType>(extension_object, DataTypeId::WriterGroupTransportDataType, context),
+        ObjectId::WriterGroupMessageDataType_Encoding_DefaultBinary => json_body::<WriterGroupMessageDataType>(extension_object, DataTypeId::WriterGroupMessageDataType, context),
+        ObjectId::PubSubConnectionDataType_Encoding_DefaultBinary => json_body::<PubSubConnectionDataType>(extension_object, DataTypeId::PubSubConnectionDataType, context),
+        ObjectId::ConnectionTransportDataType_Encoding_DefaultBinary => json_body::<ConnectionTransportDataType>(extension_object, DataTypeId::ConnectionTransportDataType, context),
+        ObjectId::NetworkAddressDataType_Encoding_DefaultBinary => json_body::<NetworkAddressDataType>(extension_object, DataTypeId::NetworkAddressDataType, context),
+        ObjectId::ReaderGroupTransportDataType_Encoding_DefaultBinary => json_body::<ReaderGroupTransportDataType>(extension_object, DataTypeId::ReaderGroupTransportDataType, context),
+        ObjectId::ReaderGroupMessageDataType_Encoding_DefaultBinary => json_body::<ReaderGroupMessageDataType>(extension_object, DataTypeId::ReaderGroupMessageDataType, context),
+        ObjectId::DataSetReaderDataType_Encoding_DefaultBinary => json_body::<DataSetReaderDataType>(extension_object, DataTypeId::DataSetReaderDataType, context),
+        ObjectId::DataSetReaderTransportDataType_Encoding_DefaultBinary => json_body::<DataSetReaderTransportDataType>(extension_object, DataTypeId::DataSetReaderTransportDataType, context),
+        ObjectId::DataSetReaderMessageDataType_Encoding_DefaultBinary => json_body::<DataSetReaderMessageDataType>(extension_object, DataTypeId::DataSetReaderMessageDataType, context),
+        ObjectId::SubscribedDataSetDataType_Encoding_DefaultBinary => json_body::<SubscribedDataSetDataType>(extension_object, DataTypeId::SubscribedDataSetDataType, context),
+        ObjectId::FieldTargetDataType_Encoding_DefaultBinary => json_body::<FieldTargetDataType>(extension_object, DataTypeId::FieldTargetDataType, context),
+        ObjectId::PubSubConfigurationDataType_Encoding_DefaultBinary => json_body::<PubSubConfigurationDataType>(extension_object, DataTypeId::PubSubConfigurationDataType, context),
+        ObjectId::RolePermissionType_Encoding_DefaultBinary => json_body::<RolePermissionType>(extension_object, DataTypeId::RolePermissionType, context),
+        ObjectId::StructureField_Encoding_DefaultBinary => json_body::<StructureField>(extension_object, DataTypeId::StructureField, context),
+        ObjectId::Argument_Encoding_DefaultBinary => json_body::<Argument>(extension_object, DataTypeId::Argument, context),
+        ObjectId::EnumValueType_Encoding_DefaultBinary => json_body::<EnumValueType>(extension_object, DataTypeId::EnumValueType, context),
+        ObjectId::OptionSet_Encoding_DefaultBinary => json_body::<OptionSet>(extension_object, DataTypeId::OptionSet, context),
+        ObjectId::TimeZoneDataType_Encoding_DefaultBinary => json_body::<TimeZoneDataType>(extension_object, DataTypeId::TimeZoneDataType, context),
+        ObjectId::ApplicationDescription_Encoding_DefaultBinary => json_body::<ApplicationDescription>(extension_object, DataTypeId::ApplicationDescription, context),
+        ObjectId::ServiceFault_Encoding_DefaultBinary => json_body::<ServiceFault>(extension_object, DataTypeId::ServiceFault, context),
+        ObjectId::SessionlessInvokeRequestType_Encoding_DefaultBinary => json_body::<SessionlessInvokeRequestType>(extension_object, DataTypeId::SessionlessInvokeRequestType, context),
+        ObjectId::SessionlessInvokeResponseType_Encoding_DefaultBinary => json_body::<SessionlessInvokeResponseType>(extension_object, DataTypeId::SessionlessInvokeResponseType, context),
+        ObjectId::FindServersRequest_Encoding_DefaultBinary => json_body::<FindServersRequest>(extension_object, DataTypeId::FindServersRequest, context),
+        ObjectId::FindServersResponse_Encoding_DefaultBinary => json_body::<FindServersResponse>(extension_object, DataTypeId::FindServersResponse, context),
+        ObjectId::ServerOnNetwork_Encoding_DefaultBinary => json_body::<ServerOnNetwork>(extension_object, DataTypeId::ServerOnNetwork, context),
+        ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary => json_body::<FindServersOnNetworkRequest>(extension_object, DataTypeId::FindServersOnNetworkRequest, context),
+        ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary => json_body::<FindServersOnNetworkResponse>(extension_object, DataTypeId::FindServersOnNetworkResponse, context),
+        ObjectId::UserTokenPolicy_Encoding_DefaultBinary => json_body::<UserTokenPolicy>(extension_object, DataTypeId::UserTokenPolicy, context),
+        ObjectId::EndpointDescription_Encoding_DefaultBinary => json_body::<EndpointDescription>(extension_object, DataTypeId::EndpointDescription, context),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultBinary => json_body::<GetEndpointsRequest>(extension_object, DataTypeId::GetEndpointsRequest, context),
+        ObjectId::GetEndpointsResponse_Encoding_DefaultBinary => json_body::<GetEndpointsResponse>(extension_object, DataTypeId::GetEndpointsResponse, context),
+        ObjectId::RegisteredServer_Encoding_DefaultBinary => json_body::<RegisteredServer>(extension_object, DataTypeId::RegisteredServer, context),
+        ObjectId::RegisterServerRequest_Encoding_DefaultBinary => json_body::<RegisterServerRequest>(extension_object, DataTypeId::RegisterServerRequest, context),
+        ObjectId::RegisterServerResponse_Encoding_DefaultBinary => json_body::<RegisterServerResponse>(extension_object, DataTypeId::RegisterServerResponse, context),
+        ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary => json_body::<DiscoveryConfiguration>(extension_object, DataTypeId::DiscoveryConfiguration, context),
+        ObjectId::RegisterServer2Request_Encoding_DefaultBinary => json_body::<RegisterServer2Request>(extension_object, DataTypeId::RegisterServer2Request, context),
+        ObjectId::RegisterServer2Response_Encoding_DefaultBinary => json_body::<RegisterServer2Response>(extension_object, DataTypeId::RegisterServer2Response, context),
+        ObjectId::ChannelSecurityToken_Encoding_DefaultBinary => json_body::<ChannelSecurityToken>(extension_object, DataTypeId::ChannelSecurityToken, context),
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary => json_body::<OpenSecureChannelRequest>(extension_object, DataTypeId::OpenSecureChannelRequest, context),
+        ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary => json_body::<OpenSecureChannelResponse>(extension_object, DataTypeId::OpenSecureChannelResponse, context),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary => json_body::<CloseSecureChannelRequest>(extension_object, DataTypeId::CloseSecureChannelRequest, context),
+        ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary => json_body::<CloseSecureChannelResponse>(extension_object, DataTypeId::CloseSecureChannelResponse, context),
+        ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary => json_body::<SignedSoftwareCertificate>(extension_object, DataTypeId::SignedSoftwareCertificate, context),
+        ObjectId::SignatureData_Encoding_DefaultBinary => json_body::<SignatureData>(extension_object, DataTypeId::SignatureData, context),
+        ObjectId::CreateSessionRequest_Encoding_DefaultBinary => json_body::<CreateSessionRequest>(extension_object, DataTypeId::CreateSessionRequest, context),
+        ObjectId::CreateSessionResponse_Encoding_DefaultBinary => json_body::<CreateSessionResponse>(extension_object, DataTypeId::CreateSessionResponse, context),
+        ObjectId::UserIdentityToken_Encoding_DefaultBinary => json_body::<UserIdentityToken>(extension_object, DataTypeId::UserIdentityToken, context),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultBinary => json_body::<ActivateSessionRequest>(extension_object, DataTypeId::ActivateSessionRequest, context),
+        ObjectId::ActivateSessionResponse_Encoding_DefaultBinary => json_body::<ActivateSessionResponse>(extension_object, DataTypeId::ActivateSessionResponse, context),
+        ObjectId::CloseSessionRequest_Encoding_DefaultBinary => json_body::<CloseSessionRequest>(extension_object, DataTypeId::CloseSessionRequest, context),
+        ObjectId::CloseSessionResponse_Encoding_DefaultBinary => json_body::<CloseSessionResponse>(extension_object, DataTypeId::CloseSessionResponse, context),
+        ObjectId::CancelRequest_Encoding_DefaultBinary => json_body::<CancelRequest>(extension_object, DataTypeId::CancelRequest, context),
+        ObjectId::CancelResponse_Encoding_DefaultBinary => json_body::<CancelResponse>(extension_object, DataTypeId::CancelResponse, context),
+        ObjectId::NodeAttributes_Encoding_DefaultBinary => json_body::<NodeAttributes>(extension_object, DataTypeId::NodeAttributes, context),
+        ObjectId::GenericAttributeValue_Encoding_DefaultBinary => json_body::<GenericAttributeValue>(extension_object, DataTypeId::GenericAttributeValue, context),
+        ObjectId::AddNodesItem_Encoding_DefaultBinary => json_body::<AddNodesItem>(extension_object, DataTypeId::AddNodesItem, context),
+        ObjectId::AddNodesResult_Encoding_DefaultBinary => json_body::<AddNodesResult>(extension_object, DataTypeId::AddNodesResult, context),
+        ObjectId::AddNodesRequest_Encoding_DefaultBinary => json_body::<AddNodesRequest>(extension_object, DataTypeId::AddNodesRequest, context),
+        ObjectId::AddNodesResponse_Encoding_DefaultBinary => json_body::<AddNodesResponse>(extension_object, DataTypeId::AddNodesResponse, context),
+        ObjectId::AddReferencesItem_Encoding_DefaultBinary => json_body::<AddReferencesItem>(extension_object, DataTypeId::AddReferencesItem, context),
+        ObjectId::AddReferencesRequest_Encoding_DefaultBinary => json_body::<AddReferencesRequest>(extension_object, DataTypeId::AddReferencesRequest, context),
+        ObjectId::AddReferencesResponse_Encoding_DefaultBinary => json_body::<AddReferencesResponse>(extension_object, DataTypeId::AddReferencesResponse, context),
+        ObjectId::DeleteNodesItem_Encoding_DefaultBinary => json_body::<DeleteNodesItem>(extension_object, DataTypeId::DeleteNodesItem, context),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => json_body::<DeleteNodesRequest>(extension_object, DataTypeId::DeleteNodesRequest, context),
+        ObjectId::DeleteNodesResponse_Encoding_DefaultBinary => json_body::<DeleteNodesResponse>(extension_object, DataTypeId::DeleteNodesResponse, context),
+        ObjectId::DeleteReferencesItem_Encoding_DefaultBinary => json_body::<DeleteReferencesItem>(extension_object, DataTypeId::DeleteReferencesItem, context),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => json_body::<DeleteReferencesRequest>(extension_object, DataTypeId::DeleteReferencesRequest, context),
+        ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary => json_body::<DeleteReferencesResponse>(extension_object, DataTypeId::DeleteReferencesResponse, context),
+        ObjectId::ViewDescription_Encoding_DefaultBinary => json_body::<ViewDescription>(extension_object, DataTypeId::ViewDescription, context),
+        ObjectId::BrowseDescription_Encoding_DefaultBinary => json_body::<BrowseDescription>(extension_object, DataTypeId::BrowseDescription, context),
+        ObjectId::ReferenceDescription_Encoding_DefaultBinary => json_body::<ReferenceDescription>(extension_object, DataTypeId::ReferenceDescription, context),
+        ObjectId::BrowseResult_Encoding_DefaultBinary => json_body::<BrowseResult>(extension_object, DataTypeId::BrowseResult, context),
+        ObjectId::BrowseRequest_Encoding_DefaultBinary => json_body::<BrowseRequest>(extension_object, DataTypeId::BrowseRequest, context),
+        ObjectId::BrowseResponse_Encoding_DefaultBinary => json_body::<BrowseResponse>(extension_object, DataTypeId::BrowseResponse, context),
+        ObjectId::BrowseNextRequest_Encoding_DefaultBinary => json_body::<BrowseNextRequest>(extension_object, DataTypeId::BrowseNextRequest, context),
+        ObjectId::BrowseNextResponse_Encoding_DefaultBinary => json_body::<BrowseNextResponse>(extension_object, DataTypeId::BrowseNextResponse, context),
+        ObjectId::RelativePathElement_Encoding_DefaultBinary => json_body::<RelativePathElement>(extension_object, DataTypeId::RelativePathElement, context),
+        ObjectId::RelativePath_Encoding_DefaultBinary => json_body::<RelativePath>(extension_object, DataTypeId::RelativePath, context),
+        ObjectId::BrowsePath_Encoding_DefaultBinary => json_body::<BrowsePath>(extension_object, DataTypeId::BrowsePath, context),
+        ObjectId::BrowsePathTarget_Encoding_DefaultBinary => json_body::<BrowsePathTarget>(extension_object, DataTypeId::BrowsePathTarget, context),
+        ObjectId::BrowsePathResult_Encoding_DefaultBinary => json_body::<BrowsePathResult>(extension_object, DataTypeId::BrowsePathResult, context),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => json_body::<TranslateBrowsePathsToNodeIdsRequest>(extension_object, DataTypeId::TranslateBrowsePathsToNodeIdsRequest, context),
+        ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary => json_body::<TranslateBrowsePathsToNodeIdsResponse>(extension_object, DataTypeId::TranslateBrowsePathsToNodeIdsResponse, context),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => json_body::<RegisterNodesRequest>(extension_object, DataTypeId::RegisterNodesRequest, context),
+        ObjectId::RegisterNodesResponse_Encoding_DefaultBinary => json_body::<RegisterNodesResponse>(extension_object, DataTypeId::RegisterNodesResponse, context),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => json_body::<UnregisterNodesRequest>(extension_object, DataTypeId::UnregisterNodesRequest, context),
+        ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary => json_body::<UnregisterNodesResponse>(extension_object, DataTypeId::UnregisterNodesResponse, context),
+        ObjectId::EndpointConfiguration_Encoding_DefaultBinary => json_body::<EndpointConfiguration>(extension_object, DataTypeId::EndpointConfiguration, context),
+        ObjectId::QueryDataDescription_Encoding_DefaultBinary => json_body::<QueryDataDescription>(extension_object, DataTypeId::QueryDataDescription, context),
+        ObjectId::NodeTypeDescription_Encoding_DefaultBinary => json_body::<NodeTypeDescription>(extension_object, DataTypeId::NodeTypeDescription, context),
+        ObjectId::QueryDataSet_Encoding_DefaultBinary => json_body::<QueryDataSet>(extension_object, DataTypeId::QueryDataSet, context),
+        ObjectId::NodeReference_Encoding_DefaultBinary => json_body::<NodeReference>(extension_object, DataTypeId::NodeReference, context),
+        ObjectId::ContentFilterElement_Encoding_DefaultBinary => json_body::<ContentFilterElement>(extension_object, DataTypeId::ContentFilterElement, context),
+        ObjectId::ContentFilter_Encoding_DefaultBinary => json_body::<ContentFilter>(extension_object, DataTypeId::ContentFilter, context),
+        ObjectId::FilterOperand_Encoding_DefaultBinary => json_body::<FilterOperand>(extension_object, DataTypeId::FilterOperand, context),
+        ObjectId::ContentFilterElementResult_Encoding_DefaultBinary => json_body::<ContentFilterElementResult>(extension_object, DataTypeId::ContentFilterElementResult, context),
+        ObjectId::ContentFilterResult_Encoding_DefaultBinary => json_body::<ContentFilterResult>(extension_object, DataTypeId::ContentFilterResult, context),
+        ObjectId::ParsingResult_Encoding_DefaultBinary => json_body::<ParsingResult>(extension_object, DataTypeId::ParsingResult, context),
+        ObjectId::QueryFirstRequest_Encoding_DefaultBinary => json_body::<QueryFirstRequest>(extension_object, DataTypeId::QueryFirstRequest, context),
+        ObjectId::QueryFirstResponse_Encoding_DefaultBinary => json_body::<QueryFirstResponse>(extension_object, DataTypeId::QueryFirstResponse, context),
+        ObjectId::QueryNextRequest_Encoding_DefaultBinary => json_body::<QueryNextRequest>(extension_object, DataTypeId::QueryNextRequest, context),
+        ObjectId::QueryNextResponse_Encoding_DefaultBinary => json_body::<QueryNextResponse>(extension_object, DataTypeId::QueryNextResponse, context),
+        ObjectId::ReadValueId_Encoding_DefaultBinary => json_body::<ReadValueId>(extension_object, DataTypeId::ReadValueId, context),
+        ObjectId::ReadRequest_Encoding_DefaultBinary => json_body::<ReadRequest>(extension_object, DataTypeId::ReadRequest, context),
+        ObjectId::ReadResponse_Encoding_DefaultBinary => json_body::<ReadResponse>(extension_object, DataTypeId::ReadResponse, context),
+        ObjectId::HistoryReadValueId_Encoding_DefaultBinary => json_body::<HistoryReadValueId>(extension_object, DataTypeId::HistoryReadValueId, context),
+        ObjectId::HistoryReadResult_Encoding_DefaultBinary => json_body::<HistoryReadResult>(extension_object, DataTypeId::HistoryReadResult, context),
+        ObjectId::HistoryReadDetails_Encoding_DefaultBinary => json_body::<HistoryReadDetails>(extension_object, DataTypeId::HistoryReadDetails, context),
+        ObjectId::HistoryData_Encoding_DefaultBinary => json_body::<HistoryData>(extension_object, DataTypeId::HistoryData, context),
+        ObjectId::ModificationInfo_Encoding_DefaultBinary => json_body::<ModificationInfo>(extension_object, DataTypeId::ModificationInfo, context),
+        ObjectId::HistoryEvent_Encoding_DefaultBinary => json_body::<HistoryEvent>(extension_object, DataTypeId::HistoryEvent, context),
+        ObjectId::HistoryReadRequest_Encoding_DefaultBinary => json_body::<HistoryReadRequest>(extension_object, DataTypeId::HistoryReadRequest, context),
+        ObjectId::HistoryReadResponse_Encoding_DefaultBinary => json_body::<HistoryReadResponse>(extension_object, DataTypeId::HistoryReadResponse, context),
+        ObjectId::WriteValue_Encoding_DefaultBinary => json_body::<WriteValue>(extension_object, DataTypeId::WriteValue, context),
+        ObjectId::WriteRequest_Encoding_DefaultBinary => json_body::<WriteRequest>(extension_object, DataTypeId::WriteRequest, context),
+        ObjectId::WriteResponse_Encoding_DefaultBinary => json_body::<WriteResponse>(extension_object, DataTypeId::WriteResponse, context),
+        ObjectId::HistoryUpdateDetails_Encoding_DefaultBinary => json_body::<HistoryUpdateDetails>(extension_object, DataTypeId::HistoryUpdateDetails, context),
+        ObjectId::HistoryUpdateResult_Encoding_DefaultBinary => json_body::<HistoryUpdateResult>(extension_object, DataTypeId::HistoryUpdateResult, context),
+        ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary => json_body::<HistoryUpdateRequest>(extension_object, DataTypeId::HistoryUpdateRequest, context),
+        ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary => json_body::<HistoryUpdateResponse>(extension_object, DataTypeId::HistoryUpdateResponse, context),
+        ObjectId::CallMethodRequest_Encoding_DefaultBinary => json_body::<CallMethodRequest>(extension_object, DataTypeId::CallMethodRequest, context),
+        ObjectId::CallMethodResult_Encoding_DefaultBinary => json_body::<CallMethodResult>(extension_object, DataTypeId::CallMethodResult, context),
+        ObjectId::CallRequest_Encoding_DefaultBinary => json_body::<CallRequest>(extension_object, DataTypeId::CallRequest, context),
+        ObjectId::CallResponse_Encoding_DefaultBinary => json_body::<CallResponse>(extension_object, DataTypeId::CallResponse, context),
+        ObjectId::MonitoringFilter_Encoding_DefaultBinary => json_body::<MonitoringFilter>(extension_object, DataTypeId::MonitoringFilter, context),
+        ObjectId::AggregateConfiguration_Encoding_DefaultBinary => json_body::<AggregateConfiguration>(extension_object, DataTypeId::AggregateConfiguration, context),
+        ObjectId::MonitoringFilterResult_Encoding_DefaultBinary => json_body::<MonitoringFilterResult>(extension_object, DataTypeId::MonitoringFilterResult, context),
+        ObjectId::MonitoringParameters_Encoding_DefaultBinary => json_body::<MonitoringParameters>(extension_object, DataTypeId::MonitoringParameters, context),
+        ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary => json_body::<MonitoredItemCreateRequest>(extension_object, DataTypeId::MonitoredItemCreateRequest, context),
+        ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary => json_body::<MonitoredItemCreateResult>(extension_object, DataTypeId::MonitoredItemCreateResult, context),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary => json_body::<CreateMonitoredItemsRequest>(extension_object, DataTypeId::CreateMonitoredItemsRequest, context),
+        ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary => json_body::<CreateMonitoredItemsResponse>(extension_object, DataTypeId::CreateMonitoredItemsResponse, context),
+        ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary => json_body::<MonitoredItemModifyRequest>(extension_object, DataTypeId::MonitoredItemModifyRequest, context),
+        ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary => json_body::<MonitoredItemModifyResult>(extension_object, DataTypeId::MonitoredItemModifyResult, context),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary => json_body::<ModifyMonitoredItemsRequest>(extension_object, DataTypeId::ModifyMonitoredItemsRequest, context),
+        ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary => json_body::<ModifyMonitoredItemsResponse>(extension_object, DataTypeId::ModifyMonitoredItemsResponse, context),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => json_body::<SetMonitoringModeRequest>(extension_object, DataTypeId::SetMonitoringModeRequest, context),
+        ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary => json_body::<SetMonitoringModeResponse>(extension_object, DataTypeId::SetMonitoringModeResponse, context),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => json_body::<SetTriggeringRequest>(extension_object, DataTypeId::SetTriggeringRequest, context),
+        ObjectId::SetTriggeringResponse_Encoding_DefaultBinary => json_body::<SetTriggeringResponse>(extension_object, DataTypeId::SetTriggeringResponse, context),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary => json_body::<DeleteMonitoredItemsRequest>(extension_object, DataTypeId::DeleteMonitoredItemsRequest, context),
+        ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary => json_body::<DeleteMonitoredItemsResponse>(extension_object, DataTypeId::DeleteMonitoredItemsResponse, context),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => json_body::<CreateSubscriptionRequest>(extension_object, DataTypeId::CreateSubscriptionRequest, context),
+        ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary => json_body::<CreateSubscriptionResponse>(extension_object, DataTypeId::CreateSubscriptionResponse, context),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary => json_body::<ModifySubscriptionRequest>(extension_object, DataTypeId::ModifySubscriptionRequest, context),
+        ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary => json_body::<ModifySubscriptionResponse>(extension_object, DataTypeId::ModifySubscriptionResponse, context),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary => json_body::<SetPublishingModeRequest>(extension_object, DataTypeId::SetPublishingModeRequest, context),
+        ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary => json_body::<SetPublishingModeResponse>(extension_object, DataTypeId::SetPublishingModeResponse, context),
+        ObjectId::NotificationMessage_Encoding_DefaultBinary => json_body::<NotificationMessage>(extension_object, DataTypeId::NotificationMessage, context),
+        ObjectId::NotificationData_Encoding_DefaultBinary => json_body::<NotificationData>(extension_object, DataTypeId::NotificationData, context),
+        ObjectId::MonitoredItemNotification_Encoding_DefaultBinary => json_body::<MonitoredItemNotification>(extension_object, DataTypeId::MonitoredItemNotification, context),
+        ObjectId::EventFieldList_Encoding_DefaultBinary => json_body::<EventFieldList>(extension_object, DataTypeId::EventFieldList, context),
+        ObjectId::HistoryEventFieldList_Encoding_DefaultBinary => json_body::<HistoryEventFieldList>(extension_object, DataTypeId::HistoryEventFieldList, context),
+        ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary => json_body::<SubscriptionAcknowledgement>(extension_object, DataTypeId::SubscriptionAcknowledgement, context),
+        ObjectId::PublishRequest_Encoding_DefaultBinary => json_body::<PublishRequest>(extension_object, DataTypeId::PublishRequest, context),
+        ObjectId::PublishResponse_Encoding_DefaultBinary => json_body::<PublishResponse>(extension_object, DataTypeId::PublishResponse, context),
+        ObjectId::RepublishRequest_Encoding_DefaultBinary => json_body::<RepublishRequest>(extension_object, DataTypeId::RepublishRequest, context),
+        ObjectId::RepublishResponse_Encoding_DefaultBinary => json_body::<RepublishResponse>(extension_object, DataTypeId::RepublishResponse, context),
+        ObjectId::TransferResult_Encoding_DefaultBinary => json_body::<TransferResult>(extension_object, DataTypeId::TransferResult, context),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => json_body::<TransferSubscriptionsRequest>(extension_object, DataTypeId::TransferSubscriptionsRequest, context),
+        ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary => json_body::<TransferSubscriptionsResponse>(extension_object, DataTypeId::TransferSubscriptionsResponse, context),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary => json_body::<DeleteSubscriptionsRequest>(extension_object, DataTypeId::DeleteSubscriptionsRequest, context),
+        ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary => json_body::<DeleteSubscriptionsResponse>(extension_object, DataTypeId::DeleteSubscriptionsResponse, context),
+        ObjectId::BuildInfo_Encoding_DefaultBinary => json_body::<BuildInfo>(extension_object, DataTypeId::BuildInfo, context),
+        ObjectId::RedundantServerDataType_Encoding_DefaultBinary => json_body::<RedundantServerDataType>(extension_object, DataTypeId::RedundantServerDataType, context),
+        ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary => json_body::<EndpointUrlListDataType>(extension_object, DataTypeId::EndpointUrlListDataType, context),
+        ObjectId::NetworkGroupDataType_Encoding_DefaultBinary => json_body::<NetworkGroupDataType>(extension_object, DataTypeId::NetworkGroupDataType, context),
+        ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary => json_body::<SamplingIntervalDiagnosticsDataType>(extension_object, DataTypeId::SamplingIntervalDiagnosticsDataType, context),
+        ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary => json_body::<ServerDiagnosticsSummaryDataType>(extension_object, DataTypeId::ServerDiagnosticsSummaryDataType, context),
+        ObjectId::ServerStatusDataType_Encoding_DefaultBinary => json_body::<ServerStatusDataType>(extension_object, DataTypeId::ServerStatusDataType, context),
+        ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary => json_body::<SessionDiagnosticsDataType>(extension_object, DataTypeId::SessionDiagnosticsDataType, context),
+        ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary => json_body::<SessionSecurityDiagnosticsDataType>(extension_object, DataTypeId::SessionSecurityDiagnosticsDataType, context),
+        ObjectId::ServiceCounterDataType_Encoding_DefaultBinary => json_body::<ServiceCounterDataType>(extension_object, DataTypeId::ServiceCounterDataType, context),
+        ObjectId::StatusResult_Encoding_DefaultBinary => json_body::<StatusResult>(extension_object, DataTypeId::StatusResult, context),
+        ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary => json_body::<SubscriptionDiagnosticsDataType>(extension_object, DataTypeId::SubscriptionDiagnosticsDataType, context),
+        ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary => json_body::<ModelChangeStructureDataType>(extension_object, DataTypeId::ModelChangeStructureDataType, context),
+        ObjectId::SemanticChangeStructureDataType_Encoding_DefaultBinary => json_body::<SemanticChangeStructureDataType>(extension_object, DataTypeId::SemanticChangeStructureDataType, context),
+        ObjectId::Range_Encoding_DefaultBinary => json_body::<Range>(extension_object, DataTypeId::Range, context),
+        ObjectId::EUInformation_Encoding_DefaultBinary => json_body::<EUInformation>(extension_object, DataTypeId::EUInformation, context),
+        ObjectId::ComplexNumberType_Encoding_DefaultBinary => json_body::<ComplexNumberType>(extension_object, DataTypeId::ComplexNumberType, context),
+        ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary => json_body::<DoubleComplexNumberType>(extension_object, DataTypeId::DoubleComplexNumberType, context),
+        ObjectId::AxisInformation_Encoding_DefaultBinary => json_body::<AxisInformation>(extension_object, DataTypeId::AxisInformation, context),
+        ObjectId::XVType_Encoding_DefaultBinary => json_body::<XVType>(extension_object, DataTypeId::XVType, context),
+        ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary => json_body::<ProgramDiagnosticDataType>(extension_object, DataTypeId::ProgramDiagnosticDataType, context),
+        ObjectId::ProgramDiagnostic2DataType_Encoding_DefaultBinary => json_body::<ProgramDiagnostic2DataType>(extension_object, DataTypeId::ProgramDiagnostic2DataType, context),
+        ObjectId::Annotation_Encoding_DefaultBinary => json_body::<Annotation>(extension_object, DataTypeId::Annotation, context),
+        _ => None,
+    }
+}
+
+/// Decodes the JSON body of an extension object if its data type is one of the service types,
+/// returning the object with the binary encoding of the body
+pub(crate) fn decode_json_body(data_type_id: &NodeId, body: &JsonValue, decoding_limits: &DecodingLimits) -> Option<EncodingResult<ExtensionObject>> {
+    let data_type_id = match data_type_id.identifier {
+        Identifier::Numeric(id) if data_type_id.namespace == 0 => DataTypeId::try_from(id).ok()?,
+        _ => return None,
+    };
+    match data_type_id {
+        DataTypeId::KeyValuePair => Some(binary_body::<KeyValuePair>(ObjectId::KeyValuePair_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::EndpointType => Some(binary_body::<EndpointType>(ObjectId::EndpointType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RationalNumber => Some(binary_body::<RationalNumber>(ObjectId::RationalNumber_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::Vector => Some(binary_body::<Vector>(ObjectId::Vector_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CartesianCoordinates => Some(binary_body::<CartesianCoordinates>(ObjectId::CartesianCoordinates_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::Orientation => Some(binary_body::<Orientation>(ObjectId::Orientation_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::Frame => Some(binary_body::<Frame>(ObjectId::Frame_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::IdentityMappingRuleType => Some(binary_body::<IdentityMappingRuleType>(ObjectId::IdentityMappingRuleType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::TrustListDataType => Some(binary_body::<TrustListDataType>(ObjectId::TrustListDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DecimalDataType => Some(binary_body::<DecimalDataType>(ObjectId::DecimalDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataTypeSchemaHeader => Some(binary_body::<DataTypeSchemaHeader>(ObjectId::DataTypeSchemaHeader_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataTypeDescription => Some(binary_body::<DataTypeDescription>(ObjectId::DataTypeDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::FieldMetaData => Some(binary_body::<FieldMetaData>(ObjectId::FieldMetaData_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ConfigurationVersionDataType => Some(binary_body::<ConfigurationVersionDataType>(ObjectId::ConfigurationVersionDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PublishedDataSetDataType => Some(binary_body::<PublishedDataSetDataType>(ObjectId::PublishedDataSetDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PublishedDataSetSourceDataType => Some(binary_body::<PublishedDataSetSourceDataType>(ObjectId::PublishedDataSetSourceDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PublishedVariableDataType => Some(binary_body::<PublishedVariableDataType>(ObjectId::PublishedVariableDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataSetWriterDataType => Some(binary_body::<DataSetWriterDataType>(ObjectId::DataSetWriterDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataSetWriterTransportDataType => Some(binary_body::<DataSetWriterTransportDataType>(ObjectId::DataSetWriterTransportDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataSetWriterMessageDataType => Some(binary_body::<DataSetWriterMessageDataType>(ObjectId::DataSetWriterMessageDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PubSubGroupDataType => Some(binary_body::<PubSubGroupDataType>(ObjectId::PubSubGroupDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::WriterGroupTransportDataType => Some(binary_body::<WriterGroupTransportDataType>(ObjectId::WriterGroupTransportDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::WriterGroupMessageDataType => Some(binary_body::<WriterGroupMessageDataType>(ObjectId::WriterGroupMessageDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PubSubConnectionDataType => Some(binary_body::<PubSubConnectionDataType>(ObjectId::PubSubConnectionDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ConnectionTransportDataType => Some(binary_body::<ConnectionTransportDataType>(ObjectId::ConnectionTransportDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::NetworkAddressDataType => Some(binary_body::<NetworkAddressDataType>(ObjectId::NetworkAddressDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ReaderGroupTransportDataType => Some(binary_body::<ReaderGroupTransportDataType>(ObjectId::ReaderGroupTransportDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ReaderGroupMessageDataType => Some(binary_body::<ReaderGroupMessageDataType>(ObjectId::ReaderGroupMessageDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataSetReaderDataType => Some(binary_body::<DataSetReaderDataType>(ObjectId::DataSetReaderDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataSetReaderTransportDataType => Some(binary_body::<DataSetReaderTransportDataType>(ObjectId::DataSetReaderTransportDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DataSetReaderMessageDataType => Some(binary_body::<DataSetReaderMessageDataType>(ObjectId::DataSetReaderMessageDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SubscribedDataSetDataType => Some(binary_body::<SubscribedDataSetDataType>(ObjectId::SubscribedDataSetDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::FieldTargetDataType => Some(binary_body::<FieldTargetDataType>(ObjectId::FieldTargetDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PubSubConfigurationDataType => Some(binary_body::<PubSubConfigurationDataType>(ObjectId::PubSubConfigurationDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RolePermissionType => Some(binary_body::<RolePermissionType>(ObjectId::RolePermissionType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::StructureField => Some(binary_body::<StructureField>(ObjectId::StructureField_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::Argument => Some(binary_body::<Argument>(ObjectId::Argument_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::EnumValueType => Some(binary_body::<EnumValueType>(ObjectId::EnumValueType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::OptionSet => Some(binary_body::<OptionSet>(ObjectId::OptionSet_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::TimeZoneDataType => Some(binary_body::<TimeZoneDataType>(ObjectId::TimeZoneDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ApplicationDescription => Some(binary_body::<ApplicationDescription>(ObjectId::ApplicationDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ServiceFault => Some(binary_body::<ServiceFault>(ObjectId::ServiceFault_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SessionlessInvokeRequestType => Some(binary_body::<SessionlessInvokeRequestType>(ObjectId::SessionlessInvokeRequestType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SessionlessInvokeResponseType => Some(binary_body::<SessionlessInvokeResponseType>(ObjectId::SessionlessInvokeResponseType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::FindServersRequest => Some(binary_body::<FindServersRequest>(ObjectId::FindServersRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::FindServersResponse => Some(binary_body::<FindServersResponse>(ObjectId::FindServersResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ServerOnNetwork => Some(binary_body::<ServerOnNetwork>(ObjectId::ServerOnNetwork_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::FindServersOnNetworkRequest => Some(binary_body::<FindServersOnNetworkRequest>(ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::FindServersOnNetworkResponse => Some(binary_body::<FindServersOnNetworkResponse>(ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::UserTokenPolicy => Some(binary_body::<UserTokenPolicy>(ObjectId::UserTokenPolicy_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::EndpointDescription => Some(binary_body::<EndpointDescription>(ObjectId::EndpointDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::GetEndpointsRequest => Some(binary_body::<GetEndpointsRequest>(ObjectId::GetEndpointsRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::GetEndpointsResponse => Some(binary_body::<GetEndpointsResponse>(ObjectId::GetEndpointsResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RegisteredServer => Some(binary_body::<RegisteredServer>(ObjectId::RegisteredServer_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RegisterServerRequest => Some(binary_body::<RegisterServerRequest>(ObjectId::RegisterServerRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RegisterServerResponse => Some(binary_body::<RegisterServerResponse>(ObjectId::RegisterServerResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DiscoveryConfiguration => Some(binary_body::<DiscoveryConfiguration>(ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RegisterServer2Request => Some(binary_body::<RegisterServer2Request>(ObjectId::RegisterServer2Request_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RegisterServer2Response => Some(binary_body::<RegisterServer2Response>(ObjectId::RegisterServer2Response_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ChannelSecurityToken => Some(binary_body::<ChannelSecurityToken>(ObjectId::ChannelSecurityToken_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::OpenSecureChannelRequest => Some(binary_body::<OpenSecureChannelRequest>(ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::OpenSecureChannelResponse => Some(binary_body::<OpenSecureChannelResponse>(ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CloseSecureChannelRequest => Some(binary_body::<CloseSecureChannelRequest>(ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CloseSecureChannelResponse => Some(binary_body::<CloseSecureChannelResponse>(ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SignedSoftwareCertificate => Some(binary_body::<SignedSoftwareCertificate>(ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SignatureData => Some(binary_body::<SignatureData>(ObjectId::SignatureData_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CreateSessionRequest => Some(binary_body::<CreateSessionRequest>(ObjectId::CreateSessionRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CreateSessionResponse => Some(binary_body::<CreateSessionResponse>(ObjectId::CreateSessionResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::UserIdentityToken => Some(binary_body::<UserIdentityToken>(ObjectId::UserIdentityToken_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ActivateSessionRequest => Some(binary_body::<ActivateSessionRequest>(ObjectId::ActivateSessionRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ActivateSessionResponse => Some(binary_body::<ActivateSessionResponse>(ObjectId::ActivateSessionResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CloseSessionRequest => Some(binary_body::<CloseSessionRequest>(ObjectId::CloseSessionRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CloseSessionResponse => Some(binary_body::<CloseSessionResponse>(ObjectId::CloseSessionResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CancelRequest => Some(binary_body::<CancelRequest>(ObjectId::CancelRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CancelResponse => Some(binary_body::<CancelResponse>(ObjectId::CancelResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::NodeAttributes => Some(binary_body::<NodeAttributes>(ObjectId::NodeAttributes_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::GenericAttributeValue => Some(binary_body::<GenericAttributeValue>(ObjectId::GenericAttributeValue_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AddNodesItem => Some(binary_body::<AddNodesItem>(ObjectId::AddNodesItem_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AddNodesResult => Some(binary_body::<AddNodesResult>(ObjectId::AddNodesResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AddNodesRequest => Some(binary_body::<AddNodesRequest>(ObjectId::AddNodesRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AddNodesResponse => Some(binary_body::<AddNodesResponse>(ObjectId::AddNodesResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AddReferencesItem => Some(binary_body::<AddReferencesItem>(ObjectId::AddReferencesItem_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AddReferencesRequest => Some(binary_body::<AddReferencesRequest>(ObjectId::AddReferencesRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AddReferencesResponse => Some(binary_body::<AddReferencesResponse>(ObjectId::AddReferencesResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteNodesItem => Some(binary_body::<DeleteNodesItem>(ObjectId::DeleteNodesItem_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteNodesRequest => Some(binary_body::<DeleteNodesRequest>(ObjectId::DeleteNodesRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteNodesResponse => Some(binary_body::<DeleteNodesResponse>(ObjectId::DeleteNodesResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteReferencesItem => Some(binary_body::<DeleteReferencesItem>(ObjectId::DeleteReferencesItem_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteReferencesRequest => Some(binary_body::<DeleteReferencesRequest>(ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteReferencesResponse => Some(binary_body::<DeleteReferencesResponse>(ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ViewDescription => Some(binary_body::<ViewDescription>(ObjectId::ViewDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowseDescription => Some(binary_body::<BrowseDescription>(ObjectId::BrowseDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ReferenceDescription => Some(binary_body::<ReferenceDescription>(ObjectId::ReferenceDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowseResult => Some(binary_body::<BrowseResult>(ObjectId::BrowseResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowseRequest => Some(binary_body::<BrowseRequest>(ObjectId::BrowseRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowseResponse => Some(binary_body::<BrowseResponse>(ObjectId::BrowseResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowseNextRequest => Some(binary_body::<BrowseNextRequest>(ObjectId::BrowseNextRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowseNextResponse => Some(binary_body::<BrowseNextResponse>(ObjectId::BrowseNextResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RelativePathElement => Some(binary_body::<RelativePathElement>(ObjectId::RelativePathElement_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RelativePath => Some(binary_body::<RelativePath>(ObjectId::RelativePath_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowsePath => Some(binary_body::<BrowsePath>(ObjectId::BrowsePath_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowsePathTarget => Some(binary_body::<BrowsePathTarget>(ObjectId::BrowsePathTarget_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BrowsePathResult => Some(binary_body::<BrowsePathResult>(ObjectId::BrowsePathResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::TranslateBrowsePathsToNodeIdsRequest => Some(binary_body::<TranslateBrowsePathsToNodeIdsRequest>(ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::TranslateBrowsePathsToNodeIdsResponse => Some(binary_body::<TranslateBrowsePathsToNodeIdsResponse>(ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RegisterNodesRequest => Some(binary_body::<RegisterNodesRequest>(ObjectId::RegisterNodesRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RegisterNodesResponse => Some(binary_body::<RegisterNodesResponse>(ObjectId::RegisterNodesResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::UnregisterNodesRequest => Some(binary_body::<UnregisterNodesRequest>(ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::UnregisterNodesResponse => Some(binary_body::<UnregisterNodesResponse>(ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::EndpointConfiguration => Some(binary_body::<EndpointConfiguration>(ObjectId::EndpointConfiguration_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::QueryDataDescription => Some(binary_body::<QueryDataDescription>(ObjectId::QueryDataDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::NodeTypeDescription => Some(binary_body::<NodeTypeDescription>(ObjectId::NodeTypeDescription_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::QueryDataSet => Some(binary_body::<QueryDataSet>(ObjectId::QueryDataSet_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::NodeReference => Some(binary_body::<NodeReference>(ObjectId::NodeReference_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ContentFilterElement => Some(binary_body::<ContentFilterElement>(ObjectId::ContentFilterElement_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ContentFilter => Some(binary_body::<ContentFilter>(ObjectId::ContentFilter_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::FilterOperand => Some(binary_body::<FilterOperand>(ObjectId::FilterOperand_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ContentFilterElementResult => Some(binary_body::<ContentFilterElementResult>(ObjectId::ContentFilterElementResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ContentFilterResult => Some(binary_body::<ContentFilterResult>(ObjectId::ContentFilterResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ParsingResult => Some(binary_body::<ParsingResult>(ObjectId::ParsingResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::QueryFirstRequest => Some(binary_body::<QueryFirstRequest>(ObjectId::QueryFirstRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::QueryFirstResponse => Some(binary_body::<QueryFirstResponse>(ObjectId::QueryFirstResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::QueryNextRequest => Some(binary_body::<QueryNextRequest>(ObjectId::QueryNextRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::QueryNextResponse => Some(binary_body::<QueryNextResponse>(ObjectId::QueryNextResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ReadValueId => Some(binary_body::<ReadValueId>(ObjectId::ReadValueId_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ReadRequest => Some(binary_body::<ReadRequest>(ObjectId::ReadRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ReadResponse => Some(binary_body::<ReadResponse>(ObjectId::ReadResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryReadValueId => Some(binary_body::<HistoryReadValueId>(ObjectId::HistoryReadValueId_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryReadResult => Some(binary_body::<HistoryReadResult>(ObjectId::HistoryReadResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryReadDetails => Some(binary_body::<HistoryReadDetails>(ObjectId::HistoryReadDetails_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryData => Some(binary_body::<HistoryData>(ObjectId::HistoryData_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ModificationInfo => Some(binary_body::<ModificationInfo>(ObjectId::ModificationInfo_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryEvent => Some(binary_body::<HistoryEvent>(ObjectId::HistoryEvent_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryReadRequest => Some(binary_body::<HistoryReadRequest>(ObjectId::HistoryReadRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryReadResponse => Some(binary_body::<HistoryReadResponse>(ObjectId::HistoryReadResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::WriteValue => Some(binary_body::<WriteValue>(ObjectId::WriteValue_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::WriteRequest => Some(binary_body::<WriteRequest>(ObjectId::WriteRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::WriteResponse => Some(binary_body::<WriteResponse>(ObjectId::WriteResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryUpdateDetails => Some(binary_body::<HistoryUpdateDetails>(ObjectId::HistoryUpdateDetails_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryUpdateResult => Some(binary_body::<HistoryUpdateResult>(ObjectId::HistoryUpdateResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryUpdateRequest => Some(binary_body::<HistoryUpdateRequest>(ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryUpdateResponse => Some(binary_body::<HistoryUpdateResponse>(ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CallMethodRequest => Some(binary_body::<CallMethodRequest>(ObjectId::CallMethodRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CallMethodResult => Some(binary_body::<CallMethodResult>(ObjectId::CallMethodResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CallRequest => Some(binary_body::<CallRequest>(ObjectId::CallRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CallResponse => Some(binary_body::<CallResponse>(ObjectId::CallResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoringFilter => Some(binary_body::<MonitoringFilter>(ObjectId::MonitoringFilter_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AggregateConfiguration => Some(binary_body::<AggregateConfiguration>(ObjectId::AggregateConfiguration_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoringFilterResult => Some(binary_body::<MonitoringFilterResult>(ObjectId::MonitoringFilterResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoringParameters => Some(binary_body::<MonitoringParameters>(ObjectId::MonitoringParameters_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoredItemCreateRequest => Some(binary_body::<MonitoredItemCreateRequest>(ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoredItemCreateResult => Some(binary_body::<MonitoredItemCreateResult>(ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CreateMonitoredItemsRequest => Some(binary_body::<CreateMonitoredItemsRequest>(ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CreateMonitoredItemsResponse => Some(binary_body::<CreateMonitoredItemsResponse>(ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoredItemModifyRequest => Some(binary_body::<MonitoredItemModifyRequest>(ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoredItemModifyResult => Some(binary_body::<MonitoredItemModifyResult>(ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ModifyMonitoredItemsRequest => Some(binary_body::<ModifyMonitoredItemsRequest>(ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ModifyMonitoredItemsResponse => Some(binary_body::<ModifyMonitoredItemsResponse>(ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SetMonitoringModeRequest => Some(binary_body::<SetMonitoringModeRequest>(ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SetMonitoringModeResponse => Some(binary_body::<SetMonitoringModeResponse>(ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SetTriggeringRequest => Some(binary_body::<SetTriggeringRequest>(ObjectId::SetTriggeringRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SetTriggeringResponse => Some(binary_body::<SetTriggeringResponse>(ObjectId::SetTriggeringResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteMonitoredItemsRequest => Some(binary_body::<DeleteMonitoredItemsRequest>(ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteMonitoredItemsResponse => Some(binary_body::<DeleteMonitoredItemsResponse>(ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CreateSubscriptionRequest => Some(binary_body::<CreateSubscriptionRequest>(ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::CreateSubscriptionResponse => Some(binary_body::<CreateSubscriptionResponse>(ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ModifySubscriptionRequest => Some(binary_body::<ModifySubscriptionRequest>(ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ModifySubscriptionResponse => Some(binary_body::<ModifySubscriptionResponse>(ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SetPublishingModeRequest => Some(binary_body::<SetPublishingModeRequest>(ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SetPublishingModeResponse => Some(binary_body::<SetPublishingModeResponse>(ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::NotificationMessage => Some(binary_body::<NotificationMessage>(ObjectId::NotificationMessage_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::NotificationData => Some(binary_body::<NotificationData>(ObjectId::NotificationData_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::MonitoredItemNotification => Some(binary_body::<MonitoredItemNotification>(ObjectId::MonitoredItemNotification_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::EventFieldList => Some(binary_body::<EventFieldList>(ObjectId::EventFieldList_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::HistoryEventFieldList => Some(binary_body::<HistoryEventFieldList>(ObjectId::HistoryEventFieldList_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SubscriptionAcknowledgement => Some(binary_body::<SubscriptionAcknowledgement>(ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PublishRequest => Some(binary_body::<PublishRequest>(ObjectId::PublishRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::PublishResponse => Some(binary_body::<PublishResponse>(ObjectId::PublishResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RepublishRequest => Some(binary_body::<RepublishRequest>(ObjectId::RepublishRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RepublishResponse => Some(binary_body::<RepublishResponse>(ObjectId::RepublishResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::TransferResult => Some(binary_body::<TransferResult>(ObjectId::TransferResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::TransferSubscriptionsRequest => Some(binary_body::<TransferSubscriptionsRequest>(ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::TransferSubscriptionsResponse => Some(binary_body::<TransferSubscriptionsResponse>(ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteSubscriptionsRequest => Some(binary_body::<DeleteSubscriptionsRequest>(ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DeleteSubscriptionsResponse => Some(binary_body::<DeleteSubscriptionsResponse>(ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::BuildInfo => Some(binary_body::<BuildInfo>(ObjectId::BuildInfo_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::RedundantServerDataType => Some(binary_body::<RedundantServerDataType>(ObjectId::RedundantServerDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::EndpointUrlListDataType => Some(binary_body::<EndpointUrlListDataType>(ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::NetworkGroupDataType => Some(binary_body::<NetworkGroupDataType>(ObjectId::NetworkGroupDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SamplingIntervalDiagnosticsDataType => Some(binary_body::<SamplingIntervalDiagnosticsDataType>(ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ServerDiagnosticsSummaryDataType => Some(binary_body::<ServerDiagnosticsSummaryDataType>(ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ServerStatusDataType => Some(binary_body::<ServerStatusDataType>(ObjectId::ServerStatusDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SessionDiagnosticsDataType => Some(binary_body::<SessionDiagnosticsDataType>(ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SessionSecurityDiagnosticsDataType => Some(binary_body::<SessionSecurityDiagnosticsDataType>(ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ServiceCounterDataType => Some(binary_body::<ServiceCounterDataType>(ObjectId::ServiceCounterDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::StatusResult => Some(binary_body::<StatusResult>(ObjectId::StatusResult_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SubscriptionDiagnosticsDataType => Some(binary_body::<SubscriptionDiagnosticsDataType>(ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ModelChangeStructureDataType => Some(binary_body::<ModelChangeStructureDataType>(ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::SemanticChangeStructureDataType => Some(binary_body::<SemanticChangeStructureDataType>(ObjectId::SemanticChangeStructureDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::Range => Some(binary_body::<Range>(ObjectId::Range_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::EUInformation => Some(binary_body::<EUInformation>(ObjectId::EUInformation_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ComplexNumberType => Some(binary_body::<ComplexNumberType>(ObjectId::ComplexNumberType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::DoubleComplexNumberType => Some(binary_body::<DoubleComplexNumberType>(ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::AxisInformation => Some(binary_body::<AxisInformation>(ObjectId::AxisInformation_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::XVType => Some(binary_body::<XVType>(ObjectId::XVType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ProgramDiagnosticDataType => Some(binary_body::<ProgramDiagnosticDataType>(ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::ProgramDiagnostic2DataType => Some(binary_body::<ProgramDiagnostic2DataType>(ObjectId::ProgramDiagnostic2DataType_Encoding_DefaultBinary, body, decoding_limits)),
+        DataTypeId::Annotation => Some(binary_body::<Annotation>(ObjectId::Annotation_Encoding_DefaultBinary, body, decoding_limits)),
+        _ => None,
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     string::UAString,
 };
@@ -48,3 +49,24 @@ impl BinaryEncoder<MdnsDiscoveryConfiguration> for MdnsDiscoveryConfiguration {
         })
     }
 }
+
+impl JsonEncoder for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "MdnsServerName", &self.mdns_server_name, context);
+        write_json_field(&mut object, "ServerCapabilities", &self.server_capabilities, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(MdnsDiscoveryConfiguration {
+            mdns_server_name: read_json_field(object, "MdnsServerName", decoding_limits)?,
+            server_capabilities: read_json_field(object, "ServerCapabilities", decoding_limits)?,
+        })
+    }
+}
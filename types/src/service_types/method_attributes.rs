@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     localized_text::LocalizedText,
 };
@@ -73,3 +74,34 @@ impl BinaryEncoder<MethodAttributes> for MethodAttributes {
         })
     }
 }
+
+impl JsonEncoder for MethodAttributes {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SpecifiedAttributes", &self.specified_attributes, context);
+        write_json_field(&mut object, "DisplayName", &self.display_name, context);
+        write_json_field(&mut object, "Description", &self.description, context);
+        write_json_field(&mut object, "WriteMask", &self.write_mask, context);
+        write_json_field(&mut object, "UserWriteMask", &self.user_write_mask, context);
+        write_json_field(&mut object, "Executable", &self.executable, context);
+        write_json_field(&mut object, "UserExecutable", &self.user_executable, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for MethodAttributes {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(MethodAttributes {
+            specified_attributes: read_json_field(object, "SpecifiedAttributes", decoding_limits)?,
+            display_name: read_json_field(object, "DisplayName", decoding_limits)?,
+            description: read_json_field(object, "Description", decoding_limits)?,
+            write_mask: read_json_field(object, "WriteMask", decoding_limits)?,
+            user_write_mask: read_json_field(object, "UserWriteMask", decoding_limits)?,
+            executable: read_json_field(object, "Executable", decoding_limits)?,
+            user_executable: read_json_field(object, "UserExecutable", decoding_limits)?,
+        })
+    }
+}
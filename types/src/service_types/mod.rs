@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 
 // The mods below are handwritten
@@ -17,6 +17,8 @@ pub use self::impls::*;
 
 // All of the remaining are generated by script
 
+pub(crate) mod json_bodies;
+
 mod key_value_pair;
 mod endpoint_type;
 mod rational_number;
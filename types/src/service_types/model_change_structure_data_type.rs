@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +62,26 @@ impl BinaryEncoder<ModelChangeStructureDataType> for ModelChangeStructureDataTyp
         })
     }
 }
+
+impl JsonEncoder for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Affected", &self.affected, context);
+        write_json_field(&mut object, "AffectedType", &self.affected_type, context);
+        write_json_field(&mut object, "Verb", &self.verb, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ModelChangeStructureDataType {
+            affected: read_json_field(object, "Affected", decoding_limits)?,
+            affected_type: read_json_field(object, "AffectedType", decoding_limits)?,
+            verb: read_json_field(object, "Verb", decoding_limits)?,
+        })
+    }
+}
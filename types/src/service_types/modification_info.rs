@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +64,26 @@ impl BinaryEncoder<ModificationInfo> for ModificationInfo {
         })
     }
 }
+
+impl JsonEncoder for ModificationInfo {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ModificationTime", &self.modification_time, context);
+        write_json_field(&mut object, "UpdateType", &self.update_type, context);
+        write_json_field(&mut object, "UserName", &self.user_name, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ModificationInfo {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ModificationInfo {
+            modification_time: read_json_field(object, "ModificationTime", decoding_limits)?,
+            update_type: read_json_field(object, "UpdateType", decoding_limits)?,
+            user_name: read_json_field(object, "UserName", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -81,3 +82,34 @@ impl BinaryEncoder<ModifySubscriptionRequest> for ModifySubscriptionRequest {
         })
     }
 }
+
+impl JsonEncoder for ModifySubscriptionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "SubscriptionId", &self.subscription_id, context);
+        write_json_field(&mut object, "RequestedPublishingInterval", &self.requested_publishing_interval, context);
+        write_json_field(&mut object, "RequestedLifetimeCount", &self.requested_lifetime_count, context);
+        write_json_field(&mut object, "RequestedMaxKeepAliveCount", &self.requested_max_keep_alive_count, context);
+        write_json_field(&mut object, "MaxNotificationsPerPublish", &self.max_notifications_per_publish, context);
+        write_json_field(&mut object, "Priority", &self.priority, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ModifySubscriptionRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ModifySubscriptionRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            subscription_id: read_json_field(object, "SubscriptionId", decoding_limits)?,
+            requested_publishing_interval: read_json_field(object, "RequestedPublishingInterval", decoding_limits)?,
+            requested_lifetime_count: read_json_field(object, "RequestedLifetimeCount", decoding_limits)?,
+            requested_max_keep_alive_count: read_json_field(object, "RequestedMaxKeepAliveCount", decoding_limits)?,
+            max_notifications_per_publish: read_json_field(object, "MaxNotificationsPerPublish", decoding_limits)?,
+            priority: read_json_field(object, "Priority", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +64,26 @@ impl BinaryEncoder<MonitoredItemCreateRequest> for MonitoredItemCreateRequest {
         })
     }
 }
+
+impl JsonEncoder for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ItemToMonitor", &self.item_to_monitor, context);
+        write_json_field(&mut object, "MonitoringMode", &self.monitoring_mode, context);
+        write_json_field(&mut object, "RequestedParameters", &self.requested_parameters, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(MonitoredItemCreateRequest {
+            item_to_monitor: read_json_field(object, "ItemToMonitor", decoding_limits)?,
+            monitoring_mode: read_json_field(object, "MonitoringMode", decoding_limits)?,
+            requested_parameters: read_json_field(object, "RequestedParameters", decoding_limits)?,
+        })
+    }
+}
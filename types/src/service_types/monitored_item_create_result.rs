@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -72,3 +73,30 @@ impl BinaryEncoder<MonitoredItemCreateResult> for MonitoredItemCreateResult {
         })
     }
 }
+
+impl JsonEncoder for MonitoredItemCreateResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "StatusCode", &self.status_code, context);
+        write_json_field(&mut object, "MonitoredItemId", &self.monitored_item_id, context);
+        write_json_field(&mut object, "RevisedSamplingInterval", &self.revised_sampling_interval, context);
+        write_json_field(&mut object, "RevisedQueueSize", &self.revised_queue_size, context);
+        write_json_field(&mut object, "FilterResult", &self.filter_result, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for MonitoredItemCreateResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(MonitoredItemCreateResult {
+            status_code: read_json_field(object, "StatusCode", decoding_limits)?,
+            monitored_item_id: read_json_field(object, "MonitoredItemId", decoding_limits)?,
+            revised_sampling_interval: read_json_field(object, "RevisedSamplingInterval", decoding_limits)?,
+            revised_queue_size: read_json_field(object, "RevisedQueueSize", decoding_limits)?,
+            filter_result: read_json_field(object, "FilterResult", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -71,3 +72,30 @@ impl BinaryEncoder<MonitoringParameters> for MonitoringParameters {
         })
     }
 }
+
+impl JsonEncoder for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ClientHandle", &self.client_handle, context);
+        write_json_field(&mut object, "SamplingInterval", &self.sampling_interval, context);
+        write_json_field(&mut object, "Filter", &self.filter, context);
+        write_json_field(&mut object, "QueueSize", &self.queue_size, context);
+        write_json_field(&mut object, "DiscardOldest", &self.discard_oldest, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(MonitoringParameters {
+            client_handle: read_json_field(object, "ClientHandle", decoding_limits)?,
+            sampling_interval: read_json_field(object, "SamplingInterval", decoding_limits)?,
+            filter: read_json_field(object, "Filter", decoding_limits)?,
+            queue_size: read_json_field(object, "QueueSize", decoding_limits)?,
+            discard_oldest: read_json_field(object, "DiscardOldest", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     string::UAString,
 };
@@ -48,3 +49,24 @@ impl BinaryEncoder<NetworkAddressUrlDataType> for NetworkAddressUrlDataType {
         })
     }
 }
+
+impl JsonEncoder for NetworkAddressUrlDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NetworkInterface", &self.network_interface, context);
+        write_json_field(&mut object, "Url", &self.url, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for NetworkAddressUrlDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(NetworkAddressUrlDataType {
+            network_interface: read_json_field(object, "NetworkInterface", decoding_limits)?,
+            url: read_json_field(object, "Url", decoding_limits)?,
+        })
+    }
+}
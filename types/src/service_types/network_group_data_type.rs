@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -57,3 +58,24 @@ impl BinaryEncoder<NetworkGroupDataType> for NetworkGroupDataType {
         })
     }
 }
+
+impl JsonEncoder for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ServerUri", &self.server_uri, context);
+        write_json_field(&mut object, "NetworkPaths", &self.network_paths, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(NetworkGroupDataType {
+            server_uri: read_json_field(object, "ServerUri", decoding_limits)?,
+            network_paths: read_json_field(object, "NetworkPaths", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -66,3 +67,28 @@ impl BinaryEncoder<NodeReference> for NodeReference {
         })
     }
 }
+
+impl JsonEncoder for NodeReference {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "ReferenceTypeId", &self.reference_type_id, context);
+        write_json_field(&mut object, "IsForward", &self.is_forward, context);
+        write_json_field(&mut object, "ReferencedNodeIds", &self.referenced_node_ids, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for NodeReference {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(NodeReference {
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            reference_type_id: read_json_field(object, "ReferenceTypeId", decoding_limits)?,
+            is_forward: read_json_field(object, "IsForward", decoding_limits)?,
+            referenced_node_ids: read_json_field(object, "ReferencedNodeIds", decoding_limits)?,
+        })
+    }
+}
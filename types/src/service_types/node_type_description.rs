@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<NodeTypeDescription> for NodeTypeDescription {
         })
     }
 }
+
+impl JsonEncoder for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "TypeDefinitionNode", &self.type_definition_node, context);
+        write_json_field(&mut object, "IncludeSubTypes", &self.include_sub_types, context);
+        write_json_field(&mut object, "DataToReturn", &self.data_to_return, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(NodeTypeDescription {
+            type_definition_node: read_json_field(object, "TypeDefinitionNode", decoding_limits)?,
+            include_sub_types: read_json_field(object, "IncludeSubTypes", decoding_limits)?,
+            data_to_return: read_json_field(object, "DataToReturn", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<NotificationMessage> for NotificationMessage {
         })
     }
 }
+
+impl JsonEncoder for NotificationMessage {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SequenceNumber", &self.sequence_number, context);
+        write_json_field(&mut object, "PublishTime", &self.publish_time, context);
+        write_json_field(&mut object, "NotificationData", &self.notification_data, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for NotificationMessage {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(NotificationMessage {
+            sequence_number: read_json_field(object, "SequenceNumber", decoding_limits)?,
+            publish_time: read_json_field(object, "PublishTime", decoding_limits)?,
+            notification_data: read_json_field(object, "NotificationData", decoding_limits)?,
+        })
+    }
+}
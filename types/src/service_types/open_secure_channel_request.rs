@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -79,3 +80,32 @@ impl BinaryEncoder<OpenSecureChannelRequest> for OpenSecureChannelRequest {
         })
     }
 }
+
+impl JsonEncoder for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "ClientProtocolVersion", &self.client_protocol_version, context);
+        write_json_field(&mut object, "RequestType", &self.request_type, context);
+        write_json_field(&mut object, "SecurityMode", &self.security_mode, context);
+        write_json_field(&mut object, "ClientNonce", &self.client_nonce, context);
+        write_json_field(&mut object, "RequestedLifetime", &self.requested_lifetime, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(OpenSecureChannelRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            client_protocol_version: read_json_field(object, "ClientProtocolVersion", decoding_limits)?,
+            request_type: read_json_field(object, "RequestType", decoding_limits)?,
+            security_mode: read_json_field(object, "SecurityMode", decoding_limits)?,
+            client_nonce: read_json_field(object, "ClientNonce", decoding_limits)?,
+            requested_lifetime: read_json_field(object, "RequestedLifetime", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -111,3 +112,44 @@ impl BinaryEncoder<ProgramDiagnostic2DataType> for ProgramDiagnostic2DataType {
         })
     }
 }
+
+impl JsonEncoder for ProgramDiagnostic2DataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "CreateSessionId", &self.create_session_id, context);
+        write_json_field(&mut object, "CreateClientName", &self.create_client_name, context);
+        write_json_field(&mut object, "InvocationCreationTime", &self.invocation_creation_time, context);
+        write_json_field(&mut object, "LastTransitionTime", &self.last_transition_time, context);
+        write_json_field(&mut object, "LastMethodCall", &self.last_method_call, context);
+        write_json_field(&mut object, "LastMethodSessionId", &self.last_method_session_id, context);
+        write_json_field(&mut object, "LastMethodInputArguments", &self.last_method_input_arguments, context);
+        write_json_field(&mut object, "LastMethodOutputArguments", &self.last_method_output_arguments, context);
+        write_json_field(&mut object, "LastMethodInputValues", &self.last_method_input_values, context);
+        write_json_field(&mut object, "LastMethodOutputValues", &self.last_method_output_values, context);
+        write_json_field(&mut object, "LastMethodCallTime", &self.last_method_call_time, context);
+        write_json_field(&mut object, "LastMethodReturnStatus", &self.last_method_return_status, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ProgramDiagnostic2DataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ProgramDiagnostic2DataType {
+            create_session_id: read_json_field(object, "CreateSessionId", decoding_limits)?,
+            create_client_name: read_json_field(object, "CreateClientName", decoding_limits)?,
+            invocation_creation_time: read_json_field(object, "InvocationCreationTime", decoding_limits)?,
+            last_transition_time: read_json_field(object, "LastTransitionTime", decoding_limits)?,
+            last_method_call: read_json_field(object, "LastMethodCall", decoding_limits)?,
+            last_method_session_id: read_json_field(object, "LastMethodSessionId", decoding_limits)?,
+            last_method_input_arguments: read_json_field(object, "LastMethodInputArguments", decoding_limits)?,
+            last_method_output_arguments: read_json_field(object, "LastMethodOutputArguments", decoding_limits)?,
+            last_method_input_values: read_json_field(object, "LastMethodInputValues", decoding_limits)?,
+            last_method_output_values: read_json_field(object, "LastMethodOutputValues", decoding_limits)?,
+            last_method_call_time: read_json_field(object, "LastMethodCallTime", decoding_limits)?,
+            last_method_return_status: read_json_field(object, "LastMethodReturnStatus", decoding_limits)?,
+        })
+    }
+}
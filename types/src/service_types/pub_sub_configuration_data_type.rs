@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<PubSubConfigurationDataType> for PubSubConfigurationDataType
         })
     }
 }
+
+impl JsonEncoder for PubSubConfigurationDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "PublishedDataSets", &self.published_data_sets, context);
+        write_json_field(&mut object, "Connections", &self.connections, context);
+        write_json_field(&mut object, "Enabled", &self.enabled, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for PubSubConfigurationDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(PubSubConfigurationDataType {
+            published_data_sets: read_json_field(object, "PublishedDataSets", decoding_limits)?,
+            connections: read_json_field(object, "Connections", decoding_limits)?,
+            enabled: read_json_field(object, "Enabled", decoding_limits)?,
+        })
+    }
+}
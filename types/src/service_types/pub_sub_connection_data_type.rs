@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -96,3 +97,38 @@ impl BinaryEncoder<PubSubConnectionDataType> for PubSubConnectionDataType {
         })
     }
 }
+
+impl JsonEncoder for PubSubConnectionDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "Enabled", &self.enabled, context);
+        write_json_field(&mut object, "PublisherId", &self.publisher_id, context);
+        write_json_field(&mut object, "TransportProfileUri", &self.transport_profile_uri, context);
+        write_json_field(&mut object, "Address", &self.address, context);
+        write_json_field(&mut object, "ConnectionProperties", &self.connection_properties, context);
+        write_json_field(&mut object, "TransportSettings", &self.transport_settings, context);
+        write_json_field(&mut object, "WriterGroups", &self.writer_groups, context);
+        write_json_field(&mut object, "ReaderGroups", &self.reader_groups, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for PubSubConnectionDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(PubSubConnectionDataType {
+            name: read_json_field(object, "Name", decoding_limits)?,
+            enabled: read_json_field(object, "Enabled", decoding_limits)?,
+            publisher_id: read_json_field(object, "PublisherId", decoding_limits)?,
+            transport_profile_uri: read_json_field(object, "TransportProfileUri", decoding_limits)?,
+            address: read_json_field(object, "Address", decoding_limits)?,
+            connection_properties: read_json_field(object, "ConnectionProperties", decoding_limits)?,
+            transport_settings: read_json_field(object, "TransportSettings", decoding_limits)?,
+            writer_groups: read_json_field(object, "WriterGroups", decoding_limits)?,
+            reader_groups: read_json_field(object, "ReaderGroups", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -84,3 +85,34 @@ impl BinaryEncoder<PubSubGroupDataType> for PubSubGroupDataType {
         })
     }
 }
+
+impl JsonEncoder for PubSubGroupDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "Enabled", &self.enabled, context);
+        write_json_field(&mut object, "SecurityMode", &self.security_mode, context);
+        write_json_field(&mut object, "SecurityGroupId", &self.security_group_id, context);
+        write_json_field(&mut object, "SecurityKeyServices", &self.security_key_services, context);
+        write_json_field(&mut object, "MaxNetworkMessageSize", &self.max_network_message_size, context);
+        write_json_field(&mut object, "GroupProperties", &self.group_properties, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for PubSubGroupDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(PubSubGroupDataType {
+            name: read_json_field(object, "Name", decoding_limits)?,
+            enabled: read_json_field(object, "Enabled", decoding_limits)?,
+            security_mode: read_json_field(object, "SecurityMode", decoding_limits)?,
+            security_group_id: read_json_field(object, "SecurityGroupId", decoding_limits)?,
+            security_key_services: read_json_field(object, "SecurityKeyServices", decoding_limits)?,
+            max_network_message_size: read_json_field(object, "MaxNetworkMessageSize", decoding_limits)?,
+            group_properties: read_json_field(object, "GroupProperties", decoding_limits)?,
+        })
+    }
+}
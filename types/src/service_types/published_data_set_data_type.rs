@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -74,3 +75,30 @@ impl BinaryEncoder<PublishedDataSetDataType> for PublishedDataSetDataType {
         })
     }
 }
+
+impl JsonEncoder for PublishedDataSetDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "DataSetFolder", &self.data_set_folder, context);
+        write_json_field(&mut object, "DataSetMetaData", &self.data_set_meta_data, context);
+        write_json_field(&mut object, "ExtensionFields", &self.extension_fields, context);
+        write_json_field(&mut object, "DataSetSource", &self.data_set_source, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for PublishedDataSetDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(PublishedDataSetDataType {
+            name: read_json_field(object, "Name", decoding_limits)?,
+            data_set_folder: read_json_field(object, "DataSetFolder", decoding_limits)?,
+            data_set_meta_data: read_json_field(object, "DataSetMetaData", decoding_limits)?,
+            extension_fields: read_json_field(object, "ExtensionFields", decoding_limits)?,
+            data_set_source: read_json_field(object, "DataSetSource", decoding_limits)?,
+        })
+    }
+}
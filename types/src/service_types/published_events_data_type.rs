@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     node_id::NodeId,
     service_types::SimpleAttributeOperand,
@@ -55,3 +56,26 @@ impl BinaryEncoder<PublishedEventsDataType> for PublishedEventsDataType {
         })
     }
 }
+
+impl JsonEncoder for PublishedEventsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "EventNotifier", &self.event_notifier, context);
+        write_json_field(&mut object, "SelectedFields", &self.selected_fields, context);
+        write_json_field(&mut object, "Filter", &self.filter, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for PublishedEventsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(PublishedEventsDataType {
+            event_notifier: read_json_field(object, "EventNotifier", decoding_limits)?,
+            selected_fields: read_json_field(object, "SelectedFields", decoding_limits)?,
+            filter: read_json_field(object, "Filter", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -89,3 +90,36 @@ impl BinaryEncoder<PublishedVariableDataType> for PublishedVariableDataType {
         })
     }
 }
+
+impl JsonEncoder for PublishedVariableDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "PublishedVariable", &self.published_variable, context);
+        write_json_field(&mut object, "AttributeId", &self.attribute_id, context);
+        write_json_field(&mut object, "SamplingIntervalHint", &self.sampling_interval_hint, context);
+        write_json_field(&mut object, "DeadbandType", &self.deadband_type, context);
+        write_json_field(&mut object, "DeadbandValue", &self.deadband_value, context);
+        write_json_field(&mut object, "IndexRange", &self.index_range, context);
+        write_json_field(&mut object, "SubstituteValue", &self.substitute_value, context);
+        write_json_field(&mut object, "MetaDataProperties", &self.meta_data_properties, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for PublishedVariableDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(PublishedVariableDataType {
+            published_variable: read_json_field(object, "PublishedVariable", decoding_limits)?,
+            attribute_id: read_json_field(object, "AttributeId", decoding_limits)?,
+            sampling_interval_hint: read_json_field(object, "SamplingIntervalHint", decoding_limits)?,
+            deadband_type: read_json_field(object, "DeadbandType", decoding_limits)?,
+            deadband_value: read_json_field(object, "DeadbandValue", decoding_limits)?,
+            index_range: read_json_field(object, "IndexRange", decoding_limits)?,
+            substitute_value: read_json_field(object, "SubstituteValue", decoding_limits)?,
+            meta_data_properties: read_json_field(object, "MetaDataProperties", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<QueryDataDescription> for QueryDataDescription {
         })
     }
 }
+
+impl JsonEncoder for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RelativePath", &self.relative_path, context);
+        write_json_field(&mut object, "AttributeId", &self.attribute_id, context);
+        write_json_field(&mut object, "IndexRange", &self.index_range, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(QueryDataDescription {
+            relative_path: read_json_field(object, "RelativePath", decoding_limits)?,
+            attribute_id: read_json_field(object, "AttributeId", decoding_limits)?,
+            index_range: read_json_field(object, "IndexRange", decoding_limits)?,
+        })
+    }
+}
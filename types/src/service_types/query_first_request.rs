@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -79,3 +80,32 @@ impl BinaryEncoder<QueryFirstRequest> for QueryFirstRequest {
         })
     }
 }
+
+impl JsonEncoder for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "View", &self.view, context);
+        write_json_field(&mut object, "NodeTypes", &self.node_types, context);
+        write_json_field(&mut object, "Filter", &self.filter, context);
+        write_json_field(&mut object, "MaxDataSetsToReturn", &self.max_data_sets_to_return, context);
+        write_json_field(&mut object, "MaxReferencesToReturn", &self.max_references_to_return, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(QueryFirstRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            view: read_json_field(object, "View", decoding_limits)?,
+            node_types: read_json_field(object, "NodeTypes", decoding_limits)?,
+            filter: read_json_field(object, "Filter", decoding_limits)?,
+            max_data_sets_to_return: read_json_field(object, "MaxDataSetsToReturn", decoding_limits)?,
+            max_references_to_return: read_json_field(object, "MaxReferencesToReturn", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +64,26 @@ impl BinaryEncoder<QueryNextResponse> for QueryNextResponse {
         })
     }
 }
+
+impl JsonEncoder for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ResponseHeader", &self.response_header, context);
+        write_json_field(&mut object, "QueryDataSets", &self.query_data_sets, context);
+        write_json_field(&mut object, "RevisedContinuationPoint", &self.revised_continuation_point, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(QueryNextResponse {
+            response_header: read_json_field(object, "ResponseHeader", decoding_limits)?,
+            query_data_sets: read_json_field(object, "QueryDataSets", decoding_limits)?,
+            revised_continuation_point: read_json_field(object, "RevisedContinuationPoint", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     date_time::DateTime,
 };
@@ -48,3 +49,24 @@ impl BinaryEncoder<ReadAtTimeDetails> for ReadAtTimeDetails {
         })
     }
 }
+
+impl JsonEncoder for ReadAtTimeDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ReqTimes", &self.req_times, context);
+        write_json_field(&mut object, "UseSimpleBounds", &self.use_simple_bounds, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ReadAtTimeDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ReadAtTimeDetails {
+            req_times: read_json_field(object, "ReqTimes", decoding_limits)?,
+            use_simple_bounds: read_json_field(object, "UseSimpleBounds", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     date_time::DateTime,
 };
@@ -63,3 +64,30 @@ impl BinaryEncoder<ReadRawModifiedDetails> for ReadRawModifiedDetails {
         })
     }
 }
+
+impl JsonEncoder for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "IsReadModified", &self.is_read_modified, context);
+        write_json_field(&mut object, "StartTime", &self.start_time, context);
+        write_json_field(&mut object, "EndTime", &self.end_time, context);
+        write_json_field(&mut object, "NumValuesPerNode", &self.num_values_per_node, context);
+        write_json_field(&mut object, "ReturnBounds", &self.return_bounds, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ReadRawModifiedDetails {
+            is_read_modified: read_json_field(object, "IsReadModified", decoding_limits)?,
+            start_time: read_json_field(object, "StartTime", decoding_limits)?,
+            end_time: read_json_field(object, "EndTime", decoding_limits)?,
+            num_values_per_node: read_json_field(object, "NumValuesPerNode", decoding_limits)?,
+            return_bounds: read_json_field(object, "ReturnBounds", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -68,3 +69,28 @@ impl BinaryEncoder<ReadRequest> for ReadRequest {
         })
     }
 }
+
+impl JsonEncoder for ReadRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "MaxAge", &self.max_age, context);
+        write_json_field(&mut object, "TimestampsToReturn", &self.timestamps_to_return, context);
+        write_json_field(&mut object, "NodesToRead", &self.nodes_to_read, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ReadRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ReadRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            max_age: read_json_field(object, "MaxAge", decoding_limits)?,
+            timestamps_to_return: read_json_field(object, "TimestampsToReturn", decoding_limits)?,
+            nodes_to_read: read_json_field(object, "NodesToRead", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<RedundantServerDataType> for RedundantServerDataType {
         })
     }
 }
+
+impl JsonEncoder for RedundantServerDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ServerId", &self.server_id, context);
+        write_json_field(&mut object, "ServiceLevel", &self.service_level, context);
+        write_json_field(&mut object, "ServerState", &self.server_state, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for RedundantServerDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(RedundantServerDataType {
+            server_id: read_json_field(object, "ServerId", decoding_limits)?,
+            service_level: read_json_field(object, "ServiceLevel", decoding_limits)?,
+            server_state: read_json_field(object, "ServerState", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -85,3 +86,34 @@ impl BinaryEncoder<ReferenceDescription> for ReferenceDescription {
         })
     }
 }
+
+impl JsonEncoder for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ReferenceTypeId", &self.reference_type_id, context);
+        write_json_field(&mut object, "IsForward", &self.is_forward, context);
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "BrowseName", &self.browse_name, context);
+        write_json_field(&mut object, "DisplayName", &self.display_name, context);
+        write_json_field(&mut object, "NodeClass", &self.node_class, context);
+        write_json_field(&mut object, "TypeDefinition", &self.type_definition, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ReferenceDescription {
+            reference_type_id: read_json_field(object, "ReferenceTypeId", decoding_limits)?,
+            is_forward: read_json_field(object, "IsForward", decoding_limits)?,
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            browse_name: read_json_field(object, "BrowseName", decoding_limits)?,
+            display_name: read_json_field(object, "DisplayName", decoding_limits)?,
+            node_class: read_json_field(object, "NodeClass", decoding_limits)?,
+            type_definition: read_json_field(object, "TypeDefinition", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +64,26 @@ impl BinaryEncoder<RegisterServer2Request> for RegisterServer2Request {
         })
     }
 }
+
+impl JsonEncoder for RegisterServer2Request {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "Server", &self.server, context);
+        write_json_field(&mut object, "DiscoveryConfiguration", &self.discovery_configuration, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for RegisterServer2Request {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(RegisterServer2Request {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            server: read_json_field(object, "Server", decoding_limits)?,
+            discovery_configuration: read_json_field(object, "DiscoveryConfiguration", decoding_limits)?,
+        })
+    }
+}
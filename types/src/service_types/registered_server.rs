@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -88,3 +89,36 @@ impl BinaryEncoder<RegisteredServer> for RegisteredServer {
         })
     }
 }
+
+impl JsonEncoder for RegisteredServer {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ServerUri", &self.server_uri, context);
+        write_json_field(&mut object, "ProductUri", &self.product_uri, context);
+        write_json_field(&mut object, "ServerNames", &self.server_names, context);
+        write_json_field(&mut object, "ServerType", &self.server_type, context);
+        write_json_field(&mut object, "GatewayServerUri", &self.gateway_server_uri, context);
+        write_json_field(&mut object, "DiscoveryUrls", &self.discovery_urls, context);
+        write_json_field(&mut object, "SemaphoreFilePath", &self.semaphore_file_path, context);
+        write_json_field(&mut object, "IsOnline", &self.is_online, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for RegisteredServer {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(RegisteredServer {
+            server_uri: read_json_field(object, "ServerUri", decoding_limits)?,
+            product_uri: read_json_field(object, "ProductUri", decoding_limits)?,
+            server_names: read_json_field(object, "ServerNames", decoding_limits)?,
+            server_type: read_json_field(object, "ServerType", decoding_limits)?,
+            gateway_server_uri: read_json_field(object, "GatewayServerUri", decoding_limits)?,
+            discovery_urls: read_json_field(object, "DiscoveryUrls", decoding_limits)?,
+            semaphore_file_path: read_json_field(object, "SemaphoreFilePath", decoding_limits)?,
+            is_online: read_json_field(object, "IsOnline", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -67,3 +68,28 @@ impl BinaryEncoder<RelativePathElement> for RelativePathElement {
         })
     }
 }
+
+impl JsonEncoder for RelativePathElement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ReferenceTypeId", &self.reference_type_id, context);
+        write_json_field(&mut object, "IsInverse", &self.is_inverse, context);
+        write_json_field(&mut object, "IncludeSubtypes", &self.include_subtypes, context);
+        write_json_field(&mut object, "TargetName", &self.target_name, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for RelativePathElement {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(RelativePathElement {
+            reference_type_id: read_json_field(object, "ReferenceTypeId", decoding_limits)?,
+            is_inverse: read_json_field(object, "IsInverse", decoding_limits)?,
+            include_subtypes: read_json_field(object, "IncludeSubtypes", decoding_limits)?,
+            target_name: read_json_field(object, "TargetName", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -65,3 +66,28 @@ impl BinaryEncoder<SamplingIntervalDiagnosticsDataType> for SamplingIntervalDiag
         })
     }
 }
+
+impl JsonEncoder for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SamplingInterval", &self.sampling_interval, context);
+        write_json_field(&mut object, "MonitoredItemCount", &self.monitored_item_count, context);
+        write_json_field(&mut object, "MaxMonitoredItemCount", &self.max_monitored_item_count, context);
+        write_json_field(&mut object, "DisabledMonitoredItemCount", &self.disabled_monitored_item_count, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(SamplingIntervalDiagnosticsDataType {
+            sampling_interval: read_json_field(object, "SamplingInterval", decoding_limits)?,
+            monitored_item_count: read_json_field(object, "MonitoredItemCount", decoding_limits)?,
+            max_monitored_item_count: read_json_field(object, "MaxMonitoredItemCount", decoding_limits)?,
+            disabled_monitored_item_count: read_json_field(object, "DisabledMonitoredItemCount", decoding_limits)?,
+        })
+    }
+}
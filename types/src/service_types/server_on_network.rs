@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -66,3 +67,28 @@ impl BinaryEncoder<ServerOnNetwork> for ServerOnNetwork {
         })
     }
 }
+
+impl JsonEncoder for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RecordId", &self.record_id, context);
+        write_json_field(&mut object, "ServerName", &self.server_name, context);
+        write_json_field(&mut object, "DiscoveryUrl", &self.discovery_url, context);
+        write_json_field(&mut object, "ServerCapabilities", &self.server_capabilities, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ServerOnNetwork {
+            record_id: read_json_field(object, "RecordId", decoding_limits)?,
+            server_name: read_json_field(object, "ServerName", decoding_limits)?,
+            discovery_url: read_json_field(object, "DiscoveryUrl", decoding_limits)?,
+            server_capabilities: read_json_field(object, "ServerCapabilities", decoding_limits)?,
+        })
+    }
+}
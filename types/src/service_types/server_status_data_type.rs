@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -79,3 +80,32 @@ impl BinaryEncoder<ServerStatusDataType> for ServerStatusDataType {
         })
     }
 }
+
+impl JsonEncoder for ServerStatusDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "StartTime", &self.start_time, context);
+        write_json_field(&mut object, "CurrentTime", &self.current_time, context);
+        write_json_field(&mut object, "State", &self.state, context);
+        write_json_field(&mut object, "BuildInfo", &self.build_info, context);
+        write_json_field(&mut object, "SecondsTillShutdown", &self.seconds_till_shutdown, context);
+        write_json_field(&mut object, "ShutdownReason", &self.shutdown_reason, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ServerStatusDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ServerStatusDataType {
+            start_time: read_json_field(object, "StartTime", decoding_limits)?,
+            current_time: read_json_field(object, "CurrentTime", decoding_limits)?,
+            state: read_json_field(object, "State", decoding_limits)?,
+            build_info: read_json_field(object, "BuildInfo", decoding_limits)?,
+            seconds_till_shutdown: read_json_field(object, "SecondsTillShutdown", decoding_limits)?,
+            shutdown_reason: read_json_field(object, "ShutdownReason", decoding_limits)?,
+        })
+    }
+}
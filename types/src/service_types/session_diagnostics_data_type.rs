@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -265,3 +266,106 @@ impl BinaryEncoder<SessionDiagnosticsDataType> for SessionDiagnosticsDataType {
         })
     }
 }
+
+impl JsonEncoder for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SessionId", &self.session_id, context);
+        write_json_field(&mut object, "SessionName", &self.session_name, context);
+        write_json_field(&mut object, "ClientDescription", &self.client_description, context);
+        write_json_field(&mut object, "ServerUri", &self.server_uri, context);
+        write_json_field(&mut object, "EndpointUrl", &self.endpoint_url, context);
+        write_json_field(&mut object, "LocaleIds", &self.locale_ids, context);
+        write_json_field(&mut object, "ActualSessionTimeout", &self.actual_session_timeout, context);
+        write_json_field(&mut object, "MaxResponseMessageSize", &self.max_response_message_size, context);
+        write_json_field(&mut object, "ClientConnectionTime", &self.client_connection_time, context);
+        write_json_field(&mut object, "ClientLastContactTime", &self.client_last_contact_time, context);
+        write_json_field(&mut object, "CurrentSubscriptionsCount", &self.current_subscriptions_count, context);
+        write_json_field(&mut object, "CurrentMonitoredItemsCount", &self.current_monitored_items_count, context);
+        write_json_field(&mut object, "CurrentPublishRequestsInQueue", &self.current_publish_requests_in_queue, context);
+        write_json_field(&mut object, "TotalRequestCount", &self.total_request_count, context);
+        write_json_field(&mut object, "UnauthorizedRequestCount", &self.unauthorized_request_count, context);
+        write_json_field(&mut object, "ReadCount", &self.read_count, context);
+        write_json_field(&mut object, "HistoryReadCount", &self.history_read_count, context);
+        write_json_field(&mut object, "WriteCount", &self.write_count, context);
+        write_json_field(&mut object, "HistoryUpdateCount", &self.history_update_count, context);
+        write_json_field(&mut object, "CallCount", &self.call_count, context);
+        write_json_field(&mut object, "CreateMonitoredItemsCount", &self.create_monitored_items_count, context);
+        write_json_field(&mut object, "ModifyMonitoredItemsCount", &self.modify_monitored_items_count, context);
+        write_json_field(&mut object, "SetMonitoringModeCount", &self.set_monitoring_mode_count, context);
+        write_json_field(&mut object, "SetTriggeringCount", &self.set_triggering_count, context);
+        write_json_field(&mut object, "DeleteMonitoredItemsCount", &self.delete_monitored_items_count, context);
+        write_json_field(&mut object, "CreateSubscriptionCount", &self.create_subscription_count, context);
+        write_json_field(&mut object, "ModifySubscriptionCount", &self.modify_subscription_count, context);
+        write_json_field(&mut object, "SetPublishingModeCount", &self.set_publishing_mode_count, context);
+        write_json_field(&mut object, "PublishCount", &self.publish_count, context);
+        write_json_field(&mut object, "RepublishCount", &self.republish_count, context);
+        write_json_field(&mut object, "TransferSubscriptionsCount", &self.transfer_subscriptions_count, context);
+        write_json_field(&mut object, "DeleteSubscriptionsCount", &self.delete_subscriptions_count, context);
+        write_json_field(&mut object, "AddNodesCount", &self.add_nodes_count, context);
+        write_json_field(&mut object, "AddReferencesCount", &self.add_references_count, context);
+        write_json_field(&mut object, "DeleteNodesCount", &self.delete_nodes_count, context);
+        write_json_field(&mut object, "DeleteReferencesCount", &self.delete_references_count, context);
+        write_json_field(&mut object, "BrowseCount", &self.browse_count, context);
+        write_json_field(&mut object, "BrowseNextCount", &self.browse_next_count, context);
+        write_json_field(&mut object, "TranslateBrowsePathsToNodeIdsCount", &self.translate_browse_paths_to_node_ids_count, context);
+        write_json_field(&mut object, "QueryFirstCount", &self.query_first_count, context);
+        write_json_field(&mut object, "QueryNextCount", &self.query_next_count, context);
+        write_json_field(&mut object, "RegisterNodesCount", &self.register_nodes_count, context);
+        write_json_field(&mut object, "UnregisterNodesCount", &self.unregister_nodes_count, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(SessionDiagnosticsDataType {
+            session_id: read_json_field(object, "SessionId", decoding_limits)?,
+            session_name: read_json_field(object, "SessionName", decoding_limits)?,
+            client_description: read_json_field(object, "ClientDescription", decoding_limits)?,
+            server_uri: read_json_field(object, "ServerUri", decoding_limits)?,
+            endpoint_url: read_json_field(object, "EndpointUrl", decoding_limits)?,
+            locale_ids: read_json_field(object, "LocaleIds", decoding_limits)?,
+            actual_session_timeout: read_json_field(object, "ActualSessionTimeout", decoding_limits)?,
+            max_response_message_size: read_json_field(object, "MaxResponseMessageSize", decoding_limits)?,
+            client_connection_time: read_json_field(object, "ClientConnectionTime", decoding_limits)?,
+            client_last_contact_time: read_json_field(object, "ClientLastContactTime", decoding_limits)?,
+            current_subscriptions_count: read_json_field(object, "CurrentSubscriptionsCount", decoding_limits)?,
+            current_monitored_items_count: read_json_field(object, "CurrentMonitoredItemsCount", decoding_limits)?,
+            current_publish_requests_in_queue: read_json_field(object, "CurrentPublishRequestsInQueue", decoding_limits)?,
+            total_request_count: read_json_field(object, "TotalRequestCount", decoding_limits)?,
+            unauthorized_request_count: read_json_field(object, "UnauthorizedRequestCount", decoding_limits)?,
+            read_count: read_json_field(object, "ReadCount", decoding_limits)?,
+            history_read_count: read_json_field(object, "HistoryReadCount", decoding_limits)?,
+            write_count: read_json_field(object, "WriteCount", decoding_limits)?,
+            history_update_count: read_json_field(object, "HistoryUpdateCount", decoding_limits)?,
+            call_count: read_json_field(object, "CallCount", decoding_limits)?,
+            create_monitored_items_count: read_json_field(object, "CreateMonitoredItemsCount", decoding_limits)?,
+            modify_monitored_items_count: read_json_field(object, "ModifyMonitoredItemsCount", decoding_limits)?,
+            set_monitoring_mode_count: read_json_field(object, "SetMonitoringModeCount", decoding_limits)?,
+            set_triggering_count: read_json_field(object, "SetTriggeringCount", decoding_limits)?,
+            delete_monitored_items_count: read_json_field(object, "DeleteMonitoredItemsCount", decoding_limits)?,
+            create_subscription_count: read_json_field(object, "CreateSubscriptionCount", decoding_limits)?,
+            modify_subscription_count: read_json_field(object, "ModifySubscriptionCount", decoding_limits)?,
+            set_publishing_mode_count: read_json_field(object, "SetPublishingModeCount", decoding_limits)?,
+            publish_count: read_json_field(object, "PublishCount", decoding_limits)?,
+            republish_count: read_json_field(object, "RepublishCount", decoding_limits)?,
+            transfer_subscriptions_count: read_json_field(object, "TransferSubscriptionsCount", decoding_limits)?,
+            delete_subscriptions_count: read_json_field(object, "DeleteSubscriptionsCount", decoding_limits)?,
+            add_nodes_count: read_json_field(object, "AddNodesCount", decoding_limits)?,
+            add_references_count: read_json_field(object, "AddReferencesCount", decoding_limits)?,
+            delete_nodes_count: read_json_field(object, "DeleteNodesCount", decoding_limits)?,
+            delete_references_count: read_json_field(object, "DeleteReferencesCount", decoding_limits)?,
+            browse_count: read_json_field(object, "BrowseCount", decoding_limits)?,
+            browse_next_count: read_json_field(object, "BrowseNextCount", decoding_limits)?,
+            translate_browse_paths_to_node_ids_count: read_json_field(object, "TranslateBrowsePathsToNodeIdsCount", decoding_limits)?,
+            query_first_count: read_json_field(object, "QueryFirstCount", decoding_limits)?,
+            query_next_count: read_json_field(object, "QueryNextCount", decoding_limits)?,
+            register_nodes_count: read_json_field(object, "RegisterNodesCount", decoding_limits)?,
+            unregister_nodes_count: read_json_field(object, "UnregisterNodesCount", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -94,3 +95,38 @@ impl BinaryEncoder<SessionSecurityDiagnosticsDataType> for SessionSecurityDiagno
         })
     }
 }
+
+impl JsonEncoder for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SessionId", &self.session_id, context);
+        write_json_field(&mut object, "ClientUserIdOfSession", &self.client_user_id_of_session, context);
+        write_json_field(&mut object, "ClientUserIdHistory", &self.client_user_id_history, context);
+        write_json_field(&mut object, "AuthenticationMechanism", &self.authentication_mechanism, context);
+        write_json_field(&mut object, "Encoding", &self.encoding, context);
+        write_json_field(&mut object, "TransportProtocol", &self.transport_protocol, context);
+        write_json_field(&mut object, "SecurityMode", &self.security_mode, context);
+        write_json_field(&mut object, "SecurityPolicyUri", &self.security_policy_uri, context);
+        write_json_field(&mut object, "ClientCertificate", &self.client_certificate, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(SessionSecurityDiagnosticsDataType {
+            session_id: read_json_field(object, "SessionId", decoding_limits)?,
+            client_user_id_of_session: read_json_field(object, "ClientUserIdOfSession", decoding_limits)?,
+            client_user_id_history: read_json_field(object, "ClientUserIdHistory", decoding_limits)?,
+            authentication_mechanism: read_json_field(object, "AuthenticationMechanism", decoding_limits)?,
+            encoding: read_json_field(object, "Encoding", decoding_limits)?,
+            transport_protocol: read_json_field(object, "TransportProtocol", decoding_limits)?,
+            security_mode: read_json_field(object, "SecurityMode", decoding_limits)?,
+            security_policy_uri: read_json_field(object, "SecurityPolicyUri", decoding_limits)?,
+            client_certificate: read_json_field(object, "ClientCertificate", decoding_limits)?,
+        })
+    }
+}
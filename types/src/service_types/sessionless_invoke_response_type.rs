@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +62,26 @@ impl BinaryEncoder<SessionlessInvokeResponseType> for SessionlessInvokeResponseT
         })
     }
 }
+
+impl JsonEncoder for SessionlessInvokeResponseType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NamespaceUris", &self.namespace_uris, context);
+        write_json_field(&mut object, "ServerUris", &self.server_uris, context);
+        write_json_field(&mut object, "ServiceId", &self.service_id, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for SessionlessInvokeResponseType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(SessionlessInvokeResponseType {
+            namespace_uris: read_json_field(object, "NamespaceUris", decoding_limits)?,
+            server_uris: read_json_field(object, "ServerUris", decoding_limits)?,
+            service_id: read_json_field(object, "ServiceId", decoding_limits)?,
+        })
+    }
+}
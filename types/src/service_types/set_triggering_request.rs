@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -71,3 +72,30 @@ impl BinaryEncoder<SetTriggeringRequest> for SetTriggeringRequest {
         })
     }
 }
+
+impl JsonEncoder for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "RequestHeader", &self.request_header, context);
+        write_json_field(&mut object, "SubscriptionId", &self.subscription_id, context);
+        write_json_field(&mut object, "TriggeringItemId", &self.triggering_item_id, context);
+        write_json_field(&mut object, "LinksToAdd", &self.links_to_add, context);
+        write_json_field(&mut object, "LinksToRemove", &self.links_to_remove, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(SetTriggeringRequest {
+            request_header: read_json_field(object, "RequestHeader", decoding_limits)?,
+            subscription_id: read_json_field(object, "SubscriptionId", decoding_limits)?,
+            triggering_item_id: read_json_field(object, "TriggeringItemId", decoding_limits)?,
+            links_to_add: read_json_field(object, "LinksToAdd", decoding_limits)?,
+            links_to_remove: read_json_field(object, "LinksToRemove", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     node_id::NodeId,
     qualified_name::QualifiedName,
@@ -59,3 +60,28 @@ impl BinaryEncoder<SimpleTypeDescription> for SimpleTypeDescription {
         })
     }
 }
+
+impl JsonEncoder for SimpleTypeDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "DataTypeId", &self.data_type_id, context);
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "BaseDataType", &self.base_data_type, context);
+        write_json_field(&mut object, "BuiltInType", &self.built_in_type, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for SimpleTypeDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(SimpleTypeDescription {
+            data_type_id: read_json_field(object, "DataTypeId", decoding_limits)?,
+            name: read_json_field(object, "Name", decoding_limits)?,
+            base_data_type: read_json_field(object, "BaseDataType", decoding_limits)?,
+            built_in_type: read_json_field(object, "BuiltInType", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     status_codes::StatusCode,
     diagnostic_info::DiagnosticInfo,
@@ -49,3 +50,24 @@ impl BinaryEncoder<StatusChangeNotification> for StatusChangeNotification {
         })
     }
 }
+
+impl JsonEncoder for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "Status", &self.status, context);
+        write_json_field(&mut object, "DiagnosticInfo", &self.diagnostic_info, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(StatusChangeNotification {
+            status: read_json_field(object, "Status", decoding_limits)?,
+            diagnostic_info: read_json_field(object, "DiagnosticInfo", decoding_limits)?,
+        })
+    }
+}
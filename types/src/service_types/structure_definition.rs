@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     node_id::NodeId,
     service_types::enums::StructureType,
@@ -60,3 +61,28 @@ impl BinaryEncoder<StructureDefinition> for StructureDefinition {
         })
     }
 }
+
+impl JsonEncoder for StructureDefinition {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "DefaultEncodingId", &self.default_encoding_id, context);
+        write_json_field(&mut object, "BaseDataType", &self.base_data_type, context);
+        write_json_field(&mut object, "StructureType", &self.structure_type, context);
+        write_json_field(&mut object, "Fields", &self.fields, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for StructureDefinition {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(StructureDefinition {
+            default_encoding_id: read_json_field(object, "DefaultEncodingId", decoding_limits)?,
+            base_data_type: read_json_field(object, "BaseDataType", decoding_limits)?,
+            structure_type: read_json_field(object, "StructureType", decoding_limits)?,
+            fields: read_json_field(object, "Fields", decoding_limits)?,
+        })
+    }
+}
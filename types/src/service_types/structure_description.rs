@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     node_id::NodeId,
     qualified_name::QualifiedName,
@@ -55,3 +56,26 @@ impl BinaryEncoder<StructureDescription> for StructureDescription {
         })
     }
 }
+
+impl JsonEncoder for StructureDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "DataTypeId", &self.data_type_id, context);
+        write_json_field(&mut object, "Name", &self.name, context);
+        write_json_field(&mut object, "StructureDefinition", &self.structure_definition, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for StructureDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(StructureDescription {
+            data_type_id: read_json_field(object, "DataTypeId", decoding_limits)?,
+            name: read_json_field(object, "Name", decoding_limits)?,
+            structure_definition: read_json_field(object, "StructureDefinition", decoding_limits)?,
+        })
+    }
+}
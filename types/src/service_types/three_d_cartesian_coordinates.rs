@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
 };
 
@@ -52,3 +53,26 @@ impl BinaryEncoder<ThreeDCartesianCoordinates> for ThreeDCartesianCoordinates {
         })
     }
 }
+
+impl JsonEncoder for ThreeDCartesianCoordinates {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "X", &self.x, context);
+        write_json_field(&mut object, "Y", &self.y, context);
+        write_json_field(&mut object, "Z", &self.z, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ThreeDCartesianCoordinates {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ThreeDCartesianCoordinates {
+            x: read_json_field(object, "X", decoding_limits)?,
+            y: read_json_field(object, "Y", decoding_limits)?,
+            z: read_json_field(object, "Z", decoding_limits)?,
+        })
+    }
+}
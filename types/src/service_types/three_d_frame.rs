@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::ThreeDCartesianCoordinates,
     service_types::ThreeDOrientation,
@@ -49,3 +50,24 @@ impl BinaryEncoder<ThreeDFrame> for ThreeDFrame {
         })
     }
 }
+
+impl JsonEncoder for ThreeDFrame {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "CartesianCoordinates", &self.cartesian_coordinates, context);
+        write_json_field(&mut object, "Orientation", &self.orientation, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ThreeDFrame {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ThreeDFrame {
+            cartesian_coordinates: read_json_field(object, "CartesianCoordinates", decoding_limits)?,
+            orientation: read_json_field(object, "Orientation", decoding_limits)?,
+        })
+    }
+}
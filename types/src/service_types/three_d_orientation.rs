@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
 };
 
@@ -52,3 +53,26 @@ impl BinaryEncoder<ThreeDOrientation> for ThreeDOrientation {
         })
     }
 }
+
+impl JsonEncoder for ThreeDOrientation {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "A", &self.a, context);
+        write_json_field(&mut object, "B", &self.b, context);
+        write_json_field(&mut object, "C", &self.c, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ThreeDOrientation {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ThreeDOrientation {
+            a: read_json_field(object, "A", decoding_limits)?,
+            b: read_json_field(object, "B", decoding_limits)?,
+            c: read_json_field(object, "C", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -71,3 +72,30 @@ impl BinaryEncoder<TrustListDataType> for TrustListDataType {
         })
     }
 }
+
+impl JsonEncoder for TrustListDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "SpecifiedLists", &self.specified_lists, context);
+        write_json_field(&mut object, "TrustedCertificates", &self.trusted_certificates, context);
+        write_json_field(&mut object, "TrustedCrls", &self.trusted_crls, context);
+        write_json_field(&mut object, "IssuerCertificates", &self.issuer_certificates, context);
+        write_json_field(&mut object, "IssuerCrls", &self.issuer_crls, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for TrustListDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(TrustListDataType {
+            specified_lists: read_json_field(object, "SpecifiedLists", decoding_limits)?,
+            trusted_certificates: read_json_field(object, "TrustedCertificates", decoding_limits)?,
+            trusted_crls: read_json_field(object, "TrustedCrls", decoding_limits)?,
+            issuer_certificates: read_json_field(object, "IssuerCertificates", decoding_limits)?,
+            issuer_crls: read_json_field(object, "IssuerCrls", decoding_limits)?,
+        })
+    }
+}
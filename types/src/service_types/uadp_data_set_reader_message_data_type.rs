@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     guid::Guid,
     service_types::enums::UadpNetworkMessageContentMask,
@@ -85,3 +86,38 @@ impl BinaryEncoder<UadpDataSetReaderMessageDataType> for UadpDataSetReaderMessag
         })
     }
 }
+
+impl JsonEncoder for UadpDataSetReaderMessageDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "GroupVersion", &self.group_version, context);
+        write_json_field(&mut object, "NetworkMessageNumber", &self.network_message_number, context);
+        write_json_field(&mut object, "DataSetOffset", &self.data_set_offset, context);
+        write_json_field(&mut object, "DataSetClassId", &self.data_set_class_id, context);
+        write_json_field(&mut object, "NetworkMessageContentMask", &self.network_message_content_mask, context);
+        write_json_field(&mut object, "DataSetMessageContentMask", &self.data_set_message_content_mask, context);
+        write_json_field(&mut object, "PublishingInterval", &self.publishing_interval, context);
+        write_json_field(&mut object, "ReceiveOffset", &self.receive_offset, context);
+        write_json_field(&mut object, "ProcessingOffset", &self.processing_offset, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for UadpDataSetReaderMessageDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(UadpDataSetReaderMessageDataType {
+            group_version: read_json_field(object, "GroupVersion", decoding_limits)?,
+            network_message_number: read_json_field(object, "NetworkMessageNumber", decoding_limits)?,
+            data_set_offset: read_json_field(object, "DataSetOffset", decoding_limits)?,
+            data_set_class_id: read_json_field(object, "DataSetClassId", decoding_limits)?,
+            network_message_content_mask: read_json_field(object, "NetworkMessageContentMask", decoding_limits)?,
+            data_set_message_content_mask: read_json_field(object, "DataSetMessageContentMask", decoding_limits)?,
+            publishing_interval: read_json_field(object, "PublishingInterval", decoding_limits)?,
+            receive_offset: read_json_field(object, "ReceiveOffset", decoding_limits)?,
+            processing_offset: read_json_field(object, "ProcessingOffset", decoding_limits)?,
+        })
+    }
+}
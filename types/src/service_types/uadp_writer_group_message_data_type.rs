@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::enums::DataSetOrderingType,
     service_types::enums::UadpNetworkMessageContentMask,
@@ -64,3 +65,30 @@ impl BinaryEncoder<UadpWriterGroupMessageDataType> for UadpWriterGroupMessageDat
         })
     }
 }
+
+impl JsonEncoder for UadpWriterGroupMessageDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "GroupVersion", &self.group_version, context);
+        write_json_field(&mut object, "DataSetOrdering", &self.data_set_ordering, context);
+        write_json_field(&mut object, "NetworkMessageContentMask", &self.network_message_content_mask, context);
+        write_json_field(&mut object, "SamplingOffset", &self.sampling_offset, context);
+        write_json_field(&mut object, "PublishingOffset", &self.publishing_offset, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for UadpWriterGroupMessageDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(UadpWriterGroupMessageDataType {
+            group_version: read_json_field(object, "GroupVersion", decoding_limits)?,
+            data_set_ordering: read_json_field(object, "DataSetOrdering", decoding_limits)?,
+            network_message_content_mask: read_json_field(object, "NetworkMessageContentMask", decoding_limits)?,
+            sampling_offset: read_json_field(object, "SamplingOffset", decoding_limits)?,
+            publishing_offset: read_json_field(object, "PublishingOffset", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     node_id::NodeId,
     service_types::enums::PerformUpdateType,
@@ -55,3 +56,26 @@ impl BinaryEncoder<UpdateDataDetails> for UpdateDataDetails {
         })
     }
 }
+
+impl JsonEncoder for UpdateDataDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "NodeId", &self.node_id, context);
+        write_json_field(&mut object, "PerformInsertReplace", &self.perform_insert_replace, context);
+        write_json_field(&mut object, "UpdateValues", &self.update_values, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for UpdateDataDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(UpdateDataDetails {
+            node_id: read_json_field(object, "NodeId", decoding_limits)?,
+            perform_insert_replace: read_json_field(object, "PerformInsertReplace", decoding_limits)?,
+            update_values: read_json_field(object, "UpdateValues", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -72,3 +73,30 @@ impl BinaryEncoder<UserTokenPolicy> for UserTokenPolicy {
         })
     }
 }
+
+impl JsonEncoder for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "PolicyId", &self.policy_id, context);
+        write_json_field(&mut object, "TokenType", &self.token_type, context);
+        write_json_field(&mut object, "IssuedTokenType", &self.issued_token_type, context);
+        write_json_field(&mut object, "IssuerEndpointUrl", &self.issuer_endpoint_url, context);
+        write_json_field(&mut object, "SecurityPolicyUri", &self.security_policy_uri, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(UserTokenPolicy {
+            policy_id: read_json_field(object, "PolicyId", decoding_limits)?,
+            token_type: read_json_field(object, "TokenType", decoding_limits)?,
+            issued_token_type: read_json_field(object, "IssuedTokenType", decoding_limits)?,
+            issuer_endpoint_url: read_json_field(object, "IssuerEndpointUrl", decoding_limits)?,
+            security_policy_uri: read_json_field(object, "SecurityPolicyUri", decoding_limits)?,
+        })
+    }
+}
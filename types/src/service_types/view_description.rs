@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +63,26 @@ impl BinaryEncoder<ViewDescription> for ViewDescription {
         })
     }
 }
+
+impl JsonEncoder for ViewDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "ViewId", &self.view_id, context);
+        write_json_field(&mut object, "Timestamp", &self.timestamp, context);
+        write_json_field(&mut object, "ViewVersion", &self.view_version, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for ViewDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(ViewDescription {
+            view_id: read_json_field(object, "ViewId", decoding_limits)?,
+            timestamp: read_json_field(object, "Timestamp", decoding_limits)?,
+            view_version: read_json_field(object, "ViewVersion", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(unused_attributes)]
 #![rustfmt::skip]
@@ -12,6 +12,7 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
     basic_types::*,
     string::UAString,
     byte_string::ByteString,
@@ -49,3 +50,24 @@ impl BinaryEncoder<X509IdentityToken> for X509IdentityToken {
         })
     }
 }
+
+impl JsonEncoder for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut object = JsonMap::new();
+        write_json_field(&mut object, "PolicyId", &self.policy_id, context);
+        write_json_field(&mut object, "CertificateData", &self.certificate_data, context);
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        Ok(X509IdentityToken {
+            policy_id: read_json_field(object, "PolicyId", decoding_limits)?,
+            certificate_data: read_json_field(object, "CertificateData", decoding_limits)?,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-// This file was autogenerated from Opc.Ua.StatusCodes.csv by tools/schema/gen_status_codes.js
+// This file was autogenerated from Opc.Ua.StatusCodes.csv by tools/codegen
 // DO NOT EDIT THIS FILE
 #![allow(non_upper_case_globals)]
 #![allow(unused_attributes)]
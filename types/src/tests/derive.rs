@@ -0,0 +1,198 @@
+use opcua_types_derive::BinaryEncoder;
+
+use crate::status_codes::StatusCode;
+use crate::*;
+
+#[derive(Debug, Clone, PartialEq, BinaryEncoder)]
+#[opcua(data_type_id = "ns=2;i=1000", encoding_id = "ns=2;i=1001")]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, BinaryEncoder)]
+#[opcua(data_type_id = "ns=2;i=2000")]
+enum Color {
+    Red = 0,
+    Green = 1,
+    Blue = 5,
+}
+
+#[derive(Debug, Clone, PartialEq, BinaryEncoder)]
+#[opcua(data_type_id = "ns=2;s=Shape", encoding_id = "ns=2;s=Shape.Binary")]
+struct Shape {
+    name: UAString,
+    color: Color,
+    points: Option<Vec<Point>>,
+    #[opcua(optional)]
+    origin: Option<Point>,
+    #[opcua(optional, name = "Tags")]
+    labels: Option<Vec<UAString>>,
+    #[opcua(data_type = "i=290")]
+    timeout: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, BinaryEncoder)]
+#[opcua(data_type_id = "ns=2;i=3000", encoding_id = "ns=2;i=3001")]
+enum Value {
+    Null,
+    Number(i32),
+    Text(UAString),
+    Numbers(Option<Vec<i32>>),
+}
+
+fn shape() -> Shape {
+    Shape {
+        name: UAString::from("Triangle"),
+        color: Color::Blue,
+        points: Some(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 0.0, y: 1.0 },
+        ]),
+        origin: None,
+        labels: None,
+        timeout: 100.0,
+    }
+}
+
+#[test]
+fn derive_structure() {
+    let point = Point { x: 1.5, y: -2.0 };
+    super::serialize_test(point.clone());
+    // The fields are encoded one after another
+    let mut expected = Vec::new();
+    1.5f64.encode(&mut expected).unwrap();
+    (-2.0f64).encode(&mut expected).unwrap();
+    let mut actual = Vec::new();
+    point.encode(&mut actual).unwrap();
+    assert_eq!(actual, expected);
+
+    let extension_object = point.to_extension_object();
+    assert_eq!(extension_object.node_id, NodeId::new(2, 1001));
+    assert_eq!(
+        extension_object
+            .decode_inner::<Point>(&DecodingLimits::default())
+            .unwrap(),
+        point
+    );
+}
+
+#[test]
+fn derive_optional_fields() {
+    let mut value = shape();
+    super::serialize_test(value.clone());
+    value.origin = Some(Point { x: 3.0, y: 4.0 });
+    super::serialize_test(value.clone());
+    value.labels = Some(vec![UAString::from("a"), UAString::from("b")]);
+    super::serialize_test(value.clone());
+
+    // The encoding mask comes first with a bit for each optional field that is present
+    value.origin = None;
+    let mut stream = Vec::new();
+    value.encode(&mut stream).unwrap();
+    assert_eq!(&stream[..4], &[2, 0, 0, 0]);
+}
+
+#[test]
+fn derive_enumeration() {
+    super::serialize_test(Color::Green);
+    let mut stream = Vec::new();
+    Color::Blue.encode(&mut stream).unwrap();
+    assert_eq!(stream, vec![5, 0, 0, 0]);
+
+    // A value that is not a variant is an error
+    let mut stream = std::io::Cursor::new(vec![2u8, 0, 0, 0]);
+    assert_eq!(
+        Color::decode(&mut stream, &DecodingLimits::default()).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn derive_union() {
+    super::serialize_test(Value::Null);
+    super::serialize_test(Value::Number(5));
+    super::serialize_test(Value::Text(UAString::from("five")));
+    super::serialize_test(Value::Numbers(Some(vec![1, 2, 3])));
+
+    let mut stream = Vec::new();
+    Value::Text(UAString::from("x"))
+        .encode(&mut stream)
+        .unwrap();
+    assert_eq!(&stream[..4], &[2, 0, 0, 0]);
+
+    // A switch field beyond the variants is an error
+    let mut stream = std::io::Cursor::new(vec![4u8, 0, 0, 0]);
+    assert_eq!(
+        Value::decode(&mut stream, &DecodingLimits::default()).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn derive_data_type_metadata() {
+    let definition = match Shape::data_type_definition() {
+        DataTypeDefinition::Structure(definition) => definition,
+        _ => panic!("Shape is not a structure"),
+    };
+    assert_eq!(
+        definition.default_encoding_id,
+        NodeId::new(2, "Shape.Binary")
+    );
+    assert_eq!(
+        definition.structure_type,
+        StructureType::StructureWithOptionalFields
+    );
+    let fields = definition.fields.unwrap();
+    let names = fields
+        .iter()
+        .map(|f| f.name.as_ref().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["Name", "Color", "Points", "Origin", "Tags", "Timeout"]
+    );
+    assert_eq!(fields[0].data_type, DataTypeId::String.into());
+    assert_eq!(fields[1].data_type, NodeId::new(2, 2000));
+    assert_eq!(fields[2].data_type, NodeId::new(2, 1000));
+    assert_eq!(fields[2].value_rank, 1);
+    assert!(!fields[2].is_optional);
+    assert!(fields[3].is_optional);
+    assert_eq!(fields[4].value_rank, 1);
+    assert_eq!(fields[5].data_type, DataTypeId::Duration.into());
+
+    match Color::data_type_definition() {
+        DataTypeDefinition::Enum(definition) => {
+            let fields = definition.fields.unwrap();
+            assert_eq!(fields.len(), 3);
+            assert_eq!(fields[2].name.as_ref(), "Blue");
+            assert_eq!(fields[2].value, 5);
+        }
+        _ => panic!("Color is not an enumeration"),
+    }
+    assert!(Color::encoding_id().is_null());
+
+    match Value::data_type_definition() {
+        DataTypeDefinition::Structure(definition) => {
+            assert_eq!(definition.structure_type, StructureType::Union);
+            assert_eq!(definition.fields.unwrap().len(), 3);
+        }
+        _ => panic!("Value is not a union"),
+    }
+
+    // A registry made from the derived definitions decodes values of the Rust types
+    let mut registry = DataTypeRegistry::new();
+    registry.add_type::<Point>();
+    registry.add_type::<Color>();
+    registry.add_type::<Shape>();
+    assert!(registry.unknown_data_types().is_empty());
+    let mut value = shape();
+    value.origin = Some(Point { x: 3.0, y: 4.0 });
+    let extension_object = value.to_extension_object();
+    let decoded = registry
+        .decode(&extension_object, &DecodingLimits::default())
+        .unwrap();
+    assert_eq!(decoded.field("Color"), Some(&DynamicValue::Enum(5)));
+    assert_eq!(registry.encode(&decoded).unwrap(), extension_object);
+}
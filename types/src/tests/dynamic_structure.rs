@@ -0,0 +1,315 @@
+use crate::status_codes::StatusCode;
+use crate::*;
+
+fn field(name: &str, data_type: NodeId, value_rank: i32, is_optional: bool) -> StructureField {
+    StructureField {
+        name: UAString::from(name),
+        description: LocalizedText::null(),
+        data_type,
+        value_rank,
+        array_dimensions: None,
+        max_string_length: 0,
+        is_optional,
+    }
+}
+
+fn structure(
+    encoding_id: u32,
+    structure_type: StructureType,
+    fields: Vec<StructureField>,
+) -> StructureDefinition {
+    StructureDefinition {
+        default_encoding_id: NodeId::new(2, encoding_id),
+        base_data_type: DataTypeId::Structure.into(),
+        structure_type,
+        fields: Some(fields),
+    }
+}
+
+/// A registry with a point, a shape which holds points and a color enum, an optional field
+/// structure and a union
+fn registry() -> DataTypeRegistry {
+    let mut registry = DataTypeRegistry::new();
+    registry.add_definition(
+        &NodeId::new(2, 1000),
+        structure(
+            1001,
+            StructureType::Structure,
+            vec![
+                field("X", DataTypeId::Double.into(), -1, false),
+                field("Y", DataTypeId::Double.into(), -1, false),
+            ],
+        ),
+    );
+    registry.add_definition(
+        &NodeId::new(2, 2000),
+        EnumDefinition {
+            fields: Some(vec![
+                EnumField {
+                    value: 0,
+                    display_name: LocalizedText::null(),
+                    description: LocalizedText::null(),
+                    name: UAString::from("Red"),
+                },
+                EnumField {
+                    value: 1,
+                    display_name: LocalizedText::null(),
+                    description: LocalizedText::null(),
+                    name: UAString::from("Green"),
+                },
+            ]),
+        },
+    );
+    registry.add_definition(
+        &NodeId::new(2, 3000),
+        structure(
+            3001,
+            StructureType::Structure,
+            vec![
+                field("Name", DataTypeId::String.into(), -1, false),
+                field("Color", NodeId::new(2, 2000), -1, false),
+                field("Points", NodeId::new(2, 1000), 1, false),
+                field("Created", DataTypeId::UtcTime.into(), -1, false),
+                field("Tag", DataTypeId::BaseDataType.into(), -1, false),
+            ],
+        ),
+    );
+    registry.add_definition(
+        &NodeId::new(2, 4000),
+        structure(
+            4001,
+            StructureType::StructureWithOptionalFields,
+            vec![
+                field("Id", DataTypeId::UInt32.into(), -1, false),
+                field("Comment", DataTypeId::String.into(), -1, true),
+                field("Origin", NodeId::new(2, 1000), -1, true),
+            ],
+        ),
+    );
+    registry.add_definition(
+        &NodeId::new(2, 5000),
+        structure(
+            5001,
+            StructureType::Union,
+            vec![
+                field("Number", DataTypeId::Int32.into(), -1, false),
+                field("Text", DataTypeId::String.into(), -1, false),
+            ],
+        ),
+    );
+    registry
+}
+
+fn point(x: f64, y: f64) -> DynamicStructure {
+    DynamicStructure::new(NodeId::new(2, 1000))
+        .with_field("X", Variant::from(x))
+        .with_field("Y", Variant::from(y))
+}
+
+fn round_trip(registry: &DataTypeRegistry, value: DynamicStructure) -> ExtensionObject {
+    let extension_object = registry.encode(&value).unwrap();
+    // Encode the extension object to a stream and back again as it would be sent
+    let extension_object = super::serialize_test_and_return(extension_object);
+    let decoded = registry
+        .decode(&extension_object, &DecodingLimits::default())
+        .unwrap();
+    assert_eq!(decoded, value);
+    extension_object
+}
+
+#[test]
+fn dynamic_structure() {
+    let registry = registry();
+    let extension_object = round_trip(&registry, point(1.5, -2.0));
+    assert_eq!(extension_object.node_id, NodeId::new(2, 1001));
+
+    // The body is the same as the fields encoded one after another
+    let mut expected = Vec::new();
+    1.5f64.encode(&mut expected).unwrap();
+    (-2.0f64).encode(&mut expected).unwrap();
+    assert_eq!(
+        extension_object.body,
+        ExtensionObjectEncoding::ByteString(ByteString::from(expected))
+    );
+
+    // A missing field cannot be encoded
+    let value = DynamicStructure::new(NodeId::new(2, 1000)).with_field("X", Variant::from(1.0));
+    assert_eq!(
+        registry.encode(&value).unwrap_err(),
+        StatusCode::BadEncodingError
+    );
+
+    // Nor can a field of the wrong type
+    let value = point(1.0, 2.0).with_field("Y", Variant::from("two"));
+    assert_eq!(
+        registry.encode(&value).unwrap_err(),
+        StatusCode::BadEncodingError
+    );
+}
+
+#[test]
+fn dynamic_structure_nested() {
+    let registry = registry();
+    let value = DynamicStructure::new(NodeId::new(2, 3000))
+        .with_field("Name", Variant::from("Triangle"))
+        .with_field("Color", DynamicValue::Enum(1))
+        .with_field(
+            "Points",
+            DynamicValue::Array(vec![
+                point(0.0, 0.0).into(),
+                point(1.0, 0.0).into(),
+                point(0.0, 1.0).into(),
+            ]),
+        )
+        .with_field(
+            "Created",
+            Variant::from(DateTime::ymd_hms(2020, 6, 1, 12, 30, 0)),
+        )
+        .with_field("Tag", Variant::from(100u16));
+    round_trip(&registry, value);
+
+    assert_eq!(
+        registry
+            .enum_field(&NodeId::new(2, 2000), 1)
+            .unwrap()
+            .name
+            .as_ref(),
+        "Green"
+    );
+    assert!(registry.enum_field(&NodeId::new(2, 2000), 2).is_none());
+}
+
+#[test]
+fn dynamic_structure_optional_fields() {
+    let registry = registry();
+    let value = DynamicStructure::new(NodeId::new(2, 4000)).with_field("Id", Variant::from(5u32));
+    round_trip(&registry, value.clone());
+    let value = value.with_field("Origin", point(3.0, 4.0));
+    let extension_object = round_trip(&registry, value.clone());
+    // Encoding mask has the second optional field
+    if let ExtensionObjectEncoding::ByteString(ref body) = extension_object.body {
+        assert_eq!(&body.value.as_ref().unwrap()[..4], &[2, 0, 0, 0]);
+    } else {
+        panic!();
+    }
+    // Decoded fields are in the order of the definition
+    let value = DynamicStructure::new(NodeId::new(2, 4000))
+        .with_field("Id", Variant::from(5u32))
+        .with_field("Comment", Variant::from("hello"))
+        .with_field("Origin", point(3.0, 4.0));
+    round_trip(&registry, value);
+
+    // The mandatory field must be present
+    let value =
+        DynamicStructure::new(NodeId::new(2, 4000)).with_field("Comment", Variant::from("x"));
+    assert_eq!(
+        registry.encode(&value).unwrap_err(),
+        StatusCode::BadEncodingError
+    );
+}
+
+#[test]
+fn dynamic_structure_union() {
+    let registry = registry();
+    round_trip(&registry, DynamicStructure::new(NodeId::new(2, 5000)));
+    let extension_object = round_trip(
+        &registry,
+        DynamicStructure::new(NodeId::new(2, 5000)).with_field("Text", Variant::from("abc")),
+    );
+    if let ExtensionObjectEncoding::ByteString(ref body) = extension_object.body {
+        assert_eq!(&body.value.as_ref().unwrap()[..4], &[2, 0, 0, 0]);
+    } else {
+        panic!();
+    }
+
+    // Only one field of a union may be set
+    let value = DynamicStructure::new(NodeId::new(2, 5000))
+        .with_field("Number", Variant::from(1i32))
+        .with_field("Text", Variant::from("abc"));
+    assert_eq!(
+        registry.encode(&value).unwrap_err(),
+        StatusCode::BadEncodingError
+    );
+
+    // A switch field beyond the fields is rejected
+    let extension_object = ExtensionObject {
+        node_id: NodeId::new(2, 5001),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(vec![3u8, 0, 0, 0])),
+    };
+    assert_eq!(
+        registry
+            .decode(&extension_object, &DecodingLimits::default())
+            .unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn dynamic_structure_unknown_types() {
+    let mut registry = registry();
+    assert!(registry.unknown_data_types().is_empty());
+    registry.add_definition(
+        &NodeId::new(2, 6000),
+        structure(
+            6001,
+            StructureType::Structure,
+            vec![
+                field("Point", NodeId::new(2, 1000), -1, false),
+                field("Other", NodeId::new(3, 1), -1, false),
+                field("Custom", NodeId::new(2, 7000), -1, false),
+            ],
+        ),
+    );
+    let mut unknown = registry.unknown_data_types();
+    unknown.sort_by_key(|n| n.to_string());
+    assert_eq!(unknown, vec![NodeId::new(2, 7000), NodeId::new(3, 1)]);
+
+    // A subtype of a built-in type is encoded as the built-in type
+    registry.add_subtype(&NodeId::new(2, 7000), &DataTypeId::Duration.into());
+    assert!(registry.is_known(&NodeId::new(2, 7000)));
+    assert_eq!(registry.unknown_data_types(), vec![NodeId::new(3, 1)]);
+
+    // A type without a definition cannot be decoded
+    let extension_object = ExtensionObject::from_encodable(NodeId::new(2, 9999), &1u32);
+    assert_eq!(
+        registry
+            .decode(&extension_object, &DecodingLimits::default())
+            .unwrap_err(),
+        StatusCode::BadDataTypeIdUnknown
+    );
+}
+
+#[test]
+fn data_type_definition() {
+    let definition = DataTypeDefinition::from(structure(
+        1001,
+        StructureType::Union,
+        vec![field("X", DataTypeId::Double.into(), -1, false)],
+    ));
+    let extension_object = definition.to_extension_object();
+    assert_eq!(
+        extension_object.object_id().unwrap(),
+        ObjectId::StructureDefinition_Encoding_DefaultBinary
+    );
+    assert_eq!(
+        DataTypeDefinition::from_extension_object(&extension_object, &DecodingLimits::default())
+            .unwrap(),
+        definition
+    );
+
+    let definition = DataTypeDefinition::from(EnumDefinition { fields: None });
+    let extension_object = definition.to_extension_object();
+    assert_eq!(
+        DataTypeDefinition::from_extension_object(&extension_object, &DecodingLimits::default())
+            .unwrap(),
+        definition
+    );
+
+    let extension_object =
+        ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &1u32);
+    assert!(DataTypeDefinition::from_extension_object(
+        &extension_object,
+        &DecodingLimits::default()
+    )
+    .is_err());
+}
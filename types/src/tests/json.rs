@@ -0,0 +1,446 @@
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use serde_json::json;
+
+use crate::json::*;
+use crate::status_codes::StatusCode;
+use crate::*;
+
+/// Encodes a value to reversible JSON, checks the JSON and that it decodes to the same value
+fn json_test<T>(value: T, expected: JsonValue)
+where
+    T: JsonEncoder + JsonDecoder + Debug + PartialEq,
+{
+    let json = value.encode_json(&JsonContext::reversible());
+    assert_eq!(json, expected);
+    let decoded = T::decode_json(&json, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, value);
+}
+
+fn non_reversible() -> JsonContext {
+    JsonContext::non_reversible(
+        vec![
+            "http://opcfoundation.org/UA/".to_string(),
+            "urn:local".to_string(),
+            "urn:other".to_string(),
+        ],
+        vec!["urn:server".to_string(), "urn:remote".to_string()],
+    )
+}
+
+#[test]
+fn json_numbers() {
+    json_test(true, json!(true));
+    json_test(-5i8, json!(-5));
+    json_test(200u8, json!(200));
+    json_test(-30000i16, json!(-30000));
+    json_test(i32::MIN, json!(i32::MIN));
+    json_test(u32::MAX, json!(u32::MAX));
+    // 64-bit integers are strings
+    json_test(i64::MIN, json!("-9223372036854775808"));
+    json_test(u64::MAX, json!("18446744073709551615"));
+    json_test(1.5f32, json!(1.5));
+    json_test(-2.25f64, json!(-2.25));
+    json_test(f64::INFINITY, json!("Infinity"));
+    json_test(f32::NEG_INFINITY, json!("-Infinity"));
+    assert_eq!(
+        f64::NAN.encode_json(&JsonContext::reversible()),
+        json!("NaN")
+    );
+    assert!(f64::decode_json(&json!("NaN"), &DecodingLimits::default())
+        .unwrap()
+        .is_nan());
+
+    // Missing values are zero, values out of range are errors
+    assert_eq!(
+        i32::decode_json(&JsonValue::Null, &DecodingLimits::default()),
+        Ok(0)
+    );
+    assert_eq!(
+        u8::decode_json(&json!(256), &DecodingLimits::default()),
+        Err(StatusCode::BadDecodingError)
+    );
+    assert_eq!(
+        i16::decode_json(&json!("1"), &DecodingLimits::default()),
+        Err(StatusCode::BadDecodingError)
+    );
+}
+
+#[test]
+fn json_strings() {
+    json_test(UAString::from("Hello"), json!("Hello"));
+    json_test(UAString::null(), JsonValue::Null);
+    json_test(ByteString::from(vec![1u8, 2, 3, 4]), json!("AQIDBA=="));
+    json_test(ByteString::null(), JsonValue::Null);
+    let guid = Guid::from_str("72962B91-FA75-4AE6-8D28-B404DC7DAF63").unwrap();
+    json_test(guid.clone(), json!(guid.to_string()));
+
+    let decoding_limits = DecodingLimits {
+        max_string_length: 3,
+        ..Default::default()
+    };
+    assert_eq!(
+        UAString::decode_json(&json!("Hello"), &decoding_limits),
+        Err(StatusCode::BadEncodingLimitsExceeded)
+    );
+}
+
+#[test]
+fn json_date_time() {
+    json_test(
+        DateTime::ymd_hms_nano(2020, 2, 29, 12, 30, 45, 123_000_000),
+        json!("2020-02-29T12:30:45.123Z"),
+    );
+    json_test(
+        DateTime::ymd_hms(2001, 1, 1, 0, 0, 0),
+        json!("2001-01-01T00:00:00Z"),
+    );
+    // The range of OPC UA date times is clamped
+    json_test(DateTime::null(), json!("0001-01-01T00:00:00Z"));
+    json_test(DateTime::endtimes(), json!("9999-12-31T23:59:59Z"));
+    let date_time = DateTime::decode_json(
+        &json!("2020-02-29T14:30:45+02:00"),
+        &DecodingLimits::default(),
+    )
+    .unwrap();
+    assert_eq!(date_time, DateTime::ymd_hms(2020, 2, 29, 12, 30, 45));
+}
+
+#[test]
+fn json_node_id() {
+    json_test(NodeId::new(0, 2255), json!({ "Id": 2255 }));
+    json_test(
+        NodeId::new(1, "Hello"),
+        json!({ "IdType": 1, "Id": "Hello", "Namespace": 1 }),
+    );
+    let guid = Guid::new();
+    json_test(
+        NodeId::new(2, guid.clone()),
+        json!({ "IdType": 2, "Id": guid.to_string(), "Namespace": 2 }),
+    );
+    json_test(
+        NodeId::new(3, ByteString::from(vec![1u8, 2, 3])),
+        json!({ "IdType": 3, "Id": "AQID", "Namespace": 3 }),
+    );
+    json_test(NodeId::null(), JsonValue::Null);
+
+    // The non-reversible form uses the URI of namespaces after 1
+    let context = non_reversible();
+    assert_eq!(
+        NodeId::new(1, 100).encode_json(&context),
+        json!({ "Id": 100, "Namespace": 1 })
+    );
+    assert_eq!(
+        NodeId::new(2, 100).encode_json(&context),
+        json!({ "Id": 100, "Namespace": "urn:other" })
+    );
+    assert_eq!(
+        NodeId::new(5, 100).encode_json(&context),
+        json!({ "Id": 100, "Namespace": 5 })
+    );
+}
+
+#[test]
+fn json_expanded_node_id() {
+    json_test(
+        ExpandedNodeId::from(NodeId::new(2, 100)),
+        json!({ "Id": 100, "Namespace": 2 }),
+    );
+    json_test(
+        ExpandedNodeId {
+            node_id: NodeId::new(0, "Hello"),
+            namespace_uri: UAString::from("urn:other"),
+            server_index: 1,
+        },
+        json!({ "IdType": 1, "Id": "Hello", "Namespace": "urn:other", "ServerUri": 1 }),
+    );
+    let value = ExpandedNodeId {
+        node_id: NodeId::new(2, 100),
+        namespace_uri: UAString::null(),
+        server_index: 1,
+    };
+    assert_eq!(
+        value.encode_json(&non_reversible()),
+        json!({ "Id": 100, "Namespace": "urn:other", "ServerUri": "urn:remote" })
+    );
+}
+
+#[test]
+fn json_status_code() {
+    json_test(StatusCode::Good, json!(0));
+    json_test(
+        StatusCode::BadNodeIdUnknown,
+        json!(StatusCode::BadNodeIdUnknown.bits()),
+    );
+    assert_eq!(
+        StatusCode::BadNodeIdUnknown.encode_json(&non_reversible()),
+        json!({ "Code": StatusCode::BadNodeIdUnknown.bits(), "Symbol": "BadNodeIdUnknown" })
+    );
+    // Good is left out of objects but not out of arrays
+    json_test(
+        vec![StatusCode::Good, StatusCode::BadTimeout],
+        json!([0, StatusCode::BadTimeout.bits()]),
+    );
+}
+
+#[test]
+fn json_names_and_text() {
+    json_test(
+        QualifiedName::new(2, "Name"),
+        json!({ "Name": "Name", "Uri": 2 }),
+    );
+    json_test(QualifiedName::new(0, "Name"), json!({ "Name": "Name" }));
+    json_test(QualifiedName::null(), JsonValue::Null);
+    assert_eq!(
+        QualifiedName::new(2, "Name").encode_json(&non_reversible()),
+        json!({ "Name": "Name", "Uri": "urn:other" })
+    );
+
+    json_test(
+        LocalizedText::new("en", "Hello"),
+        json!({ "Locale": "en", "Text": "Hello" }),
+    );
+    json_test(LocalizedText::null(), JsonValue::Null);
+    assert_eq!(
+        LocalizedText::new("en", "Hello").encode_json(&non_reversible()),
+        json!("Hello")
+    );
+}
+
+#[test]
+fn json_diagnostic_info() {
+    json_test(DiagnosticInfo::null(), JsonValue::Null);
+    json_test(
+        DiagnosticInfo {
+            symbolic_id: Some(1),
+            namespace_uri: None,
+            locale: Some(2),
+            localized_text: None,
+            additional_info: Some(UAString::from("More")),
+            inner_status_code: Some(StatusCode::BadTimeout),
+            inner_diagnostic_info: Some(Box::new(DiagnosticInfo {
+                symbolic_id: Some(3),
+                ..DiagnosticInfo::null()
+            })),
+        },
+        json!({
+            "SymbolicId": 1,
+            "Locale": 2,
+            "AdditionalInfo": "More",
+            "InnerStatusCode": StatusCode::BadTimeout.bits(),
+            "InnerDiagnosticInfo": { "SymbolicId": 3 }
+        }),
+    );
+}
+
+#[test]
+fn json_variant() {
+    json_test(Variant::Empty, JsonValue::Null);
+    json_test(Variant::from(5i32), json!({ "Type": 6, "Body": 5 }));
+    json_test(Variant::from(5u64), json!({ "Type": 9, "Body": "5" }));
+    json_test(
+        Variant::from("Hello"),
+        json!({ "Type": 12, "Body": "Hello" }),
+    );
+    json_test(
+        Variant::XmlElement(XmlElement::from("<a/>")),
+        json!({ "Type": 16, "Body": "<a/>" }),
+    );
+    json_test(
+        Variant::from(NodeId::new(1, 10)),
+        json!({ "Type": 17, "Body": { "Id": 10, "Namespace": 1 } }),
+    );
+    json_test(
+        Variant::from(vec![1i16, 2, 3]),
+        json!({ "Type": 4, "Body": [1, 2, 3] }),
+    );
+
+    // A multi-dimensional array has its dimensions in the reversible form and is nested in the
+    // non-reversible form
+    let values = (1..=6).map(Variant::from).collect::<Vec<Variant>>();
+    let value = Variant::from((values, vec![2u32, 3]));
+    json_test(
+        value.clone(),
+        json!({ "Type": 6, "Body": [1, 2, 3, 4, 5, 6], "Dimensions": [2, 3] }),
+    );
+    assert_eq!(
+        value.encode_json(&non_reversible()),
+        json!([[1, 2, 3], [4, 5, 6]])
+    );
+    assert_eq!(
+        Variant::from(true).encode_json(&non_reversible()),
+        json!(true)
+    );
+
+    // Dimensions have to match the number of values
+    assert_eq!(
+        Variant::decode_json(
+            &json!({ "Type": 6, "Body": [1, 2, 3], "Dimensions": [2, 2] }),
+            &DecodingLimits::default()
+        ),
+        Err(StatusCode::BadDecodingError)
+    );
+}
+
+#[test]
+fn json_data_value() {
+    json_test(
+        DataValue {
+            value: Some(Variant::from(1.5f64)),
+            status: Some(StatusCode::BadTimeout),
+            source_timestamp: Some(DateTime::ymd_hms(2020, 1, 1, 0, 0, 0)),
+            source_picoseconds: Some(10),
+            server_timestamp: None,
+            server_picoseconds: None,
+        },
+        json!({
+            "Value": { "Type": 11, "Body": 1.5 },
+            "Status": StatusCode::BadTimeout.bits(),
+            "SourceTimestamp": "2020-01-01T00:00:00Z",
+            "SourcePicoseconds": 10
+        }),
+    );
+    json_test(
+        DataValue {
+            value: None,
+            status: None,
+            source_timestamp: None,
+            source_picoseconds: None,
+            server_timestamp: None,
+            server_picoseconds: None,
+        },
+        json!({}),
+    );
+    let data_value = DataValue {
+        value: Some(Variant::from(StatusCode::Good)),
+        status: Some(StatusCode::Good),
+        source_timestamp: None,
+        source_picoseconds: None,
+        server_timestamp: None,
+        server_picoseconds: None,
+    };
+    assert_eq!(
+        data_value.encode_json(&JsonContext::reversible()),
+        json!({ "Value": { "Type": 19, "Body": 0 } })
+    );
+    assert_eq!(
+        data_value.encode_json(&non_reversible()),
+        json!({ "Value": { "Code": 0, "Symbol": "Good" } })
+    );
+}
+
+#[test]
+fn json_service_types() {
+    let read_value_id = ReadValueId {
+        node_id: NodeId::new(2, "Temperature"),
+        attribute_id: AttributeId::Value as u32,
+        index_range: UAString::null(),
+        data_encoding: QualifiedName::null(),
+    };
+    json_test(
+        read_value_id.clone(),
+        json!({
+            "NodeId": { "IdType": 1, "Id": "Temperature", "Namespace": 2 },
+            "AttributeId": 13
+        }),
+    );
+
+    // Arrays and enumerations
+    let request = ReadRequest {
+        request_header: RequestHeader {
+            authentication_token: NodeId::null(),
+            timestamp: DateTime::ymd_hms(2020, 1, 1, 0, 0, 0),
+            request_handle: 1,
+            return_diagnostics: DiagnosticBits::empty(),
+            audit_entry_id: UAString::null(),
+            timeout_hint: 0,
+            additional_header: ExtensionObject::null(),
+        },
+        max_age: 0.0,
+        timestamps_to_return: TimestampsToReturn::Both,
+        nodes_to_read: Some(vec![read_value_id]),
+    };
+    let json = request.encode_json(&JsonContext::reversible());
+    assert_eq!(json["TimestampsToReturn"], json!(2));
+    assert_eq!(json["NodesToRead"][0]["AttributeId"], json!(13));
+    assert_eq!(json["RequestHeader"]["RequestHandle"], json!(1));
+    let decoded = ReadRequest::decode_json(&json, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, request);
+    assert_eq!(
+        TimestampsToReturn::Both.encode_json(&non_reversible()),
+        json!("Both_2")
+    );
+    assert_eq!(
+        TimestampsToReturn::decode_json(&json!("Both_2"), &DecodingLimits::default()),
+        Ok(TimestampsToReturn::Both)
+    );
+    assert_eq!(
+        TimestampsToReturn::decode_json(&json!(10), &DecodingLimits::default()),
+        Err(StatusCode::BadDecodingError)
+    );
+}
+
+#[test]
+fn json_extension_object() {
+    json_test(ExtensionObject::null(), JsonValue::Null);
+
+    // A service type has a JSON body and the id of its data type
+    let range = Range {
+        low: 1.0,
+        high: 10.0,
+    };
+    let value = ExtensionObject::from_encodable(ObjectId::Range_Encoding_DefaultBinary, &range);
+    json_test(
+        value.clone(),
+        json!({ "TypeId": { "Id": DataTypeId::Range as u32 }, "Body": { "Low": 1.0, "High": 10.0 } }),
+    );
+    assert_eq!(
+        value.encode_json(&non_reversible()),
+        json!({ "Low": 1.0, "High": 10.0 })
+    );
+
+    // Anything else has a Base64 body
+    let value = ExtensionObject::from_encodable(NodeId::new(2, 1000), &range);
+    let json = value.encode_json(&JsonContext::reversible());
+    assert_eq!(json["Encoding"], json!(1));
+    assert_eq!(json["TypeId"], json!({ "Id": 1000, "Namespace": 2 }));
+    let decoded = ExtensionObject::decode_json(&json, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, value);
+
+    // A JSON body of an unknown type cannot be decoded
+    assert_eq!(
+        ExtensionObject::decode_json(
+            &json!({ "TypeId": { "Id": 1000, "Namespace": 2 }, "Body": {} }),
+            &DecodingLimits::default()
+        ),
+        Err(StatusCode::BadDecodingError)
+    );
+
+    // Inside a variant
+    json_test(
+        Variant::from(ExtensionObject::from_encodable(
+            ObjectId::Range_Encoding_DefaultBinary,
+            &range,
+        )),
+        json!({
+            "Type": 22,
+            "Body": { "TypeId": { "Id": DataTypeId::Range as u32 }, "Body": { "Low": 1.0, "High": 10.0 } }
+        }),
+    );
+}
+
+#[test]
+fn json_strings_round_trip() {
+    let value = Variant::from(LocalizedText::new("en", "Hello"));
+    let json = value.to_json_string(&JsonContext::reversible());
+    assert_eq!(json, r#"{"Body":{"Locale":"en","Text":"Hello"},"Type":21}"#);
+    assert_eq!(
+        Variant::from_json_str(&json, &DecodingLimits::default()),
+        Ok(value)
+    );
+    assert_eq!(
+        Variant::from_json_str("{", &DecodingLimits::default()),
+        Err(StatusCode::BadDecodingError)
+    );
+}
@@ -1,5 +1,8 @@
 mod date_time;
+mod derive;
+mod dynamic_structure;
 mod encoding;
+mod json;
 mod node_id;
 mod serde;
 mod variant;
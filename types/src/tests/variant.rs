@@ -251,6 +251,160 @@ fn index_of_string() {
     assert_eq!(r, StatusCode::BadIndexRangeNoData);
 }
 
+#[test]
+fn index_of_matrix() {
+    // A 3x4 matrix of values 0..12
+    let values: Vec<Variant> = (0..12).map(Variant::Int32).collect();
+    let v = Variant::from((values, vec![3u32, 4u32]));
+
+    // A single element
+    let r = v.range_of("1,2".parse::<NumericRange>().unwrap()).unwrap();
+    assert_eq!(
+        r,
+        Variant::from((vec![Variant::Int32(6)], vec![1u32, 1u32]))
+    );
+
+    // A 2x2 block
+    let r = v
+        .range_of("1:2,0:1".parse::<NumericRange>().unwrap())
+        .unwrap();
+    assert_eq!(
+        r,
+        Variant::from((
+            vec![
+                Variant::Int32(4),
+                Variant::Int32(5),
+                Variant::Int32(8),
+                Variant::Int32(9)
+            ],
+            vec![2u32, 2u32]
+        ))
+    );
+
+    // Upper bounds are clipped to the matrix
+    let r = v
+        .range_of("2:10,3:10".parse::<NumericRange>().unwrap())
+        .unwrap();
+    assert_eq!(
+        r,
+        Variant::from((vec![Variant::Int32(11)], vec![1u32, 1u32]))
+    );
+
+    // Lower bound outside of the matrix
+    let r = v
+        .range_of("3,0".parse::<NumericRange>().unwrap())
+        .unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeNoData);
+
+    // Ranges must match the dimensions of the matrix
+    let r = v.range_of(NumericRange::Index(1)).unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeInvalid);
+    let r = v
+        .range_of("0,0,0".parse::<NumericRange>().unwrap())
+        .unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeInvalid);
+}
+
+#[test]
+fn index_of_string_array() {
+    let v = Variant::from(vec![
+        Variant::from("Hello"),
+        Variant::from("Hi"),
+        Variant::from("Goodbye"),
+    ]);
+
+    // Substrings of some elements
+    let r = v
+        .range_of("1:2,1:3".parse::<NumericRange>().unwrap())
+        .unwrap();
+    assert_eq!(
+        r,
+        Variant::from(vec![Variant::from("i"), Variant::from("ood")])
+    );
+
+    // Elements that are too short are null
+    let r = v
+        .range_of("0:1,3".parse::<NumericRange>().unwrap())
+        .unwrap();
+    assert_eq!(
+        r,
+        Variant::from(vec![Variant::from("l"), Variant::from(UAString::null())])
+    );
+
+    // No element has data
+    let r = v
+        .range_of("0:2,10".parse::<NumericRange>().unwrap())
+        .unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeNoData);
+
+    // A substring range is invalid for other types
+    let v = Variant::from(&[1, 2, 3][..]);
+    let r = v
+        .range_of("0,0".parse::<NumericRange>().unwrap())
+        .unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeInvalid);
+}
+
+#[test]
+fn set_range_of_matrix() {
+    let values: Vec<Variant> = (0..6).map(Variant::Int32).collect();
+    let mut v = Variant::from((values, vec![2u32, 3u32]));
+
+    // Overwrite the right hand column
+    let other = Variant::from((
+        vec![Variant::Int32(20), Variant::Int32(50)],
+        vec![2u32, 1u32],
+    ));
+    v.set_range_of("0:1,2".parse::<NumericRange>().unwrap(), &other)
+        .unwrap();
+    let expected: Vec<Variant> = [0, 1, 20, 3, 4, 50]
+        .iter()
+        .map(|v| Variant::Int32(*v))
+        .collect();
+    assert_eq!(v, Variant::from((expected.clone(), vec![2u32, 3u32])));
+
+    // The size of the value must match the range
+    let other = Variant::from(vec![Variant::Int32(1)]);
+    let r = v
+        .set_range_of("0:1,2".parse::<NumericRange>().unwrap(), &other)
+        .unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeInvalid);
+    let r = v.set_range_of(NumericRange::Index(0), &other).unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeInvalid);
+    assert_eq!(v, Variant::from((expected, vec![2u32, 3u32])));
+}
+
+#[test]
+fn set_range_of_string() {
+    let mut v = Variant::from("Hello World");
+    v.set_range_of(NumericRange::Range(6, 10), &Variant::from("Earth"))
+        .unwrap();
+    assert_eq!(v, Variant::from("Hello Earth"));
+
+    let r = v
+        .set_range_of(NumericRange::Range(6, 10), &Variant::from("Mars"))
+        .unwrap_err();
+    assert_eq!(r, StatusCode::BadIndexRangeInvalid);
+
+    let mut v = Variant::from(ByteString::from(vec![1u8, 2u8, 3u8]));
+    v.set_range_of(
+        NumericRange::Index(1),
+        &Variant::from(ByteString::from(vec![9u8])),
+    )
+    .unwrap();
+    assert_eq!(v, Variant::from(ByteString::from(vec![1u8, 9u8, 3u8])));
+
+    // Substrings of string array elements
+    let mut v = Variant::from(vec![Variant::from("abc"), Variant::from("def")]);
+    let other = Variant::from(vec![Variant::from("X"), Variant::from("Y")]);
+    v.set_range_of("0:1,1".parse::<NumericRange>().unwrap(), &other)
+        .unwrap();
+    assert_eq!(
+        v,
+        Variant::from(vec![Variant::from("aXc"), Variant::from("dYf")])
+    );
+}
+
 fn ensure_conversion_fails(v: &Variant, convert_to: &[VariantTypeId]) {
     convert_to
         .iter()
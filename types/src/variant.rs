@@ -17,6 +17,7 @@ use crate::{
     encoding::*,
     extension_object::ExtensionObject,
     guid::Guid,
+    json::*,
     localized_text::LocalizedText,
     node_id::{ExpandedNodeId, Identifier, NodeId},
     node_ids::DataTypeId,
@@ -674,6 +675,90 @@ impl BinaryEncoder<Variant> for Variant {
     }
 }
 
+/// A variant is an object holding the built-in type of its value, the value and the dimensions
+/// of a multi-dimensional array in the reversible form. The non-reversible form is just the
+/// value, with a multi-dimensional array written as nested arrays.
+impl JsonEncoder for Variant {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let (type_id, body, dimensions) = match self {
+            Variant::Empty => return JsonValue::Null,
+            Variant::Array(array) => {
+                let type_id = array.encoding_mask() & !(ARRAY_DIMENSIONS_BIT | ARRAY_VALUES_BIT);
+                let values = array
+                    .values
+                    .iter()
+                    .map(|value| Variant::encode_json_value(value, context))
+                    .collect::<Vec<_>>();
+                if !array.has_dimensions() {
+                    (type_id, JsonValue::Array(values), None)
+                } else if context.is_reversible() {
+                    (
+                        type_id,
+                        JsonValue::Array(values),
+                        Some(array.dimensions.encode_json(context)),
+                    )
+                } else {
+                    (
+                        type_id,
+                        Variant::nest_json_values(&values, &array.dimensions),
+                        None,
+                    )
+                }
+            }
+            value => (
+                value.encoding_mask(),
+                Variant::encode_json_value(value, context),
+                None,
+            ),
+        };
+        if !context.is_reversible() {
+            return body;
+        }
+        let mut object = JsonMap::new();
+        object.insert("Type".to_string(), JsonValue::from(type_id));
+        object.insert("Body".to_string(), body);
+        if let Some(dimensions) = dimensions {
+            object.insert("Dimensions".to_string(), dimensions);
+        }
+        JsonValue::Object(object)
+    }
+}
+
+impl JsonDecoder for Variant {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object = json_object(value)?;
+        let type_id: u8 = read_json_field(object, "Type", decoding_limits)?;
+        match json_field(object, "Body") {
+            JsonValue::Array(values) => {
+                if values.len() > decoding_limits.max_array_length {
+                    return Err(StatusCode::BadEncodingLimitsExceeded);
+                }
+                let values = values
+                    .iter()
+                    .map(|value| Variant::decode_json_value(type_id, value, decoding_limits))
+                    .collect::<EncodingResult<Vec<Variant>>>()?;
+                let dimensions: Option<Vec<u32>> =
+                    read_json_field(object, "Dimensions", decoding_limits)?;
+                if let Some(dimensions) = dimensions {
+                    let array_dimensions_length = dimensions.iter().fold(1u32, |sum, d| sum * *d);
+                    if dimensions.is_empty() || array_dimensions_length as usize != values.len() {
+                        error!(
+                            "Array dimensions do not match array length {}",
+                            values.len()
+                        );
+                        Err(StatusCode::BadDecodingError)
+                    } else {
+                        Ok(Variant::from((values, dimensions)))
+                    }
+                } else {
+                    Ok(Variant::from(values))
+                }
+            }
+            body => Variant::decode_json_value(type_id, body, decoding_limits),
+        }
+    }
+}
+
 impl Default for Variant {
     fn default() -> Self {
         Variant::Empty
@@ -744,7 +829,10 @@ impl Variant {
     }
 
     /// Encodes just the value, not the encoding flag
-    fn encode_variant_value<S: Write>(stream: &mut S, value: &Variant) -> EncodingResult<usize> {
+    pub(crate) fn encode_variant_value<S: Write>(
+        stream: &mut S,
+        value: &Variant,
+    ) -> EncodingResult<usize> {
         match value {
             Variant::Empty => Ok(0),
             Variant::Boolean(value) => value.encode(stream),
@@ -777,7 +865,7 @@ impl Variant {
     }
 
     /// Reads just the variant value from the stream
-    fn decode_variant_value<S: Read>(
+    pub(crate) fn decode_variant_value<S: Read>(
         stream: &mut S,
         encoding_mask: u8,
         decoding_limits: &DecodingLimits,
@@ -835,6 +923,114 @@ impl Variant {
         Ok(result)
     }
 
+    /// Encodes just the value to JSON, not its type
+    fn encode_json_value(value: &Variant, context: &JsonContext) -> JsonValue {
+        match value {
+            Variant::Empty => JsonValue::Null,
+            Variant::Boolean(value) => value.encode_json(context),
+            Variant::SByte(value) => value.encode_json(context),
+            Variant::Byte(value) => value.encode_json(context),
+            Variant::Int16(value) => value.encode_json(context),
+            Variant::UInt16(value) => value.encode_json(context),
+            Variant::Int32(value) => value.encode_json(context),
+            Variant::UInt32(value) => value.encode_json(context),
+            Variant::Int64(value) => value.encode_json(context),
+            Variant::UInt64(value) => value.encode_json(context),
+            Variant::Float(value) => value.encode_json(context),
+            Variant::Double(value) => value.encode_json(context),
+            Variant::String(value) => value.encode_json(context),
+            Variant::DateTime(value) => value.encode_json(context),
+            Variant::Guid(value) => value.encode_json(context),
+            Variant::ByteString(value) => value.encode_json(context),
+            Variant::XmlElement(value) => value.encode_json(context),
+            Variant::NodeId(value) => value.encode_json(context),
+            Variant::ExpandedNodeId(value) => value.encode_json(context),
+            Variant::StatusCode(value) => value.encode_json(context),
+            Variant::QualifiedName(value) => value.encode_json(context),
+            Variant::LocalizedText(value) => value.encode_json(context),
+            Variant::ExtensionObject(value) => value.encode_json(context),
+            Variant::Array(_) => {
+                warn!("Cannot encode this variant value type (probably nested array)");
+                JsonValue::Null
+            }
+        }
+    }
+
+    /// Turns the values of a multi-dimensional array into nested arrays, one for each dimension
+    fn nest_json_values(values: &[JsonValue], dimensions: &[u32]) -> JsonValue {
+        let chunk_size = dimensions.iter().skip(1).product::<u32>() as usize;
+        if dimensions.len() <= 1 || chunk_size == 0 {
+            JsonValue::Array(values.to_vec())
+        } else {
+            JsonValue::Array(
+                values
+                    .chunks(chunk_size)
+                    .map(|values| Variant::nest_json_values(values, &dimensions[1..]))
+                    .collect(),
+            )
+        }
+    }
+
+    /// Decodes just the value from JSON, given its type
+    fn decode_json_value(
+        encoding_mask: u8,
+        value: &JsonValue,
+        decoding_limits: &DecodingLimits,
+    ) -> EncodingResult<Self> {
+        let result = if encoding_mask == 0 {
+            Variant::Empty
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Boolean) {
+            Self::from(bool::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::SByte) {
+            Self::from(i8::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Byte) {
+            Self::from(u8::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Int16) {
+            Self::from(i16::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::UInt16) {
+            Self::from(u16::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Int32) {
+            Self::from(i32::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::UInt32) {
+            Self::from(u32::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Int64) {
+            Self::from(i64::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::UInt64) {
+            Self::from(u64::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Float) {
+            Self::from(f32::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Double) {
+            Self::from(f64::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::String) {
+            Self::from(UAString::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::DateTime) {
+            Self::from(DateTime::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Guid) {
+            Self::from(Guid::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::ByteString) {
+            Self::from(ByteString::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::XmlElement) {
+            // Force the type to be XmlElement since its typedef'd to UAString
+            Variant::XmlElement(XmlElement::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::NodeId) {
+            Self::from(NodeId::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::ExpandedNodeId) {
+            Self::from(ExpandedNodeId::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::StatusCode) {
+            Self::from(StatusCode::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::QualifiedName) {
+            Self::from(QualifiedName::decode_json(value, decoding_limits)?)
+        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::LocalizedText) {
+            Self::from(LocalizedText::decode_json(value, decoding_limits)?)
+        } else if encoding_mask == 22 {
+            Self::from(ExtensionObject::decode_json(value, decoding_limits)?)
+        } else {
+            error!("Unsupported variant type {} in JSON", encoding_mask);
+            return Err(StatusCode::BadDecodingError);
+        };
+        Ok(result)
+    }
+
     /// Performs an EXPLICIT cast from one type to another. This will first attempt an implicit
     /// conversion and only then attempt to cast. Casting is potentially lossy.
     pub fn cast(&self, target_type: VariantTypeId) -> Variant {
@@ -1501,6 +1697,48 @@ impl Variant {
         }
     }
 
+    /// This function overwrites a substring of a ByteString or a UAString with the value of
+    /// another of the same type. The replacement must be exactly as long as the range.
+    fn set_substring(&mut self, min: usize, max: usize, other: &Variant) -> Result<(), StatusCode> {
+        match (self, other) {
+            (Variant::ByteString(v), Variant::ByteString(other)) => {
+                let mut bytes = v.value.clone().ok_or(StatusCode::BadIndexRangeNoData)?;
+                let other = other
+                    .value
+                    .as_ref()
+                    .ok_or(StatusCode::BadIndexRangeInvalid)?;
+                overwrite_range(&mut bytes, min, max, other)?;
+                *v = ByteString::from(bytes);
+                Ok(())
+            }
+            (Variant::String(v), Variant::String(other)) => {
+                let mut bytes = v
+                    .value()
+                    .clone()
+                    .ok_or(StatusCode::BadIndexRangeNoData)?
+                    .into_bytes();
+                let other = other
+                    .value()
+                    .as_ref()
+                    .ok_or(StatusCode::BadIndexRangeInvalid)?;
+                overwrite_range(&mut bytes, min, max, other.as_bytes())?;
+                let s = String::from_utf8(bytes).map_err(|_| StatusCode::BadIndexRangeInvalid)?;
+                *v = UAString::from(s);
+                Ok(())
+            }
+            _ => Err(StatusCode::BadTypeMismatch),
+        }
+    }
+
+    /// Returns a null value of the same type for a ByteString or a UAString, used in place of an
+    /// element whose substring has no data.
+    fn null_substring(&self) -> Variant {
+        match self {
+            Variant::ByteString(_) => Variant::from(ByteString::null()),
+            _ => Variant::from(UAString::null()),
+        }
+    }
+
     pub fn eq_scalar_type(&self, other: &Variant) -> bool {
         let self_data_type = self.scalar_data_type();
         let other_data_type = other.scalar_data_type();
@@ -1522,26 +1760,43 @@ impl Variant {
         }
     }
 
+    /// This function overwrites a range of values in the variant with the values from the other
+    /// variant. Arrays take a range for each dimension, optionally followed by a range that
+    /// is applied to each String or ByteString element. A String or ByteString takes a single
+    /// range and has the substring overwritten.
     pub fn set_range_of(&mut self, range: NumericRange, other: &Variant) -> Result<(), StatusCode> {
         // Types need to be the same
-        if !self.eq_array_type(other) {
+        if self.is_array() && !self.eq_array_type(other) {
             return Err(StatusCode::BadIndexRangeNoData);
         }
 
-        let other_array = if let Variant::Array(other) = other {
-            other
-        } else {
-            return Err(StatusCode::BadIndexRangeNoData);
-        };
-        let other_values = &other_array.values;
-
-        // Check value is same type as our array
         match self {
+            Variant::String(_) | Variant::ByteString(_) => match range {
+                NumericRange::None => Err(StatusCode::BadIndexRangeNoData),
+                NumericRange::Index(idx) => self.set_substring(idx as usize, idx as usize, other),
+                NumericRange::Range(min, max) => {
+                    self.set_substring(min as usize, max as usize, other)
+                }
+                NumericRange::MultipleRanges(_) => Err(StatusCode::BadIndexRangeInvalid),
+            },
             Variant::Array(ref mut array) => {
-                let values = &mut array.values;
+                let other_array = if let Variant::Array(other) = other {
+                    other
+                } else {
+                    return Err(StatusCode::BadIndexRangeNoData);
+                };
+                let other_values = &other_array.values;
+
                 match range {
                     NumericRange::None => Err(StatusCode::BadIndexRangeNoData),
+                    NumericRange::Index(_) | NumericRange::Range(_, _)
+                        if array.dimensions.len() > 1 =>
+                    {
+                        // Every dimension of a matrix must have a range
+                        Err(StatusCode::BadIndexRangeInvalid)
+                    }
                     NumericRange::Index(idx) => {
+                        let values = &mut array.values;
                         let idx = idx as usize;
                         if idx >= values.len() {
                             Err(StatusCode::BadIndexRangeNoData)
@@ -1553,6 +1808,7 @@ impl Variant {
                         }
                     }
                     NumericRange::Range(min, max) => {
+                        let values = &mut array.values;
                         let (min, max) = (min as usize, max as usize);
                         if min >= values.len() {
                             Err(StatusCode::BadIndexRangeNoData)
@@ -1568,10 +1824,30 @@ impl Variant {
                             Ok(())
                         }
                     }
-                    NumericRange::MultipleRanges(_ranges) => {
-                        // Not yet supported
-                        error!("Multiple ranges not supported");
-                        Err(StatusCode::BadIndexRangeNoData)
+                    NumericRange::MultipleRanges(ranges) => {
+                        let RangeSelection {
+                            indexes,
+                            dimensions,
+                            substring,
+                        } = select_range(array, &ranges)?;
+                        // The value written must be exactly the size of the selection
+                        if indexes.len() != other_values.len()
+                            || (other_array.has_dimensions()
+                                && other_array.dimensions != dimensions)
+                        {
+                            return Err(StatusCode::BadIndexRangeInvalid);
+                        }
+                        // Work on a copy so a failure part way through leaves the value untouched
+                        let mut values = array.values.clone();
+                        for (idx, other) in indexes.into_iter().zip(other_values.iter()) {
+                            if let Some((min, max)) = substring {
+                                values[idx].set_substring(min, max, other)?;
+                            } else {
+                                values[idx] = other.clone();
+                            }
+                        }
+                        array.values = values;
+                        Ok(())
                     }
                 }
             }
@@ -1583,7 +1859,8 @@ impl Variant {
     }
 
     /// This function gets a range of values from the variant if it is an array, or returns a clone
-    /// of the variant itself.
+    /// of the variant itself. Arrays take a range for each dimension, optionally followed by a
+    /// range that is applied to each String or ByteString element.
     pub fn range_of(&self, range: NumericRange) -> Result<Variant, StatusCode> {
         match range {
             NumericRange::None => Ok(self.clone()),
@@ -1591,6 +1868,9 @@ impl Variant {
                 let idx = idx as usize;
                 match self {
                     Variant::String(_) | Variant::ByteString(_) => self.substring(idx, idx),
+                    Variant::Array(array) if array.dimensions.len() > 1 => {
+                        Err(StatusCode::BadIndexRangeInvalid)
+                    }
                     Variant::Array(array) => {
                         // Get value at the index (or not)
                         let values = &array.values;
@@ -1608,6 +1888,9 @@ impl Variant {
                 let (min, max) = (min as usize, max as usize);
                 match self {
                     Variant::String(_) | Variant::ByteString(_) => self.substring(min, max),
+                    Variant::Array(array) if array.dimensions.len() > 1 => {
+                        Err(StatusCode::BadIndexRangeInvalid)
+                    }
                     Variant::Array(array) => {
                         let values = &array.values;
                         if min >= values.len() {
@@ -1627,11 +1910,143 @@ impl Variant {
                     _ => Err(StatusCode::BadIndexRangeNoData),
                 }
             }
-            NumericRange::MultipleRanges(_ranges) => {
-                // Not yet supported
-                error!("Multiple ranges not supported");
-                Err(StatusCode::BadIndexRangeNoData)
-            }
+            NumericRange::MultipleRanges(ranges) => match self {
+                Variant::String(_) | Variant::ByteString(_) => {
+                    // A scalar only has one dimension
+                    Err(StatusCode::BadIndexRangeInvalid)
+                }
+                Variant::Array(array) => {
+                    let RangeSelection {
+                        indexes,
+                        dimensions,
+                        substring,
+                    } = select_range(array, &ranges)?;
+                    let values = indexes.into_iter().map(|idx| &array.values[idx]);
+                    let values: Vec<Variant> = if let Some((min, max)) = substring {
+                        // Elements whose substring has no data are returned as nulls, but at
+                        // least one element must have data.
+                        let mut has_data = false;
+                        let values = values
+                            .map(|v| match v.substring(min, max) {
+                                Ok(v) => {
+                                    has_data = true;
+                                    v
+                                }
+                                Err(_) => v.null_substring(),
+                            })
+                            .collect();
+                        if !has_data {
+                            return Err(StatusCode::BadIndexRangeNoData);
+                        }
+                        values
+                    } else {
+                        values.cloned().collect()
+                    };
+                    if array.has_dimensions() {
+                        Ok(Variant::from((values, dimensions)))
+                    } else {
+                        Ok(Variant::from(values))
+                    }
+                }
+                _ => Err(StatusCode::BadIndexRangeNoData),
+            },
+        }
+    }
+}
+
+/// Converts the ranges of a multi-dimensional `NumericRange` into inclusive bounds. Nested
+/// multiple ranges are invalid.
+fn range_bounds(ranges: &[NumericRange]) -> Result<Vec<(usize, usize)>, StatusCode> {
+    ranges
+        .iter()
+        .map(|r| match *r {
+            NumericRange::Index(idx) => Ok((idx as usize, idx as usize)),
+            NumericRange::Range(min, max) => Ok((min as usize, max as usize)),
+            _ => Err(StatusCode::BadIndexRangeInvalid),
+        })
+        .collect()
+}
+
+/// The elements of an array selected by a multi-dimensional range.
+struct RangeSelection {
+    /// Indexes of the selected values in row-major order
+    indexes: Vec<usize>,
+    /// Dimensions of the selection
+    dimensions: Vec<u32>,
+    /// Range applied to each String or ByteString element, if there is one
+    substring: Option<(usize, usize)>,
+}
+
+/// Finds the elements of an array selected by a multi-dimensional range. The range must hold one
+/// bound per dimension of the array, optionally followed by a substring bound when the elements
+/// are Strings or ByteStrings. Upper bounds are clipped to the array but lower bounds must lie
+/// inside it.
+///
+fn select_range(array: &Array, ranges: &[NumericRange]) -> Result<RangeSelection, StatusCode> {
+    let mut bounds = range_bounds(ranges)?;
+    let dimensions: Vec<usize> = if array.has_dimensions() {
+        array.dimensions.iter().map(|d| *d as usize).collect()
+    } else {
+        vec![array.values.len()]
+    };
+    let substring = if bounds.len() == dimensions.len() {
+        None
+    } else if bounds.len() == dimensions.len() + 1 {
+        match array.values.first() {
+            Some(Variant::String(_)) | Some(Variant::ByteString(_)) => bounds.pop(),
+            _ => return Err(StatusCode::BadIndexRangeInvalid),
+        }
+    } else {
+        return Err(StatusCode::BadIndexRangeInvalid);
+    };
+
+    let mut indexes = vec![0usize];
+    let mut selected_dimensions = Vec::with_capacity(bounds.len());
+    for (i, (min, max)) in bounds.into_iter().enumerate() {
+        let dimension = dimensions[i];
+        if min >= dimension {
+            return Err(StatusCode::BadIndexRangeNoData);
+        }
+        let max = max.min(dimension - 1);
+        selected_dimensions.push((max - min + 1) as u32);
+        // Each index in this dimension is a block the size of the remaining dimensions
+        let stride: usize = dimensions[i + 1..].iter().product();
+        indexes = indexes
+            .into_iter()
+            .flat_map(|base| (min..=max).map(move |idx| base + idx * stride))
+            .collect();
+    }
+    // The dimensions may not agree with the number of values
+    if indexes.iter().any(|idx| *idx >= array.values.len()) {
+        return Err(StatusCode::BadIndexRangeNoData);
+    }
+    Ok(RangeSelection {
+        indexes,
+        dimensions: selected_dimensions,
+        substring,
+    })
+}
+
+/// Overwrites the inclusive range of the target with the source. The minimum must be inside the
+/// target, and the source must be exactly as long as the range once clipped to the target.
+fn overwrite_range<T>(
+    target: &mut [T],
+    min: usize,
+    max: usize,
+    source: &[T],
+) -> Result<(), StatusCode>
+where
+    T: Clone,
+{
+    if min >= target.len() {
+        Err(StatusCode::BadIndexRangeNoData)
+    } else {
+        let max = max.min(target.len() - 1);
+        if source.len() != max - min + 1 {
+            Err(StatusCode::BadIndexRangeInvalid)
+        } else {
+            target[min..=max].clone_from_slice(source);
+            Ok(())
         }
     }
 }